@@ -0,0 +1,44 @@
+//! Password work done by the `POST /api/v1/token` route (the "Get access token" step of `drill.yml`)
+//!
+//! Run with `cargo bench --bench login`.
+
+use auth2_api::domain::value_objects::password::{Password, PlainPassword};
+use auth2_api::domain::value_objects::password_policy::PasswordPolicy;
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+
+const PASSWORD: &str = "1A,R;(9h0Y&gYH5=7eY!ff";
+
+fn login(c: &mut Criterion) {
+    let stored = Password::new(PASSWORD, false).unwrap();
+    let mut group = c.benchmark_group("login");
+
+    // Previous behaviour: the strength scorer and a full argon2 hash were run before the verification
+    group.bench_function("hash_then_verify", |b| {
+        b.iter(|| {
+            let password = Password::new(black_box(PASSWORD), false).unwrap();
+            stored.verify(&password.original().unwrap()).unwrap();
+        })
+    });
+
+    // Current behaviour: only one argon2 verification
+    group.bench_function("verify_only", |b| {
+        b.iter(|| {
+            let password = PlainPassword::new(black_box(PASSWORD)).unwrap();
+            stored.verify(password.value()).unwrap();
+        })
+    });
+
+    // Unknown email: a dummy hash is verified, it must cost as much as `verify_only`
+    let dummy = Password::dummy(&PasswordPolicy::default()).unwrap();
+    group.bench_function("verify_unknown_email", |b| {
+        b.iter(|| {
+            let password = PlainPassword::new(black_box(PASSWORD)).unwrap();
+            assert!(dummy.verify(password.value()).is_err());
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, login);
+criterion_main!(benches);
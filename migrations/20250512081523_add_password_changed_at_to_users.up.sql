@@ -0,0 +1,9 @@
+-- Add up migration script here
+ALTER TABLE `users`
+    ADD COLUMN `password_changed_at` DATETIME(3) DEFAULT NULL AFTER `password`;
+
+UPDATE `users`
+SET `password_changed_at` = `updated_at`;
+
+ALTER TABLE `users`
+    MODIFY COLUMN `password_changed_at` DATETIME(3) NOT NULL;
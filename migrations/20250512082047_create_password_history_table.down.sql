@@ -0,0 +1,5 @@
+-- Add down migration script here
+ALTER TABLE `password_history`
+    DROP FOREIGN KEY `fk_password_history_user_id`;
+
+DROP TABLE IF EXISTS `password_history`;
@@ -0,0 +1,22 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS `password_history`
+(
+    `id`         VARCHAR(36)  NOT NULL,
+    `user_id`    VARCHAR(36)  NOT NULL,
+    `password`   VARCHAR(191) NOT NULL,
+    `created_at` DATETIME(3)  NOT NULL,
+    PRIMARY KEY (`id`),
+    INDEX `idx_password_history_user_id_created_at` (`user_id`, `created_at`)
+) ENGINE = InnoDB
+  DEFAULT CHARSET = utf8mb4
+  DEFAULT COLLATE = utf8mb4_general_ci;
+
+ALTER TABLE `password_history`
+    ADD CONSTRAINT `fk_password_history_user_id`
+        FOREIGN KEY (`user_id`)
+            REFERENCES `users` (`id`) ON DELETE CASCADE;
+
+-- Current passwords are the first entries of the history
+INSERT INTO `password_history` (`id`, `user_id`, `password`, `created_at`)
+SELECT UUID(), `id`, `password`, `password_changed_at`
+FROM `users`;
@@ -0,0 +1,5 @@
+-- Add down migration script here
+ALTER TABLE `magic_links`
+    DROP FOREIGN KEY `fk_magic_links_user_id`;
+
+DROP TABLE IF EXISTS `magic_links`;
@@ -0,0 +1,17 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS `magic_links`
+(
+    `user_id`    VARCHAR(36) NOT NULL,
+    `token`      VARCHAR(36) NOT NULL,
+    `expired_at` DATETIME(3) NOT NULL,
+    PRIMARY KEY (`user_id`),
+    UNIQUE KEY `uk_magic_links_token` (`token`),
+    KEY `idx_magic_links_expired_at` (`expired_at`)
+) ENGINE = InnoDB
+  DEFAULT CHARSET = utf8mb4
+  DEFAULT COLLATE = utf8mb4_general_ci;
+
+ALTER TABLE `magic_links`
+    ADD CONSTRAINT `fk_magic_links_user_id`
+        FOREIGN KEY (`user_id`)
+            REFERENCES `users` (`id`) ON DELETE CASCADE;
@@ -0,0 +1,5 @@
+-- Add down migration script here
+DELETE FROM `users` WHERE `password` IS NULL;
+
+ALTER TABLE `users`
+    MODIFY `password` VARCHAR(191) NOT NULL;
@@ -0,0 +1,4 @@
+-- Add up migration script here
+-- Invited users have no password until they accept the invitation
+ALTER TABLE `users`
+    MODIFY `password` VARCHAR(191) DEFAULT NULL;
@@ -0,0 +1,8 @@
+-- Add down migration script here
+ALTER TABLE `users_scopes`
+    DROP FOREIGN KEY `fk_users_scopes_scope_id`;
+
+ALTER TABLE `users_scopes`
+    DROP FOREIGN KEY `fk_users_scopes_user_id`;
+
+DROP TABLE IF EXISTS `users_scopes`;
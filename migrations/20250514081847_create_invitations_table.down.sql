@@ -0,0 +1,5 @@
+-- Add down migration script here
+ALTER TABLE `invitations`
+    DROP FOREIGN KEY `fk_invitations_user_id`;
+
+DROP TABLE IF EXISTS `invitations`;
@@ -0,0 +1,21 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS `invitations`
+(
+    `id`         VARCHAR(36) NOT NULL,
+    `user_id`    VARCHAR(36) NOT NULL,
+    `token`      VARCHAR(36) NOT NULL,
+    `expired_at` DATETIME(3) NOT NULL,
+    `created_at` DATETIME(3) NOT NULL,
+    `updated_at` DATETIME(3) NOT NULL,
+    PRIMARY KEY (`id`),
+    UNIQUE KEY `uk_invitations_user_id` (`user_id`),
+    UNIQUE KEY `uk_invitations_token` (`token`),
+    KEY `idx_invitations_expired_at` (`expired_at`)
+) ENGINE = InnoDB
+  DEFAULT CHARSET = utf8mb4
+  DEFAULT COLLATE = utf8mb4_general_ci;
+
+ALTER TABLE `invitations`
+    ADD CONSTRAINT `fk_invitations_user_id`
+        FOREIGN KEY (`user_id`)
+            REFERENCES `users` (`id`) ON DELETE CASCADE;
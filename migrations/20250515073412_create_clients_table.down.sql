@@ -0,0 +1,5 @@
+-- Add down migration script here
+ALTER TABLE `clients`
+    DROP FOREIGN KEY `fk_clients_application_id`;
+
+DROP TABLE IF EXISTS `clients`;
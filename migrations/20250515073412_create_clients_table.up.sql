@@ -0,0 +1,24 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS `clients`
+(
+    `id`                     VARCHAR(36)  NOT NULL,
+    `application_id`         VARCHAR(36)  NOT NULL,
+    `name`                   VARCHAR(63)  NOT NULL,
+    `redirect_uris`          TEXT         NOT NULL,
+    `grant_types`            VARCHAR(255) NOT NULL,
+    `access_token_lifetime`  INT UNSIGNED DEFAULT NULL,
+    `refresh_token_lifetime` INT UNSIGNED DEFAULT NULL,
+    `created_at`             DATETIME(3)  NOT NULL,
+    `updated_at`             DATETIME(3)  NOT NULL,
+    `deleted_at`             DATETIME(3)  DEFAULT NULL,
+    PRIMARY KEY (`id`),
+    KEY `idx_clients_application_id` (`application_id`),
+    KEY `idx_clients_deleted_at` (`deleted_at`)
+) ENGINE = InnoDB
+  DEFAULT CHARSET = utf8mb4
+  DEFAULT COLLATE = utf8mb4_general_ci;
+
+ALTER TABLE `clients`
+    ADD CONSTRAINT `fk_clients_application_id`
+        FOREIGN KEY (`application_id`)
+            REFERENCES `applications` (`id`);
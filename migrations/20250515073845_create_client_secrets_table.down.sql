@@ -0,0 +1,5 @@
+-- Add down migration script here
+ALTER TABLE `client_secrets`
+    DROP FOREIGN KEY `fk_client_secrets_client_id`;
+
+DROP TABLE IF EXISTS `client_secrets`;
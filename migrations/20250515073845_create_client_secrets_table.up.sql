@@ -0,0 +1,17 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS `client_secrets`
+(
+    `id`         VARCHAR(36)  NOT NULL,
+    `client_id`  VARCHAR(36)  NOT NULL,
+    `secret`     VARCHAR(191) NOT NULL,
+    `created_at` DATETIME(3)  NOT NULL,
+    PRIMARY KEY (`id`),
+    KEY `idx_client_secrets_client_id` (`client_id`)
+) ENGINE = InnoDB
+  DEFAULT CHARSET = utf8mb4
+  DEFAULT COLLATE = utf8mb4_general_ci;
+
+ALTER TABLE `client_secrets`
+    ADD CONSTRAINT `fk_client_secrets_client_id`
+        FOREIGN KEY (`client_id`)
+            REFERENCES `clients` (`id`) ON DELETE CASCADE;
@@ -0,0 +1,5 @@
+-- Add down migration script here
+ALTER TABLE `applications`
+    DROP COLUMN `audience`,
+    DROP COLUMN `refresh_token_lifetime`,
+    DROP COLUMN `access_token_lifetime`;
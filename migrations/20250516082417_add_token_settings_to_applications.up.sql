@@ -0,0 +1,6 @@
+-- Add up migration script here
+-- Token settings overriding the global JWT configuration (NULL to use the default value)
+ALTER TABLE `applications`
+    ADD COLUMN `access_token_lifetime`  INT UNSIGNED DEFAULT NULL AFTER `name`,
+    ADD COLUMN `refresh_token_lifetime` INT UNSIGNED DEFAULT NULL AFTER `access_token_lifetime`,
+    ADD COLUMN `audience`               VARCHAR(255) DEFAULT NULL AFTER `refresh_token_lifetime`;
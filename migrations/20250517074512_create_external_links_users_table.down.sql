@@ -0,0 +1,8 @@
+-- Add down migration script here
+ALTER TABLE `external_links_users`
+    DROP FOREIGN KEY `fk_external_links_users_user_id`;
+
+ALTER TABLE `external_links_users`
+    DROP FOREIGN KEY `fk_external_links_users_external_link_id`;
+
+DROP TABLE IF EXISTS `external_links_users`;
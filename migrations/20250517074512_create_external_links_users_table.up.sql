@@ -0,0 +1,23 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS `external_links_users`
+(
+    `external_link_id` VARCHAR(36)  NOT NULL,
+    `user_id`          VARCHAR(36)  NOT NULL,
+    `external_user_id` VARCHAR(255) NOT NULL,
+    `created_at`       DATETIME(3)  NOT NULL,
+    PRIMARY KEY (`external_link_id`, `user_id`),
+    UNIQUE INDEX `uk_external_links_users_external_user_id` (`external_link_id`, `external_user_id`),
+    INDEX `idx_external_links_users_user_id` (`user_id`)
+) ENGINE = InnoDB
+  DEFAULT CHARSET = utf8mb4
+  DEFAULT COLLATE = utf8mb4_general_ci;
+
+ALTER TABLE `external_links_users`
+    ADD CONSTRAINT `fk_external_links_users_external_link_id`
+        FOREIGN KEY (`external_link_id`)
+            REFERENCES `external_links` (`id`) ON DELETE CASCADE;
+
+ALTER TABLE `external_links_users`
+    ADD CONSTRAINT `fk_external_links_users_user_id`
+        FOREIGN KEY (`user_id`)
+            REFERENCES `users` (`id`) ON DELETE CASCADE;
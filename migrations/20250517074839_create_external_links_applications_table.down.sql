@@ -0,0 +1,8 @@
+-- Add down migration script here
+ALTER TABLE `external_links_applications`
+    DROP FOREIGN KEY `fk_external_links_applications_application_id`;
+
+ALTER TABLE `external_links_applications`
+    DROP FOREIGN KEY `fk_external_links_applications_external_link_id`;
+
+DROP TABLE IF EXISTS `external_links_applications`;
@@ -0,0 +1,21 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS `external_links_applications`
+(
+    `external_link_id` VARCHAR(36) NOT NULL,
+    `application_id`   VARCHAR(36) NOT NULL,
+    `created_at`       DATETIME(3) NOT NULL,
+    PRIMARY KEY (`external_link_id`, `application_id`),
+    INDEX `idx_external_links_applications_application_id` (`application_id`)
+) ENGINE = InnoDB
+  DEFAULT CHARSET = utf8mb4
+  DEFAULT COLLATE = utf8mb4_general_ci;
+
+ALTER TABLE `external_links_applications`
+    ADD CONSTRAINT `fk_external_links_applications_external_link_id`
+        FOREIGN KEY (`external_link_id`)
+            REFERENCES `external_links` (`id`) ON DELETE CASCADE;
+
+ALTER TABLE `external_links_applications`
+    ADD CONSTRAINT `fk_external_links_applications_application_id`
+        FOREIGN KEY (`application_id`)
+            REFERENCES `applications` (`id`) ON DELETE CASCADE;
@@ -0,0 +1,6 @@
+-- Add down migration script here
+ALTER TABLE `external_links`
+    DROP COLUMN `oidc_scopes`,
+    DROP COLUMN `oidc_client_secret`,
+    DROP COLUMN `oidc_client_id`,
+    DROP COLUMN `oidc_issuer`;
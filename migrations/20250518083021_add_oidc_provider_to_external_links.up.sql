@@ -0,0 +1,7 @@
+-- Add up migration script here
+-- OpenID Connect provider used to log in users (NULL if the external link is not an identity provider)
+ALTER TABLE `external_links`
+    ADD COLUMN `oidc_issuer`        VARCHAR(255) DEFAULT NULL AFTER `name`,
+    ADD COLUMN `oidc_client_id`     VARCHAR(255) DEFAULT NULL AFTER `oidc_issuer`,
+    ADD COLUMN `oidc_client_secret` VARCHAR(255) DEFAULT NULL AFTER `oidc_client_id`,
+    ADD COLUMN `oidc_scopes`        VARCHAR(255) DEFAULT NULL AFTER `oidc_client_secret`;
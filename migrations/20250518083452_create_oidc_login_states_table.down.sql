@@ -0,0 +1,8 @@
+-- Add down migration script here
+ALTER TABLE `oidc_login_states`
+    DROP FOREIGN KEY `fk_oidc_login_states_application_id`;
+
+ALTER TABLE `oidc_login_states`
+    DROP FOREIGN KEY `fk_oidc_login_states_external_link_id`;
+
+DROP TABLE IF EXISTS `oidc_login_states`;
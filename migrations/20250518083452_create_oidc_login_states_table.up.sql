@@ -0,0 +1,23 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS `oidc_login_states`
+(
+    `state`            VARCHAR(36) NOT NULL,
+    `external_link_id` VARCHAR(36) NOT NULL,
+    `application_id`   VARCHAR(36) NOT NULL,
+    `nonce`            VARCHAR(64) NOT NULL,
+    `expired_at`       DATETIME(3) NOT NULL,
+    PRIMARY KEY (`state`),
+    INDEX `idx_oidc_login_states_expired_at` (`expired_at`)
+) ENGINE = InnoDB
+  DEFAULT CHARSET = utf8mb4
+  DEFAULT COLLATE = utf8mb4_general_ci;
+
+ALTER TABLE `oidc_login_states`
+    ADD CONSTRAINT `fk_oidc_login_states_external_link_id`
+        FOREIGN KEY (`external_link_id`)
+            REFERENCES `external_links` (`id`) ON DELETE CASCADE;
+
+ALTER TABLE `oidc_login_states`
+    ADD CONSTRAINT `fk_oidc_login_states_application_id`
+        FOREIGN KEY (`application_id`)
+            REFERENCES `applications` (`id`) ON DELETE CASCADE;
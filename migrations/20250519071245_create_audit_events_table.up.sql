@@ -0,0 +1,18 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS `audit_events`
+(
+    `id`         VARCHAR(36)  NOT NULL,
+    `actor_id`   VARCHAR(36) DEFAULT NULL,
+    `action`     VARCHAR(63)  NOT NULL,
+    `target_id`  VARCHAR(255) NOT NULL,
+    `ip`         VARCHAR(45) DEFAULT NULL,
+    `request_id` VARCHAR(63) DEFAULT NULL,
+    `created_at` DATETIME(3)  NOT NULL,
+    PRIMARY KEY (`id`),
+    KEY `idx_audit_events_actor_id` (`actor_id`),
+    KEY `idx_audit_events_action` (`action`),
+    KEY `idx_audit_events_target_id` (`target_id`),
+    KEY `idx_audit_events_created_at` (`created_at`)
+) ENGINE = InnoDB
+  DEFAULT CHARSET = utf8mb4
+  DEFAULT COLLATE = utf8mb4_general_ci;
@@ -0,0 +1,5 @@
+-- Add down migration script here
+ALTER TABLE `webhooks`
+    DROP FOREIGN KEY `fk_webhooks_application_id`;
+
+DROP TABLE IF EXISTS `webhooks`;
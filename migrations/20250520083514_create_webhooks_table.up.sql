@@ -0,0 +1,22 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS `webhooks`
+(
+    `id`             VARCHAR(36)  NOT NULL,
+    `application_id` VARCHAR(36)  NOT NULL,
+    `url`            VARCHAR(255) NOT NULL,
+    `secret`         VARCHAR(63)  NOT NULL,
+    `events`         VARCHAR(255) NOT NULL,
+    `created_at`     DATETIME(3)  NOT NULL,
+    `updated_at`     DATETIME(3)  NOT NULL,
+    `deleted_at`     DATETIME(3)  DEFAULT NULL,
+    PRIMARY KEY (`id`),
+    KEY `idx_webhooks_application_id` (`application_id`),
+    KEY `idx_webhooks_deleted_at` (`deleted_at`)
+) ENGINE = InnoDB
+  DEFAULT CHARSET = utf8mb4
+  DEFAULT COLLATE = utf8mb4_general_ci;
+
+ALTER TABLE `webhooks`
+    ADD CONSTRAINT `fk_webhooks_application_id`
+        FOREIGN KEY (`application_id`)
+            REFERENCES `applications` (`id`);
@@ -0,0 +1,5 @@
+-- Add down migration script here
+ALTER TABLE `webhook_deliveries`
+    DROP FOREIGN KEY `fk_webhook_deliveries_webhook_id`;
+
+DROP TABLE IF EXISTS `webhook_deliveries`;
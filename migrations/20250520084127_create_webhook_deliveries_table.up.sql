@@ -0,0 +1,26 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS `webhook_deliveries`
+(
+    `id`              VARCHAR(36)  NOT NULL,
+    `webhook_id`      VARCHAR(36)  NOT NULL,
+    `event_id`        VARCHAR(36)  NOT NULL,
+    `event`           VARCHAR(63)  NOT NULL,
+    `payload`         TEXT         NOT NULL,
+    `status`          VARCHAR(15)  NOT NULL,
+    `attempts`        INT UNSIGNED NOT NULL DEFAULT 0,
+    `next_attempt_at` DATETIME(3)  NOT NULL,
+    `last_error`      VARCHAR(255) DEFAULT NULL,
+    `created_at`      DATETIME(3)  NOT NULL,
+    `delivered_at`    DATETIME(3)  DEFAULT NULL,
+    PRIMARY KEY (`id`),
+    KEY `idx_webhook_deliveries_webhook_id` (`webhook_id`),
+    KEY `idx_webhook_deliveries_status_next_attempt_at` (`status`, `next_attempt_at`)
+) ENGINE = InnoDB
+  DEFAULT CHARSET = utf8mb4
+  DEFAULT COLLATE = utf8mb4_general_ci;
+
+ALTER TABLE `webhook_deliveries`
+    ADD CONSTRAINT `fk_webhook_deliveries_webhook_id`
+        FOREIGN KEY (`webhook_id`)
+            REFERENCES `webhooks` (`id`)
+            ON DELETE CASCADE;
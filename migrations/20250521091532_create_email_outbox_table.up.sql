@@ -0,0 +1,20 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS `email_outbox`
+(
+    `id`              VARCHAR(36)  NOT NULL,
+    `from_address`    VARCHAR(255) NOT NULL,
+    `to_addresses`    TEXT         NOT NULL,
+    `subject`         VARCHAR(255) NOT NULL,
+    `text_body`       TEXT         DEFAULT NULL,
+    `html_body`       TEXT         DEFAULT NULL,
+    `status`          VARCHAR(15)  NOT NULL,
+    `attempts`        INT UNSIGNED NOT NULL DEFAULT 0,
+    `next_attempt_at` DATETIME(3)  NOT NULL,
+    `last_error`      VARCHAR(255) DEFAULT NULL,
+    `created_at`      DATETIME(3)  NOT NULL,
+    `sent_at`         DATETIME(3)  DEFAULT NULL,
+    PRIMARY KEY (`id`),
+    KEY `idx_email_outbox_status_next_attempt_at` (`status`, `next_attempt_at`)
+) ENGINE = InnoDB
+  DEFAULT CHARSET = utf8mb4
+  DEFAULT COLLATE = utf8mb4_general_ci;
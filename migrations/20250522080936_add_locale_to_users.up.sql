@@ -0,0 +1,3 @@
+-- Add up migration script here
+ALTER TABLE `users`
+    ADD COLUMN `locale` VARCHAR(15) DEFAULT NULL AFTER `firstname`;
@@ -0,0 +1,3 @@
+-- Add down migration script here
+ALTER TABLE `users`
+    DROP INDEX `ft_users_email_firstname_lastname`;
@@ -0,0 +1,3 @@
+-- Add up migration script here
+ALTER TABLE `users`
+    ADD FULLTEXT INDEX `ft_users_email_firstname_lastname` (`email`, `firstname`, `lastname`);
@@ -0,0 +1,6 @@
+-- Add down migration script here
+ALTER TABLE `refresh_tokens`
+    DROP FOREIGN KEY `fk_refresh_tokens_application_id`;
+
+ALTER TABLE `refresh_tokens`
+    DROP COLUMN `application_id`;
@@ -0,0 +1,8 @@
+-- Add down migration script here
+SET SESSION innodb_ft_enable_stopword = ON;
+
+ALTER TABLE `users`
+    DROP INDEX `ft_users_email_firstname_lastname`;
+
+ALTER TABLE `users`
+    ADD FULLTEXT INDEX `ft_users_email_firstname_lastname` (`email`, `firstname`, `lastname`);
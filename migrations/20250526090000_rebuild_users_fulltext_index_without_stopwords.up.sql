@@ -0,0 +1,15 @@
+-- Add up migration script here
+-- Every searched word is required, so the index must contain all the words of the users:
+-- the default InnoDB stopword list contains words such as "com", "de" or "la" which are
+-- frequent in emails and names. The stopword setting is read when the index is created.
+--
+-- The words shorter than `innodb_ft_min_token_size` (3 by default) are not indexed either.
+-- This is a read-only server option: set `innodb_ft_min_token_size = 1` in the server
+-- configuration to search short names, then rebuild the index (`OPTIMIZE TABLE users`).
+SET SESSION innodb_ft_enable_stopword = OFF;
+
+ALTER TABLE `users`
+    DROP INDEX `ft_users_email_firstname_lastname`;
+
+ALTER TABLE `users`
+    ADD FULLTEXT INDEX `ft_users_email_firstname_lastname` (`email`, `firstname`, `lastname`);
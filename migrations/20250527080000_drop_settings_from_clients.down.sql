@@ -0,0 +1,6 @@
+-- Add down migration script here
+ALTER TABLE `clients`
+    ADD COLUMN `redirect_uris`          TEXT         NOT NULL AFTER `name`,
+    ADD COLUMN `grant_types`            VARCHAR(255) NOT NULL AFTER `redirect_uris`,
+    ADD COLUMN `access_token_lifetime`  INT UNSIGNED DEFAULT NULL AFTER `grant_types`,
+    ADD COLUMN `refresh_token_lifetime` INT UNSIGNED DEFAULT NULL AFTER `access_token_lifetime`;
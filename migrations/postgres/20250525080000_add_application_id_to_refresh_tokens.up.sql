@@ -0,0 +1,10 @@
+-- Add up migration script here
+-- A refresh token can only be used by the application it was issued for.
+-- The existing refresh tokens are not bound to an application: they are revoked.
+DELETE FROM refresh_tokens;
+
+ALTER TABLE refresh_tokens
+    ADD COLUMN application_id VARCHAR(36) NOT NULL,
+    ADD CONSTRAINT fk_refresh_tokens_application_id
+        FOREIGN KEY (application_id) REFERENCES applications (id) ON DELETE CASCADE;
+CREATE INDEX idx_refresh_tokens_application_id ON refresh_tokens (application_id);
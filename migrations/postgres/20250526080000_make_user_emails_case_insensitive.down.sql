@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP INDEX uk_users_email;
+ALTER TABLE users
+    ADD CONSTRAINT uk_users_email UNIQUE (email);
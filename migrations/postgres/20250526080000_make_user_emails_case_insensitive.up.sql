@@ -0,0 +1,5 @@
+-- Add up migration script here
+-- Emails are unique whatever their case, as with the MySQL and SQLite collations.
+ALTER TABLE users
+    DROP CONSTRAINT uk_users_email;
+CREATE UNIQUE INDEX uk_users_email ON users (LOWER(email));
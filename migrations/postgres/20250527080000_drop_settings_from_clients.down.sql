@@ -0,0 +1,12 @@
+-- Add down migration script here
+ALTER TABLE clients
+    ADD COLUMN redirect_uris          TEXT         NOT NULL DEFAULT '',
+    ADD COLUMN grant_types            VARCHAR(255) NOT NULL DEFAULT '',
+    ADD COLUMN access_token_lifetime  BIGINT       DEFAULT NULL,
+    ADD COLUMN refresh_token_lifetime BIGINT       DEFAULT NULL,
+    ADD CONSTRAINT ck_clients_access_token_lifetime CHECK (access_token_lifetime BETWEEN 0 AND 4294967295),
+    ADD CONSTRAINT ck_clients_refresh_token_lifetime CHECK (refresh_token_lifetime BETWEEN 0 AND 4294967295);
+
+ALTER TABLE clients
+    ALTER COLUMN redirect_uris DROP DEFAULT,
+    ALTER COLUMN grant_types DROP DEFAULT;
@@ -0,0 +1,7 @@
+-- Add up migration script here
+-- The token settings are those of the application: the client settings were never applied.
+ALTER TABLE clients
+    DROP COLUMN redirect_uris,
+    DROP COLUMN grant_types,
+    DROP COLUMN access_token_lifetime,
+    DROP COLUMN refresh_token_lifetime;
@@ -0,0 +1,20 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS email_outbox;
+DROP TABLE IF EXISTS webhook_deliveries;
+DROP TABLE IF EXISTS webhooks;
+DROP TABLE IF EXISTS audit_events;
+DROP TABLE IF EXISTS oidc_login_states;
+DROP TABLE IF EXISTS external_links_applications;
+DROP TABLE IF EXISTS external_links_users;
+DROP TABLE IF EXISTS external_links;
+DROP TABLE IF EXISTS client_secrets;
+DROP TABLE IF EXISTS clients;
+DROP TABLE IF EXISTS invitations;
+DROP TABLE IF EXISTS users_scopes;
+DROP TABLE IF EXISTS scopes;
+DROP TABLE IF EXISTS applications;
+DROP TABLE IF EXISTS magic_links;
+DROP TABLE IF EXISTS password_history;
+DROP TABLE IF EXISTS password_resets;
+DROP TABLE IF EXISTS refresh_tokens;
+DROP TABLE IF EXISTS users;
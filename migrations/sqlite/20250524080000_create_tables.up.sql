@@ -0,0 +1,276 @@
+-- Add up migration script here
+-- Schema of the MySQL migrations up to `20250523083012_add_fulltext_index_to_users`
+-- Dates are stored as ISO 8601 text in UTC
+
+CREATE TABLE IF NOT EXISTS users
+(
+    id                  VARCHAR(36)  NOT NULL,
+    email               VARCHAR(127) NOT NULL COLLATE NOCASE,
+    password            VARCHAR(191) DEFAULT NULL,
+    password_changed_at TEXT         NOT NULL,
+    lastname            VARCHAR(63)  NOT NULL,
+    firstname           VARCHAR(63)  NOT NULL,
+    locale              VARCHAR(15)  DEFAULT NULL,
+    created_at          TEXT         NOT NULL,
+    updated_at          TEXT         NOT NULL,
+    deleted_at          TEXT         DEFAULT NULL,
+    PRIMARY KEY (id),
+    CONSTRAINT uk_users_email UNIQUE (email)
+);
+CREATE INDEX idx_users_password ON users (password);
+CREATE INDEX idx_users_deleted_at ON users (deleted_at);
+
+CREATE TABLE IF NOT EXISTS refresh_tokens
+(
+    refresh_token VARCHAR(36)  NOT NULL,
+    user_id       VARCHAR(36)  NOT NULL,
+    access_token  TEXT         NOT NULL,
+    expired_at    TEXT         NOT NULL,
+    PRIMARY KEY (refresh_token),
+    CONSTRAINT fk_refresh_tokens_user_id FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+CREATE INDEX idx_refresh_tokens_user_id ON refresh_tokens (user_id);
+CREATE INDEX idx_refresh_tokens_access_token ON refresh_tokens (access_token);
+CREATE INDEX idx_refresh_tokens_expired_at ON refresh_tokens (expired_at);
+
+CREATE TABLE IF NOT EXISTS password_resets
+(
+    user_id    VARCHAR(36)  NOT NULL,
+    token      VARCHAR(36)  NOT NULL,
+    expired_at TEXT         NOT NULL,
+    PRIMARY KEY (user_id),
+    CONSTRAINT fk_password_resets_user_id FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+CREATE INDEX idx_password_resets_expired_at ON password_resets (expired_at);
+
+CREATE TABLE IF NOT EXISTS password_history
+(
+    id         VARCHAR(36)  NOT NULL,
+    user_id    VARCHAR(36)  NOT NULL,
+    password   VARCHAR(191) NOT NULL,
+    created_at TEXT         NOT NULL,
+    PRIMARY KEY (id),
+    CONSTRAINT fk_password_history_user_id FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+CREATE INDEX idx_password_history_user_id_created_at ON password_history (user_id, created_at);
+
+CREATE TABLE IF NOT EXISTS magic_links
+(
+    user_id    VARCHAR(36)  NOT NULL,
+    token      VARCHAR(36)  NOT NULL,
+    expired_at TEXT         NOT NULL,
+    PRIMARY KEY (user_id),
+    CONSTRAINT uk_magic_links_token UNIQUE (token),
+    CONSTRAINT fk_magic_links_user_id FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+CREATE INDEX idx_magic_links_expired_at ON magic_links (expired_at);
+
+CREATE TABLE IF NOT EXISTS applications
+(
+    id                     VARCHAR(36)  NOT NULL,
+    name                   VARCHAR(63)  NOT NULL,
+    access_token_lifetime  INTEGER      DEFAULT NULL,
+    refresh_token_lifetime INTEGER      DEFAULT NULL,
+    audience               VARCHAR(255) DEFAULT NULL,
+    created_at             TEXT         NOT NULL,
+    updated_at             TEXT         NOT NULL,
+    deleted_at             TEXT         DEFAULT NULL,
+    PRIMARY KEY (id),
+    CONSTRAINT ck_applications_access_token_lifetime CHECK (access_token_lifetime BETWEEN 0 AND 4294967295),
+    CONSTRAINT ck_applications_refresh_token_lifetime CHECK (refresh_token_lifetime BETWEEN 0 AND 4294967295)
+);
+CREATE INDEX idx_applications_deleted_at ON applications (deleted_at);
+
+CREATE TABLE IF NOT EXISTS scopes
+(
+    id             VARCHAR(36)  NOT NULL,
+    application_id VARCHAR(36)  NOT NULL,
+    created_at     TEXT         NOT NULL,
+    updated_at     TEXT         NOT NULL,
+    deleted_at     TEXT         DEFAULT NULL,
+    PRIMARY KEY (id),
+    CONSTRAINT fk_scopes_application_id FOREIGN KEY (application_id) REFERENCES applications (id) ON DELETE CASCADE
+);
+CREATE INDEX idx_scopes_deleted_at ON scopes (deleted_at);
+
+CREATE TABLE IF NOT EXISTS users_scopes
+(
+    user_id    VARCHAR(36)  NOT NULL,
+    scope_id   VARCHAR(36)  NOT NULL,
+    created_at TEXT         NOT NULL,
+    PRIMARY KEY (user_id, scope_id),
+    CONSTRAINT fk_users_scopes_user_id FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE,
+    CONSTRAINT fk_users_scopes_scope_id FOREIGN KEY (scope_id) REFERENCES scopes (id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS invitations
+(
+    id         VARCHAR(36)  NOT NULL,
+    user_id    VARCHAR(36)  NOT NULL,
+    token      VARCHAR(36)  NOT NULL,
+    expired_at TEXT         NOT NULL,
+    created_at TEXT         NOT NULL,
+    updated_at TEXT         NOT NULL,
+    PRIMARY KEY (id),
+    CONSTRAINT uk_invitations_user_id UNIQUE (user_id),
+    CONSTRAINT uk_invitations_token UNIQUE (token),
+    CONSTRAINT fk_invitations_user_id FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+CREATE INDEX idx_invitations_expired_at ON invitations (expired_at);
+
+CREATE TABLE IF NOT EXISTS clients
+(
+    id                     VARCHAR(36)  NOT NULL,
+    application_id         VARCHAR(36)  NOT NULL,
+    name                   VARCHAR(63)  NOT NULL,
+    redirect_uris          TEXT         NOT NULL,
+    grant_types            VARCHAR(255) NOT NULL,
+    access_token_lifetime  INTEGER      DEFAULT NULL,
+    refresh_token_lifetime INTEGER      DEFAULT NULL,
+    created_at             TEXT         NOT NULL,
+    updated_at             TEXT         NOT NULL,
+    deleted_at             TEXT         DEFAULT NULL,
+    PRIMARY KEY (id),
+    CONSTRAINT fk_clients_application_id FOREIGN KEY (application_id) REFERENCES applications (id),
+    CONSTRAINT ck_clients_access_token_lifetime CHECK (access_token_lifetime BETWEEN 0 AND 4294967295),
+    CONSTRAINT ck_clients_refresh_token_lifetime CHECK (refresh_token_lifetime BETWEEN 0 AND 4294967295)
+);
+CREATE INDEX idx_clients_application_id ON clients (application_id);
+CREATE INDEX idx_clients_deleted_at ON clients (deleted_at);
+
+CREATE TABLE IF NOT EXISTS client_secrets
+(
+    id         VARCHAR(36)  NOT NULL,
+    client_id  VARCHAR(36)  NOT NULL,
+    secret     VARCHAR(191) NOT NULL,
+    created_at TEXT         NOT NULL,
+    PRIMARY KEY (id),
+    CONSTRAINT fk_client_secrets_client_id FOREIGN KEY (client_id) REFERENCES clients (id) ON DELETE CASCADE
+);
+CREATE INDEX idx_client_secrets_client_id ON client_secrets (client_id);
+
+CREATE TABLE IF NOT EXISTS external_links
+(
+    id                 VARCHAR(36)  NOT NULL,
+    name               VARCHAR(63)  NOT NULL,
+    oidc_issuer        VARCHAR(255) DEFAULT NULL,
+    oidc_client_id     VARCHAR(255) DEFAULT NULL,
+    oidc_client_secret VARCHAR(255) DEFAULT NULL,
+    oidc_scopes        VARCHAR(255) DEFAULT NULL,
+    created_at         TEXT         NOT NULL,
+    updated_at         TEXT         NOT NULL,
+    deleted_at         TEXT         DEFAULT NULL,
+    PRIMARY KEY (id)
+);
+CREATE INDEX idx_external_links_deleted_at ON external_links (deleted_at);
+
+CREATE TABLE IF NOT EXISTS external_links_users
+(
+    external_link_id VARCHAR(36)  NOT NULL,
+    user_id          VARCHAR(36)  NOT NULL,
+    external_user_id VARCHAR(255) NOT NULL,
+    created_at       TEXT         NOT NULL,
+    PRIMARY KEY (external_link_id, user_id),
+    CONSTRAINT uk_external_links_users_external_user_id UNIQUE (external_link_id, external_user_id),
+    CONSTRAINT fk_external_links_users_external_link_id
+        FOREIGN KEY (external_link_id) REFERENCES external_links (id) ON DELETE CASCADE,
+    CONSTRAINT fk_external_links_users_user_id FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+CREATE INDEX idx_external_links_users_user_id ON external_links_users (user_id);
+
+CREATE TABLE IF NOT EXISTS external_links_applications
+(
+    external_link_id VARCHAR(36)  NOT NULL,
+    application_id   VARCHAR(36)  NOT NULL,
+    created_at       TEXT         NOT NULL,
+    PRIMARY KEY (external_link_id, application_id),
+    CONSTRAINT fk_external_links_applications_external_link_id
+        FOREIGN KEY (external_link_id) REFERENCES external_links (id) ON DELETE CASCADE,
+    CONSTRAINT fk_external_links_applications_application_id
+        FOREIGN KEY (application_id) REFERENCES applications (id) ON DELETE CASCADE
+);
+CREATE INDEX idx_external_links_applications_application_id ON external_links_applications (application_id);
+
+CREATE TABLE IF NOT EXISTS oidc_login_states
+(
+    state            VARCHAR(36)  NOT NULL,
+    external_link_id VARCHAR(36)  NOT NULL,
+    application_id   VARCHAR(36)  NOT NULL,
+    nonce            VARCHAR(64)  NOT NULL,
+    expired_at       TEXT         NOT NULL,
+    PRIMARY KEY (state),
+    CONSTRAINT fk_oidc_login_states_external_link_id
+        FOREIGN KEY (external_link_id) REFERENCES external_links (id) ON DELETE CASCADE,
+    CONSTRAINT fk_oidc_login_states_application_id
+        FOREIGN KEY (application_id) REFERENCES applications (id) ON DELETE CASCADE
+);
+CREATE INDEX idx_oidc_login_states_expired_at ON oidc_login_states (expired_at);
+
+CREATE TABLE IF NOT EXISTS audit_events
+(
+    id         VARCHAR(36)  NOT NULL,
+    actor_id   VARCHAR(36)  DEFAULT NULL,
+    action     VARCHAR(63)  NOT NULL,
+    target_id  VARCHAR(255) NOT NULL,
+    ip         VARCHAR(45)  DEFAULT NULL,
+    request_id VARCHAR(63)  DEFAULT NULL,
+    created_at TEXT         NOT NULL,
+    PRIMARY KEY (id)
+);
+CREATE INDEX idx_audit_events_actor_id ON audit_events (actor_id);
+CREATE INDEX idx_audit_events_action ON audit_events (action);
+CREATE INDEX idx_audit_events_target_id ON audit_events (target_id);
+CREATE INDEX idx_audit_events_created_at ON audit_events (created_at);
+
+CREATE TABLE IF NOT EXISTS webhooks
+(
+    id             VARCHAR(36)  NOT NULL,
+    application_id VARCHAR(36)  NOT NULL,
+    url            VARCHAR(255) NOT NULL,
+    secret         VARCHAR(63)  NOT NULL,
+    events         VARCHAR(255) NOT NULL,
+    created_at     TEXT         NOT NULL,
+    updated_at     TEXT         NOT NULL,
+    deleted_at     TEXT         DEFAULT NULL,
+    PRIMARY KEY (id),
+    CONSTRAINT fk_webhooks_application_id FOREIGN KEY (application_id) REFERENCES applications (id)
+);
+CREATE INDEX idx_webhooks_application_id ON webhooks (application_id);
+CREATE INDEX idx_webhooks_deleted_at ON webhooks (deleted_at);
+
+CREATE TABLE IF NOT EXISTS webhook_deliveries
+(
+    id              VARCHAR(36)  NOT NULL,
+    webhook_id      VARCHAR(36)  NOT NULL,
+    event_id        VARCHAR(36)  NOT NULL,
+    event           VARCHAR(63)  NOT NULL,
+    payload         TEXT         NOT NULL,
+    status          VARCHAR(15)  NOT NULL,
+    attempts        INTEGER      NOT NULL DEFAULT 0,
+    next_attempt_at TEXT         NOT NULL,
+    last_error      VARCHAR(255) DEFAULT NULL,
+    created_at      TEXT         NOT NULL,
+    delivered_at    TEXT         DEFAULT NULL,
+    PRIMARY KEY (id),
+    CONSTRAINT fk_webhook_deliveries_webhook_id FOREIGN KEY (webhook_id) REFERENCES webhooks (id) ON DELETE CASCADE
+);
+CREATE INDEX idx_webhook_deliveries_webhook_id ON webhook_deliveries (webhook_id);
+CREATE INDEX idx_webhook_deliveries_status_next_attempt_at ON webhook_deliveries (status, next_attempt_at);
+
+CREATE TABLE IF NOT EXISTS email_outbox
+(
+    id              VARCHAR(36)  NOT NULL,
+    from_address    VARCHAR(255) NOT NULL,
+    to_addresses    TEXT         NOT NULL,
+    subject         VARCHAR(255) NOT NULL,
+    text_body       TEXT         DEFAULT NULL,
+    html_body       TEXT         DEFAULT NULL,
+    status          VARCHAR(15)  NOT NULL,
+    attempts        INTEGER      NOT NULL DEFAULT 0,
+    next_attempt_at TEXT         NOT NULL,
+    last_error      VARCHAR(255) DEFAULT NULL,
+    created_at      TEXT         NOT NULL,
+    sent_at         TEXT         DEFAULT NULL,
+    PRIMARY KEY (id)
+);
+CREATE INDEX idx_email_outbox_status_next_attempt_at ON email_outbox (status, next_attempt_at);
@@ -0,0 +1,15 @@
+-- Add down migration script here
+DROP TABLE refresh_tokens;
+
+CREATE TABLE IF NOT EXISTS refresh_tokens
+(
+    refresh_token VARCHAR(36)  NOT NULL,
+    user_id       VARCHAR(36)  NOT NULL,
+    access_token  TEXT         NOT NULL,
+    expired_at    TEXT         NOT NULL,
+    PRIMARY KEY (refresh_token),
+    CONSTRAINT fk_refresh_tokens_user_id FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+CREATE INDEX idx_refresh_tokens_user_id ON refresh_tokens (user_id);
+CREATE INDEX idx_refresh_tokens_access_token ON refresh_tokens (access_token);
+CREATE INDEX idx_refresh_tokens_expired_at ON refresh_tokens (expired_at);
@@ -0,0 +1,21 @@
+-- Add up migration script here
+-- A refresh token can only be used by the application it was issued for.
+-- The existing refresh tokens are not bound to an application: they are revoked.
+-- SQLite cannot add a NOT NULL column with a foreign key: the table is created again.
+DROP TABLE refresh_tokens;
+
+CREATE TABLE IF NOT EXISTS refresh_tokens
+(
+    refresh_token  VARCHAR(36)  NOT NULL,
+    user_id        VARCHAR(36)  NOT NULL,
+    application_id VARCHAR(36)  NOT NULL,
+    access_token   TEXT         NOT NULL,
+    expired_at     TEXT         NOT NULL,
+    PRIMARY KEY (refresh_token),
+    CONSTRAINT fk_refresh_tokens_user_id FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE,
+    CONSTRAINT fk_refresh_tokens_application_id FOREIGN KEY (application_id) REFERENCES applications (id) ON DELETE CASCADE
+);
+CREATE INDEX idx_refresh_tokens_user_id ON refresh_tokens (user_id);
+CREATE INDEX idx_refresh_tokens_application_id ON refresh_tokens (application_id);
+CREATE INDEX idx_refresh_tokens_access_token ON refresh_tokens (access_token);
+CREATE INDEX idx_refresh_tokens_expired_at ON refresh_tokens (expired_at);
@@ -0,0 +1,7 @@
+-- Add down migration script here
+ALTER TABLE clients ADD COLUMN redirect_uris TEXT NOT NULL DEFAULT '';
+ALTER TABLE clients ADD COLUMN grant_types VARCHAR(255) NOT NULL DEFAULT '';
+ALTER TABLE clients ADD COLUMN access_token_lifetime INTEGER DEFAULT NULL
+    CONSTRAINT ck_clients_access_token_lifetime CHECK (access_token_lifetime BETWEEN 0 AND 4294967295);
+ALTER TABLE clients ADD COLUMN refresh_token_lifetime INTEGER DEFAULT NULL
+    CONSTRAINT ck_clients_refresh_token_lifetime CHECK (refresh_token_lifetime BETWEEN 0 AND 4294967295);
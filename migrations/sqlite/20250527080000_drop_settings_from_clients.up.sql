@@ -0,0 +1,33 @@
+-- Add up migration script here
+-- The token settings are those of the application: the client settings were never applied.
+-- SQLite cannot drop a column used by a constraint: the table is created again,
+-- and the secrets are kept aside since dropping the clients deletes them.
+CREATE TEMPORARY TABLE client_secrets_backup AS
+SELECT id, client_id, secret, created_at
+FROM client_secrets;
+
+CREATE TABLE clients_new
+(
+    id             VARCHAR(36) NOT NULL,
+    application_id VARCHAR(36) NOT NULL,
+    name           VARCHAR(63) NOT NULL,
+    created_at     TEXT        NOT NULL,
+    updated_at     TEXT        NOT NULL,
+    deleted_at     TEXT        DEFAULT NULL,
+    PRIMARY KEY (id),
+    CONSTRAINT fk_clients_application_id FOREIGN KEY (application_id) REFERENCES applications (id)
+);
+INSERT INTO clients_new (id, application_id, name, created_at, updated_at, deleted_at)
+SELECT id, application_id, name, created_at, updated_at, deleted_at
+FROM clients;
+
+DROP TABLE clients;
+ALTER TABLE clients_new RENAME TO clients;
+CREATE INDEX idx_clients_application_id ON clients (application_id);
+CREATE INDEX idx_clients_deleted_at ON clients (deleted_at);
+
+DELETE FROM client_secrets;
+INSERT INTO client_secrets (id, client_id, secret, created_at)
+SELECT id, client_id, secret, created_at
+FROM client_secrets_backup;
+DROP TABLE client_secrets_backup;
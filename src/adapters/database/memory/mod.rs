@@ -0,0 +1,544 @@
+//! In-memory adapter
+//!
+//! The tables are kept in memory behind a lock: there is no database server to start,
+//! and the data is lost when the last clone of the [`Db`] is dropped.
+//! It is meant for the tests, including the test suites of the applications
+//! which embed the use cases (Ex.: `memory::Db::default().user_repository()`).
+//!
+//! Each repository method locks the tables once, so that it is atomic like a transaction.
+
+use crate::adapters::database::query::{valid_filters, valid_sorts};
+use crate::adapters::database::{DatabaseError, GenericDb};
+use crate::config::Config;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::pagination::{PAGINATION_MAX_LIMIT, Pagination, PaginationCursor};
+use crate::domain::value_objects::query_filter::{QueryFilterFieldValue, QueryFilterOperator, QueryFilters};
+use crate::domain::value_objects::query_sort::{QuerySortDirection, QuerySorts};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
+use std::cmp::Ordering;
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use tables::Tables;
+
+pub mod repositories;
+mod tables;
+
+/// In-memory database adapter
+///
+/// The clones share the same tables.
+#[derive(Debug, Clone, Default)]
+pub struct Db {
+    tables: Arc<RwLock<Tables>>,
+}
+
+impl Db {
+    /// Lock the tables for reading
+    ///
+    /// A panic while the lock was held does not make the tables unusable.
+    fn read(&self) -> RwLockReadGuard<'_, Tables> {
+        self.tables.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Lock the tables for writing
+    fn write(&self) -> RwLockWriteGuard<'_, Tables> {
+        self.tables.write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[async_trait]
+impl GenericDb for Db {
+    type Db = Db;
+
+    /// Create an empty database (the configuration is not used)
+    async fn new(_config: &Config) -> Result<Self::Db, DatabaseError> {
+        Ok(Self::default())
+    }
+}
+
+/// Value of a row field, used by the filters, the sorts and the cursors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MemoryValue {
+    Text(String),
+    Datetime(DateTime<Utc>),
+}
+
+impl MemoryValue {
+    /// Text of the value (the datetimes have a fixed width, so they are sorted like the text)
+    pub fn to_key(&self) -> String {
+        match self {
+            Self::Text(value) => value.clone(),
+            Self::Datetime(value) => value.to_rfc3339_opts(SecondsFormat::Nanos, true),
+        }
+    }
+}
+
+impl From<&str> for MemoryValue {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<&UtcDateTime> for MemoryValue {
+    fn from(value: &UtcDateTime) -> Self {
+        Self::Datetime(value.value())
+    }
+}
+
+/// Row of a table which can be filtered, sorted and paginated
+pub trait MemoryRow {
+    /// ID of the row, used to sort the rows with the same sort key and in the cursors
+    fn id(&self) -> String;
+
+    /// Value of a field (`None` for a `NULL` value or an unknown field)
+    fn field(&self, name: &str) -> Option<MemoryValue>;
+}
+
+impl<R: MemoryRow + ?Sized> MemoryRow for &R {
+    fn id(&self) -> String {
+        (**self).id()
+    }
+
+    fn field(&self, name: &str) -> Option<MemoryValue> {
+        (**self).field(name)
+    }
+}
+
+/// Pagination in offset mode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryPagination {
+    pub page: u32,
+    pub limit: u32,
+    pub offset: u32,
+}
+
+impl From<Pagination> for MemoryPagination {
+    fn from(pagination: Pagination) -> Self {
+        // Page & limit
+        let page = match pagination.page() >= 1 {
+            true => pagination.page(),
+            false => 1,
+        };
+
+        let limit = match (1..=PAGINATION_MAX_LIMIT).contains(&pagination.limit()) {
+            true => pagination.limit(),
+            false => PAGINATION_MAX_LIMIT,
+        };
+
+        let offset = (page - 1) * limit;
+
+        Self { page, limit, offset }
+    }
+}
+
+impl MemoryPagination {
+    /// Rows of the page (the rows must be sorted)
+    pub fn apply<R>(&self, rows: Vec<R>) -> Vec<R> {
+        rows.into_iter()
+            .skip(self.offset as usize)
+            .take(self.limit as usize)
+            .collect()
+    }
+}
+
+/// Sort the rows by the given fields, then by ID
+///
+/// `NULL` values are sorted like MySQL does: first in ascending order and last in descending order.
+fn sort_rows<R: MemoryRow>(rows: &mut [R], sorts: &[(String, QuerySortDirection)]) {
+    rows.sort_by(|a, b| {
+        sorts
+            .iter()
+            .map(|(field, direction)| {
+                let ordering = a
+                    .field(field)
+                    .map(|value| value.to_key())
+                    .cmp(&b.field(field).map(|value| value.to_key()));
+
+                match direction {
+                    QuerySortDirection::Asc => ordering,
+                    QuerySortDirection::Desc => ordering.reverse(),
+                }
+            })
+            .fold(Ordering::Equal, Ordering::then)
+            .then_with(|| a.id().cmp(&b.id()))
+    });
+}
+
+/// Query sorts for in-memory tables
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryQuerySorts(pub QuerySorts);
+
+impl MemoryQuerySorts {
+    /// Sorts of the valid fields
+    fn valid<'a>(&'a self, valid_fields: &'a [&str]) -> impl Iterator<Item = (&'a str, &'a QuerySortDirection)> {
+        valid_sorts(&self.0, valid_fields)
+    }
+
+    /// Check if at least one sort is on a valid field
+    pub fn has_valid_fields(&self, valid_fields: &[&str]) -> bool {
+        self.valid(valid_fields).next().is_some()
+    }
+
+    /// Sort the rows by the valid fields, then by ID
+    pub fn sort<R: MemoryRow>(&self, rows: &mut [R], valid_fields: &[&str]) {
+        let sorts = self
+            .valid(valid_fields)
+            .map(|(field, direction)| (field.to_string(), direction.clone()))
+            .collect::<Vec<_>>();
+
+        sort_rows(rows, &sorts);
+    }
+}
+
+/// Type of a filter field, which gives how the field is compared to the filter values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryFilterType {
+    /// Compared as text
+    Text,
+    /// Compared as datetimes (Ex.: `2024-01-01` is equal to `2024-01-01 00:00:00`)
+    Datetime,
+}
+
+impl MemoryFilterType {
+    /// Parse a datetime filter value (RFC 3339 or ISO 8601 without timezone, in UTC)
+    fn parse_datetime(value: &str) -> Option<DateTime<Utc>> {
+        if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+            return Some(datetime.with_timezone(&Utc));
+        }
+
+        [
+            "%Y-%m-%d %H:%M:%S%.f",
+            "%Y-%m-%dT%H:%M:%S%.f",
+            "%Y-%m-%d %H:%M",
+            "%Y-%m-%dT%H:%M",
+        ]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+        .map(|datetime| datetime.and_utc())
+    }
+
+    /// Compare a field value to a filter value (`None` if the filter value is not valid for this type)
+    fn compare(&self, field: &MemoryValue, value: &str) -> Option<Ordering> {
+        match (self, field) {
+            (Self::Datetime, MemoryValue::Datetime(field)) => {
+                Self::parse_datetime(value).map(|value| field.cmp(&value))
+            }
+            _ => Some(field.to_key().as_str().cmp(value)),
+        }
+    }
+}
+
+/// Field available for filtering: the field name and its type (Ex.: `("created_at", MemoryFilterType::Datetime)`)
+pub type MemoryFilterField<'a> = (&'a str, MemoryFilterType);
+
+/// Query filters for in-memory tables
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryQueryFilters(pub QueryFilters);
+
+impl MemoryQueryFilters {
+    /// Check if a row matches the conditions of the valid fields
+    ///
+    /// Like in SQL, a `NULL` value matches no condition.
+    pub fn matches<R: MemoryRow>(&self, row: &R, valid_fields: &[MemoryFilterField<'_>]) -> bool {
+        valid_filters(&self.0, valid_fields, |(name, _)| name).all(
+            |((name, filter_type), QueryFilterFieldValue { operator, values })| {
+                let Some(field) = row.field(name) else {
+                    return false;
+                };
+                let compare = |value: &str| filter_type.compare(&field, value);
+                let first = values.first().cloned().unwrap_or_default();
+
+                match operator {
+                    QueryFilterOperator::Eq => compare(&first) == Some(Ordering::Equal),
+                    QueryFilterOperator::Ne => compare(&first).is_some_and(Ordering::is_ne),
+                    QueryFilterOperator::Gt => compare(&first) == Some(Ordering::Greater),
+                    QueryFilterOperator::Gte => compare(&first).is_some_and(Ordering::is_ge),
+                    QueryFilterOperator::Lt => compare(&first) == Some(Ordering::Less),
+                    QueryFilterOperator::Lte => compare(&first).is_some_and(Ordering::is_le),
+                    QueryFilterOperator::In => values.iter().any(|value| compare(value) == Some(Ordering::Equal)),
+                    QueryFilterOperator::Nin => values.iter().all(|value| compare(value).is_some_and(Ordering::is_ne)),
+                }
+            },
+        )
+    }
+}
+
+/// Pagination of list queries, in offset mode or in cursor mode (keyset)
+///
+/// It works like the SQL adapters: in cursor mode, the rows are sorted by the first valid
+/// sort field then by ID, and the page starts after the sort key and the ID of the cursor.
+/// In offset mode, the rows are sorted by all the valid sort fields then by ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryCursorPagination {
+    /// Valid sorts
+    sorts: Vec<(String, QuerySortDirection)>,
+    cursor: Option<PaginationCursor>,
+    limit: u32,
+
+    /// Pagination in offset mode (`None` in cursor mode)
+    offset: Option<MemoryPagination>,
+}
+
+impl MemoryCursorPagination {
+    /// Create a new pagination
+    pub fn new(pagination: Pagination, sorts: &MemoryQuerySorts, valid_fields: &[&str]) -> Self {
+        let sorts = sorts
+            .valid(valid_fields)
+            .map(|(field, direction)| (field.to_string(), direction.clone()))
+            .collect();
+        let keyset = pagination.is_cursor();
+        let cursor = pagination.cursor().cloned();
+        let memory_pagination = MemoryPagination::from(pagination);
+
+        Self {
+            sorts,
+            cursor,
+            limit: memory_pagination.limit,
+            offset: match keyset {
+                true => None,
+                false => Some(memory_pagination),
+            },
+        }
+    }
+
+    /// Sort the rows and keep the rows of the page, with the cursor of the next page
+    /// (only in cursor mode, if there is a next page)
+    pub fn page<R: MemoryRow>(&self, mut rows: Vec<R>) -> (Vec<R>, Option<PaginationCursor>) {
+        if let Some(pagination) = &self.offset {
+            sort_rows(&mut rows, &self.sorts);
+
+            return (pagination.apply(rows), None);
+        }
+
+        // Sort key & ID of each row (`NULL` keys are lower than the other keys, like in the sorts)
+        let sort = self.sorts.first();
+        let direction = sort.map(|(_, direction)| direction.clone()).unwrap_or_default();
+        let mut rows = rows
+            .into_iter()
+            .map(|row| {
+                let value = sort.and_then(|(field, _)| row.field(field)).map(|value| value.to_key());
+                ((value, row.id()), row)
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(cursor) = &self.cursor {
+            let after = (
+                sort.and(cursor.value()).map(|value| value.to_string()),
+                cursor.id().to_string(),
+            );
+            rows.retain(|(key, _)| match direction {
+                QuerySortDirection::Asc => *key > after,
+                QuerySortDirection::Desc => *key < after,
+            });
+        }
+
+        rows.sort_by(|(a, _), (b, _)| match direction {
+            QuerySortDirection::Asc => a.cmp(b),
+            QuerySortDirection::Desc => b.cmp(a),
+        });
+
+        let limit = self.limit as usize;
+        let next_cursor = match rows.len() > limit {
+            true => {
+                rows.truncate(limit);
+                rows.last()
+                    .map(|((value, id), _)| PaginationCursor::new(value.clone(), id.clone()))
+            }
+            false => None,
+        };
+
+        (rows.into_iter().map(|(_, row)| row).collect(), next_cursor)
+    }
+}
+
+/// Search in the text fields of in-memory tables
+///
+/// The search is split into words, and each word can be found anywhere in a field
+/// (case-insensitive) so that partial names or emails match.
+/// The rows are ranked by the number of matching words.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryFullTextSearch(pub String);
+
+impl MemoryFullTextSearch {
+    /// Words of the search in lower case (`None` if there is no word to search)
+    pub fn to_words(&self) -> Option<Vec<String>> {
+        let words = self
+            .0
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .filter(|word| !word.is_empty())
+            .map(|word| word.to_lowercase())
+            .collect::<Vec<_>>();
+
+        match words.is_empty() {
+            true => None,
+            false => Some(words),
+        }
+    }
+
+    /// Rank of a row: the number of words found in one of its fields
+    ///
+    /// A row matches if all the words are found, otherwise its rank is `0`.
+    pub fn rank(words: &[String], fields: &[&str]) -> usize {
+        let fields = fields.iter().map(|field| field.to_lowercase()).collect::<Vec<_>>();
+        let found = words
+            .iter()
+            .filter(|word| fields.iter().any(|field| field.contains(word.as_str())))
+            .count();
+
+        match found == words.len() {
+            true => found,
+            false => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::query_filter::QueryFilterError;
+    use std::collections::HashMap;
+
+    /// Test row: an ID, a name and a creation date
+    #[derive(Debug, Clone, PartialEq)]
+    struct Row(&'static str, Option<&'static str>, &'static str);
+
+    impl MemoryRow for Row {
+        fn id(&self) -> String {
+            self.0.to_string()
+        }
+
+        fn field(&self, name: &str) -> Option<MemoryValue> {
+            match name {
+                "name" => self.1.map(MemoryValue::from),
+                "created_at" => UtcDateTime::from_rfc3339(self.2).ok().as_ref().map(MemoryValue::from),
+                _ => None,
+            }
+        }
+    }
+
+    fn rows() -> Vec<Row> {
+        vec![
+            Row("1", Some("b"), "2024-01-02T00:00:00Z"),
+            Row("2", None, "2024-01-01T00:00:00Z"),
+            Row("3", Some("a"), "2024-01-03T12:00:00Z"),
+            Row("4", Some("b"), "2024-01-01T00:00:00Z"),
+        ]
+    }
+
+    fn ids(rows: &[Row]) -> Vec<&'static str> {
+        rows.iter().map(|row| row.0).collect()
+    }
+
+    fn filters(params: &[(&str, &str)]) -> Result<MemoryQueryFilters, QueryFilterError> {
+        let params = params
+            .iter()
+            .map(|(field, values)| (field.to_string(), values.to_string()))
+            .collect::<HashMap<_, _>>();
+
+        Ok(MemoryQueryFilters(QueryFilters::try_from(params)?))
+    }
+
+    #[test]
+    fn test_memory_pagination_apply() {
+        let pagination = MemoryPagination::from(Pagination::new(3, 1_000));
+        assert_eq!(pagination.limit, PAGINATION_MAX_LIMIT);
+        assert_eq!(pagination.offset, 2 * PAGINATION_MAX_LIMIT);
+
+        let pagination = MemoryPagination::from(Pagination::new(2, 3));
+        assert_eq!(vec!["4"], ids(&pagination.apply(rows())));
+    }
+
+    #[test]
+    fn test_memory_query_sorts_sort() {
+        let sorts = MemoryQuerySorts(QuerySorts::from("-name,+unknown"));
+        assert!(sorts.has_valid_fields(&["name"]));
+        assert!(!sorts.has_valid_fields(&["created_at"]));
+
+        let mut sorted = rows();
+        sorts.sort(&mut sorted, &["name"]);
+        assert_eq!(vec!["1", "4", "3", "2"], ids(&sorted));
+
+        let mut sorted = rows();
+        MemoryQuerySorts(QuerySorts::from("+name,-created_at")).sort(&mut sorted, &["name", "created_at"]);
+        assert_eq!(vec!["2", "3", "1", "4"], ids(&sorted));
+    }
+
+    #[test]
+    fn test_memory_query_filters_matches() {
+        let valid_fields = [
+            ("name", MemoryFilterType::Text),
+            ("created_at", MemoryFilterType::Datetime),
+        ];
+        let matching = |filters: MemoryQueryFilters| {
+            ids(&rows()
+                .into_iter()
+                .filter(|row| filters.matches(row, &valid_fields))
+                .collect::<Vec<_>>())
+        };
+
+        assert_eq!(vec!["1", "3", "4"], matching(filters(&[("name", "in:a,b")]).unwrap()));
+        assert_eq!(vec!["3"], matching(filters(&[("name", "ne:b")]).unwrap()));
+        assert_eq!(
+            vec!["1", "3"],
+            matching(filters(&[("created_at", "gte:2024-01-02"), ("password", "eq:secret")]).unwrap())
+        );
+        assert_eq!(
+            vec!["2", "4"],
+            matching(filters(&[("created_at", "lte:2024-01-01 00:00:00")]).unwrap())
+        );
+        assert!(matching(filters(&[("created_at", "eq:invalid")]).unwrap()).is_empty());
+    }
+
+    #[test]
+    fn test_memory_cursor_pagination_page() {
+        let sorts = MemoryQuerySorts(QuerySorts::from("-name"));
+
+        // First page
+        let pagination = MemoryCursorPagination::new(Pagination::new_cursor(None, 2), &sorts, &["name"]);
+        let (page, cursor) = pagination.page(rows());
+        assert_eq!(vec!["4", "1"], ids(&page));
+        let cursor = cursor.unwrap();
+        assert_eq!((Some("b"), "1"), (cursor.value(), cursor.id()));
+
+        // Last page (`NULL` values are last in descending order)
+        let pagination = MemoryCursorPagination::new(Pagination::new_cursor(Some(cursor), 2), &sorts, &["name"]);
+        let (page, cursor) = pagination.page(rows());
+        assert_eq!(vec!["3", "2"], ids(&page));
+        assert!(cursor.is_none());
+
+        // Sort by ID only
+        let cursor = PaginationCursor::new(Some("z".to_string()), "2".to_string());
+        let pagination = MemoryCursorPagination::new(Pagination::new_cursor(Some(cursor), 10), &sorts, &[]);
+        assert_eq!(vec!["3", "4"], ids(&pagination.page(rows()).0));
+
+        // Offset mode
+        let pagination = MemoryCursorPagination::new(Pagination::new(1, 3), &sorts, &["name"]);
+        assert_eq!((vec!["1", "4", "3"], None), {
+            let (page, cursor) = pagination.page(rows());
+            (ids(&page), cursor)
+        });
+    }
+
+    #[test]
+    fn test_memory_full_text_search() {
+        assert_eq!(None, MemoryFullTextSearch(" -, ".to_string()).to_words());
+
+        let words = MemoryFullTextSearch("John DOE".to_string()).to_words().unwrap();
+        assert_eq!(vec!["john".to_string(), "doe".to_string()], words);
+        assert_eq!(2, MemoryFullTextSearch::rank(&words, &["john.doe@test.com", "Smith"]));
+        assert_eq!(2, MemoryFullTextSearch::rank(&words, &["Johnny", "Doe"]));
+        assert_eq!(0, MemoryFullTextSearch::rank(&words, &["Johnny", "Smith"]));
+        assert_eq!(0, MemoryFullTextSearch::rank(&words, &["Jane", "Smith"]));
+    }
+
+    /// Scenarios shared by the database adapters
+    mod repositories {
+        use super::Db;
+
+        repository_tests!(async { Db::default() });
+    }
+}
@@ -0,0 +1,215 @@
+//! Application in-memory repository
+
+use crate::adapters::database::memory::{
+    Db, MemoryCursorPagination, MemoryFilterField, MemoryFilterType, MemoryQueryFilters, MemoryQuerySorts, MemoryRow,
+    MemoryValue,
+};
+use crate::domain::repositories::application::ApplicationRepository;
+use crate::domain::repositories::application::dto::{
+    CountApplicationsDtoRequest, CountApplicationsDtoResponse, CreateApplicationDtoRequest,
+    CreateApplicationDtoResponse, DeleteApplicationDtoRequest, DeleteApplicationDtoResponse,
+    GetApplicationByIdDtoRequest, GetApplicationByIdDtoResponse, GetApplicationsDtoRequest, GetApplicationsDtoResponse,
+    RestoreApplicationDtoRequest, RestoreApplicationDtoResponse, UpdateApplicationDtoRequest,
+    UpdateApplicationDtoResponse,
+};
+use crate::domain::use_cases::application::delete_application::DeleteApplicationUseCaseResponse;
+use crate::domain::use_cases::application::restore_application::RestoreApplicationUseCaseResponse;
+use crate::domain::use_cases::application::update_application::UpdateApplicationUseCaseResponse;
+use crate::domain::use_cases::application::{ApplicationUseCaseError, ApplicationUseCaseResponse};
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Fields available for filtering the applications
+const FILTER_FIELDS: [MemoryFilterField; 6] = [
+    ("id", MemoryFilterType::Text),
+    ("name", MemoryFilterType::Text),
+    ("audience", MemoryFilterType::Text),
+    ("created_at", MemoryFilterType::Datetime),
+    ("updated_at", MemoryFilterType::Datetime),
+    ("deleted_at", MemoryFilterType::Datetime),
+];
+
+/// Fields available for sorting the applications
+const SORT_FIELDS: [&str; 4] = ["id", "name", "updated_at", "deleted_at"];
+
+impl MemoryRow for ApplicationUseCaseResponse {
+    fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn field(&self, name: &str) -> Option<MemoryValue> {
+        match name {
+            "id" => Some(MemoryValue::Text(self.id.to_string())),
+            "name" => Some(MemoryValue::from(self.name.as_str())),
+            "audience" => self.audience.as_deref().map(MemoryValue::from),
+            "created_at" => Some(MemoryValue::from(&self.created_at)),
+            "updated_at" => Some(MemoryValue::from(&self.updated_at)),
+            "deleted_at" => self.deleted_at.as_ref().map(MemoryValue::from),
+            _ => None,
+        }
+    }
+}
+
+/// Application in-memory repository
+#[derive(Debug, Clone)]
+pub struct ApplicationMemoryRepository {
+    db: Arc<Db>,
+}
+
+impl ApplicationMemoryRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl ApplicationRepository for ApplicationMemoryRepository {
+    #[instrument(skip(self), name = "application_repository_create")]
+    async fn create(
+        &self,
+        req: CreateApplicationDtoRequest,
+    ) -> Result<CreateApplicationDtoResponse, ApplicationUseCaseError> {
+        let application_id = Id::new().map_err(|err| {
+            error!(error = %err, "Failed to create application ID");
+            ApplicationUseCaseError::InvalidId()
+        })?;
+        let now = UtcDateTime::now();
+
+        let application = ApplicationUseCaseResponse {
+            id: application_id,
+            name: req.0.name,
+            access_token_lifetime: req.0.access_token_lifetime,
+            refresh_token_lifetime: req.0.refresh_token_lifetime,
+            audience: req.0.audience,
+            created_at: now.clone(),
+            updated_at: now,
+            deleted_at: None,
+        };
+        self.db
+            .write()
+            .applications
+            .insert(application.id.to_string(), application.clone());
+
+        Ok(CreateApplicationDtoResponse(application))
+    }
+
+    #[instrument(skip(self), name = "application_repository_get_by_id")]
+    async fn get_by_id(
+        &self,
+        req: GetApplicationByIdDtoRequest,
+    ) -> Result<GetApplicationByIdDtoResponse, ApplicationUseCaseError> {
+        let application = self
+            .db
+            .read()
+            .applications
+            .get(&req.0.id.to_string())
+            .filter(|application| application.deleted_at.is_none())
+            .cloned()
+            .ok_or_else(ApplicationUseCaseError::ApplicationNotFound)?;
+
+        Ok(GetApplicationByIdDtoResponse(application))
+    }
+
+    #[instrument(skip(self), name = "application_repository_get_all")]
+    async fn get_applications(
+        &self,
+        req: GetApplicationsDtoRequest,
+    ) -> Result<GetApplicationsDtoResponse, ApplicationUseCaseError> {
+        let sorts = MemoryQuerySorts(req.0.sorts.unwrap_or_default());
+        let pagination = MemoryCursorPagination::new(req.0.pagination, &sorts, &SORT_FIELDS);
+        let filters = MemoryQueryFilters(req.0.filters.unwrap_or_default());
+
+        let tables = self.db.read();
+        let rows = tables
+            .applications
+            .values()
+            .filter(|application| {
+                application.deleted_at.is_some() == req.0.deleted && filters.matches(application, &FILTER_FIELDS)
+            })
+            .collect::<Vec<_>>();
+        let (applications, next_cursor) = pagination.page(rows);
+
+        Ok(GetApplicationsDtoResponse {
+            applications: applications.into_iter().cloned().collect(),
+            next_cursor,
+        })
+    }
+
+    #[instrument(skip(self), name = "application_repository_update")]
+    async fn update(
+        &self,
+        req: UpdateApplicationDtoRequest,
+    ) -> Result<UpdateApplicationDtoResponse, ApplicationUseCaseError> {
+        let mut tables = self.db.write();
+        let application = tables
+            .applications
+            .get_mut(&req.0.id.to_string())
+            .filter(|application| application.deleted_at.is_none())
+            .ok_or_else(ApplicationUseCaseError::ApplicationNotFound)?;
+
+        application.name = req.0.name;
+        application.access_token_lifetime = req.0.access_token_lifetime;
+        application.refresh_token_lifetime = req.0.refresh_token_lifetime;
+        application.audience = req.0.audience;
+        application.updated_at = UtcDateTime::now();
+
+        Ok(UpdateApplicationDtoResponse(UpdateApplicationUseCaseResponse()))
+    }
+
+    #[instrument(skip(self), name = "application_repository_delete")]
+    async fn delete(
+        &self,
+        req: DeleteApplicationDtoRequest,
+    ) -> Result<DeleteApplicationDtoResponse, ApplicationUseCaseError> {
+        let mut tables = self.db.write();
+        let application = tables
+            .applications
+            .get_mut(&req.0.id.to_string())
+            .filter(|application| application.deleted_at.is_none())
+            .ok_or_else(ApplicationUseCaseError::ApplicationNotFound)?;
+
+        application.deleted_at = Some(UtcDateTime::now());
+
+        Ok(DeleteApplicationDtoResponse(DeleteApplicationUseCaseResponse()))
+    }
+
+    #[instrument(skip(self), name = "application_repository_count")]
+    async fn count_applications(
+        &self,
+        req: CountApplicationsDtoRequest,
+    ) -> Result<CountApplicationsDtoResponse, ApplicationUseCaseError> {
+        let filters = MemoryQueryFilters(req.filters.unwrap_or_default());
+
+        let total = self
+            .db
+            .read()
+            .applications
+            .values()
+            .filter(|application| {
+                application.deleted_at.is_some() == req.deleted && filters.matches(application, &FILTER_FIELDS)
+            })
+            .count();
+
+        Ok(CountApplicationsDtoResponse(total as i64))
+    }
+
+    #[instrument(skip(self), name = "application_repository_restore")]
+    async fn restore(
+        &self,
+        req: RestoreApplicationDtoRequest,
+    ) -> Result<RestoreApplicationDtoResponse, ApplicationUseCaseError> {
+        let mut tables = self.db.write();
+        let application = tables
+            .applications
+            .get_mut(&req.0.id.to_string())
+            .filter(|application| application.deleted_at.is_some())
+            .ok_or_else(ApplicationUseCaseError::ApplicationNotFound)?;
+
+        application.deleted_at = None;
+
+        Ok(RestoreApplicationDtoResponse(RestoreApplicationUseCaseResponse()))
+    }
+}
@@ -0,0 +1,155 @@
+//! Audit in-memory repository
+
+use crate::adapters::database::memory::{Db, MemoryCursorPagination, MemoryQuerySorts, MemoryRow, MemoryValue};
+use crate::domain::entities::audit_event::AuditEvent;
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::repositories::audit::dto::{
+    CountAuditEventsDtoRequest, CountAuditEventsDtoResponse, CreateAuditEventDtoRequest, CreateAuditEventDtoResponse,
+    GetAuditEventsDtoRequest, GetAuditEventsDtoResponse,
+};
+use crate::domain::use_cases::audit::AuditUseCaseError;
+use crate::domain::use_cases::audit::get_audit_events::AuditEventFilters;
+use crate::domain::value_objects::query_sort::QuerySorts;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+impl MemoryRow for AuditEvent {
+    fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn field(&self, name: &str) -> Option<MemoryValue> {
+        match name {
+            "actor_id" => self.actor_id.as_ref().map(|id| MemoryValue::Text(id.to_string())),
+            "action" => Some(MemoryValue::from(self.action.as_str())),
+            "target_id" => Some(MemoryValue::from(self.target_id.as_str())),
+            "created_at" => Some(MemoryValue::from(&self.created_at)),
+            _ => None,
+        }
+    }
+}
+
+/// Audit in-memory repository
+#[derive(Debug, Clone)]
+pub struct AuditMemoryRepository {
+    db: Arc<Db>,
+}
+
+impl AuditMemoryRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+
+    /// Check if an event matches the filters
+    fn matches(event: &AuditEvent, filters: &AuditEventFilters) -> bool {
+        filters
+            .actor_id
+            .as_ref()
+            .is_none_or(|actor_id| event.actor_id.as_ref() == Some(actor_id))
+            && filters
+                .action
+                .as_ref()
+                .is_none_or(|action| event.action.as_str() == action.as_str())
+            && filters
+                .target_id
+                .as_ref()
+                .is_none_or(|target_id| event.target_id == *target_id)
+            && filters.from.as_ref().is_none_or(|from| event.created_at >= *from)
+            && filters.to.as_ref().is_none_or(|to| event.created_at < *to)
+    }
+}
+
+#[async_trait]
+impl AuditRepository for AuditMemoryRepository {
+    #[instrument(skip(self), name = "audit_repository_create")]
+    async fn create(&self, req: CreateAuditEventDtoRequest) -> Result<CreateAuditEventDtoResponse, AuditUseCaseError> {
+        let mut tables = self.db.write();
+        if tables.audit_events.contains_key(&req.0.id.to_string()) {
+            error!("Failed to create audit event: ID already exists");
+            return Err(AuditUseCaseError::DatabaseError(
+                "Failed to create audit event".to_string(),
+            ));
+        }
+
+        tables.audit_events.insert(req.0.id.to_string(), req.0);
+
+        Ok(CreateAuditEventDtoResponse())
+    }
+
+    #[instrument(skip(self), name = "audit_repository_get_all")]
+    async fn get_audit_events(
+        &self,
+        req: GetAuditEventsDtoRequest,
+    ) -> Result<GetAuditEventsDtoResponse, AuditUseCaseError> {
+        // Sorts (most recent events first by default) & pagination
+        let sort_fields = ["actor_id", "action", "target_id", "created_at"];
+        let sorts = match MemoryQuerySorts(req.0.sorts.unwrap_or_default()) {
+            sorts if !sorts.has_valid_fields(&sort_fields) => MemoryQuerySorts(QuerySorts::from("-created_at")),
+            sorts => sorts,
+        };
+        let pagination = MemoryCursorPagination::new(req.0.pagination, &sorts, &sort_fields);
+
+        let tables = self.db.read();
+        let rows = tables
+            .audit_events
+            .values()
+            .filter(|event| Self::matches(event, &req.0.filters))
+            .collect::<Vec<_>>();
+        let (events, next_cursor) = pagination.page(rows);
+
+        Ok(GetAuditEventsDtoResponse {
+            events: events.into_iter().cloned().collect(),
+            next_cursor,
+        })
+    }
+
+    #[instrument(skip(self), name = "audit_repository_count")]
+    async fn count_audit_events(
+        &self,
+        req: CountAuditEventsDtoRequest,
+    ) -> Result<CountAuditEventsDtoResponse, AuditUseCaseError> {
+        let total = self
+            .db
+            .read()
+            .audit_events
+            .values()
+            .filter(|event| Self::matches(event, &req.0))
+            .count();
+
+        Ok(CountAuditEventsDtoResponse(total as i64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+    use crate::domain::value_objects::datetime::UtcDateTime;
+
+    #[test]
+    fn test_matches() {
+        let event = AuditEvent::new(&AuditContext::default(), AuditAction::LoginFailed, "target".to_string()).unwrap();
+        assert!(AuditMemoryRepository::matches(&event, &AuditEventFilters::default()));
+
+        let filters = AuditEventFilters {
+            action: Some(AuditAction::LoginFailed),
+            from: Some(event.created_at.clone()),
+            ..AuditEventFilters::default()
+        };
+        assert!(AuditMemoryRepository::matches(&event, &filters));
+
+        let filters = AuditEventFilters {
+            to: Some(event.created_at.clone()),
+            ..AuditEventFilters::default()
+        };
+        assert!(!AuditMemoryRepository::matches(&event, &filters));
+
+        let filters = AuditEventFilters {
+            target_id: Some("other".to_string()),
+            from: Some(UtcDateTime::now()),
+            ..AuditEventFilters::default()
+        };
+        assert!(!AuditMemoryRepository::matches(&event, &filters));
+    }
+}
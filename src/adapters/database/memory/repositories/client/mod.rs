@@ -0,0 +1,283 @@
+//! Client in-memory repository
+
+use crate::adapters::database::memory::tables::ClientSecretRow;
+use crate::adapters::database::memory::{Db, MemoryCursorPagination, MemoryQuerySorts, MemoryRow, MemoryValue};
+use crate::domain::repositories::client::ClientRepository;
+use crate::domain::repositories::client::dto::{
+    CountClientsDtoRequest, CountClientsDtoResponse, CreateClientDtoRequest, CreateClientDtoResponse,
+    CreateClientSecretDtoRequest, CreateClientSecretDtoResponse, DeleteClientDtoRequest, DeleteClientDtoResponse,
+    DeleteClientSecretDtoRequest, DeleteClientSecretDtoResponse, GetClientByIdDtoRequest, GetClientByIdDtoResponse,
+    GetClientSecretsDtoRequest, GetClientSecretsDtoResponse, GetClientsDtoRequest, GetClientsDtoResponse,
+    UpdateClientDtoRequest, UpdateClientDtoResponse,
+};
+use crate::domain::use_cases::application::delete_client::DeleteClientUseCaseResponse;
+use crate::domain::use_cases::application::delete_client_secret::DeleteClientSecretUseCaseResponse;
+use crate::domain::use_cases::application::update_client::UpdateClientUseCaseResponse;
+use crate::domain::use_cases::application::{
+    ApplicationUseCaseError, ClientSecretUseCaseResponse, ClientUseCaseResponse,
+};
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Fields available for sorting the clients
+const SORT_FIELDS: [&str; 3] = ["name", "created_at", "updated_at"];
+
+impl MemoryRow for ClientUseCaseResponse {
+    fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn field(&self, name: &str) -> Option<MemoryValue> {
+        match name {
+            "name" => Some(MemoryValue::from(self.name.as_str())),
+            "created_at" => Some(MemoryValue::from(&self.created_at)),
+            "updated_at" => Some(MemoryValue::from(&self.updated_at)),
+            _ => None,
+        }
+    }
+}
+
+/// Client in-memory repository
+#[derive(Debug, Clone)]
+pub struct ClientMemoryRepository {
+    db: Arc<Db>,
+}
+
+impl ClientMemoryRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+
+    /// Check if a client belongs to the application and is not deleted
+    fn is_active_client(client: &ClientUseCaseResponse, application_id: &Id) -> bool {
+        client.application_id == *application_id && client.deleted_at.is_none()
+    }
+}
+
+#[async_trait]
+impl ClientRepository for ClientMemoryRepository {
+    #[instrument(skip(self, req), name = "client_repository_create")]
+    async fn create_client(
+        &self,
+        req: CreateClientDtoRequest,
+    ) -> Result<CreateClientDtoResponse, ApplicationUseCaseError> {
+        let client_id = Id::new().map_err(|err| {
+            error!(error = %err, "Failed to create client ID");
+            ApplicationUseCaseError::InvalidId()
+        })?;
+        let secret_id = Id::new().map_err(|err| {
+            error!(error = %err, "Failed to create client secret ID");
+            ApplicationUseCaseError::InvalidId()
+        })?;
+        let now = UtcDateTime::now();
+
+        // The client is only created if the application exists
+        let mut tables = self.db.write();
+        if !tables.is_active_application(&req.client.application_id.to_string()) {
+            return Err(ApplicationUseCaseError::ApplicationNotFound());
+        }
+
+        let client = ClientUseCaseResponse {
+            id: client_id.clone(),
+            application_id: req.client.application_id,
+            name: req.client.name,
+            created_at: now.clone(),
+            updated_at: now.clone(),
+            deleted_at: None,
+        };
+        tables.clients.insert(client_id.to_string(), client.clone());
+
+        // Add first secret
+        tables.client_secrets.insert(
+            secret_id.to_string(),
+            ClientSecretRow {
+                secret: ClientSecretUseCaseResponse {
+                    id: secret_id,
+                    client_id,
+                    created_at: now,
+                },
+                value: req.secret,
+            },
+        );
+
+        Ok(CreateClientDtoResponse(client))
+    }
+
+    #[instrument(skip(self), name = "client_repository_count")]
+    async fn count_clients(
+        &self,
+        req: CountClientsDtoRequest,
+    ) -> Result<CountClientsDtoResponse, ApplicationUseCaseError> {
+        let total = self
+            .db
+            .read()
+            .clients
+            .values()
+            .filter(|client| Self::is_active_client(client, &req.application_id))
+            .count();
+
+        Ok(CountClientsDtoResponse(total as i64))
+    }
+
+    #[instrument(skip(self), name = "client_repository_get_all")]
+    async fn get_clients(&self, req: GetClientsDtoRequest) -> Result<GetClientsDtoResponse, ApplicationUseCaseError> {
+        let sorts = MemoryQuerySorts(req.0.sorts.unwrap_or_default());
+        let pagination = MemoryCursorPagination::new(req.0.pagination, &sorts, &SORT_FIELDS);
+
+        let tables = self.db.read();
+        let rows = tables
+            .clients
+            .values()
+            .filter(|client| Self::is_active_client(client, &req.0.application_id))
+            .collect::<Vec<_>>();
+        let (clients, next_cursor) = pagination.page(rows);
+
+        Ok(GetClientsDtoResponse {
+            clients: clients.into_iter().cloned().collect(),
+            next_cursor,
+        })
+    }
+
+    #[instrument(skip(self), name = "client_repository_get_by_id")]
+    async fn get_client_by_id(
+        &self,
+        req: GetClientByIdDtoRequest,
+    ) -> Result<GetClientByIdDtoResponse, ApplicationUseCaseError> {
+        let client = self
+            .db
+            .read()
+            .clients
+            .get(&req.0.id.to_string())
+            .filter(|client| Self::is_active_client(client, &req.0.application_id))
+            .cloned()
+            .ok_or_else(ApplicationUseCaseError::ClientNotFound)?;
+
+        Ok(GetClientByIdDtoResponse(client))
+    }
+
+    #[instrument(skip(self), name = "client_repository_update")]
+    async fn update_client(
+        &self,
+        req: UpdateClientDtoRequest,
+    ) -> Result<UpdateClientDtoResponse, ApplicationUseCaseError> {
+        let mut tables = self.db.write();
+        let client = tables
+            .clients
+            .get_mut(&req.0.id.to_string())
+            .filter(|client| Self::is_active_client(client, &req.0.application_id))
+            .ok_or_else(ApplicationUseCaseError::ClientNotFound)?;
+
+        client.name = req.0.name;
+        client.updated_at = UtcDateTime::now();
+
+        Ok(UpdateClientDtoResponse(UpdateClientUseCaseResponse()))
+    }
+
+    #[instrument(skip(self), name = "client_repository_delete")]
+    async fn delete_client(
+        &self,
+        req: DeleteClientDtoRequest,
+    ) -> Result<DeleteClientDtoResponse, ApplicationUseCaseError> {
+        let mut tables = self.db.write();
+        let client = tables
+            .clients
+            .get_mut(&req.0.id.to_string())
+            .filter(|client| Self::is_active_client(client, &req.0.application_id))
+            .ok_or_else(ApplicationUseCaseError::ClientNotFound)?;
+
+        client.deleted_at = Some(UtcDateTime::now());
+
+        Ok(DeleteClientDtoResponse(DeleteClientUseCaseResponse()))
+    }
+
+    #[instrument(skip(self), name = "client_repository_get_secrets")]
+    async fn get_client_secrets(
+        &self,
+        req: GetClientSecretsDtoRequest,
+    ) -> Result<GetClientSecretsDtoResponse, ApplicationUseCaseError> {
+        let tables = self.db.read();
+        let is_active_client = tables
+            .clients
+            .get(&req.0.client_id.to_string())
+            .is_some_and(|client| Self::is_active_client(client, &req.0.application_id));
+
+        let mut secrets = tables
+            .client_secrets
+            .values()
+            .filter(|row| is_active_client && row.secret.client_id == req.0.client_id)
+            .map(|row| row.secret.clone())
+            .collect::<Vec<_>>();
+        secrets.sort_by_key(|secret| (secret.created_at.value(), secret.id.to_string()));
+
+        // A client always has at least one secret
+        if secrets.is_empty() {
+            return Err(ApplicationUseCaseError::ClientNotFound());
+        }
+
+        Ok(GetClientSecretsDtoResponse(secrets))
+    }
+
+    #[instrument(skip(self, req), name = "client_repository_create_secret")]
+    async fn create_client_secret(
+        &self,
+        req: CreateClientSecretDtoRequest,
+    ) -> Result<CreateClientSecretDtoResponse, ApplicationUseCaseError> {
+        let secret_id = Id::new().map_err(|err| {
+            error!(error = %err, "Failed to create client secret ID");
+            ApplicationUseCaseError::InvalidId()
+        })?;
+        let now = UtcDateTime::now();
+
+        // The secret is only created if the client exists
+        let mut tables = self.db.write();
+        let is_active_client = tables
+            .clients
+            .get(&req.client_id.to_string())
+            .is_some_and(|client| Self::is_active_client(client, &req.application_id));
+        if !is_active_client {
+            return Err(ApplicationUseCaseError::ClientNotFound());
+        }
+
+        let secret = ClientSecretUseCaseResponse {
+            id: secret_id,
+            client_id: req.client_id,
+            created_at: now,
+        };
+        tables.client_secrets.insert(
+            secret.id.to_string(),
+            ClientSecretRow {
+                secret: secret.clone(),
+                value: req.secret,
+            },
+        );
+
+        Ok(CreateClientSecretDtoResponse(secret))
+    }
+
+    #[instrument(skip(self), name = "client_repository_delete_secret")]
+    async fn delete_client_secret(
+        &self,
+        req: DeleteClientSecretDtoRequest,
+    ) -> Result<DeleteClientSecretDtoResponse, ApplicationUseCaseError> {
+        let mut tables = self.db.write();
+        let is_active_client = tables
+            .clients
+            .get(&req.0.client_id.to_string())
+            .is_some_and(|client| Self::is_active_client(client, &req.0.application_id));
+        let secret_id = req.0.id.to_string();
+        let is_client_secret = tables
+            .client_secrets
+            .get(&secret_id)
+            .is_some_and(|row| row.secret.client_id == req.0.client_id);
+        if !is_active_client || !is_client_secret {
+            return Err(ApplicationUseCaseError::ClientSecretNotFound())?;
+        }
+
+        tables.client_secrets.remove(&secret_id);
+
+        Ok(DeleteClientSecretDtoResponse(DeleteClientSecretUseCaseResponse()))
+    }
+}
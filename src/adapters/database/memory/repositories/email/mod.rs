@@ -0,0 +1,84 @@
+//! Email outbox in-memory repository
+
+use crate::adapters::database::memory::Db;
+use crate::domain::entities::email::{EmailStatus, QueuedEmail};
+use crate::domain::repositories::email::EmailRepository;
+use crate::domain::repositories::email::dto::{
+    GetDueEmailsDtoRequest, GetDueEmailsDtoResponse, UpdateEmailDtoRequest, UpdateEmailDtoResponse,
+};
+use crate::domain::use_cases::email::EmailUseCaseError;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use async_trait::async_trait;
+use chrono::Duration;
+use std::sync::Arc;
+
+/// Time during which an email fetched by a worker is not fetched again (in second)
+///
+/// The email is retried after this delay if the worker stops before saving the result.
+const EMAIL_LEASE: i64 = 300;
+
+/// Email outbox in-memory repository
+#[derive(Debug, Clone)]
+pub struct EmailMemoryRepository {
+    db: Arc<Db>,
+}
+
+impl EmailMemoryRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl EmailRepository for EmailMemoryRepository {
+    #[instrument(skip(self), name = "email_repository_get_due_emails")]
+    async fn get_due_emails(&self, req: GetDueEmailsDtoRequest) -> Result<GetDueEmailsDtoResponse, EmailUseCaseError> {
+        let now = UtcDateTime::now();
+        let lease_until = UtcDateTime::new(now.value() + Duration::seconds(EMAIL_LEASE));
+
+        // The tables are locked: the emails cannot be claimed twice
+        let mut tables = self.db.write();
+        let mut emails = tables
+            .email_outbox
+            .values_mut()
+            .filter(|row| row.status == EmailStatus::Pending && row.next_attempt_at <= now)
+            .collect::<Vec<_>>();
+        emails.sort_by_key(|row| row.next_attempt_at.value());
+        emails.truncate(req.limit as usize);
+
+        // Lease
+        let emails = emails
+            .into_iter()
+            .map(|row| {
+                row.next_attempt_at = lease_until.clone();
+
+                QueuedEmail {
+                    id: row.id.clone(),
+                    message: row.message.clone(),
+                    attempts: row.attempts,
+                }
+            })
+            .collect();
+
+        Ok(GetDueEmailsDtoResponse(emails))
+    }
+
+    #[instrument(skip(self), name = "email_repository_update_email")]
+    async fn update_email(&self, req: UpdateEmailDtoRequest) -> Result<UpdateEmailDtoResponse, EmailUseCaseError> {
+        let sent_at = match req.status {
+            EmailStatus::Sent => Some(UtcDateTime::now()),
+            _ => None,
+        };
+
+        if let Some(row) = self.db.write().email_outbox.get_mut(&req.id.to_string()) {
+            row.status = req.status;
+            row.attempts = req.attempts;
+            row.next_attempt_at = req.next_attempt_at;
+            row.last_error = req.last_error;
+            row.sent_at = sent_at;
+        }
+
+        Ok(UpdateEmailDtoResponse())
+    }
+}
@@ -0,0 +1,361 @@
+//! External link in-memory repository
+
+use crate::adapters::database::memory::{
+    Db, MemoryCursorPagination, MemoryFilterField, MemoryFilterType, MemoryQueryFilters, MemoryQuerySorts, MemoryRow,
+    MemoryValue,
+};
+use crate::domain::repositories::external_link::ExternalLinkRepository;
+use crate::domain::repositories::external_link::dto::{
+    CountExternalLinksDtoRequest, CountExternalLinksDtoResponse, CreateExternalLinkDtoRequest,
+    CreateExternalLinkDtoResponse, DeleteExternalLinkDtoRequest, DeleteExternalLinkDtoResponse,
+    GetExternalLinkByIdDtoRequest, GetExternalLinkByIdDtoResponse, GetExternalLinksDtoRequest,
+    GetExternalLinksDtoResponse, GetLinkedUserDtoRequest, GetLinkedUserDtoResponse, LinkApplicationDtoRequest,
+    LinkApplicationDtoResponse, LinkUserDtoRequest, LinkUserDtoResponse, RestoreExternalLinkDtoRequest,
+    RestoreExternalLinkDtoResponse, UnlinkApplicationDtoRequest, UnlinkApplicationDtoResponse, UnlinkUserDtoRequest,
+    UnlinkUserDtoResponse, UpdateExternalLinkDtoRequest, UpdateExternalLinkDtoResponse,
+};
+use crate::domain::use_cases::external_link::delete_external_link::DeleteExternalLinkUseCaseResponse;
+use crate::domain::use_cases::external_link::restore_external_link::RestoreExternalLinkUseCaseResponse;
+use crate::domain::use_cases::external_link::unlink_application::UnlinkApplicationUseCaseResponse;
+use crate::domain::use_cases::external_link::unlink_user::UnlinkUserUseCaseResponse;
+use crate::domain::use_cases::external_link::update_external_link::UpdateExternalLinkUseCaseResponse;
+use crate::domain::use_cases::external_link::{
+    ExternalLinkApplicationUseCaseResponse, ExternalLinkUseCaseError, ExternalLinkUseCaseResponse,
+    ExternalLinkUserUseCaseResponse,
+};
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Fields available for filtering the external links
+const FILTER_FIELDS: [MemoryFilterField; 6] = [
+    ("id", MemoryFilterType::Text),
+    ("name", MemoryFilterType::Text),
+    ("oidc_issuer", MemoryFilterType::Text),
+    ("created_at", MemoryFilterType::Datetime),
+    ("updated_at", MemoryFilterType::Datetime),
+    ("deleted_at", MemoryFilterType::Datetime),
+];
+
+/// Fields available for sorting the external links
+const SORT_FIELDS: [&str; 4] = ["name", "created_at", "updated_at", "deleted_at"];
+
+impl MemoryRow for ExternalLinkUseCaseResponse {
+    fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn field(&self, name: &str) -> Option<MemoryValue> {
+        match name {
+            "id" => Some(MemoryValue::Text(self.id.to_string())),
+            "name" => Some(MemoryValue::from(self.name.as_str())),
+            "oidc_issuer" => self
+                .provider
+                .as_ref()
+                .map(|provider| MemoryValue::from(provider.issuer())),
+            "created_at" => Some(MemoryValue::from(&self.created_at)),
+            "updated_at" => Some(MemoryValue::from(&self.updated_at)),
+            "deleted_at" => self.deleted_at.as_ref().map(MemoryValue::from),
+            _ => None,
+        }
+    }
+}
+
+/// External link in-memory repository
+#[derive(Debug, Clone)]
+pub struct ExternalLinkMemoryRepository {
+    db: Arc<Db>,
+}
+
+impl ExternalLinkMemoryRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl ExternalLinkRepository for ExternalLinkMemoryRepository {
+    #[instrument(skip(self), name = "external_link_repository_create")]
+    async fn create(
+        &self,
+        req: CreateExternalLinkDtoRequest,
+    ) -> Result<CreateExternalLinkDtoResponse, ExternalLinkUseCaseError> {
+        let external_link_id = Id::new().map_err(|err| {
+            error!(error = %err, "Failed to create external link ID");
+            ExternalLinkUseCaseError::InvalidId()
+        })?;
+        let now = UtcDateTime::now();
+
+        let external_link = ExternalLinkUseCaseResponse {
+            id: external_link_id,
+            name: req.0.name,
+            provider: req.0.provider,
+            created_at: now.clone(),
+            updated_at: now,
+            deleted_at: None,
+        };
+        self.db
+            .write()
+            .external_links
+            .insert(external_link.id.to_string(), external_link.clone());
+
+        Ok(CreateExternalLinkDtoResponse(external_link))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_count")]
+    async fn count_external_links(
+        &self,
+        req: CountExternalLinksDtoRequest,
+    ) -> Result<CountExternalLinksDtoResponse, ExternalLinkUseCaseError> {
+        let filters = MemoryQueryFilters(req.filters.unwrap_or_default());
+
+        let total = self
+            .db
+            .read()
+            .external_links
+            .values()
+            .filter(|external_link| {
+                external_link.deleted_at.is_some() == req.deleted && filters.matches(external_link, &FILTER_FIELDS)
+            })
+            .count();
+
+        Ok(CountExternalLinksDtoResponse(total as i64))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_get_all")]
+    async fn get_external_links(
+        &self,
+        req: GetExternalLinksDtoRequest,
+    ) -> Result<GetExternalLinksDtoResponse, ExternalLinkUseCaseError> {
+        let sorts = MemoryQuerySorts(req.0.sorts.unwrap_or_default());
+        let pagination = MemoryCursorPagination::new(req.0.pagination, &sorts, &SORT_FIELDS);
+        let filters = MemoryQueryFilters(req.0.filters.unwrap_or_default());
+
+        let tables = self.db.read();
+        let rows = tables
+            .external_links
+            .values()
+            .filter(|external_link| {
+                external_link.deleted_at.is_some() == req.0.deleted && filters.matches(external_link, &FILTER_FIELDS)
+            })
+            .collect::<Vec<_>>();
+        let (external_links, next_cursor) = pagination.page(rows);
+
+        Ok(GetExternalLinksDtoResponse {
+            external_links: external_links.into_iter().cloned().collect(),
+            next_cursor,
+        })
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_get_by_id")]
+    async fn get_by_id(
+        &self,
+        req: GetExternalLinkByIdDtoRequest,
+    ) -> Result<GetExternalLinkByIdDtoResponse, ExternalLinkUseCaseError> {
+        let external_link = self
+            .db
+            .read()
+            .external_links
+            .get(&req.0.id.to_string())
+            .filter(|external_link| external_link.deleted_at.is_none())
+            .cloned()
+            .ok_or_else(ExternalLinkUseCaseError::ExternalLinkNotFound)?;
+
+        Ok(GetExternalLinkByIdDtoResponse(external_link))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_update")]
+    async fn update(
+        &self,
+        req: UpdateExternalLinkDtoRequest,
+    ) -> Result<UpdateExternalLinkDtoResponse, ExternalLinkUseCaseError> {
+        let mut tables = self.db.write();
+        let external_link = tables
+            .external_links
+            .get_mut(&req.0.id.to_string())
+            .filter(|external_link| external_link.deleted_at.is_none())
+            .ok_or_else(ExternalLinkUseCaseError::ExternalLinkNotFound)?;
+
+        external_link.name = req.0.name;
+        external_link.provider = req.0.provider;
+        external_link.updated_at = UtcDateTime::now();
+
+        Ok(UpdateExternalLinkDtoResponse(UpdateExternalLinkUseCaseResponse()))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_delete")]
+    async fn delete(
+        &self,
+        req: DeleteExternalLinkDtoRequest,
+    ) -> Result<DeleteExternalLinkDtoResponse, ExternalLinkUseCaseError> {
+        let mut tables = self.db.write();
+        let external_link = tables
+            .external_links
+            .get_mut(&req.0.id.to_string())
+            .filter(|external_link| external_link.deleted_at.is_none())
+            .ok_or_else(ExternalLinkUseCaseError::ExternalLinkNotFound)?;
+
+        external_link.deleted_at = Some(UtcDateTime::now());
+
+        Ok(DeleteExternalLinkDtoResponse(DeleteExternalLinkUseCaseResponse()))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_restore")]
+    async fn restore(
+        &self,
+        req: RestoreExternalLinkDtoRequest,
+    ) -> Result<RestoreExternalLinkDtoResponse, ExternalLinkUseCaseError> {
+        let mut tables = self.db.write();
+        let external_link = tables
+            .external_links
+            .get_mut(&req.0.id.to_string())
+            .filter(|external_link| external_link.deleted_at.is_some())
+            .ok_or_else(ExternalLinkUseCaseError::ExternalLinkNotFound)?;
+
+        external_link.deleted_at = None;
+
+        Ok(RestoreExternalLinkDtoResponse(RestoreExternalLinkUseCaseResponse()))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_link_user")]
+    async fn link_user(&self, req: LinkUserDtoRequest) -> Result<LinkUserDtoResponse, ExternalLinkUseCaseError> {
+        let mut tables = self.db.write();
+
+        if !tables.external_links.contains_key(&req.0.external_link_id.to_string()) {
+            error!("Failed to link user: external link not found");
+            return Err(ExternalLinkUseCaseError::DatabaseError(
+                "Failed to link user".to_string(),
+            ));
+        }
+
+        // The link is only created if the user exists
+        if !tables.is_active_user(&req.0.user_id.to_string()) {
+            return Err(ExternalLinkUseCaseError::UserNotFound());
+        }
+
+        // An external user ID is linked to only one user
+        let is_used = tables.external_links_users.iter().any(|link| {
+            link.external_link_id == req.0.external_link_id
+                && link.external_user_id == req.0.external_user_id
+                && link.user_id != req.0.user_id
+        });
+        if is_used {
+            return Err(ExternalLinkUseCaseError::ExternalUserIdAlreadyUsed());
+        }
+
+        // Replace the previous external user ID
+        tables
+            .external_links_users
+            .retain(|link| !(link.external_link_id == req.0.external_link_id && link.user_id == req.0.user_id));
+
+        let link = ExternalLinkUserUseCaseResponse {
+            external_link_id: req.0.external_link_id,
+            user_id: req.0.user_id,
+            external_user_id: req.0.external_user_id,
+            created_at: UtcDateTime::now(),
+        };
+        tables.external_links_users.push(link.clone());
+
+        Ok(LinkUserDtoResponse(link))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_unlink_user")]
+    async fn unlink_user(&self, req: UnlinkUserDtoRequest) -> Result<UnlinkUserDtoResponse, ExternalLinkUseCaseError> {
+        let mut tables = self.db.write();
+        let total = tables.external_links_users.len();
+        tables
+            .external_links_users
+            .retain(|link| !(link.external_link_id == req.0.external_link_id && link.user_id == req.0.user_id));
+
+        if tables.external_links_users.len() == total {
+            return Err(ExternalLinkUseCaseError::ExternalLinkUserNotFound())?;
+        }
+
+        Ok(UnlinkUserDtoResponse(UnlinkUserUseCaseResponse()))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_get_linked_user")]
+    async fn get_linked_user(
+        &self,
+        req: GetLinkedUserDtoRequest,
+    ) -> Result<GetLinkedUserDtoResponse, ExternalLinkUseCaseError> {
+        let tables = self.db.read();
+        let is_active_link = tables
+            .external_links
+            .get(&req.0.external_link_id.to_string())
+            .is_some_and(|external_link| external_link.deleted_at.is_none());
+
+        let link = tables
+            .external_links_users
+            .iter()
+            .find(|link| {
+                is_active_link
+                    && link.external_link_id == req.0.external_link_id
+                    && link.external_user_id == req.0.external_user_id
+                    && tables.is_active_user(&link.user_id.to_string())
+            })
+            .cloned();
+
+        Ok(GetLinkedUserDtoResponse(link))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_link_application")]
+    async fn link_application(
+        &self,
+        req: LinkApplicationDtoRequest,
+    ) -> Result<LinkApplicationDtoResponse, ExternalLinkUseCaseError> {
+        let now = UtcDateTime::now();
+
+        let mut tables = self.db.write();
+        if !tables.is_active_application(&req.0.application_id.to_string()) {
+            return Err(ExternalLinkUseCaseError::ApplicationNotFound());
+        }
+        if !tables.external_links.contains_key(&req.0.external_link_id.to_string()) {
+            error!("Failed to link application: external link not found");
+            return Err(ExternalLinkUseCaseError::DatabaseError(
+                "Failed to link application".to_string(),
+            ));
+        }
+
+        // Linking twice has no effect
+        let is_linked = tables
+            .external_links_applications
+            .iter()
+            .any(|link| link.external_link_id == req.0.external_link_id && link.application_id == req.0.application_id);
+        if !is_linked {
+            tables
+                .external_links_applications
+                .push(ExternalLinkApplicationUseCaseResponse {
+                    external_link_id: req.0.external_link_id.clone(),
+                    application_id: req.0.application_id.clone(),
+                    created_at: now.clone(),
+                });
+        }
+
+        Ok(LinkApplicationDtoResponse(ExternalLinkApplicationUseCaseResponse {
+            external_link_id: req.0.external_link_id,
+            application_id: req.0.application_id,
+            created_at: now,
+        }))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_unlink_application")]
+    async fn unlink_application(
+        &self,
+        req: UnlinkApplicationDtoRequest,
+    ) -> Result<UnlinkApplicationDtoResponse, ExternalLinkUseCaseError> {
+        let mut tables = self.db.write();
+        let total = tables.external_links_applications.len();
+        tables.external_links_applications.retain(|link| {
+            !(link.external_link_id == req.0.external_link_id && link.application_id == req.0.application_id)
+        });
+
+        if tables.external_links_applications.len() == total {
+            return Err(ExternalLinkUseCaseError::ExternalLinkApplicationNotFound())?;
+        }
+
+        Ok(UnlinkApplicationDtoResponse(UnlinkApplicationUseCaseResponse()))
+    }
+}
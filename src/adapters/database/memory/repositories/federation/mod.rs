@@ -0,0 +1,220 @@
+//! Federation in-memory repository
+
+use crate::adapters::database::memory::Db;
+use crate::adapters::database::memory::tables::{Tables, UserRow};
+use crate::domain::entities::user::UserId;
+use crate::domain::repositories::federation::FederationRepository;
+use crate::domain::repositories::federation::dto::{
+    ConsumeLoginStateDtoRequest, ConsumeLoginStateDtoResponse, CreateLoginStateDtoRequest, CreateLoginStateDtoResponse,
+    FindOrCreateUserDtoRequest, FindOrCreateUserDtoResponse, GetProviderDtoRequest, GetProviderDtoResponse,
+};
+use crate::domain::use_cases::external_link::ExternalLinkUserUseCaseResponse;
+use crate::domain::use_cases::federation::FederationUseCaseError;
+use crate::domain::use_cases::user::UserUseCaseResponse;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Federation in-memory repository
+#[derive(Debug, Clone)]
+pub struct FederationMemoryRepository {
+    db: Arc<Db>,
+}
+
+impl FederationMemoryRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+
+    /// Get the user ID of an external user if it is already linked
+    fn get_linked_user(
+        tables: &Tables,
+        req: &FindOrCreateUserDtoRequest,
+    ) -> Result<Option<UserId>, FederationUseCaseError> {
+        let user = tables
+            .external_links_users
+            .iter()
+            .find(|link| link.external_link_id == req.external_link_id && link.external_user_id == req.external_user_id)
+            .and_then(|link| tables.users.get(&link.user_id.to_string()));
+
+        match user {
+            Some(row) if row.user.deleted_at.is_some() => Err(FederationUseCaseError::UserDisabled()),
+            Some(row) => Ok(Some(row.user.id.clone())),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the user with the same email or create it
+    ///
+    /// An invited user is activated with the given password.
+    fn get_or_create_user_by_email(
+        tables: &mut Tables,
+        req: &FindOrCreateUserDtoRequest,
+        now: &UtcDateTime,
+    ) -> Result<UserId, FederationUseCaseError> {
+        let email = req.email.as_ref().ok_or_else(FederationUseCaseError::EmailRequired)?;
+
+        let user = tables.user_by_email(email).map(|row| {
+            (
+                row.user.id.clone(),
+                row.password.is_some(),
+                row.user.deleted_at.is_some(),
+            )
+        });
+
+        let user_id = match user {
+            Some((_, _, true)) => return Err(FederationUseCaseError::UserDisabled()),
+            Some((id, true, false)) => return Ok(id),
+            Some((id, false, false)) => {
+                if let Some(row) = tables.users.get_mut(&id.to_string()) {
+                    row.password = Some(req.password.clone());
+                    row.password_changed_at = now.clone();
+                    row.user.updated_at = now.clone();
+                }
+
+                id
+            }
+            None => {
+                let user_id = Id::new().map_err(|err| {
+                    error!(error = %err, "Failed to create user ID");
+                    FederationUseCaseError::InvalidId()
+                })?;
+                tables.users.insert(
+                    user_id.to_string(),
+                    UserRow {
+                        user: UserUseCaseResponse {
+                            id: user_id.clone(),
+                            email: email.clone(),
+                            lastname: req.lastname.clone(),
+                            firstname: req.firstname.clone(),
+                            locale: None,
+                            created_at: now.clone(),
+                            updated_at: now.clone(),
+                            deleted_at: None,
+                        },
+                        password: Some(req.password.clone()),
+                        password_changed_at: now.clone(),
+                    },
+                );
+
+                user_id
+            }
+        };
+        tables.add_password_history(&user_id, &req.password, now);
+
+        Ok(user_id)
+    }
+}
+
+#[async_trait]
+impl FederationRepository for FederationMemoryRepository {
+    #[instrument(skip(self), name = "federation_repository_get_provider")]
+    async fn get_provider(&self, req: GetProviderDtoRequest) -> Result<GetProviderDtoResponse, FederationUseCaseError> {
+        let tables = self.db.read();
+        let is_linked = tables
+            .external_links_applications
+            .iter()
+            .any(|link| link.external_link_id == req.external_link_id && link.application_id == req.application_id);
+
+        let provider = tables
+            .external_links
+            .get(&req.external_link_id.to_string())
+            .filter(|external_link| {
+                external_link.deleted_at.is_none()
+                    && is_linked
+                    && tables.is_active_application(&req.application_id.to_string())
+            })
+            .and_then(|external_link| external_link.provider.clone());
+
+        Ok(GetProviderDtoResponse(provider))
+    }
+
+    #[instrument(skip(self), name = "federation_repository_create_login_state")]
+    async fn create_login_state(
+        &self,
+        req: CreateLoginStateDtoRequest,
+    ) -> Result<CreateLoginStateDtoResponse, FederationUseCaseError> {
+        let mut tables = self.db.write();
+        if tables.oidc_login_states.contains_key(&req.0.state) {
+            error!("Failed to create login state: state already exists");
+            return Err(FederationUseCaseError::DatabaseError(
+                "Failed to create login state".to_string(),
+            ));
+        }
+
+        tables.oidc_login_states.insert(req.0.state.clone(), req.0);
+
+        Ok(CreateLoginStateDtoResponse())
+    }
+
+    #[instrument(skip(self), name = "federation_repository_consume_login_state")]
+    async fn consume_login_state(
+        &self,
+        req: ConsumeLoginStateDtoRequest,
+    ) -> Result<ConsumeLoginStateDtoResponse, FederationUseCaseError> {
+        let now = UtcDateTime::now();
+
+        let mut tables = self.db.write();
+        let login_state = tables
+            .oidc_login_states
+            .remove(&req.state)
+            .filter(|login_state| login_state.expired_at >= now);
+
+        // The state can only be used once, expired states are removed at the same time
+        tables
+            .oidc_login_states
+            .retain(|_, login_state| login_state.expired_at >= now);
+
+        Ok(ConsumeLoginStateDtoResponse(login_state))
+    }
+
+    #[instrument(skip(self), name = "federation_repository_find_or_create_user")]
+    async fn find_or_create_user(
+        &self,
+        req: FindOrCreateUserDtoRequest,
+    ) -> Result<FindOrCreateUserDtoResponse, FederationUseCaseError> {
+        let now = UtcDateTime::now();
+
+        let mut tables = self.db.write();
+        if let Some(user_id) = Self::get_linked_user(&tables, &req)? {
+            return Ok(FindOrCreateUserDtoResponse(user_id));
+        }
+
+        // Checked before any change, like the rollback of the SQL transaction
+        if !tables.external_links.contains_key(&req.external_link_id.to_string()) {
+            error!("Failed to link external user: external link not found");
+            return Err(FederationUseCaseError::DatabaseError(
+                "Failed to link external user".to_string(),
+            ));
+        }
+        let linked_user = req
+            .email
+            .as_ref()
+            .and_then(|email| tables.user_by_email(email))
+            .filter(|row| {
+                tables
+                    .external_links_users
+                    .iter()
+                    .any(|link| link.external_link_id == req.external_link_id && link.user_id == row.user.id)
+            });
+        match linked_user {
+            Some(row) if row.user.deleted_at.is_some() => return Err(FederationUseCaseError::UserDisabled()),
+            Some(_) => return Err(FederationUseCaseError::UserAlreadyLinked()),
+            None => {}
+        }
+
+        let user_id = Self::get_or_create_user_by_email(&mut tables, &req, &now)?;
+
+        // Link the external user
+        tables.external_links_users.push(ExternalLinkUserUseCaseResponse {
+            external_link_id: req.external_link_id,
+            user_id: user_id.clone(),
+            external_user_id: req.external_user_id,
+            created_at: now,
+        });
+
+        Ok(FindOrCreateUserDtoResponse(user_id))
+    }
+}
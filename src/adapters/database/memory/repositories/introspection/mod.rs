@@ -0,0 +1,100 @@
+//! Introspection in-memory repository
+
+use crate::adapters::database::memory::Db;
+use crate::domain::repositories::introspection::IntrospectionRepository;
+use crate::domain::repositories::introspection::dto::{
+    ClientCredentials, GetClientCredentialsDtoRequest, GetClientCredentialsDtoResponse, GetUserScopesDtoRequest,
+    GetUserScopesDtoResponse, IsAccessTokenActiveDtoRequest, IsAccessTokenActiveDtoResponse,
+};
+use crate::domain::use_cases::introspection::IntrospectionUseCaseError;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Introspection in-memory repository
+#[derive(Debug, Clone)]
+pub struct IntrospectionMemoryRepository {
+    db: Arc<Db>,
+}
+
+impl IntrospectionMemoryRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl IntrospectionRepository for IntrospectionMemoryRepository {
+    #[instrument(skip(self), name = "introspection_repository_get_client_credentials")]
+    async fn get_client_credentials(
+        &self,
+        req: GetClientCredentialsDtoRequest,
+    ) -> Result<GetClientCredentialsDtoResponse, IntrospectionUseCaseError> {
+        let tables = self.db.read();
+        let Some(client) = tables.clients.get(&req.0.to_string()).filter(|client| {
+            client.deleted_at.is_none() && tables.is_active_application(&client.application_id.to_string())
+        }) else {
+            return Ok(GetClientCredentialsDtoResponse(None));
+        };
+
+        let secrets = tables
+            .client_secrets
+            .values()
+            .filter(|row| row.secret.client_id == client.id)
+            .map(|row| row.value.clone())
+            .collect::<Vec<_>>();
+        if secrets.is_empty() {
+            return Ok(GetClientCredentialsDtoResponse(None));
+        }
+
+        Ok(GetClientCredentialsDtoResponse(Some(ClientCredentials {
+            client_id: req.0,
+            application_id: client.application_id.clone(),
+            secrets,
+        })))
+    }
+
+    #[instrument(skip(self, req), name = "introspection_repository_is_access_token_active")]
+    async fn is_access_token_active(
+        &self,
+        req: IsAccessTokenActiveDtoRequest,
+    ) -> Result<IsAccessTokenActiveDtoResponse, IntrospectionUseCaseError> {
+        let now = UtcDateTime::now();
+
+        // An access token is revoked as soon as its refresh token is used, deleted or expired
+        let tables = self.db.read();
+        let is_user_active = tables
+            .users
+            .get(&req.user_id.to_string())
+            .is_some_and(|row| row.user.deleted_at.is_none() && row.password.is_some());
+        let is_active = is_user_active
+            && tables
+                .refresh_tokens
+                .values()
+                .any(|row| row.user_id == req.user_id && row.access_token == req.access_token && row.expired_at >= now);
+
+        Ok(IsAccessTokenActiveDtoResponse(is_active))
+    }
+
+    #[instrument(skip(self), name = "introspection_repository_get_user_scopes")]
+    async fn get_user_scopes(
+        &self,
+        req: GetUserScopesDtoRequest,
+    ) -> Result<GetUserScopesDtoResponse, IntrospectionUseCaseError> {
+        let user_id = req.user_id.to_string();
+
+        let tables = self.db.read();
+        let mut scopes = tables
+            .users_scopes
+            .iter()
+            .filter(|row| row.user_id == user_id)
+            .filter_map(|row| tables.scopes.get(&row.scope_id))
+            .filter(|scope| scope.application_id == req.application_id && scope.deleted_at.is_none())
+            .map(|scope| scope.id.clone())
+            .collect::<Vec<_>>();
+        scopes.sort_by_key(|scope| scope.to_string());
+
+        Ok(GetUserScopesDtoResponse(scopes))
+    }
+}
@@ -0,0 +1,403 @@
+//! Invitation in-memory repository
+
+use crate::adapters::database::memory::tables::{InvitationRow, UserRow, UserScopeRow};
+use crate::adapters::database::memory::{Db, MemoryCursorPagination, MemoryQuerySorts, MemoryRow, MemoryValue};
+use crate::domain::repositories::invitation::InvitationRepository;
+use crate::domain::repositories::invitation::dto::{
+    AcceptInvitationDtoRequest, AcceptInvitationDtoResponse, CountInvitationsDtoRequest, CountInvitationsDtoResponse,
+    CreateInvitationDtoRequest, CreateInvitationDtoResponse, GetInvitationByIdDtoRequest, GetInvitationByIdDtoResponse,
+    GetInvitationsDtoRequest, GetInvitationsDtoResponse, RenewInvitationDtoRequest, RenewInvitationDtoResponse,
+    RevokeInvitationDtoRequest, RevokeInvitationDtoResponse,
+};
+use crate::domain::use_cases::invitation::revoke_invitation::RevokeInvitationUseCaseResponse;
+use crate::domain::use_cases::invitation::{InvitationUseCaseError, InvitationUseCaseResponse};
+use crate::domain::use_cases::user::UserUseCaseResponse;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Fields available for sorting the invitations
+const SORT_FIELDS: [&str; 6] = [
+    "email",
+    "lastname",
+    "firstname",
+    "expired_at",
+    "created_at",
+    "updated_at",
+];
+
+/// Invitation joined with its pending user
+struct InvitationUserRow<'a> {
+    invitation: &'a InvitationRow,
+    user: &'a UserUseCaseResponse,
+}
+
+impl InvitationUserRow<'_> {
+    fn to_response(&self) -> InvitationUseCaseResponse {
+        InvitationUseCaseResponse {
+            id: self.invitation.id.clone(),
+            user_id: self.invitation.user_id.clone(),
+            email: self.user.email.clone(),
+            lastname: self.user.lastname.clone(),
+            firstname: self.user.firstname.clone(),
+            locale: self.user.locale.clone(),
+            expired_at: self.invitation.expired_at.clone(),
+            created_at: self.invitation.created_at.clone(),
+            updated_at: self.invitation.updated_at.clone(),
+        }
+    }
+}
+
+impl MemoryRow for InvitationUserRow<'_> {
+    fn id(&self) -> String {
+        self.invitation.id.to_string()
+    }
+
+    fn field(&self, name: &str) -> Option<MemoryValue> {
+        match name {
+            "email" => Some(MemoryValue::Text(self.user.email.to_string())),
+            "lastname" => Some(MemoryValue::from(self.user.lastname.as_str())),
+            "firstname" => Some(MemoryValue::from(self.user.firstname.as_str())),
+            "expired_at" => Some(MemoryValue::from(&self.invitation.expired_at)),
+            "created_at" => Some(MemoryValue::from(&self.invitation.created_at)),
+            "updated_at" => Some(MemoryValue::from(&self.invitation.updated_at)),
+            _ => None,
+        }
+    }
+}
+
+/// Invitation in-memory repository
+#[derive(Debug, Clone)]
+pub struct InvitationMemoryRepository {
+    db: Arc<Db>,
+}
+
+impl InvitationMemoryRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl InvitationRepository for InvitationMemoryRepository {
+    #[instrument(skip(self, req), name = "invitation_repository_create")]
+    async fn create(
+        &self,
+        req: CreateInvitationDtoRequest,
+    ) -> Result<CreateInvitationDtoResponse, InvitationUseCaseError> {
+        let invitation_id = Id::new().map_err(|err| {
+            error!(error = %err, "Failed to create invitation ID");
+            InvitationUseCaseError::InvalidId()
+        })?;
+        let user_id = req.invitation.user_id.clone();
+        let now = UtcDateTime::now();
+
+        let mut tables = self.db.write();
+        if tables.user_by_email(&req.email).is_some() {
+            error!("Failed to create pending user: email already used");
+            return Err(InvitationUseCaseError::DatabaseError(
+                "Invitation creation error".to_string(),
+            ));
+        }
+        if let Some(scope) = req
+            .scopes
+            .iter()
+            .find(|scope| !tables.scopes.contains_key(&scope.to_string()))
+        {
+            error!(scope = %scope, "Failed to add scope to pending user");
+            return Err(InvitationUseCaseError::DatabaseError(
+                "Invitation creation error".to_string(),
+            ));
+        }
+
+        tables.enqueue_email(&req.message).map_err(|err| {
+            error!(error = %err, "Failed to queue invitation email");
+            InvitationUseCaseError::DatabaseError("Invitation creation error".to_string())
+        })?;
+
+        // Create pending user (without password)
+        tables.users.insert(
+            user_id.to_string(),
+            UserRow {
+                user: UserUseCaseResponse {
+                    id: user_id.clone(),
+                    email: req.email.clone(),
+                    lastname: req.lastname.clone(),
+                    firstname: req.firstname.clone(),
+                    locale: req.locale.clone(),
+                    created_at: now.clone(),
+                    updated_at: now.clone(),
+                    deleted_at: None,
+                },
+                password: None,
+                password_changed_at: now.clone(),
+            },
+        );
+
+        // Add user scopes
+        for scope in &req.scopes {
+            let row = UserScopeRow {
+                user_id: user_id.to_string(),
+                scope_id: scope.to_string(),
+            };
+            if !tables.users_scopes.contains(&row) {
+                tables.users_scopes.push(row);
+            }
+        }
+
+        // Create invitation
+        tables.invitations.insert(
+            invitation_id.to_string(),
+            InvitationRow {
+                id: invitation_id.clone(),
+                user_id: user_id.clone(),
+                token: req.invitation.token,
+                expired_at: req.invitation.expired_at.clone(),
+                created_at: now.clone(),
+                updated_at: now.clone(),
+            },
+        );
+
+        Ok(CreateInvitationDtoResponse(InvitationUseCaseResponse {
+            id: invitation_id,
+            user_id,
+            email: req.email,
+            lastname: req.lastname,
+            firstname: req.firstname,
+            locale: req.locale,
+            expired_at: req.invitation.expired_at,
+            created_at: now.clone(),
+            updated_at: now,
+        }))
+    }
+
+    #[instrument(skip(self), name = "invitation_repository_count")]
+    async fn count_invitations(
+        &self,
+        _req: CountInvitationsDtoRequest,
+    ) -> Result<CountInvitationsDtoResponse, InvitationUseCaseError> {
+        let tables = self.db.read();
+        let total = tables
+            .invitations
+            .values()
+            .filter(|invitation| tables.is_active_user(&invitation.user_id.to_string()))
+            .count();
+
+        Ok(CountInvitationsDtoResponse(total as i64))
+    }
+
+    #[instrument(skip(self), name = "invitation_repository_get_all")]
+    async fn get_invitations(
+        &self,
+        req: GetInvitationsDtoRequest,
+    ) -> Result<GetInvitationsDtoResponse, InvitationUseCaseError> {
+        let sorts = MemoryQuerySorts(req.0.sorts.unwrap_or_default());
+        let pagination = MemoryCursorPagination::new(req.0.pagination, &sorts, &SORT_FIELDS);
+
+        let tables = self.db.read();
+        let rows = tables
+            .invitations
+            .values()
+            .filter_map(|invitation| {
+                tables
+                    .users
+                    .get(&invitation.user_id.to_string())
+                    .filter(|row| row.user.deleted_at.is_none())
+                    .map(|row| InvitationUserRow {
+                        invitation,
+                        user: &row.user,
+                    })
+            })
+            .collect::<Vec<_>>();
+        let (invitations, next_cursor) = pagination.page(rows);
+
+        Ok(GetInvitationsDtoResponse {
+            invitations: invitations.iter().map(InvitationUserRow::to_response).collect(),
+            next_cursor,
+        })
+    }
+
+    #[instrument(skip(self), name = "invitation_repository_get_by_id")]
+    async fn get_by_id(
+        &self,
+        req: GetInvitationByIdDtoRequest,
+    ) -> Result<GetInvitationByIdDtoResponse, InvitationUseCaseError> {
+        let tables = self.db.read();
+        let invitation = tables
+            .invitations
+            .get(&req.id.to_string())
+            .and_then(|invitation| {
+                tables
+                    .users
+                    .get(&invitation.user_id.to_string())
+                    .filter(|row| row.user.deleted_at.is_none())
+                    .map(|row| {
+                        InvitationUserRow {
+                            invitation,
+                            user: &row.user,
+                        }
+                        .to_response()
+                    })
+            })
+            .ok_or_else(InvitationUseCaseError::InvitationNotFound)?;
+
+        Ok(GetInvitationByIdDtoResponse(invitation))
+    }
+
+    #[instrument(skip(self, req), name = "invitation_repository_renew")]
+    async fn renew(
+        &self,
+        req: RenewInvitationDtoRequest,
+    ) -> Result<RenewInvitationDtoResponse, InvitationUseCaseError> {
+        let mut tables = self.db.write();
+        if !tables.invitations.contains_key(&req.id.to_string()) {
+            return Err(InvitationUseCaseError::InvitationNotFound())?;
+        }
+
+        tables.enqueue_email(&req.message).map_err(|err| {
+            error!(error = %err, "Failed to queue invitation email");
+            InvitationUseCaseError::DatabaseError("Failed to renew invitation".to_string())
+        })?;
+
+        if let Some(invitation) = tables.invitations.get_mut(&req.id.to_string()) {
+            invitation.token = req.invitation.token;
+            invitation.expired_at = req.invitation.expired_at;
+            invitation.updated_at = UtcDateTime::now();
+        }
+
+        Ok(RenewInvitationDtoResponse())
+    }
+
+    #[instrument(skip(self), name = "invitation_repository_revoke")]
+    async fn revoke(
+        &self,
+        req: RevokeInvitationDtoRequest,
+    ) -> Result<RevokeInvitationDtoResponse, InvitationUseCaseError> {
+        let mut tables = self.db.write();
+
+        // Only a pending user (without password) is deleted, with its invitation and scopes
+        let user_id = tables
+            .invitations
+            .get(&req.0.id.to_string())
+            .map(|invitation| invitation.user_id.to_string())
+            .filter(|user_id| tables.users.get(user_id).is_some_and(|row| row.password.is_none()))
+            .ok_or_else(InvitationUseCaseError::InvitationNotFound)?;
+
+        tables.delete_user(&user_id);
+
+        Ok(RevokeInvitationDtoResponse(RevokeInvitationUseCaseResponse()))
+    }
+
+    #[instrument(skip(self, req), name = "invitation_repository_accept")]
+    async fn accept(
+        &self,
+        req: AcceptInvitationDtoRequest,
+    ) -> Result<AcceptInvitationDtoResponse, InvitationUseCaseError> {
+        let now = UtcDateTime::now();
+
+        let mut tables = self.db.write();
+        let user_id = tables
+            .invitations
+            .values()
+            .find(|invitation| invitation.token == req.0.token && invitation.expired_at >= now)
+            .map(|invitation| invitation.user_id.clone())
+            .ok_or_else(InvitationUseCaseError::InvitationNotFound)?;
+
+        // Set user password
+        let row = tables
+            .users
+            .get_mut(&user_id.to_string())
+            .filter(|row| row.user.deleted_at.is_none())
+            .ok_or_else(InvitationUseCaseError::InvitationNotFound)?;
+        row.password = Some(req.0.password.clone());
+        row.password_changed_at = now.clone();
+        row.user.updated_at = now.clone();
+
+        // Add password to history
+        tables.add_password_history(&user_id, &req.0.password, &now);
+
+        // The token can only be used once
+        tables.invitations.retain(|_, invitation| invitation.user_id != user_id);
+
+        Ok(AcceptInvitationDtoResponse { user_id })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::audit_event::AuditContext;
+    use crate::domain::entities::email::EmailMessage;
+    use crate::domain::entities::invitation::Invitation;
+    use crate::domain::use_cases::invitation::accept_invitation::AcceptInvitationUseCaseRequest;
+    use crate::domain::use_cases::invitation::revoke_invitation::RevokeInvitationUseCaseRequest;
+    use crate::domain::value_objects::email::Email;
+    use crate::domain::value_objects::password::Password;
+
+    async fn create_invitation(repository: &InvitationMemoryRepository) -> (InvitationUseCaseResponse, Invitation) {
+        let invitation = Invitation::new(Id::new().unwrap(), 24);
+        let response = repository
+            .create(CreateInvitationDtoRequest {
+                email: Email::new("john.doe@test.com").unwrap(),
+                lastname: "Doe".to_string(),
+                firstname: "John".to_string(),
+                scopes: vec![],
+                locale: None,
+                invitation: invitation.clone(),
+                message: EmailMessage::default(),
+            })
+            .await
+            .unwrap()
+            .0;
+
+        (response, invitation)
+    }
+
+    #[tokio::test]
+    async fn test_accept_invitation() {
+        let db = Db::default();
+        let repository = InvitationMemoryRepository::new(db.clone());
+        let (response, invitation) = create_invitation(&repository).await;
+        assert_eq!(db.read().email_outbox.len(), 1);
+
+        let accept = || {
+            repository.accept(AcceptInvitationDtoRequest(AcceptInvitationUseCaseRequest {
+                token: invitation.token.clone(),
+                password: Password::new("$argon2id$hash", true).unwrap(),
+                audit: AuditContext::default(),
+            }))
+        };
+        assert!(accept().await.is_ok());
+        assert_eq!(Some(InvitationUseCaseError::InvitationNotFound()), accept().await.err());
+
+        // An activated user is not deleted by a revocation
+        let result = repository
+            .revoke(RevokeInvitationDtoRequest(RevokeInvitationUseCaseRequest {
+                id: response.id,
+            }))
+            .await;
+        assert_eq!(Some(InvitationUseCaseError::InvitationNotFound()), result.err());
+        assert!(db.read().users[&response.user_id.to_string()].password.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_revoke_invitation() {
+        let db = Db::default();
+        let repository = InvitationMemoryRepository::new(db.clone());
+        let (response, _) = create_invitation(&repository).await;
+
+        repository
+            .revoke(RevokeInvitationDtoRequest(RevokeInvitationUseCaseRequest {
+                id: response.id.clone(),
+            }))
+            .await
+            .unwrap();
+        assert!(db.read().users.is_empty());
+        assert!(db.read().invitations.is_empty());
+
+        // The email can be used again
+        create_invitation(&repository).await;
+    }
+}
@@ -0,0 +1,77 @@
+//! Magic link in-memory repository
+
+use crate::adapters::database::memory::Db;
+use crate::domain::repositories::magic_link::MagicLinkRepository;
+use crate::domain::repositories::magic_link::dto::{
+    ConsumeMagicLinkDtoRequest, ConsumeMagicLinkDtoResponse, CreateUpdateMagicLinkDtoRequest,
+    CreateUpdateMagicLinkDtoResponse,
+};
+use crate::domain::use_cases::user::UserUseCaseError;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Magic link in-memory repository
+#[derive(Debug, Clone)]
+pub struct MagicLinkMemoryRepository {
+    db: Arc<Db>,
+}
+
+impl MagicLinkMemoryRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl MagicLinkRepository for MagicLinkMemoryRepository {
+    #[instrument(skip(self, req), name = "magic_link_repository_create_update")]
+    async fn create_or_update(
+        &self,
+        req: CreateUpdateMagicLinkDtoRequest,
+    ) -> Result<CreateUpdateMagicLinkDtoResponse, UserUseCaseError> {
+        let magic_link = req.magic_link;
+
+        let mut tables = self.db.write();
+        let token_used = tables
+            .magic_links
+            .values()
+            .any(|link| link.token == magic_link.token && link.user_id != magic_link.user_id);
+        if !tables.users.contains_key(&magic_link.user_id.to_string()) || token_used {
+            error!("Failed to create or update magic link: unknown user or token already used");
+            return Err(UserUseCaseError::DatabaseError(
+                "Failed to create or update magic link".to_string(),
+            ));
+        }
+
+        tables.enqueue_email(&req.message).map_err(|err| {
+            error!(error = %err, "Failed to queue magic link email");
+            UserUseCaseError::DatabaseError("Failed to queue magic link email".to_string())
+        })?;
+
+        // Only one magic link by user
+        tables.magic_links.insert(magic_link.user_id.to_string(), magic_link);
+
+        Ok(CreateUpdateMagicLinkDtoResponse())
+    }
+
+    #[instrument(skip(self), name = "magic_link_repository_consume")]
+    async fn consume(&self, req: ConsumeMagicLinkDtoRequest) -> Result<ConsumeMagicLinkDtoResponse, UserUseCaseError> {
+        let now = UtcDateTime::now();
+
+        let mut tables = self.db.write();
+        let user_id = tables
+            .magic_links
+            .values()
+            .find(|link| {
+                link.token == req.token && link.expired_at >= now && tables.is_active_user(&link.user_id.to_string())
+            })
+            .map(|link| link.user_id.clone());
+
+        // The token can only be used once
+        tables.magic_links.retain(|_, link| link.token != req.token);
+
+        Ok(ConsumeMagicLinkDtoResponse { user_id })
+    }
+}
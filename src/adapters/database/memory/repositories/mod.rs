@@ -0,0 +1,106 @@
+//! In-memory repositories implementation
+
+use crate::adapters::database::DatabaseRepositories;
+use crate::adapters::database::memory::Db;
+use application::ApplicationMemoryRepository;
+use audit::AuditMemoryRepository;
+use client::ClientMemoryRepository;
+use email::EmailMemoryRepository;
+use external_link::ExternalLinkMemoryRepository;
+use federation::FederationMemoryRepository;
+use introspection::IntrospectionMemoryRepository;
+use invitation::InvitationMemoryRepository;
+use magic_link::MagicLinkMemoryRepository;
+use password_reset::PasswordResetMemoryRepository;
+use refresh_token::RefreshTokenMemoryRepository;
+use scope::ScopeMemoryRepository;
+use user::UserMemoryRepository;
+use webhook::WebhookMemoryRepository;
+
+pub mod application;
+pub mod audit;
+pub mod client;
+pub mod email;
+pub mod external_link;
+pub mod federation;
+pub mod introspection;
+pub mod invitation;
+pub mod magic_link;
+pub mod password_reset;
+pub mod refresh_token;
+pub mod scope;
+pub mod user;
+pub mod webhook;
+
+impl DatabaseRepositories for Db {
+    type User = UserMemoryRepository;
+    type RefreshToken = RefreshTokenMemoryRepository;
+    type PasswordReset = PasswordResetMemoryRepository;
+    type MagicLink = MagicLinkMemoryRepository;
+    type Application = ApplicationMemoryRepository;
+    type Client = ClientMemoryRepository;
+    type Scope = ScopeMemoryRepository;
+    type ExternalLink = ExternalLinkMemoryRepository;
+    type Invitation = InvitationMemoryRepository;
+    type Introspection = IntrospectionMemoryRepository;
+    type Federation = FederationMemoryRepository;
+    type Audit = AuditMemoryRepository;
+    type Webhook = WebhookMemoryRepository;
+    type Email = EmailMemoryRepository;
+
+    fn user_repository(&self) -> Self::User {
+        UserMemoryRepository::new(self.clone())
+    }
+
+    fn refresh_token_repository(&self) -> Self::RefreshToken {
+        RefreshTokenMemoryRepository::new(self.clone())
+    }
+
+    fn password_reset_repository(&self) -> Self::PasswordReset {
+        PasswordResetMemoryRepository::new(self.clone())
+    }
+
+    fn magic_link_repository(&self) -> Self::MagicLink {
+        MagicLinkMemoryRepository::new(self.clone())
+    }
+
+    fn application_repository(&self) -> Self::Application {
+        ApplicationMemoryRepository::new(self.clone())
+    }
+
+    fn client_repository(&self) -> Self::Client {
+        ClientMemoryRepository::new(self.clone())
+    }
+
+    fn scope_repository(&self) -> Self::Scope {
+        ScopeMemoryRepository::new(self.clone())
+    }
+
+    fn external_link_repository(&self) -> Self::ExternalLink {
+        ExternalLinkMemoryRepository::new(self.clone())
+    }
+
+    fn invitation_repository(&self) -> Self::Invitation {
+        InvitationMemoryRepository::new(self.clone())
+    }
+
+    fn introspection_repository(&self) -> Self::Introspection {
+        IntrospectionMemoryRepository::new(self.clone())
+    }
+
+    fn federation_repository(&self) -> Self::Federation {
+        FederationMemoryRepository::new(self.clone())
+    }
+
+    fn audit_repository(&self) -> Self::Audit {
+        AuditMemoryRepository::new(self.clone())
+    }
+
+    fn webhook_repository(&self) -> Self::Webhook {
+        WebhookMemoryRepository::new(self.clone())
+    }
+
+    fn email_repository(&self) -> Self::Email {
+        EmailMemoryRepository::new(self.clone())
+    }
+}
@@ -0,0 +1,89 @@
+//! Password reset in-memory repository
+
+use crate::adapters::database::memory::Db;
+use crate::domain::repositories::password_reset::PasswordResetRepository;
+use crate::domain::repositories::password_reset::dto::{
+    CreateUpdatePasswordResetDtoRequest, CreateUpdatePasswordResetDtoResponse, DeletePasswordResetDtoRequest,
+    DeletePasswordResetDtoResponse, GetUserIdFromTokenDtoRequest, GetUserIdFromTokenDtoResponse,
+};
+use crate::domain::use_cases::user::UserUseCaseError;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Password reset in-memory repository
+#[derive(Debug, Clone)]
+pub struct PasswordResetMemoryRepository {
+    db: Arc<Db>,
+}
+
+impl PasswordResetMemoryRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl PasswordResetRepository for PasswordResetMemoryRepository {
+    #[instrument(skip(self, req), name = "password_reset_repository_create_update")]
+    async fn create_or_update(
+        &self,
+        req: CreateUpdatePasswordResetDtoRequest,
+    ) -> Result<CreateUpdatePasswordResetDtoResponse, UserUseCaseError> {
+        let password_reset = req.password_reset;
+
+        let mut tables = self.db.write();
+        if !tables.users.contains_key(&password_reset.user_id.to_string()) {
+            error!("Failed to create password reset: unknown user");
+            return Err(UserUseCaseError::DatabaseError(
+                "Failed to create password reset".to_string(),
+            ));
+        }
+
+        if let Some(message) = &req.message {
+            tables.enqueue_email(message).map_err(|err| {
+                error!(error = %err, "Failed to queue forgotten password email");
+                UserUseCaseError::DatabaseError("Failed to queue forgotten password email".to_string())
+            })?;
+        }
+
+        // Only one password reset by user
+        tables
+            .password_resets
+            .insert(password_reset.user_id.to_string(), password_reset);
+
+        Ok(CreateUpdatePasswordResetDtoResponse())
+    }
+
+    #[instrument(skip(self), name = "password_reset_repository_get_user")]
+    async fn get_user_from_token(
+        &self,
+        req: GetUserIdFromTokenDtoRequest,
+    ) -> Result<GetUserIdFromTokenDtoResponse, UserUseCaseError> {
+        let now = UtcDateTime::now();
+
+        let tables = self.db.read();
+        let user_id = tables
+            .password_resets
+            .values()
+            .find(|password_reset| {
+                password_reset.token == req.token
+                    && password_reset.expired_at >= now
+                    && tables.is_active_user(&password_reset.user_id.to_string())
+            })
+            .map(|password_reset| password_reset.user_id.clone());
+
+        Ok(GetUserIdFromTokenDtoResponse { user_id })
+    }
+
+    #[instrument(skip(self), name = "password_reset_repository_delete")]
+    async fn delete(
+        &self,
+        req: DeletePasswordResetDtoRequest,
+    ) -> Result<DeletePasswordResetDtoResponse, UserUseCaseError> {
+        self.db.write().password_resets.remove(&req.user_id.to_string());
+
+        Ok(DeletePasswordResetDtoResponse())
+    }
+}
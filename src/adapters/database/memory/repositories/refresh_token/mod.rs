@@ -0,0 +1,129 @@
+//! Refresh token in-memory repository
+
+use crate::adapters::database::memory::Db;
+use crate::adapters::database::memory::tables::RefreshTokenRow;
+use crate::domain::repositories::refresh_token::RefreshTokenRepository;
+use crate::domain::repositories::refresh_token::dto::{
+    CreateRefreshTokenDtoRequest, CreateRefreshTokenDtoResponse, DeleteExpiredRefreshTokensDtoRequest,
+    DeleteExpiredRefreshTokensDtoResponse, DeleteRefreshTokenDtoRequest, DeleteRefreshTokenDtoResponse,
+    DeleteUserRefreshTokensDtoRequest, DeleteUserRefreshTokensDtoResponse, GetRefreshTokenDtoRequest,
+    GetRefreshTokenDtoResponse,
+};
+use crate::domain::use_cases::user::UserUseCaseError;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Refresh token in-memory repository
+#[derive(Debug, Clone)]
+pub struct RefreshTokenMemoryRepository {
+    db: Arc<Db>,
+}
+
+impl RefreshTokenMemoryRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl RefreshTokenRepository for RefreshTokenMemoryRepository {
+    #[instrument(skip(self), name = "refresh_token_repository_create")]
+    async fn create_refresh_token(
+        &self,
+        req: CreateRefreshTokenDtoRequest,
+    ) -> Result<CreateRefreshTokenDtoResponse, UserUseCaseError> {
+        let mut tables = self.db.write();
+        let refresh_token = req.refresh_token.refresh_token.to_string();
+        if !tables.users.contains_key(&req.user_id.to_string())
+            || !tables.applications.contains_key(&req.application_id.to_string())
+            || tables.refresh_tokens.contains_key(&refresh_token)
+        {
+            error!("Failed to create refresh token: unknown user or application, or refresh token already used");
+            return Err(UserUseCaseError::DatabaseError(
+                "Failed to create refresh token".to_string(),
+            ));
+        }
+
+        tables.refresh_tokens.insert(
+            refresh_token,
+            RefreshTokenRow {
+                user_id: req.user_id,
+                application_id: req.application_id,
+                access_token: req.access_token.token,
+                expired_at: req.refresh_token.expired_at,
+            },
+        );
+
+        Ok(CreateRefreshTokenDtoResponse())
+    }
+
+    /// Get a refresh token
+    #[instrument(skip(self), name = "refresh_token_repository_get")]
+    async fn get_refresh_token(
+        &self,
+        req: GetRefreshTokenDtoRequest,
+    ) -> Result<GetRefreshTokenDtoResponse, UserUseCaseError> {
+        let now = UtcDateTime::now();
+
+        self.db
+            .read()
+            .refresh_tokens
+            .get(&req.0.to_string())
+            .filter(|row| row.expired_at >= now)
+            .map(|row| GetRefreshTokenDtoResponse {
+                user_id: row.user_id.clone(),
+                application_id: row.application_id.clone(),
+            })
+            .ok_or_else(UserUseCaseError::InvalidRefreshToken)
+    }
+
+    /// Delete a refresh token
+    #[instrument(skip(self), name = "refresh_token_repository_delete")]
+    async fn delete_refresh_token(
+        &self,
+        req: DeleteRefreshTokenDtoRequest,
+    ) -> Result<DeleteRefreshTokenDtoResponse, UserUseCaseError> {
+        self.db.write().refresh_tokens.remove(&req.0.to_string());
+
+        Ok(DeleteRefreshTokenDtoResponse())
+    }
+
+    /// Delete expired refresh tokens
+    #[instrument(skip(self), name = "refresh_token_repository_delete_expired")]
+    async fn delete_expired_refresh_tokens(
+        &self,
+        _req: DeleteExpiredRefreshTokensDtoRequest,
+    ) -> Result<DeleteExpiredRefreshTokensDtoResponse, UserUseCaseError> {
+        let now = UtcDateTime::now();
+
+        let mut tables = self.db.write();
+        let total = tables.refresh_tokens.len();
+        tables.refresh_tokens.retain(|_, row| row.expired_at >= now);
+
+        Ok(DeleteExpiredRefreshTokensDtoResponse {
+            deleted: (total - tables.refresh_tokens.len()) as u64,
+        })
+    }
+
+    /// Delete all the refresh tokens of a user
+    #[instrument(skip(self), name = "refresh_token_repository_delete_user_refresh_tokens")]
+    async fn delete_user_refresh_tokens(
+        &self,
+        req: DeleteUserRefreshTokensDtoRequest,
+    ) -> Result<DeleteUserRefreshTokensDtoResponse, UserUseCaseError> {
+        let mut tables = self.db.write();
+        tables.enqueue_webhook_message(&req.message).map_err(|err| {
+            error!(error = %err, "Failed to enqueue webhook message");
+            UserUseCaseError::DatabaseError("Failed to delete user refresh tokens".to_string())
+        })?;
+
+        let total = tables.refresh_tokens.len();
+        tables.refresh_tokens.retain(|_, row| row.user_id != req.user_id);
+
+        Ok(DeleteUserRefreshTokensDtoResponse {
+            deleted: (total - tables.refresh_tokens.len()) as u64,
+        })
+    }
+}
@@ -0,0 +1,163 @@
+//! Scope in-memory repository
+
+use crate::adapters::database::memory::{
+    Db, MemoryCursorPagination, MemoryFilterField, MemoryFilterType, MemoryQueryFilters, MemoryQuerySorts, MemoryRow,
+    MemoryValue,
+};
+use crate::domain::repositories::scope::ScopeRepository;
+use crate::domain::repositories::scope::dto::{
+    CountScopesDtoRequest, CountScopesDtoResponse, CreateScopeDtoRequest, CreateScopeDtoResponse,
+    DeleteScopeDtoRequest, DeleteScopeDtoResponse, GetScopesDtoRequest, GetScopesDtoResponse, RestoreScopeDtoRequest,
+    RestoreScopeDtoResponse,
+};
+use crate::domain::use_cases::scope::delete_scope::DeleteScopeUseCaseResponse;
+use crate::domain::use_cases::scope::restore_scope::RestoreScopeUseCaseResponse;
+use crate::domain::use_cases::scope::{ScopeUseCaseError, ScopeUseCaseResponse};
+use crate::domain::value_objects::datetime::UtcDateTime;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Fields available for filtering the scopes
+const FILTER_FIELDS: [MemoryFilterField; 4] = [
+    ("id", MemoryFilterType::Text),
+    ("created_at", MemoryFilterType::Datetime),
+    ("updated_at", MemoryFilterType::Datetime),
+    ("deleted_at", MemoryFilterType::Datetime),
+];
+
+/// Fields available for sorting the scopes
+const SORT_FIELDS: [&str; 4] = ["id", "created_at", "updated_at", "deleted_at"];
+
+impl MemoryRow for ScopeUseCaseResponse {
+    fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn field(&self, name: &str) -> Option<MemoryValue> {
+        match name {
+            "id" => Some(MemoryValue::Text(self.id.to_string())),
+            "created_at" => Some(MemoryValue::from(&self.created_at)),
+            "updated_at" => Some(MemoryValue::from(&self.updated_at)),
+            "deleted_at" => self.deleted_at.as_ref().map(MemoryValue::from),
+            _ => None,
+        }
+    }
+}
+
+/// Scope in-memory repository
+#[derive(Debug, Clone)]
+pub struct ScopeMemoryRepository {
+    db: Arc<Db>,
+}
+
+impl ScopeMemoryRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+
+    /// Check if a scope is in the list
+    fn is_listed(scope: &ScopeUseCaseResponse, deleted: bool, application_id: Option<&str>) -> bool {
+        scope.deleted_at.is_some() == deleted
+            && application_id.is_none_or(|application_id| scope.application_id.to_string() == application_id)
+    }
+}
+
+#[async_trait]
+impl ScopeRepository for ScopeMemoryRepository {
+    #[instrument(skip(self), name = "scope_repository_create")]
+    async fn create(&self, req: CreateScopeDtoRequest) -> Result<CreateScopeDtoResponse, ScopeUseCaseError> {
+        let now = UtcDateTime::now();
+
+        let mut tables = self.db.write();
+        if tables.scopes.contains_key(&req.0.id.to_string()) {
+            error!("Failed to create scope: the ID already exists");
+            return Err(ScopeUseCaseError::IdAlreadyExists());
+        }
+        if !tables.applications.contains_key(&req.0.application_id.to_string()) {
+            error!("Failed to create scope: unknown application");
+            return Err(ScopeUseCaseError::DatabaseError("Scope creation error".to_string()));
+        }
+
+        let scope = ScopeUseCaseResponse {
+            id: req.0.id,
+            application_id: req.0.application_id,
+            created_at: now.clone(),
+            updated_at: now,
+            deleted_at: None,
+        };
+        tables.scopes.insert(scope.id.to_string(), scope.clone());
+
+        Ok(CreateScopeDtoResponse(scope))
+    }
+
+    #[instrument(skip(self), name = "scope_repository_get_all")]
+    async fn get_scopes(&self, req: GetScopesDtoRequest) -> Result<GetScopesDtoResponse, ScopeUseCaseError> {
+        let sorts = MemoryQuerySorts(req.0.sorts.unwrap_or_default());
+        let pagination = MemoryCursorPagination::new(req.0.pagination, &sorts, &SORT_FIELDS);
+        let filters = MemoryQueryFilters(req.0.filters.unwrap_or_default());
+        let application_id = req.0.application_id.map(|application_id| application_id.to_string());
+
+        let tables = self.db.read();
+        let rows = tables
+            .scopes
+            .values()
+            .filter(|scope| {
+                Self::is_listed(scope, req.0.deleted, application_id.as_deref())
+                    && filters.matches(scope, &FILTER_FIELDS)
+            })
+            .collect::<Vec<_>>();
+        let (scopes, next_cursor) = pagination.page(rows);
+
+        Ok(GetScopesDtoResponse {
+            scopes: scopes.into_iter().cloned().collect(),
+            next_cursor,
+        })
+    }
+
+    #[instrument(skip(self), name = "scope_repository_count_all")]
+    async fn count_scopes(&self, req: CountScopesDtoRequest) -> Result<CountScopesDtoResponse, ScopeUseCaseError> {
+        let filters = MemoryQueryFilters(req.filters.unwrap_or_default());
+
+        let total = self
+            .db
+            .read()
+            .scopes
+            .values()
+            .filter(|scope| {
+                Self::is_listed(scope, req.deleted, req.application_id.as_deref())
+                    && filters.matches(scope, &FILTER_FIELDS)
+            })
+            .count();
+
+        Ok(CountScopesDtoResponse(total as i64))
+    }
+
+    #[instrument(skip(self), name = "scope_repository_delete")]
+    async fn delete(&self, req: DeleteScopeDtoRequest) -> Result<DeleteScopeDtoResponse, ScopeUseCaseError> {
+        let mut tables = self.db.write();
+        let scope = tables
+            .scopes
+            .get_mut(&req.0.id.to_string())
+            .filter(|scope| scope.deleted_at.is_none())
+            .ok_or_else(ScopeUseCaseError::ScopeNotFound)?;
+
+        scope.deleted_at = Some(UtcDateTime::now());
+
+        Ok(DeleteScopeDtoResponse(DeleteScopeUseCaseResponse()))
+    }
+
+    #[instrument(skip(self), name = "scope_repository_restore")]
+    async fn restore(&self, req: RestoreScopeDtoRequest) -> Result<RestoreScopeDtoResponse, ScopeUseCaseError> {
+        let mut tables = self.db.write();
+        let scope = tables
+            .scopes
+            .get_mut(&req.0.id.to_string())
+            .filter(|scope| scope.deleted_at.is_some())
+            .ok_or_else(ScopeUseCaseError::ScopeNotFound)?;
+
+        scope.deleted_at = None;
+
+        Ok(RestoreScopeDtoResponse(RestoreScopeUseCaseResponse()))
+    }
+}
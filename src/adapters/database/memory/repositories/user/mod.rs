@@ -0,0 +1,527 @@
+//! User in-memory repository
+
+use crate::adapters::database::memory::tables::UserRow;
+use crate::adapters::database::memory::{
+    Db, MemoryCursorPagination, MemoryFilterField, MemoryFilterType, MemoryFullTextSearch, MemoryPagination,
+    MemoryQueryFilters, MemoryQuerySorts, MemoryRow, MemoryValue,
+};
+use crate::domain::repositories::user::UserRepository;
+use crate::domain::repositories::user::dto::{
+    CountUsersDtoRequest, CountUsersDtoResponse, CreateUserDtoRequest, CreateUserDtoResponse, DeleteUserDtoRequest,
+    DeleteUserDtoResponse, GetAccessTokenInformationDtoRequest, GetAccessTokenInformationDtoResponse,
+    GetPasswordChangedAtDtoRequest, GetPasswordChangedAtDtoResponse, GetPasswordHistoryDtoRequest,
+    GetPasswordHistoryDtoResponse, GetUserByEmailDtoRequest, GetUserByEmailDtoResponse, GetUserByIdDtoRequest,
+    GetUserByIdDtoResponse, GetUsersDtoRequest, GetUsersDtoResponse, RehashPasswordDtoRequest,
+    RehashPasswordDtoResponse, RestoreUserDtoRequest, RestoreUserDtoResponse, SearchUsersDtoRequest,
+    SearchUsersDtoResponse, UpdatePasswordDtoRequest, UpdatePasswordDtoResponse,
+};
+use crate::domain::use_cases::user::delete_user::DeleteUserUseCaseResponse;
+use crate::domain::use_cases::user::restore_user::RestoreUserUseCaseResponse;
+use crate::domain::use_cases::user::{UserUseCaseError, UserUseCaseResponse};
+use crate::domain::value_objects::datetime::UtcDateTime;
+use async_trait::async_trait;
+use std::cmp::Reverse;
+use std::sync::Arc;
+
+/// Fields available for filtering the users
+const FILTER_FIELDS: [MemoryFilterField; 8] = [
+    ("id", MemoryFilterType::Text),
+    ("email", MemoryFilterType::Text),
+    ("lastname", MemoryFilterType::Text),
+    ("firstname", MemoryFilterType::Text),
+    ("locale", MemoryFilterType::Text),
+    ("created_at", MemoryFilterType::Datetime),
+    ("updated_at", MemoryFilterType::Datetime),
+    ("deleted_at", MemoryFilterType::Datetime),
+];
+
+/// Fields available for sorting the users
+const SORT_FIELDS: [&str; 6] = ["id", "lastname", "firstname", "created_at", "updated_at", "deleted_at"];
+
+impl MemoryRow for UserRow {
+    fn id(&self) -> String {
+        self.user.id.to_string()
+    }
+
+    fn field(&self, name: &str) -> Option<MemoryValue> {
+        let user = &self.user;
+
+        match name {
+            "id" => Some(MemoryValue::Text(user.id.to_string())),
+            "email" => Some(MemoryValue::Text(user.email.to_string())),
+            "lastname" => Some(MemoryValue::from(user.lastname.as_str())),
+            "firstname" => Some(MemoryValue::from(user.firstname.as_str())),
+            "locale" => user.locale.as_ref().map(|locale| MemoryValue::Text(locale.to_string())),
+            "created_at" => Some(MemoryValue::from(&user.created_at)),
+            "updated_at" => Some(MemoryValue::from(&user.updated_at)),
+            "deleted_at" => user.deleted_at.as_ref().map(MemoryValue::from),
+            _ => None,
+        }
+    }
+}
+
+/// User in-memory repository
+#[derive(Debug, Clone)]
+pub struct UserMemoryRepository {
+    db: Arc<Db>,
+}
+
+impl UserMemoryRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl UserRepository for UserMemoryRepository {
+    #[instrument(skip(self), name = "user_repository_create")]
+    async fn create_user(&self, req: CreateUserDtoRequest) -> Result<CreateUserDtoResponse, UserUseCaseError> {
+        let user_id = req.id;
+        let now = UtcDateTime::now();
+
+        let mut tables = self.db.write();
+        if tables.user_by_email(&req.user.email).is_some() {
+            error!("Failed to create user: the email is already used");
+            return Err(UserUseCaseError::DatabaseError("User creation error".to_string()));
+        }
+
+        tables.enqueue_webhook_message(&req.message).map_err(|err| {
+            error!(error = %err, "Failed to enqueue webhook message");
+            UserUseCaseError::DatabaseError("User creation error".to_string())
+        })?;
+
+        let user = UserUseCaseResponse {
+            id: user_id.clone(),
+            email: req.user.email,
+            lastname: req.user.lastname,
+            firstname: req.user.firstname,
+            locale: req.user.locale,
+            created_at: now.clone(),
+            updated_at: now.clone(),
+            deleted_at: None,
+        };
+        tables.users.insert(
+            user_id.to_string(),
+            UserRow {
+                user: user.clone(),
+                password: Some(req.user.password.clone()),
+                password_changed_at: now.clone(),
+            },
+        );
+
+        // Add password to history
+        tables.add_password_history(&user_id, &req.user.password, &now);
+
+        Ok(CreateUserDtoResponse(user))
+    }
+
+    #[instrument(skip(self), name = "user_repository_access_token_information")]
+    async fn get_access_token_information(
+        &self,
+        req: GetAccessTokenInformationDtoRequest,
+    ) -> Result<Option<GetAccessTokenInformationDtoResponse>, UserUseCaseError> {
+        let tables = self.db.read();
+
+        let response = tables
+            .user_by_email(&req.0)
+            .filter(|row| row.user.deleted_at.is_none())
+            .and_then(|row| {
+                row.password
+                    .as_ref()
+                    .map(|password| GetAccessTokenInformationDtoResponse {
+                        id: row.user.id.clone(),
+                        password: password.clone(),
+                        password_changed_at: row.password_changed_at.clone(),
+                    })
+            });
+
+        Ok(response)
+    }
+
+    #[instrument(skip(self), name = "user_repository_get_password_changed_at")]
+    async fn get_password_changed_at(
+        &self,
+        req: GetPasswordChangedAtDtoRequest,
+    ) -> Result<GetPasswordChangedAtDtoResponse, UserUseCaseError> {
+        let password_changed_at = self
+            .db
+            .read()
+            .users
+            .get(&req.0.to_string())
+            .filter(|row| row.user.deleted_at.is_none())
+            .map(|row| row.password.as_ref().map(|_| row.password_changed_at.clone()))
+            .ok_or_else(UserUseCaseError::UserNotFound)?;
+
+        Ok(GetPasswordChangedAtDtoResponse(password_changed_at))
+    }
+
+    #[instrument(skip(self), name = "user_repository_get_users")]
+    async fn get_users(&self, req: GetUsersDtoRequest) -> Result<GetUsersDtoResponse, UserUseCaseError> {
+        let sorts = MemoryQuerySorts(req.0.sorts.unwrap_or_default());
+        let pagination = MemoryCursorPagination::new(req.0.pagination, &sorts, &SORT_FIELDS);
+        let filters = MemoryQueryFilters(req.0.filters.unwrap_or_default());
+
+        let tables = self.db.read();
+        let rows = tables
+            .users
+            .values()
+            .filter(|row| row.user.deleted_at.is_some() == req.0.deleted && filters.matches(row, &FILTER_FIELDS))
+            .collect::<Vec<_>>();
+        let (rows, next_cursor) = pagination.page(rows);
+
+        Ok(GetUsersDtoResponse {
+            users: rows.into_iter().map(|row| row.user.clone()).collect(),
+            next_cursor,
+        })
+    }
+
+    #[instrument(skip(self), name = "user_repository_count_users")]
+    async fn count_users(&self, req: CountUsersDtoRequest) -> Result<CountUsersDtoResponse, UserUseCaseError> {
+        let filters = MemoryQueryFilters(req.filters.unwrap_or_default());
+
+        let total = self
+            .db
+            .read()
+            .users
+            .values()
+            .filter(|row| row.user.deleted_at.is_some() == req.deleted && filters.matches(row, &FILTER_FIELDS))
+            .count();
+
+        Ok(CountUsersDtoResponse(total as i64))
+    }
+
+    #[instrument(skip(self), name = "user_repository_search_users")]
+    async fn search_users(&self, req: SearchUsersDtoRequest) -> Result<SearchUsersDtoResponse, UserUseCaseError> {
+        let Some(words) = MemoryFullTextSearch(req.search).to_words() else {
+            return Ok(SearchUsersDtoResponse {
+                users: vec![],
+                total: 0,
+            });
+        };
+        let filters = MemoryQueryFilters(req.filters.unwrap_or_default());
+
+        let tables = self.db.read();
+        let mut rows = tables
+            .users
+            .values()
+            .filter(|row| row.user.deleted_at.is_some() == req.deleted && filters.matches(row, &FILTER_FIELDS))
+            .map(|row| {
+                let user = &row.user;
+                let email = user.email.to_string();
+                let rank = MemoryFullTextSearch::rank(&words, &[&email, &user.firstname, &user.lastname]);
+
+                (rank, user)
+            })
+            .filter(|(rank, _)| *rank > 0)
+            .collect::<Vec<_>>();
+        let total = rows.len() as i64;
+
+        // Users ranked by relevance
+        rows.sort_by_key(|(rank, user)| (Reverse(*rank), user.id.to_string()));
+        let users = MemoryPagination::from(req.pagination)
+            .apply(rows)
+            .into_iter()
+            .map(|(_, user)| user.clone())
+            .collect();
+
+        Ok(SearchUsersDtoResponse { users, total })
+    }
+
+    #[instrument(skip(self, req), name = "user_repository_get_user_by_id")]
+    async fn get_user_by_id(&self, req: GetUserByIdDtoRequest) -> Result<GetUserByIdDtoResponse, UserUseCaseError> {
+        let user = self
+            .db
+            .read()
+            .users
+            .get(&req.0.user_id.to_string())
+            .filter(|row| row.user.deleted_at.is_none())
+            .map(|row| row.user.clone())
+            .ok_or_else(UserUseCaseError::UserNotFound)?;
+
+        Ok(GetUserByIdDtoResponse(user))
+    }
+
+    #[instrument(skip(self, req), name = "user_repository_get_user_by_email")]
+    async fn get_user_by_email(
+        &self,
+        req: GetUserByEmailDtoRequest,
+    ) -> Result<GetUserByEmailDtoResponse, UserUseCaseError> {
+        let user = self
+            .db
+            .read()
+            .user_by_email(&req.0)
+            .filter(|row| row.user.deleted_at.is_none())
+            .map(|row| row.user.clone())
+            .ok_or_else(UserUseCaseError::UserNotFound)?;
+
+        Ok(GetUserByEmailDtoResponse(user))
+    }
+
+    #[instrument(skip(self, req), name = "user_repository_delete_user")]
+    async fn delete_user(&self, req: DeleteUserDtoRequest) -> Result<DeleteUserDtoResponse, UserUseCaseError> {
+        let mut tables = self.db.write();
+        if tables
+            .users
+            .get(&req.user_id.to_string())
+            .filter(|row| row.user.deleted_at.is_none())
+            .is_none()
+        {
+            return Err(UserUseCaseError::UserNotFound());
+        }
+
+        tables.enqueue_webhook_message(&req.message).map_err(|err| {
+            error!(error = %err, "Failed to enqueue webhook message");
+            UserUseCaseError::DatabaseError("Failed to delete user".to_string())
+        })?;
+
+        if let Some(row) = tables.users.get_mut(&req.user_id.to_string()) {
+            row.user.deleted_at = Some(UtcDateTime::now());
+        }
+
+        Ok(DeleteUserDtoResponse(DeleteUserUseCaseResponse()))
+    }
+
+    #[instrument(skip(self, req), name = "user_repository_update_password")]
+    async fn update_password(
+        &self,
+        req: UpdatePasswordDtoRequest,
+    ) -> Result<UpdatePasswordDtoResponse, UserUseCaseError> {
+        let now = UtcDateTime::now();
+
+        let mut tables = self.db.write();
+        if tables
+            .users
+            .get(&req.user_id.to_string())
+            .filter(|row| row.user.deleted_at.is_none())
+            .is_none()
+        {
+            return Err(UserUseCaseError::UserNotFound());
+        }
+
+        tables.enqueue_webhook_message(&req.message).map_err(|err| {
+            error!(error = %err, "Failed to enqueue webhook message");
+            UserUseCaseError::DatabaseError("Failed to update user password".to_string())
+        })?;
+
+        if let Some(row) = tables.users.get_mut(&req.user_id.to_string()) {
+            row.password = Some(req.password.clone());
+            row.password_changed_at = now.clone();
+        }
+
+        // Add password to history
+        tables.add_password_history(&req.user_id, &req.password, &now);
+
+        Ok(UpdatePasswordDtoResponse())
+    }
+
+    #[instrument(skip(self, req), name = "user_repository_rehash_password")]
+    async fn rehash_password(
+        &self,
+        req: RehashPasswordDtoRequest,
+    ) -> Result<RehashPasswordDtoResponse, UserUseCaseError> {
+        let mut tables = self.db.write();
+        let row = tables
+            .users
+            .get_mut(&req.user_id.to_string())
+            .filter(|row| row.user.deleted_at.is_none())
+            .ok_or_else(UserUseCaseError::UserNotFound)?;
+
+        row.password = Some(req.password);
+
+        Ok(RehashPasswordDtoResponse())
+    }
+
+    #[instrument(skip(self), name = "user_repository_get_password_history")]
+    async fn get_password_history(
+        &self,
+        req: GetPasswordHistoryDtoRequest,
+    ) -> Result<GetPasswordHistoryDtoResponse, UserUseCaseError> {
+        let user_id = req.user_id.to_string();
+
+        let tables = self.db.read();
+        let mut rows = tables
+            .password_history
+            .iter()
+            .rev()
+            .filter(|row| row.user_id == user_id)
+            .collect::<Vec<_>>();
+
+        // Most recent passwords first (the stable sort keeps the last added first)
+        rows.sort_by_key(|row| Reverse(row.created_at.value()));
+        let passwords = rows
+            .into_iter()
+            .take(req.limit as usize)
+            .map(|row| row.password.clone())
+            .collect();
+
+        Ok(GetPasswordHistoryDtoResponse(passwords))
+    }
+
+    #[instrument(skip(self, req), name = "user_repository_restore_user")]
+    async fn restore_user(&self, req: RestoreUserDtoRequest) -> Result<RestoreUserDtoResponse, UserUseCaseError> {
+        let mut tables = self.db.write();
+        if tables
+            .users
+            .get(&req.user_id.to_string())
+            .filter(|row| row.user.deleted_at.is_some())
+            .is_none()
+        {
+            return Err(UserUseCaseError::UserNotFound());
+        }
+
+        tables.enqueue_webhook_message(&req.message).map_err(|err| {
+            error!(error = %err, "Failed to enqueue webhook message");
+            UserUseCaseError::DatabaseError("Failed to restore user".to_string())
+        })?;
+
+        if let Some(row) = tables.users.get_mut(&req.user_id.to_string()) {
+            row.user.deleted_at = None;
+        }
+
+        Ok(RestoreUserDtoResponse(RestoreUserUseCaseResponse()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::audit_event::AuditContext;
+    use crate::domain::entities::webhook::{WebhookEvent, WebhookMessage};
+    use crate::domain::use_cases::user::create_user::CreateUserUseCaseRequest;
+    use crate::domain::use_cases::user::get_user::GetUserUseCaseRequest;
+    use crate::domain::use_cases::user::get_users::GetUsersUseCaseRequest;
+    use crate::domain::value_objects::email::Email;
+    use crate::domain::value_objects::id::Id;
+    use crate::domain::value_objects::pagination::Pagination;
+    use crate::domain::value_objects::password::Password;
+    use crate::domain::value_objects::query_sort::QuerySorts;
+    use serde_json::json;
+
+    fn create_user_request(email: &str, lastname: &str) -> CreateUserDtoRequest {
+        CreateUserDtoRequest {
+            id: Id::new().unwrap(),
+            user: CreateUserUseCaseRequest {
+                email: Email::new(email).unwrap(),
+                password: Password::new("$argon2id$hash", true).unwrap(),
+                lastname: lastname.to_string(),
+                firstname: "John".to_string(),
+                locale: None,
+                audit: AuditContext::default(),
+            },
+            message: webhook_message(WebhookEvent::UserCreated),
+        }
+    }
+
+    fn webhook_message(event: WebhookEvent) -> WebhookMessage {
+        WebhookMessage::new(event, json!({})).unwrap()
+    }
+
+    async fn create_user(repository: &UserMemoryRepository, email: &str, lastname: &str) -> UserUseCaseResponse {
+        repository
+            .create_user(create_user_request(email, lastname))
+            .await
+            .unwrap()
+            .0
+    }
+
+    async fn get_users(
+        repository: &UserMemoryRepository,
+        pagination: Pagination,
+        deleted: bool,
+    ) -> GetUsersDtoResponse {
+        repository
+            .get_users(GetUsersDtoRequest(GetUsersUseCaseRequest {
+                pagination,
+                sorts: Some(QuerySorts::from("+lastname")),
+                filters: None,
+                search: None,
+                deleted,
+            }))
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_create_user_with_used_email() {
+        let repository = UserMemoryRepository::new(Db::default());
+        create_user(&repository, "john.doe@test.com", "Doe").await;
+
+        let result = repository
+            .create_user(create_user_request("JOHN.doe@test.com", "Doe"))
+            .await;
+        assert_eq!(
+            Err(UserUseCaseError::DatabaseError("User creation error".to_string())),
+            result.map(|response| response.0)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_and_restore_user() {
+        let repository = UserMemoryRepository::new(Db::default());
+        let user = create_user(&repository, "john.doe@test.com", "Doe").await;
+        let delete = || {
+            repository.delete_user(DeleteUserDtoRequest {
+                user_id: user.id.clone(),
+                message: webhook_message(WebhookEvent::UserDeleted),
+            })
+        };
+        let restore = || {
+            repository.restore_user(RestoreUserDtoRequest {
+                user_id: user.id.clone(),
+                message: webhook_message(WebhookEvent::UserRestored),
+            })
+        };
+
+        assert!(restore().await.is_err());
+        assert!(delete().await.is_ok());
+        assert!(delete().await.is_err());
+        assert!(
+            repository
+                .get_user_by_id(GetUserByIdDtoRequest(GetUserUseCaseRequest {
+                    user_id: user.id.clone()
+                }))
+                .await
+                .is_err()
+        );
+        assert_eq!(1, get_users(&repository, Pagination::default(), true).await.users.len());
+        assert!(
+            get_users(&repository, Pagination::default(), false)
+                .await
+                .users
+                .is_empty()
+        );
+
+        assert!(restore().await.is_ok());
+        assert_eq!(
+            1,
+            get_users(&repository, Pagination::default(), false).await.users.len()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_users_with_cursor() {
+        let repository = UserMemoryRepository::new(Db::default());
+        for (email, lastname) in [("a@test.com", "C"), ("b@test.com", "A"), ("c@test.com", "B")] {
+            create_user(&repository, email, lastname).await;
+        }
+        let lastnames = |response: &GetUsersDtoResponse| {
+            response
+                .users
+                .iter()
+                .map(|user| user.lastname.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let response = get_users(&repository, Pagination::new_cursor(None, 2), false).await;
+        assert_eq!(vec!["A", "B"], lastnames(&response));
+        assert!(response.next_cursor.is_some());
+
+        let response = get_users(&repository, Pagination::new_cursor(response.next_cursor, 2), false).await;
+        assert_eq!(vec!["C"], lastnames(&response));
+        assert!(response.next_cursor.is_none());
+
+        let response = get_users(&repository, Pagination::new(2, 2), false).await;
+        assert_eq!(vec!["C"], lastnames(&response));
+    }
+}
@@ -0,0 +1,265 @@
+//! Webhook in-memory repository
+
+use crate::adapters::database::memory::{Db, MemoryCursorPagination, MemoryQuerySorts, MemoryRow, MemoryValue};
+use crate::domain::entities::webhook::{Webhook, WebhookDelivery, WebhookDeliveryStatus};
+use crate::domain::repositories::webhook::WebhookRepository;
+use crate::domain::repositories::webhook::dto::{
+    CountWebhooksDtoRequest, CountWebhooksDtoResponse, CreateWebhookDtoRequest, CreateWebhookDtoResponse,
+    DeleteWebhookDtoRequest, DeleteWebhookDtoResponse, GetDueWebhookDeliveriesDtoRequest,
+    GetDueWebhookDeliveriesDtoResponse, GetWebhooksDtoRequest, GetWebhooksDtoResponse, UpdateWebhookDeliveryDtoRequest,
+    UpdateWebhookDeliveryDtoResponse,
+};
+use crate::domain::use_cases::webhook::WebhookUseCaseError;
+use crate::domain::use_cases::webhook::delete_webhook::DeleteWebhookUseCaseResponse;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use async_trait::async_trait;
+use chrono::Duration;
+use std::sync::Arc;
+
+/// Time during which a delivery fetched by a worker is not fetched again (in second)
+///
+/// The delivery is retried after this delay if the worker stops before saving the result.
+const DELIVERY_LEASE: i64 = 300;
+
+/// Fields available for sorting the webhooks
+const SORT_FIELDS: [&str; 3] = ["url", "created_at", "updated_at"];
+
+impl MemoryRow for Webhook {
+    fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn field(&self, name: &str) -> Option<MemoryValue> {
+        match name {
+            "url" => Some(MemoryValue::from(self.url.as_str())),
+            "created_at" => Some(MemoryValue::from(&self.created_at)),
+            "updated_at" => Some(MemoryValue::from(&self.updated_at)),
+            _ => None,
+        }
+    }
+}
+
+/// Webhook in-memory repository
+#[derive(Debug, Clone)]
+pub struct WebhookMemoryRepository {
+    db: Arc<Db>,
+}
+
+impl WebhookMemoryRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for WebhookMemoryRepository {
+    #[instrument(skip(self, req), name = "webhook_repository_create")]
+    async fn create_webhook(
+        &self,
+        req: CreateWebhookDtoRequest,
+    ) -> Result<CreateWebhookDtoResponse, WebhookUseCaseError> {
+        let webhook_id = Id::new().map_err(|err| {
+            error!(error = %err, "Failed to create webhook ID");
+            WebhookUseCaseError::InvalidId()
+        })?;
+        let now = UtcDateTime::now();
+
+        // The webhook is only created if the application exists
+        let mut tables = self.db.write();
+        if !tables.is_active_application(&req.webhook.application_id.to_string()) {
+            return Err(WebhookUseCaseError::ApplicationNotFound());
+        }
+
+        let webhook = Webhook {
+            id: webhook_id,
+            application_id: req.webhook.application_id,
+            url: req.webhook.url,
+            secret: req.secret,
+            events: req.webhook.events,
+            created_at: now.clone(),
+            updated_at: now,
+            deleted_at: None,
+        };
+        tables.webhooks.insert(webhook.id.to_string(), webhook.clone());
+
+        Ok(CreateWebhookDtoResponse(webhook))
+    }
+
+    #[instrument(skip(self), name = "webhook_repository_count")]
+    async fn count_webhooks(
+        &self,
+        req: CountWebhooksDtoRequest,
+    ) -> Result<CountWebhooksDtoResponse, WebhookUseCaseError> {
+        let total = self
+            .db
+            .read()
+            .webhooks
+            .values()
+            .filter(|webhook| webhook.application_id == req.application_id && webhook.deleted_at.is_none())
+            .count();
+
+        Ok(CountWebhooksDtoResponse(total as i64))
+    }
+
+    #[instrument(skip(self), name = "webhook_repository_get_all")]
+    async fn get_webhooks(&self, req: GetWebhooksDtoRequest) -> Result<GetWebhooksDtoResponse, WebhookUseCaseError> {
+        let sorts = MemoryQuerySorts(req.0.sorts.unwrap_or_default());
+        let pagination = MemoryCursorPagination::new(req.0.pagination, &sorts, &SORT_FIELDS);
+
+        let tables = self.db.read();
+        let rows = tables
+            .webhooks
+            .values()
+            .filter(|webhook| webhook.application_id == req.0.application_id && webhook.deleted_at.is_none())
+            .collect::<Vec<_>>();
+        let (webhooks, next_cursor) = pagination.page(rows);
+
+        Ok(GetWebhooksDtoResponse {
+            webhooks: webhooks.into_iter().cloned().collect(),
+            next_cursor,
+        })
+    }
+
+    #[instrument(skip(self), name = "webhook_repository_delete")]
+    async fn delete_webhook(
+        &self,
+        req: DeleteWebhookDtoRequest,
+    ) -> Result<DeleteWebhookDtoResponse, WebhookUseCaseError> {
+        let mut tables = self.db.write();
+        let webhook = tables
+            .webhooks
+            .get_mut(&req.0.id.to_string())
+            .filter(|webhook| webhook.application_id == req.0.application_id && webhook.deleted_at.is_none())
+            .ok_or_else(WebhookUseCaseError::WebhookNotFound)?;
+
+        webhook.deleted_at = Some(UtcDateTime::now());
+
+        Ok(DeleteWebhookDtoResponse(DeleteWebhookUseCaseResponse()))
+    }
+
+    #[instrument(skip(self), name = "webhook_repository_get_due_deliveries")]
+    async fn get_due_deliveries(
+        &self,
+        req: GetDueWebhookDeliveriesDtoRequest,
+    ) -> Result<GetDueWebhookDeliveriesDtoResponse, WebhookUseCaseError> {
+        let now = UtcDateTime::now();
+        let lease_until = UtcDateTime::new(now.value() + Duration::seconds(DELIVERY_LEASE));
+
+        // The tables are locked: the deliveries cannot be claimed twice
+        let mut tables = self.db.write();
+        let tables = &mut *tables;
+        let mut deliveries = tables
+            .webhook_deliveries
+            .values_mut()
+            .filter(|row| row.status == WebhookDeliveryStatus::Pending && row.next_attempt_at <= now)
+            .filter_map(|row| {
+                tables
+                    .webhooks
+                    .get(&row.webhook_id.to_string())
+                    .filter(|webhook| webhook.deleted_at.is_none())
+                    .map(|webhook| (row, webhook))
+            })
+            .collect::<Vec<_>>();
+        deliveries.sort_by_key(|(row, _)| row.next_attempt_at.value());
+        deliveries.truncate(req.limit as usize);
+
+        // Lease
+        let deliveries = deliveries
+            .into_iter()
+            .map(|(row, webhook)| {
+                row.next_attempt_at = lease_until.clone();
+
+                WebhookDelivery {
+                    id: row.id.clone(),
+                    webhook_id: row.webhook_id.clone(),
+                    url: webhook.url.clone(),
+                    secret: webhook.secret.clone(),
+                    event_id: row.event_id.clone(),
+                    event: row.event,
+                    payload: row.payload.clone(),
+                    attempts: row.attempts,
+                }
+            })
+            .collect();
+
+        Ok(GetDueWebhookDeliveriesDtoResponse(deliveries))
+    }
+
+    #[instrument(skip(self), name = "webhook_repository_update_delivery")]
+    async fn update_delivery(
+        &self,
+        req: UpdateWebhookDeliveryDtoRequest,
+    ) -> Result<UpdateWebhookDeliveryDtoResponse, WebhookUseCaseError> {
+        let delivered_at = match req.status {
+            WebhookDeliveryStatus::Delivered => Some(UtcDateTime::now()),
+            _ => None,
+        };
+
+        if let Some(row) = self.db.write().webhook_deliveries.get_mut(&req.id.to_string()) {
+            row.status = req.status;
+            row.attempts = req.attempts;
+            row.next_attempt_at = req.next_attempt_at;
+            row.last_error = req.last_error;
+            row.delivered_at = delivered_at;
+        }
+
+        Ok(UpdateWebhookDeliveryDtoResponse())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::webhook::{WebhookEvent, WebhookMessage};
+    use crate::domain::value_objects::webhook_secret::WebhookSecret;
+    use serde_json::json;
+
+    fn add_webhook(db: &Db, events: Vec<WebhookEvent>, deleted: bool) -> Webhook {
+        let now = UtcDateTime::now();
+        let webhook = Webhook {
+            id: Id::new().unwrap(),
+            application_id: Id::new().unwrap(),
+            url: "https://example.com/hook".to_string(),
+            secret: WebhookSecret::generate(),
+            events,
+            created_at: now.clone(),
+            updated_at: now.clone(),
+            deleted_at: deleted.then_some(now),
+        };
+        db.write().webhooks.insert(webhook.id.to_string(), webhook.clone());
+
+        webhook
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_webhook_message_and_get_due_deliveries() {
+        let db = Db::default();
+        let repository = WebhookMemoryRepository::new(db.clone());
+        let webhook = add_webhook(&db, vec![WebhookEvent::UserCreated], false);
+        add_webhook(&db, vec![WebhookEvent::UserDeleted], false);
+        add_webhook(&db, vec![WebhookEvent::UserCreated], true);
+
+        let message = WebhookMessage::new(WebhookEvent::UserCreated, json!({ "user_id": "user-id" })).unwrap();
+        db.write().enqueue_webhook_message(&message).unwrap();
+
+        let deliveries = repository
+            .get_due_deliveries(GetDueWebhookDeliveriesDtoRequest { limit: 10 })
+            .await
+            .unwrap()
+            .0;
+        assert_eq!(deliveries.len(), 1);
+        assert_eq!(deliveries[0].webhook_id, webhook.id);
+        assert_eq!(deliveries[0].event_id, message.id);
+        assert_eq!(deliveries[0].payload, message.payload());
+
+        // Leased deliveries are not fetched again
+        let deliveries = repository
+            .get_due_deliveries(GetDueWebhookDeliveriesDtoRequest { limit: 10 })
+            .await
+            .unwrap()
+            .0;
+        assert!(deliveries.is_empty());
+    }
+}
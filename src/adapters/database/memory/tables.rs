@@ -0,0 +1,238 @@
+//! Tables of the in-memory database
+//!
+//! The rows are indexed by their primary key (as a string), and the constraints of the SQL
+//! schemas (unique emails, deletion of the user data) are applied by the repositories.
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::audit_event::AuditEvent;
+use crate::domain::entities::email::{EmailId, EmailMessage, EmailStatus};
+use crate::domain::entities::invitation::InvitationTokenValue;
+use crate::domain::entities::magic_link::MagicLink;
+use crate::domain::entities::oidc_login_state::OidcLoginState;
+use crate::domain::entities::password_reset::PasswordReset;
+use crate::domain::entities::user::UserId;
+use crate::domain::entities::webhook::{Webhook, WebhookDeliveryStatus, WebhookEvent, WebhookId, WebhookMessage};
+use crate::domain::use_cases::application::{
+    ApplicationUseCaseResponse, ClientSecretUseCaseResponse, ClientUseCaseResponse,
+};
+use crate::domain::use_cases::external_link::{
+    ExternalLinkApplicationUseCaseResponse, ExternalLinkUseCaseResponse, ExternalLinkUserUseCaseResponse,
+};
+use crate::domain::use_cases::scope::ScopeUseCaseResponse;
+use crate::domain::use_cases::user::UserUseCaseResponse;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::email::Email;
+use crate::domain::value_objects::id::{Id, IdError};
+use crate::domain::value_objects::password::Password;
+use std::collections::HashMap;
+
+/// User with its password (`None` for an invited user)
+#[derive(Debug, Clone)]
+pub(super) struct UserRow {
+    pub(super) user: UserUseCaseResponse,
+    pub(super) password: Option<Password>,
+    pub(super) password_changed_at: UtcDateTime,
+}
+
+/// Password previously used by a user
+#[derive(Debug, Clone)]
+pub(super) struct PasswordHistoryRow {
+    pub(super) user_id: String,
+    pub(super) password: Password,
+    pub(super) created_at: UtcDateTime,
+}
+
+/// Refresh token with the access token created at the same time
+#[derive(Debug, Clone)]
+pub(super) struct RefreshTokenRow {
+    pub(super) user_id: UserId,
+    pub(super) application_id: ApplicationId,
+    pub(super) access_token: String,
+    pub(super) expired_at: UtcDateTime,
+}
+
+/// Scope given to a user
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct UserScopeRow {
+    pub(super) user_id: String,
+    pub(super) scope_id: String,
+}
+
+/// Invitation of a pending user
+#[derive(Debug, Clone)]
+pub(super) struct InvitationRow {
+    pub(super) id: Id,
+    pub(super) user_id: UserId,
+    pub(super) token: InvitationTokenValue,
+    pub(super) expired_at: UtcDateTime,
+    pub(super) created_at: UtcDateTime,
+    pub(super) updated_at: UtcDateTime,
+}
+
+/// Client secret with its hash
+#[derive(Debug, Clone)]
+pub(super) struct ClientSecretRow {
+    pub(super) secret: ClientSecretUseCaseResponse,
+    pub(super) value: Password,
+}
+
+/// Delivery of a webhook message
+#[derive(Debug, Clone)]
+pub(super) struct WebhookDeliveryRow {
+    pub(super) id: Id,
+    pub(super) webhook_id: WebhookId,
+    pub(super) event_id: Id,
+    pub(super) event: WebhookEvent,
+    pub(super) payload: String,
+    pub(super) status: WebhookDeliveryStatus,
+    pub(super) attempts: u32,
+    pub(super) next_attempt_at: UtcDateTime,
+    pub(super) last_error: Option<String>,
+    pub(super) delivered_at: Option<UtcDateTime>,
+}
+
+/// Email of the outbox
+#[derive(Debug, Clone)]
+pub(super) struct EmailRow {
+    pub(super) id: EmailId,
+    pub(super) message: EmailMessage,
+    pub(super) status: EmailStatus,
+    pub(super) attempts: u32,
+    pub(super) next_attempt_at: UtcDateTime,
+    pub(super) last_error: Option<String>,
+    pub(super) sent_at: Option<UtcDateTime>,
+}
+
+/// Tables of the in-memory database
+#[derive(Debug, Default)]
+pub(super) struct Tables {
+    pub(super) users: HashMap<String, UserRow>,
+    pub(super) password_history: Vec<PasswordHistoryRow>,
+    pub(super) refresh_tokens: HashMap<String, RefreshTokenRow>,
+
+    /// Password resets by user ID
+    pub(super) password_resets: HashMap<String, PasswordReset>,
+
+    /// Magic links by user ID
+    pub(super) magic_links: HashMap<String, MagicLink>,
+
+    pub(super) applications: HashMap<String, ApplicationUseCaseResponse>,
+    pub(super) scopes: HashMap<String, ScopeUseCaseResponse>,
+    pub(super) users_scopes: Vec<UserScopeRow>,
+    pub(super) invitations: HashMap<String, InvitationRow>,
+    pub(super) clients: HashMap<String, ClientUseCaseResponse>,
+    pub(super) client_secrets: HashMap<String, ClientSecretRow>,
+    pub(super) external_links: HashMap<String, ExternalLinkUseCaseResponse>,
+    pub(super) external_links_users: Vec<ExternalLinkUserUseCaseResponse>,
+    pub(super) external_links_applications: Vec<ExternalLinkApplicationUseCaseResponse>,
+
+    /// OIDC login states by state
+    pub(super) oidc_login_states: HashMap<String, OidcLoginState>,
+
+    pub(super) audit_events: HashMap<String, AuditEvent>,
+    pub(super) webhooks: HashMap<String, Webhook>,
+    pub(super) webhook_deliveries: HashMap<String, WebhookDeliveryRow>,
+    pub(super) email_outbox: HashMap<String, EmailRow>,
+}
+
+impl Tables {
+    /// Get a user by email, deleted or not (emails are case-insensitive like in the SQL schemas)
+    pub(super) fn user_by_email(&self, email: &Email) -> Option<&UserRow> {
+        let email = email.to_string().to_lowercase();
+
+        self.users
+            .values()
+            .find(|row| row.user.email.to_string().to_lowercase() == email)
+    }
+
+    /// Check if a user exists and is not deleted
+    pub(super) fn is_active_user(&self, user_id: &str) -> bool {
+        self.users.get(user_id).is_some_and(|row| row.user.deleted_at.is_none())
+    }
+
+    /// Check if an application exists and is not deleted
+    pub(super) fn is_active_application(&self, application_id: &str) -> bool {
+        self.applications
+            .get(application_id)
+            .is_some_and(|application| application.deleted_at.is_none())
+    }
+
+    /// Add a hashed password to the user password history
+    pub(super) fn add_password_history(&mut self, user_id: &UserId, password: &Password, created_at: &UtcDateTime) {
+        self.password_history.push(PasswordHistoryRow {
+            user_id: user_id.to_string(),
+            password: password.clone(),
+            created_at: created_at.clone(),
+        });
+    }
+
+    /// Add an email to the outbox
+    ///
+    /// It is called by the repositories before their other changes, so that nothing is saved
+    /// if the email cannot be added.
+    pub(super) fn enqueue_email(&mut self, message: &EmailMessage) -> Result<(), IdError> {
+        let id = Id::new()?;
+
+        self.email_outbox.insert(
+            id.to_string(),
+            EmailRow {
+                id,
+                message: message.clone(),
+                status: EmailStatus::Pending,
+                attempts: 0,
+                next_attempt_at: UtcDateTime::now(),
+                last_error: None,
+                sent_at: None,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Add a delivery to the outbox for each webhook subscribed to the message event
+    ///
+    /// It is called by the repositories before their other changes, so that nothing is saved
+    /// if the deliveries cannot be added.
+    pub(super) fn enqueue_webhook_message(&mut self, message: &WebhookMessage) -> Result<(), IdError> {
+        let payload = message.payload();
+
+        // Webhooks subscribed to the event
+        let deliveries = self
+            .webhooks
+            .values()
+            .filter(|webhook| webhook.deleted_at.is_none() && webhook.events.contains(&message.event))
+            .map(|webhook| {
+                Ok(WebhookDeliveryRow {
+                    id: Id::new()?,
+                    webhook_id: webhook.id.clone(),
+                    event_id: message.id.clone(),
+                    event: message.event,
+                    payload: payload.clone(),
+                    status: WebhookDeliveryStatus::Pending,
+                    attempts: 0,
+                    next_attempt_at: message.created_at.clone(),
+                    last_error: None,
+                    delivered_at: None,
+                })
+            })
+            .collect::<Result<Vec<_>, IdError>>()?;
+        for delivery in deliveries {
+            self.webhook_deliveries.insert(delivery.id.to_string(), delivery);
+        }
+
+        Ok(())
+    }
+
+    /// Delete a user and its data (like the `ON DELETE CASCADE` of the SQL schemas)
+    pub(super) fn delete_user(&mut self, user_id: &str) {
+        self.users.remove(user_id);
+        self.password_history.retain(|row| row.user_id != user_id);
+        self.refresh_tokens.retain(|_, row| row.user_id.to_string() != user_id);
+        self.password_resets.remove(user_id);
+        self.magic_links.remove(user_id);
+        self.users_scopes.retain(|row| row.user_id != user_id);
+        self.invitations.retain(|_, row| row.user_id.to_string() != user_id);
+        self.external_links_users
+            .retain(|row| row.user_id.to_string() != user_id);
+    }
+}
@@ -1,10 +1,12 @@
 //! Application model
 
+use crate::adapters::database::model::to_u32;
 use crate::domain::use_cases::application::ApplicationUseCaseResponse;
 use crate::domain::value_objects::datetime::UtcDateTime;
 use crate::domain::value_objects::id::Id;
 use chrono::{DateTime, NaiveDateTime, Utc};
 use sqlx::FromRow;
+use std::fmt::Display;
 use std::str::FromStr;
 use thiserror::Error;
 
@@ -12,21 +14,38 @@ use thiserror::Error;
 pub enum ApplicationModelError {
     #[error("{0}")]
     InvalidId(String),
+
+    #[error("{0}")]
+    Lifetime(String),
+}
+
+/// Convert a stored lifetime
+fn lifetime<N: TryInto<u32> + Copy + Display>(value: Option<N>) -> Result<Option<u32>, ApplicationModelError> {
+    value
+        .map(|lifetime| {
+            to_u32(lifetime)
+                .map_err(|lifetime| ApplicationModelError::Lifetime(format!("Invalid lifetime: `{lifetime}`")))
+        })
+        .transpose()
 }
 
+/// Application row (`N`: stored integer type)
 #[derive(Debug, Clone, FromRow)]
-pub struct ApplicationModel {
+pub struct ApplicationModel<N = u32> {
     pub id: String,
     pub name: String,
+    pub access_token_lifetime: Option<N>,
+    pub refresh_token_lifetime: Option<N>,
+    pub audience: Option<String>,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
     pub deleted_at: Option<NaiveDateTime>,
 }
 
-impl TryFrom<ApplicationModel> for ApplicationUseCaseResponse {
+impl<N: TryInto<u32> + Copy + Display> TryFrom<ApplicationModel<N>> for ApplicationUseCaseResponse {
     type Error = ApplicationModelError;
 
-    fn try_from(value: ApplicationModel) -> Result<Self, Self::Error> {
+    fn try_from(value: ApplicationModel<N>) -> Result<Self, Self::Error> {
         let id = Id::from_str(&value.id)
             .map_err(|_| ApplicationModelError::InvalidId(format!("Invalid application ID: `{}`", value.id)))?;
         let created_at = UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(value.created_at, Utc));
@@ -38,6 +57,9 @@ impl TryFrom<ApplicationModel> for ApplicationUseCaseResponse {
         Ok(Self {
             id,
             name: value.name,
+            access_token_lifetime: lifetime(value.access_token_lifetime)?,
+            refresh_token_lifetime: lifetime(value.refresh_token_lifetime)?,
+            audience: value.audience,
             created_at,
             updated_at,
             deleted_at,
@@ -56,6 +78,9 @@ mod test {
         let model = ApplicationModel {
             id: id.to_string(),
             name: "name".to_string(),
+            access_token_lifetime: Some(5_u32),
+            refresh_token_lifetime: None,
+            audience: Some("https://api.test.com".to_string()),
             created_at: DateTime::from_timestamp(0, 0).unwrap().naive_utc(),
             updated_at: DateTime::from_timestamp(0, 0).unwrap().naive_utc(),
             deleted_at: None,
@@ -63,6 +88,9 @@ mod test {
         let expected = ApplicationUseCaseResponse {
             id: ApplicationId::from_str(id.to_string().as_str()).unwrap(),
             name: "name".to_string(),
+            access_token_lifetime: Some(5),
+            refresh_token_lifetime: None,
+            audience: Some("https://api.test.com".to_string()),
             created_at: UtcDateTime::new(DateTime::from_timestamp(0, 0).unwrap()),
             updated_at: UtcDateTime::new(DateTime::from_timestamp(0, 0).unwrap()),
             deleted_at: None,
@@ -70,4 +98,23 @@ mod test {
 
         assert_eq!(ApplicationUseCaseResponse::try_from(model).unwrap(), expected);
     }
+
+    #[test]
+    fn test_try_from_application_model_with_invalid_lifetime() {
+        let model = ApplicationModel {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: "name".to_string(),
+            access_token_lifetime: Some(-1_i64),
+            refresh_token_lifetime: None,
+            audience: None,
+            created_at: DateTime::from_timestamp(0, 0).unwrap().naive_utc(),
+            updated_at: DateTime::from_timestamp(0, 0).unwrap().naive_utc(),
+            deleted_at: None,
+        };
+
+        assert_eq!(
+            ApplicationUseCaseResponse::try_from(model),
+            Err(ApplicationModelError::Lifetime("Invalid lifetime: `-1`".to_string()))
+        );
+    }
 }
@@ -0,0 +1,83 @@
+//! Audit event model
+
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent, AuditEventId};
+use crate::domain::entities::user::UserId;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use sqlx::FromRow;
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum AuditEventModelError {
+    #[error("{0}")]
+    InvalidId(String),
+
+    #[error("{0}")]
+    InvalidAction(String),
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct AuditEventModel {
+    pub id: String,
+    pub actor_id: Option<String>,
+    pub action: String,
+    pub target_id: String,
+    pub ip: Option<String>,
+    pub request_id: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+impl TryFrom<AuditEventModel> for AuditEvent {
+    type Error = AuditEventModelError;
+
+    fn try_from(value: AuditEventModel) -> Result<Self, Self::Error> {
+        let id = AuditEventId::from_str(&value.id)
+            .map_err(|_| AuditEventModelError::InvalidId(format!("Invalid audit event ID: `{}`", value.id)))?;
+        let actor_id = value
+            .actor_id
+            .map(|actor_id| {
+                UserId::from_str(&actor_id)
+                    .map_err(|_| AuditEventModelError::InvalidId(format!("Invalid actor ID: `{actor_id}`")))
+            })
+            .transpose()?;
+        let action = AuditAction::from_str(&value.action).map_err(AuditEventModelError::InvalidAction)?;
+
+        Ok(Self {
+            id,
+            actor_id,
+            action,
+            target_id: value.target_id,
+            ip: value.ip,
+            request_id: value.request_id,
+            created_at: UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(value.created_at, Utc)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_audit_event_model() {
+        let model = AuditEventModel {
+            id: "3288fb86-db99-471d-95bc-1451c7ec6f7b".to_string(),
+            actor_id: None,
+            action: "login.failed".to_string(),
+            target_id: "john.doe@test.com".to_string(),
+            ip: Some("127.0.0.1".to_string()),
+            request_id: None,
+            created_at: Utc::now().naive_utc(),
+        };
+        let event: Result<AuditEvent, _> = model.clone().try_into();
+        assert_eq!(event.unwrap().action, AuditAction::LoginFailed);
+
+        let event: Result<AuditEvent, _> = AuditEventModel {
+            action: "login".to_string(),
+            ..model
+        }
+        .try_into();
+        assert!(matches!(event, Err(AuditEventModelError::InvalidAction(_))));
+    }
+}
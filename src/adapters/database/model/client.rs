@@ -0,0 +1,104 @@
+//! Client models
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::client::{ClientId, ClientSecretId};
+use crate::domain::use_cases::application::{ClientSecretUseCaseResponse, ClientUseCaseResponse};
+use crate::domain::value_objects::datetime::UtcDateTime;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use sqlx::FromRow;
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ClientModelError {
+    #[error("{0}")]
+    Id(String),
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ClientModel {
+    pub id: String,
+    pub application_id: String,
+    pub name: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+impl TryFrom<ClientModel> for ClientUseCaseResponse {
+    type Error = ClientModelError;
+
+    fn try_from(value: ClientModel) -> Result<Self, Self::Error> {
+        let id = ClientId::from_str(&value.id)
+            .map_err(|_| ClientModelError::Id(format!("Invalid client ID: `{}`", value.id)))?;
+        let application_id = ApplicationId::from_str(&value.application_id)
+            .map_err(|_| ClientModelError::Id(format!("Invalid application ID: `{}`", value.application_id)))?;
+        let created_at = UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(value.created_at, Utc));
+        let updated_at = UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(value.updated_at, Utc));
+        let deleted_at = value
+            .deleted_at
+            .map(|deleted_at| UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(deleted_at, Utc)));
+
+        Ok(Self {
+            id,
+            application_id,
+            name: value.name,
+            created_at,
+            updated_at,
+            deleted_at,
+        })
+    }
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ClientSecretModel {
+    pub id: String,
+    pub client_id: String,
+    pub created_at: NaiveDateTime,
+}
+
+impl TryFrom<ClientSecretModel> for ClientSecretUseCaseResponse {
+    type Error = ClientModelError;
+
+    fn try_from(value: ClientSecretModel) -> Result<Self, Self::Error> {
+        let id = ClientSecretId::from_str(&value.id)
+            .map_err(|_| ClientModelError::Id(format!("Invalid client secret ID: `{}`", value.id)))?;
+        let client_id = ClientId::from_str(&value.client_id)
+            .map_err(|_| ClientModelError::Id(format!("Invalid client ID: `{}`", value.client_id)))?;
+        let created_at = UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(value.created_at, Utc));
+
+        Ok(Self {
+            id,
+            client_id,
+            created_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn model() -> ClientModel {
+        ClientModel {
+            id: uuid::Uuid::new_v4().to_string(),
+            application_id: uuid::Uuid::new_v4().to_string(),
+            name: "Web client".to_string(),
+            created_at: DateTime::from_timestamp(0, 0).unwrap().naive_utc(),
+            updated_at: DateTime::from_timestamp(0, 0).unwrap().naive_utc(),
+            deleted_at: None,
+        }
+    }
+
+    #[test]
+    fn test_try_from_client_model_for_use_case_response() {
+        let client = ClientUseCaseResponse::try_from(model()).unwrap();
+        assert_eq!(client.name, "Web client".to_string());
+
+        let client = ClientUseCaseResponse::try_from(ClientModel {
+            application_id: "invalid".to_string(),
+            ..model()
+        });
+        assert!(client.is_err());
+    }
+}
@@ -0,0 +1,106 @@
+//! Email outbox models
+
+use crate::adapters::database::model::to_u32;
+use crate::domain::entities::email::{EmailAddress, EmailId, EmailMessage, QueuedEmail};
+use sqlx::FromRow;
+use std::fmt::Display;
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum EmailModelError {
+    #[error("{0}")]
+    InvalidId(String),
+
+    #[error("{0}")]
+    InvalidAddresses(String),
+
+    #[error("{0}")]
+    Attempts(String),
+}
+
+/// Recipients are stored as a JSON array
+pub fn join_addresses(addresses: &[EmailAddress]) -> String {
+    serde_json::to_string(addresses).unwrap_or_else(|_| "[]".to_string())
+}
+
+/// Queued email row (`N`: stored integer type)
+#[derive(Debug, Clone, FromRow)]
+pub struct EmailModel<N = u32> {
+    pub id: String,
+    pub from_address: String,
+    pub to_addresses: String,
+    pub subject: String,
+    pub text_body: Option<String>,
+    pub html_body: Option<String>,
+    pub attempts: N,
+}
+
+impl<N: TryInto<u32> + Copy + Display> TryFrom<EmailModel<N>> for QueuedEmail {
+    type Error = EmailModelError;
+
+    fn try_from(value: EmailModel<N>) -> Result<Self, Self::Error> {
+        let id = EmailId::from_str(&value.id)
+            .map_err(|_| EmailModelError::InvalidId(format!("Invalid email ID: `{}`", value.id)))?;
+        let to_addresses: Vec<EmailAddress> = serde_json::from_str(&value.to_addresses)
+            .map_err(|err| EmailModelError::InvalidAddresses(format!("Invalid recipients: {err}")))?;
+
+        Ok(Self {
+            id,
+            message: EmailMessage {
+                from_address: value.from_address,
+                to_addresses,
+                subject: value.subject,
+                text_body: value.text_body,
+                html_body: value.html_body,
+            },
+            attempts: to_u32(value.attempts)
+                .map_err(|attempts| EmailModelError::Attempts(format!("Invalid attempts: `{attempts}`")))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model<N>(attempts: N) -> EmailModel<N> {
+        EmailModel {
+            id: "6b0f3c2e-8a1d-4f5b-9c7e-2d4a6b8c0e13".to_string(),
+            from_address: "from@test.com".to_string(),
+            to_addresses: join_addresses(&["to@test.com".to_string(), "other@test.com".to_string()]),
+            subject: "Subject".to_string(),
+            text_body: Some("Body".to_string()),
+            html_body: None,
+            attempts,
+        }
+    }
+
+    #[test]
+    fn test_email_model_try_from() {
+        let email = QueuedEmail::try_from(model(1_u32)).unwrap();
+        assert_eq!(
+            email.message.to_addresses,
+            vec!["to@test.com".to_string(), "other@test.com".to_string()]
+        );
+        assert_eq!(email.attempts, 1);
+
+        let result = QueuedEmail::try_from(EmailModel {
+            to_addresses: "to@test.com".to_string(),
+            ..model(1_u32)
+        });
+        assert!(matches!(result, Err(EmailModelError::InvalidAddresses(_))));
+
+        let result = QueuedEmail::try_from(EmailModel {
+            id: "invalid".to_string(),
+            ..model(1_u32)
+        });
+        assert!(matches!(result, Err(EmailModelError::InvalidId(_))));
+
+        let email = QueuedEmail::try_from(model(3_i64)).unwrap();
+        assert_eq!(email.attempts, 3);
+
+        let result = QueuedEmail::try_from(model(-1_i64));
+        assert!(matches!(result, Err(EmailModelError::Attempts(_))));
+    }
+}
@@ -0,0 +1,159 @@
+//! External link model
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use sqlx::FromRow;
+use std::str::FromStr;
+use thiserror::Error;
+
+use crate::domain::{
+    entities::{external_link::ExternalLinkId, user::UserId},
+    use_cases::external_link::{ExternalLinkUseCaseResponse, ExternalLinkUserUseCaseResponse},
+    value_objects::{datetime::UtcDateTime, oidc_provider::OidcProvider},
+};
+
+/// Separator of the provider scopes stored in a single column
+pub const SCOPES_SEPARATOR: &str = " ";
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ExternalLinkModelError {
+    #[error("{0}")]
+    InvalidId(String),
+
+    #[error("{0}")]
+    InvalidProvider(String),
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ExternalLinkModel {
+    pub id: String,
+    pub name: String,
+    pub oidc_issuer: Option<String>,
+    pub oidc_client_id: Option<String>,
+    pub oidc_client_secret: Option<String>,
+    pub oidc_scopes: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+impl TryFrom<ExternalLinkModel> for ExternalLinkUseCaseResponse {
+    type Error = ExternalLinkModelError;
+
+    fn try_from(value: ExternalLinkModel) -> Result<Self, Self::Error> {
+        let id = ExternalLinkId::from_str(&value.id)
+            .map_err(|_| ExternalLinkModelError::InvalidId(format!("Invalid external link ID: `{}`", value.id)))?;
+        let provider = match (value.oidc_issuer, value.oidc_client_id, value.oidc_client_secret) {
+            (Some(issuer), Some(client_id), Some(client_secret)) => {
+                let scopes = value
+                    .oidc_scopes
+                    .unwrap_or_default()
+                    .split(SCOPES_SEPARATOR)
+                    .filter(|scope| !scope.is_empty())
+                    .map(str::to_string)
+                    .collect();
+                Some(
+                    OidcProvider::new(&issuer, &client_id, &client_secret, scopes)
+                        .map_err(|err| ExternalLinkModelError::InvalidProvider(err.to_string()))?,
+                )
+            }
+            _ => None,
+        };
+        let created_at = UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(value.created_at, Utc));
+        let updated_at = UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(value.updated_at, Utc));
+        let deleted_at = value
+            .deleted_at
+            .map(|deleted_at| UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(deleted_at, Utc)));
+
+        Ok(Self {
+            id,
+            name: value.name,
+            provider,
+            created_at,
+            updated_at,
+            deleted_at,
+        })
+    }
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ExternalLinkUserModel {
+    pub external_link_id: String,
+    pub user_id: String,
+    pub external_user_id: String,
+    pub created_at: NaiveDateTime,
+}
+
+impl TryFrom<ExternalLinkUserModel> for ExternalLinkUserUseCaseResponse {
+    type Error = ExternalLinkModelError;
+
+    fn try_from(value: ExternalLinkUserModel) -> Result<Self, Self::Error> {
+        let external_link_id = ExternalLinkId::from_str(&value.external_link_id).map_err(|_| {
+            ExternalLinkModelError::InvalidId(format!("Invalid external link ID: `{}`", value.external_link_id))
+        })?;
+        let user_id = UserId::from_str(&value.user_id)
+            .map_err(|_| ExternalLinkModelError::InvalidId(format!("Invalid user ID: `{}`", value.user_id)))?;
+
+        Ok(Self {
+            external_link_id,
+            user_id,
+            external_user_id: value.external_user_id,
+            created_at: UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(value.created_at, Utc)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_try_from_external_link_model_for_use_case_response() {
+        let id = uuid::Uuid::new_v4().to_string();
+        let model = ExternalLinkModel {
+            id: id.to_string(),
+            name: "name".to_string(),
+            oidc_issuer: None,
+            oidc_client_id: None,
+            oidc_client_secret: None,
+            oidc_scopes: None,
+            created_at: DateTime::from_timestamp(0, 0).unwrap().naive_utc(),
+            updated_at: DateTime::from_timestamp(0, 0).unwrap().naive_utc(),
+            deleted_at: None,
+        };
+        let expected = ExternalLinkUseCaseResponse {
+            id: ExternalLinkId::from_str(id.to_string().as_str()).unwrap(),
+            name: "name".to_string(),
+            provider: None,
+            created_at: UtcDateTime::new(DateTime::from_timestamp(0, 0).unwrap()),
+            updated_at: UtcDateTime::new(DateTime::from_timestamp(0, 0).unwrap()),
+            deleted_at: None,
+        };
+
+        assert_eq!(ExternalLinkUseCaseResponse::try_from(model).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_try_from_external_link_model_with_provider() {
+        let model = ExternalLinkModel {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: "name".to_string(),
+            oidc_issuer: Some("https://idp.test.com".to_string()),
+            oidc_client_id: Some("client".to_string()),
+            oidc_client_secret: Some("secret".to_string()),
+            oidc_scopes: Some("openid email profile".to_string()),
+            created_at: DateTime::from_timestamp(0, 0).unwrap().naive_utc(),
+            updated_at: DateTime::from_timestamp(0, 0).unwrap().naive_utc(),
+            deleted_at: None,
+        };
+        let expected = OidcProvider::new(
+            "https://idp.test.com",
+            "client",
+            "secret",
+            vec!["email".to_string(), "profile".to_string()],
+        )
+        .unwrap();
+
+        let response = ExternalLinkUseCaseResponse::try_from(model).unwrap();
+        assert_eq!(response.provider, Some(expected));
+    }
+}
@@ -0,0 +1,137 @@
+//! Federation models
+
+use crate::domain::entities::oidc_login_state::OidcLoginState;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use crate::domain::value_objects::oidc_provider::OidcProvider;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use sqlx::FromRow;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Separator of the provider scopes stored in a single column
+const SCOPES_SEPARATOR: &str = " ";
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum FederationModelError {
+    #[error("{0}")]
+    InvalidId(String),
+
+    #[error("{0}")]
+    InvalidProvider(String),
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct OidcProviderModel {
+    pub oidc_issuer: Option<String>,
+    pub oidc_client_id: Option<String>,
+    pub oidc_client_secret: Option<String>,
+    pub oidc_scopes: Option<String>,
+}
+
+impl OidcProviderModel {
+    /// Get the provider if it is configured
+    pub fn into_provider(self) -> Result<Option<OidcProvider>, FederationModelError> {
+        match (self.oidc_issuer, self.oidc_client_id, self.oidc_client_secret) {
+            (Some(issuer), Some(client_id), Some(client_secret)) => {
+                let scopes = self
+                    .oidc_scopes
+                    .unwrap_or_default()
+                    .split(SCOPES_SEPARATOR)
+                    .filter(|scope| !scope.is_empty())
+                    .map(str::to_string)
+                    .collect();
+
+                OidcProvider::new(&issuer, &client_id, &client_secret, scopes)
+                    .map(Some)
+                    .map_err(|err| FederationModelError::InvalidProvider(err.to_string()))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct OidcLoginStateModel {
+    pub state: String,
+    pub external_link_id: String,
+    pub application_id: String,
+    pub nonce: String,
+    pub expired_at: NaiveDateTime,
+}
+
+impl TryFrom<OidcLoginStateModel> for OidcLoginState {
+    type Error = FederationModelError;
+
+    fn try_from(value: OidcLoginStateModel) -> Result<Self, Self::Error> {
+        let external_link_id = Id::from_str(&value.external_link_id).map_err(|_| {
+            FederationModelError::InvalidId(format!("Invalid external link ID: `{}`", value.external_link_id))
+        })?;
+        let application_id = Id::from_str(&value.application_id).map_err(|_| {
+            FederationModelError::InvalidId(format!("Invalid application ID: `{}`", value.application_id))
+        })?;
+
+        Ok(Self {
+            state: value.state,
+            external_link_id,
+            application_id,
+            nonce: value.nonce,
+            expired_at: UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(value.expired_at, Utc)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oidc_provider_model_into_provider() {
+        let model = OidcProviderModel {
+            oidc_issuer: Some("https://idp.test.com".to_string()),
+            oidc_client_id: Some("client".to_string()),
+            oidc_client_secret: Some("secret".to_string()),
+            oidc_scopes: Some("openid email".to_string()),
+        };
+        let provider = model.clone().into_provider().unwrap().unwrap();
+        assert_eq!(provider.issuer(), "https://idp.test.com");
+        assert_eq!(provider.scopes(), &["openid".to_string(), "email".to_string()]);
+
+        let result = OidcProviderModel {
+            oidc_issuer: None,
+            ..model.clone()
+        }
+        .into_provider();
+        assert_eq!(result, Ok(None));
+
+        let result = OidcProviderModel {
+            oidc_issuer: Some("idp".to_string()),
+            ..model
+        }
+        .into_provider();
+        assert!(matches!(result, Err(FederationModelError::InvalidProvider(_))));
+    }
+
+    #[test]
+    fn test_try_from_oidc_login_state_model() {
+        let model = OidcLoginStateModel {
+            state: "state".to_string(),
+            external_link_id: "ffaa2c9c-872f-4e62-8302-d4586096cd13".to_string(),
+            application_id: "b4dc6179-e538-449b-accd-a8a1f58631af".to_string(),
+            nonce: "nonce".to_string(),
+            expired_at: Utc::now().naive_utc(),
+        };
+        let login_state: Result<OidcLoginState, _> = model.clone().try_into();
+        assert!(login_state.is_ok());
+
+        let login_state: Result<OidcLoginState, _> = OidcLoginStateModel {
+            application_id: "invalid".to_string(),
+            ..model
+        }
+        .try_into();
+        assert_eq!(
+            login_state.unwrap_err(),
+            FederationModelError::InvalidId("Invalid application ID: `invalid`".to_string())
+        );
+    }
+}
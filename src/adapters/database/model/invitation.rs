@@ -0,0 +1,112 @@
+//! Invitation model
+
+use crate::domain::entities::invitation::InvitationId;
+use crate::domain::entities::user::UserId;
+use crate::domain::use_cases::invitation::InvitationUseCaseResponse;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::email::Email;
+use crate::domain::value_objects::locale::Locale;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use sqlx::FromRow;
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum InvitationModelError {
+    #[error("{0}")]
+    Id(String),
+
+    #[error("{0}")]
+    Email(String),
+
+    #[error("{0}")]
+    Locale(String),
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct InvitationModel {
+    pub id: String,
+    pub user_id: String,
+    pub email: String,
+    pub lastname: String,
+    pub firstname: String,
+    pub locale: Option<String>,
+    pub expired_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl TryFrom<InvitationModel> for InvitationUseCaseResponse {
+    type Error = InvitationModelError;
+
+    fn try_from(value: InvitationModel) -> Result<Self, Self::Error> {
+        let id = InvitationId::from_str(&value.id)
+            .map_err(|_| InvitationModelError::Id(format!("Invalid invitation ID: `{}`", value.id)))?;
+        let user_id = UserId::from_str(&value.user_id)
+            .map_err(|_| InvitationModelError::Id(format!("Invalid user ID: `{}`", value.user_id)))?;
+        let email = Email::new(&value.email)
+            .map_err(|_| InvitationModelError::Email(format!("Invalid email: `{}`", value.email)))?;
+        let locale = value
+            .locale
+            .map(|locale| {
+                Locale::new(&locale).map_err(|_| InvitationModelError::Locale(format!("Invalid locale: `{locale}`")))
+            })
+            .transpose()?;
+        let expired_at = UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(value.expired_at, Utc));
+        let created_at = UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(value.created_at, Utc));
+        let updated_at = UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(value.updated_at, Utc));
+
+        Ok(Self {
+            id,
+            user_id,
+            email,
+            lastname: value.lastname,
+            firstname: value.firstname,
+            locale,
+            expired_at,
+            created_at,
+            updated_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_try_from_invitation_model_for_use_case_response() {
+        let id = uuid::Uuid::new_v4().to_string();
+        let user_id = uuid::Uuid::new_v4().to_string();
+        let model = InvitationModel {
+            id: id.clone(),
+            user_id: user_id.clone(),
+            email: "john.doe@test.com".to_string(),
+            lastname: "Doe".to_string(),
+            firstname: "John".to_string(),
+            locale: Some("fr".to_string()),
+            expired_at: DateTime::from_timestamp(3_600, 0).unwrap().naive_utc(),
+            created_at: DateTime::from_timestamp(0, 0).unwrap().naive_utc(),
+            updated_at: DateTime::from_timestamp(0, 0).unwrap().naive_utc(),
+        };
+        let invalid_model = InvitationModel {
+            email: "invalid".to_string(),
+            ..model.clone()
+        };
+        let expected = InvitationUseCaseResponse {
+            id: InvitationId::from_str(&id).unwrap(),
+            user_id: UserId::from_str(&user_id).unwrap(),
+            email: Email::new("john.doe@test.com").unwrap(),
+            lastname: "Doe".to_string(),
+            firstname: "John".to_string(),
+            locale: Some(Locale::new("fr").unwrap()),
+            expired_at: UtcDateTime::new(DateTime::from_timestamp(3_600, 0).unwrap()),
+            created_at: UtcDateTime::new(DateTime::from_timestamp(0, 0).unwrap()),
+            updated_at: UtcDateTime::new(DateTime::from_timestamp(0, 0).unwrap()),
+        };
+
+        assert_eq!(InvitationUseCaseResponse::try_from(model).unwrap(), expected);
+
+        assert!(InvitationUseCaseResponse::try_from(invalid_model).is_err());
+    }
+}
@@ -0,0 +1,21 @@
+//! Models of the MySQL, PostgreSQL and SQLite repositories
+//!
+//! The rows are converted to the domain types the same way by the SQL adapters.
+//! PostgreSQL has no unsigned integer type: the models with unsigned integers are generic
+//! over the stored integer type (`u32` by default, `i64` for PostgreSQL).
+
+pub mod application;
+pub mod audit;
+pub mod client;
+pub mod email;
+pub mod external_link;
+pub mod federation;
+pub mod invitation;
+pub mod scope;
+pub mod user;
+pub mod webhook;
+
+/// Convert a stored integer to `u32` (the stored value is returned if it is out of range)
+fn to_u32<N: TryInto<u32> + Copy>(value: N) -> Result<u32, N> {
+    value.try_into().map_err(|_| value)
+}
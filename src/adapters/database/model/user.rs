@@ -4,6 +4,7 @@ use crate::domain::use_cases::user::UserUseCaseResponse;
 use crate::domain::value_objects::datetime::UtcDateTime;
 use crate::domain::value_objects::email::Email;
 use crate::domain::value_objects::id::Id;
+use crate::domain::value_objects::locale::Locale;
 use chrono::{DateTime, NaiveDateTime, Utc};
 use sqlx::FromRow;
 use std::str::FromStr;
@@ -12,10 +13,13 @@ use thiserror::Error;
 #[derive(Debug, Clone, PartialEq, Error)]
 pub enum UserModelError {
     #[error("{0}")]
-    InvalidId(String),
+    Id(String),
 
     #[error("{0}")]
-    InvalidEmail(String),
+    Email(String),
+
+    #[error("{0}")]
+    Locale(String),
 }
 
 #[derive(Debug, Clone, FromRow)]
@@ -24,6 +28,7 @@ pub struct UserModel {
     pub lastname: String,
     pub firstname: String,
     pub email: String,
+    pub locale: Option<String>,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
     pub deleted_at: Option<NaiveDateTime>,
@@ -33,10 +38,15 @@ impl TryFrom<UserModel> for UserUseCaseResponse {
     type Error = UserModelError;
 
     fn try_from(value: UserModel) -> Result<Self, Self::Error> {
-        let id = Id::from_str(&value.id)
-            .map_err(|_| UserModelError::InvalidId(format!("Invalid user ID: `{}`", value.id)))?;
+        let id = Id::from_str(&value.id).map_err(|_| UserModelError::Id(format!("Invalid user ID: `{}`", value.id)))?;
         let email = Email::new(&value.email)
-            .map_err(|_| UserModelError::InvalidEmail(format!("Invalid user email: `{}`", value.email)))?;
+            .map_err(|_| UserModelError::Email(format!("Invalid user email: `{}`", value.email)))?;
+        let locale = value
+            .locale
+            .map(|locale| {
+                Locale::new(&locale).map_err(|_| UserModelError::Locale(format!("Invalid user locale: `{locale}`")))
+            })
+            .transpose()?;
         let created_at = UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(value.created_at, Utc));
         let updated_at = UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(value.updated_at, Utc));
         let deleted_at = value
@@ -48,6 +58,7 @@ impl TryFrom<UserModel> for UserUseCaseResponse {
             email,
             lastname: value.lastname,
             firstname: value.firstname,
+            locale,
             created_at,
             updated_at,
             deleted_at,
@@ -68,6 +79,7 @@ mod test {
             lastname: "lastname".to_string(),
             firstname: "firstname".to_string(),
             email: "test@test.com".to_string(),
+            locale: Some("fr_ca".to_string()),
             created_at: DateTime::from_timestamp(0, 0).unwrap().naive_utc(),
             updated_at: DateTime::from_timestamp(0, 0).unwrap().naive_utc(),
             deleted_at: None,
@@ -77,11 +89,21 @@ mod test {
             lastname: "lastname".to_string(),
             firstname: "firstname".to_string(),
             email: Email::new("test@test.com").unwrap(),
+            locale: Some(Locale::new("fr-CA").unwrap()),
             created_at: UtcDateTime::new(DateTime::from_timestamp(0, 0).unwrap()),
             updated_at: UtcDateTime::new(DateTime::from_timestamp(0, 0).unwrap()),
             deleted_at: None,
         };
 
-        assert_eq!(UserUseCaseResponse::try_from(model).unwrap(), expected);
+        assert_eq!(UserUseCaseResponse::try_from(model.clone()).unwrap(), expected);
+
+        let invalid_model = UserModel {
+            locale: Some("french".to_string()),
+            ..model
+        };
+        assert!(matches!(
+            UserUseCaseResponse::try_from(invalid_model),
+            Err(UserModelError::Locale(_))
+        ));
     }
 }
@@ -0,0 +1,189 @@
+//! Webhook models
+
+use crate::adapters::database::model::to_u32;
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::webhook::{Webhook, WebhookDelivery, WebhookDeliveryId, WebhookEvent, WebhookId};
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use crate::domain::value_objects::webhook_secret::WebhookSecret;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use sqlx::FromRow;
+use std::fmt::Display;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Separator used to store the events in a single column
+///
+/// The events are searched with `FIND_IN_SET` (MySQL), `string_to_array` (PostgreSQL) or `instr` (SQLite).
+pub const EVENTS_SEPARATOR: &str = ",";
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum WebhookModelError {
+    #[error("{0}")]
+    InvalidId(String),
+
+    #[error("{0}")]
+    InvalidEvent(String),
+
+    #[error("{0}")]
+    Attempts(String),
+}
+
+/// Parse the events stored in a single column
+fn parse_events(events: &str) -> Result<Vec<WebhookEvent>, WebhookModelError> {
+    events
+        .split(EVENTS_SEPARATOR)
+        .filter(|event| !event.is_empty())
+        .map(|event| WebhookEvent::from_str(event).map_err(WebhookModelError::InvalidEvent))
+        .collect()
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct WebhookModel {
+    pub id: String,
+    pub application_id: String,
+    pub url: String,
+    pub secret: String,
+    pub events: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+impl TryFrom<WebhookModel> for Webhook {
+    type Error = WebhookModelError;
+
+    fn try_from(value: WebhookModel) -> Result<Self, Self::Error> {
+        let id = WebhookId::from_str(&value.id)
+            .map_err(|_| WebhookModelError::InvalidId(format!("Invalid webhook ID: `{}`", value.id)))?;
+        let application_id = ApplicationId::from_str(&value.application_id)
+            .map_err(|_| WebhookModelError::InvalidId(format!("Invalid application ID: `{}`", value.application_id)))?;
+        let created_at = UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(value.created_at, Utc));
+        let updated_at = UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(value.updated_at, Utc));
+        let deleted_at = value
+            .deleted_at
+            .map(|deleted_at| UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(deleted_at, Utc)));
+
+        Ok(Self {
+            id,
+            application_id,
+            url: value.url,
+            secret: WebhookSecret::new(&value.secret),
+            events: parse_events(&value.events)?,
+            created_at,
+            updated_at,
+            deleted_at,
+        })
+    }
+}
+
+/// Webhook delivery row (`N`: stored integer type)
+#[derive(Debug, Clone, FromRow)]
+pub struct WebhookDeliveryModel<N = u32> {
+    pub id: String,
+    pub webhook_id: String,
+    pub url: String,
+    pub secret: String,
+    pub event_id: String,
+    pub event: String,
+    pub payload: String,
+    pub attempts: N,
+}
+
+impl<N: TryInto<u32> + Copy + Display> TryFrom<WebhookDeliveryModel<N>> for WebhookDelivery {
+    type Error = WebhookModelError;
+
+    fn try_from(value: WebhookDeliveryModel<N>) -> Result<Self, Self::Error> {
+        let id = WebhookDeliveryId::from_str(&value.id)
+            .map_err(|_| WebhookModelError::InvalidId(format!("Invalid webhook delivery ID: `{}`", value.id)))?;
+        let webhook_id = WebhookId::from_str(&value.webhook_id)
+            .map_err(|_| WebhookModelError::InvalidId(format!("Invalid webhook ID: `{}`", value.webhook_id)))?;
+        let event_id = Id::from_str(&value.event_id)
+            .map_err(|_| WebhookModelError::InvalidId(format!("Invalid event ID: `{}`", value.event_id)))?;
+        let event = WebhookEvent::from_str(&value.event).map_err(WebhookModelError::InvalidEvent)?;
+
+        Ok(Self {
+            id,
+            webhook_id,
+            url: value.url,
+            secret: WebhookSecret::new(&value.secret),
+            event_id,
+            event,
+            payload: value.payload,
+            attempts: to_u32(value.attempts)
+                .map_err(|attempts| WebhookModelError::Attempts(format!("Invalid attempts: `{attempts}`")))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model() -> WebhookModel {
+        WebhookModel {
+            id: "3f6c2a8e-1d4b-4e7a-9c5f-8b2d6e0a4c17".to_string(),
+            application_id: "ffaa2c9c-872f-4e62-8302-d4586096cd13".to_string(),
+            url: "https://app.test.com/webhooks".to_string(),
+            secret: "whsec_test".to_string(),
+            events: "user.created,sessions.revoked".to_string(),
+            created_at: NaiveDateTime::default(),
+            updated_at: NaiveDateTime::default(),
+            deleted_at: None,
+        }
+    }
+
+    #[test]
+    fn test_webhook_model_try_from() {
+        let webhook = Webhook::try_from(model()).unwrap();
+        assert_eq!(
+            webhook.events,
+            vec![WebhookEvent::UserCreated, WebhookEvent::SessionsRevoked]
+        );
+        assert_eq!(webhook.secret.value(), "whsec_test");
+
+        let result = Webhook::try_from(WebhookModel {
+            events: "user.created,user.updated".to_string(),
+            ..model()
+        });
+        assert!(matches!(result, Err(WebhookModelError::InvalidEvent(_))));
+
+        let result = Webhook::try_from(WebhookModel {
+            id: "invalid".to_string(),
+            ..model()
+        });
+        assert!(matches!(result, Err(WebhookModelError::InvalidId(_))));
+    }
+
+    fn delivery_model<N>(attempts: N) -> WebhookDeliveryModel<N> {
+        WebhookDeliveryModel {
+            id: "a7e4b1c9-5f2d-4a8e-b3c6-9d0f7e2a1b54".to_string(),
+            webhook_id: "3f6c2a8e-1d4b-4e7a-9c5f-8b2d6e0a4c17".to_string(),
+            url: "https://app.test.com/webhooks".to_string(),
+            secret: "whsec_test".to_string(),
+            event_id: "ffaa2c9c-872f-4e62-8302-d4586096cd13".to_string(),
+            event: "user.deleted".to_string(),
+            payload: "{}".to_string(),
+            attempts,
+        }
+    }
+
+    #[test]
+    fn test_webhook_delivery_model_try_from() {
+        let delivery = WebhookDelivery::try_from(delivery_model(2_u32)).unwrap();
+        assert_eq!(delivery.event, WebhookEvent::UserDeleted);
+        assert_eq!(delivery.attempts, 2);
+
+        let delivery = WebhookDelivery::try_from(delivery_model(2_i64)).unwrap();
+        assert_eq!(delivery.attempts, 2);
+
+        let result = WebhookDelivery::try_from(WebhookDeliveryModel {
+            event: "unknown".to_string(),
+            ..delivery_model(2_u32)
+        });
+        assert!(matches!(result, Err(WebhookModelError::InvalidEvent(_))));
+
+        let result = WebhookDelivery::try_from(delivery_model(-1_i64));
+        assert!(matches!(result, Err(WebhookModelError::Attempts(_))));
+    }
+}
@@ -1,12 +1,14 @@
 //! MySQL adapter
 
+use crate::adapters::database::query::{Conditions, sql_comparison, valid_filters, valid_sorts};
 use crate::adapters::database::{DatabaseError, GenericDb};
 use crate::config::Config;
-use crate::domain::value_objects::pagination::{PAGINATION_MAX_LIMIT, Pagination};
-use crate::domain::value_objects::query_sort::{QuerySort, QuerySorts};
+use crate::domain::value_objects::pagination::{PAGINATION_MAX_LIMIT, Pagination, PaginationCursor};
+use crate::domain::value_objects::query_filter::{QueryFilterFieldValue, QueryFilters};
+use crate::domain::value_objects::query_sort::{QuerySortDirection, QuerySorts};
 use async_trait::async_trait;
-use sqlx::mysql::MySqlPoolOptions;
-use sqlx::{MySql, Pool};
+use sqlx::mysql::{MySqlPoolOptions, MySqlRow};
+use sqlx::{FromRow, MySql, Pool, QueryBuilder, Row};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -81,9 +83,33 @@ impl From<Pagination> for MysqlPagination {
 }
 
 impl MysqlPagination {
-    /// Generate SQL code for pagination
-    pub fn to_sql(&self) -> String {
-        format!(" LIMIT {} OFFSET {}", self.limit, self.offset)
+    /// Push the `LIMIT` and `OFFSET` clauses with bound values
+    pub fn push_sql(&self, query: &mut QueryBuilder<'_, MySql>) {
+        query
+            .push(" LIMIT ")
+            .push_bind(self.limit)
+            .push(" OFFSET ")
+            .push_bind(self.offset);
+    }
+}
+
+/// `WHERE` clause of a MySQL query
+pub type MysqlConditions<'q, 'args> = Conditions<'q, 'args, MySql>;
+
+/// Push an `ORDER BY` clause (nothing if there is no sort)
+fn push_order_by<'s>(
+    query: &mut QueryBuilder<'_, MySql>,
+    sorts: impl IntoIterator<Item = (&'s str, &'s QuerySortDirection)>,
+) {
+    for (i, (column, direction)) in sorts.into_iter().enumerate() {
+        query
+            .push(match i {
+                0 => " ORDER BY ",
+                _ => ", ",
+            })
+            .push(column)
+            .push(" ")
+            .push(direction);
     }
 }
 
@@ -92,21 +118,276 @@ impl MysqlPagination {
 pub struct MysqlQuerySorts(pub QuerySorts);
 
 impl MysqlQuerySorts {
-    /// Generate SQL code for sorts (ORDER BY)
-    pub fn to_sql(&self, valid_fields: &[&str]) -> String {
-        self.0
-            .0
-            .iter()
-            .filter(|QuerySort { field, direction: _ }| valid_fields.contains(&field.as_str()))
-            .enumerate()
-            .map(|(i, QuerySort { field, direction: sort })| {
-                if i == 0 {
-                    format!(" ORDER BY {field} {sort}")
-                } else {
-                    format!(", {field} {sort}")
+    /// Sorts of the valid fields, with the column of the valid field
+    ///
+    /// A valid field can be prefixed by its table (Ex.: `u.email` is used for the `email` sort).
+    fn valid<'a>(&'a self, valid_fields: &'a [&str]) -> impl Iterator<Item = (&'a str, &'a QuerySortDirection)> {
+        valid_sorts(&self.0, valid_fields)
+    }
+
+    /// Check if at least one sort is on a valid field
+    pub fn has_valid_fields(&self, valid_fields: &[&str]) -> bool {
+        self.valid(valid_fields).next().is_some()
+    }
+
+    /// Push the `ORDER BY` clause of the valid fields
+    ///
+    /// Only the columns of `valid_fields` are written in the query.
+    pub fn push_sql(&self, query: &mut QueryBuilder<'_, MySql>, valid_fields: &[&str]) {
+        push_order_by(query, self.valid(valid_fields));
+    }
+}
+
+/// Query filters for MySQL queries
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MysqlQueryFilters(pub QueryFilters);
+
+impl MysqlQueryFilters {
+    /// Conditions of the valid fields, with the column of the valid field
+    fn conditions<'a>(
+        &'a self,
+        valid_fields: &'a [&str],
+    ) -> impl Iterator<Item = (&'a str, &'a QueryFilterFieldValue<String>)> {
+        valid_filters(&self.0, valid_fields, |column| column).map(|(column, value)| (*column, value))
+    }
+
+    /// Add the conditions of the valid fields
+    ///
+    /// Only the columns of `valid_fields` are written in the query, the values are bound.
+    pub fn push_sql(&self, conditions: &mut MysqlConditions<'_, '_>, valid_fields: &[&str]) {
+        for (column, QueryFilterFieldValue { operator, values }) in self.conditions(valid_fields) {
+            let query = conditions.push();
+            query.push(column);
+
+            query.push(sql_comparison(operator));
+
+            match operator.is_multiple() {
+                true => {
+                    query.push("(");
+                    let mut list = query.separated(", ");
+                    for value in values {
+                        list.push_bind(value.clone());
+                    }
+                    list.push_unseparated(")");
                 }
-            })
-            .collect::<String>()
+                false => {
+                    query.push_bind(values.first().cloned().unwrap_or_default());
+                }
+            }
+        }
+    }
+}
+
+/// Row of a list query: the model, the sort key and the ID used to build the next cursor
+///
+/// The query must select the columns of [`MysqlCursorPagination::push_select`].
+#[derive(Debug, Clone)]
+pub struct MysqlCursorRow<M> {
+    pub model: M,
+    pub cursor_value: Option<String>,
+    pub cursor_id: String,
+}
+
+impl<'r, M: FromRow<'r, MySqlRow>> FromRow<'r, MySqlRow> for MysqlCursorRow<M> {
+    fn from_row(row: &'r MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            model: M::from_row(row)?,
+            cursor_value: row.try_get("cursor_value")?,
+            cursor_id: row.try_get("cursor_id")?,
+        })
+    }
+}
+
+/// Pagination of list queries, in offset mode (`LIMIT/OFFSET`) or in cursor mode (keyset)
+///
+/// In cursor mode, the rows are sorted by the first valid sort field then by ID,
+/// and the page starts after the sort key and the ID of the cursor.
+/// One more row than the limit is fetched to know if there is a next page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MysqlCursorPagination {
+    /// Valid sorts, with the columns of the valid fields
+    sorts: Vec<(String, QuerySortDirection)>,
+    id_column: String,
+    cursor: Option<PaginationCursor>,
+    limit: u32,
+
+    /// `LIMIT/OFFSET` in offset mode (`None` in cursor mode)
+    offset: Option<MysqlPagination>,
+}
+
+impl MysqlCursorPagination {
+    /// Create a new pagination
+    ///
+    /// A valid field can be prefixed by its table (Ex.: `u.email` is used for the `email` sort).
+    pub fn new(pagination: Pagination, sorts: &MysqlQuerySorts, valid_fields: &[&str], id_column: &str) -> Self {
+        let sorts = sorts
+            .valid(valid_fields)
+            .map(|(column, direction)| (column.to_string(), direction.clone()))
+            .collect();
+        let keyset = pagination.is_cursor();
+        let cursor = pagination.cursor().cloned();
+        let mysql_pagination = MysqlPagination::from(pagination);
+
+        Self {
+            sorts,
+            id_column: id_column.to_string(),
+            cursor,
+            limit: mysql_pagination.limit,
+            offset: match keyset {
+                true => None,
+                false => Some(mysql_pagination),
+            },
+        }
+    }
+
+    /// Sort column and direction in cursor mode (`None` to sort by ID only)
+    fn sort(&self) -> Option<&(String, QuerySortDirection)> {
+        self.sorts.first().filter(|_| self.offset.is_none())
+    }
+
+    /// Sort direction (by ID only: ascending)
+    fn direction(&self) -> QuerySortDirection {
+        self.sort().map(|(_, direction)| direction.clone()).unwrap_or_default()
+    }
+
+    /// Push the sort key and ID columns, to add to the selected columns
+    pub fn push_select(&self, query: &mut QueryBuilder<'_, MySql>) {
+        match self.sort() {
+            Some((column, _)) => query.push(", CAST(").push(column).push(" AS CHAR)"),
+            None => query.push(", CAST(NULL AS CHAR)"),
+        };
+        query
+            .push(" AS cursor_value, ")
+            .push(&self.id_column)
+            .push(" AS cursor_id");
+    }
+
+    /// Add the condition of the rows after the cursor (nothing in offset mode or for the first page)
+    ///
+    /// MySQL sorts `NULL` values first in ascending order and last in descending order.
+    pub fn push_condition(&self, conditions: &mut MysqlConditions<'_, '_>) {
+        let Some(cursor) = self.cursor.as_ref().filter(|_| self.offset.is_none()) else {
+            return;
+        };
+        let id = &self.id_column;
+        let cursor_id = cursor.id().to_string();
+        let query = conditions.push();
+
+        match (self.sort(), cursor.value(), self.direction()) {
+            (None, _, _) => {
+                query.push(id).push(" > ").push_bind(cursor_id);
+            }
+            (Some((column, _)), Some(value), QuerySortDirection::Asc) => {
+                query
+                    .push(format_args!("({column} > "))
+                    .push_bind(value.to_string())
+                    .push(format_args!(" OR ({column} = "))
+                    .push_bind(value.to_string())
+                    .push(format_args!(" AND {id} > "))
+                    .push_bind(cursor_id)
+                    .push("))");
+            }
+            (Some((column, _)), Some(value), QuerySortDirection::Desc) => {
+                query
+                    .push(format_args!("({column} < "))
+                    .push_bind(value.to_string())
+                    .push(format_args!(" OR {column} IS NULL OR ({column} = "))
+                    .push_bind(value.to_string())
+                    .push(format_args!(" AND {id} < "))
+                    .push_bind(cursor_id)
+                    .push("))");
+            }
+            (Some((column, _)), None, QuerySortDirection::Asc) => {
+                query
+                    .push(format_args!("({column} IS NOT NULL OR {id} > "))
+                    .push_bind(cursor_id)
+                    .push(")");
+            }
+            (Some((column, _)), None, QuerySortDirection::Desc) => {
+                query
+                    .push(format_args!("({column} IS NULL AND {id} < "))
+                    .push_bind(cursor_id)
+                    .push(")");
+            }
+        }
+    }
+
+    /// Push the sorts and the pagination (`ORDER BY` and `LIMIT`)
+    pub fn push_order(&self, query: &mut QueryBuilder<'_, MySql>) {
+        match &self.offset {
+            Some(pagination) => {
+                push_order_by(
+                    query,
+                    self.sorts
+                        .iter()
+                        .map(|(column, direction)| (column.as_str(), direction)),
+                );
+                pagination.push_sql(query);
+            }
+            None => {
+                let direction = self.direction();
+                push_order_by(
+                    query,
+                    self.sort()
+                        .map(|(column, _)| (column.as_str(), &direction))
+                        .into_iter()
+                        .chain([(self.id_column.as_str(), &direction)]),
+                );
+                query.push(" LIMIT ").push_bind(self.limit + 1);
+            }
+        }
+    }
+
+    /// Page models and the cursor of the next page (only in cursor mode, if there is a next page)
+    pub fn page<M>(&self, mut rows: Vec<MysqlCursorRow<M>>) -> (Vec<M>, Option<PaginationCursor>) {
+        let limit = self.limit as usize;
+        let next_cursor = match self.offset.is_none() && rows.len() > limit {
+            true => {
+                rows.truncate(limit);
+                rows.last()
+                    .map(|row| PaginationCursor::new(row.cursor_value.clone(), row.cursor_id.clone()))
+            }
+            false => None,
+        };
+
+        (rows.into_iter().map(|row| row.model).collect(), next_cursor)
+    }
+}
+
+/// Full-text search for MySQL queries (`MATCH ... AGAINST ... IN BOOLEAN MODE`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MysqlFullTextSearch(pub String);
+
+impl MysqlFullTextSearch {
+    /// Generate the boolean mode search expression
+    ///
+    /// The search is split into words on the characters which are not part of an InnoDB word
+    /// (this also removes the boolean mode operators). Each word is required (`+`), so that all
+    /// the words of the search must match, and is used as a prefix (`*`) so that partial names
+    /// or emails match. Returns `None` if there is no word to search.
+    ///
+    /// The users full-text index is created without stopwords (see the migrations), otherwise
+    /// a required stopword such as `com` would never match.
+    pub fn to_boolean_query(&self) -> Option<String> {
+        let words = self
+            .0
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .filter(|word| !word.is_empty())
+            .map(|word| format!("+{word}*"))
+            .collect::<Vec<_>>();
+
+        match words.is_empty() {
+            true => None,
+            false => Some(words.join(" ")),
+        }
+    }
+
+    /// Push the search expression on the given columns, with the boolean query bound
+    pub fn push_sql(query: &mut QueryBuilder<'_, MySql>, columns: &[&str], boolean_query: &str) {
+        query
+            .push(format_args!("MATCH ({}) AGAINST (", columns.join(", ")))
+            .push_bind(boolean_query.to_string())
+            .push(" IN BOOLEAN MODE)");
     }
 }
 
@@ -114,7 +395,22 @@ impl MysqlQuerySorts {
 mod tests {
     use super::*;
     use crate::domain::value_objects::pagination::Pagination;
-    use crate::domain::value_objects::query_sort::QuerySortDirection;
+    use crate::domain::value_objects::query_filter::QueryFilterError;
+    use crate::domain::value_objects::query_sort::QuerySort;
+    use std::collections::HashMap;
+
+    /// SQL code pushed by `push` on an empty query
+    fn sql(push: impl FnOnce(&mut QueryBuilder<'_, MySql>)) -> String {
+        let mut query = QueryBuilder::new("");
+        push(&mut query);
+
+        query.sql().to_string()
+    }
+
+    /// SQL code of the conditions added by `push` on an empty query
+    fn conditions_sql(push: impl FnOnce(&mut MysqlConditions<'_, '_>)) -> String {
+        sql(|query| push(&mut MysqlConditions::new(query)))
+    }
 
     #[test]
     fn test_from_pagination_mysql_pagination() {
@@ -132,18 +428,16 @@ mod tests {
     }
 
     #[test]
-    fn test_mysql_pagination_to_sql() {
-        let pagination = MysqlPagination::from(Pagination::new(1, 50));
-        assert_eq!(String::from(" LIMIT 50 OFFSET 0"), pagination.to_sql());
-
+    fn test_mysql_pagination_push_sql() {
         let pagination = MysqlPagination::from(Pagination::new(3, 50));
-        assert_eq!(String::from(" LIMIT 50 OFFSET 100"), pagination.to_sql());
+        assert_eq!(" LIMIT ? OFFSET ?".to_owned(), sql(|query| pagination.push_sql(query)));
     }
 
     #[test]
     fn test_mysql_query_sorts_without_sort() {
         let mysql_sorts = MysqlQuerySorts(QuerySorts::default());
-        assert_eq!(String::new(), mysql_sorts.to_sql(&[]));
+        assert_eq!(String::new(), sql(|query| mysql_sorts.push_sql(query, &[])));
+        assert!(!mysql_sorts.has_valid_fields(&["id"]));
     }
 
     #[test]
@@ -151,13 +445,14 @@ mod tests {
         let valid_fields: &[&str] = &[];
 
         let mut mysql_sorts = MysqlQuerySorts(QuerySorts::default());
-        assert_eq!(String::new(), mysql_sorts.to_sql(valid_fields));
+        assert_eq!(String::new(), sql(|query| mysql_sorts.push_sql(query, valid_fields)));
 
         mysql_sorts = MysqlQuerySorts(QuerySorts(vec![
             QuerySort::new("id".to_owned(), QuerySortDirection::Asc),
             QuerySort::new("name".to_owned(), QuerySortDirection::Desc),
         ]));
-        assert_eq!(String::new(), mysql_sorts.to_sql(valid_fields));
+        assert_eq!(String::new(), sql(|query| mysql_sorts.push_sql(query, valid_fields)));
+        assert!(!mysql_sorts.has_valid_fields(valid_fields));
     }
 
     #[test]
@@ -165,7 +460,7 @@ mod tests {
         let valid_fields = &["id", "name"];
 
         let mut mysql_sorts = MysqlQuerySorts(QuerySorts::default());
-        assert_eq!(String::new(), mysql_sorts.to_sql(valid_fields));
+        assert_eq!(String::new(), sql(|query| mysql_sorts.push_sql(query, valid_fields)));
 
         mysql_sorts = MysqlQuerySorts(QuerySorts(vec![
             QuerySort::new("id".to_owned(), QuerySortDirection::Asc),
@@ -173,44 +468,314 @@ mod tests {
         ]));
         assert_eq!(
             " ORDER BY id ASC, name DESC".to_owned(),
-            mysql_sorts.to_sql(valid_fields)
+            sql(|query| mysql_sorts.push_sql(query, valid_fields))
         );
+        assert!(mysql_sorts.has_valid_fields(valid_fields));
 
         let valid_fields: &[&str] = &["name"];
-        assert_eq!(" ORDER BY name DESC".to_owned(), mysql_sorts.to_sql(valid_fields));
+        assert_eq!(
+            " ORDER BY name DESC".to_owned(),
+            sql(|query| mysql_sorts.push_sql(query, valid_fields))
+        );
 
         let valid_fields: &[&str] = &["id", "name"];
         mysql_sorts = MysqlQuerySorts(QuerySorts(vec![
             QuerySort::new("idz".to_owned(), QuerySortDirection::Asc),
             QuerySort::new("name".to_owned(), QuerySortDirection::Desc),
         ]));
-        assert_eq!(" ORDER BY name DESC".to_owned(), mysql_sorts.to_sql(valid_fields));
+        assert_eq!(
+            " ORDER BY name DESC".to_owned(),
+            sql(|query| mysql_sorts.push_sql(query, valid_fields))
+        );
 
         let valid_fields: &[&str] = &["id", "name"];
         mysql_sorts = MysqlQuerySorts(QuerySorts(vec![
             QuerySort::new("id".to_owned(), QuerySortDirection::Asc),
             QuerySort::new("namee".to_owned(), QuerySortDirection::Desc),
         ]));
-        assert_eq!(" ORDER BY id ASC".to_owned(), mysql_sorts.to_sql(valid_fields));
+        assert_eq!(
+            " ORDER BY id ASC".to_owned(),
+            sql(|query| mysql_sorts.push_sql(query, valid_fields))
+        );
 
         let valid_fields: &[&str] = &["id", "name"];
         mysql_sorts = MysqlQuerySorts(QuerySorts(vec![
             QuerySort::new("idz".to_owned(), QuerySortDirection::Asc),
             QuerySort::new("namee".to_owned(), QuerySortDirection::Desc),
         ]));
-        assert_eq!("".to_owned(), mysql_sorts.to_sql(valid_fields));
+        assert_eq!("".to_owned(), sql(|query| mysql_sorts.push_sql(query, valid_fields)));
+        assert!(!mysql_sorts.has_valid_fields(valid_fields));
     }
 
     #[test]
     fn test_mysql_query_sorts_with_valid_fields_and_table_prefix() {
         let valid_fields: &[&str] = &["user.id", "role.name"];
         let mysql_sorts = MysqlQuerySorts(QuerySorts(vec![
-            QuerySort::new("user.id".to_owned(), QuerySortDirection::Asc),
+            QuerySort::new("id".to_owned(), QuerySortDirection::Asc),
             QuerySort::new("role.name".to_owned(), QuerySortDirection::Desc),
         ]));
         assert_eq!(
             " ORDER BY user.id ASC, role.name DESC".to_owned(),
-            mysql_sorts.to_sql(valid_fields)
+            sql(|query| mysql_sorts.push_sql(query, valid_fields))
+        );
+    }
+
+    #[test]
+    fn test_mysql_query_sorts_with_injection() {
+        let mysql_sorts = MysqlQuerySorts(QuerySorts(vec![QuerySort::new(
+            "id; DROP TABLE users".to_owned(),
+            QuerySortDirection::Asc,
+        )]));
+        assert_eq!(String::new(), sql(|query| mysql_sorts.push_sql(query, &["id"])));
+    }
+
+    fn filters(params: &[(&str, &str)]) -> Result<MysqlQueryFilters, QueryFilterError> {
+        let params = params
+            .iter()
+            .map(|(field, values)| (field.to_string(), values.to_string()))
+            .collect::<HashMap<_, _>>();
+
+        Ok(MysqlQueryFilters(QueryFilters::try_from(params)?))
+    }
+
+    #[test]
+    fn test_mysql_query_filters_push_sql() {
+        let mysql_filters = MysqlQueryFilters(QueryFilters::default());
+        assert_eq!(
+            String::new(),
+            conditions_sql(|conditions| mysql_filters.push_sql(conditions, &["name"]))
+        );
+
+        let mysql_filters = filters(&[
+            ("name", "in:foo,bar"),
+            ("created_at", "gte:2024-01-01,lt:2025-01-01"),
+            ("email", "ne:john@test.com"),
+        ])
+        .unwrap();
+        assert_eq!(
+            " WHERE created_at >= ? AND created_at < ? AND email <> ? AND name IN (?, ?)".to_owned(),
+            conditions_sql(|conditions| mysql_filters.push_sql(conditions, &["name", "email", "created_at"]))
+        );
+    }
+
+    #[test]
+    fn test_mysql_query_filters_push_sql_with_valid_fields() {
+        let mysql_filters = filters(&[("name", "nin:foo"), ("password", "eq:secret"), ("id", "1")]).unwrap();
+
+        assert_eq!(
+            String::new(),
+            conditions_sql(|conditions| mysql_filters.push_sql(conditions, &[]))
+        );
+        assert_eq!(
+            " WHERE name NOT IN (?)".to_owned(),
+            conditions_sql(|conditions| mysql_filters.push_sql(conditions, &["name"]))
+        );
+        assert_eq!(
+            " WHERE deleted_at IS NULL AND id = ? AND name NOT IN (?)".to_owned(),
+            conditions_sql(|conditions| {
+                conditions.push_soft_delete("deleted_at", false);
+                mysql_filters.push_sql(conditions, &["id", "name"]);
+            })
+        );
+    }
+
+    #[test]
+    fn test_mysql_full_text_search_to_boolean_query() {
+        assert_eq!(MysqlFullTextSearch(String::new()).to_boolean_query(), None);
+        assert_eq!(MysqlFullTextSearch("  +-@* ".to_string()).to_boolean_query(), None);
+        assert_eq!(
+            MysqlFullTextSearch("Doe".to_string()).to_boolean_query(),
+            Some("+Doe*".to_string())
+        );
+        assert_eq!(
+            MysqlFullTextSearch("john.doe@test.com -Smith (Jérôme)".to_string()).to_boolean_query(),
+            Some("+john* +doe* +test* +com* +Smith* +Jérôme*".to_string())
+        );
+    }
+
+    #[test]
+    fn test_mysql_full_text_search_push_sql() {
+        assert_eq!(
+            "MATCH (email, firstname) AGAINST (? IN BOOLEAN MODE)".to_owned(),
+            sql(|query| MysqlFullTextSearch::push_sql(query, &["email", "firstname"], "+Doe*"))
+        );
+    }
+
+    #[test]
+    fn test_mysql_cursor_pagination_offset_mode() {
+        let sorts = MysqlQuerySorts(QuerySorts::from("-email,+created_at"));
+        let pagination =
+            MysqlCursorPagination::new(Pagination::new(3, 10), &sorts, &["u.email", "i.created_at"], "i.id");
+
+        assert_eq!(
+            ", CAST(NULL AS CHAR) AS cursor_value, i.id AS cursor_id".to_owned(),
+            sql(|query| pagination.push_select(query))
+        );
+        assert_eq!(
+            String::new(),
+            conditions_sql(|conditions| pagination.push_condition(conditions))
+        );
+        assert_eq!(
+            " ORDER BY u.email DESC, i.created_at ASC LIMIT ? OFFSET ?".to_owned(),
+            sql(|query| pagination.push_order(query))
+        );
+
+        let rows = (1..=10)
+            .map(|i| MysqlCursorRow {
+                model: i,
+                cursor_value: None,
+                cursor_id: i.to_string(),
+            })
+            .collect();
+        assert_eq!(pagination.page(rows), ((1..=10).collect(), None));
+    }
+
+    #[test]
+    fn test_mysql_cursor_pagination_cursor_mode() {
+        let valid_fields = ["id", "lastname", "deleted_at"];
+        let cursor = |value: Option<&str>| Some(PaginationCursor::new(value.map(str::to_string), "42".to_string()));
+        let condition =
+            |pagination: &MysqlCursorPagination| conditions_sql(|conditions| pagination.push_condition(conditions));
+
+        // First page, sort by ID only
+        let sorts = MysqlQuerySorts(QuerySorts::from("+unknown"));
+        let pagination = MysqlCursorPagination::new(Pagination::new_cursor(None, 10), &sorts, &valid_fields, "id");
+        assert_eq!(
+            ", CAST(NULL AS CHAR) AS cursor_value, id AS cursor_id".to_owned(),
+            sql(|query| pagination.push_select(query))
+        );
+        assert_eq!(String::new(), condition(&pagination));
+        assert_eq!(
+            " ORDER BY id ASC LIMIT ?".to_owned(),
+            sql(|query| pagination.push_order(query))
+        );
+
+        let pagination =
+            MysqlCursorPagination::new(Pagination::new_cursor(cursor(None), 10), &sorts, &valid_fields, "id");
+        assert_eq!(" WHERE id > ?".to_owned(), condition(&pagination));
+
+        // Only the first sort is used
+        let sorts = MysqlQuerySorts(QuerySorts::from("-lastname,+id"));
+        let pagination = MysqlCursorPagination::new(
+            Pagination::new_cursor(cursor(Some("Doe")), 10),
+            &sorts,
+            &valid_fields,
+            "id",
+        );
+        assert_eq!(
+            ", CAST(lastname AS CHAR) AS cursor_value, id AS cursor_id".to_owned(),
+            sql(|query| pagination.push_select(query))
+        );
+        assert_eq!(
+            " WHERE (lastname < ? OR lastname IS NULL OR (lastname = ? AND id < ?))".to_owned(),
+            condition(&pagination)
+        );
+        assert_eq!(
+            " ORDER BY lastname DESC, id DESC LIMIT ?".to_owned(),
+            sql(|query| pagination.push_order(query))
+        );
+
+        let sorts = MysqlQuerySorts(QuerySorts::from("+lastname"));
+        let pagination = MysqlCursorPagination::new(
+            Pagination::new_cursor(cursor(Some("Doe")), 10),
+            &sorts,
+            &valid_fields,
+            "id",
+        );
+        assert_eq!(
+            " WHERE (lastname > ? OR (lastname = ? AND id > ?))".to_owned(),
+            condition(&pagination)
+        );
+
+        // NULL sort key
+        let sorts = MysqlQuerySorts(QuerySorts::from("+deleted_at"));
+        let pagination =
+            MysqlCursorPagination::new(Pagination::new_cursor(cursor(None), 10), &sorts, &valid_fields, "id");
+        assert_eq!(
+            " WHERE (deleted_at IS NOT NULL OR id > ?)".to_owned(),
+            condition(&pagination)
+        );
+
+        let sorts = MysqlQuerySorts(QuerySorts::from("-deleted_at"));
+        let pagination =
+            MysqlCursorPagination::new(Pagination::new_cursor(cursor(None), 10), &sorts, &valid_fields, "id");
+        assert_eq!(
+            " WHERE (deleted_at IS NULL AND id < ?)".to_owned(),
+            condition(&pagination)
+        );
+    }
+
+    #[test]
+    fn test_mysql_cursor_pagination_page() {
+        let sorts = MysqlQuerySorts(QuerySorts::from("+lastname"));
+        let pagination = MysqlCursorPagination::new(Pagination::new_cursor(None, 2), &sorts, &["lastname"], "id");
+        let rows = |count: usize| {
+            ["Doe", "Smith", "Wayne"]
+                .into_iter()
+                .take(count)
+                .enumerate()
+                .map(|(i, lastname)| MysqlCursorRow {
+                    model: lastname,
+                    cursor_value: Some(lastname.to_string()),
+                    cursor_id: i.to_string(),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Next page
+        assert_eq!(
+            pagination.page(rows(3)),
+            (
+                vec!["Doe", "Smith"],
+                Some(PaginationCursor::new(Some("Smith".to_string()), "1".to_string()))
+            )
+        );
+
+        // Last page
+        assert_eq!(pagination.page(rows(2)), (vec!["Doe", "Smith"], None));
+    }
+
+    /// Scenarios shared by the database adapters, run on the server of `MYSQL_TEST_DATABASE_URL`
+    ///
+    /// They are ignored by default: `cargo test repositories -- --ignored` to run them.
+    mod repositories {
+        use super::{Config, Db, GenericDb};
+        use sqlx::{Connection, MySqlConnection};
+
+        /// Migrated new database
+        ///
+        /// Each test has its own database, so that the tests can run concurrently on the same server.
+        /// The databases (`test_*`) are not dropped: the server should be a disposable one.
+        async fn db() -> Db {
+            let url = std::env::var("MYSQL_TEST_DATABASE_URL").expect("MYSQL_TEST_DATABASE_URL must be set");
+            let database = format!("test_{}", uuid::Uuid::new_v4().simple());
+
+            let mut connection = MySqlConnection::connect(&url).await.unwrap();
+            sqlx::query(&format!("CREATE DATABASE {database}"))
+                .execute(&mut connection)
+                .await
+                .unwrap();
+            connection.close().await.unwrap();
+
+            let mut url = reqwest::Url::parse(&url).unwrap();
+            url.set_path(&database);
+            let config = Config {
+                database_url: url.to_string(),
+                database_auto_migration: true,
+                database_max_connections: 2,
+                database_min_connections: 0,
+                database_max_lifetime: 60,
+                database_connect_timeout: 10,
+                database_idle_timeout: 60,
+                ..Config::default()
+            };
+
+            Db::new(&config).await.unwrap()
+        }
+
+        repository_tests!(
+            #[ignore = "requires a MySQL database"]
+            db()
         );
     }
 }
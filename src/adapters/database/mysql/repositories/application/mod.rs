@@ -1,9 +1,9 @@
 //! Application MySQL repository
 
-mod model;
-
-use crate::adapters::database::mysql::repositories::application::model::ApplicationModel;
-use crate::adapters::database::mysql::{Db, MysqlPagination, MysqlQuerySorts};
+use crate::adapters::database::model::application::ApplicationModel;
+use crate::adapters::database::mysql::{
+    Db, MysqlConditions, MysqlCursorPagination, MysqlCursorRow, MysqlQueryFilters, MysqlQuerySorts,
+};
 use crate::domain::repositories::application::ApplicationRepository;
 use crate::domain::repositories::application::dto::{
     CountApplicationsDtoRequest, CountApplicationsDtoResponse, CreateApplicationDtoRequest,
@@ -19,9 +19,12 @@ use crate::domain::use_cases::application::{ApplicationUseCaseError, Application
 use crate::domain::value_objects::datetime::UtcDateTime;
 use crate::domain::value_objects::id::Id;
 use async_trait::async_trait;
-use sqlx::Row;
+use sqlx::QueryBuilder;
 use std::sync::Arc;
 
+/// Fields available for filtering the applications
+const FILTER_FIELDS: [&str; 6] = ["id", "name", "audience", "created_at", "updated_at", "deleted_at"];
+
 /// Application MySQL repository
 #[derive(Debug, Clone)]
 pub struct ApplicationMysqlRepository {
@@ -50,11 +53,14 @@ impl ApplicationRepository for ApplicationMysqlRepository {
 
         sqlx::query!(
             "
-            INSERT INTO applications (id, name, created_at, updated_at, deleted_at)
-            VALUES (?, ?, ?, ?, NULL)
+            INSERT INTO applications (id, name, access_token_lifetime, refresh_token_lifetime, audience, created_at, updated_at, deleted_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, NULL)
         ",
             application_id.clone().to_string(),
             req.0.name,
+            req.0.access_token_lifetime,
+            req.0.refresh_token_lifetime,
+            req.0.audience,
             now.value(),
             now.value()
         )
@@ -68,6 +74,9 @@ impl ApplicationRepository for ApplicationMysqlRepository {
         Ok(CreateApplicationDtoResponse(ApplicationUseCaseResponse {
             id: application_id,
             name: req.0.name,
+            access_token_lifetime: req.0.access_token_lifetime,
+            refresh_token_lifetime: req.0.refresh_token_lifetime,
+            audience: req.0.audience,
             created_at: now.clone(),
             updated_at: now,
             deleted_at: None,
@@ -80,9 +89,9 @@ impl ApplicationRepository for ApplicationMysqlRepository {
         req: GetApplicationByIdDtoRequest,
     ) -> Result<GetApplicationByIdDtoResponse, ApplicationUseCaseError> {
         let result = sqlx::query_as!(
-            ApplicationModel,
+            ApplicationModel::<u32>,
             "
-            SELECT id, name, created_at, updated_at, deleted_at
+            SELECT id, name, access_token_lifetime, refresh_token_lifetime, audience, created_at, updated_at, deleted_at
             FROM applications
             WHERE id = ?
                 AND deleted_at IS NULL",
@@ -111,33 +120,40 @@ impl ApplicationRepository for ApplicationMysqlRepository {
         &self,
         req: GetApplicationsDtoRequest,
     ) -> Result<GetApplicationsDtoResponse, ApplicationUseCaseError> {
-        let mut query = String::from(
-            r#"
-            SELECT id, name, created_at, updated_at, deleted_at
-            FROM applications
-        "#,
+        // Sorts & pagination
+        let sorts = MysqlQuerySorts(req.0.sorts.unwrap_or_default());
+        let pagination = MysqlCursorPagination::new(
+            req.0.pagination,
+            &sorts,
+            &["id", "name", "updated_at", "deleted_at"],
+            "id",
         );
 
-        query.push_str(match req.0.deleted {
-            true => " WHERE deleted_at IS NOT NULL",
-            false => " WHERE deleted_at IS NULL",
-        });
+        let mut query = QueryBuilder::new(
+            "SELECT id, name, access_token_lifetime, refresh_token_lifetime, audience, created_at, updated_at, deleted_at",
+        );
+        pagination.push_select(&mut query);
+        query.push(" FROM applications");
 
-        // Sorts
-        let sorts = MysqlQuerySorts(req.0.sorts.unwrap_or_default());
-        query.push_str(&sorts.to_sql(&["id", "name", "updated_at", "deleted_at"]));
+        // Filters
+        let mut conditions = MysqlConditions::new(&mut query);
+        conditions.push_soft_delete("deleted_at", req.0.deleted);
+        MysqlQueryFilters(req.0.filters.unwrap_or_default()).push_sql(&mut conditions, &FILTER_FIELDS);
+        pagination.push_condition(&mut conditions);
 
-        // Pagination
-        let pagination = MysqlPagination::from(req.0.pagination);
-        query.push_str(&pagination.to_sql());
+        pagination.push_order(&mut query);
 
-        let applications = sqlx::query_as::<_, ApplicationModel>(&query)
+        let rows = query
+            .build_query_as::<MysqlCursorRow<ApplicationModel>>()
             .fetch_all(self.db.pool.clone().as_ref())
             .await
             .map_err(|err| {
                 error!(error = %err, "Failed to get applications");
                 ApplicationUseCaseError::DatabaseError("Failed to get applications".to_string())
-            })?
+            })?;
+        let (applications, next_cursor) = pagination.page(rows);
+
+        let applications = applications
             .into_iter()
             .map(ApplicationUseCaseResponse::try_from)
             .collect::<Result<Vec<ApplicationUseCaseResponse>, _>>()
@@ -146,7 +162,10 @@ impl ApplicationRepository for ApplicationMysqlRepository {
                 ApplicationUseCaseError::FromModelError()
             })?;
 
-        Ok(GetApplicationsDtoResponse(applications))
+        Ok(GetApplicationsDtoResponse {
+            applications,
+            next_cursor,
+        })
     }
 
     #[instrument(skip(self), name = "application_repository_update")]
@@ -157,10 +176,13 @@ impl ApplicationRepository for ApplicationMysqlRepository {
         let result = sqlx::query!(
             "
             UPDATE applications
-            SET name = ?, updated_at = ?
+            SET name = ?, access_token_lifetime = ?, refresh_token_lifetime = ?, audience = ?, updated_at = ?
             WHERE id = ?
                 AND deleted_at IS NULL",
             req.0.name,
+            req.0.access_token_lifetime,
+            req.0.refresh_token_lifetime,
+            req.0.audience,
             UtcDateTime::now().value(),
             req.0.id.to_string()
         )
@@ -211,13 +233,14 @@ impl ApplicationRepository for ApplicationMysqlRepository {
         &self,
         req: CountApplicationsDtoRequest,
     ) -> Result<CountApplicationsDtoResponse, ApplicationUseCaseError> {
-        let mut query = String::from("SELECT COUNT(*) AS total FROM applications");
-        query.push_str(match req.deleted {
-            true => " WHERE deleted_at IS NOT NULL",
-            false => " WHERE deleted_at IS NULL",
-        });
+        let mut query = QueryBuilder::new("SELECT COUNT(*) AS total FROM applications");
+
+        let mut conditions = MysqlConditions::new(&mut query);
+        conditions.push_soft_delete("deleted_at", req.deleted);
+        MysqlQueryFilters(req.filters.unwrap_or_default()).push_sql(&mut conditions, &FILTER_FIELDS);
 
-        let result = sqlx::query(&query)
+        let (total,) = query
+            .build_query_as::<(i64,)>()
             .fetch_one(self.db.pool.clone().as_ref())
             .await
             .map_err(|err| {
@@ -225,7 +248,7 @@ impl ApplicationRepository for ApplicationMysqlRepository {
                 ApplicationUseCaseError::DatabaseError("Failed to count applications".to_string())
             })?;
 
-        Ok(CountApplicationsDtoResponse(result.try_get("total")?))
+        Ok(CountApplicationsDtoResponse(total))
     }
 
     #[instrument(skip(self), name = "application_repository_restore")]
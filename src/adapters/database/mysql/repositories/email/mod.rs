@@ -0,0 +1,161 @@
+//! Email outbox MySQL repository
+
+use crate::adapters::database::model::email::{EmailModel, join_addresses};
+use crate::adapters::database::mysql::Db;
+use crate::domain::entities::email::{EmailMessage, EmailStatus, QueuedEmail};
+use crate::domain::repositories::email::EmailRepository;
+use crate::domain::repositories::email::dto::{
+    GetDueEmailsDtoRequest, GetDueEmailsDtoResponse, UpdateEmailDtoRequest, UpdateEmailDtoResponse,
+};
+use crate::domain::use_cases::email::EmailUseCaseError;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use async_trait::async_trait;
+use chrono::Duration;
+use sqlx::MySqlConnection;
+use std::sync::Arc;
+
+/// Time during which an email fetched by a worker is not fetched again (in second)
+///
+/// It prevents several servers from sending the same email,
+/// and the email is retried after this delay if the worker stops before saving the result.
+const EMAIL_LEASE: i64 = 300;
+
+/// Add an email to the outbox
+///
+/// It is called by the other repositories with their transaction,
+/// so that the email is only sent if the action which triggered it is saved.
+pub async fn enqueue_email(conn: &mut MySqlConnection, message: &EmailMessage) -> Result<(), sqlx::Error> {
+    let id = Id::new().map_err(|err| sqlx::Error::Protocol(err.to_string()))?;
+    let now = UtcDateTime::now();
+
+    sqlx::query!(
+        "
+        INSERT INTO email_outbox (id, from_address, to_addresses, subject, text_body, html_body, status, attempts, next_attempt_at, last_error, created_at, sent_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, 0, ?, NULL, ?, NULL)
+    ",
+        id.to_string(),
+        message.from_address,
+        join_addresses(&message.to_addresses),
+        message.subject,
+        message.text_body,
+        message.html_body,
+        EmailStatus::Pending.as_str(),
+        now.value(),
+        now.value()
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Email outbox MySQL repository
+#[derive(Debug, Clone)]
+pub struct EmailMysqlRepository {
+    db: Arc<Db>,
+}
+
+impl EmailMysqlRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl EmailRepository for EmailMysqlRepository {
+    #[instrument(skip(self), name = "email_repository_get_due_emails")]
+    async fn get_due_emails(&self, req: GetDueEmailsDtoRequest) -> Result<GetDueEmailsDtoResponse, EmailUseCaseError> {
+        let now = UtcDateTime::now();
+
+        let mut tx = self.db.pool.begin().await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            EmailUseCaseError::DatabaseError("Failed to get emails".to_string())
+        })?;
+
+        // Rows locked by another server are skipped
+        let emails = sqlx::query_as!(
+            EmailModel::<u32>,
+            "
+            SELECT id, from_address, to_addresses, subject, text_body, html_body, attempts
+            FROM email_outbox
+            WHERE status = ?
+                AND next_attempt_at <= ?
+            ORDER BY next_attempt_at
+            LIMIT ?
+            FOR UPDATE SKIP LOCKED",
+            EmailStatus::Pending.as_str(),
+            now.value(),
+            req.limit
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get emails");
+            EmailUseCaseError::DatabaseError("Failed to get emails".to_string())
+        })?
+        .into_iter()
+        .map(QueuedEmail::try_from)
+        .collect::<Result<Vec<QueuedEmail>, _>>()
+        .map_err(|err| {
+            error!(error = %err, "Failed to convert email model to queued email entity");
+            EmailUseCaseError::FromModelError()
+        })?;
+
+        // Lease
+        let lease_until = now.value() + Duration::seconds(EMAIL_LEASE);
+        for email in &emails {
+            sqlx::query!(
+                "
+                UPDATE email_outbox
+                SET next_attempt_at = ?
+                WHERE id = ?",
+                lease_until,
+                email.id.to_string()
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to lease email");
+                EmailUseCaseError::DatabaseError("Failed to get emails".to_string())
+            })?;
+        }
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            EmailUseCaseError::DatabaseError("Failed to get emails".to_string())
+        })?;
+
+        Ok(GetDueEmailsDtoResponse(emails))
+    }
+
+    #[instrument(skip(self), name = "email_repository_update_email")]
+    async fn update_email(&self, req: UpdateEmailDtoRequest) -> Result<UpdateEmailDtoResponse, EmailUseCaseError> {
+        let sent_at = match req.status {
+            EmailStatus::Sent => Some(UtcDateTime::now().value()),
+            _ => None,
+        };
+
+        sqlx::query!(
+            "
+            UPDATE email_outbox
+            SET status = ?, attempts = ?, next_attempt_at = ?, last_error = ?, sent_at = ?
+            WHERE id = ?",
+            req.status.as_str(),
+            req.attempts,
+            req.next_attempt_at.value(),
+            req.last_error,
+            sent_at,
+            req.id.to_string()
+        )
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to update email");
+            EmailUseCaseError::DatabaseError("Failed to update email".to_string())
+        })?;
+
+        Ok(UpdateEmailDtoResponse())
+    }
+}
@@ -1,9 +1,14 @@
 //! MySQL repositories errors
 
 use crate::domain::use_cases::application::ApplicationUseCaseError;
+use crate::domain::use_cases::audit::AuditUseCaseError;
 use crate::domain::use_cases::external_link::ExternalLinkUseCaseError;
+use crate::domain::use_cases::federation::FederationUseCaseError;
+use crate::domain::use_cases::introspection::IntrospectionUseCaseError;
+use crate::domain::use_cases::invitation::InvitationUseCaseError;
 use crate::domain::use_cases::scope::ScopeUseCaseError;
 use crate::domain::use_cases::user::UserUseCaseError;
+use crate::domain::use_cases::webhook::WebhookUseCaseError;
 
 impl From<sqlx::error::Error> for UserUseCaseError {
     fn from(err: sqlx::error::Error) -> Self {
@@ -32,3 +37,38 @@ impl From<sqlx::error::Error> for ExternalLinkUseCaseError {
         Self::DatabaseError("Database error".to_string())
     }
 }
+
+impl From<sqlx::error::Error> for InvitationUseCaseError {
+    fn from(err: sqlx::error::Error) -> Self {
+        error!(error = %err, "Database error");
+        Self::DatabaseError("Database error".to_string())
+    }
+}
+
+impl From<sqlx::error::Error> for IntrospectionUseCaseError {
+    fn from(err: sqlx::error::Error) -> Self {
+        error!(error = %err, "Database error");
+        Self::DatabaseError("Database error".to_string())
+    }
+}
+
+impl From<sqlx::error::Error> for FederationUseCaseError {
+    fn from(err: sqlx::error::Error) -> Self {
+        error!(error = %err, "Database error");
+        Self::DatabaseError("Database error".to_string())
+    }
+}
+
+impl From<sqlx::error::Error> for AuditUseCaseError {
+    fn from(err: sqlx::error::Error) -> Self {
+        error!(error = %err, "Database error");
+        Self::DatabaseError("Database error".to_string())
+    }
+}
+
+impl From<sqlx::error::Error> for WebhookUseCaseError {
+    fn from(err: sqlx::error::Error) -> Self {
+        error!(error = %err, "Database error");
+        Self::DatabaseError("Database error".to_string())
+    }
+}
@@ -1,9 +1,10 @@
 //! External link MySQL repository
 
-mod model;
-
+use crate::adapters::database::model::external_link::{ExternalLinkModel, ExternalLinkUserModel, SCOPES_SEPARATOR};
 use crate::{
-    adapters::database::mysql::{Db, MysqlPagination, MysqlQuerySorts},
+    adapters::database::mysql::{
+        Db, MysqlConditions, MysqlCursorPagination, MysqlCursorRow, MysqlQueryFilters, MysqlQuerySorts,
+    },
     domain::{
         repositories::external_link::{
             ExternalLinkRepository,
@@ -11,24 +12,30 @@ use crate::{
                 CountExternalLinksDtoRequest, CountExternalLinksDtoResponse, CreateExternalLinkDtoRequest,
                 CreateExternalLinkDtoResponse, DeleteExternalLinkDtoRequest, DeleteExternalLinkDtoResponse,
                 GetExternalLinkByIdDtoRequest, GetExternalLinkByIdDtoResponse, GetExternalLinksDtoRequest,
-                GetExternalLinksDtoResponse, RestoreExternalLinkDtoRequest, RestoreExternalLinkDtoResponse,
+                GetExternalLinksDtoResponse, GetLinkedUserDtoRequest, GetLinkedUserDtoResponse,
+                LinkApplicationDtoRequest, LinkApplicationDtoResponse, LinkUserDtoRequest, LinkUserDtoResponse,
+                RestoreExternalLinkDtoRequest, RestoreExternalLinkDtoResponse, UnlinkApplicationDtoRequest,
+                UnlinkApplicationDtoResponse, UnlinkUserDtoRequest, UnlinkUserDtoResponse,
                 UpdateExternalLinkDtoRequest, UpdateExternalLinkDtoResponse,
             },
         },
         use_cases::external_link::{
-            ExternalLinkUseCaseError, ExternalLinkUseCaseResponse,
-            delete_external_link::DeleteExternalLinkUseCaseResponse,
+            ExternalLinkApplicationUseCaseResponse, ExternalLinkUseCaseError, ExternalLinkUseCaseResponse,
+            ExternalLinkUserUseCaseResponse, delete_external_link::DeleteExternalLinkUseCaseResponse,
             restore_external_link::RestoreExternalLinkUseCaseResponse,
+            unlink_application::UnlinkApplicationUseCaseResponse, unlink_user::UnlinkUserUseCaseResponse,
             update_external_link::UpdateExternalLinkUseCaseResponse,
         },
-        value_objects::{datetime::UtcDateTime, id::Id},
+        value_objects::{datetime::UtcDateTime, id::Id, oidc_provider::OidcProvider},
     },
 };
 use async_trait::async_trait;
-use model::ExternalLinkModel;
-use sqlx::Row;
+use sqlx::QueryBuilder;
 use std::sync::Arc;
 
+/// Fields available for filtering the external links
+const FILTER_FIELDS: [&str; 6] = ["id", "name", "oidc_issuer", "created_at", "updated_at", "deleted_at"];
+
 /// External link MySQL repository
 #[derive(Debug, Clone)]
 pub struct ExternalLinkMysqlRepository {
@@ -40,6 +47,21 @@ impl ExternalLinkMysqlRepository {
     pub fn new(db: Db) -> Self {
         Self { db: Arc::new(db) }
     }
+
+    /// Provider columns: issuer, client ID, client secret and scopes
+    fn provider_columns(
+        provider: &Option<OidcProvider>,
+    ) -> (Option<String>, Option<String>, Option<String>, Option<String>) {
+        match provider {
+            Some(provider) => (
+                Some(provider.issuer().to_string()),
+                Some(provider.client_id().to_string()),
+                Some(provider.client_secret().to_string()),
+                Some(provider.scopes().join(SCOPES_SEPARATOR)),
+            ),
+            None => (None, None, None, None),
+        }
+    }
 }
 
 #[async_trait]
@@ -54,14 +76,19 @@ impl ExternalLinkRepository for ExternalLinkMysqlRepository {
             ExternalLinkUseCaseError::InvalidId()
         })?;
         let now = UtcDateTime::now();
+        let (issuer, client_id, client_secret, scopes) = Self::provider_columns(&req.0.provider);
 
         sqlx::query!(
             "
-            INSERT INTO external_links (id, name, created_at, updated_at, deleted_at)
-            VALUES (?, ?, ?, ?, NULL)
+            INSERT INTO external_links (id, name, oidc_issuer, oidc_client_id, oidc_client_secret, oidc_scopes, created_at, updated_at, deleted_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, NULL)
         ",
             external_link_id.clone().to_string(),
             req.0.name,
+            issuer,
+            client_id,
+            client_secret,
+            scopes,
             now.value(),
             now.value()
         )
@@ -75,6 +102,7 @@ impl ExternalLinkRepository for ExternalLinkMysqlRepository {
         Ok(CreateExternalLinkDtoResponse(ExternalLinkUseCaseResponse {
             id: external_link_id,
             name: req.0.name,
+            provider: req.0.provider,
             created_at: now.clone(),
             updated_at: now,
             deleted_at: None,
@@ -86,13 +114,14 @@ impl ExternalLinkRepository for ExternalLinkMysqlRepository {
         &self,
         req: CountExternalLinksDtoRequest,
     ) -> Result<CountExternalLinksDtoResponse, ExternalLinkUseCaseError> {
-        let mut query = String::from("SELECT COUNT(*) AS total FROM external_links");
-        query.push_str(match req.deleted {
-            true => " WHERE deleted_at IS NOT NULL",
-            false => " WHERE deleted_at IS NULL",
-        });
+        let mut query = QueryBuilder::new("SELECT COUNT(*) AS total FROM external_links");
+
+        let mut conditions = MysqlConditions::new(&mut query);
+        conditions.push_soft_delete("deleted_at", req.deleted);
+        MysqlQueryFilters(req.filters.unwrap_or_default()).push_sql(&mut conditions, &FILTER_FIELDS);
 
-        let result = sqlx::query(&query)
+        let (total,) = query
+            .build_query_as::<(i64,)>()
             .fetch_one(self.db.pool.clone().as_ref())
             .await
             .map_err(|err| {
@@ -100,7 +129,7 @@ impl ExternalLinkRepository for ExternalLinkMysqlRepository {
                 ExternalLinkUseCaseError::DatabaseError("Failed to count external links".to_string())
             })?;
 
-        Ok(CountExternalLinksDtoResponse(result.try_get("total")?))
+        Ok(CountExternalLinksDtoResponse(total))
     }
 
     #[instrument(skip(self), name = "external_link_repository_get_all")]
@@ -108,33 +137,40 @@ impl ExternalLinkRepository for ExternalLinkMysqlRepository {
         &self,
         req: GetExternalLinksDtoRequest,
     ) -> Result<GetExternalLinksDtoResponse, ExternalLinkUseCaseError> {
-        let mut query = String::from(
-            r#"
-            SELECT id, name, created_at, updated_at, deleted_at
-            FROM external_links
-        "#,
+        // Sorts & pagination
+        let sorts = MysqlQuerySorts(req.0.sorts.unwrap_or_default());
+        let pagination = MysqlCursorPagination::new(
+            req.0.pagination,
+            &sorts,
+            &["name", "created_at", "updated_at", "deleted_at"],
+            "id",
         );
 
-        query.push_str(match req.0.deleted {
-            true => " WHERE deleted_at IS NOT NULL",
-            false => " WHERE deleted_at IS NULL",
-        });
+        let mut query = QueryBuilder::new(
+            "SELECT id, name, oidc_issuer, oidc_client_id, oidc_client_secret, oidc_scopes, created_at, updated_at, deleted_at",
+        );
+        pagination.push_select(&mut query);
+        query.push(" FROM external_links");
 
-        // Sorts
-        let sorts = MysqlQuerySorts(req.0.sorts.unwrap_or_default());
-        query.push_str(&sorts.to_sql(&["name", "created_at", "updated_at", "deleted_at"]));
+        // Filters
+        let mut conditions = MysqlConditions::new(&mut query);
+        conditions.push_soft_delete("deleted_at", req.0.deleted);
+        MysqlQueryFilters(req.0.filters.unwrap_or_default()).push_sql(&mut conditions, &FILTER_FIELDS);
+        pagination.push_condition(&mut conditions);
 
-        // Pagination
-        let pagination = MysqlPagination::from(req.0.pagination);
-        query.push_str(&pagination.to_sql());
+        pagination.push_order(&mut query);
 
-        let external_links = sqlx::query_as::<_, ExternalLinkModel>(&query)
+        let rows = query
+            .build_query_as::<MysqlCursorRow<ExternalLinkModel>>()
             .fetch_all(self.db.pool.clone().as_ref())
             .await
             .map_err(|err| {
                 error!(error = %err, "Failed to get external links");
                 ExternalLinkUseCaseError::DatabaseError("Failed to get external links".to_string())
-            })?
+            })?;
+        let (external_links, next_cursor) = pagination.page(rows);
+
+        let external_links = external_links
             .into_iter()
             .map(ExternalLinkUseCaseResponse::try_from)
             .collect::<Result<Vec<ExternalLinkUseCaseResponse>, _>>()
@@ -143,7 +179,10 @@ impl ExternalLinkRepository for ExternalLinkMysqlRepository {
                 ExternalLinkUseCaseError::FromModelError()
             })?;
 
-        Ok(GetExternalLinksDtoResponse(external_links))
+        Ok(GetExternalLinksDtoResponse {
+            external_links,
+            next_cursor,
+        })
     }
 
     #[instrument(skip(self), name = "external_link_repository_get_by_id")]
@@ -154,7 +193,7 @@ impl ExternalLinkRepository for ExternalLinkMysqlRepository {
         let result = sqlx::query_as!(
             ExternalLinkModel,
             "
-            SELECT id, name, created_at, updated_at, deleted_at
+            SELECT id, name, oidc_issuer, oidc_client_id, oidc_client_secret, oidc_scopes, created_at, updated_at, deleted_at
             FROM external_links
             WHERE id = ?
                 AND deleted_at IS NULL",
@@ -183,13 +222,19 @@ impl ExternalLinkRepository for ExternalLinkMysqlRepository {
         &self,
         req: UpdateExternalLinkDtoRequest,
     ) -> Result<UpdateExternalLinkDtoResponse, ExternalLinkUseCaseError> {
+        let (issuer, client_id, client_secret, scopes) = Self::provider_columns(&req.0.provider);
+
         let result = sqlx::query!(
             "
             UPDATE external_links
-            SET name = ?, updated_at = ?
+            SET name = ?, oidc_issuer = ?, oidc_client_id = ?, oidc_client_secret = ?, oidc_scopes = ?, updated_at = ?
             WHERE id = ?
                 AND deleted_at IS NULL",
             req.0.name,
+            issuer,
+            client_id,
+            client_secret,
+            scopes,
             UtcDateTime::now().value(),
             req.0.id.to_string()
         )
@@ -261,4 +306,205 @@ impl ExternalLinkRepository for ExternalLinkMysqlRepository {
 
         Ok(RestoreExternalLinkDtoResponse(RestoreExternalLinkUseCaseResponse()))
     }
+
+    #[instrument(skip(self), name = "external_link_repository_link_user")]
+    async fn link_user(&self, req: LinkUserDtoRequest) -> Result<LinkUserDtoResponse, ExternalLinkUseCaseError> {
+        let now = UtcDateTime::now();
+
+        let mut tx = self.db.pool.begin().await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            ExternalLinkUseCaseError::DatabaseError("Failed to link user".to_string())
+        })?;
+
+        // Replace the previous external user ID
+        sqlx::query!(
+            "
+            DELETE FROM external_links_users
+            WHERE external_link_id = ?
+                AND user_id = ?",
+            req.0.external_link_id.to_string(),
+            req.0.user_id.to_string()
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to delete previous user link");
+            ExternalLinkUseCaseError::DatabaseError("Failed to link user".to_string())
+        })?;
+
+        // The link is only created if the user exists
+        let result = sqlx::query!(
+            "
+            INSERT INTO external_links_users (external_link_id, user_id, external_user_id, created_at)
+            SELECT ?, id, ?, ?
+            FROM users
+            WHERE id = ?
+                AND deleted_at IS NULL",
+            req.0.external_link_id.to_string(),
+            req.0.external_user_id,
+            now.value(),
+            req.0.user_id.to_string()
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to link user");
+            match err {
+                sqlx::Error::Database(err) if err.is_unique_violation() => {
+                    ExternalLinkUseCaseError::ExternalUserIdAlreadyUsed()
+                }
+                _ => ExternalLinkUseCaseError::DatabaseError("Failed to link user".to_string()),
+            }
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(ExternalLinkUseCaseError::UserNotFound());
+        }
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            ExternalLinkUseCaseError::DatabaseError("Failed to link user".to_string())
+        })?;
+
+        Ok(LinkUserDtoResponse(ExternalLinkUserUseCaseResponse {
+            external_link_id: req.0.external_link_id,
+            user_id: req.0.user_id,
+            external_user_id: req.0.external_user_id,
+            created_at: now,
+        }))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_unlink_user")]
+    async fn unlink_user(&self, req: UnlinkUserDtoRequest) -> Result<UnlinkUserDtoResponse, ExternalLinkUseCaseError> {
+        let result = sqlx::query!(
+            "
+            DELETE FROM external_links_users
+            WHERE external_link_id = ?
+                AND user_id = ?",
+            req.0.external_link_id.to_string(),
+            req.0.user_id.to_string()
+        )
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to unlink user");
+            ExternalLinkUseCaseError::DatabaseError("Failed to unlink user".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(ExternalLinkUseCaseError::ExternalLinkUserNotFound())?;
+        }
+
+        Ok(UnlinkUserDtoResponse(UnlinkUserUseCaseResponse()))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_get_linked_user")]
+    async fn get_linked_user(
+        &self,
+        req: GetLinkedUserDtoRequest,
+    ) -> Result<GetLinkedUserDtoResponse, ExternalLinkUseCaseError> {
+        let result = sqlx::query_as!(
+            ExternalLinkUserModel,
+            "
+            SELECT elu.external_link_id, elu.user_id, elu.external_user_id, elu.created_at
+            FROM external_links_users elu
+            INNER JOIN external_links el ON el.id = elu.external_link_id AND el.deleted_at IS NULL
+            INNER JOIN users u ON u.id = elu.user_id AND u.deleted_at IS NULL
+            WHERE elu.external_link_id = ?
+                AND elu.external_user_id = ?",
+            req.0.external_link_id.to_string(),
+            req.0.external_user_id
+        )
+        .fetch_optional(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get linked user");
+            ExternalLinkUseCaseError::DatabaseError("Failed to get linked user".to_string())
+        })?;
+
+        let link = result
+            .map(ExternalLinkUserUseCaseResponse::try_from)
+            .transpose()
+            .map_err(|err| {
+                error!(error = %err, "Failed to convert linked user model to use case response");
+                ExternalLinkUseCaseError::FromModelError()
+            })?;
+
+        Ok(GetLinkedUserDtoResponse(link))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_link_application")]
+    async fn link_application(
+        &self,
+        req: LinkApplicationDtoRequest,
+    ) -> Result<LinkApplicationDtoResponse, ExternalLinkUseCaseError> {
+        let now = UtcDateTime::now();
+
+        let application = sqlx::query!(
+            "
+            SELECT id
+            FROM applications
+            WHERE id = ?
+                AND deleted_at IS NULL",
+            req.0.application_id.to_string()
+        )
+        .fetch_optional(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get application");
+            ExternalLinkUseCaseError::DatabaseError("Failed to link application".to_string())
+        })?;
+        if application.is_none() {
+            return Err(ExternalLinkUseCaseError::ApplicationNotFound());
+        }
+
+        // Linking twice has no effect
+        sqlx::query!(
+            "
+            INSERT IGNORE INTO external_links_applications (external_link_id, application_id, created_at)
+            VALUES (?, ?, ?)",
+            req.0.external_link_id.to_string(),
+            req.0.application_id.to_string(),
+            now.value()
+        )
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to link application");
+            ExternalLinkUseCaseError::DatabaseError("Failed to link application".to_string())
+        })?;
+
+        Ok(LinkApplicationDtoResponse(ExternalLinkApplicationUseCaseResponse {
+            external_link_id: req.0.external_link_id,
+            application_id: req.0.application_id,
+            created_at: now,
+        }))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_unlink_application")]
+    async fn unlink_application(
+        &self,
+        req: UnlinkApplicationDtoRequest,
+    ) -> Result<UnlinkApplicationDtoResponse, ExternalLinkUseCaseError> {
+        let result = sqlx::query!(
+            "
+            DELETE FROM external_links_applications
+            WHERE external_link_id = ?
+                AND application_id = ?",
+            req.0.external_link_id.to_string(),
+            req.0.application_id.to_string()
+        )
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to unlink application");
+            ExternalLinkUseCaseError::DatabaseError("Failed to unlink application".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(ExternalLinkUseCaseError::ExternalLinkApplicationNotFound())?;
+        }
+
+        Ok(UnlinkApplicationDtoResponse(UnlinkApplicationUseCaseResponse()))
+    }
 }
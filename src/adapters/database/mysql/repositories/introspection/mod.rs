@@ -0,0 +1,145 @@
+//! Introspection MySQL repository
+
+use crate::adapters::database::mysql::Db;
+use crate::domain::repositories::introspection::IntrospectionRepository;
+use crate::domain::repositories::introspection::dto::{
+    ClientCredentials, GetClientCredentialsDtoRequest, GetClientCredentialsDtoResponse, GetUserScopesDtoRequest,
+    GetUserScopesDtoResponse, IsAccessTokenActiveDtoRequest, IsAccessTokenActiveDtoResponse,
+};
+use crate::domain::use_cases::introspection::IntrospectionUseCaseError;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use crate::domain::value_objects::password::Password;
+use crate::domain::value_objects::scope_id::ScopeId;
+use async_trait::async_trait;
+use sqlx::Row;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Introspection MySQL repository
+#[derive(Debug, Clone)]
+pub struct IntrospectionMysqlRepository {
+    db: Arc<Db>,
+}
+
+impl IntrospectionMysqlRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl IntrospectionRepository for IntrospectionMysqlRepository {
+    #[instrument(skip(self), name = "introspection_repository_get_client_credentials")]
+    async fn get_client_credentials(
+        &self,
+        req: GetClientCredentialsDtoRequest,
+    ) -> Result<GetClientCredentialsDtoResponse, IntrospectionUseCaseError> {
+        let rows = sqlx::query!(
+            r#"
+                SELECT c.application_id, cs.secret
+                FROM clients c
+                INNER JOIN client_secrets cs ON cs.client_id = c.id
+                INNER JOIN applications a ON a.id = c.application_id AND a.deleted_at IS NULL
+                WHERE c.id = ?
+                    AND c.deleted_at IS NULL
+            "#,
+            req.0.to_string()
+        )
+        .fetch_all(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get client credentials");
+            IntrospectionUseCaseError::DatabaseError("Failed to get client credentials".to_string())
+        })?;
+
+        let Some(first) = rows.first() else {
+            return Ok(GetClientCredentialsDtoResponse(None));
+        };
+        let application_id = Id::from_str(&first.application_id)?;
+        let secrets = rows
+            .iter()
+            .map(|row| Password::new(&row.secret, true))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| {
+                error!(error = %err, "Failed to convert client secret");
+                IntrospectionUseCaseError::FromModelError()
+            })?;
+
+        Ok(GetClientCredentialsDtoResponse(Some(ClientCredentials {
+            client_id: req.0,
+            application_id,
+            secrets,
+        })))
+    }
+
+    #[instrument(skip(self, req), name = "introspection_repository_is_access_token_active")]
+    async fn is_access_token_active(
+        &self,
+        req: IsAccessTokenActiveDtoRequest,
+    ) -> Result<IsAccessTokenActiveDtoResponse, IntrospectionUseCaseError> {
+        // An access token is revoked as soon as its refresh token is used, deleted or expired
+        let query = r#"
+            SELECT COUNT(*) AS total
+            FROM refresh_tokens rt
+            INNER JOIN users u ON u.id = rt.user_id
+            WHERE rt.user_id = ?
+                AND rt.access_token = ?
+                AND rt.expired_at >= ?
+                AND u.deleted_at IS NULL
+                AND u.password IS NOT NULL
+        "#;
+
+        let result = sqlx::query(query)
+            .bind(req.user_id.to_string())
+            .bind(req.access_token)
+            .bind(UtcDateTime::now().value())
+            .fetch_one(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to check access token");
+                IntrospectionUseCaseError::DatabaseError("Failed to check access token".to_string())
+            })?;
+        let total: i64 = result.try_get("total")?;
+
+        Ok(IsAccessTokenActiveDtoResponse(total > 0))
+    }
+
+    #[instrument(skip(self), name = "introspection_repository_get_user_scopes")]
+    async fn get_user_scopes(
+        &self,
+        req: GetUserScopesDtoRequest,
+    ) -> Result<GetUserScopesDtoResponse, IntrospectionUseCaseError> {
+        let rows = sqlx::query!(
+            r#"
+                SELECT s.id
+                FROM scopes s
+                INNER JOIN users_scopes us ON us.scope_id = s.id
+                WHERE us.user_id = ?
+                    AND s.application_id = ?
+                    AND s.deleted_at IS NULL
+                ORDER BY s.id
+            "#,
+            req.user_id.to_string(),
+            req.application_id.to_string()
+        )
+        .fetch_all(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get user scopes");
+            IntrospectionUseCaseError::DatabaseError("Failed to get user scopes".to_string())
+        })?;
+
+        let scopes = rows
+            .iter()
+            .map(|row| ScopeId::new(&row.id))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| {
+                error!(error = %err, "Failed to convert scope ID");
+                IntrospectionUseCaseError::FromModelError()
+            })?;
+
+        Ok(GetUserScopesDtoResponse(scopes))
+    }
+}
@@ -0,0 +1,130 @@
+//! Magic link MySQL repository
+
+use crate::adapters::database::mysql::Db;
+use crate::adapters::database::mysql::repositories::email::enqueue_email;
+use crate::domain::repositories::magic_link::MagicLinkRepository;
+use crate::domain::repositories::magic_link::dto::{
+    ConsumeMagicLinkDtoRequest, ConsumeMagicLinkDtoResponse, CreateUpdateMagicLinkDtoRequest,
+    CreateUpdateMagicLinkDtoResponse,
+};
+use crate::domain::use_cases::user::UserUseCaseError;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use async_trait::async_trait;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Magic link MySQL repository
+#[derive(Debug, Clone)]
+pub struct MagicLinkMysqlRepository {
+    db: Arc<Db>,
+}
+
+impl MagicLinkMysqlRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl MagicLinkRepository for MagicLinkMysqlRepository {
+    #[instrument(skip(self, req), name = "magic_link_repository_create_update")]
+    async fn create_or_update(
+        &self,
+        req: CreateUpdateMagicLinkDtoRequest,
+    ) -> Result<CreateUpdateMagicLinkDtoResponse, UserUseCaseError> {
+        let magic_link = req.magic_link;
+
+        let mut tx = self.db.pool.begin().await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            UserUseCaseError::DatabaseError("Failed to create or update magic link".to_string())
+        })?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO magic_links (user_id, token, expired_at)
+                VALUES (?, ?, ?)
+                ON DUPLICATE KEY UPDATE token = ?, expired_at = ?
+            "#,
+            magic_link.user_id.to_string(),
+            magic_link.token,
+            magic_link.expired_at.value(),
+            magic_link.token,
+            magic_link.expired_at.value(),
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to create or update magic link");
+            UserUseCaseError::DatabaseError("Failed to create or update magic link".to_string())
+        })?;
+
+        enqueue_email(&mut tx, &req.message).await.map_err(|err| {
+            error!(error = %err, "Failed to queue magic link email");
+            UserUseCaseError::DatabaseError("Failed to queue magic link email".to_string())
+        })?;
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            UserUseCaseError::DatabaseError("Failed to create or update magic link".to_string())
+        })?;
+
+        Ok(CreateUpdateMagicLinkDtoResponse())
+    }
+
+    #[instrument(skip(self), name = "magic_link_repository_consume")]
+    async fn consume(&self, req: ConsumeMagicLinkDtoRequest) -> Result<ConsumeMagicLinkDtoResponse, UserUseCaseError> {
+        let mut tx = self.db.pool.begin().await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            UserUseCaseError::DatabaseError("Failed to consume magic link".to_string())
+        })?;
+
+        let result = sqlx::query!(
+            r#"
+                SELECT u.id AS user_id
+                FROM magic_links ml
+                    INNER JOIN users u ON u.id = ml.user_id AND u.deleted_at IS NULL
+                WHERE ml.token = ?
+                    AND ml.expired_at >= ?
+                LIMIT 1
+                FOR UPDATE
+            "#,
+            req.token,
+            UtcDateTime::now().value(),
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get user ID from magic link token");
+            UserUseCaseError::DatabaseError("Failed to get user ID from magic link token".to_string())
+        })?;
+
+        // The token can only be used once
+        sqlx::query!(
+            r#"
+                DELETE FROM magic_links
+                WHERE token = ?
+            "#,
+            req.token,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to delete magic link");
+            UserUseCaseError::DatabaseError("Failed to delete magic link".to_string())
+        })?;
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            UserUseCaseError::DatabaseError("Failed to consume magic link".to_string())
+        })?;
+
+        let user_id = match result {
+            Some(r) => Some(Id::from_str(&r.user_id)?),
+            None => None,
+        };
+
+        Ok(ConsumeMagicLinkDtoResponse { user_id })
+    }
+}
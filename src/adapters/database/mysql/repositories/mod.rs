@@ -1,9 +1,107 @@
 //! MySQL repositories implementation
 
+use crate::adapters::database::DatabaseRepositories;
+use crate::adapters::database::mysql::Db;
+use application::ApplicationMysqlRepository;
+use audit::AuditMysqlRepository;
+use client::ClientMysqlRepository;
+use email::EmailMysqlRepository;
+use external_link::ExternalLinkMysqlRepository;
+use federation::FederationMysqlRepository;
+use introspection::IntrospectionMysqlRepository;
+use invitation::InvitationMysqlRepository;
+use magic_link::MagicLinkMysqlRepository;
+use password_reset::PasswordResetMysqlRepository;
+use refresh_token::RefreshTokenMysqlRepository;
+use scope::ScopeMysqlRepository;
+use user::UserMysqlRepository;
+use webhook::WebhookMysqlRepository;
+
 pub mod application;
+pub mod audit;
+pub mod client;
+pub mod email;
 pub mod error;
 pub mod external_link;
+pub mod federation;
+pub mod introspection;
+pub mod invitation;
+pub mod magic_link;
 pub mod password_reset;
 pub mod refresh_token;
 pub mod scope;
 pub mod user;
+pub mod webhook;
+
+impl DatabaseRepositories for Db {
+    type User = UserMysqlRepository;
+    type RefreshToken = RefreshTokenMysqlRepository;
+    type PasswordReset = PasswordResetMysqlRepository;
+    type MagicLink = MagicLinkMysqlRepository;
+    type Application = ApplicationMysqlRepository;
+    type Client = ClientMysqlRepository;
+    type Scope = ScopeMysqlRepository;
+    type ExternalLink = ExternalLinkMysqlRepository;
+    type Invitation = InvitationMysqlRepository;
+    type Introspection = IntrospectionMysqlRepository;
+    type Federation = FederationMysqlRepository;
+    type Audit = AuditMysqlRepository;
+    type Webhook = WebhookMysqlRepository;
+    type Email = EmailMysqlRepository;
+
+    fn user_repository(&self) -> Self::User {
+        UserMysqlRepository::new(self.clone())
+    }
+
+    fn refresh_token_repository(&self) -> Self::RefreshToken {
+        RefreshTokenMysqlRepository::new(self.clone())
+    }
+
+    fn password_reset_repository(&self) -> Self::PasswordReset {
+        PasswordResetMysqlRepository::new(self.clone())
+    }
+
+    fn magic_link_repository(&self) -> Self::MagicLink {
+        MagicLinkMysqlRepository::new(self.clone())
+    }
+
+    fn application_repository(&self) -> Self::Application {
+        ApplicationMysqlRepository::new(self.clone())
+    }
+
+    fn client_repository(&self) -> Self::Client {
+        ClientMysqlRepository::new(self.clone())
+    }
+
+    fn scope_repository(&self) -> Self::Scope {
+        ScopeMysqlRepository::new(self.clone())
+    }
+
+    fn external_link_repository(&self) -> Self::ExternalLink {
+        ExternalLinkMysqlRepository::new(self.clone())
+    }
+
+    fn invitation_repository(&self) -> Self::Invitation {
+        InvitationMysqlRepository::new(self.clone())
+    }
+
+    fn introspection_repository(&self) -> Self::Introspection {
+        IntrospectionMysqlRepository::new(self.clone())
+    }
+
+    fn federation_repository(&self) -> Self::Federation {
+        FederationMysqlRepository::new(self.clone())
+    }
+
+    fn audit_repository(&self) -> Self::Audit {
+        AuditMysqlRepository::new(self.clone())
+    }
+
+    fn webhook_repository(&self) -> Self::Webhook {
+        WebhookMysqlRepository::new(self.clone())
+    }
+
+    fn email_repository(&self) -> Self::Email {
+        EmailMysqlRepository::new(self.clone())
+    }
+}
@@ -1,6 +1,7 @@
 //! Password reset MySQL repository
 
 use crate::adapters::database::mysql::Db;
+use crate::adapters::database::mysql::repositories::email::enqueue_email;
 use crate::domain::repositories::password_reset::PasswordResetRepository;
 use crate::domain::repositories::password_reset::dto::{
     CreateUpdatePasswordResetDtoRequest, CreateUpdatePasswordResetDtoResponse, DeletePasswordResetDtoRequest,
@@ -28,26 +29,38 @@ impl PasswordResetMysqlRepository {
 
 #[async_trait]
 impl PasswordResetRepository for PasswordResetMysqlRepository {
-    #[instrument(skip(self), name = "password_reset_repository_create_update")]
+    #[instrument(skip(self, req), name = "password_reset_repository_create_update")]
     async fn create_or_update(
         &self,
         req: CreateUpdatePasswordResetDtoRequest,
     ) -> Result<CreateUpdatePasswordResetDtoResponse, UserUseCaseError> {
+        let password_reset = req.password_reset;
+        let mut tx = self.db.pool.begin().await?;
+
         sqlx::query!(
             r#"
                 INSERT INTO password_resets (user_id, token, expired_at)
                 VALUES (?, ?, ?)
                 ON DUPLICATE KEY UPDATE token = ?, expired_at = ?
             "#,
-            req.0.user_id.to_string(),
-            req.0.token,
-            req.0.expired_at.value(),
-            req.0.token,
-            req.0.expired_at.value(),
+            password_reset.user_id.to_string(),
+            password_reset.token,
+            password_reset.expired_at.value(),
+            password_reset.token,
+            password_reset.expired_at.value(),
         )
-        .execute(self.db.pool.clone().as_ref())
+        .execute(&mut *tx)
         .await?;
 
+        if let Some(message) = &req.message {
+            enqueue_email(&mut tx, message).await.map_err(|err| {
+                error!(error = %err, "Failed to queue forgotten password email");
+                UserUseCaseError::DatabaseError("Failed to queue forgotten password email".to_string())
+            })?;
+        }
+
+        tx.commit().await?;
+
         Ok(CreateUpdatePasswordResetDtoResponse())
     }
 
@@ -1,11 +1,13 @@
 //! Refresh token MySQL repository
 
 use crate::adapters::database::mysql::Db;
+use crate::adapters::database::mysql::repositories::webhook::enqueue_webhook_message;
 use crate::domain::repositories::refresh_token::RefreshTokenRepository;
 use crate::domain::repositories::refresh_token::dto::{
     CreateRefreshTokenDtoRequest, CreateRefreshTokenDtoResponse, DeleteExpiredRefreshTokensDtoRequest,
     DeleteExpiredRefreshTokensDtoResponse, DeleteRefreshTokenDtoRequest, DeleteRefreshTokenDtoResponse,
-    GetRefreshTokenDtoRequest, GetRefreshTokenDtoResponse,
+    DeleteUserRefreshTokensDtoRequest, DeleteUserRefreshTokensDtoResponse, GetRefreshTokenDtoRequest,
+    GetRefreshTokenDtoResponse,
 };
 use crate::domain::use_cases::user::UserUseCaseError;
 use crate::domain::value_objects::datetime::UtcDateTime;
@@ -36,11 +38,12 @@ impl RefreshTokenRepository for RefreshTokenMysqlRepository {
     ) -> Result<CreateRefreshTokenDtoResponse, UserUseCaseError> {
         sqlx::query!(
             r#"
-                INSERT INTO refresh_tokens (refresh_token, user_id, access_token, expired_at)
-                VALUES (?, ?, ?, ?)
+                INSERT INTO refresh_tokens (refresh_token, user_id, application_id, access_token, expired_at)
+                VALUES (?, ?, ?, ?, ?)
             "#,
             req.refresh_token.refresh_token.to_string(),
             req.user_id.to_string(),
+            req.application_id.to_string(),
             req.access_token.token.to_string(),
             req.refresh_token.expired_at.value(),
         )
@@ -62,7 +65,7 @@ impl RefreshTokenRepository for RefreshTokenMysqlRepository {
     ) -> Result<GetRefreshTokenDtoResponse, UserUseCaseError> {
         let row = sqlx::query!(
             r#"
-                SELECT refresh_token, user_id
+                SELECT refresh_token, user_id, application_id
                 FROM refresh_tokens 
                 WHERE refresh_token = ? 
                     AND expired_at >= ?
@@ -78,10 +81,10 @@ impl RefreshTokenRepository for RefreshTokenMysqlRepository {
         })?;
 
         let response = match row {
-            Some(row) => {
-                let user_id = Id::from_str(&row.user_id)?;
-                GetRefreshTokenDtoResponse { user_id }
-            }
+            Some(row) => GetRefreshTokenDtoResponse {
+                user_id: Id::from_str(&row.user_id)?,
+                application_id: Id::from_str(&row.application_id)?,
+            },
             None => Err(UserUseCaseError::InvalidRefreshToken())?,
         };
 
@@ -135,4 +138,44 @@ impl RefreshTokenRepository for RefreshTokenMysqlRepository {
             deleted: result.rows_affected(),
         })
     }
+
+    /// Delete all the refresh tokens of a user
+    #[instrument(skip(self), name = "refresh_token_repository_delete_user_refresh_tokens")]
+    async fn delete_user_refresh_tokens(
+        &self,
+        req: DeleteUserRefreshTokensDtoRequest,
+    ) -> Result<DeleteUserRefreshTokensDtoResponse, UserUseCaseError> {
+        let mut tx = self.db.pool.begin().await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            UserUseCaseError::DatabaseError("Failed to delete user refresh tokens".to_string())
+        })?;
+
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM refresh_tokens
+                WHERE user_id = ?
+            "#,
+            req.user_id.to_string(),
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to delete user refresh tokens");
+            UserUseCaseError::DatabaseError("Failed to delete user refresh tokens".to_string())
+        })?;
+
+        enqueue_webhook_message(&mut tx, &req.message).await.map_err(|err| {
+            error!(error = %err, "Failed to enqueue webhook message");
+            UserUseCaseError::DatabaseError("Failed to delete user refresh tokens".to_string())
+        })?;
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            UserUseCaseError::DatabaseError("Failed to delete user refresh tokens".to_string())
+        })?;
+
+        Ok(DeleteUserRefreshTokensDtoResponse {
+            deleted: result.rows_affected(),
+        })
+    }
 }
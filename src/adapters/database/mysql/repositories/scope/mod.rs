@@ -1,7 +1,9 @@
 //! Scope MySQL repository
 
-use crate::adapters::database::mysql::repositories::scope::model::ScopeModel;
-use crate::adapters::database::mysql::{Db, MysqlPagination, MysqlQuerySorts};
+use crate::adapters::database::model::scope::ScopeModel;
+use crate::adapters::database::mysql::{
+    Db, MysqlConditions, MysqlCursorPagination, MysqlCursorRow, MysqlQueryFilters, MysqlQuerySorts,
+};
 use crate::domain::repositories::scope::ScopeRepository;
 use crate::domain::repositories::scope::dto::{
     CountScopesDtoRequest, CountScopesDtoResponse, CreateScopeDtoRequest, CreateScopeDtoResponse,
@@ -13,10 +15,11 @@ use crate::domain::use_cases::scope::restore_scope::RestoreScopeUseCaseResponse;
 use crate::domain::use_cases::scope::{ScopeUseCaseError, ScopeUseCaseResponse};
 use crate::domain::value_objects::datetime::UtcDateTime;
 use async_trait::async_trait;
-use sqlx::Row;
+use sqlx::QueryBuilder;
 use std::sync::Arc;
 
-mod model;
+/// Fields available for filtering the scopes (`application_id` has its own filter)
+const FILTER_FIELDS: [&str; 4] = ["id", "created_at", "updated_at", "deleted_at"];
 
 /// Scope MySQL repository
 #[derive(Debug, Clone)]
@@ -68,41 +71,46 @@ impl ScopeRepository for ScopeMysqlRepository {
 
     #[instrument(skip(self), name = "scope_repository_get_all")]
     async fn get_scopes(&self, req: GetScopesDtoRequest) -> Result<GetScopesDtoResponse, ScopeUseCaseError> {
-        let mut query = String::from(
-            r#"
-            SELECT id, application_id, created_at, updated_at, deleted_at
-            FROM scopes
-        "#,
+        // Sorts & pagination
+        let sorts = MysqlQuerySorts(req.0.sorts.unwrap_or_default());
+        let pagination = MysqlCursorPagination::new(
+            req.0.pagination,
+            &sorts,
+            &["id", "created_at", "updated_at", "deleted_at"],
+            "id",
         );
 
-        query.push_str(match req.0.deleted {
-            true => " WHERE deleted_at IS NOT NULL",
-            false => " WHERE deleted_at IS NULL",
-        });
+        let mut query = QueryBuilder::new("SELECT id, application_id, created_at, updated_at, deleted_at");
+        pagination.push_select(&mut query);
+        query.push(" FROM scopes");
+
+        let mut conditions = MysqlConditions::new(&mut query);
+        conditions.push_soft_delete("deleted_at", req.0.deleted);
 
-        if req.0.application_id.is_some() {
-            query.push_str(" AND application_id = ?");
+        if let Some(application_id) = req.0.application_id {
+            conditions
+                .push()
+                .push("application_id = ")
+                .push_bind(application_id.to_string());
         }
 
-        // Sorts
-        let sorts = MysqlQuerySorts(req.0.sorts.unwrap_or_default());
-        query.push_str(&sorts.to_sql(&["id", "created_at", "updated_at", "deleted_at"]));
+        // Filters
+        MysqlQueryFilters(req.0.filters.unwrap_or_default()).push_sql(&mut conditions, &FILTER_FIELDS);
+        pagination.push_condition(&mut conditions);
 
-        // Pagination
-        let pagination = MysqlPagination::from(req.0.pagination);
-        query.push_str(&pagination.to_sql());
+        pagination.push_order(&mut query);
 
-        let mut query = sqlx::query_as::<_, ScopeModel>(&query);
-        if let Some(application_id) = req.0.application_id {
-            query = query.bind(application_id.to_string());
-        }
-        let scopes = query
+        let rows = query
+            .build_query_as::<MysqlCursorRow<ScopeModel>>()
             .fetch_all(self.db.pool.clone().as_ref())
             .await
             .map_err(|err| {
                 error!(error = %err, "Failed to get scopes");
                 ScopeUseCaseError::DatabaseError("Failed to get scopes".to_string())
-            })?
+            })?;
+        let (scopes, next_cursor) = pagination.page(rows);
+
+        let scopes = scopes
             .into_iter()
             .map(ScopeUseCaseResponse::try_from)
             .collect::<Result<Vec<ScopeUseCaseResponse>, _>>()
@@ -111,31 +119,32 @@ impl ScopeRepository for ScopeMysqlRepository {
                 ScopeUseCaseError::FromModelError()
             })?;
 
-        Ok(GetScopesDtoResponse(scopes))
+        Ok(GetScopesDtoResponse { scopes, next_cursor })
     }
 
     #[instrument(skip(self), name = "scope_repository_count_all")]
     async fn count_scopes(&self, req: CountScopesDtoRequest) -> Result<CountScopesDtoResponse, ScopeUseCaseError> {
-        let mut query = String::from("SELECT COUNT(*) AS total FROM scopes");
-        query.push_str(match req.deleted {
-            true => " WHERE deleted_at IS NOT NULL",
-            false => " WHERE deleted_at IS NULL",
-        });
-
-        if req.application_id.is_some() {
-            query.push_str(" AND application_id = ?");
-        }
+        let mut query = QueryBuilder::new("SELECT COUNT(*) AS total FROM scopes");
+
+        let mut conditions = MysqlConditions::new(&mut query);
+        conditions.push_soft_delete("deleted_at", req.deleted);
 
-        let mut query = sqlx::query(&query);
         if let Some(application_id) = req.application_id {
-            query = query.bind(application_id.to_string());
+            conditions.push().push("application_id = ").push_bind(application_id);
         }
-        let result = query.fetch_one(self.db.pool.clone().as_ref()).await.map_err(|err| {
-            error!(error = %err, "Failed to count scopes");
-            ScopeUseCaseError::DatabaseError("Failed to count scopes".to_string())
-        })?;
 
-        Ok(CountScopesDtoResponse(result.try_get("total")?))
+        MysqlQueryFilters(req.filters.unwrap_or_default()).push_sql(&mut conditions, &FILTER_FIELDS);
+
+        let (total,) = query
+            .build_query_as::<(i64,)>()
+            .fetch_one(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to count scopes");
+                ScopeUseCaseError::DatabaseError("Failed to count scopes".to_string())
+            })?;
+
+        Ok(CountScopesDtoResponse(total))
     }
 
     #[instrument(skip(self), name = "scope_repository_delete")]
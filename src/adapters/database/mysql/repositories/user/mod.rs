@@ -1,16 +1,21 @@
 //! User MySQL repository
 
-mod model;
-
-use crate::adapters::database::mysql::repositories::user::model::UserModel;
-use crate::adapters::database::mysql::{Db, MysqlPagination, MysqlQuerySorts};
+use crate::adapters::database::model::user::UserModel;
+use crate::adapters::database::mysql::repositories::webhook::enqueue_webhook_message;
+use crate::adapters::database::mysql::{
+    Db, MysqlConditions, MysqlCursorPagination, MysqlCursorRow, MysqlFullTextSearch, MysqlPagination,
+    MysqlQueryFilters, MysqlQuerySorts,
+};
+use crate::domain::entities::user::UserId;
 use crate::domain::repositories::user::UserRepository;
 use crate::domain::repositories::user::dto::{
     CountUsersDtoRequest, CountUsersDtoResponse, CreateUserDtoRequest, CreateUserDtoResponse, DeleteUserDtoRequest,
     DeleteUserDtoResponse, GetAccessTokenInformationDtoRequest, GetAccessTokenInformationDtoResponse,
-    GetUserByEmailDtoRequest, GetUserByEmailDtoResponse, GetUserByIdDtoRequest, GetUserByIdDtoResponse,
-    GetUsersDtoRequest, GetUsersDtoResponse, RestoreUserDtoRequest, RestoreUserDtoResponse, UpdatePasswordDtoRequest,
-    UpdatePasswordDtoResponse,
+    GetPasswordChangedAtDtoRequest, GetPasswordChangedAtDtoResponse, GetPasswordHistoryDtoRequest,
+    GetPasswordHistoryDtoResponse, GetUserByEmailDtoRequest, GetUserByEmailDtoResponse, GetUserByIdDtoRequest,
+    GetUserByIdDtoResponse, GetUsersDtoRequest, GetUsersDtoResponse, RehashPasswordDtoRequest,
+    RehashPasswordDtoResponse, RestoreUserDtoRequest, RestoreUserDtoResponse, SearchUsersDtoRequest,
+    SearchUsersDtoResponse, UpdatePasswordDtoRequest, UpdatePasswordDtoResponse,
 };
 use crate::domain::use_cases::user::delete_user::DeleteUserUseCaseResponse;
 use crate::domain::use_cases::user::restore_user::RestoreUserUseCaseResponse;
@@ -19,10 +24,26 @@ use crate::domain::value_objects::datetime::UtcDateTime;
 use crate::domain::value_objects::id::Id;
 use crate::domain::value_objects::password::Password;
 use async_trait::async_trait;
-use sqlx::Row;
+use chrono::{DateTime, Utc};
+use sqlx::{MySql, QueryBuilder, Transaction};
 use std::str::FromStr;
 use std::sync::Arc;
 
+/// Fields available for filtering the users
+const FILTER_FIELDS: [&str; 8] = [
+    "id",
+    "email",
+    "lastname",
+    "firstname",
+    "locale",
+    "created_at",
+    "updated_at",
+    "deleted_at",
+];
+
+/// Fields of the full-text index used for searching the users
+const SEARCH_FIELDS: [&str; 3] = ["email", "firstname", "lastname"];
+
 /// User MySQL repository
 #[derive(Debug, Clone)]
 pub struct UserMysqlRepository {
@@ -34,44 +55,94 @@ impl UserMysqlRepository {
     pub fn new(db: Db) -> Self {
         Self { db: Arc::new(db) }
     }
+
+    /// Add a hashed password to the user password history
+    async fn add_password_history(
+        tx: &mut Transaction<'_, MySql>,
+        user_id: &UserId,
+        password: &Password,
+        created_at: &UtcDateTime,
+    ) -> Result<(), UserUseCaseError> {
+        let id = Id::new().map_err(|err| {
+            error!(error = %err, "Failed to create password history ID");
+            UserUseCaseError::InvalidId()
+        })?;
+
+        sqlx::query!(
+            "
+            INSERT INTO password_history (id, user_id, password, created_at)
+            VALUES (?, ?, ?, ?)
+        ",
+            id.to_string(),
+            user_id.to_string(),
+            password.to_string(),
+            created_at.value()
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to add password to history");
+            UserUseCaseError::DatabaseError("Failed to add password to history".to_string())
+        })?;
+
+        Ok(())
+    }
 }
 
 #[async_trait]
 impl UserRepository for UserMysqlRepository {
     #[instrument(skip(self), name = "user_repository_create")]
     async fn create_user(&self, req: CreateUserDtoRequest) -> Result<CreateUserDtoResponse, UserUseCaseError> {
-        let user_id = Id::new().map_err(|err| {
-            error!(error = %err, "Failed to create user ID");
-            UserUseCaseError::InvalidId()
-        })?;
+        let user_id = req.id;
         let now = UtcDateTime::now();
 
+        let mut tx = self.db.pool.begin().await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            UserUseCaseError::DatabaseError("User creation error".to_string())
+        })?;
+
         // Create user
         sqlx::query!(
             "
-            INSERT INTO users (id, email, password, lastname, firstname, created_at, updated_at, deleted_at)
-            VALUES (?, ?, ?, ?, ?, ?, ?, NULL)
+            INSERT INTO users (id, email, password, password_changed_at, lastname, firstname, locale, created_at, updated_at, deleted_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, NULL)
         ",
             user_id.clone().to_string(),
-            req.0.email.to_string(),
-            req.0.password.to_string(),
-            req.0.lastname,
-            req.0.firstname,
+            req.user.email.to_string(),
+            req.user.password.to_string(),
+            now.value(),
+            req.user.lastname,
+            req.user.firstname,
+            req.user.locale.as_ref().map(|locale| locale.to_string()),
             now.value(),
             now.value()
         )
-        .execute(self.db.pool.clone().as_ref())
+        .execute(&mut *tx)
         .await
         .map_err(|err| {
             error!(error = %err, "Failed to create user");
             UserUseCaseError::DatabaseError("User creation error".to_string())
         })?;
 
+        // Add password to history
+        Self::add_password_history(&mut tx, &user_id, &req.user.password, &now).await?;
+
+        enqueue_webhook_message(&mut tx, &req.message).await.map_err(|err| {
+            error!(error = %err, "Failed to enqueue webhook message");
+            UserUseCaseError::DatabaseError("User creation error".to_string())
+        })?;
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            UserUseCaseError::DatabaseError("User creation error".to_string())
+        })?;
+
         Ok(CreateUserDtoResponse(UserUseCaseResponse {
             id: user_id,
-            email: req.0.email,
-            lastname: req.0.lastname,
-            firstname: req.0.firstname,
+            email: req.user.email,
+            lastname: req.user.lastname,
+            firstname: req.user.firstname,
+            locale: req.user.locale,
             created_at: now.clone(),
             updated_at: now,
             deleted_at: None,
@@ -84,11 +155,12 @@ impl UserRepository for UserMysqlRepository {
         req: GetAccessTokenInformationDtoRequest,
     ) -> Result<Option<GetAccessTokenInformationDtoResponse>, UserUseCaseError> {
         let result = sqlx::query!(
-            "
-            SELECT id, password
+            r#"
+            SELECT id, password AS "password!", password_changed_at
             FROM users
             WHERE email = ?
-                AND deleted_at IS NULL",
+                AND password IS NOT NULL
+                AND deleted_at IS NULL"#,
             req.0.to_string()
         )
         .fetch_optional(self.db.pool.clone().as_ref())
@@ -103,6 +175,10 @@ impl UserRepository for UserMysqlRepository {
                 id: Id::from_str(&row.id).map_err(|_| UserUseCaseError::InvalidId())?,
                 password: Password::new(&row.password, true)
                     .map_err(|_| UserUseCaseError::InvalidPassword("Failed to generate user ID".to_string()))?,
+                password_changed_at: UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(
+                    row.password_changed_at,
+                    Utc,
+                )),
             }),
             None => None,
         };
@@ -110,35 +186,67 @@ impl UserRepository for UserMysqlRepository {
         Ok(response)
     }
 
+    #[instrument(skip(self), name = "user_repository_get_password_changed_at")]
+    async fn get_password_changed_at(
+        &self,
+        req: GetPasswordChangedAtDtoRequest,
+    ) -> Result<GetPasswordChangedAtDtoResponse, UserUseCaseError> {
+        let row = sqlx::query!(
+            "
+            SELECT password, password_changed_at
+            FROM users
+            WHERE id = ?
+                AND deleted_at IS NULL",
+            req.0.to_string()
+        )
+        .fetch_optional(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get user password change date");
+            UserUseCaseError::DatabaseError("Failed to get user password change date".to_string())
+        })?
+        .ok_or_else(UserUseCaseError::UserNotFound)?;
+
+        Ok(GetPasswordChangedAtDtoResponse(row.password.map(|_| {
+            UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(row.password_changed_at, Utc))
+        })))
+    }
+
     #[instrument(skip(self), name = "user_repository_get_users")]
     async fn get_users(&self, req: GetUsersDtoRequest) -> Result<GetUsersDtoResponse, UserUseCaseError> {
-        let mut query = String::from(
-            r#"
-            SELECT id, email, lastname, firstname, created_at, updated_at, deleted_at
-            FROM users
-        "#,
+        // Sorts & pagination
+        let sorts = MysqlQuerySorts(req.0.sorts.unwrap_or_default());
+        let pagination = MysqlCursorPagination::new(
+            req.0.pagination,
+            &sorts,
+            &["id", "lastname", "firstname", "created_at", "updated_at", "deleted_at"],
+            "id",
         );
 
-        query.push_str(match req.0.deleted {
-            true => " WHERE deleted_at IS NOT NULL",
-            false => " WHERE deleted_at IS NULL",
-        });
+        let mut query =
+            QueryBuilder::new("SELECT id, email, lastname, firstname, locale, created_at, updated_at, deleted_at");
+        pagination.push_select(&mut query);
+        query.push(" FROM users");
 
-        // Sorts
-        let sorts = MysqlQuerySorts(req.0.sorts.unwrap_or_default());
-        query.push_str(&sorts.to_sql(&["id", "lastname", "firstname", "created_at", "updated_at", "deleted_at"]));
+        // Filters
+        let mut conditions = MysqlConditions::new(&mut query);
+        conditions.push_soft_delete("deleted_at", req.0.deleted);
+        MysqlQueryFilters(req.0.filters.unwrap_or_default()).push_sql(&mut conditions, &FILTER_FIELDS);
+        pagination.push_condition(&mut conditions);
 
-        // Pagination
-        let pagination = MysqlPagination::from(req.0.pagination);
-        query.push_str(&pagination.to_sql());
+        pagination.push_order(&mut query);
 
-        let users = sqlx::query_as::<_, UserModel>(&query)
+        let rows = query
+            .build_query_as::<MysqlCursorRow<UserModel>>()
             .fetch_all(self.db.pool.clone().as_ref())
             .await
             .map_err(|err| {
                 error!(error = %err, "Failed to get users");
                 UserUseCaseError::DatabaseError("Failed to get users".to_string())
-            })?
+            })?;
+        let (users, next_cursor) = pagination.page(rows);
+
+        let users = users
             .into_iter()
             .map(UserUseCaseResponse::try_from)
             .collect::<Result<Vec<UserUseCaseResponse>, _>>()
@@ -147,18 +255,19 @@ impl UserRepository for UserMysqlRepository {
                 UserUseCaseError::FromModelError()
             })?;
 
-        Ok(GetUsersDtoResponse(users))
+        Ok(GetUsersDtoResponse { users, next_cursor })
     }
 
     #[instrument(skip(self), name = "user_repository_count_users")]
     async fn count_users(&self, req: CountUsersDtoRequest) -> Result<CountUsersDtoResponse, UserUseCaseError> {
-        let mut query = String::from("SELECT COUNT(*) AS total FROM users");
-        query.push_str(match req.deleted {
-            true => " WHERE deleted_at IS NOT NULL",
-            false => " WHERE deleted_at IS NULL",
-        });
+        let mut query = QueryBuilder::new("SELECT COUNT(*) AS total FROM users");
+
+        let mut conditions = MysqlConditions::new(&mut query);
+        conditions.push_soft_delete("deleted_at", req.deleted);
+        MysqlQueryFilters(req.filters.unwrap_or_default()).push_sql(&mut conditions, &FILTER_FIELDS);
 
-        let result = sqlx::query(&query)
+        let (total,) = query
+            .build_query_as::<(i64,)>()
             .fetch_one(self.db.pool.clone().as_ref())
             .await
             .map_err(|err| {
@@ -166,7 +275,66 @@ impl UserRepository for UserMysqlRepository {
                 UserUseCaseError::DatabaseError("Failed to count users".to_string())
             })?;
 
-        Ok(CountUsersDtoResponse(result.try_get("total")?))
+        Ok(CountUsersDtoResponse(total))
+    }
+
+    #[instrument(skip(self), name = "user_repository_search_users")]
+    async fn search_users(&self, req: SearchUsersDtoRequest) -> Result<SearchUsersDtoResponse, UserUseCaseError> {
+        let Some(search) = MysqlFullTextSearch(req.search).to_boolean_query() else {
+            return Ok(SearchUsersDtoResponse {
+                users: vec![],
+                total: 0,
+            });
+        };
+        let filters = MysqlQueryFilters(req.filters.unwrap_or_default());
+        let push_conditions = |query: &mut QueryBuilder<'_, MySql>| {
+            let mut conditions = MysqlConditions::new(query);
+            conditions.push_soft_delete("deleted_at", req.deleted);
+            MysqlFullTextSearch::push_sql(conditions.push(), &SEARCH_FIELDS, &search);
+            filters.push_sql(&mut conditions, &FILTER_FIELDS);
+        };
+
+        // Total
+        let mut query = QueryBuilder::new("SELECT COUNT(*) AS total FROM users");
+        push_conditions(&mut query);
+
+        let (total,) = query
+            .build_query_as::<(i64,)>()
+            .fetch_one(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to count searched users");
+                UserUseCaseError::DatabaseError("Failed to search users".to_string())
+            })?;
+
+        // Users ranked by relevance
+        let mut query = QueryBuilder::new(
+            "SELECT id, email, lastname, firstname, locale, created_at, updated_at, deleted_at FROM users",
+        );
+        push_conditions(&mut query);
+
+        query.push(" ORDER BY ");
+        MysqlFullTextSearch::push_sql(&mut query, &SEARCH_FIELDS, &search);
+        query.push(" DESC, id");
+        MysqlPagination::from(req.pagination).push_sql(&mut query);
+
+        let users = query
+            .build_query_as::<UserModel>()
+            .fetch_all(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to search users");
+                UserUseCaseError::DatabaseError("Failed to search users".to_string())
+            })?
+            .into_iter()
+            .map(UserUseCaseResponse::try_from)
+            .collect::<Result<Vec<UserUseCaseResponse>, _>>()
+            .map_err(|err| {
+                error!(error = %err, "Failed to convert user model to user use case response");
+                UserUseCaseError::FromModelError()
+            })?;
+
+        Ok(SearchUsersDtoResponse { users, total })
     }
 
     #[instrument(skip(self, req), name = "user_repository_get_user_by_id")]
@@ -174,7 +342,7 @@ impl UserRepository for UserMysqlRepository {
         let result = sqlx::query_as!(
             UserModel,
             "
-            SELECT id, email, lastname, firstname, created_at, updated_at, deleted_at
+            SELECT id, email, lastname, firstname, locale, created_at, updated_at, deleted_at
             FROM users
             WHERE id = ?
                 AND deleted_at IS NULL",
@@ -206,12 +374,12 @@ impl UserRepository for UserMysqlRepository {
         let result = sqlx::query_as!(
             UserModel,
             "
-            SELECT id, email, lastname, firstname, created_at, updated_at, deleted_at
+            SELECT id, email, lastname, firstname, locale, created_at, updated_at, deleted_at
             FROM users
             WHERE email = ?
                 AND deleted_at IS NULL
             LIMIT 1",
-            req.0.user_email.to_string()
+            req.0.to_string()
         )
         .fetch_optional(self.db.pool.clone().as_ref())
         .await
@@ -233,6 +401,11 @@ impl UserRepository for UserMysqlRepository {
 
     #[instrument(skip(self, req), name = "user_repository_delete_user")]
     async fn delete_user(&self, req: DeleteUserDtoRequest) -> Result<DeleteUserDtoResponse, UserUseCaseError> {
+        let mut tx = self.db.pool.begin().await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            UserUseCaseError::DatabaseError("Failed to delete user".to_string())
+        })?;
+
         let result = sqlx::query!(
             "
             UPDATE users
@@ -240,9 +413,9 @@ impl UserRepository for UserMysqlRepository {
             WHERE id = ?
                 AND deleted_at IS NULL",
             Some(UtcDateTime::now().value()),
-            req.0.user_id.to_string()
+            req.user_id.to_string()
         )
-        .execute(self.db.pool.clone().as_ref())
+        .execute(&mut *tx)
         .await
         .map_err(|err| {
             error!(error = %err, "Failed to delete user");
@@ -253,6 +426,16 @@ impl UserRepository for UserMysqlRepository {
             return Err(UserUseCaseError::UserNotFound())?;
         }
 
+        enqueue_webhook_message(&mut tx, &req.message).await.map_err(|err| {
+            error!(error = %err, "Failed to enqueue webhook message");
+            UserUseCaseError::DatabaseError("Failed to delete user".to_string())
+        })?;
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            UserUseCaseError::DatabaseError("Failed to delete user".to_string())
+        })?;
+
         Ok(DeleteUserDtoResponse(DeleteUserUseCaseResponse()))
     }
 
@@ -261,16 +444,24 @@ impl UserRepository for UserMysqlRepository {
         &self,
         req: UpdatePasswordDtoRequest,
     ) -> Result<UpdatePasswordDtoResponse, UserUseCaseError> {
+        let now = UtcDateTime::now();
+
+        let mut tx = self.db.pool.begin().await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            UserUseCaseError::DatabaseError("Failed to update user password".to_string())
+        })?;
+
         let result = sqlx::query!(
             "
             UPDATE users
-            SET password = ?
+            SET password = ?, password_changed_at = ?
             WHERE id = ?
                 AND deleted_at IS NULL",
             req.password.to_string(),
+            now.value(),
             req.user_id.to_string()
         )
-        .execute(self.db.pool.clone().as_ref())
+        .execute(&mut *tx)
         .await
         .map_err(|err| {
             error!(error = %err, "Failed to update user password");
@@ -281,20 +472,96 @@ impl UserRepository for UserMysqlRepository {
             return Err(UserUseCaseError::UserNotFound())?;
         }
 
+        // Add password to history
+        Self::add_password_history(&mut tx, &req.user_id, &req.password, &now).await?;
+
+        enqueue_webhook_message(&mut tx, &req.message).await.map_err(|err| {
+            error!(error = %err, "Failed to enqueue webhook message");
+            UserUseCaseError::DatabaseError("Failed to update user password".to_string())
+        })?;
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            UserUseCaseError::DatabaseError("Failed to update user password".to_string())
+        })?;
+
         Ok(UpdatePasswordDtoResponse())
     }
 
+    #[instrument(skip(self, req), name = "user_repository_rehash_password")]
+    async fn rehash_password(
+        &self,
+        req: RehashPasswordDtoRequest,
+    ) -> Result<RehashPasswordDtoResponse, UserUseCaseError> {
+        let result = sqlx::query!(
+            "
+            UPDATE users
+            SET password = ?
+            WHERE id = ?
+                AND deleted_at IS NULL",
+            req.password.to_string(),
+            req.user_id.to_string()
+        )
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to rehash user password");
+            UserUseCaseError::DatabaseError("Failed to rehash user password".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(UserUseCaseError::UserNotFound())?;
+        }
+
+        Ok(RehashPasswordDtoResponse())
+    }
+
+    #[instrument(skip(self), name = "user_repository_get_password_history")]
+    async fn get_password_history(
+        &self,
+        req: GetPasswordHistoryDtoRequest,
+    ) -> Result<GetPasswordHistoryDtoResponse, UserUseCaseError> {
+        let rows = sqlx::query!(
+            "
+            SELECT password
+            FROM password_history
+            WHERE user_id = ?
+            ORDER BY created_at DESC
+            LIMIT ?",
+            req.user_id.to_string(),
+            req.limit
+        )
+        .fetch_all(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get password history");
+            UserUseCaseError::DatabaseError("Failed to get password history".to_string())
+        })?;
+
+        let passwords = rows
+            .into_iter()
+            .map(|row| Password::new(&row.password, true))
+            .collect::<Result<Vec<Password>, _>>()?;
+
+        Ok(GetPasswordHistoryDtoResponse(passwords))
+    }
+
     #[instrument(skip(self, req), name = "user_repository_restore_user")]
     async fn restore_user(&self, req: RestoreUserDtoRequest) -> Result<RestoreUserDtoResponse, UserUseCaseError> {
+        let mut tx = self.db.pool.begin().await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            UserUseCaseError::DatabaseError("Failed to restore user".to_string())
+        })?;
+
         let result = sqlx::query!(
             "
             UPDATE users
             SET deleted_at = NULL
             WHERE id = ?
                 AND deleted_at IS NOT NULL",
-            req.0.user_id.to_string()
+            req.user_id.to_string()
         )
-        .execute(self.db.pool.clone().as_ref())
+        .execute(&mut *tx)
         .await
         .map_err(|err| {
             error!(error = %err, "Failed to restore user");
@@ -305,6 +572,16 @@ impl UserRepository for UserMysqlRepository {
             return Err(UserUseCaseError::UserNotFound())?;
         }
 
+        enqueue_webhook_message(&mut tx, &req.message).await.map_err(|err| {
+            error!(error = %err, "Failed to enqueue webhook message");
+            UserUseCaseError::DatabaseError("Failed to restore user".to_string())
+        })?;
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            UserUseCaseError::DatabaseError("Failed to restore user".to_string())
+        })?;
+
         Ok(RestoreUserDtoResponse(RestoreUserUseCaseResponse()))
     }
 }
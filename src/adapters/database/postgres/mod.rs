@@ -0,0 +1,616 @@
+//! PostgreSQL adapter
+//!
+//! The queries are checked at runtime (`sqlx::query_as::<_, M>`): the offline data of the
+//! `query!` macros is generated for MySQL only.
+
+use crate::adapters::database::query::{Conditions, sql_comparison, valid_filters, valid_sorts};
+use crate::adapters::database::{DatabaseError, GenericDb};
+use crate::config::Config;
+use crate::domain::value_objects::pagination::{PAGINATION_MAX_LIMIT, Pagination, PaginationCursor};
+use crate::domain::value_objects::query_filter::{QueryFilterFieldValue, QueryFilters};
+use crate::domain::value_objects::query_sort::{QuerySortDirection, QuerySorts};
+use async_trait::async_trait;
+use sqlx::postgres::{PgPoolOptions, PgRow};
+use sqlx::{FromRow, Pool, Postgres, QueryBuilder, Row};
+use std::sync::Arc;
+use std::time::Duration;
+
+pub mod repositories;
+
+/// PostgreSQL database adapter
+#[derive(Debug, Clone)]
+pub struct Db {
+    pub pool: Arc<Pool<Postgres>>,
+}
+
+#[async_trait]
+impl GenericDb for Db {
+    type Db = Db;
+
+    async fn new(config: &Config) -> Result<Self::Db, DatabaseError> {
+        let url = &config.database_url;
+        let max_connections = config.database_max_connections;
+        let min_connections = config.database_min_connections;
+        let max_lifetime = config.database_max_lifetime;
+        let connect_timeout = config.database_connect_timeout;
+        let idle_timeout = config.database_idle_timeout;
+
+        let pool = PgPoolOptions::new()
+            .max_connections(max_connections)
+            .min_connections(min_connections)
+            .max_lifetime(Some(Duration::from_secs(max_lifetime)))
+            .acquire_timeout(Duration::from_secs(connect_timeout))
+            .idle_timeout(Duration::from_secs(idle_timeout))
+            .test_before_acquire(true)
+            .connect(url)
+            .await
+            .map_err(|err| DatabaseError::CreationPoolError(err.to_string()))?;
+
+        if config.database_auto_migration {
+            info!("Run database migrations");
+            sqlx::migrate!("./migrations/postgres")
+                .run(&pool)
+                .await
+                .map_err(|err| DatabaseError::AutoMigrationError(err.to_string()))?
+        }
+
+        Ok(Self { pool: Arc::new(pool) })
+    }
+}
+
+/// Pagination for PostgreSQL queries
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostgresPagination {
+    pub page: u32,
+    pub limit: u32,
+    pub offset: u32,
+}
+
+impl From<Pagination> for PostgresPagination {
+    fn from(pagination: Pagination) -> Self {
+        // Page & limit
+        let page = match pagination.page() >= 1 {
+            true => pagination.page(),
+            false => 1,
+        };
+
+        let limit = match (1..=PAGINATION_MAX_LIMIT).contains(&pagination.limit()) {
+            true => pagination.limit(),
+            false => PAGINATION_MAX_LIMIT,
+        };
+
+        let offset = (page - 1) * limit;
+
+        Self { page, limit, offset }
+    }
+}
+
+impl PostgresPagination {
+    /// Push the `LIMIT` and `OFFSET` clauses with bound values
+    pub fn push_sql(&self, query: &mut QueryBuilder<'_, Postgres>) {
+        query
+            .push(" LIMIT ")
+            .push_bind(i64::from(self.limit))
+            .push(" OFFSET ")
+            .push_bind(i64::from(self.offset));
+    }
+}
+
+/// `WHERE` clause of a Postgres query
+pub type PostgresConditions<'q, 'args> = Conditions<'q, 'args, Postgres>;
+
+/// Push an `ORDER BY` clause (nothing if there is no sort)
+///
+/// `NULL` values are sorted like MySQL does: first in ascending order and last in descending order.
+fn push_order_by<'s>(
+    query: &mut QueryBuilder<'_, Postgres>,
+    sorts: impl IntoIterator<Item = (&'s str, &'s QuerySortDirection)>,
+) {
+    for (i, (column, direction)) in sorts.into_iter().enumerate() {
+        query
+            .push(match i {
+                0 => " ORDER BY ",
+                _ => ", ",
+            })
+            .push(column)
+            .push(match direction {
+                QuerySortDirection::Asc => " ASC NULLS FIRST",
+                QuerySortDirection::Desc => " DESC NULLS LAST",
+            });
+    }
+}
+
+/// Query sorts for PostgreSQL queries
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostgresQuerySorts(pub QuerySorts);
+
+impl PostgresQuerySorts {
+    /// Sorts of the valid fields, with the column of the valid field
+    ///
+    /// A valid field can be prefixed by its table (Ex.: `u.email` is used for the `email` sort).
+    fn valid<'a>(&'a self, valid_fields: &'a [&str]) -> impl Iterator<Item = (&'a str, &'a QuerySortDirection)> {
+        valid_sorts(&self.0, valid_fields)
+    }
+
+    /// Check if at least one sort is on a valid field
+    pub fn has_valid_fields(&self, valid_fields: &[&str]) -> bool {
+        self.valid(valid_fields).next().is_some()
+    }
+
+    /// Push the `ORDER BY` clause of the valid fields
+    ///
+    /// Only the columns of `valid_fields` are written in the query.
+    pub fn push_sql(&self, query: &mut QueryBuilder<'_, Postgres>, valid_fields: &[&str]) {
+        push_order_by(query, self.valid(valid_fields));
+    }
+}
+
+/// Field available for filtering: the column and its SQL type (Ex.: `("created_at", "TIMESTAMP")`)
+///
+/// The filter values are bound as text and cast to the type of the column.
+pub type PostgresFilterField<'a> = (&'a str, &'a str);
+
+/// Query filters for PostgreSQL queries
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostgresQueryFilters(pub QueryFilters);
+
+impl PostgresQueryFilters {
+    /// Conditions of the valid fields, with the column and the type of the valid field
+    fn conditions<'a>(
+        &'a self,
+        valid_fields: &'a [PostgresFilterField<'a>],
+    ) -> impl Iterator<Item = (&'a PostgresFilterField<'a>, &'a QueryFilterFieldValue<String>)> {
+        valid_filters(&self.0, valid_fields, |(column, _)| column)
+    }
+
+    /// Add the conditions of the valid fields
+    ///
+    /// Only the columns and types of `valid_fields` are written in the query, the values are bound.
+    pub fn push_sql(&self, conditions: &mut PostgresConditions<'_, '_>, valid_fields: &[PostgresFilterField<'_>]) {
+        for ((column, sql_type), QueryFilterFieldValue { operator, values }) in self.conditions(valid_fields) {
+            let query = conditions.push();
+            query.push(column);
+
+            query.push(sql_comparison(operator));
+
+            match operator.is_multiple() {
+                true => {
+                    query.push("(");
+                    for (i, value) in values.iter().enumerate() {
+                        if i > 0 {
+                            query.push(", ");
+                        }
+                        query
+                            .push("CAST(")
+                            .push_bind(value.clone())
+                            .push(format_args!(" AS {sql_type})"));
+                    }
+                    query.push(")");
+                }
+                false => {
+                    query
+                        .push("CAST(")
+                        .push_bind(values.first().cloned().unwrap_or_default())
+                        .push(format_args!(" AS {sql_type})"));
+                }
+            }
+        }
+    }
+}
+
+/// Row of a list query: the model, the sort key and the ID used to build the next cursor
+///
+/// The query must select the columns of [`PostgresCursorPagination::push_select`].
+#[derive(Debug, Clone)]
+pub struct PostgresCursorRow<M> {
+    pub model: M,
+    pub cursor_value: Option<String>,
+    pub cursor_id: String,
+}
+
+impl<'r, M: FromRow<'r, PgRow>> FromRow<'r, PgRow> for PostgresCursorRow<M> {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            model: M::from_row(row)?,
+            cursor_value: row.try_get("cursor_value")?,
+            cursor_id: row.try_get("cursor_id")?,
+        })
+    }
+}
+
+/// Pagination of list queries, in offset mode (`LIMIT/OFFSET`) or in cursor mode (keyset)
+///
+/// In cursor mode, the rows are sorted by the first valid sort field then by ID,
+/// and the page starts after the sort key and the ID of the cursor.
+/// The sort key is compared as text because the cursor value is bound as a text parameter.
+/// One more row than the limit is fetched to know if there is a next page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostgresCursorPagination {
+    /// Valid sorts, with the columns of the valid fields
+    sorts: Vec<(String, QuerySortDirection)>,
+    id_column: String,
+    cursor: Option<PaginationCursor>,
+    limit: u32,
+
+    /// `LIMIT/OFFSET` in offset mode (`None` in cursor mode)
+    offset: Option<PostgresPagination>,
+}
+
+impl PostgresCursorPagination {
+    /// Create a new pagination
+    ///
+    /// A valid field can be prefixed by its table (Ex.: `u.email` is used for the `email` sort).
+    pub fn new(pagination: Pagination, sorts: &PostgresQuerySorts, valid_fields: &[&str], id_column: &str) -> Self {
+        let sorts = sorts
+            .valid(valid_fields)
+            .map(|(column, direction)| (column.to_string(), direction.clone()))
+            .collect();
+        let keyset = pagination.is_cursor();
+        let cursor = pagination.cursor().cloned();
+        let postgres_pagination = PostgresPagination::from(pagination);
+
+        Self {
+            sorts,
+            id_column: id_column.to_string(),
+            cursor,
+            limit: postgres_pagination.limit,
+            offset: match keyset {
+                true => None,
+                false => Some(postgres_pagination),
+            },
+        }
+    }
+
+    /// Sort key expression and direction in cursor mode (`None` to sort by ID only)
+    fn sort(&self) -> Option<(String, QuerySortDirection)> {
+        self.sorts
+            .first()
+            .filter(|_| self.offset.is_none())
+            .map(|(column, direction)| (format!("CAST({column} AS TEXT)"), direction.clone()))
+    }
+
+    /// Sort direction (by ID only: ascending)
+    fn direction(&self) -> QuerySortDirection {
+        self.sort().map(|(_, direction)| direction).unwrap_or_default()
+    }
+
+    /// Push the sort key and ID columns, to add to the selected columns
+    pub fn push_select(&self, query: &mut QueryBuilder<'_, Postgres>) {
+        match self.sort() {
+            Some((key, _)) => query.push(", ").push(key),
+            None => query.push(", CAST(NULL AS TEXT)"),
+        };
+        query
+            .push(" AS cursor_value, ")
+            .push(&self.id_column)
+            .push(" AS cursor_id");
+    }
+
+    /// Add the condition of the rows after the cursor (nothing in offset mode or for the first page)
+    ///
+    /// `NULL` values are sorted first in ascending order and last in descending order.
+    pub fn push_condition(&self, conditions: &mut PostgresConditions<'_, '_>) {
+        let Some(cursor) = self.cursor.as_ref().filter(|_| self.offset.is_none()) else {
+            return;
+        };
+        let id = &self.id_column;
+        let cursor_id = cursor.id().to_string();
+        let query = conditions.push();
+
+        match (self.sort(), cursor.value(), self.direction()) {
+            (None, _, _) => {
+                query.push(id).push(" > ").push_bind(cursor_id);
+            }
+            (Some((key, _)), Some(value), QuerySortDirection::Asc) => {
+                query
+                    .push(format_args!("({key} > "))
+                    .push_bind(value.to_string())
+                    .push(format_args!(" OR ({key} = "))
+                    .push_bind(value.to_string())
+                    .push(format_args!(" AND {id} > "))
+                    .push_bind(cursor_id)
+                    .push("))");
+            }
+            (Some((key, _)), Some(value), QuerySortDirection::Desc) => {
+                query
+                    .push(format_args!("({key} < "))
+                    .push_bind(value.to_string())
+                    .push(format_args!(" OR {key} IS NULL OR ({key} = "))
+                    .push_bind(value.to_string())
+                    .push(format_args!(" AND {id} < "))
+                    .push_bind(cursor_id)
+                    .push("))");
+            }
+            (Some((key, _)), None, QuerySortDirection::Asc) => {
+                query
+                    .push(format_args!("({key} IS NOT NULL OR {id} > "))
+                    .push_bind(cursor_id)
+                    .push(")");
+            }
+            (Some((key, _)), None, QuerySortDirection::Desc) => {
+                query
+                    .push(format_args!("({key} IS NULL AND {id} < "))
+                    .push_bind(cursor_id)
+                    .push(")");
+            }
+        }
+    }
+
+    /// Push the sorts and the pagination (`ORDER BY` and `LIMIT`)
+    pub fn push_order(&self, query: &mut QueryBuilder<'_, Postgres>) {
+        match &self.offset {
+            Some(pagination) => {
+                push_order_by(
+                    query,
+                    self.sorts
+                        .iter()
+                        .map(|(column, direction)| (column.as_str(), direction)),
+                );
+                pagination.push_sql(query);
+            }
+            None => {
+                let direction = self.direction();
+                let sort = self.sort();
+                push_order_by(
+                    query,
+                    sort.as_ref()
+                        .map(|(key, _)| (key.as_str(), &direction))
+                        .into_iter()
+                        .chain([(self.id_column.as_str(), &direction)]),
+                );
+                query.push(" LIMIT ").push_bind(i64::from(self.limit) + 1);
+            }
+        }
+    }
+
+    /// Page models and the cursor of the next page (only in cursor mode, if there is a next page)
+    pub fn page<M>(&self, mut rows: Vec<PostgresCursorRow<M>>) -> (Vec<M>, Option<PaginationCursor>) {
+        let limit = self.limit as usize;
+        let next_cursor = match self.offset.is_none() && rows.len() > limit {
+            true => {
+                rows.truncate(limit);
+                rows.last()
+                    .map(|row| PaginationCursor::new(row.cursor_value.clone(), row.cursor_id.clone()))
+            }
+            false => None,
+        };
+
+        (rows.into_iter().map(|row| row.model).collect(), next_cursor)
+    }
+}
+
+/// Full-text search for PostgreSQL queries (`to_tsvector(...) @@ to_tsquery(...)`)
+///
+/// The `simple` configuration is used: there is no stemming and no stop words, like in MySQL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostgresFullTextSearch(pub String);
+
+impl PostgresFullTextSearch {
+    /// Generate the text search query
+    ///
+    /// The search is split into words on the characters which are not part of a word
+    /// (this also removes the `tsquery` operators), and each word is used as a prefix
+    /// so that partial names or emails match. A row matches if it contains all the words.
+    /// Returns `None` if there is no word to search.
+    pub fn to_ts_query(&self) -> Option<String> {
+        let words = self
+            .0
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .filter(|word| !word.is_empty())
+            .map(|word| format!("{word}:*"))
+            .collect::<Vec<_>>();
+
+        match words.is_empty() {
+            true => None,
+            false => Some(words.join(" & ")),
+        }
+    }
+
+    /// Text search document of the given columns
+    fn document(columns: &[&str]) -> String {
+        format!("to_tsvector('simple', {})", columns.join(" || ' ' || "))
+    }
+
+    /// Push the search condition on the given columns, with the text search query bound
+    pub fn push_sql(query: &mut QueryBuilder<'_, Postgres>, columns: &[&str], ts_query: &str) {
+        query
+            .push(Self::document(columns))
+            .push(" @@ to_tsquery('simple', ")
+            .push_bind(ts_query.to_string())
+            .push(")");
+    }
+
+    /// Push the rank of the search on the given columns (to sort by relevance)
+    pub fn push_rank(query: &mut QueryBuilder<'_, Postgres>, columns: &[&str], ts_query: &str) {
+        query
+            .push("ts_rank(")
+            .push(Self::document(columns))
+            .push(", to_tsquery('simple', ")
+            .push_bind(ts_query.to_string())
+            .push("))");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::query_filter::QueryFilterError;
+    use std::collections::HashMap;
+
+    /// SQL code pushed by `push` on an empty query
+    fn sql(push: impl FnOnce(&mut QueryBuilder<'_, Postgres>)) -> String {
+        let mut query = QueryBuilder::new("");
+        push(&mut query);
+
+        query.sql().to_string()
+    }
+
+    /// SQL code of the conditions added by `push` on an empty query
+    fn conditions_sql(push: impl FnOnce(&mut PostgresConditions<'_, '_>)) -> String {
+        sql(|query| push(&mut PostgresConditions::new(query)))
+    }
+
+    #[test]
+    fn test_postgres_pagination_push_sql() {
+        let pagination = PostgresPagination::from(Pagination::new(3, 1_000));
+        assert_eq!(pagination.limit, PAGINATION_MAX_LIMIT);
+        assert_eq!(pagination.offset, 2 * PAGINATION_MAX_LIMIT);
+        assert_eq!(
+            " LIMIT $1 OFFSET $2".to_owned(),
+            sql(|query| pagination.push_sql(query))
+        );
+    }
+
+    #[test]
+    fn test_postgres_query_sorts_push_sql() {
+        let sorts = PostgresQuerySorts(QuerySorts::from("+id,-name,+unknown"));
+        assert_eq!(
+            " ORDER BY u.id ASC NULLS FIRST, name DESC NULLS LAST".to_owned(),
+            sql(|query| sorts.push_sql(query, &["u.id", "name"]))
+        );
+        assert!(sorts.has_valid_fields(&["name"]));
+        assert!(!sorts.has_valid_fields(&["email"]));
+        assert_eq!(String::new(), sql(|query| sorts.push_sql(query, &[])));
+    }
+
+    fn filters(params: &[(&str, &str)]) -> Result<PostgresQueryFilters, QueryFilterError> {
+        let params = params
+            .iter()
+            .map(|(field, values)| (field.to_string(), values.to_string()))
+            .collect::<HashMap<_, _>>();
+
+        Ok(PostgresQueryFilters(QueryFilters::try_from(params)?))
+    }
+
+    #[test]
+    fn test_postgres_query_filters_push_sql() {
+        let postgres_filters = filters(&[
+            ("name", "in:foo,bar"),
+            ("created_at", "gte:2024-01-01"),
+            ("password", "eq:secret"),
+        ])
+        .unwrap();
+        assert_eq!(
+            " WHERE deleted_at IS NULL AND created_at >= CAST($1 AS TIMESTAMP) AND name IN (CAST($2 AS TEXT), CAST($3 AS TEXT))"
+                .to_owned(),
+            conditions_sql(|conditions| {
+                conditions.push_soft_delete("deleted_at", false);
+                postgres_filters.push_sql(conditions, &[("name", "TEXT"), ("created_at", "TIMESTAMP")]);
+            })
+        );
+    }
+
+    #[test]
+    fn test_postgres_full_text_search() {
+        assert_eq!(PostgresFullTextSearch("  +-@* ".to_string()).to_ts_query(), None);
+        assert_eq!(
+            PostgresFullTextSearch("john.doe -Smith".to_string()).to_ts_query(),
+            Some("john:* & doe:* & Smith:*".to_string())
+        );
+        assert_eq!(
+            "to_tsvector('simple', email || ' ' || lastname) @@ to_tsquery('simple', $1)".to_owned(),
+            sql(|query| PostgresFullTextSearch::push_sql(query, &["email", "lastname"], "Doe:*"))
+        );
+    }
+
+    #[test]
+    fn test_postgres_cursor_pagination_offset_mode() {
+        let sorts = PostgresQuerySorts(QuerySorts::from("-email"));
+        let pagination = PostgresCursorPagination::new(Pagination::new(3, 10), &sorts, &["u.email"], "i.id");
+
+        assert_eq!(
+            ", CAST(NULL AS TEXT) AS cursor_value, i.id AS cursor_id".to_owned(),
+            sql(|query| pagination.push_select(query))
+        );
+        assert_eq!(
+            String::new(),
+            conditions_sql(|conditions| pagination.push_condition(conditions))
+        );
+        assert_eq!(
+            " ORDER BY u.email DESC NULLS LAST LIMIT $1 OFFSET $2".to_owned(),
+            sql(|query| pagination.push_order(query))
+        );
+    }
+
+    #[test]
+    fn test_postgres_cursor_pagination_cursor_mode() {
+        let cursor = Some(PaginationCursor::new(Some("Doe".to_string()), "42".to_string()));
+        let sorts = PostgresQuerySorts(QuerySorts::from("-lastname"));
+        let pagination = PostgresCursorPagination::new(Pagination::new_cursor(cursor, 10), &sorts, &["lastname"], "id");
+
+        assert_eq!(
+            ", CAST(lastname AS TEXT) AS cursor_value, id AS cursor_id".to_owned(),
+            sql(|query| pagination.push_select(query))
+        );
+        assert_eq!(
+            " WHERE (CAST(lastname AS TEXT) < $1 OR CAST(lastname AS TEXT) IS NULL OR (CAST(lastname AS TEXT) = $2 AND id < $3))"
+                .to_owned(),
+            conditions_sql(|conditions| pagination.push_condition(conditions))
+        );
+        assert_eq!(
+            " ORDER BY CAST(lastname AS TEXT) DESC NULLS LAST, id DESC NULLS LAST LIMIT $1".to_owned(),
+            sql(|query| pagination.push_order(query))
+        );
+
+        let rows = ["Doe", "Smith"]
+            .into_iter()
+            .enumerate()
+            .map(|(i, lastname)| PostgresCursorRow {
+                model: lastname,
+                cursor_value: Some(lastname.to_string()),
+                cursor_id: i.to_string(),
+            })
+            .collect::<Vec<_>>();
+        let pagination = PostgresCursorPagination::new(Pagination::new_cursor(None, 1), &sorts, &["lastname"], "id");
+        assert_eq!(
+            pagination.page(rows),
+            (
+                vec!["Doe"],
+                Some(PaginationCursor::new(Some("Doe".to_string()), "0".to_string()))
+            )
+        );
+    }
+
+    /// Scenarios shared by the database adapters, run on the database of `POSTGRES_TEST_DATABASE_URL`
+    ///
+    /// They are ignored by default: `cargo test repositories -- --ignored` to run them.
+    mod repositories {
+        use super::{Config, Db, GenericDb};
+        use sqlx::{Connection, PgConnection};
+
+        /// Migrated database in a new schema
+        ///
+        /// Each test has its own schema, so that the tests can run concurrently on the same database.
+        /// The schemas (`test_*`) are not dropped: the database should be a disposable one.
+        async fn db() -> Db {
+            let url = std::env::var("POSTGRES_TEST_DATABASE_URL").expect("POSTGRES_TEST_DATABASE_URL must be set");
+            let schema = format!("test_{}", uuid::Uuid::new_v4().simple());
+
+            let mut connection = PgConnection::connect(&url).await.unwrap();
+            sqlx::query(&format!("CREATE SCHEMA {schema}"))
+                .execute(&mut connection)
+                .await
+                .unwrap();
+            connection.close().await.unwrap();
+
+            let separator = if url.contains('?') { '&' } else { '?' };
+            let config = Config {
+                database_url: format!("{url}{separator}options=-c%20search_path%3D{schema}"),
+                database_auto_migration: true,
+                database_max_connections: 2,
+                database_min_connections: 0,
+                database_max_lifetime: 60,
+                database_connect_timeout: 10,
+                database_idle_timeout: 60,
+                ..Config::default()
+            };
+
+            Db::new(&config).await.unwrap()
+        }
+
+        repository_tests!(
+            #[ignore = "requires a PostgreSQL database"]
+            db()
+        );
+    }
+}
@@ -0,0 +1,285 @@
+//! Application PostgreSQL repository
+
+use crate::adapters::database::model::application::ApplicationModel;
+use crate::adapters::database::postgres::{
+    Db, PostgresConditions, PostgresCursorPagination, PostgresCursorRow, PostgresFilterField, PostgresQueryFilters,
+    PostgresQuerySorts,
+};
+use crate::domain::repositories::application::ApplicationRepository;
+use crate::domain::repositories::application::dto::{
+    CountApplicationsDtoRequest, CountApplicationsDtoResponse, CreateApplicationDtoRequest,
+    CreateApplicationDtoResponse, DeleteApplicationDtoRequest, DeleteApplicationDtoResponse,
+    GetApplicationByIdDtoRequest, GetApplicationByIdDtoResponse, GetApplicationsDtoRequest, GetApplicationsDtoResponse,
+    RestoreApplicationDtoRequest, RestoreApplicationDtoResponse, UpdateApplicationDtoRequest,
+    UpdateApplicationDtoResponse,
+};
+use crate::domain::use_cases::application::delete_application::DeleteApplicationUseCaseResponse;
+use crate::domain::use_cases::application::restore_application::RestoreApplicationUseCaseResponse;
+use crate::domain::use_cases::application::update_application::UpdateApplicationUseCaseResponse;
+use crate::domain::use_cases::application::{ApplicationUseCaseError, ApplicationUseCaseResponse};
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use async_trait::async_trait;
+use sqlx::QueryBuilder;
+use std::sync::Arc;
+
+/// Fields available for filtering the applications
+const FILTER_FIELDS: [PostgresFilterField; 6] = [
+    ("id", "TEXT"),
+    ("name", "TEXT"),
+    ("audience", "TEXT"),
+    ("created_at", "TIMESTAMP"),
+    ("updated_at", "TIMESTAMP"),
+    ("deleted_at", "TIMESTAMP"),
+];
+
+/// Application PostgreSQL repository
+#[derive(Debug, Clone)]
+pub struct ApplicationPostgresRepository {
+    db: Arc<Db>,
+}
+
+impl ApplicationPostgresRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl ApplicationRepository for ApplicationPostgresRepository {
+    #[instrument(skip(self), name = "application_repository_create")]
+    async fn create(
+        &self,
+        req: CreateApplicationDtoRequest,
+    ) -> Result<CreateApplicationDtoResponse, ApplicationUseCaseError> {
+        let application_id = Id::new().map_err(|err| {
+            error!(error = %err, "Failed to create application ID");
+            ApplicationUseCaseError::InvalidId()
+        })?;
+        let now = UtcDateTime::now();
+
+        sqlx::query("
+            INSERT INTO applications (id, name, access_token_lifetime, refresh_token_lifetime, audience, created_at, updated_at, deleted_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, NULL)
+        ")
+        .bind(application_id.clone().to_string())
+        .bind(&req.0.name)
+        .bind(req.0.access_token_lifetime.map(i64::from))
+        .bind(req.0.refresh_token_lifetime.map(i64::from))
+        .bind(&req.0.audience)
+        .bind(now.value())
+        .bind(now.value())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to create application");
+            ApplicationUseCaseError::DatabaseError("Application creation error".to_string())
+        })?;
+
+        Ok(CreateApplicationDtoResponse(ApplicationUseCaseResponse {
+            id: application_id,
+            name: req.0.name,
+            access_token_lifetime: req.0.access_token_lifetime,
+            refresh_token_lifetime: req.0.refresh_token_lifetime,
+            audience: req.0.audience,
+            created_at: now.clone(),
+            updated_at: now,
+            deleted_at: None,
+        }))
+    }
+
+    #[instrument(skip(self), name = "application_repository_get_by_id")]
+    async fn get_by_id(
+        &self,
+        req: GetApplicationByIdDtoRequest,
+    ) -> Result<GetApplicationByIdDtoResponse, ApplicationUseCaseError> {
+        let result = sqlx::query_as::<_, ApplicationModel<i64>>(
+            "
+            SELECT id, name, access_token_lifetime, refresh_token_lifetime, audience, created_at, updated_at, deleted_at
+            FROM applications
+            WHERE id = $1
+                AND deleted_at IS NULL",
+        )
+        .bind(req.0.id.to_string())
+        .fetch_optional(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get application");
+            ApplicationUseCaseError::DatabaseError("Failed to get application".to_string())
+        })?;
+
+        let application = match result {
+            Some(row) => row.try_into().map_err(|err| {
+                error!(error = %err, "Failed to convert application model to application use case response");
+                ApplicationUseCaseError::FromModelError()
+            })?,
+            None => Err(ApplicationUseCaseError::ApplicationNotFound())?,
+        };
+
+        Ok(GetApplicationByIdDtoResponse(application))
+    }
+
+    #[instrument(skip(self), name = "application_repository_get_all")]
+    async fn get_applications(
+        &self,
+        req: GetApplicationsDtoRequest,
+    ) -> Result<GetApplicationsDtoResponse, ApplicationUseCaseError> {
+        // Sorts & pagination
+        let sorts = PostgresQuerySorts(req.0.sorts.unwrap_or_default());
+        let pagination = PostgresCursorPagination::new(
+            req.0.pagination,
+            &sorts,
+            &["id", "name", "updated_at", "deleted_at"],
+            "id",
+        );
+
+        let mut query = QueryBuilder::new(
+            "SELECT id, name, access_token_lifetime, refresh_token_lifetime, audience, created_at, updated_at, deleted_at",
+        );
+        pagination.push_select(&mut query);
+        query.push(" FROM applications");
+
+        // Filters
+        let mut conditions = PostgresConditions::new(&mut query);
+        conditions.push_soft_delete("deleted_at", req.0.deleted);
+        PostgresQueryFilters(req.0.filters.unwrap_or_default()).push_sql(&mut conditions, &FILTER_FIELDS);
+        pagination.push_condition(&mut conditions);
+
+        pagination.push_order(&mut query);
+
+        let rows = query
+            .build_query_as::<PostgresCursorRow<ApplicationModel<i64>>>()
+            .fetch_all(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to get applications");
+                ApplicationUseCaseError::DatabaseError("Failed to get applications".to_string())
+            })?;
+        let (applications, next_cursor) = pagination.page(rows);
+
+        let applications = applications
+            .into_iter()
+            .map(ApplicationUseCaseResponse::try_from)
+            .collect::<Result<Vec<ApplicationUseCaseResponse>, _>>()
+            .map_err(|err| {
+                error!(error = %err, "Failed to convert application model to application use case response");
+                ApplicationUseCaseError::FromModelError()
+            })?;
+
+        Ok(GetApplicationsDtoResponse {
+            applications,
+            next_cursor,
+        })
+    }
+
+    #[instrument(skip(self), name = "application_repository_update")]
+    async fn update(
+        &self,
+        req: UpdateApplicationDtoRequest,
+    ) -> Result<UpdateApplicationDtoResponse, ApplicationUseCaseError> {
+        let result = sqlx::query(
+            "
+            UPDATE applications
+            SET name = $1, access_token_lifetime = $2, refresh_token_lifetime = $3, audience = $4, updated_at = $5
+            WHERE id = $6
+                AND deleted_at IS NULL",
+        )
+        .bind(req.0.name)
+        .bind(req.0.access_token_lifetime.map(i64::from))
+        .bind(req.0.refresh_token_lifetime.map(i64::from))
+        .bind(req.0.audience)
+        .bind(UtcDateTime::now().value())
+        .bind(req.0.id.to_string())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to update application");
+            ApplicationUseCaseError::DatabaseError("Failed to update application".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(ApplicationUseCaseError::ApplicationNotFound())?;
+        }
+
+        Ok(UpdateApplicationDtoResponse(UpdateApplicationUseCaseResponse()))
+    }
+
+    #[instrument(skip(self), name = "application_repository_delete")]
+    async fn delete(
+        &self,
+        req: DeleteApplicationDtoRequest,
+    ) -> Result<DeleteApplicationDtoResponse, ApplicationUseCaseError> {
+        let result = sqlx::query(
+            "
+            UPDATE applications
+            SET deleted_at = $1
+            WHERE id = $2
+                AND deleted_at IS NULL",
+        )
+        .bind(Some(UtcDateTime::now().value()))
+        .bind(req.0.id.to_string())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to delete application");
+            ApplicationUseCaseError::DatabaseError("Failed to delete application".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(ApplicationUseCaseError::ApplicationNotFound())?;
+        }
+
+        Ok(DeleteApplicationDtoResponse(DeleteApplicationUseCaseResponse()))
+    }
+
+    #[instrument(skip(self), name = "application_repository_count")]
+    async fn count_applications(
+        &self,
+        req: CountApplicationsDtoRequest,
+    ) -> Result<CountApplicationsDtoResponse, ApplicationUseCaseError> {
+        let mut query = QueryBuilder::new("SELECT COUNT(*) AS total FROM applications");
+
+        let mut conditions = PostgresConditions::new(&mut query);
+        conditions.push_soft_delete("deleted_at", req.deleted);
+        PostgresQueryFilters(req.filters.unwrap_or_default()).push_sql(&mut conditions, &FILTER_FIELDS);
+
+        let (total,) = query
+            .build_query_as::<(i64,)>()
+            .fetch_one(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to count applications");
+                ApplicationUseCaseError::DatabaseError("Failed to count applications".to_string())
+            })?;
+
+        Ok(CountApplicationsDtoResponse(total))
+    }
+
+    #[instrument(skip(self), name = "application_repository_restore")]
+    async fn restore(
+        &self,
+        req: RestoreApplicationDtoRequest,
+    ) -> Result<RestoreApplicationDtoResponse, ApplicationUseCaseError> {
+        let result = sqlx::query(
+            "
+            UPDATE applications
+            SET deleted_at = NULL
+            WHERE id = $1
+                AND deleted_at IS NOT NULL",
+        )
+        .bind(req.0.id.to_string())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to restore application");
+            ApplicationUseCaseError::DatabaseError("Failed to restore application".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(ApplicationUseCaseError::ApplicationNotFound())?;
+        }
+
+        Ok(RestoreApplicationDtoResponse(RestoreApplicationUseCaseResponse()))
+    }
+}
@@ -0,0 +1,174 @@
+//! Audit PostgreSQL repository
+
+use crate::adapters::database::model::audit::AuditEventModel;
+use crate::adapters::database::postgres::{
+    Db, PostgresConditions, PostgresCursorPagination, PostgresCursorRow, PostgresQuerySorts,
+};
+use crate::domain::entities::audit_event::AuditEvent;
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::repositories::audit::dto::{
+    CountAuditEventsDtoRequest, CountAuditEventsDtoResponse, CreateAuditEventDtoRequest, CreateAuditEventDtoResponse,
+    GetAuditEventsDtoRequest, GetAuditEventsDtoResponse,
+};
+use crate::domain::use_cases::audit::AuditUseCaseError;
+use crate::domain::use_cases::audit::get_audit_events::AuditEventFilters;
+use crate::domain::value_objects::query_sort::QuerySorts;
+use async_trait::async_trait;
+use sqlx::QueryBuilder;
+use std::sync::Arc;
+
+/// Audit PostgreSQL repository
+#[derive(Debug, Clone)]
+pub struct AuditPostgresRepository {
+    db: Arc<Db>,
+}
+
+impl AuditPostgresRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+
+    /// Add the conditions of the filters
+    fn push_filters(conditions: &mut PostgresConditions<'_, '_>, filters: &AuditEventFilters) {
+        if let Some(actor_id) = &filters.actor_id {
+            conditions.push().push("actor_id = ").push_bind(actor_id.to_string());
+        }
+        if let Some(action) = &filters.action {
+            conditions
+                .push()
+                .push("action = ")
+                .push_bind(action.as_str().to_string());
+        }
+        if let Some(target_id) = &filters.target_id {
+            conditions.push().push("target_id = ").push_bind(target_id.clone());
+        }
+        if let Some(from) = &filters.from {
+            conditions.push().push("created_at >= ").push_bind(from.value());
+        }
+        if let Some(to) = &filters.to {
+            conditions.push().push("created_at < ").push_bind(to.value());
+        }
+    }
+}
+
+#[async_trait]
+impl AuditRepository for AuditPostgresRepository {
+    #[instrument(skip(self), name = "audit_repository_create")]
+    async fn create(&self, req: CreateAuditEventDtoRequest) -> Result<CreateAuditEventDtoResponse, AuditUseCaseError> {
+        sqlx::query(
+            r#"
+                INSERT INTO audit_events (id, actor_id, action, target_id, ip, request_id, created_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+        )
+        .bind(req.0.id.to_string())
+        .bind(req.0.actor_id.map(|actor_id| actor_id.to_string()))
+        .bind(req.0.action.as_str())
+        .bind(req.0.target_id)
+        .bind(req.0.ip)
+        .bind(req.0.request_id)
+        .bind(req.0.created_at.value())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to create audit event");
+            AuditUseCaseError::DatabaseError("Failed to create audit event".to_string())
+        })?;
+
+        Ok(CreateAuditEventDtoResponse())
+    }
+
+    #[instrument(skip(self), name = "audit_repository_get_all")]
+    async fn get_audit_events(
+        &self,
+        req: GetAuditEventsDtoRequest,
+    ) -> Result<GetAuditEventsDtoResponse, AuditUseCaseError> {
+        // Sorts (most recent events first by default) & pagination
+        let sort_fields = ["actor_id", "action", "target_id", "created_at"];
+        let sorts = match PostgresQuerySorts(req.0.sorts.unwrap_or_default()) {
+            sorts if !sorts.has_valid_fields(&sort_fields) => PostgresQuerySorts(QuerySorts::from("-created_at")),
+            sorts => sorts,
+        };
+        let pagination = PostgresCursorPagination::new(req.0.pagination, &sorts, &sort_fields, "id");
+
+        let mut query = QueryBuilder::new("SELECT id, actor_id, action, target_id, ip, request_id, created_at");
+        pagination.push_select(&mut query);
+        query.push(" FROM audit_events");
+
+        // Filters
+        let mut conditions = PostgresConditions::new(&mut query);
+        Self::push_filters(&mut conditions, &req.0.filters);
+        pagination.push_condition(&mut conditions);
+
+        pagination.push_order(&mut query);
+
+        let rows = query
+            .build_query_as::<PostgresCursorRow<AuditEventModel>>()
+            .fetch_all(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to get audit events");
+                AuditUseCaseError::DatabaseError("Failed to get audit events".to_string())
+            })?;
+        let (events, next_cursor) = pagination.page(rows);
+
+        let events = events
+            .into_iter()
+            .map(AuditEvent::try_from)
+            .collect::<Result<Vec<AuditEvent>, _>>()
+            .map_err(|err| {
+                error!(error = %err, "Failed to convert audit event model to audit event");
+                AuditUseCaseError::FromModelError()
+            })?;
+
+        Ok(GetAuditEventsDtoResponse { events, next_cursor })
+    }
+
+    #[instrument(skip(self), name = "audit_repository_count")]
+    async fn count_audit_events(
+        &self,
+        req: CountAuditEventsDtoRequest,
+    ) -> Result<CountAuditEventsDtoResponse, AuditUseCaseError> {
+        let mut query = QueryBuilder::new("SELECT COUNT(*) AS total FROM audit_events");
+        Self::push_filters(&mut PostgresConditions::new(&mut query), &req.0);
+
+        let (total,) = query
+            .build_query_as::<(i64,)>()
+            .fetch_one(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to count audit events");
+                AuditUseCaseError::DatabaseError("Failed to count audit events".to_string())
+            })?;
+
+        Ok(CountAuditEventsDtoResponse(total))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::audit_event::AuditAction;
+    use crate::domain::value_objects::datetime::UtcDateTime;
+
+    /// SQL code of the filters conditions
+    fn filters_sql(filters: &AuditEventFilters) -> String {
+        let mut query = QueryBuilder::new("");
+        AuditPostgresRepository::push_filters(&mut PostgresConditions::new(&mut query), filters);
+
+        query.sql().to_string()
+    }
+
+    #[test]
+    fn test_push_filters() {
+        assert_eq!(filters_sql(&AuditEventFilters::default()), "");
+
+        let filters = AuditEventFilters {
+            action: Some(AuditAction::LoginFailed),
+            from: Some(UtcDateTime::now()),
+            ..AuditEventFilters::default()
+        };
+        assert_eq!(filters_sql(&filters), " WHERE action = $1 AND created_at >= $2");
+    }
+}
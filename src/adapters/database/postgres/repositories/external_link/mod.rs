@@ -0,0 +1,511 @@
+//! External link PostgreSQL repository
+
+use crate::adapters::database::model::external_link::{ExternalLinkModel, ExternalLinkUserModel, SCOPES_SEPARATOR};
+use crate::{
+    adapters::database::postgres::{
+        Db, PostgresConditions, PostgresCursorPagination, PostgresCursorRow, PostgresFilterField, PostgresQueryFilters,
+        PostgresQuerySorts,
+    },
+    domain::{
+        repositories::external_link::{
+            ExternalLinkRepository,
+            dto::{
+                CountExternalLinksDtoRequest, CountExternalLinksDtoResponse, CreateExternalLinkDtoRequest,
+                CreateExternalLinkDtoResponse, DeleteExternalLinkDtoRequest, DeleteExternalLinkDtoResponse,
+                GetExternalLinkByIdDtoRequest, GetExternalLinkByIdDtoResponse, GetExternalLinksDtoRequest,
+                GetExternalLinksDtoResponse, GetLinkedUserDtoRequest, GetLinkedUserDtoResponse,
+                LinkApplicationDtoRequest, LinkApplicationDtoResponse, LinkUserDtoRequest, LinkUserDtoResponse,
+                RestoreExternalLinkDtoRequest, RestoreExternalLinkDtoResponse, UnlinkApplicationDtoRequest,
+                UnlinkApplicationDtoResponse, UnlinkUserDtoRequest, UnlinkUserDtoResponse,
+                UpdateExternalLinkDtoRequest, UpdateExternalLinkDtoResponse,
+            },
+        },
+        use_cases::external_link::{
+            ExternalLinkApplicationUseCaseResponse, ExternalLinkUseCaseError, ExternalLinkUseCaseResponse,
+            ExternalLinkUserUseCaseResponse, delete_external_link::DeleteExternalLinkUseCaseResponse,
+            restore_external_link::RestoreExternalLinkUseCaseResponse,
+            unlink_application::UnlinkApplicationUseCaseResponse, unlink_user::UnlinkUserUseCaseResponse,
+            update_external_link::UpdateExternalLinkUseCaseResponse,
+        },
+        value_objects::{datetime::UtcDateTime, id::Id, oidc_provider::OidcProvider},
+    },
+};
+use async_trait::async_trait;
+use sqlx::QueryBuilder;
+use std::sync::Arc;
+
+/// Fields available for filtering the external links
+const FILTER_FIELDS: [PostgresFilterField; 6] = [
+    ("id", "TEXT"),
+    ("name", "TEXT"),
+    ("oidc_issuer", "TEXT"),
+    ("created_at", "TIMESTAMP"),
+    ("updated_at", "TIMESTAMP"),
+    ("deleted_at", "TIMESTAMP"),
+];
+
+/// External link PostgreSQL repository
+#[derive(Debug, Clone)]
+pub struct ExternalLinkPostgresRepository {
+    db: Arc<Db>,
+}
+
+impl ExternalLinkPostgresRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+
+    /// Provider columns: issuer, client ID, client secret and scopes
+    fn provider_columns(
+        provider: &Option<OidcProvider>,
+    ) -> (Option<String>, Option<String>, Option<String>, Option<String>) {
+        match provider {
+            Some(provider) => (
+                Some(provider.issuer().to_string()),
+                Some(provider.client_id().to_string()),
+                Some(provider.client_secret().to_string()),
+                Some(provider.scopes().join(SCOPES_SEPARATOR)),
+            ),
+            None => (None, None, None, None),
+        }
+    }
+}
+
+#[async_trait]
+impl ExternalLinkRepository for ExternalLinkPostgresRepository {
+    #[instrument(skip(self), name = "external_link_repository_create")]
+    async fn create(
+        &self,
+        req: CreateExternalLinkDtoRequest,
+    ) -> Result<CreateExternalLinkDtoResponse, ExternalLinkUseCaseError> {
+        let external_link_id = Id::new().map_err(|err| {
+            error!(error = %err, "Failed to create external link ID");
+            ExternalLinkUseCaseError::InvalidId()
+        })?;
+        let now = UtcDateTime::now();
+        let (issuer, client_id, client_secret, scopes) = Self::provider_columns(&req.0.provider);
+
+        sqlx::query("
+            INSERT INTO external_links (id, name, oidc_issuer, oidc_client_id, oidc_client_secret, oidc_scopes, created_at, updated_at, deleted_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, NULL)
+        ")
+        .bind(external_link_id.clone().to_string())
+        .bind(&req.0.name)
+        .bind(issuer)
+        .bind(client_id)
+        .bind(client_secret)
+        .bind(scopes)
+        .bind(now.value())
+        .bind(now.value())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to create external link");
+            ExternalLinkUseCaseError::DatabaseError("External link creation error".to_string())
+        })?;
+
+        Ok(CreateExternalLinkDtoResponse(ExternalLinkUseCaseResponse {
+            id: external_link_id,
+            name: req.0.name,
+            provider: req.0.provider,
+            created_at: now.clone(),
+            updated_at: now,
+            deleted_at: None,
+        }))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_count")]
+    async fn count_external_links(
+        &self,
+        req: CountExternalLinksDtoRequest,
+    ) -> Result<CountExternalLinksDtoResponse, ExternalLinkUseCaseError> {
+        let mut query = QueryBuilder::new("SELECT COUNT(*) AS total FROM external_links");
+
+        let mut conditions = PostgresConditions::new(&mut query);
+        conditions.push_soft_delete("deleted_at", req.deleted);
+        PostgresQueryFilters(req.filters.unwrap_or_default()).push_sql(&mut conditions, &FILTER_FIELDS);
+
+        let (total,) = query
+            .build_query_as::<(i64,)>()
+            .fetch_one(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to count external links");
+                ExternalLinkUseCaseError::DatabaseError("Failed to count external links".to_string())
+            })?;
+
+        Ok(CountExternalLinksDtoResponse(total))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_get_all")]
+    async fn get_external_links(
+        &self,
+        req: GetExternalLinksDtoRequest,
+    ) -> Result<GetExternalLinksDtoResponse, ExternalLinkUseCaseError> {
+        // Sorts & pagination
+        let sorts = PostgresQuerySorts(req.0.sorts.unwrap_or_default());
+        let pagination = PostgresCursorPagination::new(
+            req.0.pagination,
+            &sorts,
+            &["name", "created_at", "updated_at", "deleted_at"],
+            "id",
+        );
+
+        let mut query = QueryBuilder::new(
+            "SELECT id, name, oidc_issuer, oidc_client_id, oidc_client_secret, oidc_scopes, created_at, updated_at, deleted_at",
+        );
+        pagination.push_select(&mut query);
+        query.push(" FROM external_links");
+
+        // Filters
+        let mut conditions = PostgresConditions::new(&mut query);
+        conditions.push_soft_delete("deleted_at", req.0.deleted);
+        PostgresQueryFilters(req.0.filters.unwrap_or_default()).push_sql(&mut conditions, &FILTER_FIELDS);
+        pagination.push_condition(&mut conditions);
+
+        pagination.push_order(&mut query);
+
+        let rows = query
+            .build_query_as::<PostgresCursorRow<ExternalLinkModel>>()
+            .fetch_all(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to get external links");
+                ExternalLinkUseCaseError::DatabaseError("Failed to get external links".to_string())
+            })?;
+        let (external_links, next_cursor) = pagination.page(rows);
+
+        let external_links = external_links
+            .into_iter()
+            .map(ExternalLinkUseCaseResponse::try_from)
+            .collect::<Result<Vec<ExternalLinkUseCaseResponse>, _>>()
+            .map_err(|err| {
+                error!(error = %err, "Failed to convert external link model to external link use case response");
+                ExternalLinkUseCaseError::FromModelError()
+            })?;
+
+        Ok(GetExternalLinksDtoResponse {
+            external_links,
+            next_cursor,
+        })
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_get_by_id")]
+    async fn get_by_id(
+        &self,
+        req: GetExternalLinkByIdDtoRequest,
+    ) -> Result<GetExternalLinkByIdDtoResponse, ExternalLinkUseCaseError> {
+        let result = sqlx::query_as::<_, ExternalLinkModel>("
+            SELECT id, name, oidc_issuer, oidc_client_id, oidc_client_secret, oidc_scopes, created_at, updated_at, deleted_at
+            FROM external_links
+            WHERE id = $1
+                AND deleted_at IS NULL")
+        .bind(req.0.id.to_string())
+        .fetch_optional(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get external link");
+            ExternalLinkUseCaseError::DatabaseError("Failed to get external link".to_string())
+        })?;
+
+        let external_link = match result {
+            Some(row) => row.try_into().map_err(|err| {
+                error!(error = %err, "Failed to convert external_link model to external_link use case response");
+                ExternalLinkUseCaseError::FromModelError()
+            })?,
+            None => Err(ExternalLinkUseCaseError::ExternalLinkNotFound())?,
+        };
+
+        Ok(GetExternalLinkByIdDtoResponse(external_link))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_update")]
+    async fn update(
+        &self,
+        req: UpdateExternalLinkDtoRequest,
+    ) -> Result<UpdateExternalLinkDtoResponse, ExternalLinkUseCaseError> {
+        let (issuer, client_id, client_secret, scopes) = Self::provider_columns(&req.0.provider);
+
+        let result = sqlx::query("
+            UPDATE external_links
+            SET name = $1, oidc_issuer = $2, oidc_client_id = $3, oidc_client_secret = $4, oidc_scopes = $5, updated_at = $6
+            WHERE id = $7
+                AND deleted_at IS NULL")
+        .bind(&req.0.name)
+        .bind(issuer)
+        .bind(client_id)
+        .bind(client_secret)
+        .bind(scopes)
+        .bind(UtcDateTime::now().value())
+        .bind(req.0.id.to_string())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to update external link");
+            ExternalLinkUseCaseError::DatabaseError("Failed to update external link".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(ExternalLinkUseCaseError::ExternalLinkNotFound())?;
+        }
+
+        Ok(UpdateExternalLinkDtoResponse(UpdateExternalLinkUseCaseResponse()))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_delete")]
+    async fn delete(
+        &self,
+        req: DeleteExternalLinkDtoRequest,
+    ) -> Result<DeleteExternalLinkDtoResponse, ExternalLinkUseCaseError> {
+        let result = sqlx::query(
+            "
+            UPDATE external_links
+            SET deleted_at = $1
+            WHERE id = $2
+                AND deleted_at IS NULL",
+        )
+        .bind(Some(UtcDateTime::now().value()))
+        .bind(req.0.id.to_string())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to delete external link");
+            ExternalLinkUseCaseError::DatabaseError("Failed to delete external link".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(ExternalLinkUseCaseError::ExternalLinkNotFound())?;
+        }
+
+        Ok(DeleteExternalLinkDtoResponse(DeleteExternalLinkUseCaseResponse()))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_restore")]
+    async fn restore(
+        &self,
+        req: RestoreExternalLinkDtoRequest,
+    ) -> Result<RestoreExternalLinkDtoResponse, ExternalLinkUseCaseError> {
+        let result = sqlx::query(
+            "
+            UPDATE external_links
+            SET deleted_at = NULL
+            WHERE id = $1
+                AND deleted_at IS NOT NULL",
+        )
+        .bind(req.0.id.to_string())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to restore external link");
+            ExternalLinkUseCaseError::DatabaseError("Failed to restore external link".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(ExternalLinkUseCaseError::ExternalLinkNotFound())?;
+        }
+
+        Ok(RestoreExternalLinkDtoResponse(RestoreExternalLinkUseCaseResponse()))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_link_user")]
+    async fn link_user(&self, req: LinkUserDtoRequest) -> Result<LinkUserDtoResponse, ExternalLinkUseCaseError> {
+        let now = UtcDateTime::now();
+
+        let mut tx = self.db.pool.begin().await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            ExternalLinkUseCaseError::DatabaseError("Failed to link user".to_string())
+        })?;
+
+        // Replace the previous external user ID
+        sqlx::query(
+            "
+            DELETE FROM external_links_users
+            WHERE external_link_id = $1
+                AND user_id = $2",
+        )
+        .bind(req.0.external_link_id.to_string())
+        .bind(req.0.user_id.to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to delete previous user link");
+            ExternalLinkUseCaseError::DatabaseError("Failed to link user".to_string())
+        })?;
+
+        // The link is only created if the user exists
+        let result = sqlx::query(
+            "
+            INSERT INTO external_links_users (external_link_id, user_id, external_user_id, created_at)
+            SELECT $1, id, $2, $3
+            FROM users
+            WHERE id = $4
+                AND deleted_at IS NULL",
+        )
+        .bind(req.0.external_link_id.to_string())
+        .bind(&req.0.external_user_id)
+        .bind(now.value())
+        .bind(req.0.user_id.to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to link user");
+            match err {
+                sqlx::Error::Database(err) if err.is_unique_violation() => {
+                    ExternalLinkUseCaseError::ExternalUserIdAlreadyUsed()
+                }
+                _ => ExternalLinkUseCaseError::DatabaseError("Failed to link user".to_string()),
+            }
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(ExternalLinkUseCaseError::UserNotFound());
+        }
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            ExternalLinkUseCaseError::DatabaseError("Failed to link user".to_string())
+        })?;
+
+        Ok(LinkUserDtoResponse(ExternalLinkUserUseCaseResponse {
+            external_link_id: req.0.external_link_id,
+            user_id: req.0.user_id,
+            external_user_id: req.0.external_user_id,
+            created_at: now,
+        }))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_unlink_user")]
+    async fn unlink_user(&self, req: UnlinkUserDtoRequest) -> Result<UnlinkUserDtoResponse, ExternalLinkUseCaseError> {
+        let result = sqlx::query(
+            "
+            DELETE FROM external_links_users
+            WHERE external_link_id = $1
+                AND user_id = $2",
+        )
+        .bind(req.0.external_link_id.to_string())
+        .bind(req.0.user_id.to_string())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to unlink user");
+            ExternalLinkUseCaseError::DatabaseError("Failed to unlink user".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(ExternalLinkUseCaseError::ExternalLinkUserNotFound())?;
+        }
+
+        Ok(UnlinkUserDtoResponse(UnlinkUserUseCaseResponse()))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_get_linked_user")]
+    async fn get_linked_user(
+        &self,
+        req: GetLinkedUserDtoRequest,
+    ) -> Result<GetLinkedUserDtoResponse, ExternalLinkUseCaseError> {
+        let result = sqlx::query_as::<_, ExternalLinkUserModel>(
+            "
+            SELECT elu.external_link_id, elu.user_id, elu.external_user_id, elu.created_at
+            FROM external_links_users elu
+            INNER JOIN external_links el ON el.id = elu.external_link_id AND el.deleted_at IS NULL
+            INNER JOIN users u ON u.id = elu.user_id AND u.deleted_at IS NULL
+            WHERE elu.external_link_id = $1
+                AND elu.external_user_id = $2",
+        )
+        .bind(req.0.external_link_id.to_string())
+        .bind(&req.0.external_user_id)
+        .fetch_optional(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get linked user");
+            ExternalLinkUseCaseError::DatabaseError("Failed to get linked user".to_string())
+        })?;
+
+        let link = result
+            .map(ExternalLinkUserUseCaseResponse::try_from)
+            .transpose()
+            .map_err(|err| {
+                error!(error = %err, "Failed to convert linked user model to use case response");
+                ExternalLinkUseCaseError::FromModelError()
+            })?;
+
+        Ok(GetLinkedUserDtoResponse(link))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_link_application")]
+    async fn link_application(
+        &self,
+        req: LinkApplicationDtoRequest,
+    ) -> Result<LinkApplicationDtoResponse, ExternalLinkUseCaseError> {
+        let now = UtcDateTime::now();
+
+        let application = sqlx::query(
+            "
+            SELECT id
+            FROM applications
+            WHERE id = $1
+                AND deleted_at IS NULL",
+        )
+        .bind(req.0.application_id.to_string())
+        .fetch_optional(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get application");
+            ExternalLinkUseCaseError::DatabaseError("Failed to link application".to_string())
+        })?;
+        if application.is_none() {
+            return Err(ExternalLinkUseCaseError::ApplicationNotFound());
+        }
+
+        // Linking twice has no effect
+        sqlx::query(
+            "
+            INSERT INTO external_links_applications (external_link_id, application_id, created_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT DO NOTHING",
+        )
+        .bind(req.0.external_link_id.to_string())
+        .bind(req.0.application_id.to_string())
+        .bind(now.value())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to link application");
+            ExternalLinkUseCaseError::DatabaseError("Failed to link application".to_string())
+        })?;
+
+        Ok(LinkApplicationDtoResponse(ExternalLinkApplicationUseCaseResponse {
+            external_link_id: req.0.external_link_id,
+            application_id: req.0.application_id,
+            created_at: now,
+        }))
+    }
+
+    #[instrument(skip(self), name = "external_link_repository_unlink_application")]
+    async fn unlink_application(
+        &self,
+        req: UnlinkApplicationDtoRequest,
+    ) -> Result<UnlinkApplicationDtoResponse, ExternalLinkUseCaseError> {
+        let result = sqlx::query(
+            "
+            DELETE FROM external_links_applications
+            WHERE external_link_id = $1
+                AND application_id = $2",
+        )
+        .bind(req.0.external_link_id.to_string())
+        .bind(req.0.application_id.to_string())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to unlink application");
+            ExternalLinkUseCaseError::DatabaseError("Failed to unlink application".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(ExternalLinkUseCaseError::ExternalLinkApplicationNotFound())?;
+        }
+
+        Ok(UnlinkApplicationDtoResponse(UnlinkApplicationUseCaseResponse()))
+    }
+}
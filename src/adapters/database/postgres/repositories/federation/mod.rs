@@ -0,0 +1,347 @@
+//! Federation PostgreSQL repository
+
+use crate::adapters::database::model::federation::{OidcLoginStateModel, OidcProviderModel};
+use crate::adapters::database::postgres::Db;
+use crate::domain::entities::user::UserId;
+use crate::domain::repositories::federation::FederationRepository;
+use crate::domain::repositories::federation::dto::{
+    ConsumeLoginStateDtoRequest, ConsumeLoginStateDtoResponse, CreateLoginStateDtoRequest, CreateLoginStateDtoResponse,
+    FindOrCreateUserDtoRequest, FindOrCreateUserDtoResponse, GetProviderDtoRequest, GetProviderDtoResponse,
+};
+use crate::domain::use_cases::federation::FederationUseCaseError;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use crate::domain::value_objects::password::Password;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use sqlx::{Postgres, Transaction};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Federation PostgreSQL repository
+#[derive(Debug, Clone)]
+pub struct FederationPostgresRepository {
+    db: Arc<Db>,
+}
+
+impl FederationPostgresRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+
+    /// Add a hashed password to the user password history
+    async fn add_password_history(
+        tx: &mut Transaction<'_, Postgres>,
+        user_id: &UserId,
+        password: &Password,
+        created_at: &UtcDateTime,
+    ) -> Result<(), FederationUseCaseError> {
+        let id = Id::new().map_err(|err| {
+            error!(error = %err, "Failed to create password history ID");
+            FederationUseCaseError::InvalidId()
+        })?;
+
+        sqlx::query(
+            "
+            INSERT INTO password_history (id, user_id, password, created_at)
+            VALUES ($1, $2, $3, $4)
+        ",
+        )
+        .bind(id.to_string())
+        .bind(user_id.to_string())
+        .bind(password.to_string())
+        .bind(created_at.value())
+        .execute(&mut **tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to add password to history");
+            FederationUseCaseError::DatabaseError("Failed to add password to history".to_string())
+        })?;
+
+        Ok(())
+    }
+
+    /// Get the user ID of an external user if it is already linked
+    async fn get_linked_user(
+        tx: &mut Transaction<'_, Postgres>,
+        req: &FindOrCreateUserDtoRequest,
+    ) -> Result<Option<UserId>, FederationUseCaseError> {
+        let result = sqlx::query_as::<_, (String, Option<NaiveDateTime>)>(
+            r#"
+                SELECT u.id, u.deleted_at
+                FROM external_links_users elu
+                INNER JOIN users u ON u.id = elu.user_id
+                WHERE elu.external_link_id = $1
+                    AND elu.external_user_id = $2
+            "#,
+        )
+        .bind(req.external_link_id.to_string())
+        .bind(&req.external_user_id)
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get linked user");
+            FederationUseCaseError::DatabaseError("Failed to get linked user".to_string())
+        })?;
+
+        match result {
+            Some((_, Some(_))) => Err(FederationUseCaseError::UserDisabled()),
+            Some((id, None)) => Ok(Some(Id::from_str(&id)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the user with the same email or create it
+    ///
+    /// An invited user is activated with the given password.
+    async fn get_or_create_user_by_email(
+        tx: &mut Transaction<'_, Postgres>,
+        req: &FindOrCreateUserDtoRequest,
+        now: &UtcDateTime,
+    ) -> Result<UserId, FederationUseCaseError> {
+        let email = req.email.as_ref().ok_or_else(FederationUseCaseError::EmailRequired)?;
+
+        let result = sqlx::query_as::<_, (String, Option<String>, Option<NaiveDateTime>)>(
+            r#"
+                SELECT id, password, deleted_at
+                FROM users
+                WHERE LOWER(email) = LOWER($1)
+                FOR UPDATE
+            "#,
+        )
+        .bind(email.to_string())
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get user by email");
+            FederationUseCaseError::DatabaseError("Failed to get user by email".to_string())
+        })?;
+
+        let user_id = match result {
+            Some((_, _, Some(_))) => return Err(FederationUseCaseError::UserDisabled()),
+            Some((id, Some(_), None)) => return Ok(Id::from_str(&id)?),
+            Some((id, None, None)) => {
+                let user_id = Id::from_str(&id)?;
+                sqlx::query(
+                    "
+                    UPDATE users
+                    SET password = $1, password_changed_at = $2, updated_at = $3
+                    WHERE id = $4
+                ",
+                )
+                .bind(req.password.to_string())
+                .bind(now.value())
+                .bind(now.value())
+                .bind(user_id.to_string())
+                .execute(&mut **tx)
+                .await
+                .map_err(|err| {
+                    error!(error = %err, "Failed to activate user");
+                    FederationUseCaseError::DatabaseError("Failed to activate user".to_string())
+                })?;
+
+                user_id
+            }
+            None => {
+                let user_id = Id::new().map_err(|err| {
+                    error!(error = %err, "Failed to create user ID");
+                    FederationUseCaseError::InvalidId()
+                })?;
+                sqlx::query("
+                    INSERT INTO users (id, email, password, password_changed_at, lastname, firstname, created_at, updated_at, deleted_at)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, NULL)
+                ")
+                .bind(user_id.to_string())
+                .bind(email.to_string())
+                .bind(req.password.to_string())
+                .bind(now.value())
+                .bind(&req.lastname)
+                .bind(&req.firstname)
+                .bind(now.value())
+                .bind(now.value())
+                .execute(&mut **tx)
+                .await
+                .map_err(|err| {
+                    error!(error = %err, "Failed to create user");
+                    FederationUseCaseError::DatabaseError("User creation error".to_string())
+                })?;
+
+                user_id
+            }
+        };
+        Self::add_password_history(tx, &user_id, &req.password, now).await?;
+
+        Ok(user_id)
+    }
+}
+
+#[async_trait]
+impl FederationRepository for FederationPostgresRepository {
+    #[instrument(skip(self), name = "federation_repository_get_provider")]
+    async fn get_provider(&self, req: GetProviderDtoRequest) -> Result<GetProviderDtoResponse, FederationUseCaseError> {
+        let result = sqlx::query_as::<_, OidcProviderModel>(
+            r#"
+                SELECT el.oidc_issuer, el.oidc_client_id, el.oidc_client_secret, el.oidc_scopes
+                FROM external_links el
+                INNER JOIN external_links_applications ela ON ela.external_link_id = el.id
+                INNER JOIN applications a ON a.id = ela.application_id AND a.deleted_at IS NULL
+                WHERE el.id = $1
+                    AND ela.application_id = $2
+                    AND el.deleted_at IS NULL
+            "#,
+        )
+        .bind(req.external_link_id.to_string())
+        .bind(req.application_id.to_string())
+        .fetch_optional(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get external link provider");
+            FederationUseCaseError::DatabaseError("Failed to get external link provider".to_string())
+        })?;
+
+        let provider = match result {
+            Some(model) => model.into_provider().map_err(|err| {
+                error!(error = %err, "Failed to convert external link provider");
+                FederationUseCaseError::FromModelError()
+            })?,
+            None => None,
+        };
+
+        Ok(GetProviderDtoResponse(provider))
+    }
+
+    #[instrument(skip(self), name = "federation_repository_create_login_state")]
+    async fn create_login_state(
+        &self,
+        req: CreateLoginStateDtoRequest,
+    ) -> Result<CreateLoginStateDtoResponse, FederationUseCaseError> {
+        sqlx::query(
+            r#"
+                INSERT INTO oidc_login_states (state, external_link_id, application_id, nonce, expired_at)
+                VALUES ($1, $2, $3, $4, $5)
+            "#,
+        )
+        .bind(req.0.state)
+        .bind(req.0.external_link_id.to_string())
+        .bind(req.0.application_id.to_string())
+        .bind(req.0.nonce)
+        .bind(req.0.expired_at.value())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to create login state");
+            FederationUseCaseError::DatabaseError("Failed to create login state".to_string())
+        })?;
+
+        Ok(CreateLoginStateDtoResponse())
+    }
+
+    #[instrument(skip(self), name = "federation_repository_consume_login_state")]
+    async fn consume_login_state(
+        &self,
+        req: ConsumeLoginStateDtoRequest,
+    ) -> Result<ConsumeLoginStateDtoResponse, FederationUseCaseError> {
+        let now = UtcDateTime::now();
+        let mut tx = self.db.pool.begin().await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            FederationUseCaseError::DatabaseError("Failed to consume login state".to_string())
+        })?;
+
+        let result = sqlx::query_as::<_, OidcLoginStateModel>(
+            r#"
+                SELECT state, external_link_id, application_id, nonce, expired_at
+                FROM oidc_login_states
+                WHERE state = $1
+                    AND expired_at >= $2
+                FOR UPDATE
+            "#,
+        )
+        .bind(&req.state)
+        .bind(now.value())
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get login state");
+            FederationUseCaseError::DatabaseError("Failed to get login state".to_string())
+        })?;
+
+        // The state can only be used once, expired states are removed at the same time
+        sqlx::query(
+            r#"
+                DELETE FROM oidc_login_states
+                WHERE state = $1
+                    OR expired_at < $2
+            "#,
+        )
+        .bind(req.state)
+        .bind(now.value())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to delete login state");
+            FederationUseCaseError::DatabaseError("Failed to delete login state".to_string())
+        })?;
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            FederationUseCaseError::DatabaseError("Failed to consume login state".to_string())
+        })?;
+
+        let login_state = match result {
+            Some(model) => Some(model.try_into().map_err(|err| {
+                error!(error = %err, "Failed to convert login state model");
+                FederationUseCaseError::FromModelError()
+            })?),
+            None => None,
+        };
+
+        Ok(ConsumeLoginStateDtoResponse(login_state))
+    }
+
+    #[instrument(skip(self), name = "federation_repository_find_or_create_user")]
+    async fn find_or_create_user(
+        &self,
+        req: FindOrCreateUserDtoRequest,
+    ) -> Result<FindOrCreateUserDtoResponse, FederationUseCaseError> {
+        let now = UtcDateTime::now();
+        let mut tx = self.db.pool.begin().await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            FederationUseCaseError::DatabaseError("Failed to find or create user".to_string())
+        })?;
+
+        if let Some(user_id) = Self::get_linked_user(&mut tx, &req).await? {
+            return Ok(FindOrCreateUserDtoResponse(user_id));
+        }
+
+        let user_id = Self::get_or_create_user_by_email(&mut tx, &req, &now).await?;
+
+        // Link the external user
+        sqlx::query(
+            r#"
+                INSERT INTO external_links_users (external_link_id, user_id, external_user_id, created_at)
+                VALUES ($1, $2, $3, $4)
+            "#,
+        )
+        .bind(req.external_link_id.to_string())
+        .bind(user_id.to_string())
+        .bind(req.external_user_id)
+        .bind(now.value())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| match err {
+            sqlx::Error::Database(err) if err.is_unique_violation() => FederationUseCaseError::UserAlreadyLinked(),
+            _ => {
+                error!(error = %err, "Failed to link external user");
+                FederationUseCaseError::DatabaseError("Failed to link external user".to_string())
+            }
+        })?;
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            FederationUseCaseError::DatabaseError("Failed to find or create user".to_string())
+        })?;
+
+        Ok(FindOrCreateUserDtoResponse(user_id))
+    }
+}
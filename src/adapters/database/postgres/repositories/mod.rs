@@ -0,0 +1,106 @@
+//! PostgreSQL repositories implementation
+
+use crate::adapters::database::DatabaseRepositories;
+use crate::adapters::database::postgres::Db;
+use application::ApplicationPostgresRepository;
+use audit::AuditPostgresRepository;
+use client::ClientPostgresRepository;
+use email::EmailPostgresRepository;
+use external_link::ExternalLinkPostgresRepository;
+use federation::FederationPostgresRepository;
+use introspection::IntrospectionPostgresRepository;
+use invitation::InvitationPostgresRepository;
+use magic_link::MagicLinkPostgresRepository;
+use password_reset::PasswordResetPostgresRepository;
+use refresh_token::RefreshTokenPostgresRepository;
+use scope::ScopePostgresRepository;
+use user::UserPostgresRepository;
+use webhook::WebhookPostgresRepository;
+
+pub mod application;
+pub mod audit;
+pub mod client;
+pub mod email;
+pub mod external_link;
+pub mod federation;
+pub mod introspection;
+pub mod invitation;
+pub mod magic_link;
+pub mod password_reset;
+pub mod refresh_token;
+pub mod scope;
+pub mod user;
+pub mod webhook;
+
+impl DatabaseRepositories for Db {
+    type User = UserPostgresRepository;
+    type RefreshToken = RefreshTokenPostgresRepository;
+    type PasswordReset = PasswordResetPostgresRepository;
+    type MagicLink = MagicLinkPostgresRepository;
+    type Application = ApplicationPostgresRepository;
+    type Client = ClientPostgresRepository;
+    type Scope = ScopePostgresRepository;
+    type ExternalLink = ExternalLinkPostgresRepository;
+    type Invitation = InvitationPostgresRepository;
+    type Introspection = IntrospectionPostgresRepository;
+    type Federation = FederationPostgresRepository;
+    type Audit = AuditPostgresRepository;
+    type Webhook = WebhookPostgresRepository;
+    type Email = EmailPostgresRepository;
+
+    fn user_repository(&self) -> Self::User {
+        UserPostgresRepository::new(self.clone())
+    }
+
+    fn refresh_token_repository(&self) -> Self::RefreshToken {
+        RefreshTokenPostgresRepository::new(self.clone())
+    }
+
+    fn password_reset_repository(&self) -> Self::PasswordReset {
+        PasswordResetPostgresRepository::new(self.clone())
+    }
+
+    fn magic_link_repository(&self) -> Self::MagicLink {
+        MagicLinkPostgresRepository::new(self.clone())
+    }
+
+    fn application_repository(&self) -> Self::Application {
+        ApplicationPostgresRepository::new(self.clone())
+    }
+
+    fn client_repository(&self) -> Self::Client {
+        ClientPostgresRepository::new(self.clone())
+    }
+
+    fn scope_repository(&self) -> Self::Scope {
+        ScopePostgresRepository::new(self.clone())
+    }
+
+    fn external_link_repository(&self) -> Self::ExternalLink {
+        ExternalLinkPostgresRepository::new(self.clone())
+    }
+
+    fn invitation_repository(&self) -> Self::Invitation {
+        InvitationPostgresRepository::new(self.clone())
+    }
+
+    fn introspection_repository(&self) -> Self::Introspection {
+        IntrospectionPostgresRepository::new(self.clone())
+    }
+
+    fn federation_repository(&self) -> Self::Federation {
+        FederationPostgresRepository::new(self.clone())
+    }
+
+    fn audit_repository(&self) -> Self::Audit {
+        AuditPostgresRepository::new(self.clone())
+    }
+
+    fn webhook_repository(&self) -> Self::Webhook {
+        WebhookPostgresRepository::new(self.clone())
+    }
+
+    fn email_repository(&self) -> Self::Email {
+        EmailPostgresRepository::new(self.clone())
+    }
+}
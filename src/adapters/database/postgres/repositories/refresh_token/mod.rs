@@ -0,0 +1,181 @@
+//! Refresh token PostgreSQL repository
+
+use crate::adapters::database::postgres::Db;
+use crate::adapters::database::postgres::repositories::webhook::enqueue_webhook_message;
+use crate::domain::repositories::refresh_token::RefreshTokenRepository;
+use crate::domain::repositories::refresh_token::dto::{
+    CreateRefreshTokenDtoRequest, CreateRefreshTokenDtoResponse, DeleteExpiredRefreshTokensDtoRequest,
+    DeleteExpiredRefreshTokensDtoResponse, DeleteRefreshTokenDtoRequest, DeleteRefreshTokenDtoResponse,
+    DeleteUserRefreshTokensDtoRequest, DeleteUserRefreshTokensDtoResponse, GetRefreshTokenDtoRequest,
+    GetRefreshTokenDtoResponse,
+};
+use crate::domain::use_cases::user::UserUseCaseError;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use async_trait::async_trait;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Refresh token PostgreSQL repository
+#[derive(Debug, Clone)]
+pub struct RefreshTokenPostgresRepository {
+    db: Arc<Db>,
+}
+
+impl RefreshTokenPostgresRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl RefreshTokenRepository for RefreshTokenPostgresRepository {
+    #[instrument(skip(self), name = "refresh_token_repository_create")]
+    async fn create_refresh_token(
+        &self,
+        req: CreateRefreshTokenDtoRequest,
+    ) -> Result<CreateRefreshTokenDtoResponse, UserUseCaseError> {
+        sqlx::query(
+            r#"
+                INSERT INTO refresh_tokens (refresh_token, user_id, application_id, access_token, expired_at)
+                VALUES ($1, $2, $3, $4, $5)
+            "#,
+        )
+        .bind(req.refresh_token.refresh_token.to_string())
+        .bind(req.user_id.to_string())
+        .bind(req.application_id.to_string())
+        .bind(req.access_token.token.to_string())
+        .bind(req.refresh_token.expired_at.value())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to create refresh token");
+            UserUseCaseError::DatabaseError("Failed to create refresh token".to_string())
+        })?;
+
+        Ok(CreateRefreshTokenDtoResponse())
+    }
+
+    /// Get a refresh token
+    #[instrument(skip(self), name = "refresh_token_repository_get")]
+    async fn get_refresh_token(
+        &self,
+        req: GetRefreshTokenDtoRequest,
+    ) -> Result<GetRefreshTokenDtoResponse, UserUseCaseError> {
+        let row = sqlx::query_as::<_, (String, String)>(
+            r#"
+                SELECT user_id, application_id
+                FROM refresh_tokens 
+                WHERE refresh_token = $1 
+                    AND expired_at >= $2
+            "#,
+        )
+        .bind(req.0.to_string())
+        .bind(UtcDateTime::now().value())
+        .fetch_optional(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get refresh token");
+            UserUseCaseError::DatabaseError("Failed to get refresh token".to_string())
+        })?;
+
+        let response = match row {
+            Some((user_id, application_id)) => GetRefreshTokenDtoResponse {
+                user_id: Id::from_str(&user_id)?,
+                application_id: Id::from_str(&application_id)?,
+            },
+            None => Err(UserUseCaseError::InvalidRefreshToken())?,
+        };
+
+        Ok(response)
+    }
+
+    /// Delete a refresh token
+    #[instrument(skip(self), name = "refresh_token_repository_delete")]
+    async fn delete_refresh_token(
+        &self,
+        req: DeleteRefreshTokenDtoRequest,
+    ) -> Result<DeleteRefreshTokenDtoResponse, UserUseCaseError> {
+        let _result = sqlx::query(
+            r#"
+                DELETE FROM refresh_tokens 
+                WHERE refresh_token = $1
+            "#,
+        )
+        .bind(req.0.to_string())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to delete refresh token");
+            UserUseCaseError::DatabaseError("Failed to delete refresh token".to_string())
+        })?;
+
+        Ok(DeleteRefreshTokenDtoResponse())
+    }
+
+    /// Delete expired refresh tokens
+    #[instrument(skip(self), name = "refresh_token_repository_delete_expired")]
+    async fn delete_expired_refresh_tokens(
+        &self,
+        _req: DeleteExpiredRefreshTokensDtoRequest,
+    ) -> Result<DeleteExpiredRefreshTokensDtoResponse, UserUseCaseError> {
+        let result = sqlx::query(
+            r#"
+                DELETE FROM refresh_tokens 
+                WHERE expired_at < $1
+            "#,
+        )
+        .bind(UtcDateTime::now().value())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to delete expired refresh tokens");
+            UserUseCaseError::DatabaseError("Failed to delete expired refresh tokens".to_string())
+        })?;
+
+        Ok(DeleteExpiredRefreshTokensDtoResponse {
+            deleted: result.rows_affected(),
+        })
+    }
+
+    /// Delete all the refresh tokens of a user
+    #[instrument(skip(self), name = "refresh_token_repository_delete_user_refresh_tokens")]
+    async fn delete_user_refresh_tokens(
+        &self,
+        req: DeleteUserRefreshTokensDtoRequest,
+    ) -> Result<DeleteUserRefreshTokensDtoResponse, UserUseCaseError> {
+        let mut tx = self.db.pool.begin().await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            UserUseCaseError::DatabaseError("Failed to delete user refresh tokens".to_string())
+        })?;
+
+        let result = sqlx::query(
+            r#"
+                DELETE FROM refresh_tokens
+                WHERE user_id = $1
+            "#,
+        )
+        .bind(req.user_id.to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to delete user refresh tokens");
+            UserUseCaseError::DatabaseError("Failed to delete user refresh tokens".to_string())
+        })?;
+
+        enqueue_webhook_message(&mut tx, &req.message).await.map_err(|err| {
+            error!(error = %err, "Failed to enqueue webhook message");
+            UserUseCaseError::DatabaseError("Failed to delete user refresh tokens".to_string())
+        })?;
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            UserUseCaseError::DatabaseError("Failed to delete user refresh tokens".to_string())
+        })?;
+
+        Ok(DeleteUserRefreshTokensDtoResponse {
+            deleted: result.rows_affected(),
+        })
+    }
+}
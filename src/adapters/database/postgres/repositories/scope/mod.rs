@@ -0,0 +1,204 @@
+//! Scope PostgreSQL repository
+
+use crate::adapters::database::model::scope::ScopeModel;
+use crate::adapters::database::postgres::{
+    Db, PostgresConditions, PostgresCursorPagination, PostgresCursorRow, PostgresFilterField, PostgresQueryFilters,
+    PostgresQuerySorts,
+};
+use crate::domain::repositories::scope::ScopeRepository;
+use crate::domain::repositories::scope::dto::{
+    CountScopesDtoRequest, CountScopesDtoResponse, CreateScopeDtoRequest, CreateScopeDtoResponse,
+    DeleteScopeDtoRequest, DeleteScopeDtoResponse, GetScopesDtoRequest, GetScopesDtoResponse, RestoreScopeDtoRequest,
+    RestoreScopeDtoResponse,
+};
+use crate::domain::use_cases::scope::delete_scope::DeleteScopeUseCaseResponse;
+use crate::domain::use_cases::scope::restore_scope::RestoreScopeUseCaseResponse;
+use crate::domain::use_cases::scope::{ScopeUseCaseError, ScopeUseCaseResponse};
+use crate::domain::value_objects::datetime::UtcDateTime;
+use async_trait::async_trait;
+use sqlx::QueryBuilder;
+use std::sync::Arc;
+
+/// Fields available for filtering the scopes (`application_id` has its own filter)
+const FILTER_FIELDS: [PostgresFilterField; 4] = [
+    ("id", "TEXT"),
+    ("created_at", "TIMESTAMP"),
+    ("updated_at", "TIMESTAMP"),
+    ("deleted_at", "TIMESTAMP"),
+];
+
+/// Scope PostgreSQL repository
+#[derive(Debug, Clone)]
+pub struct ScopePostgresRepository {
+    db: Arc<Db>,
+}
+
+impl ScopePostgresRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl ScopeRepository for ScopePostgresRepository {
+    #[instrument(skip(self), name = "scope_repository_create")]
+    async fn create(&self, req: CreateScopeDtoRequest) -> Result<CreateScopeDtoResponse, ScopeUseCaseError> {
+        let now = UtcDateTime::now();
+
+        sqlx::query(
+            "
+            INSERT INTO scopes (id, application_id, created_at, updated_at, deleted_at)
+            VALUES ($1, $2, $3, $4, NULL)
+        ",
+        )
+        .bind(req.0.id.to_string())
+        .bind(req.0.application_id.to_string())
+        .bind(now.value())
+        .bind(now.value())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to create scope");
+            match err {
+                sqlx::Error::Database(err) if err.is_unique_violation() => ScopeUseCaseError::IdAlreadyExists(),
+                _ => ScopeUseCaseError::DatabaseError("Scope creation error".to_string()),
+            }
+        })?;
+
+        Ok(CreateScopeDtoResponse(ScopeUseCaseResponse {
+            id: req.0.id,
+            application_id: req.0.application_id,
+            created_at: now.clone(),
+            updated_at: now,
+            deleted_at: None,
+        }))
+    }
+
+    #[instrument(skip(self), name = "scope_repository_get_all")]
+    async fn get_scopes(&self, req: GetScopesDtoRequest) -> Result<GetScopesDtoResponse, ScopeUseCaseError> {
+        // Sorts & pagination
+        let sorts = PostgresQuerySorts(req.0.sorts.unwrap_or_default());
+        let pagination = PostgresCursorPagination::new(
+            req.0.pagination,
+            &sorts,
+            &["id", "created_at", "updated_at", "deleted_at"],
+            "id",
+        );
+
+        let mut query = QueryBuilder::new("SELECT id, application_id, created_at, updated_at, deleted_at");
+        pagination.push_select(&mut query);
+        query.push(" FROM scopes");
+
+        let mut conditions = PostgresConditions::new(&mut query);
+        conditions.push_soft_delete("deleted_at", req.0.deleted);
+
+        if let Some(application_id) = req.0.application_id {
+            conditions
+                .push()
+                .push("application_id = ")
+                .push_bind(application_id.to_string());
+        }
+
+        // Filters
+        PostgresQueryFilters(req.0.filters.unwrap_or_default()).push_sql(&mut conditions, &FILTER_FIELDS);
+        pagination.push_condition(&mut conditions);
+
+        pagination.push_order(&mut query);
+
+        let rows = query
+            .build_query_as::<PostgresCursorRow<ScopeModel>>()
+            .fetch_all(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to get scopes");
+                ScopeUseCaseError::DatabaseError("Failed to get scopes".to_string())
+            })?;
+        let (scopes, next_cursor) = pagination.page(rows);
+
+        let scopes = scopes
+            .into_iter()
+            .map(ScopeUseCaseResponse::try_from)
+            .collect::<Result<Vec<ScopeUseCaseResponse>, _>>()
+            .map_err(|err| {
+                error!(error = %err, "Failed to convert scope model to scope use case response");
+                ScopeUseCaseError::FromModelError()
+            })?;
+
+        Ok(GetScopesDtoResponse { scopes, next_cursor })
+    }
+
+    #[instrument(skip(self), name = "scope_repository_count_all")]
+    async fn count_scopes(&self, req: CountScopesDtoRequest) -> Result<CountScopesDtoResponse, ScopeUseCaseError> {
+        let mut query = QueryBuilder::new("SELECT COUNT(*) AS total FROM scopes");
+
+        let mut conditions = PostgresConditions::new(&mut query);
+        conditions.push_soft_delete("deleted_at", req.deleted);
+
+        if let Some(application_id) = req.application_id {
+            conditions.push().push("application_id = ").push_bind(application_id);
+        }
+
+        PostgresQueryFilters(req.filters.unwrap_or_default()).push_sql(&mut conditions, &FILTER_FIELDS);
+
+        let (total,) = query
+            .build_query_as::<(i64,)>()
+            .fetch_one(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to count scopes");
+                ScopeUseCaseError::DatabaseError("Failed to count scopes".to_string())
+            })?;
+
+        Ok(CountScopesDtoResponse(total))
+    }
+
+    #[instrument(skip(self), name = "scope_repository_delete")]
+    async fn delete(&self, req: DeleteScopeDtoRequest) -> Result<DeleteScopeDtoResponse, ScopeUseCaseError> {
+        let result = sqlx::query(
+            "
+            UPDATE scopes
+            SET deleted_at = $1
+            WHERE id = $2
+                AND deleted_at IS NULL",
+        )
+        .bind(Some(UtcDateTime::now().value()))
+        .bind(req.0.id.to_string())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to delete scope");
+            ScopeUseCaseError::DatabaseError("Failed to delete scope".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(ScopeUseCaseError::ScopeNotFound())?;
+        }
+
+        Ok(DeleteScopeDtoResponse(DeleteScopeUseCaseResponse()))
+    }
+
+    #[instrument(skip(self), name = "scope_repository_restore")]
+    async fn restore(&self, req: RestoreScopeDtoRequest) -> Result<RestoreScopeDtoResponse, ScopeUseCaseError> {
+        let result = sqlx::query(
+            "
+            UPDATE scopes
+            SET deleted_at = NULL
+            WHERE id = $1
+                AND deleted_at IS NOT NULL",
+        )
+        .bind(req.0.id.to_string())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to restore scope");
+            ScopeUseCaseError::DatabaseError("Failed to restore scope".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(ScopeUseCaseError::ScopeNotFound())?;
+        }
+
+        Ok(RestoreScopeDtoResponse(RestoreScopeUseCaseResponse()))
+    }
+}
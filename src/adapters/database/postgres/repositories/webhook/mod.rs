@@ -0,0 +1,341 @@
+//! Webhook PostgreSQL repository
+
+use crate::adapters::database::model::webhook::{EVENTS_SEPARATOR, WebhookDeliveryModel, WebhookModel};
+use crate::adapters::database::postgres::{
+    Db, PostgresConditions, PostgresCursorPagination, PostgresCursorRow, PostgresQuerySorts,
+};
+use crate::domain::entities::webhook::{Webhook, WebhookDelivery, WebhookDeliveryStatus, WebhookEvent, WebhookMessage};
+use crate::domain::repositories::webhook::WebhookRepository;
+use crate::domain::repositories::webhook::dto::{
+    CountWebhooksDtoRequest, CountWebhooksDtoResponse, CreateWebhookDtoRequest, CreateWebhookDtoResponse,
+    DeleteWebhookDtoRequest, DeleteWebhookDtoResponse, GetDueWebhookDeliveriesDtoRequest,
+    GetDueWebhookDeliveriesDtoResponse, GetWebhooksDtoRequest, GetWebhooksDtoResponse, UpdateWebhookDeliveryDtoRequest,
+    UpdateWebhookDeliveryDtoResponse,
+};
+use crate::domain::use_cases::webhook::WebhookUseCaseError;
+use crate::domain::use_cases::webhook::delete_webhook::DeleteWebhookUseCaseResponse;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use async_trait::async_trait;
+use chrono::Duration;
+use sqlx::{PgConnection, QueryBuilder, Row};
+use std::sync::Arc;
+
+/// Time during which a delivery fetched by a worker is not fetched again (in second)
+///
+/// It prevents several servers from sending the same delivery,
+/// and the delivery is retried after this delay if the worker stops before saving the result.
+const DELIVERY_LEASE: i64 = 300;
+
+/// Add a delivery to the outbox for each webhook subscribed to the message event
+///
+/// It is called by the other repositories with their transaction,
+/// so that the event is only sent if the action which triggered it is saved.
+pub async fn enqueue_webhook_message(conn: &mut PgConnection, message: &WebhookMessage) -> Result<(), sqlx::Error> {
+    let payload = message.payload();
+
+    // Webhooks subscribed to the event
+    let webhook_ids = sqlx::query_scalar::<_, String>(
+        "
+        SELECT id
+        FROM webhooks
+        WHERE $1 = ANY(string_to_array(events, ','))
+            AND deleted_at IS NULL",
+    )
+    .bind(message.event.as_str())
+    .fetch_all(&mut *conn)
+    .await?;
+
+    for webhook_id in &webhook_ids {
+        let delivery_id = Id::new().map_err(|err| sqlx::Error::Protocol(err.to_string()))?;
+
+        sqlx::query("
+            INSERT INTO webhook_deliveries (id, webhook_id, event_id, event, payload, status, attempts, next_attempt_at, last_error, created_at, delivered_at)
+            VALUES ($1, $2, $3, $4, $5, $6, 0, $7, NULL, $8, NULL)
+        ")
+        .bind(delivery_id.to_string())
+        .bind(webhook_id)
+        .bind(message.id.to_string())
+        .bind(message.event.as_str())
+        .bind(&payload)
+        .bind(WebhookDeliveryStatus::Pending.as_str())
+        .bind(message.created_at.value())
+        .bind(message.created_at.value())
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Webhook PostgreSQL repository
+#[derive(Debug, Clone)]
+pub struct WebhookPostgresRepository {
+    db: Arc<Db>,
+}
+
+impl WebhookPostgresRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+
+    /// Events stored in a single column
+    fn join_events(events: &[WebhookEvent]) -> String {
+        events
+            .iter()
+            .map(|event| event.as_str())
+            .collect::<Vec<_>>()
+            .join(EVENTS_SEPARATOR)
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for WebhookPostgresRepository {
+    #[instrument(skip(self, req), name = "webhook_repository_create")]
+    async fn create_webhook(
+        &self,
+        req: CreateWebhookDtoRequest,
+    ) -> Result<CreateWebhookDtoResponse, WebhookUseCaseError> {
+        let webhook_id = Id::new().map_err(|err| {
+            error!(error = %err, "Failed to create webhook ID");
+            WebhookUseCaseError::InvalidId()
+        })?;
+        let now = UtcDateTime::now();
+
+        // The webhook is only created if the application exists
+        let result = sqlx::query(
+            "
+            INSERT INTO webhooks (id, application_id, url, secret, events, created_at, updated_at, deleted_at)
+            SELECT $1, id, $2, $3, $4, $5, $6, NULL
+            FROM applications
+            WHERE id = $7
+                AND deleted_at IS NULL
+        ",
+        )
+        .bind(webhook_id.to_string())
+        .bind(&req.webhook.url)
+        .bind(req.secret.value())
+        .bind(Self::join_events(&req.webhook.events))
+        .bind(now.value())
+        .bind(now.value())
+        .bind(req.webhook.application_id.to_string())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to create webhook");
+            WebhookUseCaseError::DatabaseError("Webhook creation error".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(WebhookUseCaseError::ApplicationNotFound());
+        }
+
+        Ok(CreateWebhookDtoResponse(Webhook {
+            id: webhook_id,
+            application_id: req.webhook.application_id,
+            url: req.webhook.url,
+            secret: req.secret,
+            events: req.webhook.events,
+            created_at: now.clone(),
+            updated_at: now,
+            deleted_at: None,
+        }))
+    }
+
+    #[instrument(skip(self), name = "webhook_repository_count")]
+    async fn count_webhooks(
+        &self,
+        req: CountWebhooksDtoRequest,
+    ) -> Result<CountWebhooksDtoResponse, WebhookUseCaseError> {
+        let query = "SELECT COUNT(*) AS total FROM webhooks WHERE application_id = $1 AND deleted_at IS NULL";
+
+        let result = sqlx::query(query)
+            .bind(req.application_id.to_string())
+            .fetch_one(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to count webhooks");
+                WebhookUseCaseError::DatabaseError("Failed to count webhooks".to_string())
+            })?;
+
+        Ok(CountWebhooksDtoResponse(result.try_get("total").map_err(|err| {
+            error!(error = %err, "Failed to count webhooks");
+            WebhookUseCaseError::DatabaseError("Failed to count webhooks".to_string())
+        })?))
+    }
+
+    #[instrument(skip(self), name = "webhook_repository_get_all")]
+    async fn get_webhooks(&self, req: GetWebhooksDtoRequest) -> Result<GetWebhooksDtoResponse, WebhookUseCaseError> {
+        // Sorts & pagination
+        let sorts = PostgresQuerySorts(req.0.sorts.unwrap_or_default());
+        let pagination =
+            PostgresCursorPagination::new(req.0.pagination, &sorts, &["url", "created_at", "updated_at"], "id");
+
+        let mut query =
+            QueryBuilder::new("SELECT id, application_id, url, secret, events, created_at, updated_at, deleted_at");
+        pagination.push_select(&mut query);
+        query.push(" FROM webhooks");
+
+        let mut conditions = PostgresConditions::new(&mut query);
+        conditions
+            .push()
+            .push("application_id = ")
+            .push_bind(req.0.application_id.to_string());
+        conditions.push_soft_delete("deleted_at", false);
+        pagination.push_condition(&mut conditions);
+
+        pagination.push_order(&mut query);
+
+        let rows = query
+            .build_query_as::<PostgresCursorRow<WebhookModel>>()
+            .fetch_all(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to get webhooks");
+                WebhookUseCaseError::DatabaseError("Failed to get webhooks".to_string())
+            })?;
+        let (webhooks, next_cursor) = pagination.page(rows);
+
+        let webhooks = webhooks
+            .into_iter()
+            .map(Webhook::try_from)
+            .collect::<Result<Vec<Webhook>, _>>()
+            .map_err(|err| {
+                error!(error = %err, "Failed to convert webhook model to webhook entity");
+                WebhookUseCaseError::FromModelError()
+            })?;
+
+        Ok(GetWebhooksDtoResponse { webhooks, next_cursor })
+    }
+
+    #[instrument(skip(self), name = "webhook_repository_delete")]
+    async fn delete_webhook(
+        &self,
+        req: DeleteWebhookDtoRequest,
+    ) -> Result<DeleteWebhookDtoResponse, WebhookUseCaseError> {
+        let result = sqlx::query(
+            "
+            UPDATE webhooks
+            SET deleted_at = $1
+            WHERE id = $2
+                AND application_id = $3
+                AND deleted_at IS NULL",
+        )
+        .bind(Some(UtcDateTime::now().value()))
+        .bind(req.0.id.to_string())
+        .bind(req.0.application_id.to_string())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to delete webhook");
+            WebhookUseCaseError::DatabaseError("Failed to delete webhook".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(WebhookUseCaseError::WebhookNotFound());
+        }
+
+        Ok(DeleteWebhookDtoResponse(DeleteWebhookUseCaseResponse()))
+    }
+
+    #[instrument(skip(self), name = "webhook_repository_get_due_deliveries")]
+    async fn get_due_deliveries(
+        &self,
+        req: GetDueWebhookDeliveriesDtoRequest,
+    ) -> Result<GetDueWebhookDeliveriesDtoResponse, WebhookUseCaseError> {
+        let now = UtcDateTime::now();
+
+        let mut tx = self.db.pool.begin().await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            WebhookUseCaseError::DatabaseError("Failed to get webhook deliveries".to_string())
+        })?;
+
+        // Rows locked by another server are skipped
+        let deliveries = sqlx::query_as::<_, WebhookDeliveryModel<i64>>(
+            "
+            SELECT d.id, d.webhook_id, w.url, w.secret, d.event_id, d.event, d.payload, d.attempts
+            FROM webhook_deliveries d
+                INNER JOIN webhooks w ON w.id = d.webhook_id AND w.deleted_at IS NULL
+            WHERE d.status = $1
+                AND d.next_attempt_at <= $2
+            ORDER BY d.next_attempt_at
+            LIMIT $3
+            FOR UPDATE OF d SKIP LOCKED",
+        )
+        .bind(WebhookDeliveryStatus::Pending.as_str())
+        .bind(now.value())
+        .bind(i64::from(req.limit))
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get webhook deliveries");
+            WebhookUseCaseError::DatabaseError("Failed to get webhook deliveries".to_string())
+        })?
+        .into_iter()
+        .map(WebhookDelivery::try_from)
+        .collect::<Result<Vec<WebhookDelivery>, _>>()
+        .map_err(|err| {
+            error!(error = %err, "Failed to convert webhook delivery model to webhook delivery entity");
+            WebhookUseCaseError::FromModelError()
+        })?;
+
+        // Lease
+        let lease_until = now.value() + Duration::seconds(DELIVERY_LEASE);
+        for delivery in &deliveries {
+            sqlx::query(
+                "
+                UPDATE webhook_deliveries
+                SET next_attempt_at = $1
+                WHERE id = $2",
+            )
+            .bind(lease_until)
+            .bind(delivery.id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to lease webhook delivery");
+                WebhookUseCaseError::DatabaseError("Failed to get webhook deliveries".to_string())
+            })?;
+        }
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            WebhookUseCaseError::DatabaseError("Failed to get webhook deliveries".to_string())
+        })?;
+
+        Ok(GetDueWebhookDeliveriesDtoResponse(deliveries))
+    }
+
+    #[instrument(skip(self), name = "webhook_repository_update_delivery")]
+    async fn update_delivery(
+        &self,
+        req: UpdateWebhookDeliveryDtoRequest,
+    ) -> Result<UpdateWebhookDeliveryDtoResponse, WebhookUseCaseError> {
+        let delivered_at = match req.status {
+            WebhookDeliveryStatus::Delivered => Some(UtcDateTime::now().value()),
+            _ => None,
+        };
+
+        sqlx::query(
+            "
+            UPDATE webhook_deliveries
+            SET status = $1, attempts = $2, next_attempt_at = $3, last_error = $4, delivered_at = $5
+            WHERE id = $6",
+        )
+        .bind(req.status.as_str())
+        .bind(i64::from(req.attempts))
+        .bind(req.next_attempt_at.value())
+        .bind(req.last_error)
+        .bind(delivered_at)
+        .bind(req.id.to_string())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to update webhook delivery");
+            WebhookUseCaseError::DatabaseError("Failed to update webhook delivery".to_string())
+        })?;
+
+        Ok(UpdateWebhookDeliveryDtoResponse())
+    }
+}
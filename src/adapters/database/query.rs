@@ -0,0 +1,162 @@
+//! Query helpers shared by the database adapters
+
+use crate::domain::value_objects::query_filter::{QueryFilterFieldValue, QueryFilterOperator, QueryFilters};
+use crate::domain::value_objects::query_sort::{QuerySort, QuerySortDirection, QuerySorts};
+use sqlx::{Database, QueryBuilder};
+
+/// `WHERE` clause of a query built with a [`QueryBuilder`]
+///
+/// The first condition starts the clause and the next ones are joined with `AND`,
+/// so the conditions can be added in any order and none of them is required.
+pub struct Conditions<'q, 'args, DB: Database> {
+    query: &'q mut QueryBuilder<'args, DB>,
+    empty: bool,
+}
+
+impl<'q, 'args, DB: Database> Conditions<'q, 'args, DB> {
+    /// Create a new `WHERE` clause at the end of the query
+    pub fn new(query: &'q mut QueryBuilder<'args, DB>) -> Self {
+        Self { query, empty: true }
+    }
+
+    /// Start a new condition and return the query to push it
+    pub fn push(&mut self) -> &mut QueryBuilder<'args, DB> {
+        self.query.push(match self.empty {
+            true => " WHERE ",
+            false => " AND ",
+        });
+        self.empty = false;
+
+        self.query
+    }
+
+    /// Add the soft delete condition: only the deleted rows or only the active rows
+    pub fn push_soft_delete(&mut self, column: &str, deleted: bool) -> &mut Self {
+        self.push().push(column).push(match deleted {
+            true => " IS NOT NULL",
+            false => " IS NULL",
+        });
+
+        self
+    }
+}
+
+/// Sorts of the valid fields, with the column of the valid field
+///
+/// A valid field can be prefixed by its table (Ex.: `u.email` is used for the `email` sort).
+pub fn valid_sorts<'a>(
+    sorts: &'a QuerySorts,
+    valid_fields: &'a [&str],
+) -> impl Iterator<Item = (&'a str, &'a QuerySortDirection)> {
+    sorts.0.iter().filter_map(|QuerySort { field, direction }| {
+        valid_fields
+            .iter()
+            .find(|valid| *valid == field || valid.ends_with(&format!(".{field}")))
+            .map(|column| (*column, direction))
+    })
+}
+
+/// Conditions of the valid fields, with the valid field (`name` gives the name of a valid field)
+///
+/// A field with several conditions (Ex.: `created_at[gte]` and `created_at[lt]`) is returned once per condition.
+pub fn valid_filters<'a, F>(
+    filters: &'a QueryFilters,
+    valid_fields: &'a [F],
+    name: fn(&F) -> &str,
+) -> impl Iterator<Item = (&'a F, &'a QueryFilterFieldValue<String>)> {
+    filters
+        .value()
+        .iter()
+        .filter_map(move |(field, values)| {
+            valid_fields
+                .iter()
+                .find(|valid| name(valid) == field)
+                .map(|valid| (valid, values))
+        })
+        .flat_map(|(valid, values)| values.value().iter().map(move |value| (valid, value)))
+}
+
+/// SQL comparison of a filter operator (the values follow it)
+pub fn sql_comparison(operator: &QueryFilterOperator) -> &'static str {
+    match operator {
+        QueryFilterOperator::Eq => " = ",
+        QueryFilterOperator::Ne => " <> ",
+        QueryFilterOperator::Gt => " > ",
+        QueryFilterOperator::Gte => " >= ",
+        QueryFilterOperator::Lt => " < ",
+        QueryFilterOperator::Lte => " <= ",
+        QueryFilterOperator::In => " IN ",
+        QueryFilterOperator::Nin => " NOT IN ",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::Sqlite;
+    use std::collections::HashMap;
+
+    /// SQL code of the conditions added by `push` on an empty query
+    fn conditions_sql(push: impl FnOnce(&mut Conditions<'_, '_, Sqlite>)) -> String {
+        let mut query = QueryBuilder::new("");
+        push(&mut Conditions::new(&mut query));
+
+        query.sql().to_string()
+    }
+
+    #[test]
+    fn test_conditions() {
+        assert_eq!(String::new(), conditions_sql(|_| {}));
+        assert_eq!(
+            " WHERE deleted_at IS NULL".to_owned(),
+            conditions_sql(|conditions| {
+                conditions.push_soft_delete("deleted_at", false);
+            })
+        );
+        assert_eq!(
+            " WHERE u.deleted_at IS NOT NULL AND id = ?".to_owned(),
+            conditions_sql(|conditions| {
+                conditions
+                    .push_soft_delete("u.deleted_at", true)
+                    .push()
+                    .push("id = ")
+                    .push_bind("1");
+            })
+        );
+    }
+
+    #[test]
+    fn test_valid_sorts() {
+        let sorts = QuerySorts::from("+id,-name,+unknown,-password");
+        assert_eq!(
+            vec![("u.id", &QuerySortDirection::Asc), ("name", &QuerySortDirection::Desc)],
+            valid_sorts(&sorts, &["u.id", "name"]).collect::<Vec<_>>()
+        );
+        assert_eq!(0, valid_sorts(&sorts, &[]).count());
+        assert_eq!(0, valid_sorts(&QuerySorts::default(), &["id"]).count());
+    }
+
+    #[test]
+    fn test_valid_filters() {
+        let filters = QueryFilters::try_from(HashMap::from([
+            ("lastname".to_string(), "Doe".to_string()),
+            ("created_at".to_string(), "gte:2024-01-01,lt:2025-01-01".to_string()),
+            ("password".to_string(), "eq:secret".to_string()),
+        ]))
+        .unwrap();
+        let valid_fields = [("lastname", 1), ("created_at", 2)];
+
+        let conditions = valid_filters(&filters, &valid_fields, |(name, _)| name)
+            .map(|((name, _), value)| (*name, sql_comparison(&value.operator), value.values.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                ("created_at", " >= ", vec!["2024-01-01".to_string()]),
+                ("created_at", " < ", vec!["2025-01-01".to_string()]),
+                ("lastname", " = ", vec!["Doe".to_string()]),
+            ],
+            conditions
+        );
+        assert_eq!(0, valid_filters(&filters, &[] as &[&str], |name| name).count());
+    }
+}
@@ -0,0 +1,564 @@
+//! Repository scenarios shared by the database adapters
+//!
+//! The scenarios only use the repository traits, so that all the adapters are checked
+//! against the same behavior. Each scenario expects an empty database.
+//! [`repository_tests`] generates the tests of an adapter from a database factory.
+
+use crate::adapters::database::DatabaseRepositories;
+use crate::domain::entities::access_token::AccessToken;
+use crate::domain::entities::audit_event::{AuditAction, AuditContext, AuditEvent};
+use crate::domain::entities::email::EmailMessage;
+use crate::domain::entities::invitation::Invitation;
+use crate::domain::entities::oidc_login_state::OidcLoginState;
+use crate::domain::entities::refresh_token::RefreshToken;
+use crate::domain::entities::webhook::{WebhookEvent, WebhookMessage};
+use crate::domain::repositories::application::ApplicationRepository;
+use crate::domain::repositories::application::dto::CreateApplicationDtoRequest;
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::repositories::audit::dto::{
+    CountAuditEventsDtoRequest, CreateAuditEventDtoRequest, GetAuditEventsDtoRequest,
+};
+use crate::domain::repositories::email::EmailRepository;
+use crate::domain::repositories::email::dto::GetDueEmailsDtoRequest;
+use crate::domain::repositories::external_link::ExternalLinkRepository;
+use crate::domain::repositories::external_link::dto::{CreateExternalLinkDtoRequest, LinkApplicationDtoRequest};
+use crate::domain::repositories::federation::FederationRepository;
+use crate::domain::repositories::federation::dto::{
+    ConsumeLoginStateDtoRequest, CreateLoginStateDtoRequest, FindOrCreateUserDtoRequest, GetProviderDtoRequest,
+};
+use crate::domain::repositories::invitation::InvitationRepository;
+use crate::domain::repositories::invitation::dto::{
+    AcceptInvitationDtoRequest, CreateInvitationDtoRequest, RevokeInvitationDtoRequest,
+};
+use crate::domain::repositories::refresh_token::RefreshTokenRepository;
+use crate::domain::repositories::refresh_token::dto::{
+    CreateRefreshTokenDtoRequest, DeleteRefreshTokenDtoRequest, DeleteUserRefreshTokensDtoRequest,
+    GetRefreshTokenDtoRequest,
+};
+use crate::domain::repositories::user::UserRepository;
+use crate::domain::repositories::user::dto::{
+    CreateUserDtoRequest, DeleteUserDtoRequest, GetPasswordChangedAtDtoRequest, GetUserByEmailDtoRequest,
+    GetUserByIdDtoRequest, GetUsersDtoRequest, GetUsersDtoResponse, RestoreUserDtoRequest, SearchUsersDtoRequest,
+};
+use crate::domain::repositories::webhook::WebhookRepository;
+use crate::domain::repositories::webhook::dto::{
+    CountWebhooksDtoRequest, CreateWebhookDtoRequest, GetDueWebhookDeliveriesDtoRequest, GetWebhooksDtoRequest,
+};
+use crate::domain::use_cases::application::ApplicationUseCaseResponse;
+use crate::domain::use_cases::application::create_application::CreateApplicationUseCaseRequest;
+use crate::domain::use_cases::audit::get_audit_events::{AuditEventFilters, GetAuditEventsUseCaseRequest};
+use crate::domain::use_cases::external_link::create_external_link::CreateExternalLinkUseCaseRequest;
+use crate::domain::use_cases::external_link::link_application::LinkApplicationUseCaseRequest;
+use crate::domain::use_cases::invitation::InvitationUseCaseError;
+use crate::domain::use_cases::invitation::accept_invitation::AcceptInvitationUseCaseRequest;
+use crate::domain::use_cases::invitation::revoke_invitation::RevokeInvitationUseCaseRequest;
+use crate::domain::use_cases::user::create_user::CreateUserUseCaseRequest;
+use crate::domain::use_cases::user::get_user::GetUserUseCaseRequest;
+use crate::domain::use_cases::user::get_users::GetUsersUseCaseRequest;
+use crate::domain::use_cases::user::{UserUseCaseError, UserUseCaseResponse};
+use crate::domain::use_cases::webhook::create_webhook::CreateWebhookUseCaseRequest;
+use crate::domain::use_cases::webhook::get_webhooks::GetWebhooksUseCaseRequest;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::email::Email;
+use crate::domain::value_objects::id::Id;
+use crate::domain::value_objects::oidc_provider::OidcProvider;
+use crate::domain::value_objects::pagination::Pagination;
+use crate::domain::value_objects::password::Password;
+use crate::domain::value_objects::query_sort::QuerySorts;
+use crate::domain::value_objects::webhook_secret::WebhookSecret;
+use serde_json::json;
+
+/// Generate the tests of the scenarios for a database adapter
+///
+/// `$db` is an async expression returning an `impl DatabaseRepositories`. An attribute can be added
+/// to each test, like `#[ignore]` for the adapters requiring a database server.
+macro_rules! repository_tests {
+    (#[$attr:meta] $db:expr) => {
+        repository_tests!(@scenarios [#[$attr]] $db);
+    };
+    ($db:expr) => {
+        repository_tests!(@scenarios [] $db);
+    };
+    (@scenarios $attr:tt $db:expr) => {
+        repository_tests!(@tests $attr $db;
+            users,
+            users_pagination,
+            invitations,
+            refresh_tokens,
+            audit_events,
+            federation,
+            webhooks,
+        );
+    };
+    (@tests $attr:tt $db:expr; $($scenario:ident,)+) => {
+        $(
+            repository_tests!(@test $attr $db; $scenario);
+        )+
+    };
+    (@test [$($attr:tt)*] $db:expr; $scenario:ident) => {
+        $($attr)*
+        #[tokio::test]
+        async fn $scenario() {
+            $crate::adapters::database::repository_tests::$scenario(&$db.await).await
+        }
+    };
+}
+
+fn create_user_request(email: &str, lastname: &str) -> CreateUserDtoRequest {
+    CreateUserDtoRequest {
+        id: Id::new().unwrap(),
+        user: CreateUserUseCaseRequest {
+            email: Email::new(email).unwrap(),
+            password: Password::new("$argon2id$hash", true).unwrap(),
+            lastname: lastname.to_string(),
+            firstname: "John".to_string(),
+            locale: None,
+            audit: AuditContext::default(),
+        },
+        message: webhook_message(WebhookEvent::UserCreated),
+    }
+}
+
+fn webhook_message(event: WebhookEvent) -> WebhookMessage {
+    WebhookMessage::new(event, json!({})).unwrap()
+}
+
+async fn create_user<D: DatabaseRepositories>(db: &D, email: &str, lastname: &str) -> UserUseCaseResponse {
+    db.user_repository()
+        .create_user(create_user_request(email, lastname))
+        .await
+        .unwrap()
+        .0
+}
+
+async fn create_application<D: DatabaseRepositories>(db: &D) -> ApplicationUseCaseResponse {
+    db.application_repository()
+        .create(CreateApplicationDtoRequest(CreateApplicationUseCaseRequest {
+            name: "Test application".to_string(),
+            access_token_lifetime: None,
+            refresh_token_lifetime: None,
+            audience: None,
+            audit: AuditContext::default(),
+        }))
+        .await
+        .unwrap()
+        .0
+}
+
+async fn get_users<D: DatabaseRepositories>(db: &D, pagination: Pagination, deleted: bool) -> GetUsersDtoResponse {
+    db.user_repository()
+        .get_users(GetUsersDtoRequest(GetUsersUseCaseRequest {
+            pagination,
+            sorts: Some(QuerySorts::from("+lastname")),
+            filters: None,
+            search: None,
+            deleted,
+        }))
+        .await
+        .unwrap()
+}
+
+/// User creation, soft deletion, restoration and search
+pub async fn users<D: DatabaseRepositories>(db: &D) {
+    let repository = db.user_repository();
+    let user = create_user(db, "john.doe@test.com", "Doe").await;
+    create_user(db, "jane.smith@test.com", "Smith").await;
+
+    // Emails are unique, whatever the case
+    let result = repository
+        .create_user(create_user_request("JOHN.doe@test.com", "Doe"))
+        .await;
+    assert!(matches!(result, Err(UserUseCaseError::DatabaseError(_))));
+
+    let found = repository
+        .get_user_by_email(GetUserByEmailDtoRequest(Email::new("John.Doe@test.com").unwrap()))
+        .await
+        .unwrap()
+        .0;
+    assert_eq!(found.id, user.id);
+
+    // Password change date, used to check the password expiration
+    let password_changed_at = || repository.get_password_changed_at(GetPasswordChangedAtDtoRequest(user.id.clone()));
+    assert!(password_changed_at().await.unwrap().0.is_some());
+
+    // Search
+    let search = |search: &str, pagination| {
+        repository.search_users(SearchUsersDtoRequest {
+            search: search.to_string(),
+            pagination,
+            filters: None,
+            deleted: false,
+        })
+    };
+    let response = search("doe", Pagination::new(1, 10)).await.unwrap();
+    assert_eq!(response.total, 1);
+    assert_eq!(response.users[0].id, user.id);
+    assert_eq!(search("John Doe", Pagination::new(1, 10)).await.unwrap().total, 1);
+    assert_eq!(search("unknown", Pagination::new(1, 10)).await.unwrap().total, 0);
+    assert_eq!(search("doe unknown", Pagination::new(1, 10)).await.unwrap().total, 0);
+
+    // Soft deletion
+    let delete = || {
+        repository.delete_user(DeleteUserDtoRequest {
+            user_id: user.id.clone(),
+            message: webhook_message(WebhookEvent::UserDeleted),
+        })
+    };
+    let restore = || {
+        repository.restore_user(RestoreUserDtoRequest {
+            user_id: user.id.clone(),
+            message: webhook_message(WebhookEvent::UserRestored),
+        })
+    };
+    assert!(restore().await.is_err());
+    assert!(delete().await.is_ok());
+    assert!(delete().await.is_err());
+    assert!(
+        repository
+            .get_user_by_id(GetUserByIdDtoRequest(GetUserUseCaseRequest {
+                user_id: user.id.clone()
+            }))
+            .await
+            .is_err()
+    );
+    assert!(matches!(
+        password_changed_at().await,
+        Err(UserUseCaseError::UserNotFound())
+    ));
+    assert_eq!(get_users(db, Pagination::default(), true).await.users.len(), 1);
+    assert_eq!(get_users(db, Pagination::default(), false).await.users.len(), 1);
+
+    assert!(restore().await.is_ok());
+    assert_eq!(get_users(db, Pagination::default(), false).await.users.len(), 2);
+}
+
+/// Offset and cursor pagination of the users
+pub async fn users_pagination<D: DatabaseRepositories>(db: &D) {
+    for (email, lastname) in [("a@test.com", "C"), ("b@test.com", "A"), ("c@test.com", "B")] {
+        create_user(db, email, lastname).await;
+    }
+    let lastnames = |response: &GetUsersDtoResponse| {
+        response
+            .users
+            .iter()
+            .map(|user| user.lastname.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let response = get_users(db, Pagination::new_cursor(None, 2), false).await;
+    assert_eq!(lastnames(&response), vec!["A", "B"]);
+    assert!(response.next_cursor.is_some());
+
+    let response = get_users(db, Pagination::new_cursor(response.next_cursor, 2), false).await;
+    assert_eq!(lastnames(&response), vec!["C"]);
+    assert!(response.next_cursor.is_none());
+
+    let response = get_users(db, Pagination::new(2, 2), false).await;
+    assert_eq!(lastnames(&response), vec!["C"]);
+}
+
+/// Invitation creation (with its email), acceptance and revocation
+pub async fn invitations<D: DatabaseRepositories>(db: &D) {
+    let repository = db.invitation_repository();
+    let create = || async {
+        let invitation = Invitation::new(Id::new().unwrap(), 24);
+        let response = repository
+            .create(CreateInvitationDtoRequest {
+                email: Email::new("invited@test.com").unwrap(),
+                lastname: "Doe".to_string(),
+                firstname: "Jane".to_string(),
+                scopes: vec![],
+                locale: None,
+                invitation: invitation.clone(),
+                message: EmailMessage::default(),
+            })
+            .await
+            .unwrap()
+            .0;
+
+        (response, invitation)
+    };
+    let revoke = |id| repository.revoke(RevokeInvitationDtoRequest(RevokeInvitationUseCaseRequest { id }));
+
+    // A revoked invitation frees the email
+    let (response, _) = create().await;
+    revoke(response.id).await.unwrap();
+
+    let (response, invitation) = create().await;
+    let emails = db
+        .email_repository()
+        .get_due_emails(GetDueEmailsDtoRequest { limit: 10 })
+        .await
+        .unwrap()
+        .0;
+    assert_eq!(emails.len(), 2);
+
+    let accept = || {
+        repository.accept(AcceptInvitationDtoRequest(AcceptInvitationUseCaseRequest {
+            token: invitation.token.clone(),
+            password: Password::new("$argon2id$hash", true).unwrap(),
+            audit: AuditContext::default(),
+        }))
+    };
+    assert_eq!(accept().await.unwrap().user_id, response.user_id);
+    assert_eq!(accept().await.err(), Some(InvitationUseCaseError::InvitationNotFound()));
+
+    // An activated user is not deleted by a revocation
+    assert_eq!(
+        revoke(response.id).await.err(),
+        Some(InvitationUseCaseError::InvitationNotFound())
+    );
+    assert!(
+        db.user_repository()
+            .get_user_by_id(GetUserByIdDtoRequest(GetUserUseCaseRequest {
+                user_id: response.user_id
+            }))
+            .await
+            .is_ok()
+    );
+}
+
+/// Refresh tokens are bound to a user and an application
+pub async fn refresh_tokens<D: DatabaseRepositories>(db: &D) {
+    let repository = db.refresh_token_repository();
+    let user = create_user(db, "john.doe@test.com", "Doe").await;
+    let application = create_application(db).await;
+    let create = || async {
+        let access_token = AccessToken::new("token".to_string(), UtcDateTime::now());
+        let refresh_token = RefreshToken::create(user.id.clone(), access_token.clone(), 7).unwrap();
+        repository
+            .create_refresh_token(CreateRefreshTokenDtoRequest {
+                refresh_token: refresh_token.clone(),
+                access_token,
+                user_id: user.id.clone(),
+                application_id: application.id.clone(),
+            })
+            .await
+            .unwrap();
+
+        refresh_token.refresh_token
+    };
+
+    let token = create().await;
+    let response = repository
+        .get_refresh_token(GetRefreshTokenDtoRequest(token.clone()))
+        .await
+        .unwrap();
+    assert_eq!(response.user_id, user.id);
+    assert_eq!(response.application_id, application.id);
+
+    repository
+        .delete_refresh_token(DeleteRefreshTokenDtoRequest(token.clone()))
+        .await
+        .unwrap();
+    assert_eq!(
+        repository
+            .get_refresh_token(GetRefreshTokenDtoRequest(token))
+            .await
+            .err(),
+        Some(UserUseCaseError::InvalidRefreshToken())
+    );
+
+    create().await;
+    create().await;
+    let response = repository
+        .delete_user_refresh_tokens(DeleteUserRefreshTokensDtoRequest {
+            user_id: user.id.clone(),
+            message: webhook_message(WebhookEvent::SessionsRevoked),
+        })
+        .await
+        .unwrap();
+    assert_eq!(response.deleted, 2);
+}
+
+/// Audit events are filtered by action
+pub async fn audit_events<D: DatabaseRepositories>(db: &D) {
+    let repository = db.audit_repository();
+    let context = AuditContext {
+        actor_id: None,
+        ip: Some("203.0.113.7".to_string()),
+        request_id: Some("request-id".to_string()),
+    };
+    for (action, target_id) in [
+        (AuditAction::LoginSucceeded, "user-1"),
+        (AuditAction::LoginFailed, "john.doe@test.com"),
+        (AuditAction::LoginSucceeded, "user-2"),
+    ] {
+        let event = AuditEvent::new(&context, action, target_id.to_string()).unwrap();
+        repository.create(CreateAuditEventDtoRequest(event)).await.unwrap();
+    }
+
+    let filters = AuditEventFilters {
+        action: Some(AuditAction::LoginSucceeded),
+        ..AuditEventFilters::default()
+    };
+    let response = repository
+        .get_audit_events(GetAuditEventsDtoRequest(GetAuditEventsUseCaseRequest {
+            pagination: Pagination::new(1, 10),
+            sorts: None,
+            filters: filters.clone(),
+        }))
+        .await
+        .unwrap();
+    let mut targets = response
+        .events
+        .iter()
+        .map(|event| event.target_id.as_str())
+        .collect::<Vec<_>>();
+    targets.sort();
+    assert_eq!(targets, vec!["user-1", "user-2"]);
+    assert_eq!(response.events[0].ip.as_deref(), Some("203.0.113.7"));
+    assert_eq!(response.events[0].request_id.as_deref(), Some("request-id"));
+
+    let total = repository
+        .count_audit_events(CountAuditEventsDtoRequest(filters))
+        .await
+        .unwrap()
+        .0;
+    assert_eq!(total, 2);
+}
+
+/// Login states and users of an external link provider
+pub async fn federation<D: DatabaseRepositories>(db: &D) {
+    let repository = db.federation_repository();
+    let user = create_user(db, "john.doe@test.com", "Doe").await;
+    let application = create_application(db).await;
+    let external_link = db
+        .external_link_repository()
+        .create(CreateExternalLinkDtoRequest(CreateExternalLinkUseCaseRequest {
+            name: "Partner".to_string(),
+            provider: Some(OidcProvider::new("https://idp.test.com", "client", "secret", vec![]).unwrap()),
+            audit: AuditContext::default(),
+        }))
+        .await
+        .unwrap()
+        .0;
+
+    // The provider is only available for the linked applications
+    let get_provider = || {
+        repository.get_provider(GetProviderDtoRequest {
+            external_link_id: external_link.id.clone(),
+            application_id: application.id.clone(),
+        })
+    };
+    assert!(get_provider().await.unwrap().0.is_none());
+    db.external_link_repository()
+        .link_application(LinkApplicationDtoRequest(LinkApplicationUseCaseRequest {
+            external_link_id: external_link.id.clone(),
+            application_id: application.id.clone(),
+            audit: AuditContext::default(),
+        }))
+        .await
+        .unwrap();
+    let provider = get_provider().await.unwrap().0.unwrap();
+    assert_eq!(provider.client_secret(), "secret");
+
+    // A login state can only be used once
+    let login_state = OidcLoginState::new(external_link.id.clone(), application.id.clone(), 10);
+    repository
+        .create_login_state(CreateLoginStateDtoRequest(login_state.clone()))
+        .await
+        .unwrap();
+    let consume = || {
+        repository.consume_login_state(ConsumeLoginStateDtoRequest {
+            state: login_state.state.clone(),
+        })
+    };
+    assert_eq!(
+        consume().await.unwrap().0.map(|state| state.nonce),
+        Some(login_state.nonce.clone())
+    );
+    assert!(consume().await.unwrap().0.is_none());
+
+    // An existing user is linked by a verified email, the other users are created
+    let find_or_create = |external_user_id: &str, email: Option<&str>| {
+        repository.find_or_create_user(FindOrCreateUserDtoRequest {
+            external_link_id: external_link.id.clone(),
+            external_user_id: external_user_id.to_string(),
+            email: email.map(|email| Email::new(email).unwrap()),
+            lastname: "Doe".to_string(),
+            firstname: "John".to_string(),
+            password: Password::new("$argon2id$hash", true).unwrap(),
+        })
+    };
+    assert_eq!(
+        find_or_create("partner-1", Some("john.doe@test.com")).await.unwrap().0,
+        user.id
+    );
+    assert_eq!(find_or_create("partner-1", None).await.unwrap().0, user.id);
+
+    let created = find_or_create("partner-2", Some("partner.user@test.com"))
+        .await
+        .unwrap()
+        .0;
+    assert_ne!(created, user.id);
+    assert_eq!(find_or_create("partner-2", None).await.unwrap().0, created);
+}
+
+/// Webhook list and deliveries queued with the user changes
+pub async fn webhooks<D: DatabaseRepositories>(db: &D) {
+    let repository = db.webhook_repository();
+    let application = create_application(db).await;
+    repository
+        .create_webhook(CreateWebhookDtoRequest {
+            webhook: CreateWebhookUseCaseRequest {
+                application_id: application.id.clone(),
+                url: "https://app.test.com/webhooks".to_string(),
+                events: vec![WebhookEvent::UserCreated, WebhookEvent::SessionsRevoked],
+            },
+            secret: WebhookSecret::generate(),
+        })
+        .await
+        .unwrap();
+
+    let total = repository
+        .count_webhooks(CountWebhooksDtoRequest {
+            application_id: application.id.clone(),
+        })
+        .await
+        .unwrap()
+        .0;
+    assert_eq!(total, 1);
+    let response = repository
+        .get_webhooks(GetWebhooksDtoRequest(GetWebhooksUseCaseRequest {
+            application_id: application.id.clone(),
+            pagination: Pagination::default(),
+            sorts: Some(QuerySorts::from("-created_at")),
+        }))
+        .await
+        .unwrap();
+    assert_eq!(response.webhooks.len(), 1);
+
+    // The deliveries are saved with the action which triggered the event
+    let due = || repository.get_due_deliveries(GetDueWebhookDeliveriesDtoRequest { limit: 10 });
+    let user = create_user(db, "john.doe@test.com", "Doe").await;
+    let deliveries = due().await.unwrap().0;
+    assert_eq!(deliveries.len(), 1);
+    assert_eq!(deliveries[0].event, WebhookEvent::UserCreated);
+
+    // Nothing is queued when the action fails or for an event without subscribed webhook
+    let result = db
+        .user_repository()
+        .create_user(create_user_request("john.doe@test.com", "Doe"))
+        .await;
+    assert!(result.is_err());
+    db.user_repository()
+        .delete_user(DeleteUserDtoRequest {
+            user_id: user.id.clone(),
+            message: webhook_message(WebhookEvent::UserDeleted),
+        })
+        .await
+        .unwrap();
+    assert!(due().await.unwrap().0.is_empty());
+
+    db.refresh_token_repository()
+        .delete_user_refresh_tokens(DeleteUserRefreshTokensDtoRequest {
+            user_id: user.id.clone(),
+            message: webhook_message(WebhookEvent::SessionsRevoked),
+        })
+        .await
+        .unwrap();
+    let deliveries = due().await.unwrap().0;
+    assert_eq!(deliveries.len(), 1);
+    assert_eq!(deliveries[0].event, WebhookEvent::SessionsRevoked);
+}
@@ -0,0 +1,669 @@
+//! SQLite adapter
+//!
+//! The queries are checked at runtime (`sqlx::query_as::<_, M>`): the offline data of the
+//! `query!` macros is generated for MySQL only.
+//!
+//! SQLite locks the whole database when writing, so there is no row lock (`FOR UPDATE`)
+//! and the transactions which write are started with `BEGIN IMMEDIATE`.
+//! It is meant for a single server (small deployments, tests).
+
+use crate::adapters::database::query::{Conditions, sql_comparison, valid_filters, valid_sorts};
+use crate::adapters::database::{DatabaseError, GenericDb};
+use crate::config::Config;
+use crate::domain::value_objects::pagination::{PAGINATION_MAX_LIMIT, Pagination, PaginationCursor};
+use crate::domain::value_objects::query_filter::{QueryFilterFieldValue, QueryFilters};
+use crate::domain::value_objects::query_sort::{QuerySortDirection, QuerySorts};
+use async_trait::async_trait;
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, SqliteRow};
+use sqlx::{FromRow, Pool, QueryBuilder, Row, Sqlite};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Statement starting the transactions which write (the database is locked at the beginning)
+pub const BEGIN_IMMEDIATE: &str = "BEGIN IMMEDIATE";
+
+pub mod repositories;
+
+/// SQLite database adapter
+#[derive(Debug, Clone)]
+pub struct Db {
+    pub pool: Arc<Pool<Sqlite>>,
+}
+
+#[async_trait]
+impl GenericDb for Db {
+    type Db = Db;
+
+    async fn new(config: &Config) -> Result<Self::Db, DatabaseError> {
+        let url = &config.database_url;
+        let max_connections = config.database_max_connections;
+        let min_connections = config.database_min_connections;
+        let max_lifetime = config.database_max_lifetime;
+        let connect_timeout = config.database_connect_timeout;
+        let idle_timeout = config.database_idle_timeout;
+
+        let options = SqliteConnectOptions::from_str(url)
+            .map_err(|err| DatabaseError::CreationPoolError(err.to_string()))?
+            .create_if_missing(true)
+            .journal_mode(SqliteJournalMode::Wal)
+            .busy_timeout(Duration::from_secs(connect_timeout));
+
+        // An in-memory database is dropped with its last connection, which must never be closed
+        let pool_options = match url.contains(":memory:") || url.contains("mode=memory") {
+            true => SqlitePoolOptions::new()
+                .min_connections(min_connections.max(1))
+                .max_lifetime(None)
+                .idle_timeout(None),
+            false => SqlitePoolOptions::new()
+                .min_connections(min_connections)
+                .max_lifetime(Some(Duration::from_secs(max_lifetime)))
+                .idle_timeout(Duration::from_secs(idle_timeout)),
+        };
+        let pool = pool_options
+            .max_connections(max_connections)
+            .acquire_timeout(Duration::from_secs(connect_timeout))
+            .test_before_acquire(true)
+            .connect_with(options)
+            .await
+            .map_err(|err| DatabaseError::CreationPoolError(err.to_string()))?;
+
+        if config.database_auto_migration {
+            info!("Run database migrations");
+            sqlx::migrate!("./migrations/sqlite")
+                .run(&pool)
+                .await
+                .map_err(|err| DatabaseError::AutoMigrationError(err.to_string()))?
+        }
+
+        Ok(Self { pool: Arc::new(pool) })
+    }
+}
+
+/// Pagination for SQLite queries
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqlitePagination {
+    pub page: u32,
+    pub limit: u32,
+    pub offset: u32,
+}
+
+impl From<Pagination> for SqlitePagination {
+    fn from(pagination: Pagination) -> Self {
+        // Page & limit
+        let page = match pagination.page() >= 1 {
+            true => pagination.page(),
+            false => 1,
+        };
+
+        let limit = match (1..=PAGINATION_MAX_LIMIT).contains(&pagination.limit()) {
+            true => pagination.limit(),
+            false => PAGINATION_MAX_LIMIT,
+        };
+
+        let offset = (page - 1) * limit;
+
+        Self { page, limit, offset }
+    }
+}
+
+impl SqlitePagination {
+    /// Push the `LIMIT` and `OFFSET` clauses with bound values
+    pub fn push_sql(&self, query: &mut QueryBuilder<'_, Sqlite>) {
+        query
+            .push(" LIMIT ")
+            .push_bind(self.limit)
+            .push(" OFFSET ")
+            .push_bind(self.offset);
+    }
+}
+
+/// `WHERE` clause of a Sqlite query
+pub type SqliteConditions<'q, 'args> = Conditions<'q, 'args, Sqlite>;
+
+/// Push an `ORDER BY` clause (nothing if there is no sort)
+///
+/// `NULL` values are sorted like MySQL does: first in ascending order and last in descending order.
+fn push_order_by<'s>(
+    query: &mut QueryBuilder<'_, Sqlite>,
+    sorts: impl IntoIterator<Item = (&'s str, &'s QuerySortDirection)>,
+) {
+    for (i, (column, direction)) in sorts.into_iter().enumerate() {
+        query
+            .push(match i {
+                0 => " ORDER BY ",
+                _ => ", ",
+            })
+            .push(column)
+            .push(match direction {
+                QuerySortDirection::Asc => " ASC",
+                QuerySortDirection::Desc => " DESC",
+            });
+    }
+}
+
+/// Query sorts for SQLite queries
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqliteQuerySorts(pub QuerySorts);
+
+impl SqliteQuerySorts {
+    /// Sorts of the valid fields, with the column of the valid field
+    ///
+    /// A valid field can be prefixed by its table (Ex.: `u.email` is used for the `email` sort).
+    fn valid<'a>(&'a self, valid_fields: &'a [&str]) -> impl Iterator<Item = (&'a str, &'a QuerySortDirection)> {
+        valid_sorts(&self.0, valid_fields)
+    }
+
+    /// Check if at least one sort is on a valid field
+    pub fn has_valid_fields(&self, valid_fields: &[&str]) -> bool {
+        self.valid(valid_fields).next().is_some()
+    }
+
+    /// Push the `ORDER BY` clause of the valid fields
+    ///
+    /// Only the columns of `valid_fields` are written in the query.
+    pub fn push_sql(&self, query: &mut QueryBuilder<'_, Sqlite>, valid_fields: &[&str]) {
+        push_order_by(query, self.valid(valid_fields));
+    }
+}
+
+/// Type of a filter field, which gives how the column is compared to the filter values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqliteFilterType {
+    /// Compared as text
+    Text,
+    /// Stored as ISO 8601 text: compared as julian day numbers (Ex.: `2024-01-01` is equal to `2024-01-01 00:00:00`)
+    Datetime,
+}
+
+impl SqliteFilterType {
+    /// Expression of a column or of a bound value of this type
+    fn push_expression(&self, query: &mut QueryBuilder<'_, Sqlite>, push: impl FnOnce(&mut QueryBuilder<'_, Sqlite>)) {
+        match self {
+            Self::Text => push(query),
+            Self::Datetime => {
+                query.push("julianday(");
+                push(query);
+                query.push(")");
+            }
+        }
+    }
+}
+
+/// Field available for filtering: the column and its type (Ex.: `("created_at", SqliteFilterType::Datetime)`)
+pub type SqliteFilterField<'a> = (&'a str, SqliteFilterType);
+
+/// Query filters for SQLite queries
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqliteQueryFilters(pub QueryFilters);
+
+impl SqliteQueryFilters {
+    /// Conditions of the valid fields, with the column and the type of the valid field
+    fn conditions<'a>(
+        &'a self,
+        valid_fields: &'a [SqliteFilterField<'a>],
+    ) -> impl Iterator<Item = (&'a SqliteFilterField<'a>, &'a QueryFilterFieldValue<String>)> {
+        valid_filters(&self.0, valid_fields, |(column, _)| column)
+    }
+
+    /// Add the conditions of the valid fields
+    ///
+    /// Only the columns of `valid_fields` are written in the query, the values are bound.
+    pub fn push_sql(&self, conditions: &mut SqliteConditions<'_, '_>, valid_fields: &[SqliteFilterField<'_>]) {
+        for ((column, filter_type), QueryFilterFieldValue { operator, values }) in self.conditions(valid_fields) {
+            let query = conditions.push();
+            filter_type.push_expression(query, |query| {
+                query.push(column);
+            });
+
+            query.push(sql_comparison(operator));
+
+            match operator.is_multiple() {
+                true => {
+                    query.push("(");
+                    for (i, value) in values.iter().enumerate() {
+                        if i > 0 {
+                            query.push(", ");
+                        }
+                        filter_type.push_expression(query, |query| {
+                            query.push_bind(value.clone());
+                        });
+                    }
+                    query.push(")");
+                }
+                false => {
+                    filter_type.push_expression(query, |query| {
+                        query.push_bind(values.first().cloned().unwrap_or_default());
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Row of a list query: the model, the sort key and the ID used to build the next cursor
+///
+/// The query must select the columns of [`SqliteCursorPagination::push_select`].
+#[derive(Debug, Clone)]
+pub struct SqliteCursorRow<M> {
+    pub model: M,
+    pub cursor_value: Option<String>,
+    pub cursor_id: String,
+}
+
+impl<'r, M: FromRow<'r, SqliteRow>> FromRow<'r, SqliteRow> for SqliteCursorRow<M> {
+    fn from_row(row: &'r SqliteRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            model: M::from_row(row)?,
+            cursor_value: row.try_get("cursor_value")?,
+            cursor_id: row.try_get("cursor_id")?,
+        })
+    }
+}
+
+/// Pagination of list queries, in offset mode (`LIMIT/OFFSET`) or in cursor mode (keyset)
+///
+/// In cursor mode, the rows are sorted by the first valid sort field then by ID,
+/// and the page starts after the sort key and the ID of the cursor.
+/// The sort key is compared as text because the cursor value is bound as a text parameter.
+/// One more row than the limit is fetched to know if there is a next page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqliteCursorPagination {
+    /// Valid sorts, with the columns of the valid fields
+    sorts: Vec<(String, QuerySortDirection)>,
+    id_column: String,
+    cursor: Option<PaginationCursor>,
+    limit: u32,
+
+    /// `LIMIT/OFFSET` in offset mode (`None` in cursor mode)
+    offset: Option<SqlitePagination>,
+}
+
+impl SqliteCursorPagination {
+    /// Create a new pagination
+    ///
+    /// A valid field can be prefixed by its table (Ex.: `u.email` is used for the `email` sort).
+    pub fn new(pagination: Pagination, sorts: &SqliteQuerySorts, valid_fields: &[&str], id_column: &str) -> Self {
+        let sorts = sorts
+            .valid(valid_fields)
+            .map(|(column, direction)| (column.to_string(), direction.clone()))
+            .collect();
+        let keyset = pagination.is_cursor();
+        let cursor = pagination.cursor().cloned();
+        let sqlite_pagination = SqlitePagination::from(pagination);
+
+        Self {
+            sorts,
+            id_column: id_column.to_string(),
+            cursor,
+            limit: sqlite_pagination.limit,
+            offset: match keyset {
+                true => None,
+                false => Some(sqlite_pagination),
+            },
+        }
+    }
+
+    /// Sort key expression and direction in cursor mode (`None` to sort by ID only)
+    fn sort(&self) -> Option<(String, QuerySortDirection)> {
+        self.sorts
+            .first()
+            .filter(|_| self.offset.is_none())
+            .map(|(column, direction)| (format!("CAST({column} AS TEXT)"), direction.clone()))
+    }
+
+    /// Sort direction (by ID only: ascending)
+    fn direction(&self) -> QuerySortDirection {
+        self.sort().map(|(_, direction)| direction).unwrap_or_default()
+    }
+
+    /// Push the sort key and ID columns, to add to the selected columns
+    pub fn push_select(&self, query: &mut QueryBuilder<'_, Sqlite>) {
+        match self.sort() {
+            Some((key, _)) => query.push(", ").push(key),
+            None => query.push(", CAST(NULL AS TEXT)"),
+        };
+        query
+            .push(" AS cursor_value, ")
+            .push(&self.id_column)
+            .push(" AS cursor_id");
+    }
+
+    /// Add the condition of the rows after the cursor (nothing in offset mode or for the first page)
+    ///
+    /// `NULL` values are sorted first in ascending order and last in descending order.
+    pub fn push_condition(&self, conditions: &mut SqliteConditions<'_, '_>) {
+        let Some(cursor) = self.cursor.as_ref().filter(|_| self.offset.is_none()) else {
+            return;
+        };
+        let id = &self.id_column;
+        let cursor_id = cursor.id().to_string();
+        let query = conditions.push();
+
+        match (self.sort(), cursor.value(), self.direction()) {
+            (None, _, _) => {
+                query.push(id).push(" > ").push_bind(cursor_id);
+            }
+            (Some((key, _)), Some(value), QuerySortDirection::Asc) => {
+                query
+                    .push(format_args!("({key} > "))
+                    .push_bind(value.to_string())
+                    .push(format_args!(" OR ({key} = "))
+                    .push_bind(value.to_string())
+                    .push(format_args!(" AND {id} > "))
+                    .push_bind(cursor_id)
+                    .push("))");
+            }
+            (Some((key, _)), Some(value), QuerySortDirection::Desc) => {
+                query
+                    .push(format_args!("({key} < "))
+                    .push_bind(value.to_string())
+                    .push(format_args!(" OR {key} IS NULL OR ({key} = "))
+                    .push_bind(value.to_string())
+                    .push(format_args!(" AND {id} < "))
+                    .push_bind(cursor_id)
+                    .push("))");
+            }
+            (Some((key, _)), None, QuerySortDirection::Asc) => {
+                query
+                    .push(format_args!("({key} IS NOT NULL OR {id} > "))
+                    .push_bind(cursor_id)
+                    .push(")");
+            }
+            (Some((key, _)), None, QuerySortDirection::Desc) => {
+                query
+                    .push(format_args!("({key} IS NULL AND {id} < "))
+                    .push_bind(cursor_id)
+                    .push(")");
+            }
+        }
+    }
+
+    /// Push the sorts and the pagination (`ORDER BY` and `LIMIT`)
+    pub fn push_order(&self, query: &mut QueryBuilder<'_, Sqlite>) {
+        match &self.offset {
+            Some(pagination) => {
+                push_order_by(
+                    query,
+                    self.sorts
+                        .iter()
+                        .map(|(column, direction)| (column.as_str(), direction)),
+                );
+                pagination.push_sql(query);
+            }
+            None => {
+                let direction = self.direction();
+                let sort = self.sort();
+                push_order_by(
+                    query,
+                    sort.as_ref()
+                        .map(|(key, _)| (key.as_str(), &direction))
+                        .into_iter()
+                        .chain([(self.id_column.as_str(), &direction)]),
+                );
+                query.push(" LIMIT ").push_bind(self.limit + 1);
+            }
+        }
+    }
+
+    /// Page models and the cursor of the next page (only in cursor mode, if there is a next page)
+    pub fn page<M>(&self, mut rows: Vec<SqliteCursorRow<M>>) -> (Vec<M>, Option<PaginationCursor>) {
+        let limit = self.limit as usize;
+        let next_cursor = match self.offset.is_none() && rows.len() > limit {
+            true => {
+                rows.truncate(limit);
+                rows.last()
+                    .map(|row| PaginationCursor::new(row.cursor_value.clone(), row.cursor_id.clone()))
+            }
+            false => None,
+        };
+
+        (rows.into_iter().map(|row| row.model).collect(), next_cursor)
+    }
+}
+
+/// Search for SQLite queries (`LIKE` on the searched columns)
+///
+/// SQLite has no full-text index without the FTS5 virtual tables, so the words are searched
+/// in the columns with `LIKE` (case-insensitive for ASCII characters) and the rows are ranked
+/// by the number of matching words.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqliteFullTextSearch(pub String);
+
+impl SqliteFullTextSearch {
+    /// Generate the `LIKE` patterns of the search
+    ///
+    /// The search is split into words on the characters which are not part of a word,
+    /// and each word can be found anywhere in a column so that partial names or emails match.
+    /// The `_` wildcard is escaped with `\`. A row matches if it contains all the words.
+    /// Returns `None` if there is no word to search.
+    pub fn to_like_patterns(&self) -> Option<Vec<String>> {
+        let patterns = self
+            .0
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .filter(|word| !word.is_empty())
+            .map(|word| format!("%{}%", word.replace('_', "\\_")))
+            .collect::<Vec<_>>();
+
+        match patterns.is_empty() {
+            true => None,
+            false => Some(patterns),
+        }
+    }
+
+    /// Push the condition of one pattern: the pattern is found in one of the columns
+    fn push_pattern(query: &mut QueryBuilder<'_, Sqlite>, columns: &[&str], pattern: &str) {
+        query.push("(");
+        for (i, column) in columns.iter().enumerate() {
+            if i > 0 {
+                query.push(" OR ");
+            }
+            query
+                .push(column)
+                .push(" LIKE ")
+                .push_bind(pattern.to_string())
+                .push(" ESCAPE '\\'");
+        }
+        query.push(")");
+    }
+
+    /// Push the search condition on the given columns, with the patterns bound
+    pub fn push_sql(query: &mut QueryBuilder<'_, Sqlite>, columns: &[&str], patterns: &[String]) {
+        query.push("(");
+        for (i, pattern) in patterns.iter().enumerate() {
+            if i > 0 {
+                query.push(" AND ");
+            }
+            Self::push_pattern(query, columns, pattern);
+        }
+        query.push(")");
+    }
+
+    /// Push the rank of the search on the given columns (to sort by relevance)
+    pub fn push_rank(query: &mut QueryBuilder<'_, Sqlite>, columns: &[&str], patterns: &[String]) {
+        query.push("(");
+        for (i, pattern) in patterns.iter().enumerate() {
+            if i > 0 {
+                query.push(" + ");
+            }
+            query.push("CASE WHEN ");
+            Self::push_pattern(query, columns, pattern);
+            query.push(" THEN 1 ELSE 0 END");
+        }
+        query.push(")");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::query_filter::QueryFilterError;
+    use std::collections::HashMap;
+
+    /// SQL code pushed by `push` on an empty query
+    fn sql(push: impl FnOnce(&mut QueryBuilder<'_, Sqlite>)) -> String {
+        let mut query = QueryBuilder::new("");
+        push(&mut query);
+
+        query.sql().to_string()
+    }
+
+    /// SQL code of the conditions added by `push` on an empty query
+    fn conditions_sql(push: impl FnOnce(&mut SqliteConditions<'_, '_>)) -> String {
+        sql(|query| push(&mut SqliteConditions::new(query)))
+    }
+
+    #[test]
+    fn test_sqlite_pagination_push_sql() {
+        let pagination = SqlitePagination::from(Pagination::new(3, 1_000));
+        assert_eq!(pagination.limit, PAGINATION_MAX_LIMIT);
+        assert_eq!(pagination.offset, 2 * PAGINATION_MAX_LIMIT);
+        assert_eq!(" LIMIT ? OFFSET ?".to_owned(), sql(|query| pagination.push_sql(query)));
+    }
+
+    #[test]
+    fn test_sqlite_query_sorts_push_sql() {
+        let sorts = SqliteQuerySorts(QuerySorts::from("+id,-name,+unknown"));
+        assert_eq!(
+            " ORDER BY u.id ASC, name DESC".to_owned(),
+            sql(|query| sorts.push_sql(query, &["u.id", "name"]))
+        );
+        assert!(sorts.has_valid_fields(&["name"]));
+        assert!(!sorts.has_valid_fields(&["email"]));
+        assert_eq!(String::new(), sql(|query| sorts.push_sql(query, &[])));
+    }
+
+    fn filters(params: &[(&str, &str)]) -> Result<SqliteQueryFilters, QueryFilterError> {
+        let params = params
+            .iter()
+            .map(|(field, values)| (field.to_string(), values.to_string()))
+            .collect::<HashMap<_, _>>();
+
+        Ok(SqliteQueryFilters(QueryFilters::try_from(params)?))
+    }
+
+    #[test]
+    fn test_sqlite_query_filters_push_sql() {
+        let sqlite_filters = filters(&[
+            ("name", "in:foo,bar"),
+            ("created_at", "gte:2024-01-01"),
+            ("password", "eq:secret"),
+        ])
+        .unwrap();
+        assert_eq!(
+            " WHERE deleted_at IS NULL AND julianday(created_at) >= julianday(?) AND name IN (?, ?)".to_owned(),
+            conditions_sql(|conditions| {
+                conditions.push_soft_delete("deleted_at", false);
+                sqlite_filters.push_sql(
+                    conditions,
+                    &[
+                        ("name", SqliteFilterType::Text),
+                        ("created_at", SqliteFilterType::Datetime),
+                    ],
+                );
+            })
+        );
+    }
+
+    #[test]
+    fn test_sqlite_full_text_search() {
+        assert_eq!(SqliteFullTextSearch("  +-@* ".to_string()).to_like_patterns(), None);
+        assert_eq!(
+            SqliteFullTextSearch("john_doe -Smith".to_string()).to_like_patterns(),
+            Some(vec!["%john\\_doe%".to_string(), "%Smith%".to_string()])
+        );
+
+        let patterns = vec!["%john%".to_string(), "%doe%".to_string()];
+        assert_eq!(
+            "((email LIKE ? ESCAPE '\\' OR lastname LIKE ? ESCAPE '\\') AND (email LIKE ? ESCAPE '\\' OR lastname LIKE ? ESCAPE '\\'))"
+                .to_owned(),
+            sql(|query| SqliteFullTextSearch::push_sql(query, &["email", "lastname"], &patterns))
+        );
+        assert_eq!(
+            "(CASE WHEN (email LIKE ? ESCAPE '\\') THEN 1 ELSE 0 END + CASE WHEN (email LIKE ? ESCAPE '\\') THEN 1 ELSE 0 END)"
+                .to_owned(),
+            sql(|query| SqliteFullTextSearch::push_rank(query, &["email"], &patterns))
+        );
+    }
+
+    #[test]
+    fn test_sqlite_cursor_pagination_offset_mode() {
+        let sorts = SqliteQuerySorts(QuerySorts::from("-email"));
+        let pagination = SqliteCursorPagination::new(Pagination::new(3, 10), &sorts, &["u.email"], "i.id");
+
+        assert_eq!(
+            ", CAST(NULL AS TEXT) AS cursor_value, i.id AS cursor_id".to_owned(),
+            sql(|query| pagination.push_select(query))
+        );
+        assert_eq!(
+            String::new(),
+            conditions_sql(|conditions| pagination.push_condition(conditions))
+        );
+        assert_eq!(
+            " ORDER BY u.email DESC LIMIT ? OFFSET ?".to_owned(),
+            sql(|query| pagination.push_order(query))
+        );
+    }
+
+    #[test]
+    fn test_sqlite_cursor_pagination_cursor_mode() {
+        let cursor = Some(PaginationCursor::new(Some("Doe".to_string()), "42".to_string()));
+        let sorts = SqliteQuerySorts(QuerySorts::from("-lastname"));
+        let pagination = SqliteCursorPagination::new(Pagination::new_cursor(cursor, 10), &sorts, &["lastname"], "id");
+
+        assert_eq!(
+            ", CAST(lastname AS TEXT) AS cursor_value, id AS cursor_id".to_owned(),
+            sql(|query| pagination.push_select(query))
+        );
+        assert_eq!(
+            " WHERE (CAST(lastname AS TEXT) < ? OR CAST(lastname AS TEXT) IS NULL OR (CAST(lastname AS TEXT) = ? AND id < ?))"
+                .to_owned(),
+            conditions_sql(|conditions| pagination.push_condition(conditions))
+        );
+        assert_eq!(
+            " ORDER BY CAST(lastname AS TEXT) DESC, id DESC LIMIT ?".to_owned(),
+            sql(|query| pagination.push_order(query))
+        );
+
+        let rows = ["Doe", "Smith"]
+            .into_iter()
+            .enumerate()
+            .map(|(i, lastname)| SqliteCursorRow {
+                model: lastname,
+                cursor_value: Some(lastname.to_string()),
+                cursor_id: i.to_string(),
+            })
+            .collect::<Vec<_>>();
+        let pagination = SqliteCursorPagination::new(Pagination::new_cursor(None, 1), &sorts, &["lastname"], "id");
+        assert_eq!(
+            pagination.page(rows),
+            (
+                vec!["Doe"],
+                Some(PaginationCursor::new(Some("Doe".to_string()), "0".to_string()))
+            )
+        );
+    }
+
+    /// Scenarios shared by the database adapters, run on a new in-memory database
+    mod repositories {
+        use super::{Config, Db, GenericDb};
+
+        /// Migrated in-memory database (each pool has its own database)
+        async fn db() -> Db {
+            let config = Config {
+                database_url: "sqlite::memory:".to_string(),
+                database_auto_migration: true,
+                database_max_connections: 2,
+                database_min_connections: 1,
+                database_max_lifetime: 60,
+                database_connect_timeout: 10,
+                database_idle_timeout: 60,
+                ..Config::default()
+            };
+
+            Db::new(&config).await.unwrap()
+        }
+
+        repository_tests!(db());
+    }
+}
@@ -0,0 +1,388 @@
+//! Client SQLite repository
+
+use crate::adapters::database::model::client::{ClientModel, ClientSecretModel};
+use crate::adapters::database::sqlite::{
+    BEGIN_IMMEDIATE, Db, SqliteConditions, SqliteCursorPagination, SqliteCursorRow, SqliteQuerySorts,
+};
+use crate::domain::repositories::client::ClientRepository;
+use crate::domain::repositories::client::dto::{
+    CountClientsDtoRequest, CountClientsDtoResponse, CreateClientDtoRequest, CreateClientDtoResponse,
+    CreateClientSecretDtoRequest, CreateClientSecretDtoResponse, DeleteClientDtoRequest, DeleteClientDtoResponse,
+    DeleteClientSecretDtoRequest, DeleteClientSecretDtoResponse, GetClientByIdDtoRequest, GetClientByIdDtoResponse,
+    GetClientSecretsDtoRequest, GetClientSecretsDtoResponse, GetClientsDtoRequest, GetClientsDtoResponse,
+    UpdateClientDtoRequest, UpdateClientDtoResponse,
+};
+use crate::domain::use_cases::application::delete_client::DeleteClientUseCaseResponse;
+use crate::domain::use_cases::application::delete_client_secret::DeleteClientSecretUseCaseResponse;
+use crate::domain::use_cases::application::update_client::UpdateClientUseCaseResponse;
+use crate::domain::use_cases::application::{
+    ApplicationUseCaseError, ClientSecretUseCaseResponse, ClientUseCaseResponse,
+};
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use async_trait::async_trait;
+use sqlx::{QueryBuilder, Row};
+use std::sync::Arc;
+
+/// Client SQLite repository
+#[derive(Debug, Clone)]
+pub struct ClientSqliteRepository {
+    db: Arc<Db>,
+}
+
+impl ClientSqliteRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl ClientRepository for ClientSqliteRepository {
+    #[instrument(skip(self, req), name = "client_repository_create")]
+    async fn create_client(
+        &self,
+        req: CreateClientDtoRequest,
+    ) -> Result<CreateClientDtoResponse, ApplicationUseCaseError> {
+        let client_id = Id::new().map_err(|err| {
+            error!(error = %err, "Failed to create client ID");
+            ApplicationUseCaseError::InvalidId()
+        })?;
+        let secret_id = Id::new().map_err(|err| {
+            error!(error = %err, "Failed to create client secret ID");
+            ApplicationUseCaseError::InvalidId()
+        })?;
+        let now = UtcDateTime::now();
+
+        let mut tx = self.db.pool.begin_with(BEGIN_IMMEDIATE).await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            ApplicationUseCaseError::DatabaseError("Client creation error".to_string())
+        })?;
+
+        // The client is only created if the application exists
+        let result = sqlx::query(
+            "
+            INSERT INTO clients (id, application_id, name, created_at, updated_at, deleted_at)
+            SELECT $1, id, $2, $3, $4, NULL
+            FROM applications
+            WHERE id = $5
+                AND deleted_at IS NULL
+        ",
+        )
+        .bind(client_id.to_string())
+        .bind(&req.client.name)
+        .bind(now.value())
+        .bind(now.value())
+        .bind(req.client.application_id.to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to create client");
+            ApplicationUseCaseError::DatabaseError("Client creation error".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(ApplicationUseCaseError::ApplicationNotFound());
+        }
+
+        // Add first secret
+        sqlx::query(
+            "
+            INSERT INTO client_secrets (id, client_id, secret, created_at)
+            VALUES ($1, $2, $3, $4)
+        ",
+        )
+        .bind(secret_id.to_string())
+        .bind(client_id.to_string())
+        .bind(req.secret.to_string())
+        .bind(now.value())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to create client secret");
+            ApplicationUseCaseError::DatabaseError("Client creation error".to_string())
+        })?;
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            ApplicationUseCaseError::DatabaseError("Client creation error".to_string())
+        })?;
+
+        Ok(CreateClientDtoResponse(ClientUseCaseResponse {
+            id: client_id,
+            application_id: req.client.application_id,
+            name: req.client.name,
+            created_at: now.clone(),
+            updated_at: now,
+            deleted_at: None,
+        }))
+    }
+
+    #[instrument(skip(self), name = "client_repository_count")]
+    async fn count_clients(
+        &self,
+        req: CountClientsDtoRequest,
+    ) -> Result<CountClientsDtoResponse, ApplicationUseCaseError> {
+        let query = "SELECT COUNT(*) AS total FROM clients WHERE application_id = $1 AND deleted_at IS NULL";
+
+        let result = sqlx::query(query)
+            .bind(req.application_id.to_string())
+            .fetch_one(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to count clients");
+                ApplicationUseCaseError::DatabaseError("Failed to count clients".to_string())
+            })?;
+
+        Ok(CountClientsDtoResponse(result.try_get("total")?))
+    }
+
+    #[instrument(skip(self), name = "client_repository_get_all")]
+    async fn get_clients(&self, req: GetClientsDtoRequest) -> Result<GetClientsDtoResponse, ApplicationUseCaseError> {
+        // Sorts & pagination
+        let sorts = SqliteQuerySorts(req.0.sorts.unwrap_or_default());
+        let pagination =
+            SqliteCursorPagination::new(req.0.pagination, &sorts, &["name", "created_at", "updated_at"], "id");
+
+        let mut query = QueryBuilder::new("SELECT id, application_id, name, created_at, updated_at, deleted_at");
+        pagination.push_select(&mut query);
+        query.push(" FROM clients");
+
+        let mut conditions = SqliteConditions::new(&mut query);
+        conditions
+            .push()
+            .push("application_id = ")
+            .push_bind(req.0.application_id.to_string());
+        conditions.push_soft_delete("deleted_at", false);
+        pagination.push_condition(&mut conditions);
+
+        pagination.push_order(&mut query);
+
+        let rows = query
+            .build_query_as::<SqliteCursorRow<ClientModel>>()
+            .fetch_all(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to get clients");
+                ApplicationUseCaseError::DatabaseError("Failed to get clients".to_string())
+            })?;
+        let (clients, next_cursor) = pagination.page(rows);
+
+        let clients = clients
+            .into_iter()
+            .map(ClientUseCaseResponse::try_from)
+            .collect::<Result<Vec<ClientUseCaseResponse>, _>>()
+            .map_err(|err| {
+                error!(error = %err, "Failed to convert client model to client use case response");
+                ApplicationUseCaseError::FromModelError()
+            })?;
+
+        Ok(GetClientsDtoResponse { clients, next_cursor })
+    }
+
+    #[instrument(skip(self), name = "client_repository_get_by_id")]
+    async fn get_client_by_id(
+        &self,
+        req: GetClientByIdDtoRequest,
+    ) -> Result<GetClientByIdDtoResponse, ApplicationUseCaseError> {
+        let result = sqlx::query_as::<_, ClientModel>(
+            "
+            SELECT id, application_id, name, created_at, updated_at, deleted_at
+            FROM clients
+            WHERE id = $1
+                AND application_id = $2
+                AND deleted_at IS NULL",
+        )
+        .bind(req.0.id.to_string())
+        .bind(req.0.application_id.to_string())
+        .fetch_optional(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get client");
+            ApplicationUseCaseError::DatabaseError("Failed to get client".to_string())
+        })?;
+
+        let client = match result {
+            Some(row) => row.try_into().map_err(|err| {
+                error!(error = %err, "Failed to convert client model to client use case response");
+                ApplicationUseCaseError::FromModelError()
+            })?,
+            None => Err(ApplicationUseCaseError::ClientNotFound())?,
+        };
+
+        Ok(GetClientByIdDtoResponse(client))
+    }
+
+    #[instrument(skip(self), name = "client_repository_update")]
+    async fn update_client(
+        &self,
+        req: UpdateClientDtoRequest,
+    ) -> Result<UpdateClientDtoResponse, ApplicationUseCaseError> {
+        let result = sqlx::query(
+            "
+            UPDATE clients
+            SET name = $1, updated_at = $2
+            WHERE id = $3
+                AND application_id = $4
+                AND deleted_at IS NULL",
+        )
+        .bind(&req.0.name)
+        .bind(UtcDateTime::now().value())
+        .bind(req.0.id.to_string())
+        .bind(req.0.application_id.to_string())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to update client");
+            ApplicationUseCaseError::DatabaseError("Failed to update client".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(ApplicationUseCaseError::ClientNotFound())?;
+        }
+
+        Ok(UpdateClientDtoResponse(UpdateClientUseCaseResponse()))
+    }
+
+    #[instrument(skip(self), name = "client_repository_delete")]
+    async fn delete_client(
+        &self,
+        req: DeleteClientDtoRequest,
+    ) -> Result<DeleteClientDtoResponse, ApplicationUseCaseError> {
+        let result = sqlx::query(
+            "
+            UPDATE clients
+            SET deleted_at = $1
+            WHERE id = $2
+                AND application_id = $3
+                AND deleted_at IS NULL",
+        )
+        .bind(Some(UtcDateTime::now().value()))
+        .bind(req.0.id.to_string())
+        .bind(req.0.application_id.to_string())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to delete client");
+            ApplicationUseCaseError::DatabaseError("Failed to delete client".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(ApplicationUseCaseError::ClientNotFound())?;
+        }
+
+        Ok(DeleteClientDtoResponse(DeleteClientUseCaseResponse()))
+    }
+
+    #[instrument(skip(self), name = "client_repository_get_secrets")]
+    async fn get_client_secrets(
+        &self,
+        req: GetClientSecretsDtoRequest,
+    ) -> Result<GetClientSecretsDtoResponse, ApplicationUseCaseError> {
+        let secrets = sqlx::query_as::<_, ClientSecretModel>(
+            "
+            SELECT cs.id, cs.client_id, cs.created_at
+            FROM client_secrets cs
+                INNER JOIN clients c ON c.id = cs.client_id AND c.deleted_at IS NULL
+            WHERE cs.client_id = $1
+                AND c.application_id = $2
+            ORDER BY cs.created_at",
+        )
+        .bind(req.0.client_id.to_string())
+        .bind(req.0.application_id.to_string())
+        .fetch_all(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get client secrets");
+            ApplicationUseCaseError::DatabaseError("Failed to get client secrets".to_string())
+        })?
+        .into_iter()
+        .map(ClientSecretUseCaseResponse::try_from)
+        .collect::<Result<Vec<ClientSecretUseCaseResponse>, _>>()
+        .map_err(|err| {
+            error!(error = %err, "Failed to convert client secret model to client secret use case response");
+            ApplicationUseCaseError::FromModelError()
+        })?;
+
+        // A client always has at least one secret
+        if secrets.is_empty() {
+            return Err(ApplicationUseCaseError::ClientNotFound());
+        }
+
+        Ok(GetClientSecretsDtoResponse(secrets))
+    }
+
+    #[instrument(skip(self, req), name = "client_repository_create_secret")]
+    async fn create_client_secret(
+        &self,
+        req: CreateClientSecretDtoRequest,
+    ) -> Result<CreateClientSecretDtoResponse, ApplicationUseCaseError> {
+        let secret_id = Id::new().map_err(|err| {
+            error!(error = %err, "Failed to create client secret ID");
+            ApplicationUseCaseError::InvalidId()
+        })?;
+        let now = UtcDateTime::now();
+
+        // The secret is only created if the client exists
+        let result = sqlx::query(
+            "
+            INSERT INTO client_secrets (id, client_id, secret, created_at)
+            SELECT $1, id, $2, $3
+            FROM clients
+            WHERE id = $4
+                AND application_id = $5
+                AND deleted_at IS NULL
+        ",
+        )
+        .bind(secret_id.to_string())
+        .bind(req.secret.to_string())
+        .bind(now.value())
+        .bind(req.client_id.to_string())
+        .bind(req.application_id.to_string())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to create client secret");
+            ApplicationUseCaseError::DatabaseError("Client secret creation error".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(ApplicationUseCaseError::ClientNotFound());
+        }
+
+        Ok(CreateClientSecretDtoResponse(ClientSecretUseCaseResponse {
+            id: secret_id,
+            client_id: req.client_id,
+            created_at: now,
+        }))
+    }
+
+    #[instrument(skip(self), name = "client_repository_delete_secret")]
+    async fn delete_client_secret(
+        &self,
+        req: DeleteClientSecretDtoRequest,
+    ) -> Result<DeleteClientSecretDtoResponse, ApplicationUseCaseError> {
+        let result = sqlx::query(
+            "
+            DELETE FROM client_secrets
+            WHERE id = $1
+                AND client_id = $2
+                AND client_id IN (SELECT id FROM clients WHERE application_id = $3 AND deleted_at IS NULL)",
+        )
+        .bind(req.0.id.to_string())
+        .bind(req.0.client_id.to_string())
+        .bind(req.0.application_id.to_string())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to delete client secret");
+            ApplicationUseCaseError::DatabaseError("Failed to delete client secret".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(ApplicationUseCaseError::ClientSecretNotFound())?;
+        }
+
+        Ok(DeleteClientSecretDtoResponse(DeleteClientSecretUseCaseResponse()))
+    }
+}
@@ -0,0 +1,418 @@
+//! Invitation SQLite repository
+
+use crate::adapters::database::model::invitation::InvitationModel;
+use crate::adapters::database::sqlite::repositories::email::enqueue_email;
+use crate::adapters::database::sqlite::{
+    BEGIN_IMMEDIATE, Db, SqliteConditions, SqliteCursorPagination, SqliteCursorRow, SqliteQuerySorts,
+};
+use crate::domain::entities::user::UserId;
+use crate::domain::repositories::invitation::InvitationRepository;
+use crate::domain::repositories::invitation::dto::{
+    AcceptInvitationDtoRequest, AcceptInvitationDtoResponse, CountInvitationsDtoRequest, CountInvitationsDtoResponse,
+    CreateInvitationDtoRequest, CreateInvitationDtoResponse, GetInvitationByIdDtoRequest, GetInvitationByIdDtoResponse,
+    GetInvitationsDtoRequest, GetInvitationsDtoResponse, RenewInvitationDtoRequest, RenewInvitationDtoResponse,
+    RevokeInvitationDtoRequest, RevokeInvitationDtoResponse,
+};
+use crate::domain::use_cases::invitation::revoke_invitation::RevokeInvitationUseCaseResponse;
+use crate::domain::use_cases::invitation::{InvitationUseCaseError, InvitationUseCaseResponse};
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use async_trait::async_trait;
+use sqlx::{QueryBuilder, Row};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Invitation SQLite repository
+#[derive(Debug, Clone)]
+pub struct InvitationSqliteRepository {
+    db: Arc<Db>,
+}
+
+impl InvitationSqliteRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl InvitationRepository for InvitationSqliteRepository {
+    #[instrument(skip(self, req), name = "invitation_repository_create")]
+    async fn create(
+        &self,
+        req: CreateInvitationDtoRequest,
+    ) -> Result<CreateInvitationDtoResponse, InvitationUseCaseError> {
+        let invitation_id = Id::new().map_err(|err| {
+            error!(error = %err, "Failed to create invitation ID");
+            InvitationUseCaseError::InvalidId()
+        })?;
+        let user_id = req.invitation.user_id.clone();
+        let now = UtcDateTime::now();
+
+        let mut tx = self.db.pool.begin_with(BEGIN_IMMEDIATE).await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            InvitationUseCaseError::DatabaseError("Invitation creation error".to_string())
+        })?;
+
+        // Create pending user (without password)
+        sqlx::query("
+            INSERT INTO users (id, email, password, password_changed_at, lastname, firstname, locale, created_at, updated_at, deleted_at)
+            VALUES ($1, $2, NULL, $3, $4, $5, $6, $7, $8, NULL)
+        ")
+        .bind(user_id.to_string())
+        .bind(req.email.to_string())
+        .bind(now.value())
+        .bind(&req.lastname)
+        .bind(&req.firstname)
+        .bind(req.locale.as_ref().map(|locale| locale.to_string()))
+        .bind(now.value())
+        .bind(now.value())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to create pending user");
+            InvitationUseCaseError::DatabaseError("Invitation creation error".to_string())
+        })?;
+
+        // Add user scopes
+        for scope in &req.scopes {
+            sqlx::query(
+                "
+                INSERT INTO users_scopes (user_id, scope_id, created_at)
+                VALUES ($1, $2, $3)
+            ",
+            )
+            .bind(user_id.to_string())
+            .bind(scope.to_string())
+            .bind(now.value())
+            .execute(&mut *tx)
+            .await
+            .map_err(|err| {
+                error!(error = %err, scope = %scope, "Failed to add scope to pending user");
+                InvitationUseCaseError::DatabaseError("Invitation creation error".to_string())
+            })?;
+        }
+
+        // Create invitation
+        sqlx::query(
+            "
+            INSERT INTO invitations (id, user_id, token, expired_at, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+        ",
+        )
+        .bind(invitation_id.to_string())
+        .bind(user_id.to_string())
+        .bind(req.invitation.token)
+        .bind(req.invitation.expired_at.value())
+        .bind(now.value())
+        .bind(now.value())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to create invitation");
+            InvitationUseCaseError::DatabaseError("Invitation creation error".to_string())
+        })?;
+
+        enqueue_email(&mut tx, &req.message).await.map_err(|err| {
+            error!(error = %err, "Failed to queue invitation email");
+            InvitationUseCaseError::DatabaseError("Invitation creation error".to_string())
+        })?;
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            InvitationUseCaseError::DatabaseError("Invitation creation error".to_string())
+        })?;
+
+        Ok(CreateInvitationDtoResponse(InvitationUseCaseResponse {
+            id: invitation_id,
+            user_id,
+            email: req.email,
+            lastname: req.lastname,
+            firstname: req.firstname,
+            locale: req.locale,
+            expired_at: req.invitation.expired_at,
+            created_at: now.clone(),
+            updated_at: now,
+        }))
+    }
+
+    #[instrument(skip(self), name = "invitation_repository_count")]
+    async fn count_invitations(
+        &self,
+        _req: CountInvitationsDtoRequest,
+    ) -> Result<CountInvitationsDtoResponse, InvitationUseCaseError> {
+        let query = r#"
+            SELECT COUNT(*) AS total
+            FROM invitations i
+                INNER JOIN users u ON u.id = i.user_id AND u.deleted_at IS NULL
+        "#;
+
+        let result = sqlx::query(query)
+            .fetch_one(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to count invitations");
+                InvitationUseCaseError::DatabaseError("Failed to count invitations".to_string())
+            })?;
+
+        Ok(CountInvitationsDtoResponse(result.try_get("total")?))
+    }
+
+    #[instrument(skip(self), name = "invitation_repository_get_all")]
+    async fn get_invitations(
+        &self,
+        req: GetInvitationsDtoRequest,
+    ) -> Result<GetInvitationsDtoResponse, InvitationUseCaseError> {
+        // Sorts & pagination (columns are prefixed because the cursor condition is in the `WHERE` clause)
+        let sorts = SqliteQuerySorts(req.0.sorts.unwrap_or_default());
+        let pagination = SqliteCursorPagination::new(
+            req.0.pagination,
+            &sorts,
+            &[
+                "u.email",
+                "u.lastname",
+                "u.firstname",
+                "i.expired_at",
+                "i.created_at",
+                "i.updated_at",
+            ],
+            "i.id",
+        );
+
+        let mut query = QueryBuilder::new(
+            "SELECT i.id, i.user_id, u.email, u.lastname, u.firstname, u.locale, i.expired_at, i.created_at, i.updated_at",
+        );
+        pagination.push_select(&mut query);
+        query.push(" FROM invitations i INNER JOIN users u ON u.id = i.user_id");
+
+        let mut conditions = SqliteConditions::new(&mut query);
+        conditions.push_soft_delete("u.deleted_at", false);
+        pagination.push_condition(&mut conditions);
+
+        pagination.push_order(&mut query);
+
+        let rows = query
+            .build_query_as::<SqliteCursorRow<InvitationModel>>()
+            .fetch_all(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to get invitations");
+                InvitationUseCaseError::DatabaseError("Failed to get invitations".to_string())
+            })?;
+        let (invitations, next_cursor) = pagination.page(rows);
+
+        let invitations = invitations
+            .into_iter()
+            .map(InvitationUseCaseResponse::try_from)
+            .collect::<Result<Vec<InvitationUseCaseResponse>, _>>()
+            .map_err(|err| {
+                error!(error = %err, "Failed to convert invitation model to invitation use case response");
+                InvitationUseCaseError::FromModelError()
+            })?;
+
+        Ok(GetInvitationsDtoResponse {
+            invitations,
+            next_cursor,
+        })
+    }
+
+    #[instrument(skip(self), name = "invitation_repository_get_by_id")]
+    async fn get_by_id(
+        &self,
+        req: GetInvitationByIdDtoRequest,
+    ) -> Result<GetInvitationByIdDtoResponse, InvitationUseCaseError> {
+        let result = sqlx::query_as::<_, InvitationModel>(
+            "
+            SELECT i.id, i.user_id, u.email, u.lastname, u.firstname, u.locale, i.expired_at, i.created_at, i.updated_at
+            FROM invitations i
+                INNER JOIN users u ON u.id = i.user_id AND u.deleted_at IS NULL
+            WHERE i.id = $1",
+        )
+        .bind(req.id.to_string())
+        .fetch_optional(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get invitation");
+            InvitationUseCaseError::DatabaseError("Failed to get invitation".to_string())
+        })?;
+
+        let invitation = match result {
+            Some(row) => row.try_into().map_err(|err| {
+                error!(error = %err, "Failed to convert invitation model to invitation use case response");
+                InvitationUseCaseError::FromModelError()
+            })?,
+            None => Err(InvitationUseCaseError::InvitationNotFound())?,
+        };
+
+        Ok(GetInvitationByIdDtoResponse(invitation))
+    }
+
+    #[instrument(skip(self, req), name = "invitation_repository_renew")]
+    async fn renew(
+        &self,
+        req: RenewInvitationDtoRequest,
+    ) -> Result<RenewInvitationDtoResponse, InvitationUseCaseError> {
+        let mut tx = self.db.pool.begin_with(BEGIN_IMMEDIATE).await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            InvitationUseCaseError::DatabaseError("Failed to renew invitation".to_string())
+        })?;
+
+        let result = sqlx::query(
+            "
+            UPDATE invitations
+            SET token = $1, expired_at = $2, updated_at = $3
+            WHERE id = $4",
+        )
+        .bind(req.invitation.token)
+        .bind(req.invitation.expired_at.value())
+        .bind(UtcDateTime::now().value())
+        .bind(req.id.to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to renew invitation");
+            InvitationUseCaseError::DatabaseError("Failed to renew invitation".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(InvitationUseCaseError::InvitationNotFound())?;
+        }
+
+        enqueue_email(&mut tx, &req.message).await.map_err(|err| {
+            error!(error = %err, "Failed to queue invitation email");
+            InvitationUseCaseError::DatabaseError("Failed to renew invitation".to_string())
+        })?;
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            InvitationUseCaseError::DatabaseError("Failed to renew invitation".to_string())
+        })?;
+
+        Ok(RenewInvitationDtoResponse())
+    }
+
+    #[instrument(skip(self), name = "invitation_repository_revoke")]
+    async fn revoke(
+        &self,
+        req: RevokeInvitationDtoRequest,
+    ) -> Result<RevokeInvitationDtoResponse, InvitationUseCaseError> {
+        // The invitation and the user scopes are deleted by cascade
+        let result = sqlx::query(
+            "
+            DELETE FROM users
+            WHERE id IN (SELECT user_id FROM invitations WHERE id = $1)
+                AND password IS NULL",
+        )
+        .bind(req.0.id.to_string())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to revoke invitation");
+            InvitationUseCaseError::DatabaseError("Failed to revoke invitation".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(InvitationUseCaseError::InvitationNotFound())?;
+        }
+
+        Ok(RevokeInvitationDtoResponse(RevokeInvitationUseCaseResponse()))
+    }
+
+    #[instrument(skip(self, req), name = "invitation_repository_accept")]
+    async fn accept(
+        &self,
+        req: AcceptInvitationDtoRequest,
+    ) -> Result<AcceptInvitationDtoResponse, InvitationUseCaseError> {
+        let now = UtcDateTime::now();
+
+        let mut tx = self.db.pool.begin_with(BEGIN_IMMEDIATE).await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            InvitationUseCaseError::DatabaseError("Failed to accept invitation".to_string())
+        })?;
+
+        let result = sqlx::query_scalar::<_, String>(
+            r#"
+                SELECT user_id
+                FROM invitations
+                WHERE token = $1
+                    AND expired_at >= $2
+                LIMIT 1
+            "#,
+        )
+        .bind(req.0.token)
+        .bind(now.value())
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get invitation from token");
+            InvitationUseCaseError::DatabaseError("Failed to get invitation from token".to_string())
+        })?;
+
+        let user_id = match result {
+            Some(user_id) => UserId::from_str(&user_id)?,
+            None => return Err(InvitationUseCaseError::InvitationNotFound()),
+        };
+
+        // Set user password
+        let result = sqlx::query(
+            "
+            UPDATE users
+            SET password = $1, password_changed_at = $2, updated_at = $3
+            WHERE id = $4
+                AND deleted_at IS NULL",
+        )
+        .bind(req.0.password.to_string())
+        .bind(now.value())
+        .bind(now.value())
+        .bind(user_id.to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to set user password");
+            InvitationUseCaseError::DatabaseError("Failed to accept invitation".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(InvitationUseCaseError::InvitationNotFound());
+        }
+
+        // Add password to history
+        sqlx::query(
+            "
+            INSERT INTO password_history (id, user_id, password, created_at)
+            VALUES ($1, $2, $3, $4)
+        ",
+        )
+        .bind(Id::new()?.to_string())
+        .bind(user_id.to_string())
+        .bind(req.0.password.to_string())
+        .bind(now.value())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to add password to history");
+            InvitationUseCaseError::DatabaseError("Failed to accept invitation".to_string())
+        })?;
+
+        // The token can only be used once
+        sqlx::query(
+            "
+            DELETE FROM invitations
+            WHERE user_id = $1",
+        )
+        .bind(user_id.to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to delete invitation");
+            InvitationUseCaseError::DatabaseError("Failed to accept invitation".to_string())
+        })?;
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            InvitationUseCaseError::DatabaseError("Failed to accept invitation".to_string())
+        })?;
+
+        Ok(AcceptInvitationDtoResponse { user_id })
+    }
+}
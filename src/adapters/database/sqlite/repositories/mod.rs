@@ -0,0 +1,106 @@
+//! SQLite repositories implementation
+
+use crate::adapters::database::DatabaseRepositories;
+use crate::adapters::database::sqlite::Db;
+use application::ApplicationSqliteRepository;
+use audit::AuditSqliteRepository;
+use client::ClientSqliteRepository;
+use email::EmailSqliteRepository;
+use external_link::ExternalLinkSqliteRepository;
+use federation::FederationSqliteRepository;
+use introspection::IntrospectionSqliteRepository;
+use invitation::InvitationSqliteRepository;
+use magic_link::MagicLinkSqliteRepository;
+use password_reset::PasswordResetSqliteRepository;
+use refresh_token::RefreshTokenSqliteRepository;
+use scope::ScopeSqliteRepository;
+use user::UserSqliteRepository;
+use webhook::WebhookSqliteRepository;
+
+pub mod application;
+pub mod audit;
+pub mod client;
+pub mod email;
+pub mod external_link;
+pub mod federation;
+pub mod introspection;
+pub mod invitation;
+pub mod magic_link;
+pub mod password_reset;
+pub mod refresh_token;
+pub mod scope;
+pub mod user;
+pub mod webhook;
+
+impl DatabaseRepositories for Db {
+    type User = UserSqliteRepository;
+    type RefreshToken = RefreshTokenSqliteRepository;
+    type PasswordReset = PasswordResetSqliteRepository;
+    type MagicLink = MagicLinkSqliteRepository;
+    type Application = ApplicationSqliteRepository;
+    type Client = ClientSqliteRepository;
+    type Scope = ScopeSqliteRepository;
+    type ExternalLink = ExternalLinkSqliteRepository;
+    type Invitation = InvitationSqliteRepository;
+    type Introspection = IntrospectionSqliteRepository;
+    type Federation = FederationSqliteRepository;
+    type Audit = AuditSqliteRepository;
+    type Webhook = WebhookSqliteRepository;
+    type Email = EmailSqliteRepository;
+
+    fn user_repository(&self) -> Self::User {
+        UserSqliteRepository::new(self.clone())
+    }
+
+    fn refresh_token_repository(&self) -> Self::RefreshToken {
+        RefreshTokenSqliteRepository::new(self.clone())
+    }
+
+    fn password_reset_repository(&self) -> Self::PasswordReset {
+        PasswordResetSqliteRepository::new(self.clone())
+    }
+
+    fn magic_link_repository(&self) -> Self::MagicLink {
+        MagicLinkSqliteRepository::new(self.clone())
+    }
+
+    fn application_repository(&self) -> Self::Application {
+        ApplicationSqliteRepository::new(self.clone())
+    }
+
+    fn client_repository(&self) -> Self::Client {
+        ClientSqliteRepository::new(self.clone())
+    }
+
+    fn scope_repository(&self) -> Self::Scope {
+        ScopeSqliteRepository::new(self.clone())
+    }
+
+    fn external_link_repository(&self) -> Self::ExternalLink {
+        ExternalLinkSqliteRepository::new(self.clone())
+    }
+
+    fn invitation_repository(&self) -> Self::Invitation {
+        InvitationSqliteRepository::new(self.clone())
+    }
+
+    fn introspection_repository(&self) -> Self::Introspection {
+        IntrospectionSqliteRepository::new(self.clone())
+    }
+
+    fn federation_repository(&self) -> Self::Federation {
+        FederationSqliteRepository::new(self.clone())
+    }
+
+    fn audit_repository(&self) -> Self::Audit {
+        AuditSqliteRepository::new(self.clone())
+    }
+
+    fn webhook_repository(&self) -> Self::Webhook {
+        WebhookSqliteRepository::new(self.clone())
+    }
+
+    fn email_repository(&self) -> Self::Email {
+        EmailSqliteRepository::new(self.clone())
+    }
+}
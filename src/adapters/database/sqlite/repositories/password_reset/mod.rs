@@ -0,0 +1,118 @@
+//! Password reset SQLite repository
+
+use crate::adapters::database::sqlite::repositories::email::enqueue_email;
+use crate::adapters::database::sqlite::{BEGIN_IMMEDIATE, Db};
+use crate::domain::repositories::password_reset::PasswordResetRepository;
+use crate::domain::repositories::password_reset::dto::{
+    CreateUpdatePasswordResetDtoRequest, CreateUpdatePasswordResetDtoResponse, DeletePasswordResetDtoRequest,
+    DeletePasswordResetDtoResponse, GetUserIdFromTokenDtoRequest, GetUserIdFromTokenDtoResponse,
+};
+use crate::domain::use_cases::user::UserUseCaseError;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use async_trait::async_trait;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Password reset SQLite repository
+#[derive(Debug, Clone)]
+pub struct PasswordResetSqliteRepository {
+    db: Arc<Db>,
+}
+
+impl PasswordResetSqliteRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl PasswordResetRepository for PasswordResetSqliteRepository {
+    #[instrument(skip(self, req), name = "password_reset_repository_create_update")]
+    async fn create_or_update(
+        &self,
+        req: CreateUpdatePasswordResetDtoRequest,
+    ) -> Result<CreateUpdatePasswordResetDtoResponse, UserUseCaseError> {
+        let password_reset = req.password_reset;
+        let mut tx = self.db.pool.begin_with(BEGIN_IMMEDIATE).await?;
+
+        sqlx::query(
+            r#"
+                INSERT INTO password_resets (user_id, token, expired_at)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (user_id) DO UPDATE SET token = EXCLUDED.token, expired_at = EXCLUDED.expired_at
+            "#,
+        )
+        .bind(password_reset.user_id.to_string())
+        .bind(password_reset.token)
+        .bind(password_reset.expired_at.value())
+        .execute(&mut *tx)
+        .await?;
+
+        if let Some(message) = &req.message {
+            enqueue_email(&mut tx, message).await.map_err(|err| {
+                error!(error = %err, "Failed to queue forgotten password email");
+                UserUseCaseError::DatabaseError("Failed to queue forgotten password email".to_string())
+            })?;
+        }
+
+        tx.commit().await?;
+
+        Ok(CreateUpdatePasswordResetDtoResponse())
+    }
+
+    #[instrument(skip(self), name = "password_reset_repository_get_user")]
+    async fn get_user_from_token(
+        &self,
+        req: GetUserIdFromTokenDtoRequest,
+    ) -> Result<GetUserIdFromTokenDtoResponse, UserUseCaseError> {
+        let result = sqlx::query_scalar::<_, String>(
+            r#"
+                SELECT u.id AS user_id
+                FROM password_resets pr
+                    INNER JOIN users u ON u.id = pr.user_id AND u.deleted_at IS NULL
+                WHERE pr.token = $1
+                    AND pr.expired_at >= $2
+                LIMIT 1
+            "#,
+        )
+        .bind(req.token)
+        .bind(UtcDateTime::now().value())
+        .fetch_optional(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get user ID from password reset token");
+            UserUseCaseError::DatabaseError("Failed to get user ID from password reset token".to_string())
+        })?;
+
+        let user_id = match result {
+            Some(user_id) => Some(Id::from_str(&user_id)?),
+            None => None,
+        };
+
+        Ok(GetUserIdFromTokenDtoResponse { user_id })
+    }
+
+    #[instrument(skip(self), name = "password_reset_repository_delete")]
+    async fn delete(
+        &self,
+        req: DeletePasswordResetDtoRequest,
+    ) -> Result<DeletePasswordResetDtoResponse, UserUseCaseError> {
+        sqlx::query(
+            r#"
+                DELETE FROM password_resets
+                WHERE user_id = $1
+            "#,
+        )
+        .bind(req.user_id.to_string())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to delete password reset");
+            UserUseCaseError::DatabaseError("Failed to delete password reset".to_string())
+        })?;
+
+        Ok(DeletePasswordResetDtoResponse())
+    }
+}
@@ -0,0 +1,583 @@
+//! User SQLite repository
+
+use crate::adapters::database::model::user::UserModel;
+use crate::adapters::database::sqlite::repositories::webhook::enqueue_webhook_message;
+use crate::adapters::database::sqlite::{
+    BEGIN_IMMEDIATE, Db, SqliteConditions, SqliteCursorPagination, SqliteCursorRow, SqliteFilterField,
+    SqliteFilterType, SqliteFullTextSearch, SqlitePagination, SqliteQueryFilters, SqliteQuerySorts,
+};
+use crate::domain::entities::user::UserId;
+use crate::domain::repositories::user::UserRepository;
+use crate::domain::repositories::user::dto::{
+    CountUsersDtoRequest, CountUsersDtoResponse, CreateUserDtoRequest, CreateUserDtoResponse, DeleteUserDtoRequest,
+    DeleteUserDtoResponse, GetAccessTokenInformationDtoRequest, GetAccessTokenInformationDtoResponse,
+    GetPasswordChangedAtDtoRequest, GetPasswordChangedAtDtoResponse, GetPasswordHistoryDtoRequest,
+    GetPasswordHistoryDtoResponse, GetUserByEmailDtoRequest, GetUserByEmailDtoResponse, GetUserByIdDtoRequest,
+    GetUserByIdDtoResponse, GetUsersDtoRequest, GetUsersDtoResponse, RehashPasswordDtoRequest,
+    RehashPasswordDtoResponse, RestoreUserDtoRequest, RestoreUserDtoResponse, SearchUsersDtoRequest,
+    SearchUsersDtoResponse, UpdatePasswordDtoRequest, UpdatePasswordDtoResponse,
+};
+use crate::domain::use_cases::user::delete_user::DeleteUserUseCaseResponse;
+use crate::domain::use_cases::user::restore_user::RestoreUserUseCaseResponse;
+use crate::domain::use_cases::user::{UserUseCaseError, UserUseCaseResponse};
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use crate::domain::value_objects::password::Password;
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use sqlx::{QueryBuilder, Sqlite, Transaction};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Fields available for filtering the users
+const FILTER_FIELDS: [SqliteFilterField; 8] = [
+    ("id", SqliteFilterType::Text),
+    ("email", SqliteFilterType::Text),
+    ("lastname", SqliteFilterType::Text),
+    ("firstname", SqliteFilterType::Text),
+    ("locale", SqliteFilterType::Text),
+    ("created_at", SqliteFilterType::Datetime),
+    ("updated_at", SqliteFilterType::Datetime),
+    ("deleted_at", SqliteFilterType::Datetime),
+];
+
+/// Fields of the full-text index used for searching the users
+const SEARCH_FIELDS: [&str; 3] = ["email", "firstname", "lastname"];
+
+/// User SQLite repository
+#[derive(Debug, Clone)]
+pub struct UserSqliteRepository {
+    db: Arc<Db>,
+}
+
+impl UserSqliteRepository {
+    /// Create a new repository
+    pub fn new(db: Db) -> Self {
+        Self { db: Arc::new(db) }
+    }
+
+    /// Add a hashed password to the user password history
+    async fn add_password_history(
+        tx: &mut Transaction<'_, Sqlite>,
+        user_id: &UserId,
+        password: &Password,
+        created_at: &UtcDateTime,
+    ) -> Result<(), UserUseCaseError> {
+        let id = Id::new().map_err(|err| {
+            error!(error = %err, "Failed to create password history ID");
+            UserUseCaseError::InvalidId()
+        })?;
+
+        sqlx::query(
+            "
+            INSERT INTO password_history (id, user_id, password, created_at)
+            VALUES ($1, $2, $3, $4)
+        ",
+        )
+        .bind(id.to_string())
+        .bind(user_id.to_string())
+        .bind(password.to_string())
+        .bind(created_at.value())
+        .execute(&mut **tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to add password to history");
+            UserUseCaseError::DatabaseError("Failed to add password to history".to_string())
+        })?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UserRepository for UserSqliteRepository {
+    #[instrument(skip(self), name = "user_repository_create")]
+    async fn create_user(&self, req: CreateUserDtoRequest) -> Result<CreateUserDtoResponse, UserUseCaseError> {
+        let user_id = req.id;
+        let now = UtcDateTime::now();
+
+        let mut tx = self.db.pool.begin_with(BEGIN_IMMEDIATE).await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            UserUseCaseError::DatabaseError("User creation error".to_string())
+        })?;
+
+        // Create user
+        sqlx::query("
+            INSERT INTO users (id, email, password, password_changed_at, lastname, firstname, locale, created_at, updated_at, deleted_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, NULL)
+        ")
+        .bind(user_id.clone().to_string())
+        .bind(req.user.email.to_string())
+        .bind(req.user.password.to_string())
+        .bind(now.value())
+        .bind(&req.user.lastname)
+        .bind(&req.user.firstname)
+        .bind(req.user.locale.as_ref().map(|locale| locale.to_string()))
+        .bind(now.value())
+        .bind(now.value())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to create user");
+            UserUseCaseError::DatabaseError("User creation error".to_string())
+        })?;
+
+        // Add password to history
+        Self::add_password_history(&mut tx, &user_id, &req.user.password, &now).await?;
+
+        enqueue_webhook_message(&mut tx, &req.message).await.map_err(|err| {
+            error!(error = %err, "Failed to enqueue webhook message");
+            UserUseCaseError::DatabaseError("User creation error".to_string())
+        })?;
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            UserUseCaseError::DatabaseError("User creation error".to_string())
+        })?;
+
+        Ok(CreateUserDtoResponse(UserUseCaseResponse {
+            id: user_id,
+            email: req.user.email,
+            lastname: req.user.lastname,
+            firstname: req.user.firstname,
+            locale: req.user.locale,
+            created_at: now.clone(),
+            updated_at: now,
+            deleted_at: None,
+        }))
+    }
+
+    #[instrument(skip(self), name = "user_repository_access_token_information")]
+    async fn get_access_token_information(
+        &self,
+        req: GetAccessTokenInformationDtoRequest,
+    ) -> Result<Option<GetAccessTokenInformationDtoResponse>, UserUseCaseError> {
+        let result = sqlx::query_as::<_, (String, String, NaiveDateTime)>(
+            r#"
+            SELECT id, password, password_changed_at
+            FROM users
+            WHERE email = $1
+                AND password IS NOT NULL
+                AND deleted_at IS NULL"#,
+        )
+        .bind(req.0.to_string())
+        .fetch_optional(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get user by email");
+            UserUseCaseError::DatabaseError("Failed to get user by email".to_string())
+        })?;
+
+        let response = match result {
+            Some((id, password, password_changed_at)) => Some(GetAccessTokenInformationDtoResponse {
+                id: Id::from_str(&id).map_err(|_| UserUseCaseError::InvalidId())?,
+                password: Password::new(&password, true)
+                    .map_err(|_| UserUseCaseError::InvalidPassword("Failed to generate user ID".to_string()))?,
+                password_changed_at: UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(
+                    password_changed_at,
+                    Utc,
+                )),
+            }),
+            None => None,
+        };
+
+        Ok(response)
+    }
+
+    #[instrument(skip(self), name = "user_repository_get_password_changed_at")]
+    async fn get_password_changed_at(
+        &self,
+        req: GetPasswordChangedAtDtoRequest,
+    ) -> Result<GetPasswordChangedAtDtoResponse, UserUseCaseError> {
+        let (password, password_changed_at) = sqlx::query_as::<_, (Option<String>, NaiveDateTime)>(
+            "
+            SELECT password, password_changed_at
+            FROM users
+            WHERE id = ?
+                AND deleted_at IS NULL",
+        )
+        .bind(req.0.to_string())
+        .fetch_optional(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get user password change date");
+            UserUseCaseError::DatabaseError("Failed to get user password change date".to_string())
+        })?
+        .ok_or_else(UserUseCaseError::UserNotFound)?;
+
+        Ok(GetPasswordChangedAtDtoResponse(password.map(|_| {
+            UtcDateTime::new(DateTime::<Utc>::from_naive_utc_and_offset(password_changed_at, Utc))
+        })))
+    }
+
+    #[instrument(skip(self), name = "user_repository_get_users")]
+    async fn get_users(&self, req: GetUsersDtoRequest) -> Result<GetUsersDtoResponse, UserUseCaseError> {
+        // Sorts & pagination
+        let sorts = SqliteQuerySorts(req.0.sorts.unwrap_or_default());
+        let pagination = SqliteCursorPagination::new(
+            req.0.pagination,
+            &sorts,
+            &["id", "lastname", "firstname", "created_at", "updated_at", "deleted_at"],
+            "id",
+        );
+
+        let mut query =
+            QueryBuilder::new("SELECT id, email, lastname, firstname, locale, created_at, updated_at, deleted_at");
+        pagination.push_select(&mut query);
+        query.push(" FROM users");
+
+        // Filters
+        let mut conditions = SqliteConditions::new(&mut query);
+        conditions.push_soft_delete("deleted_at", req.0.deleted);
+        SqliteQueryFilters(req.0.filters.unwrap_or_default()).push_sql(&mut conditions, &FILTER_FIELDS);
+        pagination.push_condition(&mut conditions);
+
+        pagination.push_order(&mut query);
+
+        let rows = query
+            .build_query_as::<SqliteCursorRow<UserModel>>()
+            .fetch_all(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to get users");
+                UserUseCaseError::DatabaseError("Failed to get users".to_string())
+            })?;
+        let (users, next_cursor) = pagination.page(rows);
+
+        let users = users
+            .into_iter()
+            .map(UserUseCaseResponse::try_from)
+            .collect::<Result<Vec<UserUseCaseResponse>, _>>()
+            .map_err(|err| {
+                error!(error = %err, "Failed to convert user model to user use case response");
+                UserUseCaseError::FromModelError()
+            })?;
+
+        Ok(GetUsersDtoResponse { users, next_cursor })
+    }
+
+    #[instrument(skip(self), name = "user_repository_count_users")]
+    async fn count_users(&self, req: CountUsersDtoRequest) -> Result<CountUsersDtoResponse, UserUseCaseError> {
+        let mut query = QueryBuilder::new("SELECT COUNT(*) AS total FROM users");
+
+        let mut conditions = SqliteConditions::new(&mut query);
+        conditions.push_soft_delete("deleted_at", req.deleted);
+        SqliteQueryFilters(req.filters.unwrap_or_default()).push_sql(&mut conditions, &FILTER_FIELDS);
+
+        let (total,) = query
+            .build_query_as::<(i64,)>()
+            .fetch_one(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to count users");
+                UserUseCaseError::DatabaseError("Failed to count users".to_string())
+            })?;
+
+        Ok(CountUsersDtoResponse(total))
+    }
+
+    #[instrument(skip(self), name = "user_repository_search_users")]
+    async fn search_users(&self, req: SearchUsersDtoRequest) -> Result<SearchUsersDtoResponse, UserUseCaseError> {
+        let Some(patterns) = SqliteFullTextSearch(req.search).to_like_patterns() else {
+            return Ok(SearchUsersDtoResponse {
+                users: vec![],
+                total: 0,
+            });
+        };
+        let filters = SqliteQueryFilters(req.filters.unwrap_or_default());
+        let push_conditions = |query: &mut QueryBuilder<'_, Sqlite>| {
+            let mut conditions = SqliteConditions::new(query);
+            conditions.push_soft_delete("deleted_at", req.deleted);
+            SqliteFullTextSearch::push_sql(conditions.push(), &SEARCH_FIELDS, &patterns);
+            filters.push_sql(&mut conditions, &FILTER_FIELDS);
+        };
+
+        // Total
+        let mut query = QueryBuilder::new("SELECT COUNT(*) AS total FROM users");
+        push_conditions(&mut query);
+
+        let (total,) = query
+            .build_query_as::<(i64,)>()
+            .fetch_one(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to count searched users");
+                UserUseCaseError::DatabaseError("Failed to search users".to_string())
+            })?;
+
+        // Users ranked by relevance
+        let mut query = QueryBuilder::new(
+            "SELECT id, email, lastname, firstname, locale, created_at, updated_at, deleted_at FROM users",
+        );
+        push_conditions(&mut query);
+
+        query.push(" ORDER BY ");
+        SqliteFullTextSearch::push_rank(&mut query, &SEARCH_FIELDS, &patterns);
+        query.push(" DESC, id");
+        SqlitePagination::from(req.pagination).push_sql(&mut query);
+
+        let users = query
+            .build_query_as::<UserModel>()
+            .fetch_all(self.db.pool.clone().as_ref())
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Failed to search users");
+                UserUseCaseError::DatabaseError("Failed to search users".to_string())
+            })?
+            .into_iter()
+            .map(UserUseCaseResponse::try_from)
+            .collect::<Result<Vec<UserUseCaseResponse>, _>>()
+            .map_err(|err| {
+                error!(error = %err, "Failed to convert user model to user use case response");
+                UserUseCaseError::FromModelError()
+            })?;
+
+        Ok(SearchUsersDtoResponse { users, total })
+    }
+
+    #[instrument(skip(self, req), name = "user_repository_get_user_by_id")]
+    async fn get_user_by_id(&self, req: GetUserByIdDtoRequest) -> Result<GetUserByIdDtoResponse, UserUseCaseError> {
+        let result = sqlx::query_as::<_, UserModel>(
+            "
+            SELECT id, email, lastname, firstname, locale, created_at, updated_at, deleted_at
+            FROM users
+            WHERE id = $1
+                AND deleted_at IS NULL",
+        )
+        .bind(req.0.user_id.to_string())
+        .fetch_optional(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get user by ID");
+            UserUseCaseError::DatabaseError("Failed to get user by ID".to_string())
+        })?;
+
+        let user = match result {
+            Some(row) => row.try_into().map_err(|err| {
+                error!(error = %err, "Failed to convert user model to user use case response");
+                UserUseCaseError::FromModelError()
+            })?,
+            None => Err(UserUseCaseError::UserNotFound())?,
+        };
+
+        Ok(GetUserByIdDtoResponse(user))
+    }
+
+    #[instrument(skip(self, req), name = "user_repository_get_user_by_email")]
+    async fn get_user_by_email(
+        &self,
+        req: GetUserByEmailDtoRequest,
+    ) -> Result<GetUserByEmailDtoResponse, UserUseCaseError> {
+        let result = sqlx::query_as::<_, UserModel>(
+            "
+            SELECT id, email, lastname, firstname, locale, created_at, updated_at, deleted_at
+            FROM users
+            WHERE email = $1
+                AND deleted_at IS NULL
+            LIMIT 1",
+        )
+        .bind(req.0.to_string())
+        .fetch_optional(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get user by email");
+            UserUseCaseError::DatabaseError("Failed to get user by email".to_string())
+        })?;
+
+        let user = match result {
+            Some(row) => row.try_into().map_err(|err| {
+                error!(error = %err, "Failed to convert user model to user use case response");
+                UserUseCaseError::FromModelError()
+            })?,
+            None => Err(UserUseCaseError::UserNotFound())?,
+        };
+
+        Ok(GetUserByEmailDtoResponse(user))
+    }
+
+    #[instrument(skip(self, req), name = "user_repository_delete_user")]
+    async fn delete_user(&self, req: DeleteUserDtoRequest) -> Result<DeleteUserDtoResponse, UserUseCaseError> {
+        let mut tx = self.db.pool.begin_with(BEGIN_IMMEDIATE).await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            UserUseCaseError::DatabaseError("Failed to delete user".to_string())
+        })?;
+
+        let result = sqlx::query(
+            "
+            UPDATE users
+            SET deleted_at = $1
+            WHERE id = $2
+                AND deleted_at IS NULL",
+        )
+        .bind(Some(UtcDateTime::now().value()))
+        .bind(req.user_id.to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to delete user");
+            UserUseCaseError::DatabaseError("Failed to delete user".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(UserUseCaseError::UserNotFound())?;
+        }
+
+        enqueue_webhook_message(&mut tx, &req.message).await.map_err(|err| {
+            error!(error = %err, "Failed to enqueue webhook message");
+            UserUseCaseError::DatabaseError("Failed to delete user".to_string())
+        })?;
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            UserUseCaseError::DatabaseError("Failed to delete user".to_string())
+        })?;
+
+        Ok(DeleteUserDtoResponse(DeleteUserUseCaseResponse()))
+    }
+
+    #[instrument(skip(self, req), name = "user_repository_update_password")]
+    async fn update_password(
+        &self,
+        req: UpdatePasswordDtoRequest,
+    ) -> Result<UpdatePasswordDtoResponse, UserUseCaseError> {
+        let now = UtcDateTime::now();
+
+        let mut tx = self.db.pool.begin_with(BEGIN_IMMEDIATE).await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            UserUseCaseError::DatabaseError("Failed to update user password".to_string())
+        })?;
+
+        let result = sqlx::query(
+            "
+            UPDATE users
+            SET password = $1, password_changed_at = $2
+            WHERE id = $3
+                AND deleted_at IS NULL",
+        )
+        .bind(req.password.to_string())
+        .bind(now.value())
+        .bind(req.user_id.to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to update user password");
+            UserUseCaseError::DatabaseError("Failed to update user password".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(UserUseCaseError::UserNotFound())?;
+        }
+
+        // Add password to history
+        Self::add_password_history(&mut tx, &req.user_id, &req.password, &now).await?;
+
+        enqueue_webhook_message(&mut tx, &req.message).await.map_err(|err| {
+            error!(error = %err, "Failed to enqueue webhook message");
+            UserUseCaseError::DatabaseError("Failed to update user password".to_string())
+        })?;
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            UserUseCaseError::DatabaseError("Failed to update user password".to_string())
+        })?;
+
+        Ok(UpdatePasswordDtoResponse())
+    }
+
+    #[instrument(skip(self, req), name = "user_repository_rehash_password")]
+    async fn rehash_password(
+        &self,
+        req: RehashPasswordDtoRequest,
+    ) -> Result<RehashPasswordDtoResponse, UserUseCaseError> {
+        let result = sqlx::query(
+            "
+            UPDATE users
+            SET password = $1
+            WHERE id = $2
+                AND deleted_at IS NULL",
+        )
+        .bind(req.password.to_string())
+        .bind(req.user_id.to_string())
+        .execute(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to rehash user password");
+            UserUseCaseError::DatabaseError("Failed to rehash user password".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(UserUseCaseError::UserNotFound())?;
+        }
+
+        Ok(RehashPasswordDtoResponse())
+    }
+
+    #[instrument(skip(self), name = "user_repository_get_password_history")]
+    async fn get_password_history(
+        &self,
+        req: GetPasswordHistoryDtoRequest,
+    ) -> Result<GetPasswordHistoryDtoResponse, UserUseCaseError> {
+        let rows = sqlx::query_scalar::<_, String>(
+            "
+            SELECT password
+            FROM password_history
+            WHERE user_id = $1
+            ORDER BY created_at DESC
+            LIMIT $2",
+        )
+        .bind(req.user_id.to_string())
+        .bind(req.limit)
+        .fetch_all(self.db.pool.clone().as_ref())
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to get password history");
+            UserUseCaseError::DatabaseError("Failed to get password history".to_string())
+        })?;
+
+        let passwords = rows
+            .into_iter()
+            .map(|password| Password::new(&password, true))
+            .collect::<Result<Vec<Password>, _>>()?;
+
+        Ok(GetPasswordHistoryDtoResponse(passwords))
+    }
+
+    #[instrument(skip(self, req), name = "user_repository_restore_user")]
+    async fn restore_user(&self, req: RestoreUserDtoRequest) -> Result<RestoreUserDtoResponse, UserUseCaseError> {
+        let mut tx = self.db.pool.begin_with(BEGIN_IMMEDIATE).await.map_err(|err| {
+            error!(error = %err, "Failed to start transaction");
+            UserUseCaseError::DatabaseError("Failed to restore user".to_string())
+        })?;
+
+        let result = sqlx::query(
+            "
+            UPDATE users
+            SET deleted_at = NULL
+            WHERE id = $1
+                AND deleted_at IS NOT NULL",
+        )
+        .bind(req.user_id.to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "Failed to restore user");
+            UserUseCaseError::DatabaseError("Failed to restore user".to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(UserUseCaseError::UserNotFound())?;
+        }
+
+        enqueue_webhook_message(&mut tx, &req.message).await.map_err(|err| {
+            error!(error = %err, "Failed to enqueue webhook message");
+            UserUseCaseError::DatabaseError("Failed to restore user".to_string())
+        })?;
+
+        tx.commit().await.map_err(|err| {
+            error!(error = %err, "Failed to commit transaction");
+            UserUseCaseError::DatabaseError("Failed to restore user".to_string())
+        })?;
+
+        Ok(RestoreUserDtoResponse(RestoreUserUseCaseResponse()))
+    }
+}
@@ -0,0 +1,92 @@
+//! Send email on magic link request implementation
+
+use crate::adapters::email::templates::EmailTemplates;
+use crate::domain::entities::email::EmailMessage;
+use crate::domain::services::email::EmailServiceError;
+use crate::domain::value_objects::locale::Locale;
+use tera::Context;
+use validator::ValidateUrl;
+
+#[derive(Debug, Clone)]
+pub struct MagicLink {
+    pub app_name: String,
+    pub base_url: String,
+    pub token: String,
+    pub email_from: String,
+    pub email_to: String,
+    pub locale: Option<Locale>,
+}
+
+impl MagicLink {
+    /// Get magic link
+    fn link(&self) -> Result<String, EmailServiceError> {
+        let link = format!("{}/{}", self.base_url, self.token);
+
+        match ValidateUrl::validate_url(&link) {
+            true => Ok(link),
+            false => Err(EmailServiceError::InvalidParameter(format!("invalid URL: {link}"))),
+        }
+    }
+
+    /// Render the message from the `magic_link` templates in the user locale
+    pub fn into_message(self, templates: &EmailTemplates) -> Result<EmailMessage, EmailServiceError> {
+        let mut context = Context::new();
+        context.insert("app_name", &self.app_name);
+        context.insert("link", &self.link()?);
+
+        let email = templates.render("magic_link", self.locale.as_ref(), &context)?;
+
+        Ok(EmailMessage {
+            from_address: self.email_from,
+            subject: email.subject,
+            text_body: Some(email.text_body),
+            html_body: Some(email.html_body),
+            to_addresses: vec![self.email_to],
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn magic_link(base_url: &str, locale: Option<&str>) -> MagicLink {
+        MagicLink {
+            app_name: "My App".to_owned(),
+            base_url: base_url.to_owned(),
+            token: "myToken5846".to_owned(),
+            email_from: "from@test.com".to_owned(),
+            email_to: "to@test.com".to_owned(),
+            locale: locale.map(|locale| Locale::new(locale).unwrap()),
+        }
+    }
+
+    #[test]
+    fn test_magic_link_request_link() {
+        assert_eq!(
+            magic_link("https://test.com", None).link().unwrap(),
+            "https://test.com/myToken5846".to_owned()
+        );
+        assert!(magic_link("-test.com", None).link().is_err());
+    }
+
+    #[test]
+    fn test_magic_link_request_into_message() {
+        let templates = EmailTemplates::new("en", None).unwrap();
+        let msg = magic_link("https://test.com", None).into_message(&templates).unwrap();
+
+        assert_eq!(msg.subject, "My App - Sign in".to_owned());
+        assert_eq!(msg.from_address, "from@test.com".to_owned());
+        assert_eq!(msg.to_addresses, vec!["to@test.com".to_owned()]);
+        assert!(msg.text_body.unwrap().contains("https://test.com/myToken5846"));
+        assert!(msg.html_body.is_some());
+
+        let msg = magic_link("https://test.com", Some("fr-BE"))
+            .into_message(&templates)
+            .unwrap();
+        assert_eq!(msg.subject, "My App - Connexion".to_owned());
+        assert!(msg.html_body.unwrap().contains(r#"<html lang="fr">"#));
+
+        assert!(magic_link("-test.com", None).into_message(&templates).is_err());
+    }
+}
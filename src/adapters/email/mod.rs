@@ -1,51 +1,142 @@
 //! Email adapter using `lettre` crate
 
 pub mod forgotten_password;
+pub mod invitation;
+pub mod magic_link;
+pub mod templates;
+pub mod transport;
 
 use crate::APP_NAME;
 use crate::adapters::email::forgotten_password::ForgottenPassword;
+use crate::adapters::email::invitation::Invitation;
+use crate::adapters::email::magic_link::MagicLink;
+use crate::adapters::email::templates::EmailTemplates;
+use crate::adapters::email::transport::{EmailTransportKind, FileTransport, MemoryTransport, StdoutTransport};
 use crate::domain::entities::email::{EmailConfig, EmailMessage};
 use crate::domain::services::email::forgotten_password::{
     ForgottenPasswordEmailRequest, ForgottenPasswordEmailResponse,
 };
+use crate::domain::services::email::invitation::{InvitationEmailRequest, InvitationEmailResponse};
+use crate::domain::services::email::magic_link::{MagicLinkEmailRequest, MagicLinkEmailResponse};
 use crate::domain::services::email::{EmailService, EmailServiceError, EmailTransport};
+use async_trait::async_trait;
 use lettre::address::AddressError;
 use lettre::message::{MultiPart, SinglePart, header};
-use lettre::{SmtpTransport, Transport};
+use lettre::transport::smtp::PoolConfig;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Tokio1Executor};
+use std::str::FromStr;
 use std::time::Duration;
 
-#[derive(Debug, Default, Clone)]
+/// SMTP connection security
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmtpTls {
+    /// Plain text connection (local relay or development only)
+    None,
+
+    /// Plain text connection upgraded with `STARTTLS` (mandatory)
+    StartTls,
+
+    /// TLS from the start of the connection (implicit TLS, port 465)
+    Tls,
+}
+
+impl FromStr for SmtpTls {
+    type Err = EmailServiceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "none" => Ok(Self::None),
+            "starttls" => Ok(Self::StartTls),
+            "tls" => Ok(Self::Tls),
+            _ => Err(EmailServiceError::ConfigError(format!(
+                "invalid SMTP TLS mode `{s}` (expected: none, starttls or tls)"
+            ))),
+        }
+    }
+}
+
+/// Transport selected by the configuration
+#[derive(Debug, Clone)]
+enum Transport {
+    Smtp(AsyncSmtpTransport<Tokio1Executor>),
+    File(FileTransport),
+    Stdout(StdoutTransport),
+    Memory(MemoryTransport),
+}
+
+#[derive(Debug, Clone)]
 pub struct EmailAdapter {
     config: EmailConfig,
+    transport: Transport,
+    templates: EmailTemplates,
 }
 
 impl EmailAdapter {
-    /// New email with SMTP config
-    pub fn new(config: EmailConfig) -> Self {
-        Self { config }
+    /// New email with the transport of the config
+    ///
+    /// With SMTP, it must be called inside a Tokio runtime (the SMTP connection pool spawns a task).
+    pub fn new(config: EmailConfig) -> Result<Self, EmailServiceError> {
+        let transport = match EmailTransportKind::from_str(&config.transport)? {
+            EmailTransportKind::Smtp => Transport::Smtp(Self::smtp_transport(&config)?),
+            EmailTransportKind::File => match &config.file_path {
+                Some(path) => Transport::File(FileTransport::new(path)),
+                None => {
+                    return Err(EmailServiceError::ConfigError(
+                        "the email directory is required by the file transport".to_string(),
+                    ));
+                }
+            },
+            EmailTransportKind::Stdout => Transport::Stdout(StdoutTransport::default()),
+            EmailTransportKind::Memory => Transport::Memory(MemoryTransport::default()),
+        };
+        let templates = EmailTemplates::new(&config.default_locale, config.templates_path.as_deref())?;
+
+        Ok(Self {
+            config,
+            transport,
+            templates,
+        })
     }
 
     /// Create SMTP transport
-    pub fn smtp_transport(&self) -> SmtpTransport {
-        let timeout = match self.config.timeout {
+    fn smtp_transport(config: &EmailConfig) -> Result<AsyncSmtpTransport<Tokio1Executor>, EmailServiceError> {
+        let timeout = match config.timeout {
             0 => None,
             t => Some(Duration::from_secs(t)),
         };
 
-        // TODO: Manage SMTP username and password
-        SmtpTransport::builder_dangerous(&self.config.host)
-            .port(self.config.port)
-            .timeout(timeout)
-            .build()
+        let mut builder = match SmtpTls::from_str(&config.tls)? {
+            SmtpTls::None => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host),
+            SmtpTls::StartTls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host)
+                .map_err(|err| EmailServiceError::ConfigError(err.to_string()))?,
+            SmtpTls::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host)
+                .map_err(|err| EmailServiceError::ConfigError(err.to_string()))?,
+        };
+
+        // Authentication only if credentials are provided
+        if let (Some(username), Some(password)) = (&config.username, &config.password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        let pool = PoolConfig::new()
+            .max_size(config.pool_max_size.max(1))
+            .idle_timeout(Duration::from_secs(config.pool_idle_timeout));
+
+        Ok(builder.port(config.port).timeout(timeout).pool_config(pool).build())
     }
-}
 
-impl EmailTransport for EmailAdapter {
-    /// Send an email
-    fn send(&self, message: EmailMessage) -> Result<(), EmailServiceError> {
-        let mailer = self.smtp_transport();
+    /// Emails sent with the memory transport (`None` with the other transports)
+    pub fn memory_transport(&self) -> Option<&MemoryTransport> {
+        match &self.transport {
+            Transport::Memory(transport) => Some(transport),
+            _ => None,
+        }
+    }
 
-        let mut email_builder = lettre::Message::builder().subject(message.subject).from(
+    /// Convert a message into a `lettre` message
+    fn build_message(message: &EmailMessage) -> Result<lettre::Message, EmailServiceError> {
+        let mut email_builder = lettre::Message::builder().subject(message.subject.clone()).from(
             message
                 .from_address
                 .parse()
@@ -53,29 +144,82 @@ impl EmailTransport for EmailAdapter {
         );
 
         // Add destination emails
-        for to in message.to_addresses {
+        for to in &message.to_addresses {
             email_builder = email_builder.to(to
                 .parse()
                 .map_err(|err: AddressError| EmailServiceError::InvalidEmailTo(err.to_string()))?)
         }
 
         let mut multipart = MultiPart::alternative().build();
-        if let Some(text) = message.text_body {
-            multipart = multipart.singlepart(SinglePart::builder().header(header::ContentType::TEXT_PLAIN).body(text));
+        if let Some(text) = &message.text_body {
+            multipart = multipart.singlepart(
+                SinglePart::builder()
+                    .header(header::ContentType::TEXT_PLAIN)
+                    .body(text.clone()),
+            );
         }
-        if let Some(html) = message.html_body {
-            multipart = multipart.singlepart(SinglePart::builder().header(header::ContentType::TEXT_HTML).body(html));
+        if let Some(html) = &message.html_body {
+            multipart = multipart.singlepart(
+                SinglePart::builder()
+                    .header(header::ContentType::TEXT_HTML)
+                    .body(html.clone()),
+            );
         }
-        let email = email_builder
+
+        email_builder
             .multipart(multipart)
-            .map_err(|err| EmailServiceError::MultiPartError(err.to_string()))?;
+            .map_err(|err| EmailServiceError::MultiPartError(err.to_string()))
+    }
+
+    /// Check that a message can be sent before it is queued
+    fn validate(message: EmailMessage) -> Result<EmailMessage, EmailServiceError> {
+        Self::build_message(&message)?;
+
+        Ok(message)
+    }
+}
+
+#[async_trait]
+impl EmailTransport for EmailAdapter {
+    /// Send an email
+    async fn send(&self, message: &EmailMessage) -> Result<(), EmailServiceError> {
+        let transport = match &self.transport {
+            Transport::Smtp(transport) => transport,
+            Transport::File(transport) => return transport.send(message).await,
+            Transport::Stdout(transport) => return transport.send(message).await,
+            Transport::Memory(transport) => return transport.send(message).await,
+        };
+        let email = Self::build_message(message)?;
 
-        mailer
-            .send(&email)
+        transport
+            .send(email)
+            .await
             .map_err(|err| EmailServiceError::SendError(err.to_string()))?;
 
         Ok(())
     }
+
+    /// Check the connection to the SMTP server (and the authentication) or the other transport
+    async fn check(&self) -> Result<(), EmailServiceError> {
+        let transport = match &self.transport {
+            Transport::Smtp(transport) => transport,
+            Transport::File(transport) => return transport.check().await,
+            Transport::Stdout(transport) => return transport.check().await,
+            Transport::Memory(transport) => return transport.check().await,
+        };
+
+        match transport.test_connection().await {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(EmailServiceError::ConfigError(format!(
+                "SMTP server {}:{} is not reachable",
+                self.config.host, self.config.port
+            ))),
+            Err(err) => Err(EmailServiceError::ConfigError(format!(
+                "SMTP server {}:{} connection error: {err}",
+                self.config.host, self.config.port
+            ))),
+        }
+    }
 }
 
 impl EmailService for EmailAdapter {
@@ -89,8 +233,143 @@ impl EmailService for EmailAdapter {
             token: request.token,
             email_from: self.config.forgotten_password_email_from.clone(),
             email_to: request.email.to_string(),
+            locale: request.locale,
+        };
+
+        Self::validate(msg.into_message(&self.templates)?).map(ForgottenPasswordEmailResponse)
+    }
+
+    fn magic_link(&self, request: MagicLinkEmailRequest) -> Result<MagicLinkEmailResponse, EmailServiceError> {
+        let msg = MagicLink {
+            app_name: APP_NAME.to_string(),
+            base_url: self.config.magic_link_base_url.clone(),
+            token: request.token,
+            email_from: self.config.magic_link_email_from.clone(),
+            email_to: request.email.to_string(),
+            locale: request.locale,
         };
 
-        self.send(msg.try_into()?).map(|_| ForgottenPasswordEmailResponse())
+        Self::validate(msg.into_message(&self.templates)?).map(MagicLinkEmailResponse)
+    }
+
+    fn invitation(&self, request: InvitationEmailRequest) -> Result<InvitationEmailResponse, EmailServiceError> {
+        let msg = Invitation {
+            app_name: APP_NAME.to_string(),
+            base_url: self.config.invitation_base_url.clone(),
+            token: request.token,
+            email_from: self.config.invitation_email_from.clone(),
+            email_to: request.email.to_string(),
+            locale: request.locale,
+        };
+
+        Self::validate(msg.into_message(&self.templates)?).map(InvitationEmailResponse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message() -> EmailMessage {
+        EmailMessage {
+            from_address: "from@test.com".to_string(),
+            to_addresses: vec!["to@test.com".to_string()],
+            subject: "Subject".to_string(),
+            text_body: Some("Body".to_string()),
+            html_body: Some("<p>Body</p>".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_smtp_tls_from_str() {
+        assert_eq!(SmtpTls::from_str("none"), Ok(SmtpTls::None));
+        assert_eq!(SmtpTls::from_str("STARTTLS"), Ok(SmtpTls::StartTls));
+        assert_eq!(SmtpTls::from_str(" tls "), Ok(SmtpTls::Tls));
+        assert!(matches!(SmtpTls::from_str(""), Err(EmailServiceError::ConfigError(_))));
+        assert!(matches!(
+            SmtpTls::from_str("ssl"),
+            Err(EmailServiceError::ConfigError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_email_adapter_new() {
+        let config = EmailConfig {
+            host: "smtp.test.com".to_string(),
+            port: 587,
+            username: Some("user".to_string()),
+            password: Some("password".to_string()),
+            tls: "starttls".to_string(),
+            pool_max_size: 5,
+            pool_idle_timeout: 60,
+            default_locale: "en".to_string(),
+            transport: "smtp".to_string(),
+            ..EmailConfig::default()
+        };
+        assert!(EmailAdapter::new(config.clone()).is_ok());
+        assert!(
+            EmailAdapter::new(EmailConfig {
+                tls: "tls".to_string(),
+                ..config.clone()
+            })
+            .is_ok()
+        );
+
+        let result = EmailAdapter::new(EmailConfig {
+            tls: "unknown".to_string(),
+            ..config.clone()
+        });
+        assert!(matches!(result, Err(EmailServiceError::ConfigError(_))));
+
+        let adapter = EmailAdapter::new(EmailConfig {
+            transport: "memory".to_string(),
+            ..config.clone()
+        })
+        .unwrap();
+        assert!(adapter.memory_transport().is_some());
+        assert!(EmailAdapter::new(config.clone()).unwrap().memory_transport().is_none());
+
+        let result = EmailAdapter::new(EmailConfig {
+            transport: "file".to_string(),
+            ..config.clone()
+        });
+        assert!(matches!(result, Err(EmailServiceError::ConfigError(_))));
+
+        let result = EmailAdapter::new(EmailConfig {
+            transport: "unknown".to_string(),
+            ..config
+        });
+        assert!(matches!(result, Err(EmailServiceError::ConfigError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_email_adapter_send_with_memory_transport() {
+        let adapter = EmailAdapter::new(EmailConfig {
+            transport: "memory".to_string(),
+            default_locale: "en".to_string(),
+            ..EmailConfig::default()
+        })
+        .unwrap();
+
+        assert!(adapter.check().await.is_ok());
+        adapter.send(&message()).await.unwrap();
+        assert_eq!(adapter.memory_transport().unwrap().messages(), vec![message()]);
+    }
+
+    #[test]
+    fn test_email_adapter_validate() {
+        assert_eq!(EmailAdapter::validate(message()), Ok(message()));
+
+        let result = EmailAdapter::validate(EmailMessage {
+            from_address: "from".to_string(),
+            ..message()
+        });
+        assert!(matches!(result, Err(EmailServiceError::InvalidEmailFrom(_))));
+
+        let result = EmailAdapter::validate(EmailMessage {
+            to_addresses: vec!["to@test.com".to_string(), "to".to_string()],
+            ..message()
+        });
+        assert!(matches!(result, Err(EmailServiceError::InvalidEmailTo(_))));
     }
 }
@@ -0,0 +1,300 @@
+//! Email templates rendered with Tera
+//!
+//! Built-in templates are embedded in the binary from `templates/emails`.
+//! Templates are named `<email>.<locale>.<kind>.tera` where kind is `subject`, `txt` or `html`.
+
+use crate::domain::services::email::EmailServiceError;
+use crate::domain::value_objects::locale::Locale;
+use std::error::Error;
+use std::fs;
+use tera::{Context, Tera};
+
+/// Prefix of the email template names
+const TEMPLATE_PREFIX: &str = "emails/";
+
+/// Locale of the built-in templates, used when no other locale matches
+const FALLBACK_LOCALE: &str = "en";
+
+/// Embed a template of `templates/emails`
+macro_rules! builtin_template {
+    ($name:literal) => {
+        ($name, include_str!(concat!("../../../templates/emails/", $name)))
+    };
+}
+
+/// Templates embedded in the binary
+const BUILTIN_TEMPLATES: [(&str, &str); 19] = [
+    builtin_template!("layout.html.tera"),
+    builtin_template!("forgotten_password.en.subject.tera"),
+    builtin_template!("forgotten_password.en.txt.tera"),
+    builtin_template!("forgotten_password.en.html.tera"),
+    builtin_template!("forgotten_password.fr.subject.tera"),
+    builtin_template!("forgotten_password.fr.txt.tera"),
+    builtin_template!("forgotten_password.fr.html.tera"),
+    builtin_template!("magic_link.en.subject.tera"),
+    builtin_template!("magic_link.en.txt.tera"),
+    builtin_template!("magic_link.en.html.tera"),
+    builtin_template!("magic_link.fr.subject.tera"),
+    builtin_template!("magic_link.fr.txt.tera"),
+    builtin_template!("magic_link.fr.html.tera"),
+    builtin_template!("invitation.en.subject.tera"),
+    builtin_template!("invitation.en.txt.tera"),
+    builtin_template!("invitation.en.html.tera"),
+    builtin_template!("invitation.fr.subject.tera"),
+    builtin_template!("invitation.fr.txt.tera"),
+    builtin_template!("invitation.fr.html.tera"),
+];
+
+/// Rendered email content
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderedEmail {
+    pub subject: String,
+    pub text_body: String,
+    pub html_body: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct EmailTemplates {
+    tera: Tera,
+
+    /// Locale used when the user has no locale or when no template matches its locale
+    default_locale: Locale,
+}
+
+impl EmailTemplates {
+    /// Load the built-in templates, then the templates of the `path` directory (if any)
+    ///
+    /// A template of the directory replaces the built-in template with the same file name,
+    /// so operators can customize or translate emails without recompiling.
+    pub fn new(default_locale: &str, path: Option<&str>) -> Result<Self, EmailServiceError> {
+        let default_locale = Locale::new(default_locale)
+            .map_err(|err| EmailServiceError::ConfigError(format!("invalid default email locale: {err}")))?;
+
+        let mut tera = Tera::default();
+        tera.add_raw_templates(
+            BUILTIN_TEMPLATES
+                .iter()
+                .map(|(name, content)| (format!("{TEMPLATE_PREFIX}{name}"), *content)),
+        )
+        .map_err(|err| EmailServiceError::ConfigError(tera_error(&err)))?;
+
+        if let Some(path) = path {
+            tera.add_raw_templates(Self::read_dir(path)?)
+                .map_err(|err| EmailServiceError::ConfigError(tera_error(&err)))?;
+        }
+
+        // Only the HTML body is escaped
+        tera.autoescape_on(vec![".html.tera"]);
+
+        Ok(Self { tera, default_locale })
+    }
+
+    /// Read the `.tera` files of a directory
+    fn read_dir(path: &str) -> Result<Vec<(String, String)>, EmailServiceError> {
+        let entries = fs::read_dir(path).map_err(|err| {
+            EmailServiceError::ConfigError(format!("cannot read email templates directory `{path}`: {err}"))
+        })?;
+
+        let mut templates = Vec::new();
+        for entry in entries {
+            let file = entry
+                .map_err(|err| EmailServiceError::ConfigError(format!("cannot read email template: {err}")))?
+                .path();
+            let Some(name) = file.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if !file.is_file() || !name.ends_with(".tera") {
+                continue;
+            }
+
+            let content = fs::read_to_string(&file).map_err(|err| {
+                EmailServiceError::ConfigError(format!("cannot read email template `{}`: {err}", file.display()))
+            })?;
+            templates.push((format!("{TEMPLATE_PREFIX}{name}"), content));
+        }
+
+        Ok(templates)
+    }
+
+    /// Candidate locales by priority: user locale, user language, default locale, default language
+    /// and finally the locale of the built-in templates
+    fn locales(&self, locale: Option<&Locale>) -> Vec<String> {
+        let mut candidates = Vec::new();
+        if let Some(locale) = locale {
+            candidates.extend([locale.value(), locale.language()]);
+        }
+        candidates.extend([
+            self.default_locale.value(),
+            self.default_locale.language(),
+            FALLBACK_LOCALE,
+        ]);
+
+        let mut locales: Vec<String> = Vec::new();
+        for candidate in candidates {
+            if !locales.iter().any(|locale| locale == candidate) {
+                locales.push(candidate.to_string());
+            }
+        }
+
+        locales
+    }
+
+    /// Render the first existing template `<name>.<locale>.<kind>.tera` of the candidate locales
+    fn render_kind(
+        &self,
+        name: &str,
+        kind: &str,
+        locales: &[String],
+        context: &Context,
+    ) -> Result<String, EmailServiceError> {
+        for locale in locales {
+            let template = format!("{TEMPLATE_PREFIX}{name}.{locale}.{kind}.tera");
+            if self.tera.get_template_names().any(|name| name == template) {
+                let mut context = context.clone();
+                context.insert("locale", locale);
+
+                return self
+                    .tera
+                    .render(&template, &context)
+                    .map_err(|err| EmailServiceError::TemplateError(tera_error(&err)));
+            }
+        }
+
+        Err(EmailServiceError::TemplateError(format!(
+            "no `{kind}` template found for email `{name}`"
+        )))
+    }
+
+    /// Render the subject and the bodies of an email in the given locale
+    pub fn render(
+        &self,
+        name: &str,
+        locale: Option<&Locale>,
+        context: &Context,
+    ) -> Result<RenderedEmail, EmailServiceError> {
+        let locales = self.locales(locale);
+
+        Ok(RenderedEmail {
+            subject: self.render_kind(name, "subject", &locales, context)?.trim().to_string(),
+            text_body: self.render_kind(name, "txt", &locales, context)?,
+            html_body: self.render_kind(name, "html", &locales, context)?,
+        })
+    }
+}
+
+/// Tera error message with its causes (the top-level message does not explain the error)
+fn tera_error(err: &tera::Error) -> String {
+    let mut message = err.to_string();
+    let mut source = err.source();
+    while let Some(cause) = source {
+        message.push_str(&format!(": {cause}"));
+        source = cause.source();
+    }
+
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> Context {
+        let mut context = Context::new();
+        context.insert("app_name", "My App");
+        context.insert("link", "https://test.com/token");
+        context
+    }
+
+    #[test]
+    fn test_email_templates_new() {
+        assert!(EmailTemplates::new("fr", None).is_ok());
+        assert!(matches!(
+            EmailTemplates::new("french", None),
+            Err(EmailServiceError::ConfigError(_))
+        ));
+        assert!(matches!(
+            EmailTemplates::new("en", Some("/path/not/found")),
+            Err(EmailServiceError::ConfigError(_))
+        ));
+    }
+
+    #[test]
+    fn test_email_templates_locales() {
+        let templates = EmailTemplates::new("fr", None).unwrap();
+
+        assert_eq!(templates.locales(None), vec!["fr", "en"]);
+        assert_eq!(
+            templates.locales(Some(&Locale::new("es-419").unwrap())),
+            vec!["es-419", "es", "fr", "en"]
+        );
+        assert_eq!(
+            templates.locales(Some(&Locale::new("fr-CA").unwrap())),
+            vec!["fr-CA", "fr", "en"]
+        );
+    }
+
+    #[test]
+    fn test_email_templates_render() {
+        let templates = EmailTemplates::new("en", None).unwrap();
+
+        let email = templates.render("magic_link", None, &context()).unwrap();
+        assert_eq!(email.subject, "My App - Sign in");
+        assert!(email.text_body.contains("Click here to sign in to My App:"));
+        assert!(email.text_body.contains("https://test.com/token"));
+        assert!(email.html_body.contains(r#"<html lang="en">"#));
+
+        // Fallback from `fr-CA` to `fr`
+        let email = templates
+            .render("magic_link", Some(&Locale::new("fr-CA").unwrap()), &context())
+            .unwrap();
+        assert_eq!(email.subject, "My App - Connexion");
+        assert!(email.html_body.contains(r#"<html lang="fr">"#));
+
+        // Fallback to the default locale
+        let email = templates
+            .render("invitation", Some(&Locale::new("de").unwrap()), &context())
+            .unwrap();
+        assert!(email.text_body.contains("You have been invited to join My App."));
+
+        assert!(matches!(
+            templates.render("unknown", None, &context()),
+            Err(EmailServiceError::TemplateError(_))
+        ));
+    }
+
+    #[test]
+    fn test_email_templates_render_escape_html() {
+        let templates = EmailTemplates::new("en", None).unwrap();
+        let mut context = context();
+        context.insert("app_name", "<b>App</b>");
+
+        let email = templates.render("magic_link", None, &context).unwrap();
+        assert!(email.text_body.contains("<b>App</b>"));
+        assert!(email.html_body.contains("&lt;b&gt;App&lt;&#x2F;b&gt;"));
+    }
+
+    #[test]
+    fn test_email_templates_override() {
+        let path = std::env::temp_dir().join(format!("auth2-email-templates-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&path).unwrap();
+        fs::write(path.join("magic_link.fr.subject.tera"), "Connexion à {{ app_name }}").unwrap();
+        fs::write(path.join("magic_link.de.subject.tera"), "Anmelden").unwrap();
+        fs::write(path.join("README.md"), "Not a template").unwrap();
+
+        let templates = EmailTemplates::new("en", path.to_str()).unwrap();
+        fs::remove_dir_all(&path).unwrap();
+
+        let email = templates
+            .render("magic_link", Some(&Locale::new("fr").unwrap()), &context())
+            .unwrap();
+        assert_eq!(email.subject, "Connexion à My App");
+        assert!(email.text_body.contains("Cliquez ici pour vous connecter"));
+
+        // New locale: missing bodies fall back to the default locale
+        let email = templates
+            .render("magic_link", Some(&Locale::new("de").unwrap()), &context())
+            .unwrap();
+        assert_eq!(email.subject, "Anmelden");
+        assert!(email.text_body.contains("Click here to sign in"));
+    }
+}
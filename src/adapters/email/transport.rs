@@ -0,0 +1,236 @@
+//! Email transports used instead of SMTP (files, logs or memory)
+
+use crate::adapters::email::EmailAdapter;
+use crate::domain::entities::email::EmailMessage;
+use crate::domain::services::email::{EmailServiceError, EmailTransport};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::{Arc, PoisonError, RwLock};
+use uuid::Uuid;
+
+/// Transport of the emails sent from the outbox
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmailTransportKind {
+    /// SMTP server
+    Smtp,
+
+    /// One `.eml` file per email in a directory
+    File,
+
+    /// Emails written in the logs (not allowed in production)
+    Stdout,
+
+    /// Emails kept in memory (not allowed in production)
+    Memory,
+}
+
+impl FromStr for EmailTransportKind {
+    type Err = EmailServiceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "smtp" => Ok(Self::Smtp),
+            "file" => Ok(Self::File),
+            "stdout" => Ok(Self::Stdout),
+            "memory" => Ok(Self::Memory),
+            _ => Err(EmailServiceError::ConfigError(format!(
+                "invalid email transport `{s}` (expected: smtp, file, stdout or memory)"
+            ))),
+        }
+    }
+}
+
+/// Write each email in a `.eml` file
+///
+/// Files are named with the sending date, so they are listed in sending order.
+#[derive(Debug, Clone)]
+pub struct FileTransport {
+    path: PathBuf,
+}
+
+impl FileTransport {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[async_trait]
+impl EmailTransport for FileTransport {
+    /// Write the email in the directory (created if it does not exist)
+    async fn send(&self, message: &EmailMessage) -> Result<(), EmailServiceError> {
+        let email = EmailAdapter::build_message(message)?;
+        self.check().await?;
+        let filename = format!("{}-{}.eml", Utc::now().format("%Y%m%d%H%M%S%6f"), Uuid::new_v4());
+
+        tokio::fs::write(self.path.join(filename), email.formatted())
+            .await
+            .map_err(|err| EmailServiceError::SendError(err.to_string()))
+    }
+
+    /// Create the directory if it does not exist
+    async fn check(&self) -> Result<(), EmailServiceError> {
+        tokio::fs::create_dir_all(&self.path).await.map_err(|err| {
+            EmailServiceError::ConfigError(format!("email directory {} error: {err}", self.path.display()))
+        })
+    }
+}
+
+/// Write the emails in the logs
+///
+/// The bodies contain tokens (password reset, magic link, invitation): they are only logged
+/// at the debug level.
+#[derive(Debug, Clone, Default)]
+pub struct StdoutTransport {}
+
+#[async_trait]
+impl EmailTransport for StdoutTransport {
+    /// Log the recipients and the subject, and the text body (or HTML body if there is no text) in debug
+    async fn send(&self, message: &EmailMessage) -> Result<(), EmailServiceError> {
+        info!(
+            from = %message.from_address,
+            to = %message.to_addresses.join(", "),
+            subject = %message.subject,
+            "Email sent"
+        );
+
+        if let Some(body) = message.text_body.as_deref().or(message.html_body.as_deref()) {
+            debug!(subject = %message.subject, "Email body:\n{body}");
+        }
+
+        Ok(())
+    }
+
+    async fn check(&self) -> Result<(), EmailServiceError> {
+        Ok(())
+    }
+}
+
+/// Maximum number of emails kept by the memory transport (the oldest ones are removed)
+pub const MEMORY_TRANSPORT_MAX_MESSAGES: usize = 1_000;
+
+/// Keep the last sent emails in memory
+///
+/// Clones share the same emails, so they can be read from another clone of the adapter.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryTransport {
+    messages: Arc<RwLock<VecDeque<EmailMessage>>>,
+}
+
+impl MemoryTransport {
+    /// Sent emails, in sending order
+    pub fn messages(&self) -> Vec<EmailMessage> {
+        self.messages
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Remove the sent emails
+    pub fn clear(&self) {
+        self.messages.write().unwrap_or_else(PoisonError::into_inner).clear();
+    }
+}
+
+#[async_trait]
+impl EmailTransport for MemoryTransport {
+    /// Keep the email, and remove the oldest one if the limit is reached
+    async fn send(&self, message: &EmailMessage) -> Result<(), EmailServiceError> {
+        let mut messages = self.messages.write().unwrap_or_else(PoisonError::into_inner);
+        if messages.len() >= MEMORY_TRANSPORT_MAX_MESSAGES {
+            messages.pop_front();
+        }
+        messages.push_back(message.clone());
+
+        Ok(())
+    }
+
+    async fn check(&self) -> Result<(), EmailServiceError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(subject: &str) -> EmailMessage {
+        EmailMessage {
+            from_address: "from@test.com".to_string(),
+            to_addresses: vec!["to@test.com".to_string()],
+            subject: subject.to_string(),
+            text_body: Some("Body".to_string()),
+            html_body: None,
+        }
+    }
+
+    #[test]
+    fn test_email_transport_kind_from_str() {
+        assert_eq!(EmailTransportKind::from_str("smtp"), Ok(EmailTransportKind::Smtp));
+        assert_eq!(EmailTransportKind::from_str("File"), Ok(EmailTransportKind::File));
+        assert_eq!(EmailTransportKind::from_str(" stdout "), Ok(EmailTransportKind::Stdout));
+        assert_eq!(EmailTransportKind::from_str("MEMORY"), Ok(EmailTransportKind::Memory));
+        assert!(matches!(
+            EmailTransportKind::from_str("maildir"),
+            Err(EmailServiceError::ConfigError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_file_transport() {
+        let path = std::env::temp_dir().join(format!("auth2-api-emails-{}", Uuid::new_v4()));
+        let transport = FileTransport::new(&path);
+
+        assert!(transport.send(&message("Subject")).await.is_ok());
+        assert!(transport.check().await.is_ok());
+
+        let files = std::fs::read_dir(&path)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(files.len(), 1);
+        let content = std::fs::read_to_string(files[0].path()).unwrap();
+        assert!(content.contains("Subject: Subject"));
+        assert!(content.contains("To: to@test.com"));
+
+        let result = transport
+            .send(&EmailMessage {
+                from_address: "from".to_string(),
+                ..message("Subject")
+            })
+            .await;
+        assert!(matches!(result, Err(EmailServiceError::InvalidEmailFrom(_))));
+
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_memory_transport() {
+        let transport = MemoryTransport::default();
+        let clone = transport.clone();
+
+        assert!(transport.check().await.is_ok());
+        transport.send(&message("First")).await.unwrap();
+        transport.send(&message("Second")).await.unwrap();
+        assert_eq!(clone.messages(), vec![message("First"), message("Second")]);
+
+        clone.clear();
+        assert!(transport.messages().is_empty());
+
+        // Only the last emails are kept
+        for i in 0..=MEMORY_TRANSPORT_MAX_MESSAGES {
+            transport.send(&message(&i.to_string())).await.unwrap();
+        }
+        let messages = transport.messages();
+        assert_eq!(messages.len(), MEMORY_TRANSPORT_MAX_MESSAGES);
+        assert_eq!(messages[0].subject, "1");
+        assert_eq!(
+            messages[MEMORY_TRANSPORT_MAX_MESSAGES - 1].subject,
+            MEMORY_TRANSPORT_MAX_MESSAGES.to_string()
+        );
+    }
+}
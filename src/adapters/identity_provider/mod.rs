@@ -0,0 +1,584 @@
+//! OpenID Connect identity provider adapter using `reqwest` crate
+
+use crate::domain::services::identity_provider::{
+    AuthorizationUrlRequest, ExchangeCodeRequest, IdentityClaims, IdentityProviderError, IdentityProviderService,
+};
+use crate::domain::value_objects::oidc_provider::OidcProvider;
+use async_trait::async_trait;
+use jsonwebtoken::jwk::{Jwk, JwkSet};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation, decode, decode_header};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::{Duration, Instant};
+
+/// Provider metadata (`/.well-known/openid-configuration`)
+#[derive(Debug, Clone, Deserialize)]
+struct ProviderMetadata {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    jwks_uri: Option<String>,
+    #[serde(default)]
+    id_token_signing_alg_values_supported: Vec<String>,
+}
+
+impl ProviderMetadata {
+    /// Algorithms accepted for the ID token signature
+    ///
+    /// RS256 is the OpenID Connect default when the provider does not advertise any algorithm.
+    /// Unknown algorithms (and `none`) are ignored.
+    fn id_token_algorithms(&self) -> Vec<Algorithm> {
+        if self.id_token_signing_alg_values_supported.is_empty() {
+            return vec![Algorithm::RS256];
+        }
+
+        self.id_token_signing_alg_values_supported
+            .iter()
+            .filter_map(|alg| Algorithm::from_str(alg).ok())
+            .collect()
+    }
+}
+
+/// Token endpoint response
+#[derive(Debug, Clone, Deserialize)]
+struct TokenResponse {
+    id_token: Option<String>,
+}
+
+/// ID token claims (`iss`, `aud` and `exp` are checked by the validation)
+#[derive(Debug, Clone, Deserialize)]
+struct IdTokenClaims {
+    sub: String,
+    nonce: Option<String>,
+    email: Option<String>,
+    #[serde(default)]
+    email_verified: bool,
+    given_name: Option<String>,
+    family_name: Option<String>,
+}
+
+/// Documents fetched from the providers, by issuer, with their fetch time
+#[derive(Debug)]
+struct Cache<T> {
+    ttl: Duration,
+    entries: RwLock<HashMap<String, (T, Instant)>>,
+}
+
+impl<T: Clone> Cache<T> {
+    fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Document of the issuer, if it has not expired
+    fn get(&self, issuer: &str) -> Option<T> {
+        self.entries
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(issuer)
+            .filter(|(_, fetched_at)| fetched_at.elapsed() < self.ttl)
+            .map(|(document, _)| document.clone())
+    }
+
+    /// Store the document of the issuer (nothing is stored if the cache is disabled)
+    fn set(&self, issuer: &str, document: T) {
+        if self.ttl.is_zero() {
+            return;
+        }
+
+        let mut entries = self.entries.write().unwrap_or_else(PoisonError::into_inner);
+        entries.retain(|_, (_, fetched_at)| fetched_at.elapsed() < self.ttl);
+        entries.insert(issuer.to_string(), (document, Instant::now()));
+    }
+}
+
+/// OpenID Connect adapter
+///
+/// The discovery metadata and the JWKS are cached by issuer, so a login only requests the token endpoint
+/// while they are cached. The JWKS is fetched again when the ID token is signed with an unknown key (key rotation).
+#[derive(Debug, Clone)]
+pub struct OidcAdapter {
+    client: reqwest::Client,
+    metadata: Arc<Cache<ProviderMetadata>>,
+    jwks: Arc<Cache<JwkSet>>,
+}
+
+impl OidcAdapter {
+    /// New adapter with a request timeout and a cache lifetime (in second, 0 to disable)
+    pub fn new(timeout: u64, cache_ttl: u64) -> Result<Self, IdentityProviderError> {
+        let mut builder = reqwest::Client::builder();
+        if timeout > 0 {
+            builder = builder.timeout(Duration::from_secs(timeout));
+        }
+        let client = builder
+            .build()
+            .map_err(|err| IdentityProviderError::RequestError(err.to_string()))?;
+        let cache_ttl = Duration::from_secs(cache_ttl);
+
+        Ok(Self {
+            client,
+            metadata: Arc::new(Cache::new(cache_ttl)),
+            jwks: Arc::new(Cache::new(cache_ttl)),
+        })
+    }
+
+    /// Get a JSON document from the provider
+    async fn fetch<T: DeserializeOwned>(&self, url: &str) -> Result<T, IdentityProviderError> {
+        self.client
+            .get(url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|err| IdentityProviderError::RequestError(err.to_string()))?
+            .json()
+            .await
+            .map_err(|err| IdentityProviderError::InvalidResponse(err.to_string()))
+    }
+
+    /// Get provider metadata with OpenID Connect discovery
+    async fn discover(&self, provider: &OidcProvider) -> Result<ProviderMetadata, IdentityProviderError> {
+        if let Some(metadata) = self.metadata.get(provider.issuer()) {
+            return Ok(metadata);
+        }
+
+        let url = format!("{}/.well-known/openid-configuration", provider.issuer());
+        let metadata: ProviderMetadata = self.fetch(&url).await?;
+
+        if metadata.issuer.trim_end_matches('/') != provider.issuer() {
+            return Err(IdentityProviderError::InvalidResponse(format!(
+                "issuer mismatch: `{}`",
+                metadata.issuer
+            )));
+        }
+        self.metadata.set(provider.issuer(), metadata.clone());
+
+        Ok(metadata)
+    }
+
+    /// Provider key used to sign the ID token
+    ///
+    /// The cached JWKS is used unless it does not contain the key, then the JWKS is fetched again.
+    async fn signing_key(
+        &self,
+        provider: &OidcProvider,
+        metadata: &ProviderMetadata,
+        kid: Option<&str>,
+    ) -> Result<Jwk, IdentityProviderError> {
+        let find = |jwks: &JwkSet| {
+            match kid {
+                Some(kid) => jwks.find(kid),
+                None => jwks.keys.first(),
+            }
+            .cloned()
+        };
+
+        if let Some(jwk) = self.jwks.get(provider.issuer()).as_ref().and_then(find) {
+            return Ok(jwk);
+        }
+
+        let jwks_uri = metadata
+            .jwks_uri
+            .as_deref()
+            .ok_or_else(|| IdentityProviderError::InvalidResponse("missing jwks_uri".to_string()))?;
+        let jwks: JwkSet = self.fetch(jwks_uri).await?;
+        let jwk = find(&jwks);
+        self.jwks.set(provider.issuer(), jwks);
+
+        jwk.ok_or_else(|| IdentityProviderError::InvalidIdToken("unknown signing key".to_string()))
+    }
+
+    /// Key used to check the ID token signature
+    ///
+    /// HMAC signatures use the client secret, other algorithms use the provider JWKS.
+    async fn decoding_key(
+        &self,
+        provider: &OidcProvider,
+        metadata: &ProviderMetadata,
+        algorithm: Algorithm,
+        kid: Option<String>,
+    ) -> Result<DecodingKey, IdentityProviderError> {
+        if matches!(algorithm, Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512) {
+            return Ok(DecodingKey::from_secret(provider.client_secret().as_bytes()));
+        }
+
+        let jwk = self.signing_key(provider, metadata, kid.as_deref()).await?;
+
+        DecodingKey::from_jwk(&jwk).map_err(|err| IdentityProviderError::InvalidIdToken(err.to_string()))
+    }
+}
+
+#[async_trait]
+impl IdentityProviderService for OidcAdapter {
+    #[instrument(skip(self), name = "oidc_adapter_authorization_url")]
+    async fn authorization_url(&self, request: AuthorizationUrlRequest) -> Result<String, IdentityProviderError> {
+        let metadata = self.discover(&request.provider).await?;
+
+        let query = serde_urlencoded::to_string([
+            ("response_type", "code"),
+            ("client_id", request.provider.client_id()),
+            ("redirect_uri", &request.redirect_uri),
+            ("scope", &request.provider.scopes().join(" ")),
+            ("state", &request.state),
+            ("nonce", &request.nonce),
+        ])
+        .map_err(|err| IdentityProviderError::RequestError(err.to_string()))?;
+        let separator = match metadata.authorization_endpoint.contains('?') {
+            true => '&',
+            false => '?',
+        };
+
+        Ok(format!("{}{separator}{query}", metadata.authorization_endpoint))
+    }
+
+    #[instrument(skip(self), name = "oidc_adapter_exchange_code")]
+    async fn exchange_code(&self, request: ExchangeCodeRequest) -> Result<IdentityClaims, IdentityProviderError> {
+        let provider = &request.provider;
+        let metadata = self.discover(provider).await?;
+
+        // Exchange the code with client secret basic authentication
+        let response: TokenResponse = self
+            .client
+            .post(&metadata.token_endpoint)
+            .basic_auth(provider.client_id(), Some(provider.client_secret()))
+            .form(&[
+                ("grant_type", "authorization_code"),
+                ("code", &request.code),
+                ("redirect_uri", &request.redirect_uri),
+            ])
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|err| IdentityProviderError::RequestError(err.to_string()))?
+            .json()
+            .await
+            .map_err(|err| IdentityProviderError::InvalidResponse(err.to_string()))?;
+        let id_token = response
+            .id_token
+            .ok_or_else(|| IdentityProviderError::InvalidResponse("missing id_token".to_string()))?;
+
+        // Validate the ID token
+        // The algorithm comes from the token header, so it must be one advertised by the provider
+        let header = decode_header(&id_token).map_err(|err| IdentityProviderError::InvalidIdToken(err.to_string()))?;
+        if !metadata.id_token_algorithms().contains(&header.alg) {
+            return Err(IdentityProviderError::InvalidIdToken(format!(
+                "unsupported algorithm: {:?}",
+                header.alg
+            )));
+        }
+        let key = self.decoding_key(provider, &metadata, header.alg, header.kid).await?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[&metadata.issuer]);
+        validation.set_audience(&[provider.client_id()]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+
+        let claims = decode::<IdTokenClaims>(&id_token, &key, &validation)
+            .map_err(|err| IdentityProviderError::InvalidIdToken(err.to_string()))?
+            .claims;
+        if claims.nonce.as_deref() != Some(request.nonce.as_str()) {
+            return Err(IdentityProviderError::InvalidIdToken("nonce mismatch".to_string()));
+        }
+
+        Ok(IdentityClaims {
+            subject: claims.sub,
+            email: claims.email,
+            email_verified: claims.email_verified,
+            given_name: claims.given_name,
+            family_name: claims.family_name,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::extract::State;
+    use axum::http::{HeaderMap, StatusCode, header};
+    use axum::routing::{get, post};
+    use axum::{Form, Json, Router};
+    use jsonwebtoken::{EncodingKey, Header, encode};
+    use serde_json::{Value, json};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const CLIENT_ID: &str = "client-id";
+    const CLIENT_SECRET: &str = "client-secret";
+    const NONCE: &str = "nonce";
+    const SUBJECT: &str = "partner-user-42";
+
+    /// Start a local identity provider advertising the ID token algorithms and return its issuer URL
+    ///
+    /// ID tokens are always signed with HS256.
+    async fn stub_idp(algorithms: &[&str]) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let issuer = format!("http://{}", listener.local_addr().unwrap());
+        let discovery = json!({
+            "issuer": issuer,
+            "authorization_endpoint": format!("{issuer}/authorize"),
+            "token_endpoint": format!("{issuer}/token"),
+            "jwks_uri": format!("{issuer}/jwks"),
+            "id_token_signing_alg_values_supported": algorithms,
+        });
+
+        let app = Router::new()
+            .route(
+                "/.well-known/openid-configuration",
+                get(move || async move { Json(discovery) }),
+            )
+            .route("/token", post(stub_token))
+            .with_state(issuer.clone());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        issuer
+    }
+
+    /// Token endpoint: `valid-code` returns an ID token for the client, `other-audience` for another client
+    async fn stub_token(
+        State(issuer): State<String>,
+        headers: HeaderMap,
+        Form(form): Form<HashMap<String, String>>,
+    ) -> (StatusCode, Json<Value>) {
+        let credentials = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| http_auth_basic::Credentials::from_header(value.to_string()).ok());
+        if credentials.is_none_or(|c| c.user_id != CLIENT_ID || c.password != CLIENT_SECRET) {
+            return (StatusCode::UNAUTHORIZED, Json(json!({"error": "invalid_client"})));
+        }
+
+        let audience = match form.get("code").map(String::as_str) {
+            Some("valid-code") => CLIENT_ID,
+            Some("other-audience") => "other-client",
+            _ => return (StatusCode::BAD_REQUEST, Json(json!({"error": "invalid_grant"}))),
+        };
+        let now = chrono::Utc::now().timestamp();
+        let claims = json!({
+            "iss": issuer,
+            "aud": audience,
+            "sub": SUBJECT,
+            "iat": now,
+            "exp": now + 300,
+            "nonce": NONCE,
+            "email": "john.doe@test.com",
+            "email_verified": true,
+            "given_name": "John",
+            "family_name": "Doe",
+        });
+        let id_token = encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(CLIENT_SECRET.as_bytes()),
+        )
+        .unwrap();
+
+        (
+            StatusCode::OK,
+            Json(json!({"id_token": id_token, "token_type": "Bearer"})),
+        )
+    }
+
+    /// Requests received by the stub of [`stub_jwks_idp`] and the IDs of the keys it returns
+    #[derive(Debug, Default)]
+    struct JwksStub {
+        discovery_requests: AtomicUsize,
+        jwks_requests: AtomicUsize,
+        kids: Mutex<Vec<String>>,
+    }
+
+    /// Start a local identity provider serving only its discovery metadata and its JWKS
+    async fn stub_jwks_idp(stub: Arc<JwksStub>) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let issuer = format!("http://{}", listener.local_addr().unwrap());
+        let discovery = json!({
+            "issuer": issuer,
+            "authorization_endpoint": format!("{issuer}/authorize"),
+            "token_endpoint": format!("{issuer}/token"),
+            "jwks_uri": format!("{issuer}/jwks"),
+        });
+
+        let app = Router::new()
+            .route(
+                "/.well-known/openid-configuration",
+                get(|State(stub): State<Arc<JwksStub>>| async move {
+                    stub.discovery_requests.fetch_add(1, Ordering::SeqCst);
+                    Json(discovery)
+                }),
+            )
+            .route(
+                "/jwks",
+                get(|State(stub): State<Arc<JwksStub>>| async move {
+                    stub.jwks_requests.fetch_add(1, Ordering::SeqCst);
+                    let keys = stub
+                        .kids
+                        .lock()
+                        .unwrap()
+                        .iter()
+                        .map(|kid| json!({"kty": "oct", "kid": kid, "k": "c2VjcmV0"}))
+                        .collect::<Vec<_>>();
+                    Json(json!({ "keys": keys }))
+                }),
+            )
+            .with_state(stub);
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        issuer
+    }
+
+    fn exchange_request(issuer: &str, code: &str, nonce: &str) -> ExchangeCodeRequest {
+        ExchangeCodeRequest {
+            provider: OidcProvider::new(issuer, CLIENT_ID, CLIENT_SECRET, vec![]).unwrap(),
+            redirect_uri: "http://localhost/callback".to_string(),
+            code: code.to_string(),
+            nonce: nonce.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_authorization_url() {
+        let issuer = stub_idp(&["HS256"]).await;
+        let adapter = OidcAdapter::new(5, 60).unwrap();
+
+        let url = adapter
+            .authorization_url(AuthorizationUrlRequest {
+                provider: OidcProvider::new(&issuer, CLIENT_ID, CLIENT_SECRET, vec!["email".to_string()]).unwrap(),
+                redirect_uri: "http://localhost/callback".to_string(),
+                state: "state".to_string(),
+                nonce: NONCE.to_string(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            url,
+            format!(
+                "{issuer}/authorize?response_type=code&client_id=client-id&redirect_uri=http%3A%2F%2Flocalhost%2Fcallback&scope=openid+email&state=state&nonce=nonce"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exchange_code() {
+        let issuer = stub_idp(&["HS256"]).await;
+        let adapter = OidcAdapter::new(5, 60).unwrap();
+
+        let claims = adapter
+            .exchange_code(exchange_request(&issuer, "valid-code", NONCE))
+            .await
+            .unwrap();
+        assert_eq!(
+            claims,
+            IdentityClaims {
+                subject: SUBJECT.to_string(),
+                email: Some("john.doe@test.com".to_string()),
+                email_verified: true,
+                given_name: Some("John".to_string()),
+                family_name: Some("Doe".to_string()),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exchange_code_errors() {
+        let issuer = stub_idp(&["HS256"]).await;
+        let adapter = OidcAdapter::new(5, 60).unwrap();
+
+        let result = adapter
+            .exchange_code(exchange_request(&issuer, "invalid-code", NONCE))
+            .await;
+        assert!(matches!(result, Err(IdentityProviderError::RequestError(_))));
+
+        let result = adapter
+            .exchange_code(exchange_request(&issuer, "other-audience", NONCE))
+            .await;
+        assert!(matches!(result, Err(IdentityProviderError::InvalidIdToken(_))));
+
+        let result = adapter
+            .exchange_code(exchange_request(&issuer, "valid-code", "other-nonce"))
+            .await;
+        assert_eq!(
+            result,
+            Err(IdentityProviderError::InvalidIdToken("nonce mismatch".to_string()))
+        );
+
+        let result = adapter
+            .exchange_code(ExchangeCodeRequest {
+                provider: OidcProvider::new(&issuer, CLIENT_ID, "invalid-secret", vec![]).unwrap(),
+                ..exchange_request(&issuer, "valid-code", NONCE)
+            })
+            .await;
+        assert!(matches!(result, Err(IdentityProviderError::RequestError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_exchange_code_unsupported_algorithm() {
+        let adapter = OidcAdapter::new(5, 60).unwrap();
+
+        // HS256 token (signed with the client secret) from an RS256 provider
+        let issuer = stub_idp(&["RS256"]).await;
+        let result = adapter
+            .exchange_code(exchange_request(&issuer, "valid-code", NONCE))
+            .await;
+        assert_eq!(
+            result,
+            Err(IdentityProviderError::InvalidIdToken(
+                "unsupported algorithm: HS256".to_string()
+            ))
+        );
+
+        // RS256 by default
+        let issuer = stub_idp(&[]).await;
+        let result = adapter
+            .exchange_code(exchange_request(&issuer, "valid-code", NONCE))
+            .await;
+        assert_eq!(
+            result,
+            Err(IdentityProviderError::InvalidIdToken(
+                "unsupported algorithm: HS256".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_discovery_and_jwks_cache() {
+        let stub = Arc::new(JwksStub {
+            kids: Mutex::new(vec!["key-1".to_string()]),
+            ..JwksStub::default()
+        });
+        let issuer = stub_jwks_idp(stub.clone()).await;
+        let provider = OidcProvider::new(&issuer, CLIENT_ID, CLIENT_SECRET, vec![]).unwrap();
+        let adapter = OidcAdapter::new(5, 60).unwrap();
+
+        let metadata = adapter.discover(&provider).await.unwrap();
+        adapter.discover(&provider).await.unwrap();
+        assert_eq!(stub.discovery_requests.load(Ordering::SeqCst), 1);
+
+        let jwk = adapter.signing_key(&provider, &metadata, Some("key-1")).await.unwrap();
+        assert_eq!(jwk.common.key_id, Some("key-1".to_string()));
+        adapter.signing_key(&provider, &metadata, None).await.unwrap();
+        assert_eq!(stub.jwks_requests.load(Ordering::SeqCst), 1);
+
+        // Key rotation: the JWKS is fetched again for an unknown key
+        *stub.kids.lock().unwrap() = vec!["key-2".to_string()];
+        let jwk = adapter.signing_key(&provider, &metadata, Some("key-2")).await.unwrap();
+        assert_eq!(jwk.common.key_id, Some("key-2".to_string()));
+        assert_eq!(stub.jwks_requests.load(Ordering::SeqCst), 2);
+
+        let result = adapter.signing_key(&provider, &metadata, Some("key-1")).await;
+        assert_eq!(
+            result,
+            Err(IdentityProviderError::InvalidIdToken("unknown signing key".to_string()))
+        );
+        assert_eq!(stub.jwks_requests.load(Ordering::SeqCst), 3);
+
+        // Disabled cache
+        let adapter = OidcAdapter::new(5, 0).unwrap();
+        adapter.discover(&provider).await.unwrap();
+        adapter.discover(&provider).await.unwrap();
+        assert_eq!(stub.discovery_requests.load(Ordering::SeqCst), 3);
+    }
+}
@@ -2,3 +2,5 @@
 
 pub mod database;
 pub mod email;
+pub mod identity_provider;
+pub mod webhook;
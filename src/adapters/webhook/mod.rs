@@ -0,0 +1,310 @@
+//! Webhook adapter using `reqwest` crate
+
+use crate::domain::entities::webhook::WebhookDelivery;
+use crate::domain::services::webhook::{
+    WEBHOOK_EVENT_HEADER, WEBHOOK_ID_HEADER, WEBHOOK_SIGNATURE_HEADER, WEBHOOK_TIMESTAMP_HEADER, WebhookService,
+    WebhookServiceError,
+};
+use crate::domain::value_objects::datetime::UtcDateTime;
+use async_trait::async_trait;
+use reqwest::Url;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::header::CONTENT_TYPE;
+use reqwest::redirect::Policy;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct WebhookAdapter {
+    client: reqwest::Client,
+
+    /// Allow `http` URLs and local or private hosts (development only)
+    allow_local: bool,
+}
+
+impl WebhookAdapter {
+    /// New adapter with a request timeout (in second, 0 to disable)
+    ///
+    /// Redirects are never followed. Unless `allow_local` is set, only `https` URLs are allowed
+    /// and the hosts resolving to a local or private address are rejected.
+    pub fn new(timeout: u64, allow_local: bool) -> Result<Self, WebhookServiceError> {
+        let mut builder = reqwest::Client::builder().redirect(Policy::none());
+        if timeout > 0 {
+            builder = builder.timeout(Duration::from_secs(timeout));
+        }
+        if !allow_local {
+            builder = builder.dns_resolver(Arc::new(PublicResolver));
+        }
+        let client = builder
+            .build()
+            .map_err(|err| WebhookServiceError::RequestError(err.to_string()))?;
+
+        Ok(Self { client, allow_local })
+    }
+}
+
+/// DNS resolver only returning public addresses
+///
+/// The host is resolved when the request is sent, so a webhook host cannot be
+/// changed afterwards to target an internal service.
+struct PublicResolver;
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .filter(|addr| is_public_ip(addr.ip()))
+                .collect();
+            if addrs.is_empty() {
+                return Err(format!("{} does not resolve to a public address", name.as_str()).into());
+            }
+
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// Check if an IP address is publicly routable
+fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, ..] = ip.octets();
+            !(ip.is_unspecified()
+                || ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_broadcast()
+                || ip.is_documentation()
+                || ip.is_multicast()
+                // Shared address space (100.64.0.0/10)
+                || (a == 100 && (b & 0xc0) == 64)
+                // Reserved (0.0.0.0/8 and 240.0.0.0/4)
+                || a == 0
+                || a >= 240)
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_ip(IpAddr::V4(ip)),
+            None => {
+                !(ip.is_unspecified()
+                    || ip.is_loopback()
+                    || ip.is_multicast()
+                    || ip.is_unique_local()
+                    || ip.is_unicast_link_local())
+            }
+        },
+    }
+}
+
+#[async_trait]
+impl WebhookService for WebhookAdapter {
+    fn check_url(&self, url: &str) -> Result<(), WebhookServiceError> {
+        let url = Url::parse(url).map_err(|err| WebhookServiceError::UrlNotAllowed(err.to_string()))?;
+        if self.allow_local {
+            return Ok(());
+        }
+
+        if url.scheme() != "https" {
+            return Err(WebhookServiceError::UrlNotAllowed("https is required".to_string()));
+        }
+        let host = url
+            .host_str()
+            .unwrap_or_default()
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .trim_end_matches('.')
+            .to_lowercase();
+        let local = match host.parse::<IpAddr>() {
+            Ok(ip) => !is_public_ip(ip),
+            Err(_) => host.is_empty() || host == "localhost" || host.ends_with(".localhost"),
+        };
+        if local {
+            return Err(WebhookServiceError::UrlNotAllowed(
+                "local and private hosts are not allowed".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), name = "webhook_adapter_send")]
+    async fn send(&self, delivery: &WebhookDelivery) -> Result<(), WebhookServiceError> {
+        self.check_url(&delivery.url)?;
+
+        let timestamp = UtcDateTime::now().timestamp();
+        let signature = delivery.secret.sign(timestamp, &delivery.payload);
+
+        let response = self
+            .client
+            .post(&delivery.url)
+            .header(CONTENT_TYPE, "application/json")
+            .header(WEBHOOK_ID_HEADER, delivery.event_id.to_string())
+            .header(WEBHOOK_EVENT_HEADER, delivery.event.as_str())
+            .header(WEBHOOK_TIMESTAMP_HEADER, timestamp.to_string())
+            .header(WEBHOOK_SIGNATURE_HEADER, format!("sha256={signature}"))
+            .body(delivery.payload.clone())
+            .send()
+            .await
+            .map_err(|err| WebhookServiceError::RequestError(err.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(WebhookServiceError::UnexpectedStatus(response.status().as_u16()));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::webhook::WebhookEvent;
+    use crate::domain::value_objects::id::Id;
+    use crate::domain::value_objects::webhook_secret::WebhookSecret;
+    use axum::Router;
+    use axum::extract::State;
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::response::Redirect;
+    use axum::routing::post;
+    use std::sync::{Arc, Mutex};
+
+    const SECRET: &str = "whsec_test";
+
+    /// Received requests (headers and body)
+    type Received = Arc<Mutex<Vec<(HeaderMap, String)>>>;
+
+    /// Start a local webhook receiver and return its URL
+    ///
+    /// `/ok` accepts the payloads, `/error` responds with a server error and `/redirect` redirects to `/ok`.
+    async fn stub_receiver(received: Received) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let app = Router::new()
+            .route(
+                "/ok",
+                post(
+                    |State(received): State<Received>, headers: HeaderMap, body: String| async move {
+                        received.lock().unwrap().push((headers, body));
+                        StatusCode::NO_CONTENT
+                    },
+                ),
+            )
+            .route("/error", post(|| async { StatusCode::INTERNAL_SERVER_ERROR }))
+            .route("/redirect", post(|| async { Redirect::temporary("/ok") }))
+            .with_state(received);
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        url
+    }
+
+    fn delivery(url: String) -> WebhookDelivery {
+        WebhookDelivery {
+            id: Id::new().unwrap(),
+            webhook_id: Id::new().unwrap(),
+            url,
+            secret: WebhookSecret::new(SECRET),
+            event_id: Id::new().unwrap(),
+            event: WebhookEvent::UserCreated,
+            payload: r#"{"event":"user.created"}"#.to_string(),
+            attempts: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_signed_payload() {
+        let received = Received::default();
+        let url = stub_receiver(received.clone()).await;
+        let adapter = WebhookAdapter::new(5, true).unwrap();
+        let delivery = delivery(format!("{url}/ok"));
+
+        adapter.send(&delivery).await.unwrap();
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        let (headers, body) = &received[0];
+        let header = |name: &str| headers.get(name).unwrap().to_str().unwrap().to_string();
+        let timestamp: i64 = header(WEBHOOK_TIMESTAMP_HEADER).parse().unwrap();
+
+        assert_eq!(body, &delivery.payload);
+        assert_eq!(header(WEBHOOK_ID_HEADER), delivery.event_id.to_string());
+        assert_eq!(header(WEBHOOK_EVENT_HEADER), "user.created");
+        assert_eq!(
+            header(WEBHOOK_SIGNATURE_HEADER),
+            format!("sha256={}", WebhookSecret::new(SECRET).sign(timestamp, body))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_error_status() {
+        let url = stub_receiver(Received::default()).await;
+        let adapter = WebhookAdapter::new(5, true).unwrap();
+
+        let result = adapter.send(&delivery(format!("{url}/error"))).await;
+        assert_eq!(result, Err(WebhookServiceError::UnexpectedStatus(500)));
+
+        let result = adapter.send(&delivery(format!("{url}/unknown"))).await;
+        assert_eq!(result, Err(WebhookServiceError::UnexpectedStatus(404)));
+    }
+
+    #[tokio::test]
+    async fn test_send_redirect_not_followed() {
+        let received = Received::default();
+        let url = stub_receiver(received.clone()).await;
+        let adapter = WebhookAdapter::new(5, true).unwrap();
+
+        let result = adapter.send(&delivery(format!("{url}/redirect"))).await;
+        assert_eq!(result, Err(WebhookServiceError::UnexpectedStatus(307)));
+        assert!(received.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_send_local_url_not_allowed() {
+        let received = Received::default();
+        let url = stub_receiver(received.clone()).await;
+        let adapter = WebhookAdapter::new(5, false).unwrap();
+
+        let result = adapter.send(&delivery(format!("{url}/ok"))).await;
+        assert!(matches!(result, Err(WebhookServiceError::UrlNotAllowed(_))));
+        assert!(received.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_check_url() {
+        let adapter = WebhookAdapter::new(5, false).unwrap();
+        for url in [
+            "https://app.test.com/webhooks",
+            "https://93.184.215.14/webhooks",
+            "https://[2606:4700::1111]/webhooks",
+        ] {
+            assert_eq!(adapter.check_url(url), Ok(()), "{url}");
+        }
+        for url in [
+            "not an url",
+            "http://app.test.com/webhooks",
+            "https://localhost/webhooks",
+            "https://api.localhost./webhooks",
+            "https://127.0.0.1/webhooks",
+            "https://0.0.0.0/webhooks",
+            "https://10.0.0.1/webhooks",
+            "https://172.16.0.1/webhooks",
+            "https://192.168.1.1/webhooks",
+            "https://100.64.0.1/webhooks",
+            "https://169.254.169.254/latest/meta-data",
+            "https://[::1]/webhooks",
+            "https://[fd00::1]/webhooks",
+            "https://[fe80::1]/webhooks",
+            "https://[::ffff:169.254.169.254]/webhooks",
+        ] {
+            assert!(
+                matches!(adapter.check_url(url), Err(WebhookServiceError::UrlNotAllowed(_))),
+                "{url}"
+            );
+        }
+
+        let adapter = WebhookAdapter::new(5, true).unwrap();
+        assert_eq!(adapter.check_url("http://127.0.0.1:8080/webhooks"), Ok(()));
+        assert!(adapter.check_url("not an url").is_err());
+    }
+}
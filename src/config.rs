@@ -10,6 +10,9 @@ pub enum ConfigError {
 
     #[error("Deserialize configuration error: {0}")]
     DeserializeError(String),
+
+    #[error("Invalid configuration: {0}")]
+    InvalidValue(String),
 }
 
 /// Represents configuration structure
@@ -53,6 +56,8 @@ pub struct Config {
     pub response_body_max_size: usize,
     /// Enable compression
     pub compression_enable: bool,
+    /// Reverse proxy IP addresses trusted for the `X-Forwarded-For` header (delimited by a comma)
+    pub trusted_proxies: String,
 
     /// JWT algorithm
     pub jwt_algorithm: String,
@@ -75,6 +80,10 @@ pub struct Config {
     /// Basic Auth password
     pub basic_auth_password: String,
 
+    /// Email transport: `smtp`, `file` (`.eml` files), `stdout` (logs) or `memory` (development only)
+    pub email_transport: String,
+    /// Directory of the emails written by the `file` transport
+    pub email_file_path: String,
     /// SMTP host
     pub smtp_host: String,
     /// SMTP port
@@ -85,6 +94,27 @@ pub struct Config {
     pub smtp_username: String,
     /// SMTP password
     pub smtp_password: String,
+    /// SMTP TLS mode: `none`, `starttls` or `tls` (implicit TLS)
+    pub smtp_tls: String,
+    /// Maximum number of SMTP connections in the pool
+    pub smtp_pool_max_size: u32,
+    /// Time after which an idle SMTP connection is closed (in second)
+    pub smtp_pool_idle_timeout: u64,
+    /// Check the connection to the SMTP server (or the other email transport) at startup
+    pub smtp_check_on_startup: bool,
+
+    /// Interval between two email sendings from the outbox (in second, 0 to disable the worker)
+    pub email_worker_interval: u64,
+    /// Maximum number of emails sent at each interval
+    pub email_batch_size: u32,
+    /// Number of attempts before an email is marked as dead
+    pub email_max_attempts: u32,
+    /// Delay before the first email retry, doubled after each attempt (in second)
+    pub email_backoff_base: u64,
+    /// Locale of the emails when the user has no locale (Ex.: en)
+    pub email_default_locale: String,
+    /// Directory of email templates replacing the built-in ones (empty to use only the built-in templates)
+    pub email_templates_path: String,
 
     /// Forgotten password expiration duration (in hour)
     pub forgotten_password_expiration_duration: i64,
@@ -92,6 +122,66 @@ pub struct Config {
     pub forgotten_password_base_url: String,
     /// Forgotten password email from
     pub forgotten_password_email_from: String,
+
+    /// Magic link expiration duration (in minute)
+    pub magic_link_expiration_duration: i64,
+    /// Magic link base URL for link (Ex.: http://localhost)
+    pub magic_link_base_url: String,
+    /// Magic link email from
+    pub magic_link_email_from: String,
+
+    /// Invitation expiration duration (in hour)
+    pub invitation_expiration_duration: i64,
+    /// Invitation base URL for link (Ex.: http://localhost)
+    pub invitation_base_url: String,
+    /// Invitation email from
+    pub invitation_email_from: String,
+
+    /// OpenID Connect base URL for the login callback (Ex.: http://localhost)
+    pub oidc_redirect_base_url: String,
+    /// OpenID Connect login expiration duration (in minute)
+    pub oidc_login_expiration_duration: i64,
+    /// OpenID Connect provider requests timeout (in second)
+    pub oidc_request_timeout: u64,
+    /// OpenID Connect discovery and JWKS cache lifetime (in second, 0 to disable)
+    pub oidc_cache_ttl: u64,
+
+    /// Interval between two webhook deliveries (in second, 0 to disable the worker)
+    pub webhook_worker_interval: u64,
+    /// Maximum number of webhook deliveries sent at each interval
+    pub webhook_batch_size: u32,
+    /// Number of attempts before a webhook delivery is marked as failed
+    pub webhook_max_attempts: u32,
+    /// Delay before the first webhook retry, doubled after each attempt (in second)
+    pub webhook_backoff_base: u64,
+    /// Webhook requests timeout (in second)
+    pub webhook_request_timeout: u64,
+
+    /// Password lifetime before a change is required (in day, 0 to disable)
+    pub password_lifetime: i64,
+    /// Number of previous passwords which cannot be reused (0 to disable)
+    pub password_history_size: u32,
+    /// Password minimum score (from 0 to 100)
+    pub password_score_min: f64,
+    /// Password minimum length
+    pub password_length_min: usize,
+    /// Password must contain at least one lowercase letter
+    pub password_require_lowercase: bool,
+    /// Password must contain at least one uppercase letter
+    pub password_require_uppercase: bool,
+    /// Password must contain at least one number
+    pub password_require_number: bool,
+    /// Password must contain at least one symbol
+    pub password_require_symbol: bool,
+    /// Reject common passwords
+    pub password_reject_common: bool,
+
+    /// Argon2 memory cost (in KiB)
+    pub argon2_memory_cost: u32,
+    /// Argon2 number of iterations
+    pub argon2_iterations: u32,
+    /// Argon2 degree of parallelism
+    pub argon2_parallelism: u32,
 }
 
 impl Config {
@@ -99,11 +189,54 @@ impl Config {
     pub fn from_env() -> Result<Config, ConfigError> {
         dotenvy::dotenv().ok();
 
-        config::Config::builder()
+        let config: Config = config::Config::builder()
             .add_source(config::Environment::default())
             .build()
             .map_err(|err| ConfigError::InvalidSource(err.to_string()))?
             .try_deserialize()
-            .map_err(|err| ConfigError::DeserializeError(err.to_string()))
+            .map_err(|err| ConfigError::DeserializeError(err.to_string()))?;
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    /// Check the values which depend on each other
+    fn validate(&self) -> Result<(), ConfigError> {
+        // The stdout and memory transports expose the emails (and their tokens) in the logs or in the API
+        let transport = self.email_transport.trim().to_lowercase();
+        if self.environment == "production" && matches!(transport.as_str(), "stdout" | "memory") {
+            return Err(ConfigError::InvalidValue(format!(
+                "the `{transport}` email transport is not allowed in production"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_email_transport() {
+        let config = |environment: &str, email_transport: &str| Config {
+            environment: environment.to_string(),
+            email_transport: email_transport.to_string(),
+            ..Config::default()
+        };
+
+        assert!(config("production", "smtp").validate().is_ok());
+        assert!(config("production", "file").validate().is_ok());
+        assert!(config("development", "stdout").validate().is_ok());
+        assert!(config("development", "memory").validate().is_ok());
+        assert!(matches!(
+            config("production", "stdout").validate(),
+            Err(ConfigError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            config("production", " Memory ").validate(),
+            Err(ConfigError::InvalidValue(_))
+        ));
     }
 }
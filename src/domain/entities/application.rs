@@ -10,6 +10,16 @@ pub type ApplicationId = Id;
 pub struct Application {
     pub id: ApplicationId,
     pub name: String,
+
+    /// Access token lifetime (in minute), overrides the global value
+    pub access_token_lifetime: Option<u32>,
+
+    /// Refresh token lifetime (in day), overrides the global value
+    pub refresh_token_lifetime: Option<u32>,
+
+    /// Audience (`aud` claim) of the issued tokens
+    pub audience: Option<String>,
+
     pub created_at: UtcDateTime,
     pub updated_at: UtcDateTime,
     pub deleted_at: Option<UtcDateTime>,
@@ -0,0 +1,205 @@
+//! Audit event entity
+
+use crate::domain::entities::user::UserId;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::{Id, IdError};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Audit event ID
+pub type AuditEventId = Id;
+
+/// Security-relevant action
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditAction {
+    UserCreated,
+    UserDeleted,
+    UserRestored,
+    SessionsRevoked,
+    LoginSucceeded,
+    LoginFailed,
+    ApplicationCreated,
+    ApplicationUpdated,
+    ApplicationDeleted,
+    ApplicationRestored,
+    ClientCreated,
+    ClientDeleted,
+    ClientSecretCreated,
+    ClientSecretDeleted,
+    ScopeCreated,
+    ScopeDeleted,
+    ScopeRestored,
+    ExternalLinkCreated,
+    ExternalLinkUpdated,
+    ExternalLinkDeleted,
+    ExternalLinkRestored,
+    ExternalLinkUserLinked,
+    ExternalLinkUserUnlinked,
+    ExternalLinkApplicationLinked,
+    ExternalLinkApplicationUnlinked,
+    InvitationCreated,
+    InvitationAccepted,
+}
+
+impl AuditAction {
+    /// All actions
+    pub const ALL: [AuditAction; 27] = [
+        Self::UserCreated,
+        Self::UserDeleted,
+        Self::UserRestored,
+        Self::SessionsRevoked,
+        Self::LoginSucceeded,
+        Self::LoginFailed,
+        Self::ApplicationCreated,
+        Self::ApplicationUpdated,
+        Self::ApplicationDeleted,
+        Self::ApplicationRestored,
+        Self::ClientCreated,
+        Self::ClientDeleted,
+        Self::ClientSecretCreated,
+        Self::ClientSecretDeleted,
+        Self::ScopeCreated,
+        Self::ScopeDeleted,
+        Self::ScopeRestored,
+        Self::ExternalLinkCreated,
+        Self::ExternalLinkUpdated,
+        Self::ExternalLinkDeleted,
+        Self::ExternalLinkRestored,
+        Self::ExternalLinkUserLinked,
+        Self::ExternalLinkUserUnlinked,
+        Self::ExternalLinkApplicationLinked,
+        Self::ExternalLinkApplicationUnlinked,
+        Self::InvitationCreated,
+        Self::InvitationAccepted,
+    ];
+
+    /// Get the action name (Ex.: `user.created`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::UserCreated => "user.created",
+            Self::UserDeleted => "user.deleted",
+            Self::UserRestored => "user.restored",
+            Self::SessionsRevoked => "sessions.revoked",
+            Self::LoginSucceeded => "login.succeeded",
+            Self::LoginFailed => "login.failed",
+            Self::ApplicationCreated => "application.created",
+            Self::ApplicationUpdated => "application.updated",
+            Self::ApplicationDeleted => "application.deleted",
+            Self::ApplicationRestored => "application.restored",
+            Self::ClientCreated => "client.created",
+            Self::ClientDeleted => "client.deleted",
+            Self::ClientSecretCreated => "client_secret.created",
+            Self::ClientSecretDeleted => "client_secret.deleted",
+            Self::ScopeCreated => "scope.created",
+            Self::ScopeDeleted => "scope.deleted",
+            Self::ScopeRestored => "scope.restored",
+            Self::ExternalLinkCreated => "external_link.created",
+            Self::ExternalLinkUpdated => "external_link.updated",
+            Self::ExternalLinkDeleted => "external_link.deleted",
+            Self::ExternalLinkRestored => "external_link.restored",
+            Self::ExternalLinkUserLinked => "external_link.user_linked",
+            Self::ExternalLinkUserUnlinked => "external_link.user_unlinked",
+            Self::ExternalLinkApplicationLinked => "external_link.application_linked",
+            Self::ExternalLinkApplicationUnlinked => "external_link.application_unlinked",
+            Self::InvitationCreated => "invitation.created",
+            Self::InvitationAccepted => "invitation.accepted",
+        }
+    }
+}
+
+impl Display for AuditAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for AuditAction {
+    type Err = String;
+
+    /// Parse an action name
+    ///
+    /// # Example
+    /// ```rust
+    /// use auth2_api::domain::entities::audit_event::AuditAction;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(AuditAction::from_str("login.failed"), Ok(AuditAction::LoginFailed));
+    /// assert!(AuditAction::from_str("login").is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|action| action.as_str() == s)
+            .ok_or_else(|| format!("`{s}` is not a valid audit action"))
+    }
+}
+
+/// Who performed an action and from where
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AuditContext {
+    /// Authenticated user (none for public routes and CLI)
+    pub actor_id: Option<UserId>,
+
+    /// Client IP address
+    pub ip: Option<String>,
+
+    /// Request ID (`x-request-id` header)
+    pub request_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditEvent {
+    pub id: AuditEventId,
+    pub actor_id: Option<UserId>,
+    pub action: AuditAction,
+
+    /// ID of the affected resource (email for a failed login)
+    pub target_id: String,
+    pub ip: Option<String>,
+    pub request_id: Option<String>,
+    pub created_at: UtcDateTime,
+}
+
+impl AuditEvent {
+    /// Create a new event from its context
+    pub fn new(context: &AuditContext, action: AuditAction, target_id: String) -> Result<Self, IdError> {
+        Ok(Self {
+            id: Id::new()?,
+            actor_id: context.actor_id.clone(),
+            action,
+            target_id,
+            ip: context.ip.clone(),
+            request_id: context.request_id.clone(),
+            created_at: UtcDateTime::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audit_action_from_str() {
+        for action in AuditAction::ALL {
+            assert_eq!(AuditAction::from_str(&action.to_string()), Ok(action));
+        }
+        assert!(AuditAction::from_str("user.updated").is_err());
+    }
+
+    #[test]
+    fn test_new_audit_event() {
+        let context = AuditContext {
+            actor_id: Some(Id::new().unwrap()),
+            ip: Some("127.0.0.1".to_string()),
+            request_id: Some("request-id".to_string()),
+        };
+        let event = AuditEvent::new(&context, AuditAction::UserDeleted, "user-id".to_string()).unwrap();
+
+        assert_eq!(event.actor_id, context.actor_id);
+        assert_eq!(event.action, AuditAction::UserDeleted);
+        assert_eq!(event.target_id, "user-id");
+        assert_eq!(event.ip, context.ip);
+        assert_eq!(event.request_id, context.request_id);
+    }
+}
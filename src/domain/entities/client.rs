@@ -0,0 +1,22 @@
+//! Client entity
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+
+/// Client ID (used as OAuth `client_id`)
+pub type ClientId = Id;
+
+/// Client secret ID
+pub type ClientSecretId = Id;
+
+/// Client entity: credentials of an application (the token settings are those of the application)
+#[derive(Debug, Clone)]
+pub struct Client {
+    pub id: ClientId,
+    pub application_id: ApplicationId,
+    pub name: String,
+    pub created_at: UtcDateTime,
+    pub updated_at: UtcDateTime,
+    pub deleted_at: Option<UtcDateTime>,
+}
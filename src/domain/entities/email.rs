@@ -1,11 +1,22 @@
 //! Email entity
 
 use crate::config::Config;
+use crate::domain::value_objects::id::Id;
+use std::fmt::{Display, Formatter};
 
 pub type EmailAddress = String;
 
+/// Queued email ID
+pub type EmailId = Id;
+
 #[derive(Debug, Default, Clone)]
 pub struct EmailConfig {
+    /// Transport of the emails: `smtp`, `file`, `stdout` or `memory`
+    pub transport: String,
+
+    /// Directory of the emails written by the `file` transport
+    pub file_path: Option<String>,
+
     /// SMTP host
     pub host: String,
 
@@ -21,16 +32,48 @@ pub struct EmailConfig {
     /// SMTP password
     pub password: Option<String>,
 
+    /// SMTP TLS mode: `none`, `starttls` or `tls` (implicit TLS)
+    pub tls: String,
+
+    /// Maximum number of SMTP connections in the pool
+    pub pool_max_size: u32,
+
+    /// Time after which an idle SMTP connection is closed (in second)
+    pub pool_idle_timeout: u64,
+
+    /// Locale of the emails when the user has no locale (Ex.: `en`)
+    pub default_locale: String,
+
+    /// Directory of templates replacing the built-in ones
+    pub templates_path: Option<String>,
+
     /// Forgotten password link base URL
     pub forgotten_password_base_url: String,
 
     /// Forgotten password email from
     pub forgotten_password_email_from: String,
+
+    /// Magic link base URL
+    pub magic_link_base_url: String,
+
+    /// Magic link email from
+    pub magic_link_email_from: String,
+
+    /// Invitation link base URL
+    pub invitation_base_url: String,
+
+    /// Invitation email from
+    pub invitation_email_from: String,
 }
 
 impl From<Config> for EmailConfig {
     fn from(config: Config) -> Self {
         EmailConfig {
+            transport: config.email_transport,
+            file_path: match config.email_file_path.is_empty() {
+                false => Some(config.email_file_path),
+                true => None,
+            },
             host: config.smtp_host,
             port: config.smtp_port,
             timeout: config.smtp_timeout,
@@ -42,14 +85,26 @@ impl From<Config> for EmailConfig {
                 false => Some(config.smtp_password),
                 true => None,
             },
+            tls: config.smtp_tls,
+            pool_max_size: config.smtp_pool_max_size,
+            pool_idle_timeout: config.smtp_pool_idle_timeout,
+            default_locale: config.email_default_locale,
+            templates_path: match config.email_templates_path.is_empty() {
+                false => Some(config.email_templates_path),
+                true => None,
+            },
             forgotten_password_base_url: config.forgotten_password_base_url,
             forgotten_password_email_from: config.forgotten_password_email_from,
+            magic_link_base_url: config.magic_link_base_url,
+            magic_link_email_from: config.magic_link_email_from,
+            invitation_base_url: config.invitation_base_url,
+            invitation_email_from: config.invitation_email_from,
         }
     }
 }
 
 /// Message to send
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct EmailMessage {
     pub from_address: EmailAddress,
     pub to_addresses: Vec<EmailAddress>,
@@ -57,3 +112,41 @@ pub struct EmailMessage {
     pub text_body: Option<String>,
     pub html_body: Option<String>,
 }
+
+/// Status of an email in the outbox
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmailStatus {
+    /// Not sent yet, a new attempt is planned
+    Pending,
+    Sent,
+
+    /// All attempts failed (dead letter)
+    Dead,
+}
+
+impl EmailStatus {
+    /// Get the status name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Sent => "sent",
+            Self::Dead => "dead",
+        }
+    }
+}
+
+impl Display for EmailStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Email waiting to be sent (outbox entry)
+#[derive(Debug, Clone)]
+pub struct QueuedEmail {
+    pub id: EmailId,
+    pub message: EmailMessage,
+
+    /// Number of attempts already done
+    pub attempts: u32,
+}
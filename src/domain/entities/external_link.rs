@@ -1,6 +1,8 @@
 //! ExternalLink entity represents a connection with another application
 
-use crate::domain::value_objects::{datetime::UtcDateTime, id::Id};
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::user::UserId;
+use crate::domain::value_objects::{datetime::UtcDateTime, id::Id, oidc_provider::OidcProvider};
 
 pub type ExternalLinkId = Id;
 
@@ -9,7 +11,25 @@ pub type ExternalLinkId = Id;
 pub struct ExternalLink {
     pub id: ExternalLinkId,
     pub name: String,
+    pub provider: Option<OidcProvider>,
     pub created_at: UtcDateTime,
     pub updated_at: UtcDateTime,
     pub deleted_at: Option<UtcDateTime>,
 }
+
+/// User known by a partner under its own identifier
+#[derive(Debug, Clone)]
+pub struct ExternalLinkUser {
+    pub external_link_id: ExternalLinkId,
+    pub user_id: UserId,
+    pub external_user_id: String,
+    pub created_at: UtcDateTime,
+}
+
+/// Application connected to a partner
+#[derive(Debug, Clone)]
+pub struct ExternalLinkApplication {
+    pub external_link_id: ExternalLinkId,
+    pub application_id: ApplicationId,
+    pub created_at: UtcDateTime,
+}
@@ -0,0 +1,54 @@
+//! Invitation entity
+
+use crate::domain::entities::user::UserId;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use chrono::Duration;
+use std::ops::Add;
+use uuid::Uuid;
+
+/// Invitation ID
+pub type InvitationId = Id;
+
+/// Invitation token value
+pub type InvitationTokenValue = String;
+
+#[derive(Debug, Clone)]
+pub struct Invitation {
+    pub user_id: UserId,
+    pub token: InvitationTokenValue,
+    pub expired_at: UtcDateTime,
+}
+
+impl Invitation {
+    /// Create a new invitation valid for `expiration_duration` hours
+    pub fn new(user_id: UserId, expiration_duration: i64) -> Self {
+        let now = UtcDateTime::now();
+        let expired_at = match Duration::try_hours(expiration_duration) {
+            Some(duration) => UtcDateTime::new(now.value().add(duration)),
+            None => now,
+        };
+
+        Self {
+            user_id,
+            token: Uuid::new_v4().to_string(),
+            expired_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn test_new_invitation() {
+        let user_id = UserId::new().unwrap();
+        let invitation = Invitation::new(user_id.clone(), 72);
+
+        assert_eq!(invitation.user_id, user_id);
+        assert!(invitation.expired_at.value() > Utc::now() + Duration::hours(71));
+        assert!(invitation.expired_at.value() <= Utc::now() + Duration::hours(72));
+    }
+}
@@ -0,0 +1,50 @@
+//! Magic link entity
+
+use crate::domain::entities::user::UserId;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use chrono::Duration;
+use std::ops::Add;
+use uuid::Uuid;
+
+/// Magic link token value
+pub type MagicLinkTokenValue = String;
+
+#[derive(Debug, Clone)]
+pub struct MagicLink {
+    pub user_id: UserId,
+    pub token: MagicLinkTokenValue,
+    pub expired_at: UtcDateTime,
+}
+
+impl MagicLink {
+    /// Create a new magic link valid for `expiration_duration` minutes
+    pub fn new(user_id: UserId, expiration_duration: i64) -> Self {
+        let now = UtcDateTime::now();
+        let expired_at = match Duration::try_minutes(expiration_duration) {
+            Some(duration) => UtcDateTime::new(now.value().add(duration)),
+            None => now,
+        };
+
+        Self {
+            user_id,
+            token: Uuid::new_v4().to_string(),
+            expired_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn test_new_magic_link() {
+        let user_id = UserId::new().unwrap();
+        let magic_link = MagicLink::new(user_id.clone(), 15);
+
+        assert_eq!(magic_link.user_id, user_id);
+        assert!(magic_link.expired_at.value() > Utc::now() + Duration::minutes(14));
+        assert!(magic_link.expired_at.value() <= Utc::now() + Duration::minutes(15));
+    }
+}
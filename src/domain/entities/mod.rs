@@ -3,9 +3,15 @@
 
 pub mod access_token;
 pub mod application;
+pub mod audit_event;
+pub mod client;
 pub mod email;
 pub mod external_link;
+pub mod invitation;
+pub mod magic_link;
+pub mod oidc_login_state;
 pub mod password_reset;
 pub mod refresh_token;
 pub mod scope;
 pub mod user;
+pub mod webhook;
@@ -0,0 +1,56 @@
+//! OpenID Connect login state entity
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::external_link::ExternalLinkId;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use chrono::Duration;
+use std::ops::Add;
+use uuid::Uuid;
+
+/// Login started on an identity provider and waiting for its callback
+#[derive(Debug, Clone, PartialEq)]
+pub struct OidcLoginState {
+    /// Opaque value sent to the provider and returned to the callback
+    pub state: String,
+    pub external_link_id: ExternalLinkId,
+    pub application_id: ApplicationId,
+
+    /// Value which must be found in the ID token
+    pub nonce: String,
+    pub expired_at: UtcDateTime,
+}
+
+impl OidcLoginState {
+    /// Create a new login state valid for `expiration_duration` minutes
+    pub fn new(external_link_id: ExternalLinkId, application_id: ApplicationId, expiration_duration: i64) -> Self {
+        let now = UtcDateTime::now();
+        let expired_at = match Duration::try_minutes(expiration_duration) {
+            Some(duration) => UtcDateTime::new(now.value().add(duration)),
+            None => now,
+        };
+
+        Self {
+            state: Uuid::new_v4().to_string(),
+            external_link_id,
+            application_id,
+            nonce: Uuid::new_v4().simple().to_string(),
+            expired_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::id::Id;
+    use chrono::Utc;
+
+    #[test]
+    fn test_new_oidc_login_state() {
+        let state = OidcLoginState::new(Id::new().unwrap(), Id::new().unwrap(), 10);
+
+        assert_ne!(state.state, state.nonce);
+        assert!(state.expired_at.value() > Utc::now() + Duration::minutes(9));
+        assert!(state.expired_at.value() <= Utc::now() + Duration::minutes(10));
+    }
+}
@@ -10,6 +10,7 @@ pub struct User {
     pub id: UserId,
     pub email: Email,
     pub password: Password,
+    pub password_changed_at: UtcDateTime,
     pub lastname: String,
     pub firstname: String,
     pub created_at: UtcDateTime,
@@ -34,6 +35,7 @@ impl User {
     ///     firstname: "John".to_owned(),
     ///     email: Email::new("john.doe@test.com").unwrap(),
     ///     password: Password::new("1A,R;(9h0Y&gYH5=7eY!ff", false).unwrap(),
+    ///     password_changed_at: UtcDateTime::now(),
     ///     created_at: UtcDateTime::now(),
     ///     updated_at: UtcDateTime::now(),
     ///     deleted_at: None,
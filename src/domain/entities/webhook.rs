@@ -0,0 +1,193 @@
+//! Webhook entity
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::{Id, IdError};
+use crate::domain::value_objects::webhook_secret::WebhookSecret;
+use serde_json::{Value, json};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Webhook ID
+pub type WebhookId = Id;
+
+/// Webhook delivery ID
+pub type WebhookDeliveryId = Id;
+
+/// Event sent to the webhooks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookEvent {
+    UserCreated,
+    UserDeleted,
+    UserRestored,
+    UserPasswordChanged,
+    SessionsRevoked,
+}
+
+impl WebhookEvent {
+    /// All events
+    pub const ALL: [WebhookEvent; 5] = [
+        Self::UserCreated,
+        Self::UserDeleted,
+        Self::UserRestored,
+        Self::UserPasswordChanged,
+        Self::SessionsRevoked,
+    ];
+
+    /// Get the event name (Ex.: `user.created`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::UserCreated => "user.created",
+            Self::UserDeleted => "user.deleted",
+            Self::UserRestored => "user.restored",
+            Self::UserPasswordChanged => "user.password_changed",
+            Self::SessionsRevoked => "sessions.revoked",
+        }
+    }
+}
+
+impl Display for WebhookEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for WebhookEvent {
+    type Err = String;
+
+    /// Parse an event name
+    ///
+    /// # Example
+    /// ```rust
+    /// use auth2_api::domain::entities::webhook::WebhookEvent;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(WebhookEvent::from_str("user.deleted"), Ok(WebhookEvent::UserDeleted));
+    /// assert!(WebhookEvent::from_str("user").is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|event| event.as_str() == s)
+            .ok_or_else(|| format!("`{s}` is not a valid webhook event"))
+    }
+}
+
+/// Webhook: URL of an application notified when subscribed events occur
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    pub id: WebhookId,
+    pub application_id: ApplicationId,
+    pub url: String,
+
+    /// Secret used to sign the payloads
+    pub secret: WebhookSecret,
+
+    /// Subscribed events
+    pub events: Vec<WebhookEvent>,
+
+    pub created_at: UtcDateTime,
+    pub updated_at: UtcDateTime,
+    pub deleted_at: Option<UtcDateTime>,
+}
+
+/// Occurrence of an event, sent to every subscribed webhook
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookMessage {
+    pub id: Id,
+    pub event: WebhookEvent,
+    pub data: Value,
+    pub created_at: UtcDateTime,
+}
+
+impl WebhookMessage {
+    /// Create a new message
+    pub fn new(event: WebhookEvent, data: Value) -> Result<Self, IdError> {
+        Ok(Self {
+            id: Id::new()?,
+            event,
+            data,
+            created_at: UtcDateTime::now(),
+        })
+    }
+
+    /// JSON body sent to the webhooks
+    pub fn payload(&self) -> String {
+        json!({
+            "id": self.id.to_string(),
+            "event": self.event.as_str(),
+            "created_at": self.created_at.to_string(),
+            "data": self.data,
+        })
+        .to_string()
+    }
+}
+
+/// Webhook delivery status
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookDeliveryStatus {
+    /// Not delivered yet, a new attempt is planned
+    Pending,
+    Delivered,
+
+    /// All attempts failed
+    Failed,
+}
+
+impl WebhookDeliveryStatus {
+    /// Get the status name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Delivered => "delivered",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+impl Display for WebhookDeliveryStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Message waiting to be delivered to a webhook (outbox entry)
+#[derive(Debug, Clone)]
+pub struct WebhookDelivery {
+    pub id: WebhookDeliveryId,
+    pub webhook_id: WebhookId,
+    pub url: String,
+    pub secret: WebhookSecret,
+
+    /// ID of the message, identical for all the webhooks
+    pub event_id: Id,
+    pub event: WebhookEvent,
+    pub payload: String,
+
+    /// Number of attempts already done
+    pub attempts: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_webhook_event_from_str() {
+        for event in WebhookEvent::ALL {
+            assert_eq!(WebhookEvent::from_str(&event.to_string()), Ok(event));
+        }
+        assert!(WebhookEvent::from_str("user.updated").is_err());
+    }
+
+    #[test]
+    fn test_webhook_message_payload() {
+        let message = WebhookMessage::new(WebhookEvent::UserCreated, json!({ "user_id": "user-id" })).unwrap();
+        let payload: Value = serde_json::from_str(&message.payload()).unwrap();
+
+        assert_eq!(payload["id"], message.id.to_string());
+        assert_eq!(payload["event"], "user.created");
+        assert_eq!(payload["created_at"], message.created_at.to_string());
+        assert_eq!(payload["data"]["user_id"], "user-id");
+    }
+}
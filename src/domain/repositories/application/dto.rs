@@ -13,6 +13,8 @@ use crate::domain::use_cases::application::restore_application::{
 use crate::domain::use_cases::application::update_application::{
     UpdateApplicationUseCaseRequest, UpdateApplicationUseCaseResponse,
 };
+use crate::domain::value_objects::pagination::PaginationCursor;
+use crate::domain::value_objects::query_filter::QueryFilters;
 
 // ================ Create application ================
 
@@ -36,7 +38,10 @@ pub struct GetApplicationByIdDtoResponse(pub ApplicationUseCaseResponse);
 pub struct GetApplicationsDtoRequest(pub GetApplicationsUseCaseRequest);
 
 #[derive(Debug, Clone)]
-pub struct GetApplicationsDtoResponse(pub Vec<ApplicationUseCaseResponse>);
+pub struct GetApplicationsDtoResponse {
+    pub applications: Vec<ApplicationUseCaseResponse>,
+    pub next_cursor: Option<PaginationCursor>,
+}
 
 // ================ Update application ================
 
@@ -59,6 +64,7 @@ pub struct DeleteApplicationDtoResponse(pub DeleteApplicationUseCaseResponse);
 #[derive(Debug, Clone)]
 pub struct CountApplicationsDtoRequest {
     pub deleted: bool,
+    pub filters: Option<QueryFilters>,
 }
 
 #[derive(Debug, Clone)]
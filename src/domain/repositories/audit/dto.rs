@@ -0,0 +1,32 @@
+//! DTO for audit repository
+
+use crate::domain::entities::audit_event::AuditEvent;
+use crate::domain::use_cases::audit::get_audit_events::{AuditEventFilters, GetAuditEventsUseCaseRequest};
+use crate::domain::value_objects::pagination::PaginationCursor;
+
+// ================ Create audit event ================
+
+#[derive(Debug, Clone)]
+pub struct CreateAuditEventDtoRequest(pub AuditEvent);
+
+#[derive(Debug, Clone)]
+pub struct CreateAuditEventDtoResponse();
+
+// ================ Get audit events ================
+
+#[derive(Debug, Clone)]
+pub struct GetAuditEventsDtoRequest(pub GetAuditEventsUseCaseRequest);
+
+#[derive(Debug, Clone)]
+pub struct GetAuditEventsDtoResponse {
+    pub events: Vec<AuditEvent>,
+    pub next_cursor: Option<PaginationCursor>,
+}
+
+// ================ Count audit events ================
+
+#[derive(Debug, Clone)]
+pub struct CountAuditEventsDtoRequest(pub AuditEventFilters);
+
+#[derive(Debug, Clone)]
+pub struct CountAuditEventsDtoResponse(pub i64);
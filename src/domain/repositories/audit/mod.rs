@@ -0,0 +1,28 @@
+//! Audit repository
+
+pub mod dto;
+
+use crate::domain::use_cases::audit::AuditUseCaseError;
+use async_trait::async_trait;
+use dto::{
+    CountAuditEventsDtoRequest, CountAuditEventsDtoResponse, CreateAuditEventDtoRequest, CreateAuditEventDtoResponse,
+    GetAuditEventsDtoRequest, GetAuditEventsDtoResponse,
+};
+
+#[async_trait]
+pub trait AuditRepository: Clone {
+    /// Save an audit event
+    async fn create(&self, req: CreateAuditEventDtoRequest) -> Result<CreateAuditEventDtoResponse, AuditUseCaseError>;
+
+    /// Get audit events
+    async fn get_audit_events(
+        &self,
+        req: GetAuditEventsDtoRequest,
+    ) -> Result<GetAuditEventsDtoResponse, AuditUseCaseError>;
+
+    /// Count audit events
+    async fn count_audit_events(
+        &self,
+        req: CountAuditEventsDtoRequest,
+    ) -> Result<CountAuditEventsDtoResponse, AuditUseCaseError>;
+}
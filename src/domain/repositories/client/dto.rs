@@ -0,0 +1,104 @@
+//! DTO for client repository
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::client::ClientId;
+use crate::domain::use_cases::application::create_client::CreateClientUseCaseRequest;
+use crate::domain::use_cases::application::delete_client::{DeleteClientUseCaseRequest, DeleteClientUseCaseResponse};
+use crate::domain::use_cases::application::delete_client_secret::{
+    DeleteClientSecretUseCaseRequest, DeleteClientSecretUseCaseResponse,
+};
+use crate::domain::use_cases::application::get_client::GetClientByIdUseCaseRequest;
+use crate::domain::use_cases::application::get_client_secrets::GetClientSecretsUseCaseRequest;
+use crate::domain::use_cases::application::get_clients::GetClientsUseCaseRequest;
+use crate::domain::use_cases::application::update_client::{UpdateClientUseCaseRequest, UpdateClientUseCaseResponse};
+use crate::domain::use_cases::application::{ClientSecretUseCaseResponse, ClientUseCaseResponse};
+use crate::domain::value_objects::pagination::PaginationCursor;
+use crate::domain::value_objects::password::Password;
+
+// ================ Create client ================
+
+#[derive(Debug, Clone)]
+pub struct CreateClientDtoRequest {
+    pub client: CreateClientUseCaseRequest,
+
+    /// Hashed first secret
+    pub secret: Password,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateClientDtoResponse(pub ClientUseCaseResponse);
+
+// ================ Count clients ================
+
+#[derive(Debug, Clone)]
+pub struct CountClientsDtoRequest {
+    pub application_id: ApplicationId,
+}
+
+#[derive(Debug, Clone)]
+pub struct CountClientsDtoResponse(pub i64);
+
+// ================ Get all clients ================
+
+#[derive(Debug, Clone)]
+pub struct GetClientsDtoRequest(pub GetClientsUseCaseRequest);
+
+#[derive(Debug, Clone)]
+pub struct GetClientsDtoResponse {
+    pub clients: Vec<ClientUseCaseResponse>,
+    pub next_cursor: Option<PaginationCursor>,
+}
+
+// ================ Get client by ID ================
+
+#[derive(Debug, Clone)]
+pub struct GetClientByIdDtoRequest(pub GetClientByIdUseCaseRequest);
+
+#[derive(Debug, Clone)]
+pub struct GetClientByIdDtoResponse(pub ClientUseCaseResponse);
+
+// ================ Update client ================
+
+#[derive(Debug, Clone)]
+pub struct UpdateClientDtoRequest(pub UpdateClientUseCaseRequest);
+
+#[derive(Debug, Clone)]
+pub struct UpdateClientDtoResponse(pub UpdateClientUseCaseResponse);
+
+// ================ Delete client ================
+
+#[derive(Debug, Clone)]
+pub struct DeleteClientDtoRequest(pub DeleteClientUseCaseRequest);
+
+#[derive(Debug, Clone)]
+pub struct DeleteClientDtoResponse(pub DeleteClientUseCaseResponse);
+
+// ================ Get client secrets ================
+
+#[derive(Debug, Clone)]
+pub struct GetClientSecretsDtoRequest(pub GetClientSecretsUseCaseRequest);
+
+#[derive(Debug, Clone)]
+pub struct GetClientSecretsDtoResponse(pub Vec<ClientSecretUseCaseResponse>);
+
+// ================ Create client secret ================
+
+#[derive(Debug, Clone)]
+pub struct CreateClientSecretDtoRequest {
+    pub application_id: ApplicationId,
+    pub client_id: ClientId,
+
+    /// Hashed secret
+    pub secret: Password,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateClientSecretDtoResponse(pub ClientSecretUseCaseResponse);
+
+// ================ Delete client secret ================
+
+#[derive(Debug, Clone)]
+pub struct DeleteClientSecretDtoRequest(pub DeleteClientSecretUseCaseRequest);
+
+#[derive(Debug, Clone)]
+pub struct DeleteClientSecretDtoResponse(pub DeleteClientSecretUseCaseResponse);
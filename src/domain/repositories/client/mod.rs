@@ -0,0 +1,67 @@
+//! Client repository
+
+pub mod dto;
+
+use crate::domain::repositories::client::dto::{
+    CountClientsDtoRequest, CountClientsDtoResponse, CreateClientDtoRequest, CreateClientDtoResponse,
+    CreateClientSecretDtoRequest, CreateClientSecretDtoResponse, DeleteClientDtoRequest, DeleteClientDtoResponse,
+    DeleteClientSecretDtoRequest, DeleteClientSecretDtoResponse, GetClientByIdDtoRequest, GetClientByIdDtoResponse,
+    GetClientSecretsDtoRequest, GetClientSecretsDtoResponse, GetClientsDtoRequest, GetClientsDtoResponse,
+    UpdateClientDtoRequest, UpdateClientDtoResponse,
+};
+use crate::domain::use_cases::application::ApplicationUseCaseError;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ClientRepository: Clone {
+    /// Create a client of an application with its first secret
+    async fn create_client(
+        &self,
+        req: CreateClientDtoRequest,
+    ) -> Result<CreateClientDtoResponse, ApplicationUseCaseError>;
+
+    /// Count the clients of an application
+    async fn count_clients(
+        &self,
+        req: CountClientsDtoRequest,
+    ) -> Result<CountClientsDtoResponse, ApplicationUseCaseError>;
+
+    /// Get the clients of an application
+    async fn get_clients(&self, req: GetClientsDtoRequest) -> Result<GetClientsDtoResponse, ApplicationUseCaseError>;
+
+    /// Get client by ID
+    async fn get_client_by_id(
+        &self,
+        req: GetClientByIdDtoRequest,
+    ) -> Result<GetClientByIdDtoResponse, ApplicationUseCaseError>;
+
+    /// Update client
+    async fn update_client(
+        &self,
+        req: UpdateClientDtoRequest,
+    ) -> Result<UpdateClientDtoResponse, ApplicationUseCaseError>;
+
+    /// Delete client
+    async fn delete_client(
+        &self,
+        req: DeleteClientDtoRequest,
+    ) -> Result<DeleteClientDtoResponse, ApplicationUseCaseError>;
+
+    /// Get the secrets of a client
+    async fn get_client_secrets(
+        &self,
+        req: GetClientSecretsDtoRequest,
+    ) -> Result<GetClientSecretsDtoResponse, ApplicationUseCaseError>;
+
+    /// Add a secret to a client
+    async fn create_client_secret(
+        &self,
+        req: CreateClientSecretDtoRequest,
+    ) -> Result<CreateClientSecretDtoResponse, ApplicationUseCaseError>;
+
+    /// Delete a client secret
+    async fn delete_client_secret(
+        &self,
+        req: DeleteClientSecretDtoRequest,
+    ) -> Result<DeleteClientSecretDtoResponse, ApplicationUseCaseError>;
+}
@@ -0,0 +1,32 @@
+//! DTO for email outbox repository
+
+use crate::domain::entities::email::{EmailId, EmailStatus, QueuedEmail};
+use crate::domain::value_objects::datetime::UtcDateTime;
+
+// ================ Get due emails ================
+
+#[derive(Debug, Clone)]
+pub struct GetDueEmailsDtoRequest {
+    pub limit: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetDueEmailsDtoResponse(pub Vec<QueuedEmail>);
+
+// ================ Update email ================
+
+#[derive(Debug, Clone)]
+pub struct UpdateEmailDtoRequest {
+    pub id: EmailId,
+    pub status: EmailStatus,
+    pub attempts: u32,
+
+    /// Next attempt date (pending emails only)
+    pub next_attempt_at: UtcDateTime,
+
+    /// Error of the last failed attempt
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateEmailDtoResponse();
@@ -0,0 +1,19 @@
+//! Email outbox repository
+//!
+//! Emails are added to the outbox by the repositories of the actions which send them
+//! (password reset, magic link, invitation), in the same transaction.
+
+pub mod dto;
+
+use crate::domain::use_cases::email::EmailUseCaseError;
+use async_trait::async_trait;
+use dto::{GetDueEmailsDtoRequest, GetDueEmailsDtoResponse, UpdateEmailDtoRequest, UpdateEmailDtoResponse};
+
+#[async_trait]
+pub trait EmailRepository: Clone {
+    /// Get pending emails whose next attempt is due
+    async fn get_due_emails(&self, req: GetDueEmailsDtoRequest) -> Result<GetDueEmailsDtoResponse, EmailUseCaseError>;
+
+    /// Save the result of a sending attempt
+    async fn update_email(&self, req: UpdateEmailDtoRequest) -> Result<UpdateEmailDtoResponse, EmailUseCaseError>;
+}
@@ -1,14 +1,21 @@
 //! DTO for external link repository
 
 use crate::domain::use_cases::external_link::{
-    ExternalLinkUseCaseResponse,
+    ExternalLinkApplicationUseCaseResponse, ExternalLinkUseCaseResponse, ExternalLinkUserUseCaseResponse,
     create_external_link::CreateExternalLinkUseCaseRequest,
     delete_external_link::{DeleteExternalLinkUseCaseRequest, DeleteExternalLinkUseCaseResponse},
     get_external_link::GetExternalLinkByIdUseCaseRequest,
     get_external_links::GetExternalLinksUseCaseRequest,
+    get_linked_user::GetLinkedUserUseCaseRequest,
+    link_application::LinkApplicationUseCaseRequest,
+    link_user::LinkUserUseCaseRequest,
     restore_external_link::{RestoreExternalLinkUseCaseRequest, RestoreExternalLinkUseCaseResponse},
+    unlink_application::{UnlinkApplicationUseCaseRequest, UnlinkApplicationUseCaseResponse},
+    unlink_user::{UnlinkUserUseCaseRequest, UnlinkUserUseCaseResponse},
     update_external_link::{UpdateExternalLinkUseCaseRequest, UpdateExternalLinkUseCaseResponse},
 };
+use crate::domain::value_objects::pagination::PaginationCursor;
+use crate::domain::value_objects::query_filter::QueryFilters;
 
 // ================ Create external link ================
 
@@ -23,6 +30,7 @@ pub struct CreateExternalLinkDtoResponse(pub ExternalLinkUseCaseResponse);
 #[derive(Debug, Clone)]
 pub struct CountExternalLinksDtoRequest {
     pub deleted: bool,
+    pub filters: Option<QueryFilters>,
 }
 
 #[derive(Debug, Clone)]
@@ -34,7 +42,10 @@ pub struct CountExternalLinksDtoResponse(pub i64);
 pub struct GetExternalLinksDtoRequest(pub GetExternalLinksUseCaseRequest);
 
 #[derive(Debug, Clone)]
-pub struct GetExternalLinksDtoResponse(pub Vec<ExternalLinkUseCaseResponse>);
+pub struct GetExternalLinksDtoResponse {
+    pub external_links: Vec<ExternalLinkUseCaseResponse>,
+    pub next_cursor: Option<PaginationCursor>,
+}
 
 // ================ Get external link by ID ================
 
@@ -67,3 +78,43 @@ pub struct RestoreExternalLinkDtoRequest(pub RestoreExternalLinkUseCaseRequest);
 
 #[derive(Debug, Clone)]
 pub struct RestoreExternalLinkDtoResponse(pub RestoreExternalLinkUseCaseResponse);
+
+// ================ Link user ================
+
+#[derive(Debug, Clone)]
+pub struct LinkUserDtoRequest(pub LinkUserUseCaseRequest);
+
+#[derive(Debug, Clone)]
+pub struct LinkUserDtoResponse(pub ExternalLinkUserUseCaseResponse);
+
+// ================ Unlink user ================
+
+#[derive(Debug, Clone)]
+pub struct UnlinkUserDtoRequest(pub UnlinkUserUseCaseRequest);
+
+#[derive(Debug, Clone)]
+pub struct UnlinkUserDtoResponse(pub UnlinkUserUseCaseResponse);
+
+// ================ Get linked user ================
+
+#[derive(Debug, Clone)]
+pub struct GetLinkedUserDtoRequest(pub GetLinkedUserUseCaseRequest);
+
+#[derive(Debug, Clone)]
+pub struct GetLinkedUserDtoResponse(pub Option<ExternalLinkUserUseCaseResponse>);
+
+// ================ Link application ================
+
+#[derive(Debug, Clone)]
+pub struct LinkApplicationDtoRequest(pub LinkApplicationUseCaseRequest);
+
+#[derive(Debug, Clone)]
+pub struct LinkApplicationDtoResponse(pub ExternalLinkApplicationUseCaseResponse);
+
+// ================ Unlink application ================
+
+#[derive(Debug, Clone)]
+pub struct UnlinkApplicationDtoRequest(pub UnlinkApplicationUseCaseRequest);
+
+#[derive(Debug, Clone)]
+pub struct UnlinkApplicationDtoResponse(pub UnlinkApplicationUseCaseResponse);
@@ -8,8 +8,10 @@ use dto::{
     CountExternalLinksDtoRequest, CountExternalLinksDtoResponse, CreateExternalLinkDtoRequest,
     CreateExternalLinkDtoResponse, DeleteExternalLinkDtoRequest, DeleteExternalLinkDtoResponse,
     GetExternalLinkByIdDtoRequest, GetExternalLinkByIdDtoResponse, GetExternalLinksDtoRequest,
-    GetExternalLinksDtoResponse, RestoreExternalLinkDtoRequest, RestoreExternalLinkDtoResponse,
-    UpdateExternalLinkDtoRequest, UpdateExternalLinkDtoResponse,
+    GetExternalLinksDtoResponse, GetLinkedUserDtoRequest, GetLinkedUserDtoResponse, LinkApplicationDtoRequest,
+    LinkApplicationDtoResponse, LinkUserDtoRequest, LinkUserDtoResponse, RestoreExternalLinkDtoRequest,
+    RestoreExternalLinkDtoResponse, UnlinkApplicationDtoRequest, UnlinkApplicationDtoResponse, UnlinkUserDtoRequest,
+    UnlinkUserDtoResponse, UpdateExternalLinkDtoRequest, UpdateExternalLinkDtoResponse,
 };
 
 #[async_trait]
@@ -55,4 +57,28 @@ pub trait ExternalLinkRepository: Clone {
         &self,
         req: RestoreExternalLinkDtoRequest,
     ) -> Result<RestoreExternalLinkDtoResponse, ExternalLinkUseCaseError>;
+
+    /// Link a user to an external link or replace its external user ID
+    async fn link_user(&self, req: LinkUserDtoRequest) -> Result<LinkUserDtoResponse, ExternalLinkUseCaseError>;
+
+    /// Unlink a user from an external link
+    async fn unlink_user(&self, req: UnlinkUserDtoRequest) -> Result<UnlinkUserDtoResponse, ExternalLinkUseCaseError>;
+
+    /// Get a linked user from its external user ID
+    async fn get_linked_user(
+        &self,
+        req: GetLinkedUserDtoRequest,
+    ) -> Result<GetLinkedUserDtoResponse, ExternalLinkUseCaseError>;
+
+    /// Link an application to an external link
+    async fn link_application(
+        &self,
+        req: LinkApplicationDtoRequest,
+    ) -> Result<LinkApplicationDtoResponse, ExternalLinkUseCaseError>;
+
+    /// Unlink an application from an external link
+    async fn unlink_application(
+        &self,
+        req: UnlinkApplicationDtoRequest,
+    ) -> Result<UnlinkApplicationDtoResponse, ExternalLinkUseCaseError>;
 }
@@ -0,0 +1,59 @@
+//! DTO for federation repository
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::external_link::ExternalLinkId;
+use crate::domain::entities::oidc_login_state::OidcLoginState;
+use crate::domain::entities::user::UserId;
+use crate::domain::value_objects::email::Email;
+use crate::domain::value_objects::oidc_provider::OidcProvider;
+use crate::domain::value_objects::password::Password;
+
+// ================ Get provider ================
+
+#[derive(Debug, Clone)]
+pub struct GetProviderDtoRequest {
+    pub external_link_id: ExternalLinkId,
+    pub application_id: ApplicationId,
+}
+
+/// Provider of the external link (none if the link is unknown, has no provider or is not attached to the application)
+#[derive(Debug, Clone)]
+pub struct GetProviderDtoResponse(pub Option<OidcProvider>);
+
+// ================ Create login state ================
+
+#[derive(Debug, Clone)]
+pub struct CreateLoginStateDtoRequest(pub OidcLoginState);
+
+#[derive(Debug, Clone)]
+pub struct CreateLoginStateDtoResponse();
+
+// ================ Consume login state ================
+
+#[derive(Debug, Clone)]
+pub struct ConsumeLoginStateDtoRequest {
+    pub state: String,
+}
+
+/// Consumed login state (none if the state is unknown or expired)
+#[derive(Debug, Clone)]
+pub struct ConsumeLoginStateDtoResponse(pub Option<OidcLoginState>);
+
+// ================ Find or create user ================
+
+#[derive(Debug, Clone)]
+pub struct FindOrCreateUserDtoRequest {
+    pub external_link_id: ExternalLinkId,
+    pub external_user_id: String,
+
+    /// Verified email used to link an existing user or to create a new one
+    pub email: Option<Email>,
+    pub lastname: String,
+    pub firstname: String,
+
+    /// Hashed password of a created user
+    pub password: Password,
+}
+
+#[derive(Debug, Clone)]
+pub struct FindOrCreateUserDtoResponse(pub UserId);
@@ -0,0 +1,36 @@
+//! Federation repository
+
+pub mod dto;
+
+use crate::domain::use_cases::federation::FederationUseCaseError;
+use async_trait::async_trait;
+use dto::{
+    ConsumeLoginStateDtoRequest, ConsumeLoginStateDtoResponse, CreateLoginStateDtoRequest, CreateLoginStateDtoResponse,
+    FindOrCreateUserDtoRequest, FindOrCreateUserDtoResponse, GetProviderDtoRequest, GetProviderDtoResponse,
+};
+
+#[async_trait]
+pub trait FederationRepository: Clone {
+    /// Get the OpenID Connect provider of an external link attached to an application
+    async fn get_provider(&self, req: GetProviderDtoRequest) -> Result<GetProviderDtoResponse, FederationUseCaseError>;
+
+    /// Save a login state until the provider callback
+    async fn create_login_state(
+        &self,
+        req: CreateLoginStateDtoRequest,
+    ) -> Result<CreateLoginStateDtoResponse, FederationUseCaseError>;
+
+    /// Get and delete an unexpired login state
+    async fn consume_login_state(
+        &self,
+        req: ConsumeLoginStateDtoRequest,
+    ) -> Result<ConsumeLoginStateDtoResponse, FederationUseCaseError>;
+
+    /// Get the user linked to an external user
+    ///
+    /// Without link, the user with the same email is linked or a new user is created.
+    async fn find_or_create_user(
+        &self,
+        req: FindOrCreateUserDtoRequest,
+    ) -> Result<FindOrCreateUserDtoResponse, FederationUseCaseError>;
+}
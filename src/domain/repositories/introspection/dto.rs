@@ -0,0 +1,45 @@
+//! DTO for introspection repository
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::client::ClientId;
+use crate::domain::entities::user::UserId;
+use crate::domain::value_objects::password::Password;
+use crate::domain::value_objects::scope_id::ScopeId;
+
+// ================ Get client credentials ================
+
+#[derive(Debug, Clone)]
+pub struct GetClientCredentialsDtoRequest(pub ClientId);
+
+/// Client with its hashed secrets
+#[derive(Debug, Clone)]
+pub struct ClientCredentials {
+    pub client_id: ClientId,
+    pub application_id: ApplicationId,
+    pub secrets: Vec<Password>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetClientCredentialsDtoResponse(pub Option<ClientCredentials>);
+
+// ================ Is access token active ================
+
+#[derive(Debug, Clone)]
+pub struct IsAccessTokenActiveDtoRequest {
+    pub user_id: UserId,
+    pub access_token: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct IsAccessTokenActiveDtoResponse(pub bool);
+
+// ================ Get user scopes ================
+
+#[derive(Debug, Clone)]
+pub struct GetUserScopesDtoRequest {
+    pub user_id: UserId,
+    pub application_id: ApplicationId,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetUserScopesDtoResponse(pub Vec<ScopeId>);
@@ -0,0 +1,31 @@
+//! Introspection repository
+
+pub mod dto;
+
+use crate::domain::use_cases::introspection::IntrospectionUseCaseError;
+use async_trait::async_trait;
+use dto::{
+    GetClientCredentialsDtoRequest, GetClientCredentialsDtoResponse, GetUserScopesDtoRequest, GetUserScopesDtoResponse,
+    IsAccessTokenActiveDtoRequest, IsAccessTokenActiveDtoResponse,
+};
+
+#[async_trait]
+pub trait IntrospectionRepository: Clone {
+    /// Get an active client with its hashed secrets
+    async fn get_client_credentials(
+        &self,
+        req: GetClientCredentialsDtoRequest,
+    ) -> Result<GetClientCredentialsDtoResponse, IntrospectionUseCaseError>;
+
+    /// Check that an access token has not been revoked and that its user is still enabled
+    async fn is_access_token_active(
+        &self,
+        req: IsAccessTokenActiveDtoRequest,
+    ) -> Result<IsAccessTokenActiveDtoResponse, IntrospectionUseCaseError>;
+
+    /// Get the user scopes of an application
+    async fn get_user_scopes(
+        &self,
+        req: GetUserScopesDtoRequest,
+    ) -> Result<GetUserScopesDtoResponse, IntrospectionUseCaseError>;
+}
@@ -0,0 +1,97 @@
+//! DTO for invitation repository
+
+use crate::domain::entities::email::EmailMessage;
+use crate::domain::entities::invitation::{Invitation, InvitationId};
+use crate::domain::entities::user::UserId;
+use crate::domain::use_cases::invitation::InvitationUseCaseResponse;
+use crate::domain::use_cases::invitation::accept_invitation::AcceptInvitationUseCaseRequest;
+use crate::domain::use_cases::invitation::get_invitations::GetInvitationsUseCaseRequest;
+use crate::domain::use_cases::invitation::revoke_invitation::{
+    RevokeInvitationUseCaseRequest, RevokeInvitationUseCaseResponse,
+};
+use crate::domain::value_objects::email::Email;
+use crate::domain::value_objects::locale::Locale;
+use crate::domain::value_objects::pagination::PaginationCursor;
+use crate::domain::value_objects::scope_id::ScopeId;
+
+// ================ Create invitation ================
+
+/// Create a pending user with its scopes and its invitation
+#[derive(Debug, Clone)]
+pub struct CreateInvitationDtoRequest {
+    pub email: Email,
+    pub lastname: String,
+    pub firstname: String,
+    pub scopes: Vec<ScopeId>,
+    pub locale: Option<Locale>,
+    pub invitation: Invitation,
+
+    /// Email with the invitation link, queued in the outbox with the invitation
+    pub message: EmailMessage,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateInvitationDtoResponse(pub InvitationUseCaseResponse);
+
+// ================ Count invitations ================
+
+#[derive(Debug, Clone)]
+pub struct CountInvitationsDtoRequest();
+
+#[derive(Debug, Clone)]
+pub struct CountInvitationsDtoResponse(pub i64);
+
+// ================ Get all invitations ================
+
+#[derive(Debug, Clone)]
+pub struct GetInvitationsDtoRequest(pub GetInvitationsUseCaseRequest);
+
+#[derive(Debug, Clone)]
+pub struct GetInvitationsDtoResponse {
+    pub invitations: Vec<InvitationUseCaseResponse>,
+    pub next_cursor: Option<PaginationCursor>,
+}
+
+// ================ Get invitation by ID ================
+
+#[derive(Debug, Clone)]
+pub struct GetInvitationByIdDtoRequest {
+    pub id: InvitationId,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetInvitationByIdDtoResponse(pub InvitationUseCaseResponse);
+
+// ================ Renew invitation ================
+
+/// Replace the token and the expiration date of an invitation
+#[derive(Debug, Clone)]
+pub struct RenewInvitationDtoRequest {
+    pub id: InvitationId,
+    pub invitation: Invitation,
+
+    /// Email with the new invitation link, queued in the outbox with the invitation
+    pub message: EmailMessage,
+}
+
+#[derive(Debug, Clone)]
+pub struct RenewInvitationDtoResponse();
+
+// ================ Revoke invitation ================
+
+#[derive(Debug, Clone)]
+pub struct RevokeInvitationDtoRequest(pub RevokeInvitationUseCaseRequest);
+
+#[derive(Debug, Clone)]
+pub struct RevokeInvitationDtoResponse(pub RevokeInvitationUseCaseResponse);
+
+// ================ Accept invitation ================
+
+#[derive(Debug, Clone)]
+pub struct AcceptInvitationDtoRequest(pub AcceptInvitationUseCaseRequest);
+
+#[derive(Debug, Clone)]
+pub struct AcceptInvitationDtoResponse {
+    /// Invited user
+    pub user_id: UserId,
+}
@@ -0,0 +1,55 @@
+//! Invitation repository
+
+pub mod dto;
+
+use crate::domain::use_cases::invitation::InvitationUseCaseError;
+use async_trait::async_trait;
+use dto::{
+    AcceptInvitationDtoRequest, AcceptInvitationDtoResponse, CountInvitationsDtoRequest, CountInvitationsDtoResponse,
+    CreateInvitationDtoRequest, CreateInvitationDtoResponse, GetInvitationByIdDtoRequest, GetInvitationByIdDtoResponse,
+    GetInvitationsDtoRequest, GetInvitationsDtoResponse, RenewInvitationDtoRequest, RenewInvitationDtoResponse,
+    RevokeInvitationDtoRequest, RevokeInvitationDtoResponse,
+};
+
+#[async_trait]
+pub trait InvitationRepository: Clone {
+    /// Create a pending user (without password) with its scopes and its invitation
+    async fn create(
+        &self,
+        req: CreateInvitationDtoRequest,
+    ) -> Result<CreateInvitationDtoResponse, InvitationUseCaseError>;
+
+    /// Count all pending invitations
+    async fn count_invitations(
+        &self,
+        req: CountInvitationsDtoRequest,
+    ) -> Result<CountInvitationsDtoResponse, InvitationUseCaseError>;
+
+    /// Get all pending invitations
+    async fn get_invitations(
+        &self,
+        req: GetInvitationsDtoRequest,
+    ) -> Result<GetInvitationsDtoResponse, InvitationUseCaseError>;
+
+    /// Get invitation by ID
+    async fn get_by_id(
+        &self,
+        req: GetInvitationByIdDtoRequest,
+    ) -> Result<GetInvitationByIdDtoResponse, InvitationUseCaseError>;
+
+    /// Replace the token and the expiration date of an invitation
+    async fn renew(&self, req: RenewInvitationDtoRequest)
+    -> Result<RenewInvitationDtoResponse, InvitationUseCaseError>;
+
+    /// Delete an invitation and its pending user
+    async fn revoke(
+        &self,
+        req: RevokeInvitationDtoRequest,
+    ) -> Result<RevokeInvitationDtoResponse, InvitationUseCaseError>;
+
+    /// Set the user password from a valid token and delete the invitation
+    async fn accept(
+        &self,
+        req: AcceptInvitationDtoRequest,
+    ) -> Result<AcceptInvitationDtoResponse, InvitationUseCaseError>;
+}
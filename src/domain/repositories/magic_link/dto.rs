@@ -0,0 +1,34 @@
+//! DTO for magic link repository
+
+use crate::domain::entities::email::EmailMessage;
+use crate::domain::entities::magic_link::{MagicLink, MagicLinkTokenValue};
+use crate::domain::entities::user::UserId;
+
+// ================ Magic link creation or update ================
+
+/// Create or update magic link request
+#[derive(Debug, Clone)]
+pub struct CreateUpdateMagicLinkDtoRequest {
+    pub magic_link: MagicLink,
+
+    /// Email with the token, queued in the outbox with the magic link
+    pub message: EmailMessage,
+}
+
+/// Create or update magic link response
+#[derive(Debug, Clone)]
+pub struct CreateUpdateMagicLinkDtoResponse();
+
+// ================ Consume magic link ================
+
+/// Consume magic link request
+#[derive(Debug, Clone)]
+pub struct ConsumeMagicLinkDtoRequest {
+    pub token: MagicLinkTokenValue,
+}
+
+/// Consume magic link response (no user ID if the token is unknown or expired)
+#[derive(Debug, Clone)]
+pub struct ConsumeMagicLinkDtoResponse {
+    pub user_id: Option<UserId>,
+}
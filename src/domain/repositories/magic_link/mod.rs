@@ -0,0 +1,25 @@
+//! Magic link repository
+
+pub mod dto;
+
+use crate::domain::repositories::magic_link::dto::{
+    ConsumeMagicLinkDtoRequest, ConsumeMagicLinkDtoResponse, CreateUpdateMagicLinkDtoRequest,
+    CreateUpdateMagicLinkDtoResponse,
+};
+use crate::domain::use_cases::user::UserUseCaseError;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait MagicLinkRepository: Clone {
+    /// Add or update the magic link of a user
+    async fn create_or_update(
+        &self,
+        request: CreateUpdateMagicLinkDtoRequest,
+    ) -> Result<CreateUpdateMagicLinkDtoResponse, UserUseCaseError>;
+
+    /// Get the user ID from a valid token and delete the magic link, so it can only be used once
+    async fn consume(
+        &self,
+        request: ConsumeMagicLinkDtoRequest,
+    ) -> Result<ConsumeMagicLinkDtoResponse, UserUseCaseError>;
+}
@@ -3,8 +3,16 @@
 //! a clean API to interact with the database or external resources.
 
 pub mod application;
+pub mod audit;
+pub mod client;
+pub mod email;
 pub mod external_link;
+pub mod federation;
+pub mod introspection;
+pub mod invitation;
+pub mod magic_link;
 pub mod password_reset;
 pub mod refresh_token;
 pub mod scope;
 pub mod user;
+pub mod webhook;
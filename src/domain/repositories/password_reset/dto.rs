@@ -1,5 +1,6 @@
 //! DTO for password reset repository
 
+use crate::domain::entities::email::EmailMessage;
 use crate::domain::entities::password_reset::{PasswordReset, PasswordResetTokenValue};
 use crate::domain::entities::user::UserId;
 
@@ -7,7 +8,12 @@ use crate::domain::entities::user::UserId;
 
 /// Create or update password reset request
 #[derive(Debug, Clone)]
-pub struct CreateUpdatePasswordResetDtoRequest(pub PasswordReset);
+pub struct CreateUpdatePasswordResetDtoRequest {
+    pub password_reset: PasswordReset,
+
+    /// Email with the token, queued in the outbox with the password reset
+    pub message: Option<EmailMessage>,
+}
 
 /// Create or update password reset response
 #[derive(Debug, Clone)]
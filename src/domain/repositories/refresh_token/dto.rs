@@ -1,8 +1,10 @@
 //! DTO for refresh token repository
 
 use crate::domain::entities::access_token::AccessToken;
+use crate::domain::entities::application::ApplicationId;
 use crate::domain::entities::refresh_token::{RefreshToken, RefreshTokenId};
 use crate::domain::entities::user::UserId;
+use crate::domain::entities::webhook::WebhookMessage;
 
 // ================ Refresh token creation ================
 
@@ -11,6 +13,9 @@ pub struct CreateRefreshTokenDtoRequest {
     pub refresh_token: RefreshToken,
     pub access_token: AccessToken,
     pub user_id: UserId,
+
+    /// Application for which the refresh token is issued (the only one which can use it)
+    pub application_id: ApplicationId,
 }
 
 #[derive(Debug, Clone)]
@@ -24,6 +29,7 @@ pub struct GetRefreshTokenDtoRequest(pub RefreshTokenId);
 #[derive(Debug, Clone)]
 pub struct GetRefreshTokenDtoResponse {
     pub user_id: UserId,
+    pub application_id: ApplicationId,
 }
 
 // ================ Delete refresh token ================
@@ -43,3 +49,18 @@ pub struct DeleteExpiredRefreshTokensDtoRequest();
 pub struct DeleteExpiredRefreshTokensDtoResponse {
     pub deleted: u64,
 }
+
+// ================ Delete user refresh tokens ================
+
+#[derive(Debug, Clone)]
+pub struct DeleteUserRefreshTokensDtoRequest {
+    pub user_id: UserId,
+
+    /// Webhook message, queued in the outbox with the deletion
+    pub message: WebhookMessage,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteUserRefreshTokensDtoResponse {
+    pub deleted: u64,
+}
@@ -3,7 +3,8 @@
 pub mod dto;
 
 use crate::domain::repositories::refresh_token::dto::{
-    DeleteExpiredRefreshTokensDtoRequest, DeleteExpiredRefreshTokensDtoResponse,
+    DeleteExpiredRefreshTokensDtoRequest, DeleteExpiredRefreshTokensDtoResponse, DeleteUserRefreshTokensDtoRequest,
+    DeleteUserRefreshTokensDtoResponse,
 };
 use crate::domain::use_cases::user::UserUseCaseError;
 use async_trait::async_trait;
@@ -37,4 +38,10 @@ pub trait RefreshTokenRepository: Clone {
         &self,
         req: DeleteExpiredRefreshTokensDtoRequest,
     ) -> Result<DeleteExpiredRefreshTokensDtoResponse, UserUseCaseError>;
+
+    /// Delete all the refresh tokens of a user
+    async fn delete_user_refresh_tokens(
+        &self,
+        req: DeleteUserRefreshTokensDtoRequest,
+    ) -> Result<DeleteUserRefreshTokensDtoResponse, UserUseCaseError>;
 }
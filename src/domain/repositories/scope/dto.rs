@@ -5,6 +5,8 @@ use crate::domain::use_cases::scope::create_scope::CreateScopeUseCaseRequest;
 use crate::domain::use_cases::scope::delete_scope::{DeleteScopeUseCaseRequest, DeleteScopeUseCaseResponse};
 use crate::domain::use_cases::scope::get_scopes::GetScopesUseCaseRequest;
 use crate::domain::use_cases::scope::restore_scope::{RestoreScopeUseCaseRequest, RestoreScopeUseCaseResponse};
+use crate::domain::value_objects::pagination::PaginationCursor;
+use crate::domain::value_objects::query_filter::QueryFilters;
 
 // ================ Create application ================
 
@@ -20,7 +22,10 @@ pub struct CreateScopeDtoResponse(pub ScopeUseCaseResponse);
 pub struct GetScopesDtoRequest(pub GetScopesUseCaseRequest);
 
 #[derive(Debug, Clone)]
-pub struct GetScopesDtoResponse(pub Vec<ScopeUseCaseResponse>);
+pub struct GetScopesDtoResponse {
+    pub scopes: Vec<ScopeUseCaseResponse>,
+    pub next_cursor: Option<PaginationCursor>,
+}
 
 // ================ Count scopes ================
 
@@ -28,6 +33,7 @@ pub struct GetScopesDtoResponse(pub Vec<ScopeUseCaseResponse>);
 pub struct CountScopesDtoRequest {
     pub deleted: bool,
     pub application_id: Option<String>,
+    pub filters: Option<QueryFilters>,
 }
 
 #[derive(Debug, Clone)]
@@ -1,20 +1,29 @@
 //! DTO for user repository
 
 use crate::domain::entities::user::UserId;
+use crate::domain::entities::webhook::WebhookMessage;
 use crate::domain::use_cases::user::UserUseCaseResponse;
 use crate::domain::use_cases::user::create_user::CreateUserUseCaseRequest;
-use crate::domain::use_cases::user::delete_user::{DeleteUserUseCaseRequest, DeleteUserUseCaseResponse};
-use crate::domain::use_cases::user::forgotten_password::ForgottenPasswordUseCaseRequest;
+use crate::domain::use_cases::user::delete_user::DeleteUserUseCaseResponse;
 use crate::domain::use_cases::user::get_user::GetUserUseCaseRequest;
 use crate::domain::use_cases::user::get_users::GetUsersUseCaseRequest;
-use crate::domain::use_cases::user::restore_user::{RestoreUserUseCaseRequest, RestoreUserUseCaseResponse};
+use crate::domain::use_cases::user::restore_user::RestoreUserUseCaseResponse;
+use crate::domain::value_objects::datetime::UtcDateTime;
 use crate::domain::value_objects::email::Email;
+use crate::domain::value_objects::pagination::{Pagination, PaginationCursor};
 use crate::domain::value_objects::password::Password;
+use crate::domain::value_objects::query_filter::QueryFilters;
 
 // ================ User creation ================
 
 #[derive(Debug, Clone)]
-pub struct CreateUserDtoRequest(pub CreateUserUseCaseRequest);
+pub struct CreateUserDtoRequest {
+    pub id: UserId,
+    pub user: CreateUserUseCaseRequest,
+
+    /// Webhook message, queued in the outbox with the user
+    pub message: WebhookMessage,
+}
 
 #[derive(Debug, Clone)]
 pub struct CreateUserDtoResponse(pub UserUseCaseResponse);
@@ -28,13 +37,24 @@ pub struct GetAccessTokenInformationDtoRequest(pub Email);
 pub struct GetAccessTokenInformationDtoResponse {
     pub id: UserId,
     pub password: Password,
+    pub password_changed_at: UtcDateTime,
 }
 
+// ================ Get password change date ================
+
+#[derive(Debug, Clone)]
+pub struct GetPasswordChangedAtDtoRequest(pub UserId);
+
+/// Date of the last password change (`None` if the user has no password)
+#[derive(Debug, Clone)]
+pub struct GetPasswordChangedAtDtoResponse(pub Option<UtcDateTime>);
+
 // ================ Get users ================
 
 #[derive(Debug, Clone)]
 pub struct CountUsersDtoRequest {
     pub deleted: bool,
+    pub filters: Option<QueryFilters>,
 }
 
 #[derive(Debug, Clone)]
@@ -44,7 +64,26 @@ pub struct CountUsersDtoResponse(pub i64);
 pub struct GetUsersDtoRequest(pub GetUsersUseCaseRequest);
 
 #[derive(Debug, Clone)]
-pub struct GetUsersDtoResponse(pub Vec<UserUseCaseResponse>);
+pub struct GetUsersDtoResponse {
+    pub users: Vec<UserUseCaseResponse>,
+    pub next_cursor: Option<PaginationCursor>,
+}
+
+// ================ Search users ================
+
+#[derive(Debug, Clone)]
+pub struct SearchUsersDtoRequest {
+    pub search: String,
+    pub pagination: Pagination,
+    pub filters: Option<QueryFilters>,
+    pub deleted: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchUsersDtoResponse {
+    pub users: Vec<UserUseCaseResponse>,
+    pub total: i64,
+}
 
 // ================ Get a user by ID ================
 
@@ -57,7 +96,7 @@ pub struct GetUserByIdDtoResponse(pub UserUseCaseResponse);
 // ================ Get a user by email ================
 
 #[derive(Debug, Clone)]
-pub struct GetUserByEmailDtoRequest(pub ForgottenPasswordUseCaseRequest);
+pub struct GetUserByEmailDtoRequest(pub Email);
 
 #[derive(Debug, Clone)]
 pub struct GetUserByEmailDtoResponse(pub UserUseCaseResponse);
@@ -65,7 +104,12 @@ pub struct GetUserByEmailDtoResponse(pub UserUseCaseResponse);
 // ================ Delete a user ================
 
 #[derive(Debug, Clone)]
-pub struct DeleteUserDtoRequest(pub DeleteUserUseCaseRequest);
+pub struct DeleteUserDtoRequest {
+    pub user_id: UserId,
+
+    /// Webhook message, queued in the outbox with the deletion
+    pub message: WebhookMessage,
+}
 
 #[derive(Debug, Clone)]
 pub struct DeleteUserDtoResponse(pub DeleteUserUseCaseResponse);
@@ -76,15 +120,45 @@ pub struct DeleteUserDtoResponse(pub DeleteUserUseCaseResponse);
 pub struct UpdatePasswordDtoRequest {
     pub user_id: UserId,
     pub password: Password,
+
+    /// Webhook message, queued in the outbox with the new password
+    pub message: WebhookMessage,
 }
 
 #[derive(Debug, Clone)]
 pub struct UpdatePasswordDtoResponse();
 
+// ================ Rehash password ================
+
+#[derive(Debug, Clone)]
+pub struct RehashPasswordDtoRequest {
+    pub user_id: UserId,
+    pub password: Password,
+}
+
+#[derive(Debug, Clone)]
+pub struct RehashPasswordDtoResponse();
+
+// ================ Password history ================
+
+#[derive(Debug, Clone)]
+pub struct GetPasswordHistoryDtoRequest {
+    pub user_id: UserId,
+    pub limit: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetPasswordHistoryDtoResponse(pub Vec<Password>);
+
 // ================ Restore a user ================
 
 #[derive(Debug, Clone)]
-pub struct RestoreUserDtoRequest(pub RestoreUserUseCaseRequest);
+pub struct RestoreUserDtoRequest {
+    pub user_id: UserId,
+
+    /// Webhook message, queued in the outbox with the restoration
+    pub message: WebhookMessage,
+}
 
 #[derive(Debug, Clone)]
 pub struct RestoreUserDtoResponse(pub RestoreUserUseCaseResponse);
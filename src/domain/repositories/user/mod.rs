@@ -5,9 +5,11 @@ pub mod dto;
 use crate::domain::repositories::user::dto::{
     CountUsersDtoRequest, CountUsersDtoResponse, CreateUserDtoRequest, CreateUserDtoResponse, DeleteUserDtoRequest,
     DeleteUserDtoResponse, GetAccessTokenInformationDtoRequest, GetAccessTokenInformationDtoResponse,
-    GetUserByEmailDtoRequest, GetUserByEmailDtoResponse, GetUserByIdDtoRequest, GetUserByIdDtoResponse,
-    GetUsersDtoRequest, GetUsersDtoResponse, RestoreUserDtoRequest, RestoreUserDtoResponse, UpdatePasswordDtoRequest,
-    UpdatePasswordDtoResponse,
+    GetPasswordChangedAtDtoRequest, GetPasswordChangedAtDtoResponse, GetPasswordHistoryDtoRequest,
+    GetPasswordHistoryDtoResponse, GetUserByEmailDtoRequest, GetUserByEmailDtoResponse, GetUserByIdDtoRequest,
+    GetUserByIdDtoResponse, GetUsersDtoRequest, GetUsersDtoResponse, RehashPasswordDtoRequest,
+    RehashPasswordDtoResponse, RestoreUserDtoRequest, RestoreUserDtoResponse, SearchUsersDtoRequest,
+    SearchUsersDtoResponse, UpdatePasswordDtoRequest, UpdatePasswordDtoResponse,
 };
 use crate::domain::use_cases::user::UserUseCaseError;
 use async_trait::async_trait;
@@ -23,12 +25,21 @@ pub trait UserRepository: Clone {
         req: GetAccessTokenInformationDtoRequest,
     ) -> Result<Option<GetAccessTokenInformationDtoResponse>, UserUseCaseError>;
 
+    /// Get the date of the last password change of a user, to check the password expiration
+    async fn get_password_changed_at(
+        &self,
+        req: GetPasswordChangedAtDtoRequest,
+    ) -> Result<GetPasswordChangedAtDtoResponse, UserUseCaseError>;
+
     /// Get all users
     async fn get_users(&self, req: GetUsersDtoRequest) -> Result<GetUsersDtoResponse, UserUseCaseError>;
 
     /// Count all users
     async fn count_users(&self, req: CountUsersDtoRequest) -> Result<CountUsersDtoResponse, UserUseCaseError>;
 
+    /// Search users by email, firstname and lastname, ranked by relevance
+    async fn search_users(&self, req: SearchUsersDtoRequest) -> Result<SearchUsersDtoResponse, UserUseCaseError>;
+
     /// Get a user by ID
     async fn get_user_by_id(&self, req: GetUserByIdDtoRequest) -> Result<GetUserByIdDtoResponse, UserUseCaseError>;
 
@@ -41,12 +52,24 @@ pub trait UserRepository: Clone {
     /// Delete a user by ID
     async fn delete_user(&self, req: DeleteUserDtoRequest) -> Result<DeleteUserDtoResponse, UserUseCaseError>;
 
-    /// Update password
+    /// Update password (the new password is added to the password history)
     async fn update_password(
         &self,
         req: UpdatePasswordDtoRequest,
     ) -> Result<UpdatePasswordDtoResponse, UserUseCaseError>;
 
+    /// Replace the password hash without changing the password (the password history is not updated)
+    async fn rehash_password(
+        &self,
+        req: RehashPasswordDtoRequest,
+    ) -> Result<RehashPasswordDtoResponse, UserUseCaseError>;
+
+    /// Get the last hashed passwords of a user, the most recent first
+    async fn get_password_history(
+        &self,
+        req: GetPasswordHistoryDtoRequest,
+    ) -> Result<GetPasswordHistoryDtoResponse, UserUseCaseError>;
+
     /// Restore a user
     async fn restore_user(&self, req: RestoreUserDtoRequest) -> Result<RestoreUserDtoResponse, UserUseCaseError>;
 }
@@ -0,0 +1,78 @@
+//! DTO for webhook repository
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::webhook::{Webhook, WebhookDelivery, WebhookDeliveryId, WebhookDeliveryStatus};
+use crate::domain::use_cases::webhook::create_webhook::CreateWebhookUseCaseRequest;
+use crate::domain::use_cases::webhook::delete_webhook::{DeleteWebhookUseCaseRequest, DeleteWebhookUseCaseResponse};
+use crate::domain::use_cases::webhook::get_webhooks::GetWebhooksUseCaseRequest;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::pagination::PaginationCursor;
+use crate::domain::value_objects::webhook_secret::WebhookSecret;
+
+// ================ Create webhook ================
+
+#[derive(Debug, Clone)]
+pub struct CreateWebhookDtoRequest {
+    pub webhook: CreateWebhookUseCaseRequest,
+    pub secret: WebhookSecret,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateWebhookDtoResponse(pub Webhook);
+
+// ================ Count webhooks ================
+
+#[derive(Debug, Clone)]
+pub struct CountWebhooksDtoRequest {
+    pub application_id: ApplicationId,
+}
+
+#[derive(Debug, Clone)]
+pub struct CountWebhooksDtoResponse(pub i64);
+
+// ================ Get webhooks ================
+
+#[derive(Debug, Clone)]
+pub struct GetWebhooksDtoRequest(pub GetWebhooksUseCaseRequest);
+
+#[derive(Debug, Clone)]
+pub struct GetWebhooksDtoResponse {
+    pub webhooks: Vec<Webhook>,
+    pub next_cursor: Option<PaginationCursor>,
+}
+
+// ================ Delete webhook ================
+
+#[derive(Debug, Clone)]
+pub struct DeleteWebhookDtoRequest(pub DeleteWebhookUseCaseRequest);
+
+#[derive(Debug, Clone)]
+pub struct DeleteWebhookDtoResponse(pub DeleteWebhookUseCaseResponse);
+
+// ================ Get due deliveries ================
+
+#[derive(Debug, Clone)]
+pub struct GetDueWebhookDeliveriesDtoRequest {
+    pub limit: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetDueWebhookDeliveriesDtoResponse(pub Vec<WebhookDelivery>);
+
+// ================ Update delivery ================
+
+#[derive(Debug, Clone)]
+pub struct UpdateWebhookDeliveryDtoRequest {
+    pub id: WebhookDeliveryId,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: u32,
+
+    /// Next attempt date (pending deliveries only)
+    pub next_attempt_at: UtcDateTime,
+
+    /// Error of the last failed attempt
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateWebhookDeliveryDtoResponse();
@@ -0,0 +1,51 @@
+//! Webhook repository
+//!
+//! Deliveries are added to the outbox by the repositories of the actions which trigger the events
+//! (user creation, deletion, restoration, password change, sessions revocation), in the same transaction.
+
+pub mod dto;
+
+use crate::domain::use_cases::webhook::WebhookUseCaseError;
+use async_trait::async_trait;
+use dto::{
+    CountWebhooksDtoRequest, CountWebhooksDtoResponse, CreateWebhookDtoRequest, CreateWebhookDtoResponse,
+    DeleteWebhookDtoRequest, DeleteWebhookDtoResponse, GetDueWebhookDeliveriesDtoRequest,
+    GetDueWebhookDeliveriesDtoResponse, GetWebhooksDtoRequest, GetWebhooksDtoResponse, UpdateWebhookDeliveryDtoRequest,
+    UpdateWebhookDeliveryDtoResponse,
+};
+
+#[async_trait]
+pub trait WebhookRepository: Clone {
+    /// Create a webhook of an application
+    async fn create_webhook(
+        &self,
+        req: CreateWebhookDtoRequest,
+    ) -> Result<CreateWebhookDtoResponse, WebhookUseCaseError>;
+
+    /// Count the webhooks of an application
+    async fn count_webhooks(
+        &self,
+        req: CountWebhooksDtoRequest,
+    ) -> Result<CountWebhooksDtoResponse, WebhookUseCaseError>;
+
+    /// Get the webhooks of an application
+    async fn get_webhooks(&self, req: GetWebhooksDtoRequest) -> Result<GetWebhooksDtoResponse, WebhookUseCaseError>;
+
+    /// Delete webhook
+    async fn delete_webhook(
+        &self,
+        req: DeleteWebhookDtoRequest,
+    ) -> Result<DeleteWebhookDtoResponse, WebhookUseCaseError>;
+
+    /// Get pending deliveries whose next attempt is due
+    async fn get_due_deliveries(
+        &self,
+        req: GetDueWebhookDeliveriesDtoRequest,
+    ) -> Result<GetDueWebhookDeliveriesDtoResponse, WebhookUseCaseError>;
+
+    /// Save the result of a delivery attempt
+    async fn update_delivery(
+        &self,
+        req: UpdateWebhookDeliveryDtoRequest,
+    ) -> Result<UpdateWebhookDeliveryDtoResponse, WebhookUseCaseError>;
+}
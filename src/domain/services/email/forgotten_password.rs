@@ -1,13 +1,18 @@
 //! Send email on forgotten password request
 
+use crate::domain::entities::email::EmailMessage;
 use crate::domain::entities::password_reset::PasswordResetTokenValue;
 use crate::domain::value_objects::email::Email;
+use crate::domain::value_objects::locale::Locale;
 
 #[derive(Debug, Clone)]
 pub struct ForgottenPasswordEmailRequest {
     pub email: Email,
     pub token: PasswordResetTokenValue,
+
+    /// Language of the email (default locale if not set)
+    pub locale: Option<Locale>,
 }
 
 #[derive(Debug, Clone)]
-pub struct ForgottenPasswordEmailResponse();
+pub struct ForgottenPasswordEmailResponse(pub EmailMessage);
@@ -0,0 +1,18 @@
+//! Send email on user invitation
+
+use crate::domain::entities::email::EmailMessage;
+use crate::domain::entities::invitation::InvitationTokenValue;
+use crate::domain::value_objects::email::Email;
+use crate::domain::value_objects::locale::Locale;
+
+#[derive(Debug, Clone)]
+pub struct InvitationEmailRequest {
+    pub email: Email,
+    pub token: InvitationTokenValue,
+
+    /// Language of the email (default locale if not set)
+    pub locale: Option<Locale>,
+}
+
+#[derive(Debug, Clone)]
+pub struct InvitationEmailResponse(pub EmailMessage);
@@ -0,0 +1,18 @@
+//! Send email on magic link request
+
+use crate::domain::entities::email::EmailMessage;
+use crate::domain::entities::magic_link::MagicLinkTokenValue;
+use crate::domain::value_objects::email::Email;
+use crate::domain::value_objects::locale::Locale;
+
+#[derive(Debug, Clone)]
+pub struct MagicLinkEmailRequest {
+    pub email: Email,
+    pub token: MagicLinkTokenValue,
+
+    /// Language of the email (default locale if not set)
+    pub locale: Option<Locale>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MagicLinkEmailResponse(pub EmailMessage);
@@ -1,11 +1,16 @@
 //! Email service
 
 pub mod forgotten_password;
+pub mod invitation;
+pub mod magic_link;
 
 use crate::domain::entities::email::EmailMessage;
 use crate::domain::services::email::forgotten_password::{
     ForgottenPasswordEmailRequest, ForgottenPasswordEmailResponse,
 };
+use crate::domain::services::email::invitation::{InvitationEmailRequest, InvitationEmailResponse};
+use crate::domain::services::email::magic_link::{MagicLinkEmailRequest, MagicLinkEmailResponse};
+use async_trait::async_trait;
 use thiserror::Error;
 
 #[derive(Debug, Clone, PartialEq, Error)]
@@ -27,18 +32,33 @@ pub enum EmailServiceError {
 
     #[error("Invalid parameter: {0}")]
     InvalidParameter(String),
+
+    #[error("Email template error: {0}")]
+    TemplateError(String),
 }
 
-pub trait EmailTransport {
+#[async_trait]
+pub trait EmailTransport: Clone {
     /// Send an email
-    fn send(&self, message: EmailMessage) -> Result<(), EmailServiceError>;
+    async fn send(&self, message: &EmailMessage) -> Result<(), EmailServiceError>;
+
+    /// Check that emails can be sent (Ex.: connection to the server)
+    async fn check(&self) -> Result<(), EmailServiceError>;
 }
 
 /// List all email services
-pub trait EmailService {
-    /// Send email for a forgotten password request
+///
+/// The emails are only built: they are queued in the outbox and sent later by [`EmailTransport`].
+pub trait EmailService: Clone {
+    /// Build email for a forgotten password request
     fn forgotten_password(
         &self,
         request: ForgottenPasswordEmailRequest,
     ) -> Result<ForgottenPasswordEmailResponse, EmailServiceError>;
+
+    /// Build email with a magic link to log in without password
+    fn magic_link(&self, request: MagicLinkEmailRequest) -> Result<MagicLinkEmailResponse, EmailServiceError>;
+
+    /// Build email with the invitation link to set a password
+    fn invitation(&self, request: InvitationEmailRequest) -> Result<InvitationEmailResponse, EmailServiceError>;
 }
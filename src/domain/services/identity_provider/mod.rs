@@ -0,0 +1,67 @@
+//! Identity provider service used to log in users with an OpenID Connect provider
+
+use crate::domain::value_objects::oidc_provider::OidcProvider;
+use async_trait::async_trait;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum IdentityProviderError {
+    #[error("Identity provider request error: {0}")]
+    RequestError(String),
+
+    #[error("Invalid identity provider response: {0}")]
+    InvalidResponse(String),
+
+    #[error("Invalid ID token: {0}")]
+    InvalidIdToken(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct AuthorizationUrlRequest {
+    /// Provider configuration
+    pub provider: OidcProvider,
+
+    /// URL called by the provider after the login
+    pub redirect_uri: String,
+
+    /// Opaque value returned to the callback
+    pub state: String,
+
+    /// Value which must be found in the ID token
+    pub nonce: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExchangeCodeRequest {
+    /// Provider configuration
+    pub provider: OidcProvider,
+
+    /// URL used to get the authorization code
+    pub redirect_uri: String,
+
+    /// Authorization code returned to the callback
+    pub code: String,
+
+    /// Value which must be found in the ID token
+    pub nonce: String,
+}
+
+/// Claims of a validated ID token
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdentityClaims {
+    /// User identifier at the provider
+    pub subject: String,
+    pub email: Option<String>,
+    pub email_verified: bool,
+    pub given_name: Option<String>,
+    pub family_name: Option<String>,
+}
+
+#[async_trait]
+pub trait IdentityProviderService: Clone {
+    /// Build the URL of the provider login page
+    async fn authorization_url(&self, request: AuthorizationUrlRequest) -> Result<String, IdentityProviderError>;
+
+    /// Exchange an authorization code for the claims of a validated ID token
+    async fn exchange_code(&self, request: ExchangeCodeRequest) -> Result<IdentityClaims, IdentityProviderError>;
+}
@@ -2,4 +2,6 @@
 //! They are the business logic of the application.
 
 pub mod email;
+pub mod identity_provider;
 pub mod security;
+pub mod webhook;
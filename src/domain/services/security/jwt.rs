@@ -112,6 +112,28 @@ impl Jwt {
         self.refresh_lifetime
     }
 
+    /// Get a copy of the JWT using the application lifetimes when they are defined
+    ///
+    /// # Example
+    /// ```rust
+    /// use auth2_api::domain::services::security::jwt::Jwt;
+    ///
+    /// let jwt = Jwt::init("HS256", 15, 7, Some("secret"), None, None).unwrap();
+    /// assert_eq!(jwt.for_application(None, Some(30)).refresh_lifetime(), 30);
+    /// assert_eq!(jwt.for_application(Some(5), None).refresh_lifetime(), 7);
+    /// ```
+    pub fn for_application(&self, access_lifetime: Option<u32>, refresh_lifetime: Option<u32>) -> Self {
+        let mut jwt = self.clone();
+        if let Some(duration) = access_lifetime {
+            jwt.set_access_lifetime(duration.into());
+        }
+        if let Some(duration) = refresh_lifetime {
+            jwt.set_refresh_lifetime(duration.into());
+        }
+
+        jwt
+    }
+
     /// Update access token lifetime (in minute)
     pub fn set_access_lifetime(&mut self, duration: i64) {
         self.access_lifetime = duration;
@@ -173,6 +195,7 @@ impl Jwt {
             nbf: now.timestamp(),
             application_id: data.application_id,
             client_id: data.client_id,
+            aud: data.audience,
         };
 
         match self.encoding_key.clone() {
@@ -190,8 +213,11 @@ impl Jwt {
     }
 
     /// Parse JWT
+    ///
+    /// The audience is not checked here because tokens of every application are accepted by this API.
     pub fn parse(&self, token: &AccessToken) -> Result<Payload, JwtError> {
-        let validation = Validation::new(self.algorithm);
+        let mut validation = Validation::new(self.algorithm);
+        validation.validate_aud = false;
 
         match self.decoding_key.clone() {
             Some(decoding_key) => {
@@ -236,8 +262,10 @@ mod tests {
         let jwt = Jwt::default();
         assert!(jwt.use_secret());
 
-        let mut jwt = Jwt::default();
-        jwt.algorithm = Algorithm::ES256;
+        let mut jwt = Jwt {
+            algorithm: Algorithm::ES256,
+            ..Default::default()
+        };
         assert!(!jwt.use_secret());
 
         jwt.algorithm = Algorithm::HS256;
@@ -258,4 +286,24 @@ mod tests {
             assert_eq!(e, JwtError::InvalidAlgorithm("ES512".to_string()));
         }
     }
+
+    #[test]
+    fn test_jwt_generate_and_parse_with_audience() {
+        let jwt = Jwt::init("HS256", 15, 7, Some("secret"), None, None).unwrap();
+        let data = PayloadData::new("user_id".to_string(), "application_id".to_string(), "".to_string())
+            .with_audience(Some("https://api.test.com".to_string()));
+
+        let token = jwt.generate(data).unwrap();
+        let payload = jwt.parse(&token).unwrap();
+        assert_eq!(payload.application_id, "application_id");
+        assert_eq!(payload.aud, Some("https://api.test.com".to_string()));
+        assert_eq!(payload.exp - payload.iat, 15 * 60);
+
+        let token = jwt
+            .for_application(Some(5), None)
+            .generate(PayloadData::from(payload))
+            .unwrap();
+        let payload = jwt.parse(&token).unwrap();
+        assert_eq!(payload.exp - payload.iat, 5 * 60);
+    }
 }
@@ -30,6 +30,10 @@ pub struct Payload {
     /// Client ID
     pub client_id: String, // TODO: Custom type?
 
+    /// Audience
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aud: Option<String>,
+
     /// Expiration time
     pub exp: i64,
 
@@ -51,6 +55,7 @@ pub struct PayloadData {
     pub user_id: String,        // TODO: Custom type?
     pub application_id: String, // TODO: Custom type?
     pub client_id: String,      // TODO: Custom type?
+    pub audience: Option<String>,
 }
 
 impl PayloadData {
@@ -60,8 +65,15 @@ impl PayloadData {
             user_id,
             application_id,
             client_id,
+            audience: None,
         }
     }
+
+    /// Set the audience of the token
+    pub fn with_audience(mut self, audience: Option<String>) -> Self {
+        self.audience = audience;
+        self
+    }
 }
 
 impl From<Payload> for PayloadData {
@@ -70,6 +82,7 @@ impl From<Payload> for PayloadData {
             user_id: payload.sub,
             application_id: payload.application_id,
             client_id: payload.client_id,
+            audience: payload.aud,
         }
     }
 }
@@ -84,6 +97,7 @@ mod tests {
             sub: "user_id".to_owned(),
             application_id: "app_id".to_owned(),
             client_id: "client_id".to_owned(),
+            aud: Some("audience".to_owned()),
             exp: 0,
             iat: 0,
             nbf: 0,
@@ -94,5 +108,6 @@ mod tests {
         assert_eq!(data.user_id, "user_id");
         assert_eq!(data.application_id, "app_id");
         assert_eq!(data.client_id, "client_id");
+        assert_eq!(data.audience, Some("audience".to_owned()));
     }
 }
@@ -0,0 +1,38 @@
+//! Webhook service used to send event payloads to the webhook receivers
+
+use crate::domain::entities::webhook::WebhookDelivery;
+use async_trait::async_trait;
+use thiserror::Error;
+
+/// Header containing the message ID
+pub const WEBHOOK_ID_HEADER: &str = "x-webhook-id";
+
+/// Header containing the event name
+pub const WEBHOOK_EVENT_HEADER: &str = "x-webhook-event";
+
+/// Header containing the timestamp used in the signature
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "x-webhook-timestamp";
+
+/// Header containing the signature (`sha256=<hex>`)
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "x-webhook-signature";
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum WebhookServiceError {
+    #[error("Webhook request error: {0}")]
+    RequestError(String),
+
+    #[error("Webhook receiver responded with status {0}")]
+    UnexpectedStatus(u16),
+
+    #[error("Webhook URL not allowed: {0}")]
+    UrlNotAllowed(String),
+}
+
+#[async_trait]
+pub trait WebhookService: Clone {
+    /// Check that a webhook URL can be called (scheme and host)
+    fn check_url(&self, url: &str) -> Result<(), WebhookServiceError>;
+
+    /// Send a signed payload to a webhook
+    async fn send(&self, delivery: &WebhookDelivery) -> Result<(), WebhookServiceError>;
+}
@@ -20,6 +20,8 @@ pub const VALID_APPLICATION_ID: &str = "ffaa2c9c-872f-4e62-8302-d4586096cd13";
 pub const INVALID_APPLICATION_ID: &str = "b4dc6179-e538-449b-accd-a8a1f58631af";
 pub const VALID_APPLICATION_NAME: &str = "Test application";
 pub const INVALID_APPLICATION_NAME: &str = "Test invalid application";
+pub const VALID_APPLICATION_ACCESS_TOKEN_LIFETIME: u32 = 5;
+pub const VALID_APPLICATION_AUDIENCE: &str = "https://api.test.com";
 
 /// Application repository mock
 #[derive(Debug, Clone)]
@@ -38,6 +40,9 @@ impl ApplicationRepository for ApplicationRepositoryMock {
             Ok(CreateApplicationDtoResponse(ApplicationUseCaseResponse {
                 id: Id::from_str(VALID_APPLICATION_ID).unwrap(),
                 name: VALID_APPLICATION_NAME.to_string(),
+                access_token_lifetime: req.0.access_token_lifetime,
+                refresh_token_lifetime: req.0.refresh_token_lifetime,
+                audience: req.0.audience,
                 created_at: now.clone(),
                 updated_at: now,
                 deleted_at: None,
@@ -60,6 +65,9 @@ impl ApplicationRepository for ApplicationRepositoryMock {
             Ok(GetApplicationByIdDtoResponse(ApplicationUseCaseResponse {
                 id: Id::from_str(VALID_APPLICATION_ID).unwrap(),
                 name: "Test application".to_string(),
+                access_token_lifetime: Some(VALID_APPLICATION_ACCESS_TOKEN_LIFETIME),
+                refresh_token_lifetime: None,
+                audience: Some(VALID_APPLICATION_AUDIENCE.to_string()),
                 created_at: now.clone(),
                 updated_at: now,
                 deleted_at: None,
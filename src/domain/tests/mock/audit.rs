@@ -0,0 +1,61 @@
+//! Mock of the audit repository
+
+use crate::domain::entities::audit_event::{AuditAction, AuditContext, AuditEvent};
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::repositories::audit::dto::{
+    CountAuditEventsDtoRequest, CountAuditEventsDtoResponse, CreateAuditEventDtoRequest, CreateAuditEventDtoResponse,
+    GetAuditEventsDtoRequest, GetAuditEventsDtoResponse,
+};
+use crate::domain::use_cases::audit::AuditUseCaseError;
+use crate::domain::use_cases::audit::get_audit_events::AuditEventFilters;
+use async_trait::async_trait;
+
+/// Audit repository mock
+///
+/// It contains a single `user.created` event.
+#[derive(Debug, Clone)]
+pub struct AuditRepositoryMock {}
+
+impl AuditRepositoryMock {
+    /// Events matching the filters
+    fn events(filters: &AuditEventFilters) -> Vec<AuditEvent> {
+        let event = AuditEvent::new(
+            &AuditContext::default(),
+            AuditAction::UserCreated,
+            "d05659eb-007f-42bf-96a8-6005d9840a22".to_string(),
+        )
+        .unwrap();
+
+        match filters.action {
+            Some(action) if action != event.action => vec![],
+            _ => vec![event],
+        }
+    }
+}
+
+#[async_trait]
+impl AuditRepository for AuditRepositoryMock {
+    /// Save an audit event
+    async fn create(&self, _req: CreateAuditEventDtoRequest) -> Result<CreateAuditEventDtoResponse, AuditUseCaseError> {
+        Ok(CreateAuditEventDtoResponse())
+    }
+
+    /// Get audit events
+    async fn get_audit_events(
+        &self,
+        req: GetAuditEventsDtoRequest,
+    ) -> Result<GetAuditEventsDtoResponse, AuditUseCaseError> {
+        Ok(GetAuditEventsDtoResponse {
+            events: Self::events(&req.0.filters),
+            next_cursor: None,
+        })
+    }
+
+    /// Count audit events
+    async fn count_audit_events(
+        &self,
+        req: CountAuditEventsDtoRequest,
+    ) -> Result<CountAuditEventsDtoResponse, AuditUseCaseError> {
+        Ok(CountAuditEventsDtoResponse(Self::events(&req.0).len() as i64))
+    }
+}
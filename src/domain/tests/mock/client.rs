@@ -0,0 +1,178 @@
+//! Mock of the client repository
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::client::ClientId;
+use crate::domain::repositories::client::ClientRepository;
+use crate::domain::repositories::client::dto::{
+    CountClientsDtoRequest, CountClientsDtoResponse, CreateClientDtoRequest, CreateClientDtoResponse,
+    CreateClientSecretDtoRequest, CreateClientSecretDtoResponse, DeleteClientDtoRequest, DeleteClientDtoResponse,
+    DeleteClientSecretDtoRequest, DeleteClientSecretDtoResponse, GetClientByIdDtoRequest, GetClientByIdDtoResponse,
+    GetClientSecretsDtoRequest, GetClientSecretsDtoResponse, GetClientsDtoRequest, GetClientsDtoResponse,
+    UpdateClientDtoRequest, UpdateClientDtoResponse,
+};
+use crate::domain::use_cases::application::delete_client::DeleteClientUseCaseResponse;
+use crate::domain::use_cases::application::delete_client_secret::DeleteClientSecretUseCaseResponse;
+use crate::domain::use_cases::application::update_client::UpdateClientUseCaseResponse;
+use crate::domain::use_cases::application::{
+    ApplicationUseCaseError, ClientSecretUseCaseResponse, ClientUseCaseResponse,
+};
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use async_trait::async_trait;
+use std::str::FromStr;
+
+pub const VALID_APPLICATION_ID: &str = "ffaa2c9c-872f-4e62-8302-d4586096cd13";
+pub const INVALID_APPLICATION_ID: &str = "b4dc6179-e538-449b-accd-a8a1f58631af";
+pub const VALID_CLIENT_ID: &str = "5b0e8a4c-2f0e-4a7e-9f7c-0e4b8d7f2a61";
+pub const INVALID_CLIENT_ID: &str = "c3a1e2f4-6d5b-4c8a-9e7f-1b2d3c4e5f60";
+pub const SINGLE_SECRET_CLIENT_ID: &str = "e7d6c5b4-a392-4817-b6a5-f4e3d2c1b0a9";
+pub const VALID_SECRET_ID: &str = "0a1b2c3d-4e5f-4a6b-8c7d-9e0f1a2b3c4d";
+pub const OTHER_SECRET_ID: &str = "9f8e7d6c-5b4a-4392-8170-6f5e4d3c2b1a";
+
+/// Client repository mock
+#[derive(Debug, Clone)]
+pub struct ClientRepositoryMock {}
+
+impl ClientRepositoryMock {
+    /// Client returned by the mock
+    fn client(application_id: ApplicationId, name: String) -> Result<ClientUseCaseResponse, ApplicationUseCaseError> {
+        let now = UtcDateTime::now();
+
+        Ok(ClientUseCaseResponse {
+            id: Id::from_str(VALID_CLIENT_ID).map_err(|_| ApplicationUseCaseError::InvalidId())?,
+            application_id,
+            name,
+            created_at: now.clone(),
+            updated_at: now,
+            deleted_at: None,
+        })
+    }
+
+    /// Secret returned by the mock
+    fn secret(client_id: &ClientId, id: &str) -> Result<ClientSecretUseCaseResponse, ApplicationUseCaseError> {
+        Ok(ClientSecretUseCaseResponse {
+            id: Id::from_str(id).map_err(|_| ApplicationUseCaseError::InvalidId())?,
+            client_id: client_id.clone(),
+            created_at: UtcDateTime::now(),
+        })
+    }
+
+    /// Fail if the application ID is not VALID_APPLICATION_ID or if the client is unknown
+    fn check_client(application_id: &ApplicationId, client_id: &ClientId) -> Result<(), ApplicationUseCaseError> {
+        if application_id.to_string() != VALID_APPLICATION_ID {
+            return Err(ApplicationUseCaseError::ApplicationNotFound());
+        }
+
+        match client_id.to_string().as_str() {
+            VALID_CLIENT_ID | SINGLE_SECRET_CLIENT_ID => Ok(()),
+            _ => Err(ApplicationUseCaseError::ClientNotFound()),
+        }
+    }
+}
+
+#[async_trait]
+impl ClientRepository for ClientRepositoryMock {
+    /// Create client
+    async fn create_client(
+        &self,
+        req: CreateClientDtoRequest,
+    ) -> Result<CreateClientDtoResponse, ApplicationUseCaseError> {
+        if req.client.application_id.to_string() != VALID_APPLICATION_ID {
+            return Err(ApplicationUseCaseError::ApplicationNotFound());
+        }
+
+        Ok(CreateClientDtoResponse(Self::client(
+            req.client.application_id,
+            req.client.name,
+        )?))
+    }
+
+    /// Count clients
+    async fn count_clients(
+        &self,
+        _req: CountClientsDtoRequest,
+    ) -> Result<CountClientsDtoResponse, ApplicationUseCaseError> {
+        Ok(CountClientsDtoResponse(1))
+    }
+
+    /// Get clients
+    async fn get_clients(&self, req: GetClientsDtoRequest) -> Result<GetClientsDtoResponse, ApplicationUseCaseError> {
+        Ok(GetClientsDtoResponse {
+            clients: vec![Self::client(req.0.application_id, "Client".to_string())?],
+            next_cursor: None,
+        })
+    }
+
+    /// Get client by ID
+    async fn get_client_by_id(
+        &self,
+        req: GetClientByIdDtoRequest,
+    ) -> Result<GetClientByIdDtoResponse, ApplicationUseCaseError> {
+        Self::check_client(&req.0.application_id, &req.0.id)?;
+
+        Ok(GetClientByIdDtoResponse(Self::client(
+            req.0.application_id,
+            "Client".to_string(),
+        )?))
+    }
+
+    /// Update client
+    async fn update_client(
+        &self,
+        req: UpdateClientDtoRequest,
+    ) -> Result<UpdateClientDtoResponse, ApplicationUseCaseError> {
+        Self::check_client(&req.0.application_id, &req.0.id)?;
+
+        Ok(UpdateClientDtoResponse(UpdateClientUseCaseResponse()))
+    }
+
+    /// Delete client
+    async fn delete_client(
+        &self,
+        req: DeleteClientDtoRequest,
+    ) -> Result<DeleteClientDtoResponse, ApplicationUseCaseError> {
+        Self::check_client(&req.0.application_id, &req.0.id)?;
+
+        Ok(DeleteClientDtoResponse(DeleteClientUseCaseResponse()))
+    }
+
+    /// Get client secrets
+    ///
+    /// SINGLE_SECRET_CLIENT_ID has only one secret, VALID_CLIENT_ID has two secrets
+    async fn get_client_secrets(
+        &self,
+        req: GetClientSecretsDtoRequest,
+    ) -> Result<GetClientSecretsDtoResponse, ApplicationUseCaseError> {
+        Self::check_client(&req.0.application_id, &req.0.client_id)?;
+
+        let mut secrets = vec![Self::secret(&req.0.client_id, VALID_SECRET_ID)?];
+        if req.0.client_id.to_string() == VALID_CLIENT_ID {
+            secrets.push(Self::secret(&req.0.client_id, OTHER_SECRET_ID)?);
+        }
+
+        Ok(GetClientSecretsDtoResponse(secrets))
+    }
+
+    /// Create client secret
+    async fn create_client_secret(
+        &self,
+        req: CreateClientSecretDtoRequest,
+    ) -> Result<CreateClientSecretDtoResponse, ApplicationUseCaseError> {
+        Self::check_client(&req.application_id, &req.client_id)?;
+
+        Ok(CreateClientSecretDtoResponse(Self::secret(
+            &req.client_id,
+            OTHER_SECRET_ID,
+        )?))
+    }
+
+    /// Delete client secret
+    async fn delete_client_secret(
+        &self,
+        req: DeleteClientSecretDtoRequest,
+    ) -> Result<DeleteClientSecretDtoResponse, ApplicationUseCaseError> {
+        Self::check_client(&req.0.application_id, &req.0.client_id)?;
+
+        Ok(DeleteClientSecretDtoResponse(DeleteClientSecretUseCaseResponse()))
+    }
+}
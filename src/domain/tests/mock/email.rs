@@ -0,0 +1,125 @@
+//! Mock of the email service, transport and repository
+
+use crate::domain::entities::email::{EmailMessage, QueuedEmail};
+use crate::domain::repositories::email::EmailRepository;
+use crate::domain::repositories::email::dto::{
+    GetDueEmailsDtoRequest, GetDueEmailsDtoResponse, UpdateEmailDtoRequest, UpdateEmailDtoResponse,
+};
+use crate::domain::services::email::forgotten_password::{
+    ForgottenPasswordEmailRequest, ForgottenPasswordEmailResponse,
+};
+use crate::domain::services::email::invitation::{InvitationEmailRequest, InvitationEmailResponse};
+use crate::domain::services::email::magic_link::{MagicLinkEmailRequest, MagicLinkEmailResponse};
+use crate::domain::services::email::{EmailService, EmailServiceError, EmailTransport};
+use crate::domain::use_cases::email::EmailUseCaseError;
+use crate::domain::value_objects::id::Id;
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+
+pub const INVALID_EMAIL_TO: &str = "invalid@test.com";
+
+/// Email service mock
+#[derive(Debug, Clone)]
+pub struct EmailServiceMock {}
+
+impl EmailServiceMock {
+    /// Fail if the recipient is INVALID_EMAIL_TO
+    fn build(&self, email_to: String) -> Result<EmailMessage, EmailServiceError> {
+        match email_to.as_str() {
+            INVALID_EMAIL_TO => Err(EmailServiceError::InvalidEmailTo("Invalid email".to_string())),
+            _ => Ok(EmailMessage {
+                from_address: "from@test.com".to_string(),
+                to_addresses: vec![email_to],
+                subject: "Subject".to_string(),
+                text_body: Some("Body".to_string()),
+                html_body: None,
+            }),
+        }
+    }
+}
+
+impl EmailService for EmailServiceMock {
+    /// Build email for a forgotten password request
+    fn forgotten_password(
+        &self,
+        request: ForgottenPasswordEmailRequest,
+    ) -> Result<ForgottenPasswordEmailResponse, EmailServiceError> {
+        self.build(request.email.to_string())
+            .map(ForgottenPasswordEmailResponse)
+    }
+
+    /// Build email with a magic link
+    fn magic_link(&self, request: MagicLinkEmailRequest) -> Result<MagicLinkEmailResponse, EmailServiceError> {
+        self.build(request.email.to_string()).map(MagicLinkEmailResponse)
+    }
+
+    /// Build email with an invitation link
+    fn invitation(&self, request: InvitationEmailRequest) -> Result<InvitationEmailResponse, EmailServiceError> {
+        self.build(request.email.to_string()).map(InvitationEmailResponse)
+    }
+}
+
+/// Email transport mock
+///
+/// Sending fails if the recipient is INVALID_EMAIL_TO.
+#[derive(Debug, Clone)]
+pub struct EmailTransportMock {}
+
+#[async_trait]
+impl EmailTransport for EmailTransportMock {
+    async fn send(&self, message: &EmailMessage) -> Result<(), EmailServiceError> {
+        if message.to_addresses.iter().any(|to| to == INVALID_EMAIL_TO) {
+            return Err(EmailServiceError::SendError("Failed to send email".to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn check(&self) -> Result<(), EmailServiceError> {
+        Ok(())
+    }
+}
+
+/// Email outbox repository mock
+///
+/// Updates are recorded.
+#[derive(Debug, Clone, Default)]
+pub struct EmailRepositoryMock {
+    pub updates: Arc<Mutex<Vec<UpdateEmailDtoRequest>>>,
+}
+
+impl EmailRepositoryMock {
+    /// Queued email returned by the mock
+    fn email(to: &str, attempts: u32) -> QueuedEmail {
+        QueuedEmail {
+            id: Id::new().unwrap(),
+            message: EmailMessage {
+                from_address: "from@test.com".to_string(),
+                to_addresses: vec![to.to_string()],
+                subject: "Subject".to_string(),
+                text_body: Some("Body".to_string()),
+                html_body: None,
+            },
+            attempts,
+        }
+    }
+}
+
+#[async_trait]
+impl EmailRepository for EmailRepositoryMock {
+    /// An email which is sent, a first failure and a third failure
+    async fn get_due_emails(&self, _req: GetDueEmailsDtoRequest) -> Result<GetDueEmailsDtoResponse, EmailUseCaseError> {
+        Ok(GetDueEmailsDtoResponse(vec![
+            Self::email("to@test.com", 0),
+            Self::email(INVALID_EMAIL_TO, 0),
+            Self::email(INVALID_EMAIL_TO, 2),
+        ]))
+    }
+
+    /// Record the update
+    async fn update_email(&self, req: UpdateEmailDtoRequest) -> Result<UpdateEmailDtoResponse, EmailUseCaseError> {
+        self.updates.lock().unwrap().push(req);
+
+        Ok(UpdateEmailDtoResponse())
+    }
+}
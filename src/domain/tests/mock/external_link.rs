@@ -7,11 +7,17 @@ use crate::domain::{
             CountExternalLinksDtoRequest, CountExternalLinksDtoResponse, CreateExternalLinkDtoRequest,
             CreateExternalLinkDtoResponse, DeleteExternalLinkDtoRequest, DeleteExternalLinkDtoResponse,
             GetExternalLinkByIdDtoRequest, GetExternalLinkByIdDtoResponse, GetExternalLinksDtoRequest,
-            GetExternalLinksDtoResponse, RestoreExternalLinkDtoRequest, RestoreExternalLinkDtoResponse,
-            UpdateExternalLinkDtoRequest, UpdateExternalLinkDtoResponse,
+            GetExternalLinksDtoResponse, GetLinkedUserDtoRequest, GetLinkedUserDtoResponse, LinkApplicationDtoRequest,
+            LinkApplicationDtoResponse, LinkUserDtoRequest, LinkUserDtoResponse, RestoreExternalLinkDtoRequest,
+            RestoreExternalLinkDtoResponse, UnlinkApplicationDtoRequest, UnlinkApplicationDtoResponse,
+            UnlinkUserDtoRequest, UnlinkUserDtoResponse, UpdateExternalLinkDtoRequest, UpdateExternalLinkDtoResponse,
         },
     },
-    use_cases::external_link::{ExternalLinkUseCaseError, ExternalLinkUseCaseResponse},
+    use_cases::external_link::{
+        ExternalLinkApplicationUseCaseResponse, ExternalLinkUseCaseError, ExternalLinkUseCaseResponse,
+        ExternalLinkUserUseCaseResponse, unlink_application::UnlinkApplicationUseCaseResponse,
+        unlink_user::UnlinkUserUseCaseResponse,
+    },
     value_objects::{datetime::UtcDateTime, id::Id},
 };
 use async_trait::async_trait;
@@ -21,6 +27,12 @@ pub const VALID_EXTERNAL_LINK_ID: &str = "ffaa2c9c-872f-4e62-8302-d4586096cd13";
 pub const INVALID_EXTERNAL_LINK_ID: &str = "b4dc6179-e538-449b-accd-a8a1f58631af";
 pub const VALID_EXTERNAL_LINK_NAME: &str = "Test external link";
 pub const INVALID_EXTERNAL_LINK_NAME: &str = "Test invalid external link";
+pub const VALID_USER_ID: &str = "d05659eb-007f-42bf-96a8-6005d9840a22";
+pub const INVALID_USER_ID: &str = "3f2b8e61-95c4-4d7a-b0e8-7c6d5a4b3e21";
+pub const VALID_APPLICATION_ID: &str = "8c1d2e3f-4a5b-4c6d-8e7f-9a0b1c2d3e4f";
+pub const INVALID_APPLICATION_ID: &str = "e4f5a6b7-c8d9-4e0f-a1b2-c3d4e5f6a7b8";
+pub const VALID_EXTERNAL_USER_ID: &str = "partner-user-42";
+pub const USED_EXTERNAL_USER_ID: &str = "partner-user-7";
 
 /// External link repository mock
 #[derive(Debug, Clone)]
@@ -39,6 +51,7 @@ impl ExternalLinkRepository for ExternalLinkRepositoryMock {
             Ok(CreateExternalLinkDtoResponse(ExternalLinkUseCaseResponse {
                 id: Id::from_str(VALID_EXTERNAL_LINK_ID).unwrap(),
                 name: VALID_EXTERNAL_LINK_NAME.to_string(),
+                provider: None,
                 created_at: now.clone(),
                 updated_at: now,
                 deleted_at: None,
@@ -69,9 +82,21 @@ impl ExternalLinkRepository for ExternalLinkRepositoryMock {
     /// Get external link by ID
     async fn get_by_id(
         &self,
-        _req: GetExternalLinkByIdDtoRequest,
+        req: GetExternalLinkByIdDtoRequest,
     ) -> Result<GetExternalLinkByIdDtoResponse, ExternalLinkUseCaseError> {
-        todo!()
+        if req.0.id.to_string() != VALID_EXTERNAL_LINK_ID {
+            return Err(ExternalLinkUseCaseError::ExternalLinkNotFound());
+        }
+        let now = UtcDateTime::now();
+
+        Ok(GetExternalLinkByIdDtoResponse(ExternalLinkUseCaseResponse {
+            id: req.0.id,
+            name: VALID_EXTERNAL_LINK_NAME.to_string(),
+            provider: None,
+            created_at: now.clone(),
+            updated_at: now,
+            deleted_at: None,
+        }))
     }
 
     /// Update external link
@@ -97,4 +122,72 @@ impl ExternalLinkRepository for ExternalLinkRepositoryMock {
     ) -> Result<RestoreExternalLinkDtoResponse, ExternalLinkUseCaseError> {
         todo!()
     }
+
+    /// Link a user to an external link or replace its external user ID
+    async fn link_user(&self, req: LinkUserDtoRequest) -> Result<LinkUserDtoResponse, ExternalLinkUseCaseError> {
+        if req.0.external_user_id == USED_EXTERNAL_USER_ID {
+            return Err(ExternalLinkUseCaseError::ExternalUserIdAlreadyUsed());
+        }
+
+        Ok(LinkUserDtoResponse(ExternalLinkUserUseCaseResponse {
+            external_link_id: req.0.external_link_id,
+            user_id: req.0.user_id,
+            external_user_id: req.0.external_user_id,
+            created_at: UtcDateTime::now(),
+        }))
+    }
+
+    /// Unlink a user from an external link
+    async fn unlink_user(&self, req: UnlinkUserDtoRequest) -> Result<UnlinkUserDtoResponse, ExternalLinkUseCaseError> {
+        if req.0.user_id.to_string() != VALID_USER_ID {
+            return Err(ExternalLinkUseCaseError::ExternalLinkUserNotFound());
+        }
+
+        Ok(UnlinkUserDtoResponse(UnlinkUserUseCaseResponse()))
+    }
+
+    /// Get a linked user from its external user ID
+    async fn get_linked_user(
+        &self,
+        req: GetLinkedUserDtoRequest,
+    ) -> Result<GetLinkedUserDtoResponse, ExternalLinkUseCaseError> {
+        if req.0.external_user_id != VALID_EXTERNAL_USER_ID {
+            return Ok(GetLinkedUserDtoResponse(None));
+        }
+
+        Ok(GetLinkedUserDtoResponse(Some(ExternalLinkUserUseCaseResponse {
+            external_link_id: req.0.external_link_id,
+            user_id: Id::from_str(VALID_USER_ID).unwrap(),
+            external_user_id: req.0.external_user_id,
+            created_at: UtcDateTime::now(),
+        })))
+    }
+
+    /// Link an application to an external link
+    async fn link_application(
+        &self,
+        req: LinkApplicationDtoRequest,
+    ) -> Result<LinkApplicationDtoResponse, ExternalLinkUseCaseError> {
+        if req.0.application_id.to_string() != VALID_APPLICATION_ID {
+            return Err(ExternalLinkUseCaseError::ApplicationNotFound());
+        }
+
+        Ok(LinkApplicationDtoResponse(ExternalLinkApplicationUseCaseResponse {
+            external_link_id: req.0.external_link_id,
+            application_id: req.0.application_id,
+            created_at: UtcDateTime::now(),
+        }))
+    }
+
+    /// Unlink an application from an external link
+    async fn unlink_application(
+        &self,
+        req: UnlinkApplicationDtoRequest,
+    ) -> Result<UnlinkApplicationDtoResponse, ExternalLinkUseCaseError> {
+        if req.0.application_id.to_string() != VALID_APPLICATION_ID {
+            return Err(ExternalLinkUseCaseError::ExternalLinkApplicationNotFound());
+        }
+
+        Ok(UnlinkApplicationDtoResponse(UnlinkApplicationUseCaseResponse()))
+    }
 }
@@ -0,0 +1,118 @@
+//! Mock of the federation repository and of the identity provider service
+
+use crate::domain::entities::oidc_login_state::OidcLoginState;
+use crate::domain::repositories::federation::FederationRepository;
+use crate::domain::repositories::federation::dto::{
+    ConsumeLoginStateDtoRequest, ConsumeLoginStateDtoResponse, CreateLoginStateDtoRequest, CreateLoginStateDtoResponse,
+    FindOrCreateUserDtoRequest, FindOrCreateUserDtoResponse, GetProviderDtoRequest, GetProviderDtoResponse,
+};
+use crate::domain::services::identity_provider::{
+    AuthorizationUrlRequest, ExchangeCodeRequest, IdentityClaims, IdentityProviderError, IdentityProviderService,
+};
+use crate::domain::use_cases::federation::FederationUseCaseError;
+use crate::domain::value_objects::id::Id;
+use crate::domain::value_objects::oidc_provider::OidcProvider;
+use async_trait::async_trait;
+use std::str::FromStr;
+
+pub const VALID_EXTERNAL_LINK_ID: &str = "ffaa2c9c-872f-4e62-8302-d4586096cd13";
+pub const OTHER_EXTERNAL_LINK_ID: &str = "b4dc6179-e538-449b-accd-a8a1f58631af";
+pub const VALID_APPLICATION_ID: &str = "ffaa2c9c-872f-4e62-8302-d4586096cd13";
+pub const VALID_USER_ID: &str = "d05659eb-007f-42bf-96a8-6005d9840a22";
+pub const VALID_EXTERNAL_USER_ID: &str = "partner-user-42";
+pub const VALID_STATE: &str = "6f1d0a3e-5b2c-4e8f-9a7d-3c4b5a6e7f80";
+pub const VALID_NONCE: &str = "0f9e8d7c6b5a49382716a5b4c3d2e1f0";
+pub const VALID_CODE: &str = "valid-code";
+pub const INVALID_CODE: &str = "invalid-code";
+
+/// Federation repository mock
+#[derive(Debug, Clone)]
+pub struct FederationRepositoryMock {}
+
+#[async_trait]
+impl FederationRepository for FederationRepositoryMock {
+    /// Get the OpenID Connect provider of an external link attached to an application
+    async fn get_provider(&self, req: GetProviderDtoRequest) -> Result<GetProviderDtoResponse, FederationUseCaseError> {
+        if req.external_link_id.to_string() != VALID_EXTERNAL_LINK_ID
+            || req.application_id.to_string() != VALID_APPLICATION_ID
+        {
+            return Ok(GetProviderDtoResponse(None));
+        }
+
+        Ok(GetProviderDtoResponse(Some(
+            OidcProvider::new("https://idp.test.com", "client-id", "client-secret", vec![]).unwrap(),
+        )))
+    }
+
+    /// Save a login state until the provider callback
+    async fn create_login_state(
+        &self,
+        _req: CreateLoginStateDtoRequest,
+    ) -> Result<CreateLoginStateDtoResponse, FederationUseCaseError> {
+        Ok(CreateLoginStateDtoResponse())
+    }
+
+    /// Get and delete an unexpired login state
+    async fn consume_login_state(
+        &self,
+        req: ConsumeLoginStateDtoRequest,
+    ) -> Result<ConsumeLoginStateDtoResponse, FederationUseCaseError> {
+        if req.state != VALID_STATE {
+            return Ok(ConsumeLoginStateDtoResponse(None));
+        }
+
+        let mut login_state = OidcLoginState::new(
+            Id::from_str(VALID_EXTERNAL_LINK_ID).unwrap(),
+            Id::from_str(VALID_APPLICATION_ID).unwrap(),
+            10,
+        );
+        login_state.state = VALID_STATE.to_string();
+        login_state.nonce = VALID_NONCE.to_string();
+
+        Ok(ConsumeLoginStateDtoResponse(Some(login_state)))
+    }
+
+    /// Get the user linked to an external user
+    async fn find_or_create_user(
+        &self,
+        req: FindOrCreateUserDtoRequest,
+    ) -> Result<FindOrCreateUserDtoResponse, FederationUseCaseError> {
+        if req.email.is_none() && req.external_user_id != VALID_EXTERNAL_USER_ID {
+            return Err(FederationUseCaseError::EmailRequired());
+        }
+
+        Ok(FindOrCreateUserDtoResponse(Id::from_str(VALID_USER_ID).unwrap()))
+    }
+}
+
+/// Identity provider service mock
+#[derive(Debug, Clone)]
+pub struct IdentityProviderServiceMock {}
+
+#[async_trait]
+impl IdentityProviderService for IdentityProviderServiceMock {
+    /// Build the URL of the provider login page
+    async fn authorization_url(&self, request: AuthorizationUrlRequest) -> Result<String, IdentityProviderError> {
+        Ok(format!(
+            "{}/authorize?state={}&nonce={}",
+            request.provider.issuer(),
+            request.state,
+            request.nonce
+        ))
+    }
+
+    /// Exchange an authorization code for the claims of a validated ID token
+    async fn exchange_code(&self, request: ExchangeCodeRequest) -> Result<IdentityClaims, IdentityProviderError> {
+        if request.code != VALID_CODE || request.nonce != VALID_NONCE {
+            return Err(IdentityProviderError::InvalidIdToken("invalid code".to_string()));
+        }
+
+        Ok(IdentityClaims {
+            subject: VALID_EXTERNAL_USER_ID.to_string(),
+            email: Some("john.doe@test.com".to_string()),
+            email_verified: true,
+            given_name: Some("John".to_string()),
+            family_name: Some("Doe".to_string()),
+        })
+    }
+}
@@ -0,0 +1,66 @@
+//! Mock of the introspection repository
+
+use crate::domain::repositories::introspection::IntrospectionRepository;
+use crate::domain::repositories::introspection::dto::{
+    ClientCredentials, GetClientCredentialsDtoRequest, GetClientCredentialsDtoResponse, GetUserScopesDtoRequest,
+    GetUserScopesDtoResponse, IsAccessTokenActiveDtoRequest, IsAccessTokenActiveDtoResponse,
+};
+use crate::domain::use_cases::introspection::IntrospectionUseCaseError;
+use crate::domain::value_objects::client_secret::ClientSecret;
+use crate::domain::value_objects::id::Id;
+use crate::domain::value_objects::password_policy::PasswordPolicy;
+use crate::domain::value_objects::scope_id::ScopeId;
+use async_trait::async_trait;
+use std::str::FromStr;
+
+pub const VALID_CLIENT_ID: &str = "3f0b5c8e-2d41-4a7b-9e6f-1c2d3e4f5a6b";
+pub const VALID_CLIENT_SECRET: &str = "Vf3kPz8QmW1xYt7LsR2nBc6HdJ9gKa4E";
+pub const VALID_APPLICATION_ID: &str = "ffaa2c9c-872f-4e62-8302-d4586096cd13";
+pub const OTHER_APPLICATION_ID: &str = "b4dc6179-e538-449b-accd-a8a1f58631af";
+pub const VALID_USER_ID: &str = "d05659eb-007f-42bf-96a8-6005d9840a22";
+pub const DISABLED_USER_ID: &str = "5c2e7f9a-1b3d-4e6f-8a0c-2d4f6b8e0a1c";
+pub const VALID_SCOPE_ID: &str = "users:read";
+
+/// Introspection repository mock
+#[derive(Debug, Clone)]
+pub struct IntrospectionRepositoryMock {}
+
+#[async_trait]
+impl IntrospectionRepository for IntrospectionRepositoryMock {
+    /// Get an active client with its hashed secrets
+    async fn get_client_credentials(
+        &self,
+        req: GetClientCredentialsDtoRequest,
+    ) -> Result<GetClientCredentialsDtoResponse, IntrospectionUseCaseError> {
+        if req.0.to_string() != VALID_CLIENT_ID {
+            return Ok(GetClientCredentialsDtoResponse(None));
+        }
+
+        Ok(GetClientCredentialsDtoResponse(Some(ClientCredentials {
+            client_id: req.0,
+            application_id: Id::from_str(VALID_APPLICATION_ID).unwrap(),
+            secrets: vec![
+                ClientSecret::generate().hash(&PasswordPolicy::default()).unwrap(),
+                ClientSecret::new(VALID_CLIENT_SECRET)
+                    .hash(&PasswordPolicy::default())
+                    .unwrap(),
+            ],
+        })))
+    }
+
+    /// Check that an access token has not been revoked and that its user is still enabled
+    async fn is_access_token_active(
+        &self,
+        req: IsAccessTokenActiveDtoRequest,
+    ) -> Result<IsAccessTokenActiveDtoResponse, IntrospectionUseCaseError> {
+        Ok(IsAccessTokenActiveDtoResponse(req.user_id.to_string() == VALID_USER_ID))
+    }
+
+    /// Get the user scopes of an application
+    async fn get_user_scopes(
+        &self,
+        _req: GetUserScopesDtoRequest,
+    ) -> Result<GetUserScopesDtoResponse, IntrospectionUseCaseError> {
+        Ok(GetUserScopesDtoResponse(vec![ScopeId::new(VALID_SCOPE_ID).unwrap()]))
+    }
+}
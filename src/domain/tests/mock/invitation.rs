@@ -0,0 +1,141 @@
+//! Mock of the invitation repository
+
+use crate::domain::entities::invitation::InvitationId;
+use crate::domain::repositories::invitation::InvitationRepository;
+use crate::domain::repositories::invitation::dto::{
+    AcceptInvitationDtoRequest, AcceptInvitationDtoResponse, CountInvitationsDtoRequest, CountInvitationsDtoResponse,
+    CreateInvitationDtoRequest, CreateInvitationDtoResponse, GetInvitationByIdDtoRequest, GetInvitationByIdDtoResponse,
+    GetInvitationsDtoRequest, GetInvitationsDtoResponse, RenewInvitationDtoRequest, RenewInvitationDtoResponse,
+    RevokeInvitationDtoRequest, RevokeInvitationDtoResponse,
+};
+use crate::domain::use_cases::invitation::revoke_invitation::RevokeInvitationUseCaseResponse;
+use crate::domain::use_cases::invitation::{InvitationUseCaseError, InvitationUseCaseResponse};
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::email::Email;
+use crate::domain::value_objects::id::Id;
+use async_trait::async_trait;
+use std::str::FromStr;
+
+pub const VALID_ID: &str = "8b1c2bd6-6b3d-4b1c-9a5e-3c1f0f3fa1d2";
+pub const INVITATION_NOT_FOUND_ID: &str = "0f7d0a54-0c55-4a6d-8e43-4b4b3f0c9e11";
+pub const VALID_USER_ID: &str = "3288fb86-db99-471d-95bc-1451c7ec6f7b";
+pub const VALID_EMAIL: &str = "john.doe@test.com";
+pub const INVALID_EMAIL: &str = "already.used@test.com";
+pub const VALID_TOKEN: &str = "valid_token";
+pub const INVALID_TOKEN: &str = "invalid_token";
+
+/// Invitation repository mock
+#[derive(Debug, Clone)]
+pub struct InvitationRepositoryMock {}
+
+impl InvitationRepositoryMock {
+    /// Pending invitation returned by the mock
+    fn invitation(email: Email) -> Result<InvitationUseCaseResponse, InvitationUseCaseError> {
+        let now = UtcDateTime::now();
+
+        Ok(InvitationUseCaseResponse {
+            id: Id::from_str(VALID_ID)?,
+            user_id: Id::from_str(VALID_USER_ID)?,
+            email,
+            lastname: "Doe".to_string(),
+            firstname: "John".to_string(),
+            locale: None,
+            expired_at: now.clone(),
+            created_at: now.clone(),
+            updated_at: now,
+        })
+    }
+
+    /// Fail if the ID is not VALID_ID
+    fn check_id(id: &InvitationId) -> Result<(), InvitationUseCaseError> {
+        match id.to_string().as_str() {
+            VALID_ID => Ok(()),
+            _ => Err(InvitationUseCaseError::InvitationNotFound()),
+        }
+    }
+}
+
+#[async_trait]
+impl InvitationRepository for InvitationRepositoryMock {
+    /// Create an invitation
+    ///
+    /// If the email is INVALID_EMAIL, return an error (email already used)
+    async fn create(
+        &self,
+        req: CreateInvitationDtoRequest,
+    ) -> Result<CreateInvitationDtoResponse, InvitationUseCaseError> {
+        if req.email.to_string().as_str() == INVALID_EMAIL {
+            return Err(InvitationUseCaseError::DatabaseError(
+                "Invitation creation error".to_string(),
+            ));
+        }
+
+        Ok(CreateInvitationDtoResponse(Self::invitation(req.email)?))
+    }
+
+    /// Count invitations
+    async fn count_invitations(
+        &self,
+        _req: CountInvitationsDtoRequest,
+    ) -> Result<CountInvitationsDtoResponse, InvitationUseCaseError> {
+        Ok(CountInvitationsDtoResponse(1))
+    }
+
+    /// Get invitations
+    async fn get_invitations(
+        &self,
+        _req: GetInvitationsDtoRequest,
+    ) -> Result<GetInvitationsDtoResponse, InvitationUseCaseError> {
+        Ok(GetInvitationsDtoResponse {
+            invitations: vec![Self::invitation(Email::new(VALID_EMAIL)?)?],
+            next_cursor: None,
+        })
+    }
+
+    /// Get invitation by ID
+    async fn get_by_id(
+        &self,
+        req: GetInvitationByIdDtoRequest,
+    ) -> Result<GetInvitationByIdDtoResponse, InvitationUseCaseError> {
+        Self::check_id(&req.id)?;
+
+        Ok(GetInvitationByIdDtoResponse(Self::invitation(Email::new(
+            VALID_EMAIL,
+        )?)?))
+    }
+
+    /// Renew invitation
+    async fn renew(
+        &self,
+        req: RenewInvitationDtoRequest,
+    ) -> Result<RenewInvitationDtoResponse, InvitationUseCaseError> {
+        Self::check_id(&req.id)?;
+
+        Ok(RenewInvitationDtoResponse())
+    }
+
+    /// Revoke invitation
+    async fn revoke(
+        &self,
+        req: RevokeInvitationDtoRequest,
+    ) -> Result<RevokeInvitationDtoResponse, InvitationUseCaseError> {
+        Self::check_id(&req.0.id)?;
+
+        Ok(RevokeInvitationDtoResponse(RevokeInvitationUseCaseResponse()))
+    }
+
+    /// Accept invitation
+    ///
+    /// If the token is VALID_TOKEN, return a success, else return an error
+    async fn accept(
+        &self,
+        req: AcceptInvitationDtoRequest,
+    ) -> Result<AcceptInvitationDtoResponse, InvitationUseCaseError> {
+        match req.0.token.as_str() {
+            VALID_TOKEN => Ok(AcceptInvitationDtoResponse {
+                user_id: Id::from_str(VALID_USER_ID)?,
+            }),
+            _ => Err(InvitationUseCaseError::InvitationNotFound()),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Mock of the magic link repository
+
+use crate::domain::repositories::magic_link::MagicLinkRepository;
+use crate::domain::repositories::magic_link::dto::{
+    ConsumeMagicLinkDtoRequest, ConsumeMagicLinkDtoResponse, CreateUpdateMagicLinkDtoRequest,
+    CreateUpdateMagicLinkDtoResponse,
+};
+use crate::domain::tests::mock::user::EXPIRED_PASSWORD_ID;
+use crate::domain::use_cases::user::UserUseCaseError;
+use crate::domain::value_objects::id::Id;
+use async_trait::async_trait;
+use std::str::FromStr;
+
+pub const VALID_TOKEN: &str = "valid_token";
+pub const INVALID_TOKEN: &str = "invalid_token";
+pub const VALID_USER_ID: &str = "3288fb86-db99-471d-95bc-1451c7ec6f7b";
+
+/// Token of a user whose password has expired (EXPIRED_PASSWORD_ID)
+pub const EXPIRED_PASSWORD_TOKEN: &str = "expired_password_token";
+
+/// Magic link repository mock
+#[derive(Debug, Clone)]
+pub struct MagicLinkRepositoryMock {}
+
+#[async_trait]
+impl MagicLinkRepository for MagicLinkRepositoryMock {
+    /// Add or update magic link
+    ///
+    /// If the user ID is VALID_USER_ID, return a success, else return an error
+    async fn create_or_update(
+        &self,
+        req: CreateUpdateMagicLinkDtoRequest,
+    ) -> Result<CreateUpdateMagicLinkDtoResponse, UserUseCaseError> {
+        if req.magic_link.user_id.to_string().as_str() == VALID_USER_ID {
+            Ok(CreateUpdateMagicLinkDtoResponse())
+        } else {
+            Err(UserUseCaseError::DatabaseError(
+                "Failed to create or update magic link".to_string(),
+            ))
+        }
+    }
+
+    /// Consume magic link
+    async fn consume(&self, req: ConsumeMagicLinkDtoRequest) -> Result<ConsumeMagicLinkDtoResponse, UserUseCaseError> {
+        if req.token.as_str() == VALID_TOKEN {
+            Ok(ConsumeMagicLinkDtoResponse {
+                user_id: Some(Id::from_str(VALID_USER_ID)?),
+            })
+        } else if req.token.as_str() == EXPIRED_PASSWORD_TOKEN {
+            Ok(ConsumeMagicLinkDtoResponse {
+                user_id: Some(Id::from_str(EXPIRED_PASSWORD_ID)?),
+            })
+        } else {
+            Ok(ConsumeMagicLinkDtoResponse { user_id: None })
+        }
+    }
+}
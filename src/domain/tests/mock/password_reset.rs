@@ -5,6 +5,7 @@ use crate::domain::repositories::password_reset::dto::{
     CreateUpdatePasswordResetDtoRequest, CreateUpdatePasswordResetDtoResponse, DeletePasswordResetDtoRequest,
     DeletePasswordResetDtoResponse, GetUserIdFromTokenDtoRequest, GetUserIdFromTokenDtoResponse,
 };
+use crate::domain::tests::mock::user::EXPIRED_PASSWORD_ID;
 use crate::domain::use_cases::user::UserUseCaseError;
 use crate::domain::value_objects::id::Id;
 use async_trait::async_trait;
@@ -21,12 +22,12 @@ pub struct PasswordResetRepositoryMock {}
 
 #[async_trait]
 impl PasswordResetRepository for PasswordResetRepositoryMock {
-    /// Add or update forgotten password request
+    /// Add or update forgotten password request (for VALID_USER_ID or the user whose password has expired)
     async fn create_or_update(
         &self,
         req: CreateUpdatePasswordResetDtoRequest,
     ) -> Result<CreateUpdatePasswordResetDtoResponse, UserUseCaseError> {
-        if req.0.token.as_str() == VALID_TOKEN {
+        if [VALID_USER_ID, EXPIRED_PASSWORD_ID].contains(&req.password_reset.user_id.to_string().as_str()) {
             Ok(CreateUpdatePasswordResetDtoResponse())
         } else {
             Err(UserUseCaseError::DatabaseError(
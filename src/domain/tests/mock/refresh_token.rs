@@ -4,8 +4,11 @@ use crate::domain::repositories::refresh_token::RefreshTokenRepository;
 use crate::domain::repositories::refresh_token::dto::{
     CreateRefreshTokenDtoRequest, CreateRefreshTokenDtoResponse, DeleteExpiredRefreshTokensDtoRequest,
     DeleteExpiredRefreshTokensDtoResponse, DeleteRefreshTokenDtoRequest, DeleteRefreshTokenDtoResponse,
-    GetRefreshTokenDtoRequest, GetRefreshTokenDtoResponse,
+    DeleteUserRefreshTokensDtoRequest, DeleteUserRefreshTokensDtoResponse, GetRefreshTokenDtoRequest,
+    GetRefreshTokenDtoResponse,
 };
+use crate::domain::tests::mock::application::{INVALID_APPLICATION_ID, VALID_APPLICATION_ID};
+use crate::domain::tests::mock::user::EXPIRED_PASSWORD_ID;
 use crate::domain::use_cases::user::UserUseCaseError;
 use crate::domain::value_objects::id::Id;
 use async_trait::async_trait;
@@ -14,6 +17,12 @@ use std::str::FromStr;
 pub const VALID_REFRESH_TOKEN_ID: &str = "3288fb86-db99-471d-95bc-1451c7ec6f7b";
 pub const INVALID_REFRESH_TOKEN_ID: &str = "3288fb86-db99-471d-95bc-1451c7ec6f7a";
 
+/// Refresh token issued for another application than VALID_APPLICATION_ID
+pub const OTHER_APPLICATION_REFRESH_TOKEN_ID: &str = "3288fb86-db99-471d-95bc-1451c7ec6f7c";
+
+/// Refresh token of a user whose password has expired (EXPIRED_PASSWORD_ID)
+pub const EXPIRED_PASSWORD_REFRESH_TOKEN_ID: &str = "3288fb86-db99-471d-95bc-1451c7ec6f7d";
+
 /// Refresh token repository mock
 #[derive(Debug, Clone)]
 pub struct RefreshTokenRepositoryMock {}
@@ -36,7 +45,22 @@ impl RefreshTokenRepository for RefreshTokenRepositoryMock {
         let token_id = req.0;
 
         if token_id == Id::from_str(VALID_REFRESH_TOKEN_ID)? {
-            return Ok(GetRefreshTokenDtoResponse { user_id: Id::new()? });
+            return Ok(GetRefreshTokenDtoResponse {
+                user_id: Id::new()?,
+                application_id: Id::from_str(VALID_APPLICATION_ID)?,
+            });
+        }
+        if token_id == Id::from_str(OTHER_APPLICATION_REFRESH_TOKEN_ID)? {
+            return Ok(GetRefreshTokenDtoResponse {
+                user_id: Id::new()?,
+                application_id: Id::from_str(INVALID_APPLICATION_ID)?,
+            });
+        }
+        if token_id == Id::from_str(EXPIRED_PASSWORD_REFRESH_TOKEN_ID)? {
+            return Ok(GetRefreshTokenDtoResponse {
+                user_id: Id::from_str(EXPIRED_PASSWORD_ID)?,
+                application_id: Id::from_str(VALID_APPLICATION_ID)?,
+            });
         }
 
         Err(UserUseCaseError::RefreshTokenCreationError(
@@ -51,7 +75,9 @@ impl RefreshTokenRepository for RefreshTokenRepositoryMock {
     ) -> Result<DeleteRefreshTokenDtoResponse, UserUseCaseError> {
         let token_id = req.0;
 
-        if token_id == Id::from_str(VALID_REFRESH_TOKEN_ID)? {
+        if token_id == Id::from_str(VALID_REFRESH_TOKEN_ID)?
+            || token_id == Id::from_str(EXPIRED_PASSWORD_REFRESH_TOKEN_ID)?
+        {
             return Ok(DeleteRefreshTokenDtoResponse());
         }
 
@@ -67,4 +93,12 @@ impl RefreshTokenRepository for RefreshTokenRepositoryMock {
     ) -> Result<DeleteExpiredRefreshTokensDtoResponse, UserUseCaseError> {
         Ok(DeleteExpiredRefreshTokensDtoResponse { deleted: 23 })
     }
+
+    /// Delete all the refresh tokens of a user
+    async fn delete_user_refresh_tokens(
+        &self,
+        _req: DeleteUserRefreshTokensDtoRequest,
+    ) -> Result<DeleteUserRefreshTokensDtoResponse, UserUseCaseError> {
+        Ok(DeleteUserRefreshTokensDtoResponse { deleted: 2 })
+    }
 }
@@ -5,9 +5,11 @@ use crate::domain::repositories::user::UserRepository;
 use crate::domain::repositories::user::dto::{
     CountUsersDtoRequest, CountUsersDtoResponse, CreateUserDtoRequest, CreateUserDtoResponse, DeleteUserDtoRequest,
     DeleteUserDtoResponse, GetAccessTokenInformationDtoRequest, GetAccessTokenInformationDtoResponse,
-    GetUserByEmailDtoRequest, GetUserByEmailDtoResponse, GetUserByIdDtoRequest, GetUserByIdDtoResponse,
-    GetUsersDtoRequest, GetUsersDtoResponse, RestoreUserDtoRequest, RestoreUserDtoResponse, UpdatePasswordDtoRequest,
-    UpdatePasswordDtoResponse,
+    GetPasswordChangedAtDtoRequest, GetPasswordChangedAtDtoResponse, GetPasswordHistoryDtoRequest,
+    GetPasswordHistoryDtoResponse, GetUserByEmailDtoRequest, GetUserByEmailDtoResponse, GetUserByIdDtoRequest,
+    GetUserByIdDtoResponse, GetUsersDtoRequest, GetUsersDtoResponse, RehashPasswordDtoRequest,
+    RehashPasswordDtoResponse, RestoreUserDtoRequest, RestoreUserDtoResponse, SearchUsersDtoRequest,
+    SearchUsersDtoResponse, UpdatePasswordDtoRequest, UpdatePasswordDtoResponse,
 };
 use crate::domain::use_cases::user::delete_user::DeleteUserUseCaseResponse;
 use crate::domain::use_cases::user::restore_user::RestoreUserUseCaseResponse;
@@ -16,6 +18,7 @@ use crate::domain::value_objects::datetime::UtcDateTime;
 use crate::domain::value_objects::email::Email;
 use crate::domain::value_objects::password::Password;
 use async_trait::async_trait;
+use chrono::{Duration, Utc};
 use std::str::FromStr;
 
 pub const VALID_ID: &str = "3288fb86-db99-471d-95bc-1451c7ec6f7b";
@@ -24,6 +27,8 @@ pub const VALID_PASSWORD: &str = "1A,R;(9h0Y&gYH5=7eY!ff";
 pub const INVALID_ID: &str = "1a811ea9-2c02-4acc-ae9f-c8f8522702f3";
 pub const INVALID_EMAIL: &str = "jane.doe@test.com";
 pub const EMAIL_NOT_FOUND: &str = "lucky.luke@test.com";
+pub const EXPIRED_PASSWORD_EMAIL: &str = "joe.dalton@test.com";
+pub const EXPIRED_PASSWORD_ID: &str = "5d0c4a3e-2f4b-4d8e-9a61-0b7e3c9f1a24";
 pub const INVALID_PASSWORD: &str = "1A,R;(9h0Y&gYH5=7eY!ee";
 
 /// User repository mock
@@ -36,12 +41,13 @@ impl UserRepository for UserRepositoryMock {
     ///
     /// If the email is VALID_EMAIL, return a valid user, else return an error
     async fn create_user(&self, req: CreateUserDtoRequest) -> Result<CreateUserDtoResponse, UserUseCaseError> {
-        if req.0.email.value() == VALID_EMAIL {
+        if req.user.email.value() == VALID_EMAIL {
             Ok(CreateUserDtoResponse(UserUseCaseResponse {
-                id: UserId::from_str(VALID_ID)?,
-                email: req.0.email,
-                lastname: req.0.lastname,
-                firstname: req.0.firstname,
+                id: req.id,
+                email: req.user.email,
+                lastname: req.user.lastname,
+                firstname: req.user.firstname,
+                locale: req.user.locale,
                 created_at: UtcDateTime::now(),
                 updated_at: UtcDateTime::now(),
                 deleted_at: None,
@@ -62,6 +68,15 @@ impl UserRepository for UserRepositoryMock {
                 Ok(Some(GetAccessTokenInformationDtoResponse {
                     id: UserId::from_str(VALID_ID)?,
                     password: Password::new(VALID_PASSWORD, false)?,
+                    password_changed_at: UtcDateTime::now(),
+                }))
+            }
+            EXPIRED_PASSWORD_EMAIL => {
+                // Valid user with a password changed a long time ago
+                Ok(Some(GetAccessTokenInformationDtoResponse {
+                    id: UserId::from_str(VALID_ID)?,
+                    password: Password::new(VALID_PASSWORD, false)?,
+                    password_changed_at: UtcDateTime::new(Utc::now() - Duration::days(365)),
                 }))
             }
             EMAIL_NOT_FOUND => Ok(None),
@@ -69,9 +84,28 @@ impl UserRepository for UserRepositoryMock {
         }
     }
 
+    /// Get the date of the last password change
+    ///
+    /// The password of EXPIRED_PASSWORD_ID has been changed a long time ago, INVALID_ID is not found
+    async fn get_password_changed_at(
+        &self,
+        req: GetPasswordChangedAtDtoRequest,
+    ) -> Result<GetPasswordChangedAtDtoResponse, UserUseCaseError> {
+        match req.0.to_string().as_str() {
+            EXPIRED_PASSWORD_ID => Ok(GetPasswordChangedAtDtoResponse(Some(UtcDateTime::new(
+                Utc::now() - Duration::days(365),
+            )))),
+            INVALID_ID => Err(UserUseCaseError::UserNotFound()),
+            _ => Ok(GetPasswordChangedAtDtoResponse(Some(UtcDateTime::now()))),
+        }
+    }
+
     /// Get all users
     async fn get_users(&self, _req: GetUsersDtoRequest) -> Result<GetUsersDtoResponse, UserUseCaseError> {
-        Ok(GetUsersDtoResponse(vec![]))
+        Ok(GetUsersDtoResponse {
+            users: vec![],
+            next_cursor: None,
+        })
     }
 
     /// Count all users
@@ -79,6 +113,28 @@ impl UserRepository for UserRepositoryMock {
         Ok(CountUsersDtoResponse(0))
     }
 
+    /// Search users
+    async fn search_users(&self, req: SearchUsersDtoRequest) -> Result<SearchUsersDtoResponse, UserUseCaseError> {
+        let users = match VALID_EMAIL.contains(&req.search.to_lowercase()) {
+            true => vec![UserUseCaseResponse {
+                id: UserId::from_str(VALID_ID)?,
+                email: Email::new(VALID_EMAIL)?,
+                lastname: "Doe".to_string(),
+                firstname: "John".to_string(),
+                locale: None,
+                created_at: UtcDateTime::now(),
+                updated_at: UtcDateTime::now(),
+                deleted_at: None,
+            }],
+            false => vec![],
+        };
+
+        Ok(SearchUsersDtoResponse {
+            total: users.len() as i64,
+            users,
+        })
+    }
+
     /// Get a user by ID
     async fn get_user_by_id(&self, req: GetUserByIdDtoRequest) -> Result<GetUserByIdDtoResponse, UserUseCaseError> {
         match req.0.user_id.to_string().as_str() {
@@ -87,6 +143,7 @@ impl UserRepository for UserRepositoryMock {
                 email: Email::new(VALID_EMAIL)?,
                 lastname: "Doe".to_string(),
                 firstname: "John".to_string(),
+                locale: None,
                 created_at: UtcDateTime::now(),
                 updated_at: UtcDateTime::now(),
                 deleted_at: None,
@@ -98,14 +155,26 @@ impl UserRepository for UserRepositoryMock {
     /// Get a user by email
     async fn get_user_by_email(
         &self,
-        _req: GetUserByEmailDtoRequest,
+        req: GetUserByEmailDtoRequest,
     ) -> Result<GetUserByEmailDtoResponse, UserUseCaseError> {
-        todo!()
+        match req.0.to_string().as_str() {
+            VALID_EMAIL => Ok(GetUserByEmailDtoResponse(UserUseCaseResponse {
+                id: UserId::from_str(VALID_ID)?,
+                email: Email::new(VALID_EMAIL)?,
+                lastname: "Doe".to_string(),
+                firstname: "John".to_string(),
+                locale: None,
+                created_at: UtcDateTime::now(),
+                updated_at: UtcDateTime::now(),
+                deleted_at: None,
+            })),
+            _ => Err(UserUseCaseError::UserNotFound()),
+        }
     }
 
     /// Delete a user by ID
     async fn delete_user(&self, req: DeleteUserDtoRequest) -> Result<DeleteUserDtoResponse, UserUseCaseError> {
-        match req.0.user_id.to_string().as_str() {
+        match req.user_id.to_string().as_str() {
             VALID_ID => Ok(DeleteUserDtoResponse(DeleteUserUseCaseResponse())),
             _ => Err(UserUseCaseError::DatabaseError("User not found".to_string())),
         }
@@ -123,9 +192,38 @@ impl UserRepository for UserRepositoryMock {
         }
     }
 
+    /// Rehash password
+    async fn rehash_password(
+        &self,
+        req: RehashPasswordDtoRequest,
+    ) -> Result<RehashPasswordDtoResponse, UserUseCaseError> {
+        if req.user_id.to_string().as_str() == VALID_ID {
+            Ok(RehashPasswordDtoResponse())
+        } else {
+            Err(UserUseCaseError::DatabaseError("User not found".to_string()))
+        }
+    }
+
+    /// Get password history
+    ///
+    /// If the user ID is VALID_ID, return VALID_PASSWORD as the only previous password
+    async fn get_password_history(
+        &self,
+        req: GetPasswordHistoryDtoRequest,
+    ) -> Result<GetPasswordHistoryDtoResponse, UserUseCaseError> {
+        if req.user_id.to_string().as_str() == VALID_ID {
+            Ok(GetPasswordHistoryDtoResponse(vec![Password::new(
+                VALID_PASSWORD,
+                false,
+            )?]))
+        } else {
+            Ok(GetPasswordHistoryDtoResponse(vec![]))
+        }
+    }
+
     /// Restore a user
     async fn restore_user(&self, req: RestoreUserDtoRequest) -> Result<RestoreUserDtoResponse, UserUseCaseError> {
-        match req.0.user_id.to_string().as_str() {
+        match req.user_id.to_string().as_str() {
             VALID_ID => Ok(RestoreUserDtoResponse(RestoreUserUseCaseResponse())),
             _ => Err(UserUseCaseError::DatabaseError("User not found".to_string())),
         }
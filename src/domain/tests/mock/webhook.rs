@@ -0,0 +1,172 @@
+//! Mock of the webhook repository and service
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::webhook::{Webhook, WebhookDelivery, WebhookEvent};
+use crate::domain::repositories::webhook::WebhookRepository;
+use crate::domain::repositories::webhook::dto::{
+    CountWebhooksDtoRequest, CountWebhooksDtoResponse, CreateWebhookDtoRequest, CreateWebhookDtoResponse,
+    DeleteWebhookDtoRequest, DeleteWebhookDtoResponse, GetDueWebhookDeliveriesDtoRequest,
+    GetDueWebhookDeliveriesDtoResponse, GetWebhooksDtoRequest, GetWebhooksDtoResponse, UpdateWebhookDeliveryDtoRequest,
+    UpdateWebhookDeliveryDtoResponse,
+};
+use crate::domain::services::webhook::{WebhookService, WebhookServiceError};
+use crate::domain::use_cases::webhook::WebhookUseCaseError;
+use crate::domain::use_cases::webhook::delete_webhook::DeleteWebhookUseCaseResponse;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::id::Id;
+use crate::domain::value_objects::webhook_secret::WebhookSecret;
+use async_trait::async_trait;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+pub const VALID_APPLICATION_ID: &str = "ffaa2c9c-872f-4e62-8302-d4586096cd13";
+pub const INVALID_APPLICATION_ID: &str = "b4dc6179-e538-449b-accd-a8a1f58631af";
+pub const VALID_WEBHOOK_ID: &str = "3f6c2a8e-1d4b-4e7a-9c5f-8b2d6e0a4c17";
+pub const INVALID_WEBHOOK_ID: &str = "a7e4b1c9-5f2d-4a8e-b3c6-9d0f7e2a1b54";
+
+/// Webhook repository mock
+///
+/// Delivery updates are recorded.
+#[derive(Debug, Clone, Default)]
+pub struct WebhookRepositoryMock {
+    pub updates: Arc<Mutex<Vec<UpdateWebhookDeliveryDtoRequest>>>,
+}
+
+impl WebhookRepositoryMock {
+    /// Webhook returned by the mock
+    fn webhook(application_id: ApplicationId, url: String, events: Vec<WebhookEvent>) -> Webhook {
+        let now = UtcDateTime::now();
+
+        Webhook {
+            id: Id::from_str(VALID_WEBHOOK_ID).unwrap(),
+            application_id,
+            url,
+            secret: WebhookSecret::generate(),
+            events,
+            created_at: now.clone(),
+            updated_at: now,
+            deleted_at: None,
+        }
+    }
+
+    /// Delivery returned by the mock
+    fn delivery(url: &str, attempts: u32) -> WebhookDelivery {
+        WebhookDelivery {
+            id: Id::new().unwrap(),
+            webhook_id: Id::from_str(VALID_WEBHOOK_ID).unwrap(),
+            url: url.to_string(),
+            secret: WebhookSecret::new("whsec_test"),
+            event_id: Id::new().unwrap(),
+            event: WebhookEvent::UserCreated,
+            payload: "{}".to_string(),
+            attempts,
+        }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for WebhookRepositoryMock {
+    /// Create webhook
+    async fn create_webhook(
+        &self,
+        req: CreateWebhookDtoRequest,
+    ) -> Result<CreateWebhookDtoResponse, WebhookUseCaseError> {
+        if req.webhook.application_id.to_string() != VALID_APPLICATION_ID {
+            return Err(WebhookUseCaseError::ApplicationNotFound());
+        }
+
+        let mut webhook = Self::webhook(req.webhook.application_id, req.webhook.url, req.webhook.events);
+        webhook.secret = req.secret;
+
+        Ok(CreateWebhookDtoResponse(webhook))
+    }
+
+    /// Count the webhooks of an application (one webhook for VALID_APPLICATION_ID)
+    async fn count_webhooks(
+        &self,
+        req: CountWebhooksDtoRequest,
+    ) -> Result<CountWebhooksDtoResponse, WebhookUseCaseError> {
+        match req.application_id.to_string().as_str() {
+            VALID_APPLICATION_ID => Ok(CountWebhooksDtoResponse(1)),
+            _ => Ok(CountWebhooksDtoResponse(0)),
+        }
+    }
+
+    /// Get the webhooks of an application
+    async fn get_webhooks(&self, req: GetWebhooksDtoRequest) -> Result<GetWebhooksDtoResponse, WebhookUseCaseError> {
+        if req.0.application_id.to_string() != VALID_APPLICATION_ID {
+            return Ok(GetWebhooksDtoResponse {
+                webhooks: vec![],
+                next_cursor: None,
+            });
+        }
+
+        Ok(GetWebhooksDtoResponse {
+            webhooks: vec![Self::webhook(
+                req.0.application_id,
+                "https://app.test.com/webhooks".to_string(),
+                WebhookEvent::ALL.to_vec(),
+            )],
+            next_cursor: None,
+        })
+    }
+
+    /// Delete webhook
+    async fn delete_webhook(
+        &self,
+        req: DeleteWebhookDtoRequest,
+    ) -> Result<DeleteWebhookDtoResponse, WebhookUseCaseError> {
+        if req.0.application_id.to_string() != VALID_APPLICATION_ID || req.0.id.to_string() != VALID_WEBHOOK_ID {
+            return Err(WebhookUseCaseError::WebhookNotFound());
+        }
+
+        Ok(DeleteWebhookDtoResponse(DeleteWebhookUseCaseResponse()))
+    }
+
+    /// A delivery which succeeds, a first failure and a third failure
+    async fn get_due_deliveries(
+        &self,
+        _req: GetDueWebhookDeliveriesDtoRequest,
+    ) -> Result<GetDueWebhookDeliveriesDtoResponse, WebhookUseCaseError> {
+        Ok(GetDueWebhookDeliveriesDtoResponse(vec![
+            Self::delivery("https://app.test.com/ok", 0),
+            Self::delivery("https://app.test.com/error", 0),
+            Self::delivery("https://app.test.com/error", 2),
+        ]))
+    }
+
+    /// Record the update
+    async fn update_delivery(
+        &self,
+        req: UpdateWebhookDeliveryDtoRequest,
+    ) -> Result<UpdateWebhookDeliveryDtoResponse, WebhookUseCaseError> {
+        self.updates.lock().unwrap().push(req);
+
+        Ok(UpdateWebhookDeliveryDtoResponse())
+    }
+}
+
+/// Webhook service mock
+///
+/// Only `https` URLs are allowed and sending fails if the URL ends with `/error`.
+#[derive(Debug, Clone)]
+pub struct WebhookServiceMock {}
+
+#[async_trait]
+impl WebhookService for WebhookServiceMock {
+    fn check_url(&self, url: &str) -> Result<(), WebhookServiceError> {
+        if !url.starts_with("https://") {
+            return Err(WebhookServiceError::UrlNotAllowed("https is required".to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn send(&self, delivery: &WebhookDelivery) -> Result<(), WebhookServiceError> {
+        if delivery.url.ends_with("/error") {
+            return Err(WebhookServiceError::UnexpectedStatus(500));
+        }
+
+        Ok(())
+    }
+}
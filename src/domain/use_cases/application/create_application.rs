@@ -1,25 +1,45 @@
 //! Create an application use case
 
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
 use crate::domain::repositories::application::ApplicationRepository;
 use crate::domain::repositories::application::dto::CreateApplicationDtoRequest;
-use crate::domain::use_cases::application::{ApplicationUseCaseError, ApplicationUseCaseResponse};
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::use_cases::application::{ApplicationUseCaseError, ApplicationUseCaseResponse, validation_error};
+use crate::domain::use_cases::audit::record_audit_event;
 use validator::Validate;
 
 #[derive(Debug, Clone, Validate)]
 pub struct CreateApplicationUseCaseRequest {
     #[validate(length(min = 3))]
     pub name: String,
+
+    /// Access token lifetime (in minute)
+    #[validate(range(min = 1))]
+    pub access_token_lifetime: Option<u32>,
+
+    /// Refresh token lifetime (in day)
+    #[validate(range(min = 1))]
+    pub refresh_token_lifetime: Option<u32>,
+
+    #[validate(length(min = 1, max = 255))]
+    pub audience: Option<String>,
+
+    pub audit: AuditContext,
 }
 
 #[derive(Debug, Clone)]
-pub struct CreateApplicationUseCase<A: ApplicationRepository> {
+pub struct CreateApplicationUseCase<A: ApplicationRepository, R: AuditRepository> {
     application_repository: A,
+    audit_repository: R,
 }
 
-impl<A: ApplicationRepository> CreateApplicationUseCase<A> {
+impl<A: ApplicationRepository, R: AuditRepository> CreateApplicationUseCase<A, R> {
     /// Create a new use case
-    pub fn new(application_repository: A) -> Self {
-        Self { application_repository }
+    pub fn new(application_repository: A, audit_repository: R) -> Self {
+        Self {
+            application_repository,
+            audit_repository,
+        }
     }
 
     /// Create a new application
@@ -29,14 +49,23 @@ impl<A: ApplicationRepository> CreateApplicationUseCase<A> {
         request: CreateApplicationUseCaseRequest,
     ) -> Result<ApplicationUseCaseResponse, ApplicationUseCaseError> {
         if let Err(err) = request.validate() {
-            return Err(ApplicationUseCaseError::InvalidName(err.to_string()));
+            return Err(validation_error(err));
         }
 
+        let audit = request.audit.clone();
         let application = self
             .application_repository
             .create(CreateApplicationDtoRequest(request))
             .await?;
 
+        record_audit_event(
+            &self.audit_repository,
+            &audit,
+            AuditAction::ApplicationCreated,
+            application.0.id.to_string(),
+        )
+        .await;
+
         Ok(application.0)
     }
 }
@@ -47,14 +76,19 @@ mod tests {
     use crate::domain::tests::mock::application::{
         ApplicationRepositoryMock, INVALID_APPLICATION_NAME, VALID_APPLICATION_NAME,
     };
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
 
     #[tokio::test]
     async fn test_create_application() {
         let application_repository = ApplicationRepositoryMock {};
-        let use_case = CreateApplicationUseCase::new(application_repository);
+        let use_case = CreateApplicationUseCase::new(application_repository, AuditRepositoryMock {});
 
         let request = CreateApplicationUseCaseRequest {
             name: VALID_APPLICATION_NAME.to_string(),
+            access_token_lifetime: Some(5),
+            refresh_token_lifetime: None,
+            audience: Some("https://api.test.com".to_string()),
+            audit: AuditContext::default(),
         };
 
         let response = use_case.call(request).await;
@@ -64,21 +98,51 @@ mod tests {
     #[tokio::test]
     async fn test_create_application_invalid_name() {
         let application_repository = ApplicationRepositoryMock {};
-        let use_case = CreateApplicationUseCase::new(application_repository);
+        let use_case = CreateApplicationUseCase::new(application_repository, AuditRepositoryMock {});
 
-        let request = CreateApplicationUseCaseRequest { name: "dd".to_string() };
+        let request = CreateApplicationUseCaseRequest {
+            name: "dd".to_string(),
+            access_token_lifetime: None,
+            refresh_token_lifetime: None,
+            audience: None,
+            audit: AuditContext::default(),
+        };
 
         let response = use_case.call(request).await;
-        assert!(response.is_err());
+        assert!(matches!(response, Err(ApplicationUseCaseError::InvalidName(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_application_invalid_token_settings() {
+        let application_repository = ApplicationRepositoryMock {};
+        let use_case = CreateApplicationUseCase::new(application_repository, AuditRepositoryMock {});
+
+        let request = CreateApplicationUseCaseRequest {
+            name: VALID_APPLICATION_NAME.to_string(),
+            access_token_lifetime: Some(0),
+            refresh_token_lifetime: None,
+            audience: None,
+            audit: AuditContext::default(),
+        };
+
+        let response = use_case.call(request).await;
+        assert!(matches!(
+            response,
+            Err(ApplicationUseCaseError::InvalidTokenSettings(_))
+        ));
     }
 
     #[tokio::test]
     async fn test_create_application_error() {
         let application_repository = ApplicationRepositoryMock {};
-        let use_case = CreateApplicationUseCase::new(application_repository);
+        let use_case = CreateApplicationUseCase::new(application_repository, AuditRepositoryMock {});
 
         let request = CreateApplicationUseCaseRequest {
             name: INVALID_APPLICATION_NAME.to_string(),
+            access_token_lifetime: None,
+            refresh_token_lifetime: None,
+            audience: None,
+            audit: AuditContext::default(),
         };
 
         let response = use_case.call(request).await;
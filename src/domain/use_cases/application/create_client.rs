@@ -0,0 +1,129 @@
+//! Create an application client use case
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::repositories::client::ClientRepository;
+use crate::domain::repositories::client::dto::CreateClientDtoRequest;
+use crate::domain::use_cases::application::{ApplicationUseCaseError, ClientUseCaseResponse};
+use crate::domain::use_cases::audit::record_audit_event;
+use crate::domain::value_objects::client_secret::ClientSecret;
+use crate::domain::value_objects::password_policy::PasswordPolicy;
+use validator::Validate;
+
+#[derive(Debug, Clone, Validate)]
+pub struct CreateClientUseCaseRequest {
+    pub application_id: ApplicationId,
+
+    #[validate(length(min = 3))]
+    pub name: String,
+
+    /// Policy used to hash the secret
+    pub password_policy: PasswordPolicy,
+
+    pub audit: AuditContext,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateClientUseCaseResponse {
+    pub client: ClientUseCaseResponse,
+
+    /// Plain secret, it is only returned once
+    pub secret: ClientSecret,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateClientUseCase<C: ClientRepository, R: AuditRepository> {
+    client_repository: C,
+    audit_repository: R,
+}
+
+impl<C: ClientRepository, R: AuditRepository> CreateClientUseCase<C, R> {
+    /// Create a new use case
+    pub fn new(client_repository: C, audit_repository: R) -> Self {
+        Self {
+            client_repository,
+            audit_repository,
+        }
+    }
+
+    /// Create a new client with a first secret
+    #[instrument(skip(self), name = "create_client_use_case")]
+    pub async fn call(
+        &self,
+        request: CreateClientUseCaseRequest,
+    ) -> Result<CreateClientUseCaseResponse, ApplicationUseCaseError> {
+        if let Err(err) = request.validate() {
+            return Err(ApplicationUseCaseError::InvalidClient(err.to_string()));
+        }
+
+        let secret = ClientSecret::generate();
+        let hashed_secret = secret
+            .hash(&request.password_policy)
+            .map_err(|err| ApplicationUseCaseError::ClientSecretGenerationError(err.to_string()))?;
+
+        let audit = request.audit.clone();
+        let client = self
+            .client_repository
+            .create_client(CreateClientDtoRequest {
+                client: request,
+                secret: hashed_secret,
+            })
+            .await?;
+
+        record_audit_event(
+            &self.audit_repository,
+            &audit,
+            AuditAction::ClientCreated,
+            client.0.id.to_string(),
+        )
+        .await;
+
+        Ok(CreateClientUseCaseResponse {
+            client: client.0,
+            secret,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
+    use crate::domain::tests::mock::client::{ClientRepositoryMock, INVALID_APPLICATION_ID, VALID_APPLICATION_ID};
+    use std::str::FromStr;
+
+    fn request(application_id: &str) -> CreateClientUseCaseRequest {
+        CreateClientUseCaseRequest {
+            application_id: ApplicationId::from_str(application_id).unwrap(),
+            name: "Web client".to_string(),
+            password_policy: PasswordPolicy::default(),
+            audit: AuditContext::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_client_use_case() {
+        let use_case = CreateClientUseCase::new(ClientRepositoryMock {}, AuditRepositoryMock {});
+
+        let response = use_case.call(request(VALID_APPLICATION_ID)).await.unwrap();
+        assert_eq!(response.client.name, "Web client".to_string());
+        assert!(!response.secret.value().is_empty());
+
+        let response = use_case.call(request(INVALID_APPLICATION_ID)).await;
+        assert_eq!(response.unwrap_err(), ApplicationUseCaseError::ApplicationNotFound());
+    }
+
+    #[tokio::test]
+    async fn test_create_client_use_case_invalid_request() {
+        let use_case = CreateClientUseCase::new(ClientRepositoryMock {}, AuditRepositoryMock {});
+
+        let response = use_case
+            .call(CreateClientUseCaseRequest {
+                name: "TV".to_string(),
+                ..request(VALID_APPLICATION_ID)
+            })
+            .await;
+        assert!(matches!(response, Err(ApplicationUseCaseError::InvalidClient(_))));
+    }
+}
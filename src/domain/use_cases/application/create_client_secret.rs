@@ -0,0 +1,116 @@
+//! Add a secret to an application client use case (secret rotation)
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+use crate::domain::entities::client::ClientId;
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::repositories::client::ClientRepository;
+use crate::domain::repositories::client::dto::CreateClientSecretDtoRequest;
+use crate::domain::use_cases::application::{ApplicationUseCaseError, ClientSecretUseCaseResponse};
+use crate::domain::use_cases::audit::record_audit_event;
+use crate::domain::value_objects::client_secret::ClientSecret;
+use crate::domain::value_objects::password_policy::PasswordPolicy;
+
+#[derive(Debug, Clone)]
+pub struct CreateClientSecretUseCaseRequest {
+    pub application_id: ApplicationId,
+    pub client_id: ClientId,
+
+    /// Policy used to hash the secret
+    pub password_policy: PasswordPolicy,
+
+    pub audit: AuditContext,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateClientSecretUseCaseResponse {
+    pub client_secret: ClientSecretUseCaseResponse,
+
+    /// Plain secret, it is only returned once
+    pub secret: ClientSecret,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateClientSecretUseCase<C: ClientRepository, R: AuditRepository> {
+    client_repository: C,
+    audit_repository: R,
+}
+
+impl<C: ClientRepository, R: AuditRepository> CreateClientSecretUseCase<C, R> {
+    /// Create a new use case
+    pub fn new(client_repository: C, audit_repository: R) -> Self {
+        Self {
+            client_repository,
+            audit_repository,
+        }
+    }
+
+    /// Generate a new secret, previous secrets remain valid until they are deleted
+    #[instrument(skip(self), name = "create_client_secret_use_case")]
+    pub async fn call(
+        &self,
+        request: CreateClientSecretUseCaseRequest,
+    ) -> Result<CreateClientSecretUseCaseResponse, ApplicationUseCaseError> {
+        let secret = ClientSecret::generate();
+        let hashed_secret = secret
+            .hash(&request.password_policy)
+            .map_err(|err| ApplicationUseCaseError::ClientSecretGenerationError(err.to_string()))?;
+
+        let client_secret = self
+            .client_repository
+            .create_client_secret(CreateClientSecretDtoRequest {
+                application_id: request.application_id,
+                client_id: request.client_id,
+                secret: hashed_secret,
+            })
+            .await?;
+
+        record_audit_event(
+            &self.audit_repository,
+            &request.audit,
+            AuditAction::ClientSecretCreated,
+            client_secret.0.id.to_string(),
+        )
+        .await;
+
+        Ok(CreateClientSecretUseCaseResponse {
+            client_secret: client_secret.0,
+            secret,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
+    use crate::domain::tests::mock::client::{
+        ClientRepositoryMock, INVALID_CLIENT_ID, VALID_APPLICATION_ID, VALID_CLIENT_ID,
+    };
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test_create_client_secret_use_case() {
+        let use_case = CreateClientSecretUseCase::new(ClientRepositoryMock {}, AuditRepositoryMock {});
+
+        let response = use_case
+            .call(CreateClientSecretUseCaseRequest {
+                application_id: ApplicationId::from_str(VALID_APPLICATION_ID).unwrap(),
+                client_id: ClientId::from_str(VALID_CLIENT_ID).unwrap(),
+                password_policy: PasswordPolicy::default(),
+                audit: AuditContext::default(),
+            })
+            .await;
+        assert!(response.is_ok());
+
+        let response = use_case
+            .call(CreateClientSecretUseCaseRequest {
+                application_id: ApplicationId::from_str(VALID_APPLICATION_ID).unwrap(),
+                client_id: ClientId::from_str(INVALID_CLIENT_ID).unwrap(),
+                password_policy: PasswordPolicy::default(),
+                audit: AuditContext::default(),
+            })
+            .await;
+        assert_eq!(response.unwrap_err(), ApplicationUseCaseError::ClientNotFound());
+    }
+}
@@ -1,27 +1,35 @@
 //! Delete application use case
 
 use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
 use crate::domain::repositories::application::ApplicationRepository;
 use crate::domain::repositories::application::dto::DeleteApplicationDtoRequest;
+use crate::domain::repositories::audit::AuditRepository;
 use crate::domain::use_cases::application::ApplicationUseCaseError;
+use crate::domain::use_cases::audit::record_audit_event;
 
 #[derive(Debug, Clone)]
 pub struct DeleteApplicationUseCaseRequest {
     pub id: ApplicationId,
+    pub audit: AuditContext,
 }
 
 #[derive(Debug, Clone)]
 pub struct DeleteApplicationUseCaseResponse();
 
 #[derive(Debug, Clone)]
-pub struct DeleteApplicationUseCase<A: ApplicationRepository> {
+pub struct DeleteApplicationUseCase<A: ApplicationRepository, R: AuditRepository> {
     application_repository: A,
+    audit_repository: R,
 }
 
-impl<A: ApplicationRepository> DeleteApplicationUseCase<A> {
+impl<A: ApplicationRepository, R: AuditRepository> DeleteApplicationUseCase<A, R> {
     /// Create a new use case
-    pub fn new(application_repository: A) -> Self {
-        Self { application_repository }
+    pub fn new(application_repository: A, audit_repository: R) -> Self {
+        Self {
+            application_repository,
+            audit_repository,
+        }
     }
 
     /// Delete an application
@@ -30,11 +38,14 @@ impl<A: ApplicationRepository> DeleteApplicationUseCase<A> {
         &self,
         request: DeleteApplicationUseCaseRequest,
     ) -> Result<DeleteApplicationUseCaseResponse, ApplicationUseCaseError> {
+        let (id, audit) = (request.id.to_string(), request.audit.clone());
         let application = self
             .application_repository
             .delete(DeleteApplicationDtoRequest(request))
             .await?;
 
+        record_audit_event(&self.audit_repository, &audit, AuditAction::ApplicationDeleted, id).await;
+
         Ok(application.0)
     }
 }
@@ -42,6 +53,7 @@ impl<A: ApplicationRepository> DeleteApplicationUseCase<A> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
     use crate::domain::{
         tests::mock::application::{ApplicationRepositoryMock, INVALID_APPLICATION_ID, VALID_APPLICATION_ID},
         value_objects::id::Id,
@@ -51,10 +63,11 @@ mod tests {
     #[tokio::test]
     async fn test_delete_application_use_case() {
         let application_repository = ApplicationRepositoryMock {};
-        let use_case = DeleteApplicationUseCase::new(application_repository);
+        let use_case = DeleteApplicationUseCase::new(application_repository, AuditRepositoryMock {});
 
         let request = DeleteApplicationUseCaseRequest {
             id: Id::from_str(VALID_APPLICATION_ID).unwrap(),
+            audit: AuditContext::default(),
         };
 
         let response = use_case.call(request).await;
@@ -64,10 +77,11 @@ mod tests {
     #[tokio::test]
     async fn test_delete_application_use_case_with_error() {
         let application_repository = ApplicationRepositoryMock {};
-        let use_case = DeleteApplicationUseCase::new(application_repository);
+        let use_case = DeleteApplicationUseCase::new(application_repository, AuditRepositoryMock {});
 
         let request = DeleteApplicationUseCaseRequest {
             id: Id::from_str(INVALID_APPLICATION_ID).unwrap(),
+            audit: AuditContext::default(),
         };
 
         let response = use_case.call(request).await;
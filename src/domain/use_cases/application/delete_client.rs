@@ -0,0 +1,87 @@
+//! Delete an application client use case
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+use crate::domain::entities::client::ClientId;
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::repositories::client::ClientRepository;
+use crate::domain::repositories::client::dto::DeleteClientDtoRequest;
+use crate::domain::use_cases::application::ApplicationUseCaseError;
+use crate::domain::use_cases::audit::record_audit_event;
+
+#[derive(Debug, Clone)]
+pub struct DeleteClientUseCaseRequest {
+    pub application_id: ApplicationId,
+    pub id: ClientId,
+    pub audit: AuditContext,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteClientUseCaseResponse();
+
+#[derive(Debug, Clone)]
+pub struct DeleteClientUseCase<C: ClientRepository, R: AuditRepository> {
+    client_repository: C,
+    audit_repository: R,
+}
+
+impl<C: ClientRepository, R: AuditRepository> DeleteClientUseCase<C, R> {
+    /// Create a new use case
+    pub fn new(client_repository: C, audit_repository: R) -> Self {
+        Self {
+            client_repository,
+            audit_repository,
+        }
+    }
+
+    /// Delete a client
+    #[instrument(skip(self), name = "delete_client_use_case")]
+    pub async fn call(
+        &self,
+        request: DeleteClientUseCaseRequest,
+    ) -> Result<DeleteClientUseCaseResponse, ApplicationUseCaseError> {
+        let audit = request.audit.clone();
+        let target_id = request.id.to_string();
+        let client = self
+            .client_repository
+            .delete_client(DeleteClientDtoRequest(request))
+            .await?;
+
+        record_audit_event(&self.audit_repository, &audit, AuditAction::ClientDeleted, target_id).await;
+
+        Ok(client.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
+    use crate::domain::tests::mock::client::{
+        ClientRepositoryMock, INVALID_CLIENT_ID, VALID_APPLICATION_ID, VALID_CLIENT_ID,
+    };
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test_delete_client_use_case() {
+        let use_case = DeleteClientUseCase::new(ClientRepositoryMock {}, AuditRepositoryMock {});
+
+        let response = use_case
+            .call(DeleteClientUseCaseRequest {
+                application_id: ApplicationId::from_str(VALID_APPLICATION_ID).unwrap(),
+                id: ClientId::from_str(VALID_CLIENT_ID).unwrap(),
+                audit: AuditContext::default(),
+            })
+            .await;
+        assert!(response.is_ok());
+
+        let response = use_case
+            .call(DeleteClientUseCaseRequest {
+                application_id: ApplicationId::from_str(VALID_APPLICATION_ID).unwrap(),
+                id: ClientId::from_str(INVALID_CLIENT_ID).unwrap(),
+                audit: AuditContext::default(),
+            })
+            .await;
+        assert_eq!(response.unwrap_err(), ApplicationUseCaseError::ClientNotFound());
+    }
+}
@@ -0,0 +1,118 @@
+//! Delete a secret of an application client use case
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+use crate::domain::entities::client::{ClientId, ClientSecretId};
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::repositories::client::ClientRepository;
+use crate::domain::repositories::client::dto::{DeleteClientSecretDtoRequest, GetClientSecretsDtoRequest};
+use crate::domain::use_cases::application::ApplicationUseCaseError;
+use crate::domain::use_cases::application::get_client_secrets::GetClientSecretsUseCaseRequest;
+use crate::domain::use_cases::audit::record_audit_event;
+
+#[derive(Debug, Clone)]
+pub struct DeleteClientSecretUseCaseRequest {
+    pub application_id: ApplicationId,
+    pub client_id: ClientId,
+    pub id: ClientSecretId,
+    pub audit: AuditContext,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteClientSecretUseCaseResponse();
+
+#[derive(Debug, Clone)]
+pub struct DeleteClientSecretUseCase<C: ClientRepository, R: AuditRepository> {
+    client_repository: C,
+    audit_repository: R,
+}
+
+impl<C: ClientRepository, R: AuditRepository> DeleteClientSecretUseCase<C, R> {
+    /// Create a new use case
+    pub fn new(client_repository: C, audit_repository: R) -> Self {
+        Self {
+            client_repository,
+            audit_repository,
+        }
+    }
+
+    /// Delete a client secret, a client must keep at least one secret
+    #[instrument(skip(self), name = "delete_client_secret_use_case")]
+    pub async fn call(
+        &self,
+        request: DeleteClientSecretUseCaseRequest,
+    ) -> Result<DeleteClientSecretUseCaseResponse, ApplicationUseCaseError> {
+        let secrets = self
+            .client_repository
+            .get_client_secrets(GetClientSecretsDtoRequest(GetClientSecretsUseCaseRequest {
+                application_id: request.application_id.clone(),
+                client_id: request.client_id.clone(),
+            }))
+            .await?
+            .0;
+
+        if !secrets.iter().any(|secret| secret.id == request.id) {
+            return Err(ApplicationUseCaseError::ClientSecretNotFound());
+        }
+        if secrets.len() <= 1 {
+            return Err(ApplicationUseCaseError::LastClientSecret());
+        }
+
+        let audit = request.audit.clone();
+        let target_id = request.id.to_string();
+        let response = self
+            .client_repository
+            .delete_client_secret(DeleteClientSecretDtoRequest(request))
+            .await?;
+
+        record_audit_event(
+            &self.audit_repository,
+            &audit,
+            AuditAction::ClientSecretDeleted,
+            target_id,
+        )
+        .await;
+
+        Ok(response.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
+    use crate::domain::tests::mock::client::{
+        ClientRepositoryMock, SINGLE_SECRET_CLIENT_ID, VALID_APPLICATION_ID, VALID_CLIENT_ID, VALID_SECRET_ID,
+    };
+    use std::str::FromStr;
+
+    fn request(client_id: &str, id: &str) -> DeleteClientSecretUseCaseRequest {
+        DeleteClientSecretUseCaseRequest {
+            application_id: ApplicationId::from_str(VALID_APPLICATION_ID).unwrap(),
+            client_id: ClientId::from_str(client_id).unwrap(),
+            id: ClientSecretId::from_str(id).unwrap(),
+            audit: AuditContext::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_client_secret_use_case() {
+        let use_case = DeleteClientSecretUseCase::new(ClientRepositoryMock {}, AuditRepositoryMock {});
+
+        assert!(use_case.call(request(VALID_CLIENT_ID, VALID_SECRET_ID)).await.is_ok());
+        assert_eq!(
+            use_case
+                .call(request(VALID_CLIENT_ID, VALID_APPLICATION_ID))
+                .await
+                .unwrap_err(),
+            ApplicationUseCaseError::ClientSecretNotFound()
+        );
+        assert_eq!(
+            use_case
+                .call(request(SINGLE_SECRET_CLIENT_ID, VALID_SECRET_ID))
+                .await
+                .unwrap_err(),
+            ApplicationUseCaseError::LastClientSecret()
+        );
+    }
+}
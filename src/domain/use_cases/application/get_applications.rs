@@ -1,16 +1,19 @@
 //! Get all applications with pagination use case
 
 use crate::domain::repositories::application::ApplicationRepository;
-use crate::domain::repositories::application::dto::{CountApplicationsDtoRequest, GetApplicationsDtoRequest};
+use crate::domain::repositories::application::dto::{
+    CountApplicationsDtoRequest, GetApplicationsDtoRequest, GetApplicationsDtoResponse,
+};
 use crate::domain::use_cases::application::{ApplicationUseCaseError, ApplicationUseCaseResponse};
-use crate::domain::value_objects::pagination::Pagination;
+use crate::domain::value_objects::pagination::{Pagination, PaginationCursor};
+use crate::domain::value_objects::query_filter::QueryFilters;
 use crate::domain::value_objects::query_sort::QuerySorts;
 
 #[derive(Debug, Clone)]
 pub struct GetApplicationsUseCaseRequest {
     pub pagination: Pagination,
     pub sorts: Option<QuerySorts>,
-    // pub filters: Option<Filters>,
+    pub filters: Option<QueryFilters>,
     pub deleted: bool,
 }
 
@@ -18,6 +21,7 @@ pub struct GetApplicationsUseCaseRequest {
 pub struct GetApplicationsUseCaseResponse {
     pub applications: Vec<ApplicationUseCaseResponse>,
     pub total: i64,
+    pub next_cursor: Option<PaginationCursor>,
 }
 
 #[derive(Debug, Clone)]
@@ -41,16 +45,23 @@ impl<A: ApplicationRepository> GetApplicationsUseCase<A> {
             .application_repository
             .count_applications(CountApplicationsDtoRequest {
                 deleted: request.deleted,
+                filters: request.filters.clone(),
             })
             .await?
             .0;
-        let applications = self
+        let GetApplicationsDtoResponse {
+            applications,
+            next_cursor,
+        } = self
             .application_repository
             .get_applications(GetApplicationsDtoRequest(request))
-            .await?
-            .0;
+            .await?;
 
-        Ok(GetApplicationsUseCaseResponse { applications, total })
+        Ok(GetApplicationsUseCaseResponse {
+            applications,
+            total,
+            next_cursor,
+        })
     }
 }
 
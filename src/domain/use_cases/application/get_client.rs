@@ -0,0 +1,69 @@
+//! Get an application client by its ID use case
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::client::ClientId;
+use crate::domain::repositories::client::ClientRepository;
+use crate::domain::repositories::client::dto::GetClientByIdDtoRequest;
+use crate::domain::use_cases::application::{ApplicationUseCaseError, ClientUseCaseResponse};
+
+#[derive(Debug, Clone)]
+pub struct GetClientByIdUseCaseRequest {
+    pub application_id: ApplicationId,
+    pub id: ClientId,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetClientByIdUseCase<C: ClientRepository> {
+    client_repository: C,
+}
+
+impl<C: ClientRepository> GetClientByIdUseCase<C> {
+    /// Create a new use case
+    pub fn new(client_repository: C) -> Self {
+        Self { client_repository }
+    }
+
+    /// Get a client by its ID
+    #[instrument(skip(self), name = "get_client_use_case")]
+    pub async fn call(
+        &self,
+        request: GetClientByIdUseCaseRequest,
+    ) -> Result<ClientUseCaseResponse, ApplicationUseCaseError> {
+        let client = self
+            .client_repository
+            .get_client_by_id(GetClientByIdDtoRequest(request))
+            .await?;
+
+        Ok(client.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::client::{
+        ClientRepositoryMock, INVALID_CLIENT_ID, VALID_APPLICATION_ID, VALID_CLIENT_ID,
+    };
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test_get_client_use_case() {
+        let use_case = GetClientByIdUseCase::new(ClientRepositoryMock {});
+
+        let response = use_case
+            .call(GetClientByIdUseCaseRequest {
+                application_id: ApplicationId::from_str(VALID_APPLICATION_ID).unwrap(),
+                id: ClientId::from_str(VALID_CLIENT_ID).unwrap(),
+            })
+            .await;
+        assert!(response.is_ok());
+
+        let response = use_case
+            .call(GetClientByIdUseCaseRequest {
+                application_id: ApplicationId::from_str(VALID_APPLICATION_ID).unwrap(),
+                id: ClientId::from_str(INVALID_CLIENT_ID).unwrap(),
+            })
+            .await;
+        assert_eq!(response.unwrap_err(), ApplicationUseCaseError::ClientNotFound());
+    }
+}
@@ -0,0 +1,60 @@
+//! Get the secrets of an application client use case
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::client::ClientId;
+use crate::domain::repositories::client::ClientRepository;
+use crate::domain::repositories::client::dto::GetClientSecretsDtoRequest;
+use crate::domain::use_cases::application::{ApplicationUseCaseError, ClientSecretUseCaseResponse};
+
+#[derive(Debug, Clone)]
+pub struct GetClientSecretsUseCaseRequest {
+    pub application_id: ApplicationId,
+    pub client_id: ClientId,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetClientSecretsUseCase<C: ClientRepository> {
+    client_repository: C,
+}
+
+impl<C: ClientRepository> GetClientSecretsUseCase<C> {
+    /// Create a new use case
+    pub fn new(client_repository: C) -> Self {
+        Self { client_repository }
+    }
+
+    /// Get the secrets (without their value) of a client
+    #[instrument(skip(self), name = "get_client_secrets_use_case")]
+    pub async fn call(
+        &self,
+        request: GetClientSecretsUseCaseRequest,
+    ) -> Result<Vec<ClientSecretUseCaseResponse>, ApplicationUseCaseError> {
+        let secrets = self
+            .client_repository
+            .get_client_secrets(GetClientSecretsDtoRequest(request))
+            .await?;
+
+        Ok(secrets.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::client::{ClientRepositoryMock, VALID_APPLICATION_ID, VALID_CLIENT_ID};
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test_get_client_secrets_use_case() {
+        let use_case = GetClientSecretsUseCase::new(ClientRepositoryMock {});
+
+        let response = use_case
+            .call(GetClientSecretsUseCaseRequest {
+                application_id: ApplicationId::from_str(VALID_APPLICATION_ID).unwrap(),
+                client_id: ClientId::from_str(VALID_CLIENT_ID).unwrap(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.len(), 2);
+    }
+}
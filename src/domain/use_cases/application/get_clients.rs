@@ -0,0 +1,83 @@
+//! Get the clients of an application use case
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::repositories::client::ClientRepository;
+use crate::domain::repositories::client::dto::{CountClientsDtoRequest, GetClientsDtoRequest, GetClientsDtoResponse};
+use crate::domain::use_cases::application::{ApplicationUseCaseError, ClientUseCaseResponse};
+use crate::domain::value_objects::pagination::{Pagination, PaginationCursor};
+use crate::domain::value_objects::query_sort::QuerySorts;
+
+#[derive(Debug, Clone)]
+pub struct GetClientsUseCaseRequest {
+    pub application_id: ApplicationId,
+    pub pagination: Pagination,
+    pub sorts: Option<QuerySorts>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetClientsUseCaseResponse {
+    pub clients: Vec<ClientUseCaseResponse>,
+    pub total: i64,
+    pub next_cursor: Option<PaginationCursor>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetClientsUseCase<C: ClientRepository> {
+    client_repository: C,
+}
+
+impl<C: ClientRepository> GetClientsUseCase<C> {
+    /// Create a new use case
+    pub fn new(client_repository: C) -> Self {
+        Self { client_repository }
+    }
+
+    /// Get the clients of an application
+    #[instrument(skip(self), name = "get_clients_use_case")]
+    pub async fn call(
+        &self,
+        request: GetClientsUseCaseRequest,
+    ) -> Result<GetClientsUseCaseResponse, ApplicationUseCaseError> {
+        let total = self
+            .client_repository
+            .count_clients(CountClientsDtoRequest {
+                application_id: request.application_id.clone(),
+            })
+            .await?
+            .0;
+
+        let GetClientsDtoResponse { clients, next_cursor } = self
+            .client_repository
+            .get_clients(GetClientsDtoRequest(request))
+            .await?;
+
+        Ok(GetClientsUseCaseResponse {
+            clients,
+            total,
+            next_cursor,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::client::{ClientRepositoryMock, VALID_APPLICATION_ID};
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test_get_clients_use_case() {
+        let use_case = GetClientsUseCase::new(ClientRepositoryMock {});
+
+        let response = use_case
+            .call(GetClientsUseCaseRequest {
+                application_id: ApplicationId::from_str(VALID_APPLICATION_ID).unwrap(),
+                pagination: Pagination::default(),
+                sorts: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.total, 1);
+        assert_eq!(response.clients.len(), 1);
+    }
+}
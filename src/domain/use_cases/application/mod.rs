@@ -1,43 +1,82 @@
 //! Application use cases
 
 pub mod create_application;
+pub mod create_client;
+pub mod create_client_secret;
 pub mod delete_application;
+pub mod delete_client;
+pub mod delete_client_secret;
 pub mod get_application;
 pub mod get_applications;
+pub mod get_client;
+pub mod get_client_secrets;
+pub mod get_clients;
 pub mod restore_application;
 pub mod update_application;
+pub mod update_client;
 
 use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::client::{ClientId, ClientSecretId};
 use crate::domain::repositories::application::ApplicationRepository;
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::repositories::client::ClientRepository;
 use crate::domain::use_cases::application::create_application::CreateApplicationUseCase;
+use crate::domain::use_cases::application::create_client::CreateClientUseCase;
+use crate::domain::use_cases::application::create_client_secret::CreateClientSecretUseCase;
 use crate::domain::use_cases::application::delete_application::DeleteApplicationUseCase;
+use crate::domain::use_cases::application::delete_client::DeleteClientUseCase;
+use crate::domain::use_cases::application::delete_client_secret::DeleteClientSecretUseCase;
 use crate::domain::use_cases::application::get_application::GetApplicationByIdUseCase;
 use crate::domain::use_cases::application::get_applications::GetApplicationsUseCase;
+use crate::domain::use_cases::application::get_client::GetClientByIdUseCase;
+use crate::domain::use_cases::application::get_client_secrets::GetClientSecretsUseCase;
+use crate::domain::use_cases::application::get_clients::GetClientsUseCase;
 use crate::domain::use_cases::application::restore_application::RestoreApplicationUseCase;
 use crate::domain::use_cases::application::update_application::UpdateApplicationUseCase;
+use crate::domain::use_cases::application::update_client::UpdateClientUseCase;
 use crate::domain::value_objects::datetime::UtcDateTime;
 use thiserror::Error;
+use validator::ValidationErrors;
 
 #[derive(Debug, Clone)]
-pub struct ApplicationUseCases<A: ApplicationRepository> {
-    pub create_application: CreateApplicationUseCase<A>,
+pub struct ApplicationUseCases<A: ApplicationRepository, C: ClientRepository, R: AuditRepository> {
+    pub create_application: CreateApplicationUseCase<A, R>,
     pub get_application: GetApplicationByIdUseCase<A>,
     pub get_applications: GetApplicationsUseCase<A>,
-    pub update_application: UpdateApplicationUseCase<A>,
-    pub delete_application: DeleteApplicationUseCase<A>,
-    pub restore_application: RestoreApplicationUseCase<A>,
+    pub update_application: UpdateApplicationUseCase<A, R>,
+    pub delete_application: DeleteApplicationUseCase<A, R>,
+    pub restore_application: RestoreApplicationUseCase<A, R>,
+    pub create_client: CreateClientUseCase<C, R>,
+    pub get_client: GetClientByIdUseCase<C>,
+    pub get_clients: GetClientsUseCase<C>,
+    pub update_client: UpdateClientUseCase<C>,
+    pub delete_client: DeleteClientUseCase<C, R>,
+    pub create_client_secret: CreateClientSecretUseCase<C, R>,
+    pub get_client_secrets: GetClientSecretsUseCase<C>,
+    pub delete_client_secret: DeleteClientSecretUseCase<C, R>,
 }
 
-impl<A: ApplicationRepository> ApplicationUseCases<A> {
+impl<A: ApplicationRepository, C: ClientRepository, R: AuditRepository> ApplicationUseCases<A, C, R> {
     /// Create a new application use cases
-    pub fn new(application_repository: A) -> Self {
+    pub fn new(application_repository: A, client_repository: C, audit_repository: R) -> Self {
         Self {
-            create_application: CreateApplicationUseCase::new(application_repository.clone()),
+            create_application: CreateApplicationUseCase::new(application_repository.clone(), audit_repository.clone()),
             get_application: GetApplicationByIdUseCase::new(application_repository.clone()),
             get_applications: GetApplicationsUseCase::new(application_repository.clone()),
-            update_application: UpdateApplicationUseCase::new(application_repository.clone()),
-            delete_application: DeleteApplicationUseCase::new(application_repository.clone()),
-            restore_application: RestoreApplicationUseCase::new(application_repository.clone()),
+            update_application: UpdateApplicationUseCase::new(application_repository.clone(), audit_repository.clone()),
+            delete_application: DeleteApplicationUseCase::new(application_repository.clone(), audit_repository.clone()),
+            restore_application: RestoreApplicationUseCase::new(
+                application_repository.clone(),
+                audit_repository.clone(),
+            ),
+            create_client: CreateClientUseCase::new(client_repository.clone(), audit_repository.clone()),
+            get_client: GetClientByIdUseCase::new(client_repository.clone()),
+            get_clients: GetClientsUseCase::new(client_repository.clone()),
+            update_client: UpdateClientUseCase::new(client_repository.clone()),
+            delete_client: DeleteClientUseCase::new(client_repository.clone(), audit_repository.clone()),
+            create_client_secret: CreateClientSecretUseCase::new(client_repository.clone(), audit_repository.clone()),
+            get_client_secrets: GetClientSecretsUseCase::new(client_repository.clone()),
+            delete_client_secret: DeleteClientSecretUseCase::new(client_repository, audit_repository),
         }
     }
 }
@@ -56,6 +95,24 @@ pub enum ApplicationUseCaseError {
     #[error("Invalid name: {0}")]
     InvalidName(String),
 
+    #[error("Invalid token settings: {0}")]
+    InvalidTokenSettings(String),
+
+    #[error("Client not found")]
+    ClientNotFound(),
+
+    #[error("Client secret not found")]
+    ClientSecretNotFound(),
+
+    #[error("The last secret of a client cannot be deleted")]
+    LastClientSecret(),
+
+    #[error("Invalid client: {0}")]
+    InvalidClient(String),
+
+    #[error("Client secret generation error: {0}")]
+    ClientSecretGenerationError(String),
+
     #[error("{0}")]
     DatabaseError(String),
 }
@@ -65,7 +122,38 @@ pub enum ApplicationUseCaseError {
 pub struct ApplicationUseCaseResponse {
     pub id: ApplicationId,
     pub name: String,
+    pub access_token_lifetime: Option<u32>,
+    pub refresh_token_lifetime: Option<u32>,
+    pub audience: Option<String>,
+    pub created_at: UtcDateTime,
+    pub updated_at: UtcDateTime,
+    pub deleted_at: Option<UtcDateTime>,
+}
+
+/// Client use case generic response
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientUseCaseResponse {
+    pub id: ClientId,
+    pub application_id: ApplicationId,
+    pub name: String,
     pub created_at: UtcDateTime,
     pub updated_at: UtcDateTime,
     pub deleted_at: Option<UtcDateTime>,
 }
+
+/// Client secret use case generic response (the hashed secret is never returned)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientSecretUseCaseResponse {
+    pub id: ClientSecretId,
+    pub client_id: ClientId,
+    pub created_at: UtcDateTime,
+}
+
+/// Convert application validation errors into use case error
+fn validation_error(errors: ValidationErrors) -> ApplicationUseCaseError {
+    if errors.field_errors().contains_key("name") {
+        ApplicationUseCaseError::InvalidName(errors.to_string())
+    } else {
+        ApplicationUseCaseError::InvalidTokenSettings(errors.to_string())
+    }
+}
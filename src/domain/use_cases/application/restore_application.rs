@@ -1,27 +1,35 @@
 //! Restore restored application use case
 
 use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
 use crate::domain::repositories::application::ApplicationRepository;
 use crate::domain::repositories::application::dto::RestoreApplicationDtoRequest;
+use crate::domain::repositories::audit::AuditRepository;
 use crate::domain::use_cases::application::ApplicationUseCaseError;
+use crate::domain::use_cases::audit::record_audit_event;
 
 #[derive(Debug, Clone)]
 pub struct RestoreApplicationUseCaseRequest {
     pub id: ApplicationId,
+    pub audit: AuditContext,
 }
 
 #[derive(Debug, Clone)]
 pub struct RestoreApplicationUseCaseResponse();
 
 #[derive(Debug, Clone)]
-pub struct RestoreApplicationUseCase<A: ApplicationRepository> {
+pub struct RestoreApplicationUseCase<A: ApplicationRepository, R: AuditRepository> {
     application_repository: A,
+    audit_repository: R,
 }
 
-impl<A: ApplicationRepository> RestoreApplicationUseCase<A> {
+impl<A: ApplicationRepository, R: AuditRepository> RestoreApplicationUseCase<A, R> {
     /// Create a new use case
-    pub fn new(application_repository: A) -> Self {
-        Self { application_repository }
+    pub fn new(application_repository: A, audit_repository: R) -> Self {
+        Self {
+            application_repository,
+            audit_repository,
+        }
     }
 
     /// Restore an application
@@ -30,11 +38,14 @@ impl<A: ApplicationRepository> RestoreApplicationUseCase<A> {
         &self,
         request: RestoreApplicationUseCaseRequest,
     ) -> Result<RestoreApplicationUseCaseResponse, ApplicationUseCaseError> {
+        let (id, audit) = (request.id.to_string(), request.audit.clone());
         let application = self
             .application_repository
             .restore(RestoreApplicationDtoRequest(request))
             .await?;
 
+        record_audit_event(&self.audit_repository, &audit, AuditAction::ApplicationRestored, id).await;
+
         Ok(application.0)
     }
 }
@@ -42,6 +53,7 @@ impl<A: ApplicationRepository> RestoreApplicationUseCase<A> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
     use crate::domain::{
         tests::mock::application::{ApplicationRepositoryMock, INVALID_APPLICATION_ID, VALID_APPLICATION_ID},
         value_objects::id::Id,
@@ -51,10 +63,11 @@ mod tests {
     #[tokio::test]
     async fn test_restore_application_use_case() {
         let application_repository = ApplicationRepositoryMock {};
-        let use_case = RestoreApplicationUseCase::new(application_repository);
+        let use_case = RestoreApplicationUseCase::new(application_repository, AuditRepositoryMock {});
 
         let request = RestoreApplicationUseCaseRequest {
             id: Id::from_str(VALID_APPLICATION_ID).unwrap(),
+            audit: AuditContext::default(),
         };
 
         let response = use_case.call(request).await;
@@ -64,10 +77,11 @@ mod tests {
     #[tokio::test]
     async fn test_restore_application_use_case_with_error() {
         let application_repository = ApplicationRepositoryMock {};
-        let use_case = RestoreApplicationUseCase::new(application_repository);
+        let use_case = RestoreApplicationUseCase::new(application_repository, AuditRepositoryMock {});
 
         let request = RestoreApplicationUseCaseRequest {
             id: Id::from_str(INVALID_APPLICATION_ID).unwrap(),
+            audit: AuditContext::default(),
         };
 
         let response = use_case.call(request).await;
@@ -1,28 +1,51 @@
 //! Update application use case
 
 use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
 use crate::domain::repositories::application::ApplicationRepository;
 use crate::domain::repositories::application::dto::UpdateApplicationDtoRequest;
-use crate::domain::use_cases::application::ApplicationUseCaseError;
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::use_cases::application::{ApplicationUseCaseError, validation_error};
+use crate::domain::use_cases::audit::record_audit_event;
+use validator::Validate;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Validate)]
 pub struct UpdateApplicationUseCaseRequest {
     pub id: ApplicationId,
+
+    #[validate(length(min = 3))]
     pub name: String,
+
+    /// Access token lifetime (in minute)
+    #[validate(range(min = 1))]
+    pub access_token_lifetime: Option<u32>,
+
+    /// Refresh token lifetime (in day)
+    #[validate(range(min = 1))]
+    pub refresh_token_lifetime: Option<u32>,
+
+    #[validate(length(min = 1, max = 255))]
+    pub audience: Option<String>,
+
+    pub audit: AuditContext,
 }
 
 #[derive(Debug, Clone)]
 pub struct UpdateApplicationUseCaseResponse();
 
 #[derive(Debug, Clone)]
-pub struct UpdateApplicationUseCase<A: ApplicationRepository> {
+pub struct UpdateApplicationUseCase<A: ApplicationRepository, R: AuditRepository> {
     application_repository: A,
+    audit_repository: R,
 }
 
-impl<A: ApplicationRepository> UpdateApplicationUseCase<A> {
+impl<A: ApplicationRepository, R: AuditRepository> UpdateApplicationUseCase<A, R> {
     /// Create a new use case
-    pub fn new(application_repository: A) -> Self {
-        Self { application_repository }
+    pub fn new(application_repository: A, audit_repository: R) -> Self {
+        Self {
+            application_repository,
+            audit_repository,
+        }
     }
 
     /// Update an application
@@ -31,11 +54,18 @@ impl<A: ApplicationRepository> UpdateApplicationUseCase<A> {
         &self,
         request: UpdateApplicationUseCaseRequest,
     ) -> Result<UpdateApplicationUseCaseResponse, ApplicationUseCaseError> {
+        if let Err(err) = request.validate() {
+            return Err(validation_error(err));
+        }
+
+        let (id, audit) = (request.id.to_string(), request.audit.clone());
         let application = self
             .application_repository
             .update(UpdateApplicationDtoRequest(request))
             .await?;
 
+        record_audit_event(&self.audit_repository, &audit, AuditAction::ApplicationUpdated, id).await;
+
         Ok(application.0)
     }
 }
@@ -0,0 +1,86 @@
+//! Update an application client use case
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::client::ClientId;
+use crate::domain::repositories::client::ClientRepository;
+use crate::domain::repositories::client::dto::UpdateClientDtoRequest;
+use crate::domain::use_cases::application::ApplicationUseCaseError;
+use validator::Validate;
+
+#[derive(Debug, Clone, Validate)]
+pub struct UpdateClientUseCaseRequest {
+    pub application_id: ApplicationId,
+    pub id: ClientId,
+
+    #[validate(length(min = 3))]
+    pub name: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateClientUseCaseResponse();
+
+#[derive(Debug, Clone)]
+pub struct UpdateClientUseCase<C: ClientRepository> {
+    client_repository: C,
+}
+
+impl<C: ClientRepository> UpdateClientUseCase<C> {
+    /// Create a new use case
+    pub fn new(client_repository: C) -> Self {
+        Self { client_repository }
+    }
+
+    /// Update a client
+    #[instrument(skip(self), name = "update_client_use_case")]
+    pub async fn call(
+        &self,
+        request: UpdateClientUseCaseRequest,
+    ) -> Result<UpdateClientUseCaseResponse, ApplicationUseCaseError> {
+        if let Err(err) = request.validate() {
+            return Err(ApplicationUseCaseError::InvalidClient(err.to_string()));
+        }
+
+        let client = self
+            .client_repository
+            .update_client(UpdateClientDtoRequest(request))
+            .await?;
+
+        Ok(client.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::client::{
+        ClientRepositoryMock, INVALID_CLIENT_ID, VALID_APPLICATION_ID, VALID_CLIENT_ID,
+    };
+    use std::str::FromStr;
+
+    fn request(client_id: &str) -> UpdateClientUseCaseRequest {
+        UpdateClientUseCaseRequest {
+            application_id: ApplicationId::from_str(VALID_APPLICATION_ID).unwrap(),
+            id: ClientId::from_str(client_id).unwrap(),
+            name: "TV client".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_client_use_case() {
+        let use_case = UpdateClientUseCase::new(ClientRepositoryMock {});
+
+        assert!(use_case.call(request(VALID_CLIENT_ID)).await.is_ok());
+        assert_eq!(
+            use_case.call(request(INVALID_CLIENT_ID)).await.unwrap_err(),
+            ApplicationUseCaseError::ClientNotFound()
+        );
+
+        let response = use_case
+            .call(UpdateClientUseCaseRequest {
+                name: "TV".to_string(),
+                ..request(VALID_CLIENT_ID)
+            })
+            .await;
+        assert!(matches!(response, Err(ApplicationUseCaseError::InvalidClient(_))));
+    }
+}
@@ -0,0 +1,112 @@
+//! Get audit events use case
+
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::entities::user::UserId;
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::repositories::audit::dto::{
+    CountAuditEventsDtoRequest, GetAuditEventsDtoRequest, GetAuditEventsDtoResponse,
+};
+use crate::domain::use_cases::audit::AuditUseCaseError;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::pagination::{Pagination, PaginationCursor};
+use crate::domain::value_objects::query_sort::QuerySorts;
+
+/// Audit events filters (all filters must match)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AuditEventFilters {
+    pub actor_id: Option<UserId>,
+    pub action: Option<AuditAction>,
+    pub target_id: Option<String>,
+
+    /// Events created at or after this date
+    pub from: Option<UtcDateTime>,
+
+    /// Events created before this date
+    pub to: Option<UtcDateTime>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetAuditEventsUseCaseRequest {
+    pub pagination: Pagination,
+    pub sorts: Option<QuerySorts>,
+    pub filters: AuditEventFilters,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetAuditEventsUseCaseResponse {
+    pub events: Vec<AuditEvent>,
+    pub total: i64,
+    pub next_cursor: Option<PaginationCursor>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetAuditEventsUseCase<R: AuditRepository> {
+    audit_repository: R,
+}
+
+impl<R: AuditRepository> GetAuditEventsUseCase<R> {
+    /// Create a new use case
+    pub fn new(audit_repository: R) -> Self {
+        Self { audit_repository }
+    }
+
+    /// Get audit events
+    #[instrument(skip(self), name = "get_audit_events_use_case")]
+    pub async fn call(
+        &self,
+        request: GetAuditEventsUseCaseRequest,
+    ) -> Result<GetAuditEventsUseCaseResponse, AuditUseCaseError> {
+        let total = self
+            .audit_repository
+            .count_audit_events(CountAuditEventsDtoRequest(request.filters.clone()))
+            .await?
+            .0;
+
+        let GetAuditEventsDtoResponse { events, next_cursor } = self
+            .audit_repository
+            .get_audit_events(GetAuditEventsDtoRequest(request))
+            .await?;
+
+        Ok(GetAuditEventsUseCaseResponse {
+            events,
+            total,
+            next_cursor,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
+
+    #[tokio::test]
+    async fn test_get_audit_events_use_case() {
+        let use_case = GetAuditEventsUseCase::new(AuditRepositoryMock {});
+
+        let response = use_case
+            .call(GetAuditEventsUseCaseRequest {
+                pagination: Pagination::default(),
+                sorts: None,
+                filters: AuditEventFilters::default(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.total, 1);
+        assert_eq!(response.events.len(), 1);
+
+        let response = use_case
+            .call(GetAuditEventsUseCaseRequest {
+                pagination: Pagination::default(),
+                sorts: None,
+                filters: AuditEventFilters {
+                    action: Some(AuditAction::LoginFailed),
+                    ..AuditEventFilters::default()
+                },
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.total, 0);
+        assert!(response.events.is_empty());
+    }
+}
@@ -0,0 +1,64 @@
+//! Audit use cases
+
+pub mod get_audit_events;
+
+use crate::domain::entities::audit_event::{AuditAction, AuditContext, AuditEvent};
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::repositories::audit::dto::CreateAuditEventDtoRequest;
+use crate::domain::use_cases::audit::get_audit_events::GetAuditEventsUseCase;
+use crate::domain::value_objects::id::IdError;
+use thiserror::Error;
+
+#[derive(Debug, Clone)]
+pub struct AuditUseCases<R: AuditRepository> {
+    pub get_audit_events: GetAuditEventsUseCase<R>,
+}
+
+impl<R: AuditRepository> AuditUseCases<R> {
+    /// Create a new audit use cases
+    pub fn new(audit_repository: R) -> Self {
+        Self {
+            get_audit_events: GetAuditEventsUseCase::new(audit_repository),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum AuditUseCaseError {
+    #[error("Invalid id")]
+    InvalidId(),
+
+    #[error("Model conversion error")]
+    FromModelError(),
+
+    #[error("{0}")]
+    DatabaseError(String),
+}
+
+impl From<IdError> for AuditUseCaseError {
+    fn from(_: IdError) -> Self {
+        AuditUseCaseError::InvalidId()
+    }
+}
+
+/// Save an audit event
+///
+/// A failure is only logged because it must not prevent the audited action.
+pub async fn record_audit_event<R: AuditRepository>(
+    audit_repository: &R,
+    context: &AuditContext,
+    action: AuditAction,
+    target_id: String,
+) {
+    let event = match AuditEvent::new(context, action, target_id) {
+        Ok(event) => event,
+        Err(err) => {
+            warn!(error = %err, %action, "Failed to create audit event");
+            return;
+        }
+    };
+
+    if let Err(err) = audit_repository.create(CreateAuditEventDtoRequest(event)).await {
+        warn!(error = %err, %action, "Failed to save audit event");
+    }
+}
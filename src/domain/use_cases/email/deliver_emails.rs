@@ -0,0 +1,164 @@
+//! Deliver queued emails use case
+
+use crate::domain::entities::email::{EmailStatus, QueuedEmail};
+use crate::domain::repositories::email::EmailRepository;
+use crate::domain::repositories::email::dto::{GetDueEmailsDtoRequest, UpdateEmailDtoRequest};
+use crate::domain::services::email::EmailTransport;
+use crate::domain::use_cases::email::EmailUseCaseError;
+use crate::domain::use_cases::retry::{LAST_ERROR_MAX_LENGTH, backoff};
+use crate::domain::value_objects::datetime::UtcDateTime;
+use chrono::Duration;
+use futures::future::join_all;
+
+#[derive(Debug, Clone)]
+pub struct DeliverEmailsUseCaseRequest {
+    /// Maximum number of emails sent
+    pub batch_size: u32,
+
+    /// Number of attempts before an email is marked as dead
+    pub max_attempts: u32,
+
+    /// Delay before the first retry (in second), doubled after each attempt
+    pub backoff_base: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeliverEmailsUseCaseResponse {
+    pub sent: usize,
+
+    /// Emails which will be retried later
+    pub retried: usize,
+
+    /// Emails which reached the maximum number of attempts
+    pub dead: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeliverEmailsUseCase<R: EmailRepository, T: EmailTransport> {
+    email_repository: R,
+    email_transport: T,
+}
+
+impl<R: EmailRepository, T: EmailTransport> DeliverEmailsUseCase<R, T> {
+    /// Create a new use case
+    pub fn new(email_repository: R, email_transport: T) -> Self {
+        Self {
+            email_repository,
+            email_transport,
+        }
+    }
+
+    /// Send the due emails of the outbox
+    #[instrument(skip(self), name = "deliver_emails_use_case")]
+    pub async fn call(
+        &self,
+        request: DeliverEmailsUseCaseRequest,
+    ) -> Result<DeliverEmailsUseCaseResponse, EmailUseCaseError> {
+        let emails = self
+            .email_repository
+            .get_due_emails(GetDueEmailsDtoRequest {
+                limit: request.batch_size,
+            })
+            .await?
+            .0;
+
+        let updates = join_all(emails.iter().map(|email| self.attempt(email, &request))).await;
+
+        let mut response = DeliverEmailsUseCaseResponse::default();
+        for update in updates {
+            match update.status {
+                EmailStatus::Sent => response.sent += 1,
+                EmailStatus::Pending => response.retried += 1,
+                EmailStatus::Dead => response.dead += 1,
+            }
+
+            self.email_repository.update_email(update).await?;
+        }
+
+        Ok(response)
+    }
+
+    /// Send an email and compute its new state
+    async fn attempt(&self, email: &QueuedEmail, request: &DeliverEmailsUseCaseRequest) -> UpdateEmailDtoRequest {
+        let attempts = email.attempts.saturating_add(1);
+        let now = UtcDateTime::now();
+
+        match self.email_transport.send(&email.message).await {
+            Ok(()) => UpdateEmailDtoRequest {
+                id: email.id.clone(),
+                status: EmailStatus::Sent,
+                attempts,
+                next_attempt_at: now,
+                last_error: None,
+            },
+            Err(err) => {
+                warn!(error = %err, email_id = %email.id, attempts, "Email sending failed");
+
+                let status = if attempts >= request.max_attempts {
+                    EmailStatus::Dead
+                } else {
+                    EmailStatus::Pending
+                };
+                let delay = backoff(request.backoff_base, attempts);
+
+                UpdateEmailDtoRequest {
+                    id: email.id.clone(),
+                    status,
+                    attempts,
+                    next_attempt_at: UtcDateTime::new(now.value() + Duration::seconds(delay as i64)),
+                    last_error: Some(err.to_string().chars().take(LAST_ERROR_MAX_LENGTH).collect()),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::email::{EmailRepositoryMock, EmailTransportMock};
+
+    #[tokio::test]
+    async fn test_deliver_emails_use_case() {
+        let repository = EmailRepositoryMock::default();
+        let use_case = DeliverEmailsUseCase::new(repository.clone(), EmailTransportMock {});
+
+        let response = use_case
+            .call(DeliverEmailsUseCaseRequest {
+                batch_size: 10,
+                max_attempts: 3,
+                backoff_base: 60,
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            response,
+            DeliverEmailsUseCaseResponse {
+                sent: 1,
+                retried: 1,
+                dead: 1,
+            }
+        );
+
+        let updates = repository.updates.lock().unwrap();
+        assert_eq!(updates.len(), 3);
+
+        let sent = &updates[0];
+        assert_eq!(sent.status, EmailStatus::Sent);
+        assert_eq!(sent.attempts, 1);
+        assert_eq!(sent.last_error, None);
+
+        // First failure: retried 60 seconds later
+        let retried = &updates[1];
+        assert_eq!(retried.status, EmailStatus::Pending);
+        assert_eq!(retried.attempts, 1);
+        assert!(retried.last_error.is_some());
+        let delay = retried.next_attempt_at.timestamp() - UtcDateTime::now().timestamp();
+        assert!((59..=60).contains(&delay));
+
+        // Third failure: dead letter
+        let dead = &updates[2];
+        assert_eq!(dead.status, EmailStatus::Dead);
+        assert_eq!(dead.attempts, 3);
+    }
+}
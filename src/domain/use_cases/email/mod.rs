@@ -0,0 +1,31 @@
+//! Email use cases
+
+pub mod deliver_emails;
+
+use crate::domain::repositories::email::EmailRepository;
+use crate::domain::services::email::EmailTransport;
+use crate::domain::use_cases::email::deliver_emails::DeliverEmailsUseCase;
+use thiserror::Error;
+
+#[derive(Debug, Clone)]
+pub struct EmailUseCases<R: EmailRepository, T: EmailTransport> {
+    pub deliver_emails: DeliverEmailsUseCase<R, T>,
+}
+
+impl<R: EmailRepository, T: EmailTransport> EmailUseCases<R, T> {
+    /// Create a new email use cases
+    pub fn new(email_repository: R, email_transport: T) -> Self {
+        Self {
+            deliver_emails: DeliverEmailsUseCase::new(email_repository, email_transport),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum EmailUseCaseError {
+    #[error("Model conversion error")]
+    FromModelError(),
+
+    #[error("{0}")]
+    DatabaseError(String),
+}
@@ -1,24 +1,35 @@
 //! Create external link use case
 
 use super::{ExternalLinkUseCaseError, ExternalLinkUseCaseResponse};
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+use crate::domain::repositories::audit::AuditRepository;
 use crate::domain::repositories::external_link::ExternalLinkRepository;
 use crate::domain::repositories::external_link::dto::CreateExternalLinkDtoRequest;
+use crate::domain::use_cases::audit::record_audit_event;
+use crate::domain::value_objects::oidc_provider::OidcProvider;
 
 #[derive(Debug, Clone)]
 pub struct CreateExternalLinkUseCaseRequest {
     pub name: String,
+
+    /// OpenID Connect provider used to log in users
+    pub provider: Option<OidcProvider>,
+
+    pub audit: AuditContext,
 }
 
 #[derive(Debug, Clone)]
-pub struct CreateExternalLinkUseCase<L: ExternalLinkRepository> {
+pub struct CreateExternalLinkUseCase<L: ExternalLinkRepository, R: AuditRepository> {
     external_link_repository: L,
+    audit_repository: R,
 }
 
-impl<L: ExternalLinkRepository> CreateExternalLinkUseCase<L> {
+impl<L: ExternalLinkRepository, R: AuditRepository> CreateExternalLinkUseCase<L, R> {
     /// Create a new use case
-    pub fn new(external_link_repository: L) -> Self {
+    pub fn new(external_link_repository: L, audit_repository: R) -> Self {
         Self {
             external_link_repository,
+            audit_repository,
         }
     }
 
@@ -28,11 +39,20 @@ impl<L: ExternalLinkRepository> CreateExternalLinkUseCase<L> {
         &self,
         request: CreateExternalLinkUseCaseRequest,
     ) -> Result<ExternalLinkUseCaseResponse, ExternalLinkUseCaseError> {
+        let audit = request.audit.clone();
         let external_link = self
             .external_link_repository
             .create(CreateExternalLinkDtoRequest(request))
             .await?;
 
+        record_audit_event(
+            &self.audit_repository,
+            &audit,
+            AuditAction::ExternalLinkCreated,
+            external_link.0.id.to_string(),
+        )
+        .await;
+
         Ok(external_link.0)
     }
 }
@@ -40,16 +60,19 @@ impl<L: ExternalLinkRepository> CreateExternalLinkUseCase<L> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
     use crate::domain::tests::mock::external_link::{ExternalLinkRepositoryMock, VALID_EXTERNAL_LINK_NAME};
 
     #[tokio::test]
     async fn test_create_external_link() {
         let repository = ExternalLinkRepositoryMock {};
-        let create_external_link = CreateExternalLinkUseCase::new(repository);
+        let create_external_link = CreateExternalLinkUseCase::new(repository, AuditRepositoryMock {});
 
         let result = create_external_link
             .call(CreateExternalLinkUseCaseRequest {
                 name: VALID_EXTERNAL_LINK_NAME.to_string(),
+                provider: None,
+                audit: AuditContext::default(),
             })
             .await;
 
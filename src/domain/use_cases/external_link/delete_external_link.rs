@@ -1,6 +1,9 @@
 //! Delete external link use case
 
 use super::ExternalLinkUseCaseError;
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::use_cases::audit::record_audit_event;
 use crate::domain::{
     entities::external_link::ExternalLinkId,
     repositories::external_link::{ExternalLinkRepository, dto::DeleteExternalLinkDtoRequest},
@@ -9,21 +12,24 @@ use crate::domain::{
 #[derive(Debug, Clone)]
 pub struct DeleteExternalLinkUseCaseRequest {
     pub id: ExternalLinkId,
+    pub audit: AuditContext,
 }
 
 #[derive(Debug, Clone)]
 pub struct DeleteExternalLinkUseCaseResponse();
 
 #[derive(Debug, Clone)]
-pub struct DeleteExternalLinkUseCase<L: ExternalLinkRepository> {
+pub struct DeleteExternalLinkUseCase<L: ExternalLinkRepository, R: AuditRepository> {
     external_link_repository: L,
+    audit_repository: R,
 }
 
-impl<L: ExternalLinkRepository> DeleteExternalLinkUseCase<L> {
+impl<L: ExternalLinkRepository, R: AuditRepository> DeleteExternalLinkUseCase<L, R> {
     /// Create a new use case
-    pub fn new(external_link_repository: L) -> Self {
+    pub fn new(external_link_repository: L, audit_repository: R) -> Self {
         Self {
             external_link_repository,
+            audit_repository,
         }
     }
 
@@ -33,11 +39,14 @@ impl<L: ExternalLinkRepository> DeleteExternalLinkUseCase<L> {
         &self,
         request: DeleteExternalLinkUseCaseRequest,
     ) -> Result<DeleteExternalLinkUseCaseResponse, ExternalLinkUseCaseError> {
+        let (id, audit) = (request.id.to_string(), request.audit.clone());
         let external_link = self
             .external_link_repository
             .delete(DeleteExternalLinkDtoRequest(request))
             .await?;
 
+        record_audit_event(&self.audit_repository, &audit, AuditAction::ExternalLinkDeleted, id).await;
+
         Ok(external_link.0)
     }
 }
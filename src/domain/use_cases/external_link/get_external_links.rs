@@ -2,14 +2,18 @@
 
 use super::{ExternalLinkUseCaseError, ExternalLinkUseCaseResponse};
 use crate::domain::repositories::external_link::ExternalLinkRepository;
-use crate::domain::repositories::external_link::dto::{CountExternalLinksDtoRequest, GetExternalLinksDtoRequest};
-use crate::domain::value_objects::pagination::Pagination;
+use crate::domain::repositories::external_link::dto::{
+    CountExternalLinksDtoRequest, GetExternalLinksDtoRequest, GetExternalLinksDtoResponse,
+};
+use crate::domain::value_objects::pagination::{Pagination, PaginationCursor};
+use crate::domain::value_objects::query_filter::QueryFilters;
 use crate::domain::value_objects::query_sort::QuerySorts;
 
 #[derive(Debug, Clone)]
 pub struct GetExternalLinksUseCaseRequest {
     pub pagination: Pagination,
     pub sorts: Option<QuerySorts>,
+    pub filters: Option<QueryFilters>,
     pub deleted: bool,
 }
 
@@ -17,6 +21,7 @@ pub struct GetExternalLinksUseCaseRequest {
 pub struct GetExternalLinksUseCaseResponse {
     pub external_links: Vec<ExternalLinkUseCaseResponse>,
     pub total: i64,
+    pub next_cursor: Option<PaginationCursor>,
 }
 
 #[derive(Debug, Clone)]
@@ -42,17 +47,24 @@ impl<L: ExternalLinkRepository> GetExternalLinksUseCase<L> {
             .external_link_repository
             .count_external_links(CountExternalLinksDtoRequest {
                 deleted: request.deleted,
+                filters: request.filters.clone(),
             })
             .await?
             .0;
 
-        let external_links = self
+        let GetExternalLinksDtoResponse {
+            external_links,
+            next_cursor,
+        } = self
             .external_link_repository
             .get_external_links(GetExternalLinksDtoRequest(request))
-            .await?
-            .0;
+            .await?;
 
-        Ok(GetExternalLinksUseCaseResponse { external_links, total })
+        Ok(GetExternalLinksUseCaseResponse {
+            external_links,
+            total,
+            next_cursor,
+        })
     }
 }
 
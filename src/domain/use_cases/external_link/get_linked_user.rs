@@ -0,0 +1,72 @@
+//! Get the internal user known by a partner under an external user ID use case
+
+use super::{ExternalLinkUseCaseError, ExternalLinkUserUseCaseResponse};
+use crate::domain::{
+    entities::external_link::ExternalLinkId,
+    repositories::external_link::{ExternalLinkRepository, dto::GetLinkedUserDtoRequest},
+};
+
+#[derive(Debug, Clone)]
+pub struct GetLinkedUserUseCaseRequest {
+    pub external_link_id: ExternalLinkId,
+    pub external_user_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetLinkedUserUseCase<L: ExternalLinkRepository> {
+    external_link_repository: L,
+}
+
+impl<L: ExternalLinkRepository> GetLinkedUserUseCase<L> {
+    /// Create a new use case
+    pub fn new(external_link_repository: L) -> Self {
+        Self {
+            external_link_repository,
+        }
+    }
+
+    /// Get a linked user from its external user ID
+    #[instrument(skip(self), name = "get_linked_user_use_case")]
+    pub async fn call(
+        &self,
+        request: GetLinkedUserUseCaseRequest,
+    ) -> Result<ExternalLinkUserUseCaseResponse, ExternalLinkUseCaseError> {
+        self.external_link_repository
+            .get_linked_user(GetLinkedUserDtoRequest(request))
+            .await?
+            .0
+            .ok_or(ExternalLinkUseCaseError::ExternalLinkUserNotFound())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::external_link::{
+        ExternalLinkRepositoryMock, VALID_EXTERNAL_LINK_ID, VALID_EXTERNAL_USER_ID, VALID_USER_ID,
+    };
+    use crate::domain::value_objects::id::Id;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test_get_linked_user_use_case() {
+        let use_case = GetLinkedUserUseCase::new(ExternalLinkRepositoryMock {});
+
+        let response = use_case
+            .call(GetLinkedUserUseCaseRequest {
+                external_link_id: Id::from_str(VALID_EXTERNAL_LINK_ID).unwrap(),
+                external_user_id: VALID_EXTERNAL_USER_ID.to_string(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.user_id.to_string(), VALID_USER_ID);
+
+        let response = use_case
+            .call(GetLinkedUserUseCaseRequest {
+                external_link_id: Id::from_str(VALID_EXTERNAL_LINK_ID).unwrap(),
+                external_user_id: "unknown".to_string(),
+            })
+            .await;
+        assert_eq!(response, Err(ExternalLinkUseCaseError::ExternalLinkUserNotFound()));
+    }
+}
@@ -0,0 +1,115 @@
+//! Link an application to an external link use case
+
+use super::{ExternalLinkApplicationUseCaseResponse, ExternalLinkUseCaseError};
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::use_cases::audit::record_audit_event;
+use crate::domain::{
+    entities::{application::ApplicationId, external_link::ExternalLinkId},
+    repositories::external_link::{
+        ExternalLinkRepository,
+        dto::{GetExternalLinkByIdDtoRequest, LinkApplicationDtoRequest},
+    },
+    use_cases::external_link::get_external_link::GetExternalLinkByIdUseCaseRequest,
+};
+
+#[derive(Debug, Clone)]
+pub struct LinkApplicationUseCaseRequest {
+    pub external_link_id: ExternalLinkId,
+    pub application_id: ApplicationId,
+    pub audit: AuditContext,
+}
+
+#[derive(Debug, Clone)]
+pub struct LinkApplicationUseCase<L: ExternalLinkRepository, R: AuditRepository> {
+    external_link_repository: L,
+    audit_repository: R,
+}
+
+impl<L: ExternalLinkRepository, R: AuditRepository> LinkApplicationUseCase<L, R> {
+    /// Create a new use case
+    pub fn new(external_link_repository: L, audit_repository: R) -> Self {
+        Self {
+            external_link_repository,
+            audit_repository,
+        }
+    }
+
+    /// Link an application to an external link
+    #[instrument(skip(self), name = "link_application_use_case")]
+    pub async fn call(
+        &self,
+        request: LinkApplicationUseCaseRequest,
+    ) -> Result<ExternalLinkApplicationUseCaseResponse, ExternalLinkUseCaseError> {
+        // Check that the external link exists
+        self.external_link_repository
+            .get_by_id(GetExternalLinkByIdDtoRequest(GetExternalLinkByIdUseCaseRequest {
+                id: request.external_link_id.clone(),
+            }))
+            .await?;
+
+        let audit = request.audit.clone();
+        let target_id = request.application_id.to_string();
+        let link = self
+            .external_link_repository
+            .link_application(LinkApplicationDtoRequest(request))
+            .await?;
+
+        record_audit_event(
+            &self.audit_repository,
+            &audit,
+            AuditAction::ExternalLinkApplicationLinked,
+            target_id,
+        )
+        .await;
+
+        Ok(link.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
+    use crate::domain::tests::mock::external_link::{
+        ExternalLinkRepositoryMock, INVALID_APPLICATION_ID, INVALID_EXTERNAL_LINK_ID, VALID_APPLICATION_ID,
+        VALID_EXTERNAL_LINK_ID,
+    };
+    use crate::domain::value_objects::id::Id;
+    use std::str::FromStr;
+
+    fn request(external_link_id: &str, application_id: &str) -> LinkApplicationUseCaseRequest {
+        LinkApplicationUseCaseRequest {
+            external_link_id: Id::from_str(external_link_id).unwrap(),
+            application_id: Id::from_str(application_id).unwrap(),
+            audit: AuditContext::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_link_application_use_case() {
+        let use_case = LinkApplicationUseCase::new(ExternalLinkRepositoryMock {}, AuditRepositoryMock {});
+
+        let response = use_case
+            .call(request(VALID_EXTERNAL_LINK_ID, VALID_APPLICATION_ID))
+            .await
+            .unwrap();
+        assert_eq!(response.external_link_id.to_string(), VALID_EXTERNAL_LINK_ID);
+        assert_eq!(response.application_id.to_string(), VALID_APPLICATION_ID);
+    }
+
+    #[tokio::test]
+    async fn test_link_application_use_case_errors() {
+        let use_case = LinkApplicationUseCase::new(ExternalLinkRepositoryMock {}, AuditRepositoryMock {});
+
+        let response = use_case
+            .call(request(INVALID_EXTERNAL_LINK_ID, VALID_APPLICATION_ID))
+            .await;
+        assert_eq!(response, Err(ExternalLinkUseCaseError::ExternalLinkNotFound()));
+
+        let response = use_case
+            .call(request(VALID_EXTERNAL_LINK_ID, INVALID_APPLICATION_ID))
+            .await;
+        assert_eq!(response, Err(ExternalLinkUseCaseError::ApplicationNotFound()));
+    }
+}
@@ -0,0 +1,128 @@
+//! Link a user to an external link use case
+
+use super::{ExternalLinkUseCaseError, ExternalLinkUserUseCaseResponse};
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::use_cases::audit::record_audit_event;
+use crate::domain::{
+    entities::{external_link::ExternalLinkId, user::UserId},
+    repositories::external_link::{
+        ExternalLinkRepository,
+        dto::{GetExternalLinkByIdDtoRequest, LinkUserDtoRequest},
+    },
+    use_cases::external_link::get_external_link::GetExternalLinkByIdUseCaseRequest,
+};
+use validator::Validate;
+
+#[derive(Debug, Clone, Validate)]
+pub struct LinkUserUseCaseRequest {
+    pub external_link_id: ExternalLinkId,
+    pub user_id: UserId,
+
+    /// User identifier provided by the partner
+    #[validate(length(min = 1, max = 255))]
+    pub external_user_id: String,
+
+    pub audit: AuditContext,
+}
+
+#[derive(Debug, Clone)]
+pub struct LinkUserUseCase<L: ExternalLinkRepository, R: AuditRepository> {
+    external_link_repository: L,
+    audit_repository: R,
+}
+
+impl<L: ExternalLinkRepository, R: AuditRepository> LinkUserUseCase<L, R> {
+    /// Create a new use case
+    pub fn new(external_link_repository: L, audit_repository: R) -> Self {
+        Self {
+            external_link_repository,
+            audit_repository,
+        }
+    }
+
+    /// Link a user to an external link, replacing its previous external user ID
+    #[instrument(skip(self), name = "link_user_use_case")]
+    pub async fn call(
+        &self,
+        request: LinkUserUseCaseRequest,
+    ) -> Result<ExternalLinkUserUseCaseResponse, ExternalLinkUseCaseError> {
+        request
+            .validate()
+            .map_err(|_| ExternalLinkUseCaseError::InvalidExternalUserId())?;
+
+        // Check that the external link exists
+        self.external_link_repository
+            .get_by_id(GetExternalLinkByIdDtoRequest(GetExternalLinkByIdUseCaseRequest {
+                id: request.external_link_id.clone(),
+            }))
+            .await?;
+
+        let audit = request.audit.clone();
+        let link = self
+            .external_link_repository
+            .link_user(LinkUserDtoRequest(request))
+            .await?;
+
+        record_audit_event(
+            &self.audit_repository,
+            &audit,
+            AuditAction::ExternalLinkUserLinked,
+            link.0.user_id.to_string(),
+        )
+        .await;
+
+        Ok(link.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
+    use crate::domain::tests::mock::external_link::{
+        ExternalLinkRepositoryMock, INVALID_EXTERNAL_LINK_ID, USED_EXTERNAL_USER_ID, VALID_EXTERNAL_LINK_ID,
+        VALID_EXTERNAL_USER_ID, VALID_USER_ID,
+    };
+    use crate::domain::value_objects::id::Id;
+    use std::str::FromStr;
+
+    fn request(external_link_id: &str, external_user_id: &str) -> LinkUserUseCaseRequest {
+        LinkUserUseCaseRequest {
+            external_link_id: Id::from_str(external_link_id).unwrap(),
+            user_id: Id::from_str(VALID_USER_ID).unwrap(),
+            external_user_id: external_user_id.to_string(),
+            audit: AuditContext::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_link_user_use_case() {
+        let use_case = LinkUserUseCase::new(ExternalLinkRepositoryMock {}, AuditRepositoryMock {});
+
+        let response = use_case
+            .call(request(VALID_EXTERNAL_LINK_ID, VALID_EXTERNAL_USER_ID))
+            .await
+            .unwrap();
+        assert_eq!(response.user_id.to_string(), VALID_USER_ID);
+        assert_eq!(response.external_user_id, VALID_EXTERNAL_USER_ID);
+    }
+
+    #[tokio::test]
+    async fn test_link_user_use_case_errors() {
+        let use_case = LinkUserUseCase::new(ExternalLinkRepositoryMock {}, AuditRepositoryMock {});
+
+        let response = use_case.call(request(VALID_EXTERNAL_LINK_ID, "")).await;
+        assert_eq!(response, Err(ExternalLinkUseCaseError::InvalidExternalUserId()));
+
+        let response = use_case
+            .call(request(INVALID_EXTERNAL_LINK_ID, VALID_EXTERNAL_USER_ID))
+            .await;
+        assert_eq!(response, Err(ExternalLinkUseCaseError::ExternalLinkNotFound()));
+
+        let response = use_case
+            .call(request(VALID_EXTERNAL_LINK_ID, USED_EXTERNAL_USER_ID))
+            .await;
+        assert_eq!(response, Err(ExternalLinkUseCaseError::ExternalUserIdAlreadyUsed()));
+    }
+}
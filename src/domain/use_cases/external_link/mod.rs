@@ -4,41 +4,73 @@ pub mod create_external_link;
 pub mod delete_external_link;
 pub mod get_external_link;
 pub mod get_external_links;
+pub mod get_linked_user;
+pub mod link_application;
+pub mod link_user;
 pub mod restore_external_link;
+pub mod unlink_application;
+pub mod unlink_user;
 pub mod update_external_link;
 
+use crate::domain::repositories::audit::AuditRepository;
 use crate::domain::{
-    entities::external_link::ExternalLinkId, repositories::external_link::ExternalLinkRepository,
-    value_objects::datetime::UtcDateTime,
+    entities::{application::ApplicationId, external_link::ExternalLinkId, user::UserId},
+    repositories::external_link::ExternalLinkRepository,
+    value_objects::{datetime::UtcDateTime, oidc_provider::OidcProvider},
 };
 use create_external_link::CreateExternalLinkUseCase;
 use thiserror::Error;
 
 #[derive(Debug, Clone)]
-pub struct ExternalLinkUseCases<L: ExternalLinkRepository> {
-    pub create_external_link: create_external_link::CreateExternalLinkUseCase<L>,
+pub struct ExternalLinkUseCases<L: ExternalLinkRepository, R: AuditRepository> {
+    pub create_external_link: create_external_link::CreateExternalLinkUseCase<L, R>,
     pub get_external_link: get_external_link::GetExternalLinkByIdUseCase<L>,
     pub get_external_links: get_external_links::GetExternalLinksUseCase<L>,
-    pub update_external_link: update_external_link::UpdateExternalLinkUseCase<L>,
-    pub delete_external_link: delete_external_link::DeleteExternalLinkUseCase<L>,
-    pub restore_external_link: restore_external_link::RestoreExternalLinkUseCase<L>,
+    pub update_external_link: update_external_link::UpdateExternalLinkUseCase<L, R>,
+    pub delete_external_link: delete_external_link::DeleteExternalLinkUseCase<L, R>,
+    pub restore_external_link: restore_external_link::RestoreExternalLinkUseCase<L, R>,
+    pub link_user: link_user::LinkUserUseCase<L, R>,
+    pub unlink_user: unlink_user::UnlinkUserUseCase<L, R>,
+    pub get_linked_user: get_linked_user::GetLinkedUserUseCase<L>,
+    pub link_application: link_application::LinkApplicationUseCase<L, R>,
+    pub unlink_application: unlink_application::UnlinkApplicationUseCase<L, R>,
 }
 
-impl<L: ExternalLinkRepository> ExternalLinkUseCases<L> {
+impl<L: ExternalLinkRepository, R: AuditRepository> ExternalLinkUseCases<L, R> {
     /// Create a new external link use cases
-    pub fn new(external_link_repository: L) -> Self {
+    pub fn new(external_link_repository: L, audit_repository: R) -> Self {
         Self {
-            create_external_link: CreateExternalLinkUseCase::new(external_link_repository.clone()),
+            create_external_link: CreateExternalLinkUseCase::new(
+                external_link_repository.clone(),
+                audit_repository.clone(),
+            ),
             get_external_link: get_external_link::GetExternalLinkByIdUseCase::new(external_link_repository.clone()),
             get_external_links: get_external_links::GetExternalLinksUseCase::new(external_link_repository.clone()),
             update_external_link: update_external_link::UpdateExternalLinkUseCase::new(
                 external_link_repository.clone(),
+                audit_repository.clone(),
             ),
             delete_external_link: delete_external_link::DeleteExternalLinkUseCase::new(
                 external_link_repository.clone(),
+                audit_repository.clone(),
             ),
             restore_external_link: restore_external_link::RestoreExternalLinkUseCase::new(
                 external_link_repository.clone(),
+                audit_repository.clone(),
+            ),
+            link_user: link_user::LinkUserUseCase::new(external_link_repository.clone(), audit_repository.clone()),
+            unlink_user: unlink_user::UnlinkUserUseCase::new(
+                external_link_repository.clone(),
+                audit_repository.clone(),
+            ),
+            get_linked_user: get_linked_user::GetLinkedUserUseCase::new(external_link_repository.clone()),
+            link_application: link_application::LinkApplicationUseCase::new(
+                external_link_repository.clone(),
+                audit_repository.clone(),
+            ),
+            unlink_application: unlink_application::UnlinkApplicationUseCase::new(
+                external_link_repository,
+                audit_repository,
             ),
         }
     }
@@ -52,6 +84,24 @@ pub enum ExternalLinkUseCaseError {
     #[error("Invalid external link id")]
     InvalidId(),
 
+    #[error("User not found")]
+    UserNotFound(),
+
+    #[error("Application not found")]
+    ApplicationNotFound(),
+
+    #[error("User is not linked to this external link")]
+    ExternalLinkUserNotFound(),
+
+    #[error("Application is not linked to this external link")]
+    ExternalLinkApplicationNotFound(),
+
+    #[error("Invalid external user ID (between 1 and 255 characters)")]
+    InvalidExternalUserId(),
+
+    #[error("External user ID already used by another user")]
+    ExternalUserIdAlreadyUsed(),
+
     #[error("Model conversion error")]
     FromModelError(),
 
@@ -64,7 +114,25 @@ pub enum ExternalLinkUseCaseError {
 pub struct ExternalLinkUseCaseResponse {
     pub id: ExternalLinkId,
     pub name: String,
+    pub provider: Option<OidcProvider>,
     pub created_at: UtcDateTime,
     pub updated_at: UtcDateTime,
     pub deleted_at: Option<UtcDateTime>,
 }
+
+/// User linked to an external link use case response
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalLinkUserUseCaseResponse {
+    pub external_link_id: ExternalLinkId,
+    pub user_id: UserId,
+    pub external_user_id: String,
+    pub created_at: UtcDateTime,
+}
+
+/// Application linked to an external link use case response
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalLinkApplicationUseCaseResponse {
+    pub external_link_id: ExternalLinkId,
+    pub application_id: ApplicationId,
+    pub created_at: UtcDateTime,
+}
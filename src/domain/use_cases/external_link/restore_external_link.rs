@@ -1,6 +1,9 @@
 //! Restore external link use case
 
 use super::ExternalLinkUseCaseError;
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::use_cases::audit::record_audit_event;
 use crate::domain::{
     entities::external_link::ExternalLinkId,
     repositories::external_link::{ExternalLinkRepository, dto::RestoreExternalLinkDtoRequest},
@@ -9,21 +12,24 @@ use crate::domain::{
 #[derive(Debug, Clone)]
 pub struct RestoreExternalLinkUseCaseRequest {
     pub id: ExternalLinkId,
+    pub audit: AuditContext,
 }
 
 #[derive(Debug, Clone)]
 pub struct RestoreExternalLinkUseCaseResponse();
 
 #[derive(Debug, Clone)]
-pub struct RestoreExternalLinkUseCase<L: ExternalLinkRepository> {
+pub struct RestoreExternalLinkUseCase<L: ExternalLinkRepository, R: AuditRepository> {
     external_link_repository: L,
+    audit_repository: R,
 }
 
-impl<L: ExternalLinkRepository> RestoreExternalLinkUseCase<L> {
+impl<L: ExternalLinkRepository, R: AuditRepository> RestoreExternalLinkUseCase<L, R> {
     /// Create a new use case
-    pub fn new(external_link_repository: L) -> Self {
+    pub fn new(external_link_repository: L, audit_repository: R) -> Self {
         Self {
             external_link_repository,
+            audit_repository,
         }
     }
 
@@ -33,11 +39,14 @@ impl<L: ExternalLinkRepository> RestoreExternalLinkUseCase<L> {
         &self,
         request: RestoreExternalLinkUseCaseRequest,
     ) -> Result<RestoreExternalLinkUseCaseResponse, ExternalLinkUseCaseError> {
+        let (id, audit) = (request.id.to_string(), request.audit.clone());
         let external_link = self
             .external_link_repository
             .restore(RestoreExternalLinkDtoRequest(request))
             .await?;
 
+        record_audit_event(&self.audit_repository, &audit, AuditAction::ExternalLinkRestored, id).await;
+
         Ok(external_link.0)
     }
 }
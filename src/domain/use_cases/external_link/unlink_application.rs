@@ -0,0 +1,93 @@
+//! Unlink an application from an external link use case
+
+use super::ExternalLinkUseCaseError;
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::use_cases::audit::record_audit_event;
+use crate::domain::{
+    entities::{application::ApplicationId, external_link::ExternalLinkId},
+    repositories::external_link::{ExternalLinkRepository, dto::UnlinkApplicationDtoRequest},
+};
+
+#[derive(Debug, Clone)]
+pub struct UnlinkApplicationUseCaseRequest {
+    pub external_link_id: ExternalLinkId,
+    pub application_id: ApplicationId,
+    pub audit: AuditContext,
+}
+
+#[derive(Debug, Clone)]
+pub struct UnlinkApplicationUseCaseResponse();
+
+#[derive(Debug, Clone)]
+pub struct UnlinkApplicationUseCase<L: ExternalLinkRepository, R: AuditRepository> {
+    external_link_repository: L,
+    audit_repository: R,
+}
+
+impl<L: ExternalLinkRepository, R: AuditRepository> UnlinkApplicationUseCase<L, R> {
+    /// Create a new use case
+    pub fn new(external_link_repository: L, audit_repository: R) -> Self {
+        Self {
+            external_link_repository,
+            audit_repository,
+        }
+    }
+
+    /// Unlink an application from an external link
+    #[instrument(skip(self), name = "unlink_application_use_case")]
+    pub async fn call(
+        &self,
+        request: UnlinkApplicationUseCaseRequest,
+    ) -> Result<UnlinkApplicationUseCaseResponse, ExternalLinkUseCaseError> {
+        let audit = request.audit.clone();
+        let target_id = request.application_id.to_string();
+        let response = self
+            .external_link_repository
+            .unlink_application(UnlinkApplicationDtoRequest(request))
+            .await?;
+
+        record_audit_event(
+            &self.audit_repository,
+            &audit,
+            AuditAction::ExternalLinkApplicationUnlinked,
+            target_id,
+        )
+        .await;
+
+        Ok(response.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
+    use crate::domain::tests::mock::external_link::{
+        ExternalLinkRepositoryMock, INVALID_APPLICATION_ID, VALID_APPLICATION_ID, VALID_EXTERNAL_LINK_ID,
+    };
+    use crate::domain::value_objects::id::Id;
+    use std::str::FromStr;
+
+    fn request(application_id: &str) -> UnlinkApplicationUseCaseRequest {
+        UnlinkApplicationUseCaseRequest {
+            external_link_id: Id::from_str(VALID_EXTERNAL_LINK_ID).unwrap(),
+            application_id: Id::from_str(application_id).unwrap(),
+            audit: AuditContext::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unlink_application_use_case() {
+        let use_case = UnlinkApplicationUseCase::new(ExternalLinkRepositoryMock {}, AuditRepositoryMock {});
+
+        let response = use_case.call(request(VALID_APPLICATION_ID)).await;
+        assert!(response.is_ok());
+
+        let response = use_case.call(request(INVALID_APPLICATION_ID)).await;
+        assert!(matches!(
+            response,
+            Err(ExternalLinkUseCaseError::ExternalLinkApplicationNotFound())
+        ));
+    }
+}
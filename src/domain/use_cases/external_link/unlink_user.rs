@@ -0,0 +1,93 @@
+//! Unlink a user from an external link use case
+
+use super::ExternalLinkUseCaseError;
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::use_cases::audit::record_audit_event;
+use crate::domain::{
+    entities::{external_link::ExternalLinkId, user::UserId},
+    repositories::external_link::{ExternalLinkRepository, dto::UnlinkUserDtoRequest},
+};
+
+#[derive(Debug, Clone)]
+pub struct UnlinkUserUseCaseRequest {
+    pub external_link_id: ExternalLinkId,
+    pub user_id: UserId,
+    pub audit: AuditContext,
+}
+
+#[derive(Debug, Clone)]
+pub struct UnlinkUserUseCaseResponse();
+
+#[derive(Debug, Clone)]
+pub struct UnlinkUserUseCase<L: ExternalLinkRepository, R: AuditRepository> {
+    external_link_repository: L,
+    audit_repository: R,
+}
+
+impl<L: ExternalLinkRepository, R: AuditRepository> UnlinkUserUseCase<L, R> {
+    /// Create a new use case
+    pub fn new(external_link_repository: L, audit_repository: R) -> Self {
+        Self {
+            external_link_repository,
+            audit_repository,
+        }
+    }
+
+    /// Unlink a user from an external link
+    #[instrument(skip(self), name = "unlink_user_use_case")]
+    pub async fn call(
+        &self,
+        request: UnlinkUserUseCaseRequest,
+    ) -> Result<UnlinkUserUseCaseResponse, ExternalLinkUseCaseError> {
+        let audit = request.audit.clone();
+        let target_id = request.user_id.to_string();
+        let response = self
+            .external_link_repository
+            .unlink_user(UnlinkUserDtoRequest(request))
+            .await?;
+
+        record_audit_event(
+            &self.audit_repository,
+            &audit,
+            AuditAction::ExternalLinkUserUnlinked,
+            target_id,
+        )
+        .await;
+
+        Ok(response.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
+    use crate::domain::tests::mock::external_link::{
+        ExternalLinkRepositoryMock, INVALID_USER_ID, VALID_EXTERNAL_LINK_ID, VALID_USER_ID,
+    };
+    use crate::domain::value_objects::id::Id;
+    use std::str::FromStr;
+
+    fn request(user_id: &str) -> UnlinkUserUseCaseRequest {
+        UnlinkUserUseCaseRequest {
+            external_link_id: Id::from_str(VALID_EXTERNAL_LINK_ID).unwrap(),
+            user_id: Id::from_str(user_id).unwrap(),
+            audit: AuditContext::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unlink_user_use_case() {
+        let use_case = UnlinkUserUseCase::new(ExternalLinkRepositoryMock {}, AuditRepositoryMock {});
+
+        let response = use_case.call(request(VALID_USER_ID)).await;
+        assert!(response.is_ok());
+
+        let response = use_case.call(request(INVALID_USER_ID)).await;
+        assert!(matches!(
+            response,
+            Err(ExternalLinkUseCaseError::ExternalLinkUserNotFound())
+        ));
+    }
+}
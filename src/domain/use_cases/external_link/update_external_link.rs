@@ -1,30 +1,41 @@
 //! Update external link use case
 
 use super::ExternalLinkUseCaseError;
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::use_cases::audit::record_audit_event;
 use crate::domain::{
     entities::external_link::ExternalLinkId,
     repositories::external_link::{ExternalLinkRepository, dto::UpdateExternalLinkDtoRequest},
+    value_objects::oidc_provider::OidcProvider,
 };
 
 #[derive(Debug, Clone)]
 pub struct UpdateExternalLinkUseCaseRequest {
     pub id: ExternalLinkId,
     pub name: String,
+
+    /// OpenID Connect provider used to log in users
+    pub provider: Option<OidcProvider>,
+
+    pub audit: AuditContext,
 }
 
 #[derive(Debug, Clone)]
 pub struct UpdateExternalLinkUseCaseResponse();
 
 #[derive(Debug, Clone)]
-pub struct UpdateExternalLinkUseCase<L: ExternalLinkRepository> {
+pub struct UpdateExternalLinkUseCase<L: ExternalLinkRepository, R: AuditRepository> {
     external_link_repository: L,
+    audit_repository: R,
 }
 
-impl<L: ExternalLinkRepository> UpdateExternalLinkUseCase<L> {
+impl<L: ExternalLinkRepository, R: AuditRepository> UpdateExternalLinkUseCase<L, R> {
     /// Create a new use case
-    pub fn new(external_link_repository: L) -> Self {
+    pub fn new(external_link_repository: L, audit_repository: R) -> Self {
         Self {
             external_link_repository,
+            audit_repository,
         }
     }
 
@@ -34,11 +45,14 @@ impl<L: ExternalLinkRepository> UpdateExternalLinkUseCase<L> {
         &self,
         request: UpdateExternalLinkUseCaseRequest,
     ) -> Result<UpdateExternalLinkUseCaseResponse, ExternalLinkUseCaseError> {
+        let (id, audit) = (request.id.to_string(), request.audit.clone());
         let external_link = self
             .external_link_repository
             .update(UpdateExternalLinkDtoRequest(request))
             .await?;
 
+        record_audit_event(&self.audit_repository, &audit, AuditAction::ExternalLinkUpdated, id).await;
+
         Ok(external_link.0)
     }
 }
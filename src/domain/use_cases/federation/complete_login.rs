@@ -0,0 +1,288 @@
+//! Complete login use case
+
+use crate::domain::entities::access_token::AccessToken;
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+use crate::domain::entities::external_link::ExternalLinkId;
+use crate::domain::entities::refresh_token::RefreshToken;
+use crate::domain::repositories::application::ApplicationRepository;
+use crate::domain::repositories::application::dto::GetApplicationByIdDtoRequest;
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::repositories::federation::FederationRepository;
+use crate::domain::repositories::federation::dto::{
+    ConsumeLoginStateDtoRequest, FindOrCreateUserDtoRequest, GetProviderDtoRequest,
+};
+use crate::domain::repositories::refresh_token::RefreshTokenRepository;
+use crate::domain::repositories::refresh_token::dto::CreateRefreshTokenDtoRequest;
+use crate::domain::services::identity_provider::{ExchangeCodeRequest, IdentityProviderService};
+use crate::domain::services::security::jwt::Jwt;
+use crate::domain::services::security::payload::PayloadData;
+use crate::domain::use_cases::application::ApplicationUseCaseError;
+use crate::domain::use_cases::application::get_application::GetApplicationByIdUseCaseRequest;
+use crate::domain::use_cases::audit::record_audit_event;
+use crate::domain::use_cases::federation::FederationUseCaseError;
+use crate::domain::value_objects::client_secret::ClientSecret;
+use crate::domain::value_objects::email::Email;
+use crate::domain::value_objects::password_policy::PasswordPolicy;
+
+/// Maximum length of the user lastname and firstname
+const NAME_MAX_LENGTH: usize = 63;
+
+#[derive(Debug, Clone)]
+pub struct CompleteLoginUseCaseRequest {
+    pub external_link_id: ExternalLinkId,
+
+    /// State returned by the provider
+    pub state: String,
+
+    /// Authorization code returned by the provider
+    pub code: String,
+
+    /// URL used to start the login
+    pub redirect_uri: String,
+
+    /// JWT instance
+    pub jwt: Jwt,
+
+    /// Policy used to hash the random password of a new user
+    pub password_policy: PasswordPolicy,
+
+    pub audit: AuditContext,
+}
+
+#[derive(Debug, Clone)]
+pub struct CompleteLoginUseCaseResponse {
+    /// Access token
+    pub access_token: AccessToken,
+
+    /// Refresh token
+    pub refresh_token: RefreshToken,
+}
+
+#[derive(Debug, Clone)]
+pub struct CompleteLoginUseCase<
+    F: FederationRepository,
+    T: RefreshTokenRepository,
+    A: ApplicationRepository,
+    I: IdentityProviderService,
+    R: AuditRepository,
+> {
+    federation_repository: F,
+    refresh_token_repository: T,
+    application_repository: A,
+    identity_provider_service: I,
+    audit_repository: R,
+}
+
+impl<
+    F: FederationRepository,
+    T: RefreshTokenRepository,
+    A: ApplicationRepository,
+    I: IdentityProviderService,
+    R: AuditRepository,
+> CompleteLoginUseCase<F, T, A, I, R>
+{
+    /// Create a new use case
+    pub fn new(
+        federation_repository: F,
+        refresh_token_repository: T,
+        application_repository: A,
+        identity_provider_service: I,
+        audit_repository: R,
+    ) -> Self {
+        Self {
+            federation_repository,
+            refresh_token_repository,
+            application_repository,
+            identity_provider_service,
+            audit_repository,
+        }
+    }
+
+    /// Validate the provider callback and issue an access token and a refresh token
+    #[instrument(skip(self), name = "complete_login_use_case")]
+    pub async fn call(
+        &self,
+        request: CompleteLoginUseCaseRequest,
+    ) -> Result<CompleteLoginUseCaseResponse, FederationUseCaseError> {
+        // The state can only be used once
+        let login_state = self
+            .federation_repository
+            .consume_login_state(ConsumeLoginStateDtoRequest { state: request.state })
+            .await?
+            .0
+            .filter(|login_state| login_state.external_link_id == request.external_link_id)
+            .ok_or_else(FederationUseCaseError::InvalidState)?;
+
+        let provider = self
+            .federation_repository
+            .get_provider(GetProviderDtoRequest {
+                external_link_id: login_state.external_link_id.clone(),
+                application_id: login_state.application_id.clone(),
+            })
+            .await?
+            .0
+            .ok_or_else(FederationUseCaseError::ProviderNotFound)?;
+
+        // Get the validated ID token claims
+        let claims = self
+            .identity_provider_service
+            .exchange_code(ExchangeCodeRequest {
+                provider,
+                redirect_uri: request.redirect_uri,
+                code: request.code,
+                nonce: login_state.nonce,
+            })
+            .await?;
+
+        // Only a verified email can be trusted to link an existing user
+        let email = match (claims.email, claims.email_verified) {
+            (Some(email), true) => Email::new(&email).ok(),
+            _ => None,
+        };
+        let user_id = self
+            .federation_repository
+            .find_or_create_user(FindOrCreateUserDtoRequest {
+                external_link_id: login_state.external_link_id,
+                external_user_id: claims.subject,
+                email,
+                lastname: truncate_name(claims.family_name),
+                firstname: truncate_name(claims.given_name),
+                password: ClientSecret::generate().hash(&request.password_policy)?,
+            })
+            .await?
+            .0;
+
+        // Use the application token settings
+        let application = self
+            .application_repository
+            .get_by_id(GetApplicationByIdDtoRequest(GetApplicationByIdUseCaseRequest {
+                id: login_state.application_id,
+            }))
+            .await
+            .map_err(|err| match err {
+                ApplicationUseCaseError::ApplicationNotFound() => FederationUseCaseError::ApplicationNotFound(),
+                _ => FederationUseCaseError::DatabaseError(err.to_string()),
+            })?
+            .0;
+        let jwt = request
+            .jwt
+            .for_application(application.access_token_lifetime, application.refresh_token_lifetime);
+
+        // Generate access token
+        let payload = PayloadData::new(user_id.to_string(), application.id.to_string(), "".to_string())
+            .with_audience(application.audience);
+        let access_token = jwt.generate(payload).map_err(|err| {
+            error!(error = %err, "Error generating access token");
+            FederationUseCaseError::AccessTokenGenerationError()
+        })?;
+
+        // Generate and save refresh token
+        let refresh_token = RefreshToken::create(user_id.clone(), access_token.clone(), jwt.refresh_lifetime())?;
+        self.refresh_token_repository
+            .create_refresh_token(CreateRefreshTokenDtoRequest {
+                refresh_token: refresh_token.clone(),
+                access_token: access_token.clone(),
+                user_id: user_id.clone(),
+                application_id: application.id.clone(),
+            })
+            .await?;
+
+        record_audit_event(
+            &self.audit_repository,
+            &request.audit,
+            AuditAction::LoginSucceeded,
+            user_id.to_string(),
+        )
+        .await;
+
+        Ok(CompleteLoginUseCaseResponse {
+            access_token,
+            refresh_token,
+        })
+    }
+}
+
+/// Truncate an optional name claim to fit in the user table
+fn truncate_name(name: Option<String>) -> String {
+    name.unwrap_or_default().trim().chars().take(NAME_MAX_LENGTH).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::application::ApplicationRepositoryMock;
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
+    use crate::domain::tests::mock::federation::{
+        FederationRepositoryMock, INVALID_CODE, IdentityProviderServiceMock, OTHER_EXTERNAL_LINK_ID, VALID_CODE,
+        VALID_EXTERNAL_LINK_ID, VALID_STATE, VALID_USER_ID,
+    };
+    use crate::domain::tests::mock::refresh_token::RefreshTokenRepositoryMock;
+    use crate::domain::value_objects::id::Id;
+    use std::str::FromStr;
+
+    fn use_case() -> CompleteLoginUseCase<
+        FederationRepositoryMock,
+        RefreshTokenRepositoryMock,
+        ApplicationRepositoryMock,
+        IdentityProviderServiceMock,
+        AuditRepositoryMock,
+    > {
+        CompleteLoginUseCase::new(
+            FederationRepositoryMock {},
+            RefreshTokenRepositoryMock {},
+            ApplicationRepositoryMock {},
+            IdentityProviderServiceMock {},
+            AuditRepositoryMock {},
+        )
+    }
+
+    fn request(external_link_id: &str, state: &str, code: &str) -> CompleteLoginUseCaseRequest {
+        CompleteLoginUseCaseRequest {
+            external_link_id: Id::from_str(external_link_id).unwrap(),
+            state: state.to_string(),
+            code: code.to_string(),
+            redirect_uri: "http://localhost/callback".to_string(),
+            jwt: Jwt::init("HS256", 1, 1, Some("secret"), None, None).unwrap(),
+            password_policy: PasswordPolicy::default(),
+            audit: AuditContext::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_complete_login_use_case() {
+        let response = use_case()
+            .call(request(VALID_EXTERNAL_LINK_ID, VALID_STATE, VALID_CODE))
+            .await
+            .unwrap();
+        assert_eq!(response.refresh_token.user_id.to_string(), VALID_USER_ID);
+        assert_eq!(response.refresh_token.access_token, response.access_token);
+    }
+
+    #[tokio::test]
+    async fn test_complete_login_use_case_errors() {
+        let response = use_case()
+            .call(request(VALID_EXTERNAL_LINK_ID, "unknown-state", VALID_CODE))
+            .await;
+        assert_eq!(response.unwrap_err(), FederationUseCaseError::InvalidState());
+
+        let response = use_case()
+            .call(request(OTHER_EXTERNAL_LINK_ID, VALID_STATE, VALID_CODE))
+            .await;
+        assert_eq!(response.unwrap_err(), FederationUseCaseError::InvalidState());
+
+        let response = use_case()
+            .call(request(VALID_EXTERNAL_LINK_ID, VALID_STATE, INVALID_CODE))
+            .await;
+        assert!(matches!(
+            response.unwrap_err(),
+            FederationUseCaseError::IdentityProviderError(_)
+        ));
+    }
+
+    #[test]
+    fn test_truncate_name() {
+        assert_eq!(truncate_name(None), "");
+        assert_eq!(truncate_name(Some(" John ".to_string())), "John");
+        assert_eq!(truncate_name(Some("a".repeat(100))).len(), NAME_MAX_LENGTH);
+    }
+}
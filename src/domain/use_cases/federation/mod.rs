@@ -0,0 +1,134 @@
+//! Federation use cases (login with the OpenID Connect provider of an external link)
+
+pub mod complete_login;
+pub mod start_login;
+
+use crate::domain::entities::refresh_token::RefreshTokenError;
+use crate::domain::repositories::application::ApplicationRepository;
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::repositories::federation::FederationRepository;
+use crate::domain::repositories::refresh_token::RefreshTokenRepository;
+use crate::domain::services::identity_provider::{IdentityProviderError, IdentityProviderService};
+use crate::domain::use_cases::federation::complete_login::CompleteLoginUseCase;
+use crate::domain::use_cases::federation::start_login::StartLoginUseCase;
+use crate::domain::use_cases::user::UserUseCaseError;
+use crate::domain::value_objects::id::IdError;
+use crate::domain::value_objects::password::PasswordError;
+use thiserror::Error;
+
+#[derive(Debug, Clone)]
+pub struct FederationUseCases<
+    F: FederationRepository,
+    T: RefreshTokenRepository,
+    A: ApplicationRepository,
+    I: IdentityProviderService,
+    R: AuditRepository,
+> {
+    pub start_login: StartLoginUseCase<F, I>,
+    pub complete_login: CompleteLoginUseCase<F, T, A, I, R>,
+}
+
+impl<
+    F: FederationRepository,
+    T: RefreshTokenRepository,
+    A: ApplicationRepository,
+    I: IdentityProviderService,
+    R: AuditRepository,
+> FederationUseCases<F, T, A, I, R>
+{
+    /// Create a new federation use cases
+    pub fn new(
+        federation_repository: F,
+        refresh_token_repository: T,
+        application_repository: A,
+        identity_provider_service: I,
+        audit_repository: R,
+    ) -> Self {
+        Self {
+            start_login: StartLoginUseCase::new(federation_repository.clone(), identity_provider_service.clone()),
+            complete_login: CompleteLoginUseCase::new(
+                federation_repository,
+                refresh_token_repository,
+                application_repository,
+                identity_provider_service,
+                audit_repository,
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum FederationUseCaseError {
+    #[error("OpenID Connect provider not found for this external link and application")]
+    ProviderNotFound(),
+
+    #[error("Application not found")]
+    ApplicationNotFound(),
+
+    #[error("Invalid or expired login state")]
+    InvalidState(),
+
+    #[error("Identity provider error: {0}")]
+    IdentityProviderError(String),
+
+    #[error("A verified email is required to create or link a user")]
+    EmailRequired(),
+
+    #[error("User is disabled")]
+    UserDisabled(),
+
+    #[error("User is already linked to another external user")]
+    UserAlreadyLinked(),
+
+    #[error("Invalid user password: {0}")]
+    InvalidPassword(String),
+
+    #[error("Access token generation error")]
+    AccessTokenGenerationError(),
+
+    #[error("Refresh token creation error: {0}")]
+    RefreshTokenCreationError(String),
+
+    #[error("Invalid id")]
+    InvalidId(),
+
+    #[error("Model conversion error")]
+    FromModelError(),
+
+    #[error("{0}")]
+    DatabaseError(String),
+}
+
+impl From<IdError> for FederationUseCaseError {
+    fn from(_: IdError) -> Self {
+        FederationUseCaseError::InvalidId()
+    }
+}
+
+impl From<PasswordError> for FederationUseCaseError {
+    fn from(err: PasswordError) -> Self {
+        FederationUseCaseError::InvalidPassword(err.to_string())
+    }
+}
+
+impl From<RefreshTokenError> for FederationUseCaseError {
+    fn from(err: RefreshTokenError) -> Self {
+        FederationUseCaseError::RefreshTokenCreationError(err.to_string())
+    }
+}
+
+impl From<IdentityProviderError> for FederationUseCaseError {
+    fn from(err: IdentityProviderError) -> Self {
+        FederationUseCaseError::IdentityProviderError(err.to_string())
+    }
+}
+
+/// Errors of the refresh token repository
+impl From<UserUseCaseError> for FederationUseCaseError {
+    fn from(err: UserUseCaseError) -> Self {
+        match err {
+            UserUseCaseError::RefreshTokenCreationError(msg) => FederationUseCaseError::RefreshTokenCreationError(msg),
+            _ => FederationUseCaseError::DatabaseError(err.to_string()),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! Start login use case
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::external_link::ExternalLinkId;
+use crate::domain::entities::oidc_login_state::OidcLoginState;
+use crate::domain::repositories::federation::FederationRepository;
+use crate::domain::repositories::federation::dto::{CreateLoginStateDtoRequest, GetProviderDtoRequest};
+use crate::domain::services::identity_provider::{AuthorizationUrlRequest, IdentityProviderService};
+use crate::domain::use_cases::federation::FederationUseCaseError;
+
+#[derive(Debug, Clone)]
+pub struct StartLoginUseCaseRequest {
+    pub external_link_id: ExternalLinkId,
+
+    /// Application for which the tokens will be issued
+    pub application_id: ApplicationId,
+
+    /// URL called by the provider after the login
+    pub redirect_uri: String,
+
+    /// Login state lifetime (in minute)
+    pub expiration_duration: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StartLoginUseCaseResponse {
+    /// URL of the provider login page
+    pub authorization_url: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct StartLoginUseCase<F: FederationRepository, I: IdentityProviderService> {
+    federation_repository: F,
+    identity_provider_service: I,
+}
+
+impl<F: FederationRepository, I: IdentityProviderService> StartLoginUseCase<F, I> {
+    /// Create a new use case
+    pub fn new(federation_repository: F, identity_provider_service: I) -> Self {
+        Self {
+            federation_repository,
+            identity_provider_service,
+        }
+    }
+
+    /// Save a login state and build the provider login URL
+    #[instrument(skip(self), name = "start_login_use_case")]
+    pub async fn call(
+        &self,
+        request: StartLoginUseCaseRequest,
+    ) -> Result<StartLoginUseCaseResponse, FederationUseCaseError> {
+        let provider = self
+            .federation_repository
+            .get_provider(GetProviderDtoRequest {
+                external_link_id: request.external_link_id.clone(),
+                application_id: request.application_id.clone(),
+            })
+            .await?
+            .0
+            .ok_or_else(FederationUseCaseError::ProviderNotFound)?;
+
+        let login_state = OidcLoginState::new(
+            request.external_link_id,
+            request.application_id,
+            request.expiration_duration,
+        );
+        let authorization_url = self
+            .identity_provider_service
+            .authorization_url(AuthorizationUrlRequest {
+                provider,
+                redirect_uri: request.redirect_uri,
+                state: login_state.state.clone(),
+                nonce: login_state.nonce.clone(),
+            })
+            .await?;
+
+        self.federation_repository
+            .create_login_state(CreateLoginStateDtoRequest(login_state))
+            .await?;
+
+        Ok(StartLoginUseCaseResponse { authorization_url })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::federation::{
+        FederationRepositoryMock, IdentityProviderServiceMock, VALID_APPLICATION_ID, VALID_EXTERNAL_LINK_ID,
+    };
+    use crate::domain::value_objects::id::Id;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test_start_login_use_case() {
+        let use_case = StartLoginUseCase::new(FederationRepositoryMock {}, IdentityProviderServiceMock {});
+        let request = StartLoginUseCaseRequest {
+            external_link_id: Id::from_str(VALID_EXTERNAL_LINK_ID).unwrap(),
+            application_id: Id::from_str(VALID_APPLICATION_ID).unwrap(),
+            redirect_uri: "http://localhost/callback".to_string(),
+            expiration_duration: 10,
+        };
+
+        let response = use_case.call(request.clone()).await.unwrap();
+        assert!(
+            response
+                .authorization_url
+                .starts_with("https://idp.test.com/authorize?state=")
+        );
+
+        let response = use_case
+            .call(StartLoginUseCaseRequest {
+                application_id: Id::new().unwrap(),
+                ..request
+            })
+            .await;
+        assert_eq!(response, Err(FederationUseCaseError::ProviderNotFound()));
+    }
+}
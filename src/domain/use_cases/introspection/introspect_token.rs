@@ -0,0 +1,253 @@
+//! Introspect token use case
+
+use crate::domain::entities::access_token::AccessToken;
+use crate::domain::entities::client::ClientId;
+use crate::domain::entities::user::UserId;
+use crate::domain::repositories::introspection::IntrospectionRepository;
+use crate::domain::repositories::introspection::dto::{
+    GetClientCredentialsDtoRequest, GetUserScopesDtoRequest, IsAccessTokenActiveDtoRequest,
+};
+use crate::domain::services::security::jwt::Jwt;
+use crate::domain::use_cases::introspection::IntrospectionUseCaseError;
+use crate::domain::value_objects::client_secret::ClientSecret;
+use crate::domain::value_objects::id::Id;
+use crate::domain::value_objects::scope_id::ScopeId;
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub struct IntrospectTokenUseCaseRequest {
+    /// Client used by the resource server
+    pub client_id: ClientId,
+
+    /// Client secret
+    pub client_secret: ClientSecret,
+
+    /// Token to introspect
+    pub token: AccessToken,
+
+    /// JWT instance
+    pub jwt: Jwt,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntrospectTokenUseCaseResponse {
+    /// Token is invalid, expired, revoked or its user is disabled
+    Inactive,
+
+    /// Token is active
+    Active {
+        /// User ID
+        sub: UserId,
+
+        /// User scopes of the token application
+        scopes: Vec<ScopeId>,
+
+        /// Client for which the token was issued, or the authenticated client
+        /// if the token was issued for the whole application
+        client_id: String,
+
+        /// Expiration time
+        exp: i64,
+
+        /// Issued at
+        iat: i64,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct IntrospectTokenUseCase<I: IntrospectionRepository> {
+    introspection_repository: I,
+}
+
+impl<I: IntrospectionRepository> IntrospectTokenUseCase<I> {
+    /// Create a new use case
+    pub fn new(introspection_repository: I) -> Self {
+        Self {
+            introspection_repository,
+        }
+    }
+
+    /// Introspect a token
+    #[instrument(skip(self, request), name = "introspect_token_use_case")]
+    pub async fn call(
+        &self,
+        request: IntrospectTokenUseCaseRequest,
+    ) -> Result<IntrospectTokenUseCaseResponse, IntrospectionUseCaseError> {
+        // Authenticate the client
+        let client = self
+            .introspection_repository
+            .get_client_credentials(GetClientCredentialsDtoRequest(request.client_id))
+            .await?
+            .0
+            .ok_or(IntrospectionUseCaseError::InvalidClient())?;
+        if !request.client_secret.matches(&client.secrets) {
+            return Err(IntrospectionUseCaseError::InvalidClient());
+        }
+
+        // Check the token signature and expiration
+        let payload = match request.jwt.parse(&request.token) {
+            Ok(payload) => payload,
+            Err(_) => return Ok(IntrospectTokenUseCaseResponse::Inactive),
+        };
+
+        // A client can only introspect the tokens of its application
+        if payload.application_id != client.application_id.to_string() {
+            return Ok(IntrospectTokenUseCaseResponse::Inactive);
+        }
+
+        // Check revocation and user status
+        let user_id = match Id::from_str(&payload.sub) {
+            Ok(user_id) => user_id,
+            Err(_) => return Ok(IntrospectTokenUseCaseResponse::Inactive),
+        };
+        let active = self
+            .introspection_repository
+            .is_access_token_active(IsAccessTokenActiveDtoRequest {
+                user_id: user_id.clone(),
+                access_token: request.token.token,
+            })
+            .await?
+            .0;
+        if !active {
+            return Ok(IntrospectTokenUseCaseResponse::Inactive);
+        }
+
+        let scopes = self
+            .introspection_repository
+            .get_user_scopes(GetUserScopesDtoRequest {
+                user_id: user_id.clone(),
+                application_id: client.application_id,
+            })
+            .await?
+            .0;
+
+        Ok(IntrospectTokenUseCaseResponse::Active {
+            sub: user_id,
+            scopes,
+            client_id: Some(payload.client_id)
+                .filter(|client_id| !client_id.is_empty())
+                .unwrap_or_else(|| client.client_id.to_string()),
+            exp: payload.exp,
+            iat: payload.iat,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::services::security::payload::PayloadData;
+    use crate::domain::tests::mock::introspection::{
+        DISABLED_USER_ID, IntrospectionRepositoryMock, OTHER_APPLICATION_ID, VALID_APPLICATION_ID, VALID_CLIENT_ID,
+        VALID_CLIENT_SECRET, VALID_SCOPE_ID, VALID_USER_ID,
+    };
+    use crate::domain::value_objects::datetime::UtcDateTime;
+
+    fn jwt() -> Jwt {
+        Jwt::init("HS256", 15, 7, Some("secret"), None, None).unwrap()
+    }
+
+    fn token(user_id: &str, application_id: &str) -> AccessToken {
+        jwt()
+            .generate(PayloadData::new(
+                user_id.to_string(),
+                application_id.to_string(),
+                "".to_string(),
+            ))
+            .unwrap()
+    }
+
+    fn request(token: AccessToken) -> IntrospectTokenUseCaseRequest {
+        IntrospectTokenUseCaseRequest {
+            client_id: Id::from_str(VALID_CLIENT_ID).unwrap(),
+            client_secret: ClientSecret::new(VALID_CLIENT_SECRET),
+            token,
+            jwt: jwt(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_introspect_token_use_case() {
+        let use_case = IntrospectTokenUseCase::new(IntrospectionRepositoryMock {});
+
+        let response = use_case
+            .call(request(token(VALID_USER_ID, VALID_APPLICATION_ID)))
+            .await
+            .unwrap();
+        match response {
+            IntrospectTokenUseCaseResponse::Active {
+                sub,
+                scopes,
+                client_id,
+                exp,
+                iat,
+            } => {
+                assert_eq!(sub.to_string(), VALID_USER_ID);
+                assert_eq!(scopes, vec![ScopeId::new(VALID_SCOPE_ID).unwrap()]);
+                assert_eq!(client_id, VALID_CLIENT_ID);
+                assert_eq!(exp - iat, 15 * 60);
+            }
+            IntrospectTokenUseCaseResponse::Inactive => panic!("active token expected"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_introspect_token_use_case_client_claim() {
+        let use_case = IntrospectTokenUseCase::new(IntrospectionRepositoryMock {});
+        let token = jwt()
+            .generate(PayloadData::new(
+                VALID_USER_ID.to_string(),
+                VALID_APPLICATION_ID.to_string(),
+                "token_client".to_string(),
+            ))
+            .unwrap();
+
+        let response = use_case.call(request(token)).await.unwrap();
+        match response {
+            IntrospectTokenUseCaseResponse::Active { client_id, .. } => assert_eq!(client_id, "token_client"),
+            IntrospectTokenUseCaseResponse::Inactive => panic!("active token expected"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_introspect_token_use_case_invalid_client() {
+        let use_case = IntrospectTokenUseCase::new(IntrospectionRepositoryMock {});
+        let token = token(VALID_USER_ID, VALID_APPLICATION_ID);
+
+        let response = use_case
+            .call(IntrospectTokenUseCaseRequest {
+                client_secret: ClientSecret::new("invalid"),
+                ..request(token.clone())
+            })
+            .await;
+        assert_eq!(response, Err(IntrospectionUseCaseError::InvalidClient()));
+
+        let response = use_case
+            .call(IntrospectTokenUseCaseRequest {
+                client_id: Id::new().unwrap(),
+                ..request(token)
+            })
+            .await;
+        assert_eq!(response, Err(IntrospectionUseCaseError::InvalidClient()));
+    }
+
+    #[tokio::test]
+    async fn test_introspect_token_use_case_inactive() {
+        let use_case = IntrospectTokenUseCase::new(IntrospectionRepositoryMock {});
+
+        // Invalid token
+        let invalid = AccessToken::new("invalid".to_string(), UtcDateTime::now());
+        let response = use_case.call(request(invalid)).await;
+        assert_eq!(response, Ok(IntrospectTokenUseCaseResponse::Inactive));
+
+        // Token of another application
+        let response = use_case.call(request(token(VALID_USER_ID, OTHER_APPLICATION_ID))).await;
+        assert_eq!(response, Ok(IntrospectTokenUseCaseResponse::Inactive));
+
+        // Revoked token or disabled user
+        let response = use_case
+            .call(request(token(DISABLED_USER_ID, VALID_APPLICATION_ID)))
+            .await;
+        assert_eq!(response, Ok(IntrospectTokenUseCaseResponse::Inactive));
+    }
+}
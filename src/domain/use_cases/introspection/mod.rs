@@ -0,0 +1,43 @@
+//! Token introspection use cases (RFC 7662)
+
+pub mod introspect_token;
+
+use crate::domain::repositories::introspection::IntrospectionRepository;
+use crate::domain::use_cases::introspection::introspect_token::IntrospectTokenUseCase;
+use crate::domain::value_objects::id::IdError;
+use thiserror::Error;
+
+#[derive(Debug, Clone)]
+pub struct IntrospectionUseCases<I: IntrospectionRepository> {
+    pub introspect_token: IntrospectTokenUseCase<I>,
+}
+
+impl<I: IntrospectionRepository> IntrospectionUseCases<I> {
+    /// Create a new introspection use cases
+    pub fn new(introspection_repository: I) -> Self {
+        Self {
+            introspect_token: IntrospectTokenUseCase::new(introspection_repository),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum IntrospectionUseCaseError {
+    #[error("Invalid client credentials")]
+    InvalidClient(),
+
+    #[error("Invalid id")]
+    InvalidId(),
+
+    #[error("Model conversion error")]
+    FromModelError(),
+
+    #[error("{0}")]
+    DatabaseError(String),
+}
+
+impl From<IdError> for IntrospectionUseCaseError {
+    fn from(_: IdError) -> Self {
+        IntrospectionUseCaseError::InvalidId()
+    }
+}
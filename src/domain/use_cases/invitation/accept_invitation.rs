@@ -0,0 +1,91 @@
+//! Accept invitation use case
+
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+use crate::domain::entities::invitation::InvitationTokenValue;
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::repositories::invitation::InvitationRepository;
+use crate::domain::repositories::invitation::dto::AcceptInvitationDtoRequest;
+use crate::domain::use_cases::audit::record_audit_event;
+use crate::domain::use_cases::invitation::InvitationUseCaseError;
+use crate::domain::value_objects::password::Password;
+
+#[derive(Debug, Clone)]
+pub struct AcceptInvitationUseCaseRequest {
+    pub token: InvitationTokenValue,
+    pub password: Password,
+    pub audit: AuditContext,
+}
+
+#[derive(Debug, Clone)]
+pub struct AcceptInvitationUseCaseResponse();
+
+#[derive(Debug, Clone)]
+pub struct AcceptInvitationUseCase<I: InvitationRepository, R: AuditRepository> {
+    invitation_repository: I,
+    audit_repository: R,
+}
+
+impl<I: InvitationRepository, R: AuditRepository> AcceptInvitationUseCase<I, R> {
+    /// Create a new use case
+    pub fn new(invitation_repository: I, audit_repository: R) -> Self {
+        Self {
+            invitation_repository,
+            audit_repository,
+        }
+    }
+
+    /// Set the password of the invited user and delete the invitation
+    #[instrument(skip(self, request), name = "accept_invitation_use_case")]
+    pub async fn call(
+        &self,
+        request: AcceptInvitationUseCaseRequest,
+    ) -> Result<AcceptInvitationUseCaseResponse, InvitationUseCaseError> {
+        let audit = request.audit.clone();
+        let response = self
+            .invitation_repository
+            .accept(AcceptInvitationDtoRequest(request))
+            .await?;
+
+        record_audit_event(
+            &self.audit_repository,
+            &audit,
+            AuditAction::InvitationAccepted,
+            response.user_id.to_string(),
+        )
+        .await;
+
+        Ok(AcceptInvitationUseCaseResponse())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
+    use crate::domain::tests::mock::invitation::{INVALID_TOKEN, InvitationRepositoryMock, VALID_TOKEN};
+    use crate::domain::tests::mock::user::VALID_PASSWORD;
+
+    #[tokio::test]
+    async fn test_accept_invitation_use_case() {
+        let use_case = AcceptInvitationUseCase::new(InvitationRepositoryMock {}, AuditRepositoryMock {});
+        let password = Password::new(VALID_PASSWORD, false).unwrap();
+
+        let response = use_case
+            .call(AcceptInvitationUseCaseRequest {
+                token: VALID_TOKEN.to_string(),
+                password: password.clone(),
+                audit: AuditContext::default(),
+            })
+            .await;
+        assert!(response.is_ok());
+
+        let response = use_case
+            .call(AcceptInvitationUseCaseRequest {
+                token: INVALID_TOKEN.to_string(),
+                password,
+                audit: AuditContext::default(),
+            })
+            .await;
+        assert_eq!(response.unwrap_err(), InvitationUseCaseError::InvitationNotFound());
+    }
+}
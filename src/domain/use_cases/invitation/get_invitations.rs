@@ -0,0 +1,82 @@
+//! Get invitations use case
+
+use super::{InvitationUseCaseError, InvitationUseCaseResponse};
+use crate::domain::repositories::invitation::InvitationRepository;
+use crate::domain::repositories::invitation::dto::{
+    CountInvitationsDtoRequest, GetInvitationsDtoRequest, GetInvitationsDtoResponse,
+};
+use crate::domain::value_objects::pagination::{Pagination, PaginationCursor};
+use crate::domain::value_objects::query_sort::QuerySorts;
+
+#[derive(Debug, Clone)]
+pub struct GetInvitationsUseCaseRequest {
+    pub pagination: Pagination,
+    pub sorts: Option<QuerySorts>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetInvitationsUseCaseResponse {
+    pub invitations: Vec<InvitationUseCaseResponse>,
+    pub total: i64,
+    pub next_cursor: Option<PaginationCursor>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetInvitationsUseCase<I: InvitationRepository> {
+    invitation_repository: I,
+}
+
+impl<I: InvitationRepository> GetInvitationsUseCase<I> {
+    /// Create a new use case
+    pub fn new(invitation_repository: I) -> Self {
+        Self { invitation_repository }
+    }
+
+    /// Get pending invitations
+    #[instrument(skip(self), name = "get_invitations_use_case")]
+    pub async fn call(
+        &self,
+        request: GetInvitationsUseCaseRequest,
+    ) -> Result<GetInvitationsUseCaseResponse, InvitationUseCaseError> {
+        let total = self
+            .invitation_repository
+            .count_invitations(CountInvitationsDtoRequest())
+            .await?
+            .0;
+
+        let GetInvitationsDtoResponse {
+            invitations,
+            next_cursor,
+        } = self
+            .invitation_repository
+            .get_invitations(GetInvitationsDtoRequest(request))
+            .await?;
+
+        Ok(GetInvitationsUseCaseResponse {
+            invitations,
+            total,
+            next_cursor,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::invitation::InvitationRepositoryMock;
+
+    #[tokio::test]
+    async fn test_get_invitations_use_case() {
+        let use_case = GetInvitationsUseCase::new(InvitationRepositoryMock {});
+
+        let response = use_case
+            .call(GetInvitationsUseCaseRequest {
+                pagination: Pagination::default(),
+                sorts: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.total, 1);
+        assert_eq!(response.invitations.len(), 1);
+    }
+}
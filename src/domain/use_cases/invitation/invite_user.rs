@@ -0,0 +1,128 @@
+//! Invite user use case
+
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+use crate::domain::entities::invitation::Invitation;
+use crate::domain::entities::user::UserId;
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::repositories::invitation::InvitationRepository;
+use crate::domain::repositories::invitation::dto::CreateInvitationDtoRequest;
+use crate::domain::services::email::EmailService;
+use crate::domain::services::email::invitation::InvitationEmailRequest;
+use crate::domain::use_cases::audit::record_audit_event;
+use crate::domain::use_cases::invitation::{InvitationUseCaseError, InvitationUseCaseResponse};
+use crate::domain::value_objects::email::Email;
+use crate::domain::value_objects::locale::Locale;
+use crate::domain::value_objects::scope_id::ScopeId;
+
+#[derive(Debug, Clone)]
+pub struct InviteUserUseCaseRequest {
+    pub email: Email,
+    pub lastname: String,
+    pub firstname: String,
+    pub scopes: Vec<ScopeId>,
+
+    /// Language of the emails sent to the user
+    pub locale: Option<Locale>,
+
+    /// Invitation lifetime (in hour)
+    pub expiration_duration: i64,
+
+    pub audit: AuditContext,
+}
+
+#[derive(Debug, Clone)]
+pub struct InviteUserUseCase<I: InvitationRepository, E: EmailService, R: AuditRepository> {
+    invitation_repository: I,
+    email_service: E,
+    audit_repository: R,
+}
+
+impl<I: InvitationRepository, E: EmailService, R: AuditRepository> InviteUserUseCase<I, E, R> {
+    /// Create a new use case
+    pub fn new(invitation_repository: I, email_service: E, audit_repository: R) -> Self {
+        Self {
+            invitation_repository,
+            email_service,
+            audit_repository,
+        }
+    }
+
+    /// Create a pending user without password and queue an email with the invitation link
+    #[instrument(skip(self), name = "invite_user_use_case")]
+    pub async fn call(
+        &self,
+        request: InviteUserUseCaseRequest,
+    ) -> Result<InvitationUseCaseResponse, InvitationUseCaseError> {
+        let invitation = Invitation::new(UserId::new()?, request.expiration_duration);
+
+        // Build email with the token to the invited user
+        let email = self.email_service.invitation(InvitationEmailRequest {
+            email: request.email.clone(),
+            token: invitation.token.clone(),
+            locale: request.locale.clone(),
+        })?;
+
+        // The email is queued in the same transaction as the invitation
+        let response = self
+            .invitation_repository
+            .create(CreateInvitationDtoRequest {
+                email: request.email,
+                lastname: request.lastname,
+                firstname: request.firstname,
+                scopes: request.scopes,
+                locale: request.locale,
+                invitation,
+                message: email.0,
+            })
+            .await?
+            .0;
+
+        // The target is the invited user, as for the acceptance
+        record_audit_event(
+            &self.audit_repository,
+            &request.audit,
+            AuditAction::InvitationCreated,
+            response.user_id.to_string(),
+        )
+        .await;
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
+    use crate::domain::tests::mock::email::{EmailServiceMock, INVALID_EMAIL_TO};
+    use crate::domain::tests::mock::invitation::{INVALID_EMAIL, InvitationRepositoryMock, VALID_EMAIL};
+
+    fn request(email: &str) -> InviteUserUseCaseRequest {
+        InviteUserUseCaseRequest {
+            email: Email::new(email).unwrap(),
+            lastname: "Doe".to_string(),
+            firstname: "John".to_string(),
+            scopes: vec![ScopeId::new("users:read").unwrap()],
+            locale: None,
+            expiration_duration: 72,
+            audit: AuditContext::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invite_user_use_case() {
+        let use_case = InviteUserUseCase::new(InvitationRepositoryMock {}, EmailServiceMock {}, AuditRepositoryMock {});
+
+        let response = use_case.call(request(VALID_EMAIL)).await.unwrap();
+        assert_eq!(response.email, Email::new(VALID_EMAIL).unwrap());
+
+        let response = use_case.call(request(INVALID_EMAIL)).await;
+        assert_eq!(
+            response.unwrap_err(),
+            InvitationUseCaseError::DatabaseError("Invitation creation error".to_string())
+        );
+
+        let response = use_case.call(request(INVALID_EMAIL_TO)).await;
+        assert!(matches!(response, Err(InvitationUseCaseError::SendEmailError(_))));
+    }
+}
@@ -0,0 +1,102 @@
+//! Invitation use cases
+
+pub mod accept_invitation;
+pub mod get_invitations;
+pub mod invite_user;
+pub mod resend_invitation;
+pub mod revoke_invitation;
+
+use crate::domain::entities::invitation::InvitationId;
+use crate::domain::entities::user::UserId;
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::repositories::invitation::InvitationRepository;
+use crate::domain::services::email::{EmailService, EmailServiceError};
+use crate::domain::use_cases::invitation::accept_invitation::AcceptInvitationUseCase;
+use crate::domain::use_cases::invitation::get_invitations::GetInvitationsUseCase;
+use crate::domain::use_cases::invitation::invite_user::InviteUserUseCase;
+use crate::domain::use_cases::invitation::resend_invitation::ResendInvitationUseCase;
+use crate::domain::use_cases::invitation::revoke_invitation::RevokeInvitationUseCase;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::domain::value_objects::email::{Email, EmailError};
+use crate::domain::value_objects::id::IdError;
+use crate::domain::value_objects::locale::Locale;
+use thiserror::Error;
+
+#[derive(Debug, Clone)]
+pub struct InvitationUseCases<I: InvitationRepository, E: EmailService, R: AuditRepository> {
+    pub invite_user: InviteUserUseCase<I, E, R>,
+    pub get_invitations: GetInvitationsUseCase<I>,
+    pub resend_invitation: ResendInvitationUseCase<I, E>,
+    pub revoke_invitation: RevokeInvitationUseCase<I>,
+    pub accept_invitation: AcceptInvitationUseCase<I, R>,
+}
+
+impl<I: InvitationRepository, E: EmailService, R: AuditRepository> InvitationUseCases<I, E, R> {
+    /// Create a new invitation use cases
+    pub fn new(invitation_repository: I, email_service: E, audit_repository: R) -> Self {
+        Self {
+            invite_user: InviteUserUseCase::new(
+                invitation_repository.clone(),
+                email_service.clone(),
+                audit_repository.clone(),
+            ),
+            get_invitations: GetInvitationsUseCase::new(invitation_repository.clone()),
+            resend_invitation: ResendInvitationUseCase::new(invitation_repository.clone(), email_service),
+            revoke_invitation: RevokeInvitationUseCase::new(invitation_repository.clone()),
+            accept_invitation: AcceptInvitationUseCase::new(invitation_repository, audit_repository),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum InvitationUseCaseError {
+    #[error("Invitation not found or expired")]
+    InvitationNotFound(),
+
+    #[error("Invalid invitation id")]
+    InvalidId(),
+
+    #[error("Invalid user email: {0}")]
+    InvalidEmail(String),
+
+    #[error("Send email error: {0}")]
+    SendEmailError(String),
+
+    #[error("Model conversion error")]
+    FromModelError(),
+
+    #[error("{0}")]
+    DatabaseError(String),
+}
+
+impl From<IdError> for InvitationUseCaseError {
+    fn from(_: IdError) -> Self {
+        InvitationUseCaseError::InvalidId()
+    }
+}
+
+impl From<EmailError> for InvitationUseCaseError {
+    fn from(err: EmailError) -> Self {
+        InvitationUseCaseError::InvalidEmail(err.to_string())
+    }
+}
+
+impl From<EmailServiceError> for InvitationUseCaseError {
+    fn from(err: EmailServiceError) -> Self {
+        InvitationUseCaseError::SendEmailError(err.to_string())
+    }
+}
+
+/// Invitation use case generic response
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvitationUseCaseResponse {
+    pub id: InvitationId,
+    pub user_id: UserId,
+    pub email: Email,
+    pub lastname: String,
+    pub firstname: String,
+    pub locale: Option<Locale>,
+    pub expired_at: UtcDateTime,
+    pub created_at: UtcDateTime,
+    pub updated_at: UtcDateTime,
+}
@@ -0,0 +1,95 @@
+//! Resend invitation use case
+
+use crate::domain::entities::invitation::{Invitation, InvitationId};
+use crate::domain::repositories::invitation::InvitationRepository;
+use crate::domain::repositories::invitation::dto::{GetInvitationByIdDtoRequest, RenewInvitationDtoRequest};
+use crate::domain::services::email::EmailService;
+use crate::domain::services::email::invitation::InvitationEmailRequest;
+use crate::domain::use_cases::invitation::InvitationUseCaseError;
+
+#[derive(Debug, Clone)]
+pub struct ResendInvitationUseCaseRequest {
+    pub id: InvitationId,
+
+    /// Invitation lifetime (in hour)
+    pub expiration_duration: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ResendInvitationUseCaseResponse();
+
+#[derive(Debug, Clone)]
+pub struct ResendInvitationUseCase<I: InvitationRepository, E: EmailService> {
+    invitation_repository: I,
+    email_service: E,
+}
+
+impl<I: InvitationRepository, E: EmailService> ResendInvitationUseCase<I, E> {
+    /// Create a new use case
+    pub fn new(invitation_repository: I, email_service: E) -> Self {
+        Self {
+            invitation_repository,
+            email_service,
+        }
+    }
+
+    /// Generate a new token (the previous one is no longer valid) and queue a new email
+    #[instrument(skip(self), name = "resend_invitation_use_case")]
+    pub async fn call(
+        &self,
+        request: ResendInvitationUseCaseRequest,
+    ) -> Result<ResendInvitationUseCaseResponse, InvitationUseCaseError> {
+        let current = self
+            .invitation_repository
+            .get_by_id(GetInvitationByIdDtoRequest { id: request.id.clone() })
+            .await?
+            .0;
+
+        let invitation = Invitation::new(current.user_id, request.expiration_duration);
+        let email = self.email_service.invitation(InvitationEmailRequest {
+            email: current.email,
+            token: invitation.token.clone(),
+            locale: current.locale,
+        })?;
+
+        // The email is queued in the same transaction as the new token
+        self.invitation_repository
+            .renew(RenewInvitationDtoRequest {
+                id: request.id,
+                invitation,
+                message: email.0,
+            })
+            .await?;
+
+        Ok(ResendInvitationUseCaseResponse())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::email::EmailServiceMock;
+    use crate::domain::tests::mock::invitation::{INVITATION_NOT_FOUND_ID, InvitationRepositoryMock, VALID_ID};
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test_resend_invitation_use_case() {
+        let use_case = ResendInvitationUseCase::new(InvitationRepositoryMock {}, EmailServiceMock {});
+
+        let response = use_case
+            .call(ResendInvitationUseCaseRequest {
+                id: InvitationId::from_str(VALID_ID).unwrap(),
+                expiration_duration: 72,
+            })
+            .await;
+        assert!(response.is_ok());
+
+        let response = use_case
+            .call(ResendInvitationUseCaseRequest {
+                id: InvitationId::from_str(INVITATION_NOT_FOUND_ID).unwrap(),
+                expiration_duration: 72,
+            })
+            .await;
+        assert_eq!(response.unwrap_err(), InvitationUseCaseError::InvitationNotFound());
+    }
+}
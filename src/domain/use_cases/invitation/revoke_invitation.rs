@@ -0,0 +1,64 @@
+//! Revoke invitation use case
+
+use crate::domain::entities::invitation::InvitationId;
+use crate::domain::repositories::invitation::InvitationRepository;
+use crate::domain::repositories::invitation::dto::RevokeInvitationDtoRequest;
+use crate::domain::use_cases::invitation::InvitationUseCaseError;
+
+#[derive(Debug, Clone)]
+pub struct RevokeInvitationUseCaseRequest {
+    pub id: InvitationId,
+}
+
+#[derive(Debug, Clone)]
+pub struct RevokeInvitationUseCaseResponse();
+
+#[derive(Debug, Clone)]
+pub struct RevokeInvitationUseCase<I: InvitationRepository> {
+    invitation_repository: I,
+}
+
+impl<I: InvitationRepository> RevokeInvitationUseCase<I> {
+    /// Create a new use case
+    pub fn new(invitation_repository: I) -> Self {
+        Self { invitation_repository }
+    }
+
+    /// Revoke an invitation and remove the pending user
+    #[instrument(skip(self), name = "revoke_invitation_use_case")]
+    pub async fn call(
+        &self,
+        request: RevokeInvitationUseCaseRequest,
+    ) -> Result<RevokeInvitationUseCaseResponse, InvitationUseCaseError> {
+        self.invitation_repository
+            .revoke(RevokeInvitationDtoRequest(request))
+            .await
+            .map(|response| response.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::invitation::{INVITATION_NOT_FOUND_ID, InvitationRepositoryMock, VALID_ID};
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test_revoke_invitation_use_case() {
+        let use_case = RevokeInvitationUseCase::new(InvitationRepositoryMock {});
+
+        let response = use_case
+            .call(RevokeInvitationUseCaseRequest {
+                id: InvitationId::from_str(VALID_ID).unwrap(),
+            })
+            .await;
+        assert!(response.is_ok());
+
+        let response = use_case
+            .call(RevokeInvitationUseCaseRequest {
+                id: InvitationId::from_str(INVITATION_NOT_FOUND_ID).unwrap(),
+            })
+            .await;
+        assert_eq!(response.unwrap_err(), InvitationUseCaseError::InvitationNotFound());
+    }
+}
@@ -1,7 +1,14 @@
 //! Use cases represents the business logic of the application.
 
 pub mod application;
+pub mod audit;
 pub mod database;
+pub mod email;
 pub mod external_link;
+pub mod federation;
+pub mod introspection;
+pub mod invitation;
+pub mod retry;
 pub mod scope;
 pub mod user;
+pub mod webhook;
@@ -0,0 +1,24 @@
+//! Retries of the queued deliveries (emails and webhooks)
+
+/// Maximum delay between two attempts (in second)
+const MAX_BACKOFF: u64 = 86_400;
+
+/// Maximum length of the error message saved after a failed attempt
+pub const LAST_ERROR_MAX_LENGTH: usize = 255;
+
+/// Exponential backoff: delay (in second) before the next attempt
+///
+/// # Example
+/// ```rust
+/// use auth2_api::domain::use_cases::retry::backoff;
+///
+/// assert_eq!(backoff(30, 1), 30);
+/// assert_eq!(backoff(30, 2), 60);
+/// assert_eq!(backoff(30, 4), 240);
+/// assert_eq!(backoff(30, 50), 86_400);
+/// ```
+pub fn backoff(base: u64, attempts: u32) -> u64 {
+    let factor = 2u64.saturating_pow(attempts.saturating_sub(1));
+
+    base.saturating_mul(factor).min(MAX_BACKOFF)
+}
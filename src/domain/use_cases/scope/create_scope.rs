@@ -1,8 +1,11 @@
 //! Create scope use case
 
 use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+use crate::domain::repositories::audit::AuditRepository;
 use crate::domain::repositories::scope::ScopeRepository;
 use crate::domain::repositories::scope::dto::CreateScopeDtoRequest;
+use crate::domain::use_cases::audit::record_audit_event;
 use crate::domain::use_cases::scope::{ScopeUseCaseError, ScopeUseCaseResponse};
 use crate::domain::value_objects::scope_id::ScopeId;
 
@@ -10,24 +13,38 @@ use crate::domain::value_objects::scope_id::ScopeId;
 pub struct CreateScopeUseCaseRequest {
     pub id: ScopeId,
     pub application_id: ApplicationId,
+    pub audit: AuditContext,
 }
 
 #[derive(Debug, Clone)]
-pub struct CreateScopeUseCase<S: ScopeRepository> {
+pub struct CreateScopeUseCase<S: ScopeRepository, R: AuditRepository> {
     scope_repository: S,
+    audit_repository: R,
 }
 
-impl<S: ScopeRepository> CreateScopeUseCase<S> {
+impl<S: ScopeRepository, R: AuditRepository> CreateScopeUseCase<S, R> {
     /// Create a new use case
-    pub fn new(scope_repository: S) -> Self {
-        Self { scope_repository }
+    pub fn new(scope_repository: S, audit_repository: R) -> Self {
+        Self {
+            scope_repository,
+            audit_repository,
+        }
     }
 
     /// Create a new scope
     #[instrument(skip(self), name = "create_scope_use_case")]
     pub async fn call(&self, request: CreateScopeUseCaseRequest) -> Result<ScopeUseCaseResponse, ScopeUseCaseError> {
+        let audit = request.audit.clone();
         let scope = self.scope_repository.create(CreateScopeDtoRequest(request)).await?;
 
+        record_audit_event(
+            &self.audit_repository,
+            &audit,
+            AuditAction::ScopeCreated,
+            scope.0.id.to_string(),
+        )
+        .await;
+
         Ok(scope.0)
     }
 }
@@ -36,6 +53,7 @@ impl<S: ScopeRepository> CreateScopeUseCase<S> {
 mod tests {
     use super::*;
     use crate::domain::tests::mock::application::VALID_APPLICATION_ID;
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
     use crate::domain::tests::mock::scope::{INVALID_SCOPE_ID, ScopeRepositoryMock, VALID_SCOPE_ID};
     use crate::domain::value_objects::id::Id;
     use std::str::FromStr;
@@ -43,12 +61,13 @@ mod tests {
     #[tokio::test]
     async fn test_create_scope() {
         let repository = ScopeRepositoryMock {};
-        let create_scope = CreateScopeUseCase::new(repository);
+        let create_scope = CreateScopeUseCase::new(repository, AuditRepositoryMock {});
 
         let result = create_scope
             .call(CreateScopeUseCaseRequest {
                 id: ScopeId::new(VALID_SCOPE_ID).unwrap(),
                 application_id: Id::from_str(VALID_APPLICATION_ID).unwrap(),
+                audit: AuditContext::default(),
             })
             .await;
 
@@ -58,12 +77,13 @@ mod tests {
     #[tokio::test]
     async fn test_create_scope_database_error() {
         let repository = ScopeRepositoryMock {};
-        let create_scope = CreateScopeUseCase::new(repository);
+        let create_scope = CreateScopeUseCase::new(repository, AuditRepositoryMock {});
 
         let result = create_scope
             .call(CreateScopeUseCaseRequest {
                 id: ScopeId::new(INVALID_SCOPE_ID).unwrap(),
                 application_id: Id::from_str(VALID_APPLICATION_ID).unwrap(),
+                audit: AuditContext::default(),
             })
             .await;
 
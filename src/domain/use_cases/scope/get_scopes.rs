@@ -2,9 +2,10 @@
 
 use crate::domain::entities::application::ApplicationId;
 use crate::domain::repositories::scope::ScopeRepository;
-use crate::domain::repositories::scope::dto::{CountScopesDtoRequest, GetScopesDtoRequest};
+use crate::domain::repositories::scope::dto::{CountScopesDtoRequest, GetScopesDtoRequest, GetScopesDtoResponse};
 use crate::domain::use_cases::scope::{ScopeUseCaseError, ScopeUseCaseResponse};
-use crate::domain::value_objects::pagination::Pagination;
+use crate::domain::value_objects::pagination::{Pagination, PaginationCursor};
+use crate::domain::value_objects::query_filter::QueryFilters;
 use crate::domain::value_objects::query_sort::QuerySorts;
 use validator::Validate;
 
@@ -12,6 +13,7 @@ use validator::Validate;
 pub struct GetScopesUseCaseRequest {
     pub pagination: Pagination,
     pub sorts: Option<QuerySorts>,
+    pub filters: Option<QueryFilters>,
     pub deleted: bool,
     pub application_id: Option<ApplicationId>,
 }
@@ -20,6 +22,7 @@ pub struct GetScopesUseCaseRequest {
 pub struct GetScopesUseCaseResponse {
     pub scopes: Vec<ScopeUseCaseResponse>,
     pub total: i64,
+    pub next_cursor: Option<PaginationCursor>,
 }
 
 #[derive(Debug, Clone)]
@@ -40,13 +43,19 @@ impl<S: ScopeRepository> GetScopesUseCase<S> {
             .scope_repository
             .count_scopes(CountScopesDtoRequest {
                 deleted: request.deleted,
+                filters: request.filters.clone(),
                 application_id: request.application_id.clone().map(|id| id.to_string()),
             })
             .await?
             .0;
-        let scopes = self.scope_repository.get_scopes(GetScopesDtoRequest(request)).await?.0;
+        let GetScopesDtoResponse { scopes, next_cursor } =
+            self.scope_repository.get_scopes(GetScopesDtoRequest(request)).await?;
 
-        Ok(GetScopesUseCaseResponse { scopes, total })
+        Ok(GetScopesUseCaseResponse {
+            scopes,
+            total,
+            next_cursor,
+        })
     }
 }
 
@@ -6,6 +6,7 @@ pub mod get_scopes;
 pub mod restore_scope;
 
 use crate::domain::entities::application::ApplicationId;
+use crate::domain::repositories::audit::AuditRepository;
 use crate::domain::repositories::scope::ScopeRepository;
 use crate::domain::use_cases::scope::create_scope::CreateScopeUseCase;
 use crate::domain::use_cases::scope::delete_scope::DeleteScopeUseCase;
@@ -16,21 +17,21 @@ use crate::domain::value_objects::scope_id::ScopeId;
 use thiserror::Error;
 
 #[derive(Debug, Clone)]
-pub struct ScopeUseCases<S: ScopeRepository> {
-    pub create_scope: CreateScopeUseCase<S>,
+pub struct ScopeUseCases<S: ScopeRepository, R: AuditRepository> {
+    pub create_scope: CreateScopeUseCase<S, R>,
     pub get_scopes: GetScopesUseCase<S>,
-    pub delete_scope: DeleteScopeUseCase<S>,
-    pub restore_scope: RestoreScopeUseCase<S>,
+    pub delete_scope: DeleteScopeUseCase<S, R>,
+    pub restore_scope: RestoreScopeUseCase<S, R>,
 }
 
-impl<S: ScopeRepository> ScopeUseCases<S> {
+impl<S: ScopeRepository, R: AuditRepository> ScopeUseCases<S, R> {
     /// Create a new scope use cases
-    pub fn new(scope_repository: S) -> Self {
+    pub fn new(scope_repository: S, audit_repository: R) -> Self {
         Self {
-            create_scope: CreateScopeUseCase::new(scope_repository.clone()),
+            create_scope: CreateScopeUseCase::new(scope_repository.clone(), audit_repository.clone()),
             get_scopes: GetScopesUseCase::new(scope_repository.clone()),
-            delete_scope: DeleteScopeUseCase::new(scope_repository.clone()),
-            restore_scope: RestoreScopeUseCase::new(scope_repository.clone()),
+            delete_scope: DeleteScopeUseCase::new(scope_repository.clone(), audit_repository.clone()),
+            restore_scope: RestoreScopeUseCase::new(scope_repository.clone(), audit_repository),
         }
     }
 }
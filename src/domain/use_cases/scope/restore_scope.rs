@@ -1,27 +1,35 @@
 //! Restore deleted scope use case
 
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+use crate::domain::repositories::audit::AuditRepository;
 use crate::domain::repositories::scope::ScopeRepository;
 use crate::domain::repositories::scope::dto::RestoreScopeDtoRequest;
+use crate::domain::use_cases::audit::record_audit_event;
 use crate::domain::use_cases::scope::ScopeUseCaseError;
 use crate::domain::value_objects::scope_id::ScopeId;
 
 #[derive(Debug, Clone)]
 pub struct RestoreScopeUseCaseRequest {
     pub id: ScopeId,
+    pub audit: AuditContext,
 }
 
 #[derive(Debug, Clone)]
 pub struct RestoreScopeUseCaseResponse();
 
 #[derive(Debug, Clone)]
-pub struct RestoreScopeUseCase<A: ScopeRepository> {
+pub struct RestoreScopeUseCase<A: ScopeRepository, R: AuditRepository> {
     scope_repository: A,
+    audit_repository: R,
 }
 
-impl<A: ScopeRepository> RestoreScopeUseCase<A> {
+impl<A: ScopeRepository, R: AuditRepository> RestoreScopeUseCase<A, R> {
     /// Create a new use case
-    pub fn new(scope_repository: A) -> Self {
-        Self { scope_repository }
+    pub fn new(scope_repository: A, audit_repository: R) -> Self {
+        Self {
+            scope_repository,
+            audit_repository,
+        }
     }
 
     /// Restore an scope
@@ -30,8 +38,11 @@ impl<A: ScopeRepository> RestoreScopeUseCase<A> {
         &self,
         request: RestoreScopeUseCaseRequest,
     ) -> Result<RestoreScopeUseCaseResponse, ScopeUseCaseError> {
+        let (id, audit) = (request.id.to_string(), request.audit.clone());
         let scope = self.scope_repository.restore(RestoreScopeDtoRequest(request)).await?;
 
+        record_audit_event(&self.audit_repository, &audit, AuditAction::ScopeRestored, id).await;
+
         Ok(scope.0)
     }
 }
@@ -39,15 +50,17 @@ impl<A: ScopeRepository> RestoreScopeUseCase<A> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
     use crate::domain::tests::mock::scope::{INVALID_SCOPE_ID, ScopeRepositoryMock, VALID_SCOPE_ID};
 
     #[tokio::test]
     async fn test_restore_scope_use_case() {
         let scope_repository = ScopeRepositoryMock {};
-        let use_case = RestoreScopeUseCase::new(scope_repository);
+        let use_case = RestoreScopeUseCase::new(scope_repository, AuditRepositoryMock {});
 
         let request = RestoreScopeUseCaseRequest {
             id: ScopeId::new(VALID_SCOPE_ID).unwrap(),
+            audit: AuditContext::default(),
         };
 
         let response = use_case.call(request).await;
@@ -57,10 +70,11 @@ mod tests {
     #[tokio::test]
     async fn test_restore_scope_use_case_with_error() {
         let scope_repository = ScopeRepositoryMock {};
-        let use_case = RestoreScopeUseCase::new(scope_repository);
+        let use_case = RestoreScopeUseCase::new(scope_repository, AuditRepositoryMock {});
 
         let request = RestoreScopeUseCaseRequest {
             id: ScopeId::new(INVALID_SCOPE_ID).unwrap(),
+            audit: AuditContext::default(),
         };
 
         let response = use_case.call(request).await;
@@ -0,0 +1,269 @@
+//! Consume magic link use case
+
+use crate::domain::entities::access_token::AccessToken;
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+use crate::domain::entities::magic_link::MagicLinkTokenValue;
+use crate::domain::entities::password_reset::PasswordReset;
+use crate::domain::entities::refresh_token::RefreshToken;
+use crate::domain::repositories::application::ApplicationRepository;
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::repositories::magic_link::MagicLinkRepository;
+use crate::domain::repositories::magic_link::dto::ConsumeMagicLinkDtoRequest;
+use crate::domain::repositories::password_reset::PasswordResetRepository;
+use crate::domain::repositories::refresh_token::RefreshTokenRepository;
+use crate::domain::repositories::refresh_token::dto::CreateRefreshTokenDtoRequest;
+use crate::domain::repositories::user::UserRepository;
+use crate::domain::repositories::user::dto::GetPasswordChangedAtDtoRequest;
+use crate::domain::services::security::jwt::Jwt;
+use crate::domain::services::security::payload::PayloadData;
+use crate::domain::use_cases::audit::record_audit_event;
+use crate::domain::use_cases::user::{UserUseCaseError, check_password_expiration, get_token_application};
+
+#[derive(Debug, Clone)]
+pub struct ConsumeMagicLinkUseCaseRequest {
+    /// Magic link token
+    pub token: MagicLinkTokenValue,
+
+    /// Application for which the tokens are issued
+    pub application_id: ApplicationId,
+
+    /// JWT instance
+    pub jwt: Jwt,
+
+    /// Password lifetime (in day, 0 to disable expiration)
+    pub password_lifetime: i64,
+
+    /// Password reset token lifetime (in hour) used when the password has expired
+    pub password_reset_expiration_duration: i64,
+
+    /// Audit context of the login
+    pub audit: AuditContext,
+}
+
+#[derive(Debug, Clone)]
+pub enum ConsumeMagicLinkUseCaseResponse {
+    /// User is authenticated
+    Tokens {
+        /// Access token
+        access_token: AccessToken,
+
+        /// Refresh token
+        refresh_token: RefreshToken,
+    },
+
+    /// User password has expired and must be changed with the password reset token
+    PasswordExpired(PasswordReset),
+}
+
+#[derive(Debug, Clone)]
+pub struct ConsumeMagicLinkUseCase<
+    U: UserRepository,
+    M: MagicLinkRepository,
+    T: RefreshTokenRepository,
+    P: PasswordResetRepository,
+    A: ApplicationRepository,
+    R: AuditRepository,
+> {
+    user_repository: U,
+    magic_link_repository: M,
+    refresh_token_repository: T,
+    password_reset_repository: P,
+    application_repository: A,
+    audit_repository: R,
+}
+
+impl<
+    U: UserRepository,
+    M: MagicLinkRepository,
+    T: RefreshTokenRepository,
+    P: PasswordResetRepository,
+    A: ApplicationRepository,
+    R: AuditRepository,
+> ConsumeMagicLinkUseCase<U, M, T, P, A, R>
+{
+    /// Create a new use case
+    pub fn new(
+        user_repository: U,
+        magic_link_repository: M,
+        refresh_token_repository: T,
+        password_reset_repository: P,
+        application_repository: A,
+        audit_repository: R,
+    ) -> Self {
+        Self {
+            user_repository,
+            magic_link_repository,
+            refresh_token_repository,
+            password_reset_repository,
+            application_repository,
+            audit_repository,
+        }
+    }
+
+    /// Exchange a magic link token for an access token and a refresh token
+    #[instrument(skip(self), name = "consume_magic_link_use_case")]
+    pub async fn call(
+        &self,
+        request: ConsumeMagicLinkUseCaseRequest,
+    ) -> Result<ConsumeMagicLinkUseCaseResponse, UserUseCaseError> {
+        // Use the application token settings (checked before the token is invalidated)
+        let application = get_token_application(&self.application_repository, request.application_id).await?;
+        let jwt = request
+            .jwt
+            .for_application(application.access_token_lifetime, application.refresh_token_lifetime);
+
+        // Get user ID from token and invalidate it
+        let user_id = self
+            .magic_link_repository
+            .consume(ConsumeMagicLinkDtoRequest { token: request.token })
+            .await?
+            .user_id
+            .ok_or_else(UserUseCaseError::MagicLinkNotFound)?;
+
+        // Check password expiration
+        let password_changed_at = self
+            .user_repository
+            .get_password_changed_at(GetPasswordChangedAtDtoRequest(user_id.clone()))
+            .await?
+            .0;
+        if let Some(password_reset) = check_password_expiration(
+            &self.password_reset_repository,
+            &user_id,
+            password_changed_at.as_ref(),
+            request.password_lifetime,
+            request.password_reset_expiration_duration,
+        )
+        .await?
+        {
+            return Ok(ConsumeMagicLinkUseCaseResponse::PasswordExpired(password_reset));
+        }
+
+        // Generate access token
+        let payload = PayloadData::new(user_id.to_string(), application.id.to_string(), "".to_string())
+            .with_audience(application.audience);
+        let access_token = jwt.generate(payload).map_err(|err| {
+            error!(error = %err, "Error generating access token");
+            UserUseCaseError::AccessTokenGenerationError()
+        })?;
+
+        // Generate and save refresh token
+        let refresh_token = RefreshToken::create(user_id.clone(), access_token.clone(), jwt.refresh_lifetime())?;
+        self.refresh_token_repository
+            .create_refresh_token(CreateRefreshTokenDtoRequest {
+                refresh_token: refresh_token.clone(),
+                access_token: access_token.clone(),
+                user_id: user_id.clone(),
+                application_id: application.id.clone(),
+            })
+            .await?;
+
+        record_audit_event(
+            &self.audit_repository,
+            &request.audit,
+            AuditAction::LoginSucceeded,
+            user_id.to_string(),
+        )
+        .await;
+
+        Ok(ConsumeMagicLinkUseCaseResponse::Tokens {
+            access_token,
+            refresh_token,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::application::{
+        ApplicationRepositoryMock, INVALID_APPLICATION_ID, VALID_APPLICATION_ACCESS_TOKEN_LIFETIME,
+        VALID_APPLICATION_AUDIENCE, VALID_APPLICATION_ID,
+    };
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
+    use crate::domain::tests::mock::magic_link::{
+        EXPIRED_PASSWORD_TOKEN, INVALID_TOKEN, MagicLinkRepositoryMock, VALID_TOKEN,
+    };
+    use crate::domain::tests::mock::password_reset::PasswordResetRepositoryMock;
+    use crate::domain::tests::mock::refresh_token::RefreshTokenRepositoryMock;
+    use crate::domain::tests::mock::user::UserRepositoryMock;
+    use crate::domain::value_objects::id::Id;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test_consume_magic_link_use_case() {
+        let use_case = ConsumeMagicLinkUseCase::new(
+            UserRepositoryMock {},
+            MagicLinkRepositoryMock {},
+            RefreshTokenRepositoryMock {},
+            PasswordResetRepositoryMock {},
+            ApplicationRepositoryMock {},
+            AuditRepositoryMock {},
+        );
+        let jwt = Jwt::init("HS256", 1, 1, Some("secret"), None, None).unwrap();
+        let application_id = Id::from_str(VALID_APPLICATION_ID).unwrap();
+
+        let response = use_case
+            .call(ConsumeMagicLinkUseCaseRequest {
+                token: VALID_TOKEN.into(),
+                application_id: application_id.clone(),
+                jwt: jwt.clone(),
+                password_lifetime: 90,
+                password_reset_expiration_duration: 1,
+                audit: AuditContext::default(),
+            })
+            .await;
+        let Ok(ConsumeMagicLinkUseCaseResponse::Tokens { access_token, .. }) = response else {
+            panic!("Tokens expected");
+        };
+
+        // Application settings
+        let payload = jwt.parse(&access_token).unwrap();
+        assert_eq!(payload.application_id, VALID_APPLICATION_ID);
+        assert_eq!(payload.aud, Some(VALID_APPLICATION_AUDIENCE.to_string()));
+        assert_eq!(
+            payload.exp - payload.iat,
+            i64::from(VALID_APPLICATION_ACCESS_TOKEN_LIFETIME) * 60
+        );
+
+        let response = use_case
+            .call(ConsumeMagicLinkUseCaseRequest {
+                token: INVALID_TOKEN.into(),
+                application_id,
+                jwt: jwt.clone(),
+                password_lifetime: 90,
+                password_reset_expiration_duration: 1,
+                audit: AuditContext::default(),
+            })
+            .await;
+        assert_eq!(response.unwrap_err(), UserUseCaseError::MagicLinkNotFound());
+
+        let response = use_case
+            .call(ConsumeMagicLinkUseCaseRequest {
+                token: VALID_TOKEN.into(),
+                application_id: Id::from_str(INVALID_APPLICATION_ID).unwrap(),
+                jwt: jwt.clone(),
+                password_lifetime: 90,
+                password_reset_expiration_duration: 1,
+                audit: AuditContext::default(),
+            })
+            .await;
+        assert_eq!(response.unwrap_err(), UserUseCaseError::ApplicationNotFound());
+
+        // The password has expired: it must be changed before the tokens are issued
+        let response = use_case
+            .call(ConsumeMagicLinkUseCaseRequest {
+                token: EXPIRED_PASSWORD_TOKEN.into(),
+                application_id: Id::from_str(VALID_APPLICATION_ID).unwrap(),
+                jwt,
+                password_lifetime: 90,
+                password_reset_expiration_duration: 1,
+                audit: AuditContext::default(),
+            })
+            .await;
+        assert!(matches!(
+            response,
+            Ok(ConsumeMagicLinkUseCaseResponse::PasswordExpired(_))
+        ));
+    }
+}
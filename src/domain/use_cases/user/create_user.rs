@@ -1,10 +1,17 @@
 //! User creation use case
 
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+use crate::domain::entities::user::UserId;
+use crate::domain::entities::webhook::{WebhookEvent, WebhookMessage};
+use crate::domain::repositories::audit::AuditRepository;
 use crate::domain::repositories::user::UserRepository;
 use crate::domain::repositories::user::dto::CreateUserDtoRequest;
+use crate::domain::use_cases::audit::record_audit_event;
 use crate::domain::use_cases::user::{UserUseCaseError, UserUseCaseResponse};
 use crate::domain::value_objects::email::Email;
+use crate::domain::value_objects::locale::Locale;
 use crate::domain::value_objects::password::Password;
+use serde_json::json;
 
 #[derive(Debug, Clone)]
 pub struct CreateUserUseCaseRequest {
@@ -12,17 +19,23 @@ pub struct CreateUserUseCaseRequest {
     pub password: Password,
     pub lastname: String,
     pub firstname: String,
+    pub locale: Option<Locale>,
+    pub audit: AuditContext,
 }
 
 #[derive(Debug, Clone)]
-pub struct CreateUserUseCase<U: UserRepository> {
+pub struct CreateUserUseCase<U: UserRepository, R: AuditRepository> {
     user_repository: U,
+    audit_repository: R,
 }
 
-impl<U: UserRepository> CreateUserUseCase<U> {
+impl<U: UserRepository, R: AuditRepository> CreateUserUseCase<U, R> {
     /// Create a new use case
-    pub fn new(user_repository: U) -> Self {
-        Self { user_repository }
+    pub fn new(user_repository: U, audit_repository: R) -> Self {
+        Self {
+            user_repository,
+            audit_repository,
+        }
     }
 
     /// Create a new user
@@ -30,7 +43,33 @@ impl<U: UserRepository> CreateUserUseCase<U> {
     pub async fn call(&self, request: CreateUserUseCaseRequest) -> Result<UserUseCaseResponse, UserUseCaseError> {
         // TODO: Validation?
 
-        let user = self.user_repository.create_user(CreateUserDtoRequest(request)).await?;
+        let audit = request.audit.clone();
+        let user_id = UserId::new()?;
+        let message = WebhookMessage::new(
+            WebhookEvent::UserCreated,
+            json!({
+                "user_id": user_id.to_string(),
+                "email": request.email.value(),
+                "lastname": request.lastname,
+                "firstname": request.firstname,
+            }),
+        )?;
+        let user = self
+            .user_repository
+            .create_user(CreateUserDtoRequest {
+                id: user_id,
+                user: request,
+                message,
+            })
+            .await?;
+
+        record_audit_event(
+            &self.audit_repository,
+            &audit,
+            AuditAction::UserCreated,
+            user.0.id.to_string(),
+        )
+        .await;
 
         Ok(user.0)
     }
@@ -39,6 +78,7 @@ impl<U: UserRepository> CreateUserUseCase<U> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
     use crate::domain::tests::mock::user::{INVALID_EMAIL, UserRepositoryMock, VALID_EMAIL};
     use fake::Fake;
     use fake::faker::internet::fr_fr::Password;
@@ -46,14 +86,16 @@ mod tests {
     #[tokio::test]
     async fn test_create_user_use_case() {
         let user_repository = UserRepositoryMock {};
-        let use_case = CreateUserUseCase::new(user_repository);
+        let use_case = CreateUserUseCase::new(user_repository, AuditRepositoryMock {});
         let password: String = Password(16..25).fake();
 
         let request = CreateUserUseCaseRequest {
-            email: Email::new(&VALID_EMAIL).unwrap(),
+            email: Email::new(VALID_EMAIL).unwrap(),
             password: Password::new(&password, false).unwrap(),
             lastname: "Doe".to_string(),
             firstname: "John".to_string(),
+            locale: None,
+            audit: AuditContext::default(),
         };
 
         let response = use_case.call(request).await;
@@ -63,14 +105,16 @@ mod tests {
     #[tokio::test]
     async fn test_create_user_use_case_invalid_email() {
         let user_repository = UserRepositoryMock {};
-        let use_case = CreateUserUseCase::new(user_repository);
+        let use_case = CreateUserUseCase::new(user_repository, AuditRepositoryMock {});
         let password: String = Password(16..25).fake();
 
         let request = CreateUserUseCaseRequest {
-            email: Email::new(&INVALID_EMAIL).unwrap(),
+            email: Email::new(INVALID_EMAIL).unwrap(),
             password: Password::new(&password, false).unwrap(),
             lastname: "Doe".to_string(),
             firstname: "Jane".to_string(),
+            locale: None,
+            audit: AuditContext::default(),
         };
 
         let response = use_case.call(request).await;
@@ -1,27 +1,37 @@
 //! Delete a user from its ID
 
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
 use crate::domain::entities::user::UserId;
+use crate::domain::entities::webhook::{WebhookEvent, WebhookMessage};
+use crate::domain::repositories::audit::AuditRepository;
 use crate::domain::repositories::user::UserRepository;
 use crate::domain::repositories::user::dto::DeleteUserDtoRequest;
+use crate::domain::use_cases::audit::record_audit_event;
 use crate::domain::use_cases::user::UserUseCaseError;
+use serde_json::json;
 
 #[derive(Debug, Clone)]
 pub struct DeleteUserUseCaseRequest {
     pub user_id: UserId,
+    pub audit: AuditContext,
 }
 
 #[derive(Debug, Clone)]
 pub struct DeleteUserUseCaseResponse();
 
 #[derive(Debug, Clone)]
-pub struct DeleteUserUseCase<U: UserRepository> {
+pub struct DeleteUserUseCase<U: UserRepository, R: AuditRepository> {
     user_repository: U,
+    audit_repository: R,
 }
 
-impl<U: UserRepository> DeleteUserUseCase<U> {
+impl<U: UserRepository, R: AuditRepository> DeleteUserUseCase<U, R> {
     /// Create a new use case
-    pub fn new(user_repository: U) -> Self {
-        Self { user_repository }
+    pub fn new(user_repository: U, audit_repository: R) -> Self {
+        Self {
+            user_repository,
+            audit_repository,
+        }
     }
 
     /// Delete a user
@@ -29,7 +39,23 @@ impl<U: UserRepository> DeleteUserUseCase<U> {
     pub async fn call(&self, request: DeleteUserUseCaseRequest) -> Result<DeleteUserUseCaseResponse, UserUseCaseError> {
         // TODO: Validation?
 
-        let result = self.user_repository.delete_user(DeleteUserDtoRequest(request)).await?;
+        let user_id = request.user_id.to_string();
+        let message = WebhookMessage::new(WebhookEvent::UserDeleted, json!({ "user_id": user_id }))?;
+        let result = self
+            .user_repository
+            .delete_user(DeleteUserDtoRequest {
+                user_id: request.user_id,
+                message,
+            })
+            .await?;
+
+        record_audit_event(
+            &self.audit_repository,
+            &request.audit,
+            AuditAction::UserDeleted,
+            user_id,
+        )
+        .await;
 
         Ok(result.0)
     }
@@ -38,16 +64,18 @@ impl<U: UserRepository> DeleteUserUseCase<U> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
     use crate::domain::tests::mock::user::{INVALID_ID, UserRepositoryMock, VALID_ID};
     use std::str::FromStr;
 
     #[tokio::test]
     async fn test_delete_user_use_case() {
         let user_repository = UserRepositoryMock {};
-        let use_case = DeleteUserUseCase::new(user_repository);
+        let use_case = DeleteUserUseCase::new(user_repository, AuditRepositoryMock {});
 
         let request = DeleteUserUseCaseRequest {
             user_id: UserId::from_str(VALID_ID).unwrap(),
+            audit: AuditContext::default(),
         };
 
         let response = use_case.call(request).await;
@@ -57,10 +85,11 @@ mod tests {
     #[tokio::test]
     async fn test_delete_user_use_case_not_found() {
         let user_repository = UserRepositoryMock {};
-        let use_case = DeleteUserUseCase::new(user_repository);
+        let use_case = DeleteUserUseCase::new(user_repository, AuditRepositoryMock {});
 
         let request = DeleteUserUseCaseRequest {
             user_id: UserId::from_str(INVALID_ID).unwrap(),
+            audit: AuditContext::default(),
         };
 
         let response = use_case.call(request).await;
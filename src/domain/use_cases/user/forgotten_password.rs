@@ -36,7 +36,7 @@ impl<U: UserRepository, P: PasswordResetRepository, E: EmailService> ForgottenPa
         }
     }
 
-    /// Generate a token to generate a new password and send it to the user by email
+    /// Generate a token to generate a new password and queue an email to send it to the user
     #[instrument(skip(self), name = "forgotten_password_use_case")]
     pub async fn call(
         &self,
@@ -45,23 +45,28 @@ impl<U: UserRepository, P: PasswordResetRepository, E: EmailService> ForgottenPa
         // Get user by email
         let user = self
             .user_repository
-            .get_user_by_email(GetUserByEmailDtoRequest(request.clone()))
+            .get_user_by_email(GetUserByEmailDtoRequest(request.user_email.clone()))
             .await?;
 
-        // Create a new password reset entry
+        // Build email with the token to the user
         let password_reset = PasswordReset::new(user.0.id.clone(), request.expiration_duration);
-        self.password_reset_repository
-            .create_or_update(CreateUpdatePasswordResetDtoRequest(password_reset.clone()))
-            .await?;
-
-        // Send email with the token to the user
-        self.email_service
+        let email = self
+            .email_service
             .forgotten_password(ForgottenPasswordEmailRequest {
                 email: user.0.email.clone(),
                 token: password_reset.token.clone(),
+                locale: user.0.locale,
             })
             .map_err(|err| UserUseCaseError::SendEmailError(err.to_string()))?;
 
+        // Create a new password reset entry and queue the email in the same transaction
+        self.password_reset_repository
+            .create_or_update(CreateUpdatePasswordResetDtoRequest {
+                password_reset: password_reset.clone(),
+                message: Some(email.0),
+            })
+            .await?;
+
         Ok(ForgottenPasswordUseCaseResponse(password_reset))
     }
 }
@@ -1,51 +1,106 @@
 //! Get Access Token Use Case
 
 use crate::domain::entities::access_token::AccessToken;
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+use crate::domain::entities::password_reset::PasswordReset;
 use crate::domain::entities::refresh_token::RefreshToken;
+use crate::domain::entities::user::UserId;
+use crate::domain::repositories::application::ApplicationRepository;
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::repositories::password_reset::PasswordResetRepository;
 use crate::domain::repositories::refresh_token::RefreshTokenRepository;
 use crate::domain::repositories::refresh_token::dto::CreateRefreshTokenDtoRequest;
 use crate::domain::repositories::user::UserRepository;
-use crate::domain::repositories::user::dto::GetAccessTokenInformationDtoRequest;
+use crate::domain::repositories::user::dto::{GetAccessTokenInformationDtoRequest, RehashPasswordDtoRequest};
 use crate::domain::services::security::jwt::Jwt;
 use crate::domain::services::security::payload::PayloadData;
-use crate::domain::use_cases::user::UserUseCaseError;
+use crate::domain::use_cases::audit::record_audit_event;
+use crate::domain::use_cases::user::{UserUseCaseError, check_password_expiration, get_token_application};
 use crate::domain::value_objects::email::Email;
-use crate::domain::value_objects::password::Password;
+use crate::domain::value_objects::password::{Password, PlainPassword};
+use crate::domain::value_objects::password_policy::PasswordPolicy;
 
-// TODO: Add application_id and client_id later
+// TODO: Add client_id later
 #[derive(Debug, Clone)]
 pub struct GetAccessTokenUseCaseRequest {
     /// User email
     pub email: Email,
 
+    /// Application for which the tokens are issued
+    pub application_id: ApplicationId,
+
     /// User password
-    pub password: Password,
+    pub password: PlainPassword,
 
     /// JWT instance
     pub jwt: Jwt,
+
+    /// Password lifetime (in day, 0 to disable expiration)
+    pub password_lifetime: i64,
+
+    /// Password reset token lifetime (in hour) used when the password has expired
+    pub password_reset_expiration_duration: i64,
+
+    /// Password policy used to rehash the password if needed
+    pub password_policy: PasswordPolicy,
+
+    /// Audit context of the login attempt
+    pub audit: AuditContext,
 }
 
 #[derive(Debug, Clone)]
-pub struct GetAccessTokenUseCaseResponse {
-    /// Access token
-    pub access_token: AccessToken,
+pub enum GetAccessTokenUseCaseResponse {
+    /// User is authenticated
+    Tokens {
+        /// Access token
+        access_token: AccessToken,
 
-    /// Refresh token
-    pub refresh_token: RefreshToken,
+        /// Refresh token
+        refresh_token: RefreshToken,
+    },
+
+    /// User password has expired and must be changed with the password reset token
+    PasswordExpired(PasswordReset),
 }
 
 #[derive(Debug, Clone)]
-pub struct GetAccessTokenUseCase<U: UserRepository, T: RefreshTokenRepository> {
+pub struct GetAccessTokenUseCase<
+    U: UserRepository,
+    T: RefreshTokenRepository,
+    P: PasswordResetRepository,
+    A: ApplicationRepository,
+    R: AuditRepository,
+> {
     user_repository: U,
     refresh_token_repository: T,
+    password_reset_repository: P,
+    application_repository: A,
+    audit_repository: R,
 }
 
-impl<U: UserRepository, T: RefreshTokenRepository> GetAccessTokenUseCase<U, T> {
+impl<
+    U: UserRepository,
+    T: RefreshTokenRepository,
+    P: PasswordResetRepository,
+    A: ApplicationRepository,
+    R: AuditRepository,
+> GetAccessTokenUseCase<U, T, P, A, R>
+{
     /// Create a new use case
-    pub fn new(user_repository: U, refresh_token_repository: T) -> Self {
+    pub fn new(
+        user_repository: U,
+        refresh_token_repository: T,
+        password_reset_repository: P,
+        application_repository: A,
+        audit_repository: R,
+    ) -> Self {
         Self {
             user_repository,
             refresh_token_repository,
+            password_reset_repository,
+            application_repository,
+            audit_repository,
         }
     }
 
@@ -55,89 +110,269 @@ impl<U: UserRepository, T: RefreshTokenRepository> GetAccessTokenUseCase<U, T> {
         &self,
         request: GetAccessTokenUseCaseRequest,
     ) -> Result<GetAccessTokenUseCaseResponse, UserUseCaseError> {
-        // TODO: Validation?
-
-        let original_password = request.password.original().ok_or(UserUseCaseError::InvalidPassword(
-            "No hashed password not found".to_string(),
-        ))?;
-
         // Get user by email
+        let email = request.email.value();
         let resp = self
             .user_repository
             .get_access_token_information(GetAccessTokenInformationDtoRequest(request.email))
             .await?;
 
         // Check user password
-        let user_id = match resp {
-            Some(user) => {
-                if user.password.verify(&original_password).is_ok() {
-                    user.id
-                } else {
-                    Err(UserUseCaseError::IncorrectPassword())?
+        let user = match resp {
+            Some(user) if user.password.verify(request.password.value()).is_ok() => user,
+            _ => {
+                // Unknown email: verify a dummy hash, so that the response time does not reveal if the account exists
+                if resp.is_none() {
+                    let _ = Password::dummy(&request.password_policy)
+                        .and_then(|dummy| dummy.verify(request.password.value()));
                 }
+
+                record_audit_event(&self.audit_repository, &request.audit, AuditAction::LoginFailed, email).await;
+
+                // Same error for an unknown email and a wrong password
+                Err(UserUseCaseError::Unauthorized())?
             }
-            None => Err(UserUseCaseError::Unauthorized())?,
         };
+        let user_id = user.id;
+
+        // Upgrade the password hash if it has been generated with weaker parameters
+        if user.password.needs_rehash(&request.password_policy) {
+            self.rehash_password(&user_id, request.password.value(), &request.password_policy)
+                .await;
+        }
+
+        // Check password expiration
+        if let Some(password_reset) = check_password_expiration(
+            &self.password_reset_repository,
+            &user_id,
+            Some(&user.password_changed_at),
+            request.password_lifetime,
+            request.password_reset_expiration_duration,
+        )
+        .await?
+        {
+            return Ok(GetAccessTokenUseCaseResponse::PasswordExpired(password_reset));
+        }
+
+        // Use the application token settings
+        let application = get_token_application(&self.application_repository, request.application_id).await?;
+        let jwt = request
+            .jwt
+            .for_application(application.access_token_lifetime, application.refresh_token_lifetime);
 
         // Generate access token
-        let payload = PayloadData::new(user_id.to_string(), "".to_string(), "".to_string());
-        let access_token = request.jwt.generate(payload).map_err(|err| {
+        let payload = PayloadData::new(user_id.to_string(), application.id.to_string(), "".to_string())
+            .with_audience(application.audience);
+        let access_token = jwt.generate(payload).map_err(|err| {
             error!(error = %err, "Error generating access token");
             UserUseCaseError::AccessTokenGenerationError()
         })?;
 
         // Generate and save refresh token
-        let refresh_token =
-            RefreshToken::create(user_id.clone(), access_token.clone(), request.jwt.refresh_lifetime())?;
+        let refresh_token = RefreshToken::create(user_id.clone(), access_token.clone(), jwt.refresh_lifetime())?;
         self.refresh_token_repository
             .create_refresh_token(CreateRefreshTokenDtoRequest {
                 refresh_token: refresh_token.clone(),
                 access_token: access_token.clone(),
-                user_id,
+                user_id: user_id.clone(),
+                application_id: application.id.clone(),
             })
             .await?;
 
-        Ok(GetAccessTokenUseCaseResponse {
+        record_audit_event(
+            &self.audit_repository,
+            &request.audit,
+            AuditAction::LoginSucceeded,
+            user_id.to_string(),
+        )
+        .await;
+
+        Ok(GetAccessTokenUseCaseResponse::Tokens {
             access_token,
             refresh_token,
         })
     }
+
+    /// Rehash the user password with the policy parameters
+    ///
+    /// A failure is only logged because it must not prevent the user from logging in.
+    async fn rehash_password(&self, user_id: &UserId, original_password: &str, policy: &PasswordPolicy) {
+        let password = match Password::hash(original_password, policy) {
+            Ok(password) => password,
+            Err(err) => {
+                warn!(error = %err, "Failed to rehash user password");
+                return;
+            }
+        };
+
+        if let Err(err) = self
+            .user_repository
+            .rehash_password(RehashPasswordDtoRequest {
+                user_id: user_id.clone(),
+                password,
+            })
+            .await
+        {
+            warn!(error = %err, "Failed to save rehashed user password");
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::tests::mock::application::{
+        ApplicationRepositoryMock, INVALID_APPLICATION_ID, VALID_APPLICATION_ACCESS_TOKEN_LIFETIME,
+        VALID_APPLICATION_AUDIENCE, VALID_APPLICATION_ID,
+    };
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
+    use crate::domain::tests::mock::password_reset::PasswordResetRepositoryMock;
     use crate::domain::tests::mock::refresh_token::RefreshTokenRepositoryMock;
     use crate::domain::tests::mock::user::{
-        EMAIL_NOT_FOUND, INVALID_EMAIL, INVALID_PASSWORD, UserRepositoryMock, VALID_EMAIL, VALID_PASSWORD,
+        EMAIL_NOT_FOUND, EXPIRED_PASSWORD_EMAIL, INVALID_EMAIL, INVALID_PASSWORD, UserRepositoryMock, VALID_EMAIL,
+        VALID_PASSWORD,
     };
     use crate::domain::use_cases::user::UserUseCaseError;
+    use crate::domain::value_objects::id::Id;
+    use std::str::FromStr;
 
     #[tokio::test]
     async fn test_get_access_token_use_case() {
         let user_repository = UserRepositoryMock {};
         let refresh_token_repository = RefreshTokenRepositoryMock {};
-        let use_case = GetAccessTokenUseCase::new(user_repository, refresh_token_repository);
-        let password = Password::new(VALID_PASSWORD, false).unwrap();
+        let password_reset_repository = PasswordResetRepositoryMock {};
+        let use_case = GetAccessTokenUseCase::new(
+            user_repository,
+            refresh_token_repository,
+            password_reset_repository,
+            ApplicationRepositoryMock {},
+            AuditRepositoryMock {},
+        );
+        let password = PlainPassword::new(VALID_PASSWORD).unwrap();
         let email = Email::new(VALID_EMAIL).unwrap();
         let jwt = Jwt::init("HS256", 1, 1, Some("secret"), None, None).unwrap();
 
-        let request = GetAccessTokenUseCaseRequest { email, password, jwt };
+        let request = GetAccessTokenUseCaseRequest {
+            email,
+            application_id: Id::from_str(VALID_APPLICATION_ID).unwrap(),
+            password,
+            jwt: jwt.clone(),
+            password_lifetime: 90,
+            password_reset_expiration_duration: 1,
+            password_policy: PasswordPolicy::default(),
+            audit: AuditContext::default(),
+        };
+
+        let response = use_case.call(request).await;
+        let Ok(GetAccessTokenUseCaseResponse::Tokens { access_token, .. }) = response else {
+            panic!("tokens expected");
+        };
+
+        // Application settings
+        let payload = jwt.parse(&access_token).unwrap();
+        assert_eq!(payload.application_id, VALID_APPLICATION_ID);
+        assert_eq!(payload.aud, Some(VALID_APPLICATION_AUDIENCE.to_string()));
+        assert_eq!(
+            payload.exp - payload.iat,
+            i64::from(VALID_APPLICATION_ACCESS_TOKEN_LIFETIME) * 60
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_access_token_use_case_application_not_found() {
+        let use_case = GetAccessTokenUseCase::new(
+            UserRepositoryMock {},
+            RefreshTokenRepositoryMock {},
+            PasswordResetRepositoryMock {},
+            ApplicationRepositoryMock {},
+            AuditRepositoryMock {},
+        );
+        let jwt = Jwt::init("HS256", 1, 1, Some("secret"), None, None).unwrap();
+
+        let request = GetAccessTokenUseCaseRequest {
+            email: Email::new(VALID_EMAIL).unwrap(),
+            application_id: Id::from_str(INVALID_APPLICATION_ID).unwrap(),
+            password: PlainPassword::new(VALID_PASSWORD).unwrap(),
+            jwt,
+            password_lifetime: 90,
+            password_reset_expiration_duration: 1,
+            password_policy: PasswordPolicy::default(),
+            audit: AuditContext::default(),
+        };
 
         let response = use_case.call(request).await;
-        assert!(response.is_ok());
+        assert!(matches!(response, Err(UserUseCaseError::ApplicationNotFound())));
+    }
+
+    #[tokio::test]
+    async fn test_get_access_token_use_case_password_expired() {
+        let user_repository = UserRepositoryMock {};
+        let refresh_token_repository = RefreshTokenRepositoryMock {};
+        let password_reset_repository = PasswordResetRepositoryMock {};
+        let use_case = GetAccessTokenUseCase::new(
+            user_repository,
+            refresh_token_repository,
+            password_reset_repository,
+            ApplicationRepositoryMock {},
+            AuditRepositoryMock {},
+        );
+        let password = PlainPassword::new(VALID_PASSWORD).unwrap();
+        let email = Email::new(EXPIRED_PASSWORD_EMAIL).unwrap();
+        let jwt = Jwt::init("HS256", 1, 1, Some("secret"), None, None).unwrap();
+
+        let request = GetAccessTokenUseCaseRequest {
+            email,
+            application_id: Id::from_str(VALID_APPLICATION_ID).unwrap(),
+            password,
+            jwt,
+            password_lifetime: 90,
+            password_reset_expiration_duration: 1,
+            password_policy: PasswordPolicy::default(),
+            audit: AuditContext::default(),
+        };
+
+        let response = use_case.call(request.clone()).await;
+        assert!(matches!(
+            response,
+            Ok(GetAccessTokenUseCaseResponse::PasswordExpired(_))
+        ));
+
+        // Expiration disabled
+        let response = use_case
+            .call(GetAccessTokenUseCaseRequest {
+                password_lifetime: 0,
+                ..request
+            })
+            .await;
+        assert!(matches!(response, Ok(GetAccessTokenUseCaseResponse::Tokens { .. })));
     }
 
     #[tokio::test]
     async fn test_get_access_token_use_case_invalid_email() {
         let user_repository = UserRepositoryMock {};
         let refresh_token_repository = RefreshTokenRepositoryMock {};
-        let use_case = GetAccessTokenUseCase::new(user_repository, refresh_token_repository);
-        let password = Password::new(VALID_PASSWORD, false).unwrap();
+        let password_reset_repository = PasswordResetRepositoryMock {};
+        let use_case = GetAccessTokenUseCase::new(
+            user_repository,
+            refresh_token_repository,
+            password_reset_repository,
+            ApplicationRepositoryMock {},
+            AuditRepositoryMock {},
+        );
+        let password = PlainPassword::new(VALID_PASSWORD).unwrap();
         let email = Email::new(INVALID_EMAIL).unwrap();
         let jwt = Jwt::init("HS256", 1, 1, Some("secret"), None, None).unwrap();
 
-        let request = GetAccessTokenUseCaseRequest { email, password, jwt };
+        let request = GetAccessTokenUseCaseRequest {
+            email,
+            application_id: Id::from_str(VALID_APPLICATION_ID).unwrap(),
+            password,
+            jwt,
+            password_lifetime: 90,
+            password_reset_expiration_duration: 1,
+            password_policy: PasswordPolicy::default(),
+            audit: AuditContext::default(),
+        };
 
         let response = use_case.call(request).await;
         assert!(response.is_err());
@@ -150,17 +385,33 @@ mod tests {
     async fn test_get_access_token_use_case_incorrect_password() {
         let user_repository = UserRepositoryMock {};
         let refresh_token_repository = RefreshTokenRepositoryMock {};
-        let use_case = GetAccessTokenUseCase::new(user_repository, refresh_token_repository);
-        let password = Password::new(INVALID_PASSWORD, false).unwrap();
+        let password_reset_repository = PasswordResetRepositoryMock {};
+        let use_case = GetAccessTokenUseCase::new(
+            user_repository,
+            refresh_token_repository,
+            password_reset_repository,
+            ApplicationRepositoryMock {},
+            AuditRepositoryMock {},
+        );
+        let password = PlainPassword::new(INVALID_PASSWORD).unwrap();
         let email = Email::new(VALID_EMAIL).unwrap();
         let jwt = Jwt::init("HS256", 1, 1, Some("secret"), None, None).unwrap();
 
-        let request = GetAccessTokenUseCaseRequest { email, password, jwt };
+        let request = GetAccessTokenUseCaseRequest {
+            email,
+            application_id: Id::from_str(VALID_APPLICATION_ID).unwrap(),
+            password,
+            jwt,
+            password_lifetime: 90,
+            password_reset_expiration_duration: 1,
+            password_policy: PasswordPolicy::default(),
+            audit: AuditContext::default(),
+        };
 
         let response = use_case.call(request).await;
         assert!(response.is_err());
         if let Err(e) = response {
-            assert_eq!(e, UserUseCaseError::IncorrectPassword());
+            assert_eq!(e, UserUseCaseError::Unauthorized());
         }
     }
 
@@ -168,12 +419,28 @@ mod tests {
     async fn test_get_access_token_use_case_user_not_found() {
         let user_repository = UserRepositoryMock {};
         let refresh_token_repository = RefreshTokenRepositoryMock {};
-        let use_case = GetAccessTokenUseCase::new(user_repository, refresh_token_repository);
-        let password = Password::new(VALID_PASSWORD, false).unwrap();
+        let password_reset_repository = PasswordResetRepositoryMock {};
+        let use_case = GetAccessTokenUseCase::new(
+            user_repository,
+            refresh_token_repository,
+            password_reset_repository,
+            ApplicationRepositoryMock {},
+            AuditRepositoryMock {},
+        );
+        let password = PlainPassword::new(VALID_PASSWORD).unwrap();
         let email = Email::new(EMAIL_NOT_FOUND).unwrap();
         let jwt = Jwt::init("HS256", 1, 1, Some("secret"), None, None).unwrap();
 
-        let request = GetAccessTokenUseCaseRequest { email, password, jwt };
+        let request = GetAccessTokenUseCaseRequest {
+            email,
+            application_id: Id::from_str(VALID_APPLICATION_ID).unwrap(),
+            password,
+            jwt,
+            password_lifetime: 90,
+            password_reset_expiration_duration: 1,
+            password_policy: PasswordPolicy::default(),
+            audit: AuditContext::default(),
+        };
 
         let response = use_case.call(request).await;
         assert!(response.is_err());
@@ -181,4 +448,38 @@ mod tests {
             assert_eq!(e, UserUseCaseError::Unauthorized());
         }
     }
+
+    #[tokio::test]
+    async fn test_get_access_token_use_case_with_stronger_hash_parameters() {
+        let user_repository = UserRepositoryMock {};
+        let refresh_token_repository = RefreshTokenRepositoryMock {};
+        let password_reset_repository = PasswordResetRepositoryMock {};
+        let use_case = GetAccessTokenUseCase::new(
+            user_repository,
+            refresh_token_repository,
+            password_reset_repository,
+            ApplicationRepositoryMock {},
+            AuditRepositoryMock {},
+        );
+        let password = PlainPassword::new(VALID_PASSWORD).unwrap();
+        let email = Email::new(VALID_EMAIL).unwrap();
+        let jwt = Jwt::init("HS256", 1, 1, Some("secret"), None, None).unwrap();
+
+        let request = GetAccessTokenUseCaseRequest {
+            email,
+            application_id: Id::from_str(VALID_APPLICATION_ID).unwrap(),
+            password,
+            jwt,
+            password_lifetime: 0,
+            password_reset_expiration_duration: 1,
+            password_policy: PasswordPolicy {
+                argon2_memory_cost: 1_024,
+                ..Default::default()
+            },
+            audit: AuditContext::default(),
+        };
+
+        let response = use_case.call(request).await;
+        assert!(matches!(response, Ok(GetAccessTokenUseCaseResponse::Tokens { .. })));
+    }
 }
@@ -1,16 +1,22 @@
 //! Get all users with pagination use case
 
 use crate::domain::repositories::user::UserRepository;
-use crate::domain::repositories::user::dto::{CountUsersDtoRequest, GetUsersDtoRequest};
+use crate::domain::repositories::user::dto::{
+    CountUsersDtoRequest, GetUsersDtoRequest, GetUsersDtoResponse, SearchUsersDtoRequest,
+};
 use crate::domain::use_cases::user::{UserUseCaseError, UserUseCaseResponse};
-use crate::domain::value_objects::pagination::Pagination;
+use crate::domain::value_objects::pagination::{Pagination, PaginationCursor};
+use crate::domain::value_objects::query_filter::QueryFilters;
 use crate::domain::value_objects::query_sort::QuerySorts;
 
 #[derive(Debug, Clone)]
 pub struct GetUsersUseCaseRequest {
     pub pagination: Pagination,
     pub sorts: Option<QuerySorts>,
-    // pub filters: Option<Filters>,
+    pub filters: Option<QueryFilters>,
+
+    /// Full-text search on email, firstname and lastname (results are ranked by relevance)
+    pub search: Option<String>,
     pub deleted: bool,
 }
 
@@ -18,6 +24,7 @@ pub struct GetUsersUseCaseRequest {
 pub struct GetUsersUseCaseResponse {
     pub users: Vec<UserUseCaseResponse>,
     pub total: i64,
+    pub next_cursor: Option<PaginationCursor>,
 }
 
 #[derive(Debug, Clone)]
@@ -36,16 +43,52 @@ impl<U: UserRepository> GetUsersUseCase<U> {
     pub async fn call(&self, request: GetUsersUseCaseRequest) -> Result<GetUsersUseCaseResponse, UserUseCaseError> {
         // TODO: Validation?
 
+        if let Some(search) = request
+            .search
+            .as_deref()
+            .map(str::trim)
+            .filter(|search| !search.is_empty())
+        {
+            // Search results are ranked by relevance, which cannot be used as a cursor
+            if request.pagination.is_cursor() {
+                return Err(UserUseCaseError::InvalidArguments(
+                    "cursor pagination is not available with search".to_string(),
+                ));
+            }
+
+            let response = self
+                .user_repository
+                .search_users(SearchUsersDtoRequest {
+                    search: search.to_string(),
+                    pagination: request.pagination,
+                    filters: request.filters,
+                    deleted: request.deleted,
+                })
+                .await?;
+
+            return Ok(GetUsersUseCaseResponse {
+                users: response.users,
+                total: response.total,
+                next_cursor: None,
+            });
+        }
+
         let total = self
             .user_repository
             .count_users(CountUsersDtoRequest {
                 deleted: request.deleted,
+                filters: request.filters.clone(),
             })
             .await?
             .0;
-        let users = self.user_repository.get_users(GetUsersDtoRequest(request)).await?.0;
+        let GetUsersDtoResponse { users, next_cursor } =
+            self.user_repository.get_users(GetUsersDtoRequest(request)).await?;
 
-        Ok(GetUsersUseCaseResponse { users, total })
+        Ok(GetUsersUseCaseResponse {
+            users,
+            total,
+            next_cursor,
+        })
     }
 }
 
@@ -64,10 +107,49 @@ mod tests {
         let request = GetUsersUseCaseRequest {
             pagination: Pagination::new(1, 10),
             sorts: Some(QuerySorts::default()),
+            filters: None,
+            search: None,
             deleted: false,
         };
 
         let response = use_case.call(request).await;
         assert!(response.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_get_users_use_case_with_search() {
+        let user_repository = UserRepositoryMock {};
+        let use_case = GetUsersUseCase::new(user_repository);
+
+        let request = GetUsersUseCaseRequest {
+            pagination: Pagination::new(1, 10),
+            sorts: None,
+            filters: None,
+            search: Some(" john ".to_string()),
+            deleted: false,
+        };
+
+        let response = use_case.call(request.clone()).await.unwrap();
+        assert_eq!(response.total, 1);
+        assert_eq!(response.users[0].lastname, "Doe");
+
+        // Empty search lists all users
+        let response = use_case
+            .call(GetUsersUseCaseRequest {
+                search: Some("  ".to_string()),
+                ..request.clone()
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.total, 0);
+
+        // Search results cannot be paginated with a cursor
+        let response = use_case
+            .call(GetUsersUseCaseRequest {
+                pagination: Pagination::new_cursor(None, 10),
+                ..request
+            })
+            .await;
+        assert!(matches!(response, Err(UserUseCaseError::InvalidArguments(_))));
+    }
 }
@@ -1,5 +1,6 @@
 //! List of user use cases
 
+pub mod consume_magic_link;
 pub mod create_user;
 pub mod delete_user;
 pub mod forgotten_password;
@@ -8,14 +9,26 @@ pub mod get_user;
 pub mod get_users;
 pub mod refresh_token;
 pub mod restore_user;
+pub mod revoke_sessions;
+pub mod send_magic_link;
 pub mod update_password_from_token;
 
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::password_reset::PasswordReset;
 use crate::domain::entities::refresh_token::RefreshTokenError;
 use crate::domain::entities::user::UserId;
+use crate::domain::repositories::application::ApplicationRepository;
+use crate::domain::repositories::application::dto::GetApplicationByIdDtoRequest;
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::repositories::magic_link::MagicLinkRepository;
 use crate::domain::repositories::password_reset::PasswordResetRepository;
+use crate::domain::repositories::password_reset::dto::CreateUpdatePasswordResetDtoRequest;
 use crate::domain::repositories::refresh_token::RefreshTokenRepository;
 use crate::domain::repositories::user::UserRepository;
 use crate::domain::services::email::{EmailService, EmailServiceError};
+use crate::domain::use_cases::application::get_application::GetApplicationByIdUseCaseRequest;
+use crate::domain::use_cases::application::{ApplicationUseCaseError, ApplicationUseCaseResponse};
+use crate::domain::use_cases::user::consume_magic_link::ConsumeMagicLinkUseCase;
 use crate::domain::use_cases::user::delete_user::DeleteUserUseCase;
 use crate::domain::use_cases::user::forgotten_password::ForgottenPasswordUseCase;
 use crate::domain::use_cases::user::get_access_token::GetAccessTokenUseCase;
@@ -23,54 +36,109 @@ use crate::domain::use_cases::user::get_user::GetUserUseCase;
 use crate::domain::use_cases::user::get_users::GetUsersUseCase;
 use crate::domain::use_cases::user::refresh_token::RefreshTokenUseCase;
 use crate::domain::use_cases::user::restore_user::RestoreUserUseCase;
+use crate::domain::use_cases::user::revoke_sessions::RevokeSessionsUseCase;
+use crate::domain::use_cases::user::send_magic_link::SendMagicLinkUseCase;
 use crate::domain::use_cases::user::update_password_from_token::UpdatePasswordFromTokenUseCase;
 use crate::domain::value_objects::datetime::{UtcDateTime, UtcDateTimeError};
 use crate::domain::value_objects::email::{Email, EmailError};
 use crate::domain::value_objects::id::IdError;
+use crate::domain::value_objects::locale::Locale;
 use crate::domain::value_objects::password::PasswordError;
+use chrono::{Duration, Utc};
 use create_user::CreateUserUseCase;
 use thiserror::Error;
 
 #[derive(Debug, Clone)]
-pub struct UserUseCases<U: UserRepository, T: RefreshTokenRepository, P: PasswordResetRepository, E: EmailService> {
-    pub create_user: CreateUserUseCase<U>,
-    pub get_access_token: GetAccessTokenUseCase<U, T>,
+pub struct UserUseCases<
+    U: UserRepository,
+    T: RefreshTokenRepository,
+    P: PasswordResetRepository,
+    M: MagicLinkRepository,
+    A: ApplicationRepository,
+    E: EmailService,
+    R: AuditRepository,
+> {
+    pub create_user: CreateUserUseCase<U, R>,
+    pub get_access_token: GetAccessTokenUseCase<U, T, P, A, R>,
     pub get_users: GetUsersUseCase<U>,
     pub get_user: GetUserUseCase<U>,
-    pub delete_user: DeleteUserUseCase<U>,
-    pub refresh_token: RefreshTokenUseCase<T>,
+    pub delete_user: DeleteUserUseCase<U, R>,
+    pub refresh_token: RefreshTokenUseCase<U, T, P, A>,
     pub forgotten_password: ForgottenPasswordUseCase<U, P, E>,
     pub update_password_from_token: UpdatePasswordFromTokenUseCase<U, P>,
-    pub restore_user: RestoreUserUseCase<U>,
+    pub restore_user: RestoreUserUseCase<U, R>,
+    pub revoke_sessions: RevokeSessionsUseCase<U, T, R>,
+    pub send_magic_link: SendMagicLinkUseCase<U, M, E>,
+    pub consume_magic_link: ConsumeMagicLinkUseCase<U, M, T, P, A, R>,
 }
 
-impl<U: UserRepository, T: RefreshTokenRepository, P: PasswordResetRepository, E: EmailService>
-    UserUseCases<U, T, P, E>
+impl<
+    U: UserRepository,
+    T: RefreshTokenRepository,
+    P: PasswordResetRepository,
+    M: MagicLinkRepository,
+    A: ApplicationRepository,
+    E: EmailService,
+    R: AuditRepository,
+> UserUseCases<U, T, P, M, A, E, R>
 {
     /// Create a new user use cases
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         user_repository: U,
         refresh_token_repository: T,
         password_reset_repository: P,
+        magic_link_repository: M,
+        application_repository: A,
         email_service: E,
+        audit_repository: R,
     ) -> Self {
         Self {
-            create_user: CreateUserUseCase::new(user_repository.clone()),
-            get_access_token: GetAccessTokenUseCase::new(user_repository.clone(), refresh_token_repository.clone()),
+            create_user: CreateUserUseCase::new(user_repository.clone(), audit_repository.clone()),
+            get_access_token: GetAccessTokenUseCase::new(
+                user_repository.clone(),
+                refresh_token_repository.clone(),
+                password_reset_repository.clone(),
+                application_repository.clone(),
+                audit_repository.clone(),
+            ),
             get_users: GetUsersUseCase::new(user_repository.clone()),
             get_user: GetUserUseCase::new(user_repository.clone()),
-            delete_user: DeleteUserUseCase::new(user_repository.clone()),
-            refresh_token: RefreshTokenUseCase::new(refresh_token_repository),
+            delete_user: DeleteUserUseCase::new(user_repository.clone(), audit_repository.clone()),
+            refresh_token: RefreshTokenUseCase::new(
+                user_repository.clone(),
+                refresh_token_repository.clone(),
+                password_reset_repository.clone(),
+                application_repository.clone(),
+            ),
             forgotten_password: ForgottenPasswordUseCase::new(
                 user_repository.clone(),
                 password_reset_repository.clone(),
-                email_service,
+                email_service.clone(),
             ),
             update_password_from_token: UpdatePasswordFromTokenUseCase::new(
                 user_repository.clone(),
                 password_reset_repository.clone(),
             ),
-            restore_user: RestoreUserUseCase::new(user_repository.clone()),
+            restore_user: RestoreUserUseCase::new(user_repository.clone(), audit_repository.clone()),
+            revoke_sessions: RevokeSessionsUseCase::new(
+                user_repository.clone(),
+                refresh_token_repository.clone(),
+                audit_repository.clone(),
+            ),
+            send_magic_link: SendMagicLinkUseCase::new(
+                user_repository.clone(),
+                magic_link_repository.clone(),
+                email_service,
+            ),
+            consume_magic_link: ConsumeMagicLinkUseCase::new(
+                user_repository,
+                magic_link_repository,
+                refresh_token_repository,
+                password_reset_repository,
+                application_repository,
+                audit_repository,
+            ),
         }
     }
 }
@@ -80,9 +148,6 @@ pub enum UserUseCaseError {
     #[error("Invalid user id")]
     InvalidId(),
 
-    #[error("Incorrect user password")]
-    IncorrectPassword(),
-
     #[error("User not found")]
     UserNotFound(),
 
@@ -101,6 +166,9 @@ pub enum UserUseCaseError {
     #[error("Invalid user password: {0}")]
     InvalidPassword(String),
 
+    #[error("Invalid user locale: {0}")]
+    InvalidLocale(String),
+
     #[error("Invalid UTC datetime: {0}")]
     InvalidUtcDateTime(String),
 
@@ -113,6 +181,15 @@ pub enum UserUseCaseError {
     #[error("Not forgotten password found")]
     ForgottenPasswordNotFound(),
 
+    #[error("Application not found")]
+    ApplicationNotFound(),
+
+    #[error("Magic link not found or expired")]
+    MagicLinkNotFound(),
+
+    #[error("Password has already been used recently")]
+    PasswordAlreadyUsed(),
+
     #[error("Model conversion error")]
     FromModelError(),
 
@@ -159,6 +236,62 @@ impl From<EmailServiceError> for UserUseCaseError {
     }
 }
 
+/// Get the application for which tokens are issued
+async fn get_token_application<A: ApplicationRepository>(
+    application_repository: &A,
+    application_id: ApplicationId,
+) -> Result<ApplicationUseCaseResponse, UserUseCaseError> {
+    application_repository
+        .get_by_id(GetApplicationByIdDtoRequest(GetApplicationByIdUseCaseRequest {
+            id: application_id,
+        }))
+        .await
+        .map(|response| response.0)
+        .map_err(|err| match err {
+            ApplicationUseCaseError::ApplicationNotFound() => UserUseCaseError::ApplicationNotFound(),
+            _ => UserUseCaseError::DatabaseError(err.to_string()),
+        })
+}
+
+/// Check the password expiration of a user before issuing tokens
+///
+/// If the password has expired, a password reset is created and returned: its token must be used to
+/// change the password before new tokens are issued. Users without password never expire.
+async fn check_password_expiration<P: PasswordResetRepository>(
+    password_reset_repository: &P,
+    user_id: &UserId,
+    password_changed_at: Option<&UtcDateTime>,
+    password_lifetime: i64,
+    password_reset_expiration_duration: i64,
+) -> Result<Option<PasswordReset>, UserUseCaseError> {
+    match password_changed_at {
+        Some(password_changed_at) if is_password_expired(password_changed_at, password_lifetime) => {
+            let password_reset = PasswordReset::new(user_id.clone(), password_reset_expiration_duration);
+            password_reset_repository
+                .create_or_update(CreateUpdatePasswordResetDtoRequest {
+                    password_reset: password_reset.clone(),
+                    message: None,
+                })
+                .await?;
+
+            Ok(Some(password_reset))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Check if a password changed at `password_changed_at` is older than `lifetime` days
+fn is_password_expired(password_changed_at: &UtcDateTime, lifetime: i64) -> bool {
+    if lifetime <= 0 {
+        return false;
+    }
+
+    match Duration::try_days(lifetime) {
+        Some(duration) => password_changed_at.value() + duration < Utc::now(),
+        None => false,
+    }
+}
+
 /// User use case generic response
 #[derive(Debug, Clone, PartialEq)]
 pub struct UserUseCaseResponse {
@@ -166,7 +299,28 @@ pub struct UserUseCaseResponse {
     pub email: Email,
     pub lastname: String,
     pub firstname: String,
+
+    /// Language of the emails (default locale if empty)
+    pub locale: Option<Locale>,
+
     pub created_at: UtcDateTime,
     pub updated_at: UtcDateTime,
     pub deleted_at: Option<UtcDateTime>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_password_expired() {
+        let now = UtcDateTime::now();
+        let old = UtcDateTime::new(Utc::now() - Duration::days(100));
+
+        assert!(!is_password_expired(&now, 0));
+        assert!(!is_password_expired(&old, 0));
+        assert!(!is_password_expired(&now, 90));
+        assert!(is_password_expired(&old, 90));
+        assert!(!is_password_expired(&old, 120));
+    }
+}
@@ -1,8 +1,14 @@
 //! User refresh token use case
 
-use super::UserUseCaseError;
+use super::{UserUseCaseError, check_password_expiration, get_token_application};
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::password_reset::PasswordReset;
 use crate::domain::entities::refresh_token::RefreshTokenId;
+use crate::domain::repositories::application::ApplicationRepository;
+use crate::domain::repositories::password_reset::PasswordResetRepository;
 use crate::domain::repositories::refresh_token::dto::{CreateRefreshTokenDtoRequest, DeleteRefreshTokenDtoRequest};
+use crate::domain::repositories::user::UserRepository;
+use crate::domain::repositories::user::dto::GetPasswordChangedAtDtoRequest;
 use crate::domain::services::security::payload::PayloadData;
 use crate::domain::{
     entities::{access_token::AccessToken, refresh_token::RefreshToken},
@@ -13,25 +19,56 @@ use crate::domain::{
 #[derive(Debug, Clone)]
 pub struct RefreshTokenUseCaseRequest {
     pub refresh_token_id: RefreshTokenId,
+    pub application_id: ApplicationId,
     pub jwt: Jwt,
+
+    /// Password lifetime (in day, 0 to disable expiration)
+    pub password_lifetime: i64,
+
+    /// Password reset token lifetime (in hour) used when the password has expired
+    pub password_reset_expiration_duration: i64,
 }
 
 #[derive(Debug, Clone)]
-pub struct RefreshTokenUseCaseResponse {
-    pub access_token: AccessToken,
-    pub refresh_token: RefreshToken,
+pub enum RefreshTokenUseCaseResponse {
+    /// New tokens
+    Tokens {
+        access_token: AccessToken,
+        refresh_token: RefreshToken,
+    },
+
+    /// User password has expired and must be changed with the password reset token
+    PasswordExpired(PasswordReset),
 }
 
 #[derive(Debug, Clone)]
-pub struct RefreshTokenUseCase<T: RefreshTokenRepository> {
+pub struct RefreshTokenUseCase<
+    U: UserRepository,
+    T: RefreshTokenRepository,
+    P: PasswordResetRepository,
+    A: ApplicationRepository,
+> {
+    user_repository: U,
     refresh_token_repository: T,
+    password_reset_repository: P,
+    application_repository: A,
 }
 
-impl<T: RefreshTokenRepository> RefreshTokenUseCase<T> {
+impl<U: UserRepository, T: RefreshTokenRepository, P: PasswordResetRepository, A: ApplicationRepository>
+    RefreshTokenUseCase<U, T, P, A>
+{
     /// Create a new use case
-    pub fn new(refresh_token_repository: T) -> Self {
+    pub fn new(
+        user_repository: U,
+        refresh_token_repository: T,
+        password_reset_repository: P,
+        application_repository: A,
+    ) -> Self {
         Self {
+            user_repository,
             refresh_token_repository,
+            password_reset_repository,
+            application_repository,
         }
     }
 
@@ -41,6 +78,12 @@ impl<T: RefreshTokenRepository> RefreshTokenUseCase<T> {
         &self,
         request: RefreshTokenUseCaseRequest,
     ) -> Result<RefreshTokenUseCaseResponse, UserUseCaseError> {
+        // Use the application token settings
+        let application = get_token_application(&self.application_repository, request.application_id).await?;
+        let jwt = request
+            .jwt
+            .for_application(application.access_token_lifetime, application.refresh_token_lifetime);
+
         // Get the refresh token
         let refresh_token_resp = self
             .refresh_token_repository
@@ -48,30 +91,54 @@ impl<T: RefreshTokenRepository> RefreshTokenUseCase<T> {
             .await?;
         let user_id = refresh_token_resp.user_id;
 
+        // The refresh token can only be used by the application it was issued for
+        if refresh_token_resp.application_id != application.id {
+            return Err(UserUseCaseError::InvalidRefreshToken());
+        }
+
         // Delete the refresh token
         self.refresh_token_repository
             .delete_refresh_token(DeleteRefreshTokenDtoRequest(request.refresh_token_id))
             .await?;
 
+        // Check password expiration (the session ends with the deleted refresh token)
+        let password_changed_at = self
+            .user_repository
+            .get_password_changed_at(GetPasswordChangedAtDtoRequest(user_id.clone()))
+            .await?
+            .0;
+        if let Some(password_reset) = check_password_expiration(
+            &self.password_reset_repository,
+            &user_id,
+            password_changed_at.as_ref(),
+            request.password_lifetime,
+            request.password_reset_expiration_duration,
+        )
+        .await?
+        {
+            return Ok(RefreshTokenUseCaseResponse::PasswordExpired(password_reset));
+        }
+
         // Generate a new access token
-        let payload = PayloadData::new(user_id.to_string(), "".to_string(), "".to_string());
-        let access_token = request.jwt.generate(payload).map_err(|err| {
+        let payload = PayloadData::new(user_id.to_string(), application.id.to_string(), "".to_string())
+            .with_audience(application.audience);
+        let access_token = jwt.generate(payload).map_err(|err| {
             error!(error = %err, "Error generating access token");
             UserUseCaseError::AccessTokenGenerationError()
         })?;
 
         // Generate and save refresh token
-        let refresh_token =
-            RefreshToken::create(user_id.clone(), access_token.clone(), request.jwt.refresh_lifetime())?;
+        let refresh_token = RefreshToken::create(user_id.clone(), access_token.clone(), jwt.refresh_lifetime())?;
         self.refresh_token_repository
             .create_refresh_token(CreateRefreshTokenDtoRequest {
                 refresh_token: refresh_token.clone(),
                 access_token: access_token.clone(),
                 user_id,
+                application_id: application.id,
             })
             .await?;
 
-        Ok(RefreshTokenUseCaseResponse {
+        Ok(RefreshTokenUseCaseResponse::Tokens {
             access_token,
             refresh_token,
         })
@@ -81,41 +148,126 @@ impl<T: RefreshTokenRepository> RefreshTokenUseCase<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::tests::mock::application::{
+        ApplicationRepositoryMock, INVALID_APPLICATION_ID, VALID_APPLICATION_ID,
+    };
+    use crate::domain::tests::mock::password_reset::PasswordResetRepositoryMock;
     use crate::domain::tests::mock::refresh_token::{
-        INVALID_REFRESH_TOKEN_ID, RefreshTokenRepositoryMock, VALID_REFRESH_TOKEN_ID,
+        EXPIRED_PASSWORD_REFRESH_TOKEN_ID, INVALID_REFRESH_TOKEN_ID, OTHER_APPLICATION_REFRESH_TOKEN_ID,
+        RefreshTokenRepositoryMock, VALID_REFRESH_TOKEN_ID,
     };
+    use crate::domain::tests::mock::user::UserRepositoryMock;
     use crate::domain::value_objects::id::Id;
     use std::str::FromStr;
 
+    fn use_case() -> RefreshTokenUseCase<
+        UserRepositoryMock,
+        RefreshTokenRepositoryMock,
+        PasswordResetRepositoryMock,
+        ApplicationRepositoryMock,
+    > {
+        RefreshTokenUseCase::new(
+            UserRepositoryMock {},
+            RefreshTokenRepositoryMock {},
+            PasswordResetRepositoryMock {},
+            ApplicationRepositoryMock {},
+        )
+    }
+
     #[tokio::test]
     async fn test_refresh_token_use_case() {
-        let refresh_token_repository = RefreshTokenRepositoryMock {};
-        let use_case = RefreshTokenUseCase::new(refresh_token_repository);
+        let use_case = use_case();
         let jwt = Jwt::init("HS256", 1, 1, Some("secret"), None, None).unwrap();
         let refresh_token_id = Id::from_str(VALID_REFRESH_TOKEN_ID).unwrap();
 
         let response = use_case
             .call(RefreshTokenUseCaseRequest {
-                refresh_token_id: refresh_token_id,
+                refresh_token_id,
+                application_id: Id::from_str(VALID_APPLICATION_ID).unwrap(),
                 jwt,
+                password_lifetime: 90,
+                password_reset_expiration_duration: 1,
             })
             .await;
-        assert!(response.is_ok());
+        assert!(matches!(response, Ok(RefreshTokenUseCaseResponse::Tokens { .. })));
     }
 
     #[tokio::test]
     async fn test_refresh_token_use_case_invalid_token() {
-        let refresh_token_repository = RefreshTokenRepositoryMock {};
-        let use_case = RefreshTokenUseCase::new(refresh_token_repository);
+        let use_case = use_case();
         let jwt = Jwt::init("HS256", 1, 1, Some("secret"), None, None).unwrap();
         let refresh_token_id = Id::from_str(INVALID_REFRESH_TOKEN_ID).unwrap();
 
         let response = use_case
             .call(RefreshTokenUseCaseRequest {
-                refresh_token_id: refresh_token_id,
+                refresh_token_id,
+                application_id: Id::from_str(VALID_APPLICATION_ID).unwrap(),
                 jwt,
+                password_lifetime: 90,
+                password_reset_expiration_duration: 1,
             })
             .await;
         assert!(response.is_err());
     }
+
+    #[tokio::test]
+    async fn test_refresh_token_use_case_other_application() {
+        let use_case = use_case();
+        let jwt = Jwt::init("HS256", 1, 1, Some("secret"), None, None).unwrap();
+
+        // Refresh token issued for another application
+        let response = use_case
+            .call(RefreshTokenUseCaseRequest {
+                refresh_token_id: Id::from_str(OTHER_APPLICATION_REFRESH_TOKEN_ID).unwrap(),
+                application_id: Id::from_str(VALID_APPLICATION_ID).unwrap(),
+                jwt,
+                password_lifetime: 90,
+                password_reset_expiration_duration: 1,
+            })
+            .await;
+        assert!(matches!(response, Err(UserUseCaseError::InvalidRefreshToken())));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_use_case_application_not_found() {
+        let use_case = use_case();
+        let jwt = Jwt::init("HS256", 1, 1, Some("secret"), None, None).unwrap();
+        let refresh_token_id = Id::from_str(VALID_REFRESH_TOKEN_ID).unwrap();
+
+        let response = use_case
+            .call(RefreshTokenUseCaseRequest {
+                refresh_token_id,
+                application_id: Id::from_str(INVALID_APPLICATION_ID).unwrap(),
+                jwt,
+                password_lifetime: 90,
+                password_reset_expiration_duration: 1,
+            })
+            .await;
+        assert!(matches!(response, Err(UserUseCaseError::ApplicationNotFound())));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_use_case_password_expired() {
+        let use_case = use_case();
+        let jwt = Jwt::init("HS256", 1, 1, Some("secret"), None, None).unwrap();
+        let request = RefreshTokenUseCaseRequest {
+            refresh_token_id: Id::from_str(EXPIRED_PASSWORD_REFRESH_TOKEN_ID).unwrap(),
+            application_id: Id::from_str(VALID_APPLICATION_ID).unwrap(),
+            jwt,
+            password_lifetime: 90,
+            password_reset_expiration_duration: 1,
+        };
+
+        let response = use_case.call(request.clone()).await;
+        assert!(matches!(response, Ok(RefreshTokenUseCaseResponse::PasswordExpired(_))));
+
+        // Expiration disabled
+        let response = use_case
+            .call(RefreshTokenUseCaseRequest {
+                password_lifetime: 0,
+                ..request
+            })
+            .await;
+        assert!(matches!(response, Ok(RefreshTokenUseCaseResponse::Tokens { .. })));
+    }
 }
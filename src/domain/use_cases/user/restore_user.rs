@@ -1,27 +1,37 @@
 //! Restore a deleted user
 
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
 use crate::domain::entities::user::UserId;
+use crate::domain::entities::webhook::{WebhookEvent, WebhookMessage};
+use crate::domain::repositories::audit::AuditRepository;
 use crate::domain::repositories::user::UserRepository;
 use crate::domain::repositories::user::dto::RestoreUserDtoRequest;
+use crate::domain::use_cases::audit::record_audit_event;
 use crate::domain::use_cases::user::UserUseCaseError;
+use serde_json::json;
 
 #[derive(Debug, Clone)]
 pub struct RestoreUserUseCaseRequest {
     pub user_id: UserId,
+    pub audit: AuditContext,
 }
 
 #[derive(Debug, Clone)]
 pub struct RestoreUserUseCaseResponse();
 
 #[derive(Debug, Clone)]
-pub struct RestoreUserUseCase<U: UserRepository> {
+pub struct RestoreUserUseCase<U: UserRepository, R: AuditRepository> {
     user_repository: U,
+    audit_repository: R,
 }
 
-impl<U: UserRepository> RestoreUserUseCase<U> {
+impl<U: UserRepository, R: AuditRepository> RestoreUserUseCase<U, R> {
     /// Create a new use case
-    pub fn new(user_repository: U) -> Self {
-        Self { user_repository }
+    pub fn new(user_repository: U, audit_repository: R) -> Self {
+        Self {
+            user_repository,
+            audit_repository,
+        }
     }
 
     /// Restore a user
@@ -32,11 +42,24 @@ impl<U: UserRepository> RestoreUserUseCase<U> {
     ) -> Result<RestoreUserUseCaseResponse, UserUseCaseError> {
         // TODO: Validation?
 
+        let user_id = request.user_id.to_string();
+        let message = WebhookMessage::new(WebhookEvent::UserRestored, json!({ "user_id": user_id }))?;
         let result = self
             .user_repository
-            .restore_user(RestoreUserDtoRequest(request))
+            .restore_user(RestoreUserDtoRequest {
+                user_id: request.user_id,
+                message,
+            })
             .await?;
 
+        record_audit_event(
+            &self.audit_repository,
+            &request.audit,
+            AuditAction::UserRestored,
+            user_id,
+        )
+        .await;
+
         Ok(result.0)
     }
 }
@@ -44,16 +67,18 @@ impl<U: UserRepository> RestoreUserUseCase<U> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
     use crate::domain::tests::mock::user::{INVALID_ID, UserRepositoryMock, VALID_ID};
     use std::str::FromStr;
 
     #[tokio::test]
     async fn test_delete_user_use_case() {
         let user_repository = UserRepositoryMock {};
-        let use_case = RestoreUserUseCase::new(user_repository);
+        let use_case = RestoreUserUseCase::new(user_repository, AuditRepositoryMock {});
 
         let request = RestoreUserUseCaseRequest {
             user_id: UserId::from_str(VALID_ID).unwrap(),
+            audit: AuditContext::default(),
         };
 
         let response = use_case.call(request).await;
@@ -63,10 +88,11 @@ mod tests {
     #[tokio::test]
     async fn test_delete_user_use_case_not_found() {
         let user_repository = UserRepositoryMock {};
-        let use_case = RestoreUserUseCase::new(user_repository);
+        let use_case = RestoreUserUseCase::new(user_repository, AuditRepositoryMock {});
 
         let request = RestoreUserUseCaseRequest {
             user_id: UserId::from_str(INVALID_ID).unwrap(),
+            audit: AuditContext::default(),
         };
 
         let response = use_case.call(request).await;
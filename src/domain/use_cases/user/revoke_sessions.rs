@@ -0,0 +1,120 @@
+//! Revoke all the sessions of a user
+
+use crate::domain::entities::audit_event::{AuditAction, AuditContext};
+use crate::domain::entities::user::UserId;
+use crate::domain::entities::webhook::{WebhookEvent, WebhookMessage};
+use crate::domain::repositories::audit::AuditRepository;
+use crate::domain::repositories::refresh_token::RefreshTokenRepository;
+use crate::domain::repositories::refresh_token::dto::DeleteUserRefreshTokensDtoRequest;
+use crate::domain::repositories::user::UserRepository;
+use crate::domain::repositories::user::dto::GetUserByIdDtoRequest;
+use crate::domain::use_cases::audit::record_audit_event;
+use crate::domain::use_cases::user::UserUseCaseError;
+use crate::domain::use_cases::user::get_user::GetUserUseCaseRequest;
+use serde_json::json;
+
+#[derive(Debug, Clone)]
+pub struct RevokeSessionsUseCaseRequest {
+    pub user_id: UserId,
+    pub audit: AuditContext,
+}
+
+#[derive(Debug, Clone)]
+pub struct RevokeSessionsUseCaseResponse {
+    /// Number of deleted refresh tokens
+    pub revoked: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct RevokeSessionsUseCase<U: UserRepository, T: RefreshTokenRepository, R: AuditRepository> {
+    user_repository: U,
+    refresh_token_repository: T,
+    audit_repository: R,
+}
+
+impl<U: UserRepository, T: RefreshTokenRepository, R: AuditRepository> RevokeSessionsUseCase<U, T, R> {
+    /// Create a new use case
+    pub fn new(user_repository: U, refresh_token_repository: T, audit_repository: R) -> Self {
+        Self {
+            user_repository,
+            refresh_token_repository,
+            audit_repository,
+        }
+    }
+
+    /// Delete the refresh tokens of a user
+    ///
+    /// Access tokens already issued remain valid until they expire.
+    #[instrument(skip(self), name = "revoke_sessions_use_case")]
+    pub async fn call(
+        &self,
+        request: RevokeSessionsUseCaseRequest,
+    ) -> Result<RevokeSessionsUseCaseResponse, UserUseCaseError> {
+        // Check that the user exists
+        self.user_repository
+            .get_user_by_id(GetUserByIdDtoRequest(GetUserUseCaseRequest {
+                user_id: request.user_id.clone(),
+            }))
+            .await?;
+
+        let user_id = request.user_id.to_string();
+        let message = WebhookMessage::new(WebhookEvent::SessionsRevoked, json!({ "user_id": user_id }))?;
+        let revoked = self
+            .refresh_token_repository
+            .delete_user_refresh_tokens(DeleteUserRefreshTokensDtoRequest {
+                user_id: request.user_id,
+                message,
+            })
+            .await?
+            .deleted;
+
+        record_audit_event(
+            &self.audit_repository,
+            &request.audit,
+            AuditAction::SessionsRevoked,
+            user_id,
+        )
+        .await;
+
+        Ok(RevokeSessionsUseCaseResponse { revoked })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::audit::AuditRepositoryMock;
+    use crate::domain::tests::mock::refresh_token::RefreshTokenRepositoryMock;
+    use crate::domain::tests::mock::user::{INVALID_ID, UserRepositoryMock, VALID_ID};
+    use std::str::FromStr;
+
+    fn request(user_id: &str) -> RevokeSessionsUseCaseRequest {
+        RevokeSessionsUseCaseRequest {
+            user_id: UserId::from_str(user_id).unwrap(),
+            audit: AuditContext::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_revoke_sessions_use_case() {
+        let use_case = RevokeSessionsUseCase::new(
+            UserRepositoryMock {},
+            RefreshTokenRepositoryMock {},
+            AuditRepositoryMock {},
+        );
+
+        let response = use_case.call(request(VALID_ID)).await.unwrap();
+        assert_eq!(response.revoked, 2);
+    }
+
+    #[tokio::test]
+    async fn test_revoke_sessions_use_case_unknown_user() {
+        let use_case = RevokeSessionsUseCase::new(
+            UserRepositoryMock {},
+            RefreshTokenRepositoryMock {},
+            AuditRepositoryMock {},
+        );
+
+        assert!(use_case.call(request(INVALID_ID)).await.is_err());
+    }
+}
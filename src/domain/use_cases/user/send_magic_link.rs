@@ -0,0 +1,115 @@
+//! Send magic link use case
+
+use crate::domain::entities::magic_link::MagicLink;
+use crate::domain::repositories::magic_link::MagicLinkRepository;
+use crate::domain::repositories::magic_link::dto::CreateUpdateMagicLinkDtoRequest;
+use crate::domain::repositories::user::UserRepository;
+use crate::domain::repositories::user::dto::GetUserByEmailDtoRequest;
+use crate::domain::services::email::EmailService;
+use crate::domain::services::email::magic_link::MagicLinkEmailRequest;
+use crate::domain::use_cases::user::UserUseCaseError;
+use crate::domain::value_objects::email::Email;
+
+#[derive(Debug, Clone)]
+pub struct SendMagicLinkUseCaseRequest {
+    pub user_email: Email,
+
+    /// Magic link lifetime (in minute)
+    pub expiration_duration: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct SendMagicLinkUseCaseResponse();
+
+#[derive(Debug, Clone)]
+pub struct SendMagicLinkUseCase<U: UserRepository, M: MagicLinkRepository, E: EmailService> {
+    user_repository: U,
+    magic_link_repository: M,
+    email_service: E,
+}
+
+impl<U: UserRepository, M: MagicLinkRepository, E: EmailService> SendMagicLinkUseCase<U, M, E> {
+    /// Create a new use case
+    pub fn new(user_repository: U, magic_link_repository: M, email_service: E) -> Self {
+        Self {
+            user_repository,
+            magic_link_repository,
+            email_service,
+        }
+    }
+
+    /// Generate a single-use token and queue an email to send it to the user
+    ///
+    /// An unknown email is not an error, so that the response does not reveal if the account exists.
+    #[instrument(skip(self), name = "send_magic_link_use_case")]
+    pub async fn call(
+        &self,
+        request: SendMagicLinkUseCaseRequest,
+    ) -> Result<SendMagicLinkUseCaseResponse, UserUseCaseError> {
+        // Get user by email
+        let user = match self
+            .user_repository
+            .get_user_by_email(GetUserByEmailDtoRequest(request.user_email))
+            .await
+        {
+            Ok(user) => user,
+            Err(UserUseCaseError::UserNotFound()) => {
+                debug!("Magic link requested for an unknown email");
+                return Ok(SendMagicLinkUseCaseResponse());
+            }
+            Err(err) => Err(err)?,
+        };
+
+        // Build email with the token to the user
+        let magic_link = MagicLink::new(user.0.id.clone(), request.expiration_duration);
+        let email = self
+            .email_service
+            .magic_link(MagicLinkEmailRequest {
+                email: user.0.email,
+                token: magic_link.token.clone(),
+                locale: user.0.locale,
+            })
+            .map_err(|err| UserUseCaseError::SendEmailError(err.to_string()))?;
+
+        // Create a new magic link (replace the previous one) and queue the email in the same transaction
+        self.magic_link_repository
+            .create_or_update(CreateUpdateMagicLinkDtoRequest {
+                magic_link,
+                message: email.0,
+            })
+            .await?;
+
+        Ok(SendMagicLinkUseCaseResponse())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::email::EmailServiceMock;
+    use crate::domain::tests::mock::magic_link::MagicLinkRepositoryMock;
+    use crate::domain::tests::mock::user::{EMAIL_NOT_FOUND, UserRepositoryMock, VALID_EMAIL};
+
+    #[tokio::test]
+    async fn test_send_magic_link_use_case() {
+        let use_case =
+            SendMagicLinkUseCase::new(UserRepositoryMock {}, MagicLinkRepositoryMock {}, EmailServiceMock {});
+
+        let response = use_case
+            .call(SendMagicLinkUseCaseRequest {
+                user_email: Email::new(VALID_EMAIL).unwrap(),
+                expiration_duration: 15,
+            })
+            .await;
+        assert!(response.is_ok());
+
+        // Unknown email: same response, no email is sent
+        let response = use_case
+            .call(SendMagicLinkUseCaseRequest {
+                user_email: Email::new(EMAIL_NOT_FOUND).unwrap(),
+                expiration_duration: 15,
+            })
+            .await;
+        assert!(response.is_ok());
+    }
+}
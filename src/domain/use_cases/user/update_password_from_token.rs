@@ -1,17 +1,22 @@
 //! Update user password from forgotten password request
 
 use crate::domain::entities::password_reset::PasswordResetTokenValue;
+use crate::domain::entities::webhook::{WebhookEvent, WebhookMessage};
 use crate::domain::repositories::password_reset::PasswordResetRepository;
 use crate::domain::repositories::password_reset::dto::{DeletePasswordResetDtoRequest, GetUserIdFromTokenDtoRequest};
 use crate::domain::repositories::user::UserRepository;
-use crate::domain::repositories::user::dto::UpdatePasswordDtoRequest;
+use crate::domain::repositories::user::dto::{GetPasswordHistoryDtoRequest, UpdatePasswordDtoRequest};
 use crate::domain::use_cases::user::UserUseCaseError;
 use crate::domain::value_objects::password::Password;
+use serde_json::json;
 
 #[derive(Debug, Clone)]
 pub struct UpdatePasswordFromTokenUseCaseRequest {
     pub token: PasswordResetTokenValue,
     pub password: Password,
+
+    /// Number of previous passwords which cannot be reused (0 to disable)
+    pub password_history_size: u32,
 }
 
 #[derive(Debug, Clone)]
@@ -33,7 +38,6 @@ impl<U: UserRepository, P: PasswordResetRepository> UpdatePasswordFromTokenUseCa
     }
 
     /// Update user password from forgotten password request token
-    // TODO: Check if the password is not the same as the previous one
     #[instrument(skip(self), name = "update_password_from_token_use_case")]
     pub async fn call(
         &self,
@@ -47,11 +51,35 @@ impl<U: UserRepository, P: PasswordResetRepository> UpdatePasswordFromTokenUseCa
             .user_id
             .ok_or_else(UserUseCaseError::ForgottenPasswordNotFound)?;
 
+        // Check that the new password has not been used recently
+        if request.password_history_size > 0 {
+            let original_password = request.password.original().ok_or(UserUseCaseError::InvalidPassword(
+                "No original password found".to_string(),
+            ))?;
+
+            let history = self
+                .user_repository
+                .get_password_history(GetPasswordHistoryDtoRequest {
+                    user_id: user_id.clone(),
+                    limit: request.password_history_size,
+                })
+                .await?;
+
+            if history.0.iter().any(|p| p.verify(&original_password).is_ok()) {
+                return Err(UserUseCaseError::PasswordAlreadyUsed());
+            }
+        }
+
         // Update user password
+        let message = WebhookMessage::new(
+            WebhookEvent::UserPasswordChanged,
+            json!({ "user_id": user_id.to_string() }),
+        )?;
         self.user_repository
             .update_password(UpdatePasswordDtoRequest {
                 user_id: user_id.clone(),
                 password: request.password,
+                message,
             })
             .await?;
 
@@ -68,7 +96,7 @@ impl<U: UserRepository, P: PasswordResetRepository> UpdatePasswordFromTokenUseCa
 mod tests {
     use super::*;
     use crate::domain::tests::mock::password_reset::{INVALID_TOKEN, PasswordResetRepositoryMock, VALID_TOKEN};
-    use crate::domain::tests::mock::user::UserRepositoryMock;
+    use crate::domain::tests::mock::user::{UserRepositoryMock, VALID_PASSWORD};
 
     #[tokio::test]
     async fn test_update_password_from_token_use_case() {
@@ -80,6 +108,7 @@ mod tests {
             .call(UpdatePasswordFromTokenUseCaseRequest {
                 token: VALID_TOKEN.into(),
                 password: Password::new("newPassword123", false).unwrap(),
+                password_history_size: 5,
             })
             .await;
         assert!(response.is_ok());
@@ -88,8 +117,35 @@ mod tests {
             .call(UpdatePasswordFromTokenUseCaseRequest {
                 token: INVALID_TOKEN.into(),
                 password: Password::new("newPassword123", false).unwrap(),
+                password_history_size: 5,
             })
             .await;
         assert!(response.is_err());
     }
+
+    #[tokio::test]
+    async fn test_update_password_from_token_use_case_password_already_used() {
+        let user_repository = UserRepositoryMock {};
+        let password_reset_repository = PasswordResetRepositoryMock {};
+        let use_case = UpdatePasswordFromTokenUseCase::new(user_repository, password_reset_repository);
+
+        let response = use_case
+            .call(UpdatePasswordFromTokenUseCaseRequest {
+                token: VALID_TOKEN.into(),
+                password: Password::new(VALID_PASSWORD, false).unwrap(),
+                password_history_size: 5,
+            })
+            .await;
+        assert_eq!(response.unwrap_err(), UserUseCaseError::PasswordAlreadyUsed());
+
+        // History check disabled
+        let response = use_case
+            .call(UpdatePasswordFromTokenUseCaseRequest {
+                token: VALID_TOKEN.into(),
+                password: Password::new(VALID_PASSWORD, false).unwrap(),
+                password_history_size: 0,
+            })
+            .await;
+        assert!(response.is_ok());
+    }
 }
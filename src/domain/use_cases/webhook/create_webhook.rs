@@ -0,0 +1,146 @@
+//! Create an application webhook use case
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::webhook::{Webhook, WebhookEvent};
+use crate::domain::repositories::webhook::WebhookRepository;
+use crate::domain::repositories::webhook::dto::CreateWebhookDtoRequest;
+use crate::domain::services::webhook::WebhookService;
+use crate::domain::use_cases::webhook::WebhookUseCaseError;
+use crate::domain::value_objects::webhook_secret::WebhookSecret;
+use validator::Validate;
+
+#[derive(Debug, Clone, Validate)]
+pub struct CreateWebhookUseCaseRequest {
+    pub application_id: ApplicationId,
+
+    #[validate(url, length(max = 255))]
+    pub url: String,
+
+    /// Subscribed events
+    pub events: Vec<WebhookEvent>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateWebhookUseCaseResponse {
+    /// Webhook with its secret, it is only returned once
+    pub webhook: Webhook,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateWebhookUseCase<W: WebhookRepository, S: WebhookService> {
+    webhook_repository: W,
+    webhook_service: S,
+}
+
+impl<W: WebhookRepository, S: WebhookService> CreateWebhookUseCase<W, S> {
+    /// Create a new use case
+    pub fn new(webhook_repository: W, webhook_service: S) -> Self {
+        Self {
+            webhook_repository,
+            webhook_service,
+        }
+    }
+
+    /// Create a new webhook with a generated secret
+    #[instrument(skip(self), name = "create_webhook_use_case")]
+    pub async fn call(
+        &self,
+        mut request: CreateWebhookUseCaseRequest,
+    ) -> Result<CreateWebhookUseCaseResponse, WebhookUseCaseError> {
+        if let Err(err) = request.validate() {
+            return Err(WebhookUseCaseError::InvalidWebhook(err.to_string()));
+        }
+        if let Err(err) = self.webhook_service.check_url(&request.url) {
+            return Err(WebhookUseCaseError::InvalidWebhook(err.to_string()));
+        }
+        if request.events.is_empty() {
+            return Err(WebhookUseCaseError::InvalidWebhook(
+                "at least one event is required".to_string(),
+            ));
+        }
+
+        let mut events = Vec::with_capacity(request.events.len());
+        for event in request.events {
+            if !events.contains(&event) {
+                events.push(event);
+            }
+        }
+        request.events = events;
+
+        let webhook = self
+            .webhook_repository
+            .create_webhook(CreateWebhookDtoRequest {
+                webhook: request,
+                secret: WebhookSecret::generate(),
+            })
+            .await?;
+
+        Ok(CreateWebhookUseCaseResponse { webhook: webhook.0 })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::webhook::{
+        INVALID_APPLICATION_ID, VALID_APPLICATION_ID, WebhookRepositoryMock, WebhookServiceMock,
+    };
+    use std::str::FromStr;
+
+    fn request(application_id: &str) -> CreateWebhookUseCaseRequest {
+        CreateWebhookUseCaseRequest {
+            application_id: ApplicationId::from_str(application_id).unwrap(),
+            url: "https://app.test.com/webhooks".to_string(),
+            events: vec![
+                WebhookEvent::UserCreated,
+                WebhookEvent::UserDeleted,
+                WebhookEvent::UserCreated,
+            ],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_webhook_use_case() {
+        let use_case = CreateWebhookUseCase::new(WebhookRepositoryMock::default(), WebhookServiceMock {});
+
+        let response = use_case.call(request(VALID_APPLICATION_ID)).await.unwrap();
+        assert_eq!(response.webhook.url, "https://app.test.com/webhooks".to_string());
+        assert_eq!(
+            response.webhook.events,
+            vec![WebhookEvent::UserCreated, WebhookEvent::UserDeleted]
+        );
+        assert!(response.webhook.secret.value().starts_with("whsec_"));
+
+        let response = use_case.call(request(INVALID_APPLICATION_ID)).await;
+        assert_eq!(response.unwrap_err(), WebhookUseCaseError::ApplicationNotFound());
+    }
+
+    #[tokio::test]
+    async fn test_create_webhook_use_case_invalid_request() {
+        let use_case = CreateWebhookUseCase::new(WebhookRepositoryMock::default(), WebhookServiceMock {});
+
+        let response = use_case
+            .call(CreateWebhookUseCaseRequest {
+                url: "not an url".to_string(),
+                ..request(VALID_APPLICATION_ID)
+            })
+            .await;
+        assert!(matches!(response, Err(WebhookUseCaseError::InvalidWebhook(_))));
+
+        let response = use_case
+            .call(CreateWebhookUseCaseRequest {
+                url: "http://app.test.com/webhooks".to_string(),
+                ..request(VALID_APPLICATION_ID)
+            })
+            .await;
+        assert!(matches!(response, Err(WebhookUseCaseError::InvalidWebhook(_))));
+
+        let response = use_case
+            .call(CreateWebhookUseCaseRequest {
+                events: vec![],
+                ..request(VALID_APPLICATION_ID)
+            })
+            .await;
+        assert!(matches!(response, Err(WebhookUseCaseError::InvalidWebhook(_))));
+    }
+}
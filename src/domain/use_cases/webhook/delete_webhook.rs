@@ -0,0 +1,68 @@
+//! Delete an application webhook use case
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::webhook::WebhookId;
+use crate::domain::repositories::webhook::WebhookRepository;
+use crate::domain::repositories::webhook::dto::DeleteWebhookDtoRequest;
+use crate::domain::use_cases::webhook::WebhookUseCaseError;
+
+#[derive(Debug, Clone)]
+pub struct DeleteWebhookUseCaseRequest {
+    pub application_id: ApplicationId,
+    pub id: WebhookId,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteWebhookUseCaseResponse();
+
+#[derive(Debug, Clone)]
+pub struct DeleteWebhookUseCase<W: WebhookRepository> {
+    webhook_repository: W,
+}
+
+impl<W: WebhookRepository> DeleteWebhookUseCase<W> {
+    /// Create a new use case
+    pub fn new(webhook_repository: W) -> Self {
+        Self { webhook_repository }
+    }
+
+    /// Delete a webhook (its pending deliveries are not sent anymore)
+    #[instrument(skip(self), name = "delete_webhook_use_case")]
+    pub async fn call(
+        &self,
+        request: DeleteWebhookUseCaseRequest,
+    ) -> Result<DeleteWebhookUseCaseResponse, WebhookUseCaseError> {
+        let webhook = self
+            .webhook_repository
+            .delete_webhook(DeleteWebhookDtoRequest(request))
+            .await?;
+
+        Ok(webhook.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::webhook::{
+        INVALID_WEBHOOK_ID, VALID_APPLICATION_ID, VALID_WEBHOOK_ID, WebhookRepositoryMock,
+    };
+    use std::str::FromStr;
+
+    fn request(id: &str) -> DeleteWebhookUseCaseRequest {
+        DeleteWebhookUseCaseRequest {
+            application_id: ApplicationId::from_str(VALID_APPLICATION_ID).unwrap(),
+            id: WebhookId::from_str(id).unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_webhook_use_case() {
+        let use_case = DeleteWebhookUseCase::new(WebhookRepositoryMock::default());
+
+        assert!(use_case.call(request(VALID_WEBHOOK_ID)).await.is_ok());
+
+        let response = use_case.call(request(INVALID_WEBHOOK_ID)).await;
+        assert_eq!(response.unwrap_err(), WebhookUseCaseError::WebhookNotFound());
+    }
+}
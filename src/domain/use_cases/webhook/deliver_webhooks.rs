@@ -0,0 +1,168 @@
+//! Deliver pending webhook messages use case
+
+use crate::domain::entities::webhook::{WebhookDelivery, WebhookDeliveryStatus};
+use crate::domain::repositories::webhook::WebhookRepository;
+use crate::domain::repositories::webhook::dto::{GetDueWebhookDeliveriesDtoRequest, UpdateWebhookDeliveryDtoRequest};
+use crate::domain::services::webhook::WebhookService;
+use crate::domain::use_cases::retry::{LAST_ERROR_MAX_LENGTH, backoff};
+use crate::domain::use_cases::webhook::WebhookUseCaseError;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use chrono::Duration;
+use futures::future::join_all;
+
+#[derive(Debug, Clone)]
+pub struct DeliverWebhooksUseCaseRequest {
+    /// Maximum number of deliveries sent
+    pub batch_size: u32,
+
+    /// Number of attempts before a delivery is marked as failed
+    pub max_attempts: u32,
+
+    /// Delay before the first retry (in second), doubled after each attempt
+    pub backoff_base: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeliverWebhooksUseCaseResponse {
+    pub delivered: usize,
+
+    /// Deliveries which will be retried later
+    pub retried: usize,
+
+    /// Deliveries which reached the maximum number of attempts
+    pub failed: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeliverWebhooksUseCase<W: WebhookRepository, S: WebhookService> {
+    webhook_repository: W,
+    webhook_service: S,
+}
+
+impl<W: WebhookRepository, S: WebhookService> DeliverWebhooksUseCase<W, S> {
+    /// Create a new use case
+    pub fn new(webhook_repository: W, webhook_service: S) -> Self {
+        Self {
+            webhook_repository,
+            webhook_service,
+        }
+    }
+
+    /// Send the due deliveries of the outbox
+    #[instrument(skip(self), name = "deliver_webhooks_use_case")]
+    pub async fn call(
+        &self,
+        request: DeliverWebhooksUseCaseRequest,
+    ) -> Result<DeliverWebhooksUseCaseResponse, WebhookUseCaseError> {
+        let deliveries = self
+            .webhook_repository
+            .get_due_deliveries(GetDueWebhookDeliveriesDtoRequest {
+                limit: request.batch_size,
+            })
+            .await?
+            .0;
+
+        let updates = join_all(deliveries.iter().map(|delivery| self.attempt(delivery, &request))).await;
+
+        let mut response = DeliverWebhooksUseCaseResponse::default();
+        for update in updates {
+            match update.status {
+                WebhookDeliveryStatus::Delivered => response.delivered += 1,
+                WebhookDeliveryStatus::Pending => response.retried += 1,
+                WebhookDeliveryStatus::Failed => response.failed += 1,
+            }
+
+            self.webhook_repository.update_delivery(update).await?;
+        }
+
+        Ok(response)
+    }
+
+    /// Send a delivery and compute its new state
+    async fn attempt(
+        &self,
+        delivery: &WebhookDelivery,
+        request: &DeliverWebhooksUseCaseRequest,
+    ) -> UpdateWebhookDeliveryDtoRequest {
+        let attempts = delivery.attempts.saturating_add(1);
+        let now = UtcDateTime::now();
+
+        match self.webhook_service.send(delivery).await {
+            Ok(()) => UpdateWebhookDeliveryDtoRequest {
+                id: delivery.id.clone(),
+                status: WebhookDeliveryStatus::Delivered,
+                attempts,
+                next_attempt_at: now,
+                last_error: None,
+            },
+            Err(err) => {
+                warn!(error = %err, delivery_id = %delivery.id, attempts, "Webhook delivery failed");
+
+                let status = if attempts >= request.max_attempts {
+                    WebhookDeliveryStatus::Failed
+                } else {
+                    WebhookDeliveryStatus::Pending
+                };
+                let delay = backoff(request.backoff_base, attempts);
+
+                UpdateWebhookDeliveryDtoRequest {
+                    id: delivery.id.clone(),
+                    status,
+                    attempts,
+                    next_attempt_at: UtcDateTime::new(now.value() + Duration::seconds(delay as i64)),
+                    last_error: Some(err.to_string().chars().take(LAST_ERROR_MAX_LENGTH).collect()),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::webhook::{WebhookRepositoryMock, WebhookServiceMock};
+
+    #[tokio::test]
+    async fn test_deliver_webhooks_use_case() {
+        let repository = WebhookRepositoryMock::default();
+        let use_case = DeliverWebhooksUseCase::new(repository.clone(), WebhookServiceMock {});
+
+        let response = use_case
+            .call(DeliverWebhooksUseCaseRequest {
+                batch_size: 10,
+                max_attempts: 3,
+                backoff_base: 30,
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            response,
+            DeliverWebhooksUseCaseResponse {
+                delivered: 1,
+                retried: 1,
+                failed: 1,
+            }
+        );
+
+        let updates = repository.updates.lock().unwrap();
+        assert_eq!(updates.len(), 3);
+
+        let delivered = &updates[0];
+        assert_eq!(delivered.status, WebhookDeliveryStatus::Delivered);
+        assert_eq!(delivered.attempts, 1);
+        assert_eq!(delivered.last_error, None);
+
+        // First failure: retried 30 seconds later
+        let retried = &updates[1];
+        assert_eq!(retried.status, WebhookDeliveryStatus::Pending);
+        assert_eq!(retried.attempts, 1);
+        assert!(retried.last_error.is_some());
+        let delay = retried.next_attempt_at.timestamp() - UtcDateTime::now().timestamp();
+        assert!((29..=30).contains(&delay));
+
+        // Third failure: no more attempts
+        let failed = &updates[2];
+        assert_eq!(failed.status, WebhookDeliveryStatus::Failed);
+        assert_eq!(failed.attempts, 3);
+    }
+}
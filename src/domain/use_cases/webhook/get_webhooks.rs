@@ -0,0 +1,97 @@
+//! Get the webhooks of an application use case
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::webhook::Webhook;
+use crate::domain::repositories::webhook::WebhookRepository;
+use crate::domain::repositories::webhook::dto::{
+    CountWebhooksDtoRequest, GetWebhooksDtoRequest, GetWebhooksDtoResponse,
+};
+use crate::domain::use_cases::webhook::WebhookUseCaseError;
+use crate::domain::value_objects::pagination::{Pagination, PaginationCursor};
+use crate::domain::value_objects::query_sort::QuerySorts;
+
+#[derive(Debug, Clone)]
+pub struct GetWebhooksUseCaseRequest {
+    pub application_id: ApplicationId,
+    pub pagination: Pagination,
+    pub sorts: Option<QuerySorts>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetWebhooksUseCaseResponse {
+    pub webhooks: Vec<Webhook>,
+    pub total: i64,
+    pub next_cursor: Option<PaginationCursor>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetWebhooksUseCase<W: WebhookRepository> {
+    webhook_repository: W,
+}
+
+impl<W: WebhookRepository> GetWebhooksUseCase<W> {
+    /// Create a new use case
+    pub fn new(webhook_repository: W) -> Self {
+        Self { webhook_repository }
+    }
+
+    /// Get the webhooks of an application
+    #[instrument(skip(self), name = "get_webhooks_use_case")]
+    pub async fn call(
+        &self,
+        request: GetWebhooksUseCaseRequest,
+    ) -> Result<GetWebhooksUseCaseResponse, WebhookUseCaseError> {
+        let total = self
+            .webhook_repository
+            .count_webhooks(CountWebhooksDtoRequest {
+                application_id: request.application_id.clone(),
+            })
+            .await?
+            .0;
+
+        let GetWebhooksDtoResponse { webhooks, next_cursor } = self
+            .webhook_repository
+            .get_webhooks(GetWebhooksDtoRequest(request))
+            .await?;
+
+        Ok(GetWebhooksUseCaseResponse {
+            webhooks,
+            total,
+            next_cursor,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tests::mock::webhook::{INVALID_APPLICATION_ID, VALID_APPLICATION_ID, WebhookRepositoryMock};
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test_get_webhooks_use_case() {
+        let use_case = GetWebhooksUseCase::new(WebhookRepositoryMock::default());
+
+        let response = use_case
+            .call(GetWebhooksUseCaseRequest {
+                application_id: ApplicationId::from_str(VALID_APPLICATION_ID).unwrap(),
+                pagination: Pagination::default(),
+                sorts: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.total, 1);
+        assert_eq!(response.webhooks.len(), 1);
+
+        let response = use_case
+            .call(GetWebhooksUseCaseRequest {
+                application_id: ApplicationId::from_str(INVALID_APPLICATION_ID).unwrap(),
+                pagination: Pagination::default(),
+                sorts: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.total, 0);
+        assert!(response.webhooks.is_empty());
+    }
+}
@@ -0,0 +1,62 @@
+//! Webhook use cases
+
+pub mod create_webhook;
+pub mod delete_webhook;
+pub mod deliver_webhooks;
+pub mod get_webhooks;
+
+use crate::domain::repositories::webhook::WebhookRepository;
+use crate::domain::services::webhook::WebhookService;
+use crate::domain::use_cases::webhook::create_webhook::CreateWebhookUseCase;
+use crate::domain::use_cases::webhook::delete_webhook::DeleteWebhookUseCase;
+use crate::domain::use_cases::webhook::deliver_webhooks::DeliverWebhooksUseCase;
+use crate::domain::use_cases::webhook::get_webhooks::GetWebhooksUseCase;
+use crate::domain::value_objects::id::IdError;
+use thiserror::Error;
+
+#[derive(Debug, Clone)]
+pub struct WebhookUseCases<W: WebhookRepository, S: WebhookService> {
+    pub create_webhook: CreateWebhookUseCase<W, S>,
+    pub get_webhooks: GetWebhooksUseCase<W>,
+    pub delete_webhook: DeleteWebhookUseCase<W>,
+    pub deliver_webhooks: DeliverWebhooksUseCase<W, S>,
+}
+
+impl<W: WebhookRepository, S: WebhookService> WebhookUseCases<W, S> {
+    /// Create a new webhook use cases
+    pub fn new(webhook_repository: W, webhook_service: S) -> Self {
+        Self {
+            create_webhook: CreateWebhookUseCase::new(webhook_repository.clone(), webhook_service.clone()),
+            get_webhooks: GetWebhooksUseCase::new(webhook_repository.clone()),
+            delete_webhook: DeleteWebhookUseCase::new(webhook_repository.clone()),
+            deliver_webhooks: DeliverWebhooksUseCase::new(webhook_repository, webhook_service),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum WebhookUseCaseError {
+    #[error("Application not found")]
+    ApplicationNotFound(),
+
+    #[error("Webhook not found")]
+    WebhookNotFound(),
+
+    #[error("Invalid id")]
+    InvalidId(),
+
+    #[error("Invalid webhook: {0}")]
+    InvalidWebhook(String),
+
+    #[error("Model conversion error")]
+    FromModelError(),
+
+    #[error("{0}")]
+    DatabaseError(String),
+}
+
+impl From<IdError> for WebhookUseCaseError {
+    fn from(_: IdError) -> Self {
+        WebhookUseCaseError::InvalidId()
+    }
+}
@@ -0,0 +1,93 @@
+//! Client secret value object representation
+
+use crate::domain::value_objects::password::{Password, PasswordError};
+use crate::domain::value_objects::password_policy::PasswordPolicy;
+use rand::Rng;
+use rand::distr::Alphanumeric;
+use std::fmt::{Debug, Formatter};
+
+/// Length of a generated client secret
+const CLIENT_SECRET_LENGTH: usize = 48;
+
+/// Plain client secret, only known when it is generated or sent by a client
+#[derive(Clone, PartialEq, Eq)]
+pub struct ClientSecret(String);
+
+impl ClientSecret {
+    /// Generate a new random client secret
+    ///
+    /// # Example
+    /// ```rust
+    /// use auth2_api::domain::value_objects::client_secret::ClientSecret;
+    ///
+    /// let secret = ClientSecret::generate();
+    /// assert_eq!(secret.value().len(), 48);
+    /// assert_ne!(secret, ClientSecret::generate());
+    /// ```
+    pub fn generate() -> Self {
+        Self(
+            rand::rng()
+                .sample_iter(&Alphanumeric)
+                .take(CLIENT_SECRET_LENGTH)
+                .map(char::from)
+                .collect(),
+        )
+    }
+
+    /// Create a client secret from the plain value sent by a client
+    pub fn new(value: &str) -> Self {
+        Self(value.to_string())
+    }
+
+    /// Check if the secret matches one of the hashed client secrets
+    pub fn matches(&self, hashed_secrets: &[Password]) -> bool {
+        hashed_secrets.iter().any(|hashed| hashed.verify(&self.0).is_ok())
+    }
+
+    /// Get plain value
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+
+    /// Hash the secret before storing it, with the Argon2 parameters of the password policy
+    pub fn hash(&self, policy: &PasswordPolicy) -> Result<Password, PasswordError> {
+        Password::hash(&self.0, policy)
+    }
+}
+
+/// The plain secret must never be displayed in logs
+impl Debug for ClientSecret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ClientSecret(***)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_secret_hash() {
+        let secret = ClientSecret::generate();
+        let hashed = secret.hash(&PasswordPolicy::default()).unwrap();
+
+        assert!(hashed.verify(secret.value()).is_ok());
+        assert!(hashed.verify("invalid").is_err());
+        assert_eq!(format!("{secret:?}"), "ClientSecret(***)");
+    }
+
+    #[test]
+    fn test_client_secret_matches() {
+        let secret = ClientSecret::generate();
+        let other = ClientSecret::generate();
+        let hashed = vec![
+            other.hash(&PasswordPolicy::default()).unwrap(),
+            secret.hash(&PasswordPolicy::default()).unwrap(),
+        ];
+
+        assert!(secret.matches(&hashed));
+        assert!(other.matches(&hashed));
+        assert!(!ClientSecret::new("invalid").matches(&hashed));
+        assert!(!secret.matches(&[]));
+    }
+}
@@ -0,0 +1,85 @@
+//! Locale value object
+
+use regex::Regex;
+use std::{
+    fmt::{Display, Formatter},
+    sync::LazyLock,
+};
+use thiserror::Error;
+
+/// Locale regex validation
+/// A language code (2 or 3 letters) optionally followed by a region code (Ex.: `fr`, `fr-CA`, `en_US`)
+static LOCALE_REGEX: LazyLock<Result<Regex, LocaleError>> = LazyLock::new(|| {
+    Regex::new(r"^(?<language>[a-zA-Z]{2,3})(?:[-_](?<region>[a-zA-Z]{2}|[0-9]{3}))?$")
+        .map_err(|_| LocaleError::RegexError())
+});
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum LocaleError {
+    #[error("Invalid locale regex")]
+    RegexError(),
+
+    #[error("{0}")]
+    Invalid(String),
+}
+
+/// User language, used to localize the emails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Locale {
+    value: String,
+}
+
+impl Locale {
+    /// Create, validate and normalize a new locale (`language` or `language-REGION`)
+    ///
+    /// # Example
+    /// ```rust
+    /// use auth2_api::domain::value_objects::locale::Locale;
+    ///
+    /// assert_eq!(Locale::new("fr").unwrap().value(), "fr");
+    /// assert_eq!(Locale::new("FR_ca").unwrap().value(), "fr-CA");
+    /// assert_eq!(Locale::new("es-419").unwrap().value(), "es-419");
+    ///
+    /// assert!(Locale::new("").is_err());
+    /// assert!(Locale::new("french").is_err());
+    /// assert!(Locale::new("fr-").is_err());
+    /// ```
+    pub fn new(value: &str) -> Result<Self, LocaleError> {
+        let regex = LOCALE_REGEX.clone()?;
+        let captures = regex.captures(value.trim()).ok_or_else(|| {
+            LocaleError::Invalid("Invalid locale (language code optionally followed by a region code)".to_string())
+        })?;
+
+        let language = captures["language"].to_lowercase();
+        let value = match captures.name("region") {
+            Some(region) => format!("{language}-{}", region.as_str().to_uppercase()),
+            None => language,
+        };
+
+        Ok(Self { value })
+    }
+
+    /// Get locale value
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Get the language code, without region
+    ///
+    /// # Example
+    /// ```rust
+    /// use auth2_api::domain::value_objects::locale::Locale;
+    ///
+    /// assert_eq!(Locale::new("fr-CA").unwrap().language(), "fr");
+    /// assert_eq!(Locale::new("en").unwrap().language(), "en");
+    /// ```
+    pub fn language(&self) -> &str {
+        self.value.split('-').next().unwrap_or(&self.value)
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
@@ -1,11 +1,16 @@
 //! Value objects are immutable objects that represent a value.
 //! They are used to validate the input of the application and to ensure that the data is always in a valid state.
 
+pub mod client_secret;
 pub mod datetime;
 pub mod email;
 pub mod id;
+pub mod locale;
+pub mod oidc_provider;
 pub mod pagination;
 pub mod password;
+pub mod password_policy;
 pub mod query_filter;
 pub mod query_sort;
 pub mod scope_id;
+pub mod webhook_secret;
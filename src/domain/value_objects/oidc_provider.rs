@@ -0,0 +1,113 @@
+//! OpenID Connect provider value object representation
+
+use std::fmt::{Debug, Formatter};
+use thiserror::Error;
+use validator::Validate;
+
+/// Scope required by OpenID Connect to get an ID token
+pub const OPENID_SCOPE: &str = "openid";
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum OidcProviderError {
+    #[error("Invalid OpenID Connect provider: {0}")]
+    Invalid(#[from] validator::ValidationErrors),
+
+    #[error("Invalid OpenID Connect scope: `{0}`")]
+    InvalidScope(String),
+}
+
+/// OpenID Connect provider configuration of an external link
+#[derive(Clone, PartialEq, Eq, Validate)]
+pub struct OidcProvider {
+    /// Issuer URL used for discovery (`{issuer}/.well-known/openid-configuration`)
+    #[validate(url, length(max = 255))]
+    issuer: String,
+
+    /// Client ID registered on the provider
+    #[validate(length(min = 1, max = 255))]
+    client_id: String,
+
+    /// Client secret registered on the provider
+    #[validate(length(min = 1, max = 255))]
+    client_secret: String,
+
+    /// Requested scopes, `openid` is always included
+    scopes: Vec<String>,
+}
+
+impl OidcProvider {
+    /// Create and validate a new provider
+    ///
+    /// # Example
+    /// ```rust
+    /// use auth2_api::domain::value_objects::oidc_provider::OidcProvider;
+    ///
+    /// let provider = OidcProvider::new("https://idp.test.com", "client", "secret", vec!["email".to_string()]);
+    /// assert!(provider.is_ok());
+    /// assert_eq!(provider.unwrap().scopes(), &["openid".to_string(), "email".to_string()]);
+    ///
+    /// let provider = OidcProvider::new("idp", "client", "secret", vec![]);
+    /// assert!(provider.is_err());
+    ///
+    /// let provider = OidcProvider::new("https://idp.test.com", "client", "secret", vec!["e mail".to_string()]);
+    /// assert!(provider.is_err());
+    /// ```
+    pub fn new(
+        issuer: &str,
+        client_id: &str,
+        client_secret: &str,
+        scopes: Vec<String>,
+    ) -> Result<Self, OidcProviderError> {
+        if let Some(scope) = scopes
+            .iter()
+            .find(|scope| scope.is_empty() || scope.chars().any(char::is_whitespace))
+        {
+            return Err(OidcProviderError::InvalidScope(scope.clone()));
+        }
+
+        let mut all_scopes = vec![OPENID_SCOPE.to_string()];
+        all_scopes.extend(scopes.into_iter().filter(|scope| scope != OPENID_SCOPE));
+
+        let provider = Self {
+            issuer: issuer.trim_end_matches('/').to_string(),
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            scopes: all_scopes,
+        };
+        provider.validate()?;
+
+        Ok(provider)
+    }
+
+    /// Get issuer URL
+    pub fn issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    /// Get client ID
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    /// Get client secret
+    pub fn client_secret(&self) -> &str {
+        &self.client_secret
+    }
+
+    /// Get scopes
+    pub fn scopes(&self) -> &[String] {
+        &self.scopes
+    }
+}
+
+/// The client secret must never be displayed in logs
+impl Debug for OidcProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OidcProvider")
+            .field("issuer", &self.issuer)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &"***")
+            .field("scopes", &self.scopes)
+            .finish()
+    }
+}
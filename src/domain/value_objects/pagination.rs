@@ -1,12 +1,94 @@
 //! Pagination value object representation
+//!
+//! Two modes are available:
+//! - offset: the page number and the limit (`LIMIT/OFFSET`)
+//! - cursor (keyset): the limit and an opaque cursor pointing to the last item of the previous page
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use std::fmt::Display;
+use std::str::FromStr;
+use thiserror::Error;
 
 /// Pagination max limit
 pub const PAGINATION_MAX_LIMIT: u32 = 500;
 
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PaginationError {
+    #[error("Invalid pagination cursor")]
+    InvalidCursor(),
+}
+
+/// Pagination cursor: sort key and ID of the last item of a page
+///
+/// It is exposed as an opaque base64 (URL safe) string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaginationCursor {
+    value: Option<String>,
+    id: String,
+}
+
+impl PaginationCursor {
+    /// Create a new cursor
+    pub fn new(value: Option<String>, id: String) -> Self {
+        Self { value, id }
+    }
+
+    /// Get the sort key value (`None` if the value is `NULL`)
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    /// Get the ID
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl Display for PaginationCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let json = serde_json::to_string(&(&self.value, &self.id)).map_err(|_| std::fmt::Error)?;
+
+        write!(f, "{}", URL_SAFE_NO_PAD.encode(json))
+    }
+}
+
+impl FromStr for PaginationCursor {
+    type Err = PaginationError;
+
+    /// Decode a cursor
+    ///
+    /// # Example
+    /// ```
+    /// use auth2_api::domain::value_objects::pagination::PaginationCursor;
+    /// use std::str::FromStr;
+    ///
+    /// let cursor = PaginationCursor::new(Some("Doe".to_string()), "1".to_string());
+    /// assert_eq!(PaginationCursor::from_str(&cursor.to_string()), Ok(cursor));
+    ///
+    /// assert!(PaginationCursor::from_str("invalid").is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = URL_SAFE_NO_PAD
+            .decode(s)
+            .map_err(|_| PaginationError::InvalidCursor())?;
+        let (value, id) =
+            serde_json::from_slice::<(Option<String>, String)>(&json).map_err(|_| PaginationError::InvalidCursor())?;
+
+        Ok(Self { value, id })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Pagination {
     page: u32,
     limit: u32,
+
+    /// Cursor mode (keyset pagination)
+    keyset: bool,
+
+    /// Cursor of the last item of the previous page (`None` for the first page)
+    cursor: Option<PaginationCursor>,
 }
 
 impl Pagination {
@@ -39,7 +121,38 @@ impl Pagination {
             limit
         };
 
-        Self { page, limit }
+        Self {
+            page,
+            limit,
+            keyset: false,
+            cursor: None,
+        }
+    }
+
+    /// Create a new pagination in cursor mode
+    ///
+    /// The page is ignored in cursor mode, the first page has no cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use auth2_api::domain::value_objects::pagination::{Pagination, PaginationCursor};
+    ///
+    /// let pagination = Pagination::new_cursor(None, 100);
+    /// assert!(pagination.is_cursor());
+    /// assert_eq!(pagination.cursor(), None);
+    ///
+    /// let cursor = PaginationCursor::new(None, "1".to_string());
+    /// let pagination = Pagination::new_cursor(Some(cursor.clone()), 100);
+    /// assert_eq!(pagination.cursor(), Some(&cursor));
+    /// assert_eq!(pagination.limit(), 100);
+    /// ```
+    pub fn new_cursor(cursor: Option<PaginationCursor>, limit: u32) -> Self {
+        Self {
+            keyset: true,
+            cursor,
+            ..Self::new(1, limit)
+        }
     }
 
     /// Get page
@@ -51,6 +164,16 @@ impl Pagination {
     pub fn limit(&self) -> u32 {
         self.limit
     }
+
+    /// Is cursor mode?
+    pub fn is_cursor(&self) -> bool {
+        self.keyset
+    }
+
+    /// Get cursor
+    pub fn cursor(&self) -> Option<&PaginationCursor> {
+        self.cursor.as_ref()
+    }
 }
 
 impl Default for Pagination {
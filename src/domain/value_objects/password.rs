@@ -2,48 +2,42 @@
 //!
 //! Attention: argon2 is slow!
 
+use crate::domain::value_objects::password_policy::PasswordPolicy;
 use argon2::password_hash::SaltString;
 use argon2::password_hash::rand_core::OsRng;
-use argon2::{Algorithm, Argon2, Params, PasswordHash, PasswordHasher, PasswordVerifier, Version};
-use passwords::{analyzer, scorer};
-use std::fmt::{Display, Formatter};
+use argon2::{Algorithm, Argon2, Params, PasswordHash, PasswordHasher, PasswordVerifier};
+use std::fmt::{Debug, Display, Formatter};
 use thiserror::Error;
-use validator::Validate;
-
-/// Minimum password score to be accepted
-const SCORE_MIN: f64 = 80.0;
 
 #[derive(Debug, Clone, PartialEq, Error)]
 pub enum PasswordError {
-    #[error("Invalid password: {0}")]
-    Invalid(#[from] validator::ValidationErrors),
+    #[error("Password must contain at least {0} characters")]
+    TooShort(usize),
+
+    #[error("Password must contain at least one {0}")]
+    MissingCharacterClass(String),
+
+    #[error("Password is too common")]
+    CommonPassword(),
 
     #[error("Password hash error: {0}")]
     HashError(String),
 
     #[error("Password is not strong enough")]
     PasswordNotStrong(),
+
+    #[error("Password is empty")]
+    Empty(),
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Eq, Validate)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Password {
-    #[validate(length(min = 8))]
     value: String,
     original: Option<String>,
 }
 
 impl Password {
-    /// Create and validate a new hashed password
-    ///
-    /// Password scores:
-    /// - 0 ~ 20 is very dangerous
-    /// - 20 ~ 40 is dangerous
-    /// - 40 ~ 60 is very weak
-    /// - 60 ~ 80 is weak
-    /// - 80 ~ 90 is good
-    /// - 90 ~ 95 is strong
-    /// - 95 ~ 99 is very strong
-    /// - 99 ~ 100 is invulnerable
+    /// Create and validate a new hashed password with the default password policy
     ///
     /// # Example
     /// ```rust
@@ -68,36 +62,72 @@ impl Password {
     /// assert!(Password::new(&invalid_password, false).is_err());
     /// ```
     pub fn new(value: &str, hashed: bool) -> Result<Self, PasswordError> {
-        // Is password strong enough?
-        if !hashed && scorer::score(&analyzer::analyze(value)) < SCORE_MIN {
-            return Err(PasswordError::PasswordNotStrong());
-        }
-
-        let mut password = Self {
-            value: value.to_string(),
-            original: None,
-        };
+        Self::new_with_policy(value, hashed, &PasswordPolicy::default())
+    }
 
-        if !hashed {
-            password.validate()?;
+    /// Create a new hashed password which must respect the password policy
+    ///
+    /// If `hashed` is `true`, `value` is an already hashed password and is not checked.
+    pub fn new_with_policy(value: &str, hashed: bool, policy: &PasswordPolicy) -> Result<Self, PasswordError> {
+        if hashed {
+            return Ok(Self {
+                value: value.to_string(),
+                original: None,
+            });
+        }
 
-            password.original = Some(value.to_string());
+        // Does the password respect the policy?
+        policy.check(value)?;
 
-            // Hash password
-            let argon2 = Argon2::new(
-                Algorithm::Argon2id,
-                Version::default(),
-                Params::new(512, 2, 1, None).map_err(|err| PasswordError::HashError(err.to_string()))?,
-            );
-            let salt = SaltString::generate(&mut OsRng);
+        Self::hash(value, policy)
+    }
 
-            password.value = argon2
-                .hash_password(value.as_bytes(), &salt)
-                .map_err(|err| PasswordError::HashError(err.to_string()))?
-                .to_string();
-        }
+    /// Hash a plain password with the policy argon2 parameters, without checking its strength
+    pub fn hash(value: &str, policy: &PasswordPolicy) -> Result<Self, PasswordError> {
+        let salt = SaltString::generate(&mut OsRng);
+        let hash = policy
+            .argon2()?
+            .hash_password(value.as_bytes(), &salt)
+            .map_err(|err| PasswordError::HashError(err.to_string()))?
+            .to_string();
+
+        Ok(Self {
+            value: hash,
+            original: Some(value.to_string()),
+        })
+    }
 
-        Ok(password)
+    /// Hash matching no password, with the policy argon2 parameters
+    ///
+    /// Verifying it costs as much as verifying a user password, so that a login with an unknown email
+    /// takes the same time as a login with a wrong password.
+    ///
+    /// # Example
+    /// ```rust
+    /// use auth2_api::domain::value_objects::password::Password;
+    /// use auth2_api::domain::value_objects::password_policy::PasswordPolicy;
+    ///
+    /// let policy = PasswordPolicy::default();
+    /// let dummy = Password::dummy(&policy).unwrap();
+    /// assert!(!dummy.needs_rehash(&policy));
+    ///
+    /// // The hash is verified (it is not rejected as malformed)
+    /// let password = Password::new("1A,R;(9h0Y&gYH5=7eY!ff", false).unwrap();
+    /// assert!(dummy.verify("1A,R;(9h0Y&gYH5=7eY!ff").is_err());
+    /// assert_eq!(dummy.verify("1A,R;(9h0Y&gYH5=7eY!ff"), password.verify("wrong password"));
+    /// ```
+    pub fn dummy(policy: &PasswordPolicy) -> Result<Self, PasswordError> {
+        let params = policy.argon2_params()?;
+
+        Ok(Self {
+            value: format!(
+                "$argon2id$v=19$m={},t={},p={}$ZHVtbXlzYWx0ZHVtbXlzYWx0$AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+                params.m_cost(),
+                params.t_cost(),
+                params.p_cost()
+            ),
+            original: None,
+        })
     }
 
     /// Get password value
@@ -124,17 +154,45 @@ impl Password {
     /// assert!(hashed_password.verify("1234567").is_err());
     /// ```
     pub fn verify(&self, password: &str) -> Result<(), PasswordError> {
-        let argon2 = Argon2::new(
-            Algorithm::Argon2id,
-            Version::default(),
-            Params::new(512, 2, 1, None).map_err(|err| PasswordError::HashError(err.to_string()))?,
-        );
         let parsed_hash = PasswordHash::new(&self.value).map_err(|err| PasswordError::HashError(err.to_string()))?;
 
-        argon2
+        // Argon2 parameters used for the verification are the ones stored in the hash
+        Argon2::default()
             .verify_password(password.as_bytes(), &parsed_hash)
             .map_err(|err| PasswordError::HashError(err.to_string()))
     }
+
+    /// Check if the hash has been generated with other algorithm or weaker parameters than the policy ones
+    ///
+    /// # Example
+    /// ```rust
+    /// use auth2_api::domain::value_objects::password::Password;
+    /// use auth2_api::domain::value_objects::password_policy::PasswordPolicy;
+    ///
+    /// let password = Password::new("1A,R;(9h0Y&gYH5=7eY!ff", false).unwrap();
+    /// assert!(!password.needs_rehash(&PasswordPolicy::default()));
+    ///
+    /// let stronger_policy = PasswordPolicy {
+    ///     argon2_memory_cost: 1_024,
+    ///     ..Default::default()
+    /// };
+    /// assert!(password.needs_rehash(&stronger_policy));
+    /// ```
+    pub fn needs_rehash(&self, policy: &PasswordPolicy) -> bool {
+        let Ok(parsed_hash) = PasswordHash::new(&self.value) else {
+            return true;
+        };
+        if parsed_hash.algorithm != Algorithm::Argon2id.ident() {
+            return true;
+        }
+        let (Ok(current), Ok(expected)) = (Params::try_from(&parsed_hash), policy.argon2_params()) else {
+            return true;
+        };
+
+        current.m_cost() < expected.m_cost()
+            || current.t_cost() < expected.t_cost()
+            || current.p_cost() < expected.p_cost()
+    }
 }
 
 impl Display for Password {
@@ -142,3 +200,48 @@ impl Display for Password {
         write!(f, "{}", self.value)
     }
 }
+
+/// Plain password sent by a user to authenticate
+///
+/// Unlike [`Password`], it is neither checked against the password policy nor hashed:
+/// it is only used to verify a stored hash.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PlainPassword(String);
+
+impl PlainPassword {
+    /// Create a new plain password
+    ///
+    /// # Example
+    /// ```rust
+    /// use auth2_api::domain::value_objects::password::{Password, PlainPassword};
+    ///
+    /// let hashed_password = Password::new("1A,R;(9h0Y&gYH5=7eY!ff", false).unwrap();
+    ///
+    /// let password = PlainPassword::new("1A,R;(9h0Y&gYH5=7eY!ff").unwrap();
+    /// assert!(hashed_password.verify(password.value()).is_ok());
+    ///
+    /// // Weak passwords are accepted, they are only verified
+    /// assert!(PlainPassword::new("AAAAAAAA").is_ok());
+    ///
+    /// assert!(PlainPassword::new("").is_err());
+    /// ```
+    pub fn new(value: &str) -> Result<Self, PasswordError> {
+        if value.is_empty() {
+            return Err(PasswordError::Empty());
+        }
+
+        Ok(Self(value.to_string()))
+    }
+
+    /// Get plain password value
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+/// The plain password must never be displayed in logs
+impl Debug for PlainPassword {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PlainPassword(***)")
+    }
+}
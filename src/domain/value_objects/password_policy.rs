@@ -0,0 +1,261 @@
+//! Password policy used to validate and hash passwords
+
+use crate::config::Config;
+use crate::domain::value_objects::password::PasswordError;
+use argon2::{Algorithm, Argon2, Params, Version};
+use passwords::{analyzer, scorer};
+
+/// Default minimum password score
+const DEFAULT_SCORE_MIN: f64 = 80.0;
+
+/// Default minimum password length
+const DEFAULT_LENGTH_MIN: usize = 8;
+
+/// Default argon2 memory cost (in KiB)
+const DEFAULT_ARGON2_MEMORY_COST: u32 = 512;
+
+/// Default argon2 number of iterations
+const DEFAULT_ARGON2_ITERATIONS: u32 = 2;
+
+/// Default argon2 degree of parallelism
+const DEFAULT_ARGON2_PARALLELISM: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PasswordPolicy {
+    /// Minimum password score (from 0 to 100)
+    pub score_min: f64,
+
+    /// Minimum password length
+    pub length_min: usize,
+
+    /// At least one lowercase letter is required
+    pub require_lowercase: bool,
+
+    /// At least one uppercase letter is required
+    pub require_uppercase: bool,
+
+    /// At least one number is required
+    pub require_number: bool,
+
+    /// At least one symbol is required
+    pub require_symbol: bool,
+
+    /// Reject common passwords
+    pub reject_common: bool,
+
+    /// Argon2 memory cost (in KiB)
+    pub argon2_memory_cost: u32,
+
+    /// Argon2 number of iterations
+    pub argon2_iterations: u32,
+
+    /// Argon2 degree of parallelism
+    pub argon2_parallelism: u32,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            score_min: DEFAULT_SCORE_MIN,
+            length_min: DEFAULT_LENGTH_MIN,
+            require_lowercase: false,
+            require_uppercase: false,
+            require_number: false,
+            require_symbol: false,
+            reject_common: true,
+            argon2_memory_cost: DEFAULT_ARGON2_MEMORY_COST,
+            argon2_iterations: DEFAULT_ARGON2_ITERATIONS,
+            argon2_parallelism: DEFAULT_ARGON2_PARALLELISM,
+        }
+    }
+}
+
+impl From<Config> for PasswordPolicy {
+    fn from(config: Config) -> Self {
+        Self {
+            score_min: config.password_score_min,
+            length_min: config.password_length_min,
+            require_lowercase: config.password_require_lowercase,
+            require_uppercase: config.password_require_uppercase,
+            require_number: config.password_require_number,
+            require_symbol: config.password_require_symbol,
+            reject_common: config.password_reject_common,
+            argon2_memory_cost: config.argon2_memory_cost,
+            argon2_iterations: config.argon2_iterations,
+            argon2_parallelism: config.argon2_parallelism,
+        }
+    }
+}
+
+impl PasswordPolicy {
+    /// Check that a plain password respects the policy
+    ///
+    /// Password scores:
+    /// - 0 ~ 20 is very dangerous
+    /// - 20 ~ 40 is dangerous
+    /// - 40 ~ 60 is very weak
+    /// - 60 ~ 80 is weak
+    /// - 80 ~ 90 is good
+    /// - 90 ~ 95 is strong
+    /// - 95 ~ 99 is very strong
+    /// - 99 ~ 100 is invulnerable
+    ///
+    /// # Example
+    /// ```rust
+    /// use auth2_api::domain::value_objects::password::PasswordError;
+    /// use auth2_api::domain::value_objects::password_policy::PasswordPolicy;
+    ///
+    /// let policy = PasswordPolicy {
+    ///     require_symbol: true,
+    ///     ..Default::default()
+    /// };
+    /// assert!(policy.check("1A,R;(9h0Y&gYH5=7eY!ff").is_ok());
+    /// assert_eq!(policy.check("1234567"), Err(PasswordError::TooShort(8)));
+    /// assert_eq!(
+    ///     policy.check("1AbR09h0YmgYH5o7eYsff"),
+    ///     Err(PasswordError::MissingCharacterClass("symbol".to_string()))
+    /// );
+    /// ```
+    pub fn check(&self, password: &str) -> Result<(), PasswordError> {
+        let analyzed = analyzer::analyze(password);
+
+        if analyzed.length() < self.length_min {
+            return Err(PasswordError::TooShort(self.length_min));
+        }
+
+        let classes = [
+            (
+                self.require_lowercase,
+                analyzed.lowercase_letters_count(),
+                "lowercase letter",
+            ),
+            (
+                self.require_uppercase,
+                analyzed.uppercase_letters_count(),
+                "uppercase letter",
+            ),
+            (self.require_number, analyzed.numbers_count(), "number"),
+            (self.require_symbol, analyzed.symbols_count(), "symbol"),
+        ];
+        if let Some((_, _, class)) = classes.iter().find(|(required, count, _)| *required && *count == 0) {
+            return Err(PasswordError::MissingCharacterClass(class.to_string()));
+        }
+
+        if self.reject_common && analyzed.is_common() {
+            return Err(PasswordError::CommonPassword());
+        }
+
+        if scorer::score(&analyzed) < self.score_min {
+            return Err(PasswordError::PasswordNotStrong());
+        }
+
+        Ok(())
+    }
+
+    /// Argon2 parameters
+    pub fn argon2_params(&self) -> Result<Params, PasswordError> {
+        Params::new(
+            self.argon2_memory_cost,
+            self.argon2_iterations,
+            self.argon2_parallelism,
+            None,
+        )
+        .map_err(|err| PasswordError::HashError(err.to_string()))
+    }
+
+    /// Argon2 hasher
+    pub fn argon2(&self) -> Result<Argon2<'static>, PasswordError> {
+        Ok(Argon2::new(
+            Algorithm::Argon2id,
+            Version::default(),
+            self.argon2_params()?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_length() {
+        let policy = PasswordPolicy {
+            score_min: 0.0,
+            length_min: 12,
+            ..Default::default()
+        };
+
+        assert_eq!(policy.check("aZ3;kP9!"), Err(PasswordError::TooShort(12)));
+        assert!(policy.check("aZ3;kP9!xW2@").is_ok());
+    }
+
+    #[test]
+    fn test_check_character_classes() {
+        let policy = PasswordPolicy {
+            score_min: 0.0,
+            require_lowercase: true,
+            require_uppercase: true,
+            require_number: true,
+            require_symbol: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            policy.check("AZ3;KP9!XW"),
+            Err(PasswordError::MissingCharacterClass("lowercase letter".to_string()))
+        );
+        assert_eq!(
+            policy.check("az3;kp9!xw"),
+            Err(PasswordError::MissingCharacterClass("uppercase letter".to_string()))
+        );
+        assert_eq!(
+            policy.check("aZb;kPc!xW"),
+            Err(PasswordError::MissingCharacterClass("number".to_string()))
+        );
+        assert_eq!(
+            policy.check("aZ3bkP9cxW"),
+            Err(PasswordError::MissingCharacterClass("symbol".to_string()))
+        );
+        assert!(policy.check("aZ3;kP9!xW").is_ok());
+    }
+
+    #[test]
+    fn test_check_common_password() {
+        let policy = PasswordPolicy {
+            score_min: 0.0,
+            ..Default::default()
+        };
+        assert_eq!(policy.check("password"), Err(PasswordError::CommonPassword()));
+
+        let policy = PasswordPolicy {
+            reject_common: false,
+            ..policy
+        };
+        assert!(policy.check("password").is_ok());
+    }
+
+    #[test]
+    fn test_check_score() {
+        let policy = PasswordPolicy {
+            reject_common: false,
+            ..Default::default()
+        };
+        assert_eq!(policy.check("abcdefgh1"), Err(PasswordError::PasswordNotStrong()));
+        assert!(policy.check("1A,R;(9h0Y&gYH5=7eY!ff").is_ok());
+    }
+
+    #[test]
+    fn test_argon2_params() {
+        let policy = PasswordPolicy::default();
+        let params = policy.argon2_params().unwrap();
+        assert_eq!(params.m_cost(), DEFAULT_ARGON2_MEMORY_COST);
+        assert_eq!(params.t_cost(), DEFAULT_ARGON2_ITERATIONS);
+        assert_eq!(params.p_cost(), DEFAULT_ARGON2_PARALLELISM);
+
+        let policy = PasswordPolicy {
+            argon2_memory_cost: 0,
+            ..Default::default()
+        };
+        assert!(policy.argon2_params().is_err());
+    }
+}
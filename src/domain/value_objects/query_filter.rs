@@ -1,16 +1,37 @@
 //! Query filters value object representation
 //!
+//! A filter is a query parameter with the field name as key and a list of conditions as value.
+//! Each condition is `<operator>:<value>` and the conditions are separated by commas.
+//! The `in` and `nin` operators take several values separated by commas.
+//! A value without operator is an equality condition.
+//!
 //! Example:
-//! <base_url>?price=gt:100,le:200&name=in:John&created_at=gte:2021-01-01
-
-// TODO: Add tests and improve documentation
+//! <base_url>?price=gt:100,lte:200&name=in:John,Jane&created_at=gte:2021-01-01
 
-use std::collections::HashMap;
-use std::hash::Hash;
+use std::collections::{BTreeMap, HashMap};
 use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum QueryFilterError {
+    #[error("`{0}` is not a valid filter operator")]
+    InvalidOperator(String),
+
+    #[error("`{0}` is not a valid filter value")]
+    InvalidValue(String),
+
+    #[error("Filter value `{0}` must be preceded by an operator")]
+    MissingOperator(String),
+
+    #[error("Filter value cannot be empty")]
+    EmptyValue(),
+}
+
+/// Query filter field (Ex.: "name", "email", "created_at")
+pub type QueryFilterField = String;
 
 /// Query filter operator
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum QueryFilterOperator {
     /// Equal
     Eq,
@@ -37,8 +58,15 @@ pub enum QueryFilterOperator {
     Nin,
 }
 
+impl QueryFilterOperator {
+    /// Operator with a list of values
+    pub fn is_multiple(&self) -> bool {
+        matches!(self, Self::In | Self::Nin)
+    }
+}
+
 impl FromStr for QueryFilterOperator {
-    type Err = String;
+    type Err = QueryFilterError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
@@ -50,25 +78,24 @@ impl FromStr for QueryFilterOperator {
             "lte" => Ok(Self::Lte),
             "in" => Ok(Self::In),
             "nin" => Ok(Self::Nin),
-            err => Err(format!("{err} in not a valid filter operator")),
+            err => Err(QueryFilterError::InvalidOperator(err.to_string())),
         }
     }
 }
 
-/// Query filter field name (Ex.: "name", "email", "created_at")
-pub trait QueryFilterFieldName: PartialEq + Eq + Hash {}
-
-/// Query filter field value containing the operator and the value
+/// Query filter field value containing the operator and the values
+///
+/// `in` and `nin` operators can have several values, the other operators have only one.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryFilterFieldValue<V> {
     pub operator: QueryFilterOperator,
-    pub value: V,
+    pub values: Vec<V>,
 }
 
 impl<V> QueryFilterFieldValue<V> {
     /// Create a new filter field value
-    pub fn new(operator: QueryFilterOperator, value: V) -> Self {
-        Self { operator, value }
+    pub fn new(operator: QueryFilterOperator, values: Vec<V>) -> Self {
+        Self { operator, values }
     }
 }
 
@@ -88,25 +115,196 @@ impl<V> QueryFilterFieldValues<V> {
     }
 }
 
-impl<V> FromStr for QueryFilterFieldValues<V> {
-    type Err = String; // TODO: Change to a custom error type
+impl<V: FromStr> FromStr for QueryFilterFieldValues<V> {
+    type Err = QueryFilterError;
+
+    /// Parse the conditions of a field
+    ///
+    /// # Example
+    /// ```
+    /// use auth2_api::domain::value_objects::query_filter::{
+    ///     QueryFilterFieldValue, QueryFilterFieldValues, QueryFilterOperator,
+    /// };
+    /// use std::str::FromStr;
+    ///
+    /// let values = QueryFilterFieldValues::<u32>::from_str("gt:100,lte:200").unwrap();
+    /// assert_eq!(
+    ///     values.value(),
+    ///     &vec![
+    ///         QueryFilterFieldValue::new(QueryFilterOperator::Gt, vec![100]),
+    ///         QueryFilterFieldValue::new(QueryFilterOperator::Lte, vec![200]),
+    ///     ]
+    /// );
+    ///
+    /// let values = QueryFilterFieldValues::<String>::from_str("in:foo,bar").unwrap();
+    /// assert_eq!(
+    ///     values.value(),
+    ///     &vec![QueryFilterFieldValue::new(
+    ///         QueryFilterOperator::In,
+    ///         vec!["foo".to_string(), "bar".to_string()]
+    ///     )]
+    /// );
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_value =
+            |value: &str| V::from_str(value).map_err(|_| QueryFilterError::InvalidValue(value.to_string()));
+        let mut conditions: Vec<QueryFilterFieldValue<V>> = Vec::new();
+
+        for part in s.split(',') {
+            // The prefix is an operator only if it contains only lowercase letters
+            // (Ex.: the `:` of `2024-01-01T10:00:00Z` is not an operator separator)
+            let condition = match part.split_once(':') {
+                Some((operator, value)) if !operator.is_empty() && operator.chars().all(|c| c.is_ascii_lowercase()) => {
+                    Some((QueryFilterOperator::from_str(operator)?, value))
+                }
+                _ => None,
+            };
+
+            match condition {
+                Some((_, "")) => return Err(QueryFilterError::EmptyValue()),
+                Some((operator, value)) => {
+                    conditions.push(QueryFilterFieldValue::new(operator, vec![parse_value(value)?]))
+                }
+                None if part.is_empty() => return Err(QueryFilterError::EmptyValue()),
+                None => match conditions.last_mut() {
+                    // Next value of a list
+                    Some(last) if last.operator.is_multiple() => last.values.push(parse_value(part)?),
+                    Some(_) => return Err(QueryFilterError::MissingOperator(part.to_string())),
+                    None => conditions.push(QueryFilterFieldValue::new(
+                        QueryFilterOperator::Eq,
+                        vec![parse_value(part)?],
+                    )),
+                },
+            }
+        }
 
-    fn from_str(_s: &str) -> Result<Self, Self::Err> {
-        todo!()
+        Ok(Self(conditions))
     }
 }
 
 /// Query filters value object
-pub struct QueryFilters<F: QueryFilterFieldName, V>(HashMap<F, QueryFilterFieldValues<V>>);
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryFilters<V = String>(BTreeMap<QueryFilterField, QueryFilterFieldValues<V>>);
 
-impl<F: QueryFilterFieldName, V> QueryFilters<F, V> {
+impl<V> QueryFilters<V> {
     /// Create a new query filters value object
-    pub fn new(filters: HashMap<F, QueryFilterFieldValues<V>>) -> Self {
+    pub fn new(filters: BTreeMap<QueryFilterField, QueryFilterFieldValues<V>>) -> Self {
         Self(filters)
     }
 
     /// Get the query filters
-    pub fn value(&self) -> &HashMap<F, QueryFilterFieldValues<V>> {
+    pub fn value(&self) -> &BTreeMap<QueryFilterField, QueryFilterFieldValues<V>> {
         &self.0
     }
 }
+
+impl<V: FromStr> TryFrom<HashMap<String, String>> for QueryFilters<V> {
+    type Error = QueryFilterError;
+
+    /// Parse filters from query parameters (field name => conditions)
+    fn try_from(params: HashMap<String, String>) -> Result<Self, Self::Error> {
+        params
+            .into_iter()
+            .map(|(field, values)| Ok((field, QueryFilterFieldValues::from_str(&values)?)))
+            .collect::<Result<BTreeMap<_, _>, _>>()
+            .map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn condition(operator: QueryFilterOperator, values: &[&str]) -> QueryFilterFieldValue<String> {
+        QueryFilterFieldValue::new(operator, values.iter().map(|value| value.to_string()).collect())
+    }
+
+    #[test]
+    fn test_query_filter_operator_from_str() {
+        assert_eq!(QueryFilterOperator::from_str("gte"), Ok(QueryFilterOperator::Gte));
+        assert_eq!(QueryFilterOperator::from_str("nin"), Ok(QueryFilterOperator::Nin));
+        assert_eq!(
+            QueryFilterOperator::from_str("le"),
+            Err(QueryFilterError::InvalidOperator("le".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_query_filter_field_values_from_str() {
+        let values = QueryFilterFieldValues::<String>::from_str("John").unwrap();
+        assert_eq!(values.value(), &vec![condition(QueryFilterOperator::Eq, &["John"])]);
+
+        let values = QueryFilterFieldValues::<String>::from_str("nin:foo,bar,baz").unwrap();
+        assert_eq!(
+            values.value(),
+            &vec![condition(QueryFilterOperator::Nin, &["foo", "bar", "baz"])]
+        );
+
+        let values = QueryFilterFieldValues::<String>::from_str("in:a,b,ne:c").unwrap();
+        assert_eq!(
+            values.value(),
+            &vec![
+                condition(QueryFilterOperator::In, &["a", "b"]),
+                condition(QueryFilterOperator::Ne, &["c"]),
+            ]
+        );
+
+        // `:` in value
+        let values = QueryFilterFieldValues::<String>::from_str("gte:2024-01-01T10:00:00Z,2024-01-02T10:00:00Z");
+        assert_eq!(
+            values,
+            Err(QueryFilterError::MissingOperator("2024-01-02T10:00:00Z".to_string()))
+        );
+        let values = QueryFilterFieldValues::<String>::from_str("2024-01-01T10:00:00Z").unwrap();
+        assert_eq!(
+            values.value(),
+            &vec![condition(QueryFilterOperator::Eq, &["2024-01-01T10:00:00Z"])]
+        );
+    }
+
+    #[test]
+    fn test_query_filter_field_values_from_str_errors() {
+        assert_eq!(
+            QueryFilterFieldValues::<String>::from_str("like:John"),
+            Err(QueryFilterError::InvalidOperator("like".to_string()))
+        );
+        assert_eq!(
+            QueryFilterFieldValues::<String>::from_str("eq:a,b"),
+            Err(QueryFilterError::MissingOperator("b".to_string()))
+        );
+        assert_eq!(
+            QueryFilterFieldValues::<String>::from_str(""),
+            Err(QueryFilterError::EmptyValue())
+        );
+        assert_eq!(
+            QueryFilterFieldValues::<String>::from_str("in:a,,b"),
+            Err(QueryFilterError::EmptyValue())
+        );
+        assert_eq!(
+            QueryFilterFieldValues::<String>::from_str("gt:"),
+            Err(QueryFilterError::EmptyValue())
+        );
+        assert_eq!(
+            QueryFilterFieldValues::<u32>::from_str("gt:ten"),
+            Err(QueryFilterError::InvalidValue("ten".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_query_filters_try_from_params() {
+        let params = HashMap::from([
+            ("name".to_string(), "in:foo,bar".to_string()),
+            ("created_at".to_string(), "gte:2024-01-01".to_string()),
+        ]);
+        let filters = QueryFilters::<String>::try_from(params).unwrap();
+
+        assert_eq!(filters.value().keys().collect::<Vec<_>>(), vec!["created_at", "name"]);
+        assert_eq!(
+            filters.value()["name"].value(),
+            &vec![condition(QueryFilterOperator::In, &["foo", "bar"])]
+        );
+
+        let params = HashMap::from([("name".to_string(), "like:foo".to_string())]);
+        assert!(QueryFilters::<String>::try_from(params).is_err());
+    }
+}
@@ -0,0 +1,96 @@
+//! Webhook secret value object representation
+
+use hmac::{Hmac, Mac};
+use rand::Rng;
+use rand::distr::Alphanumeric;
+use sha2::Sha256;
+use std::fmt::{Debug, Formatter};
+
+/// Prefix of a generated webhook secret
+const WEBHOOK_SECRET_PREFIX: &str = "whsec_";
+
+/// Length of a generated webhook secret (without prefix)
+const WEBHOOK_SECRET_LENGTH: usize = 40;
+
+/// Secret shared with a webhook receiver to sign the payloads
+///
+/// Unlike client secrets, it is stored in plain text because it is needed to sign each payload.
+#[derive(Clone, PartialEq, Eq)]
+pub struct WebhookSecret(String);
+
+impl WebhookSecret {
+    /// Generate a new random webhook secret
+    ///
+    /// # Example
+    /// ```rust
+    /// use auth2_api::domain::value_objects::webhook_secret::WebhookSecret;
+    ///
+    /// let secret = WebhookSecret::generate();
+    /// assert!(secret.value().starts_with("whsec_"));
+    /// assert_eq!(secret.value().len(), 46);
+    /// assert_ne!(secret, WebhookSecret::generate());
+    /// ```
+    pub fn generate() -> Self {
+        let value: String = rand::rng()
+            .sample_iter(&Alphanumeric)
+            .take(WEBHOOK_SECRET_LENGTH)
+            .map(char::from)
+            .collect();
+
+        Self(format!("{WEBHOOK_SECRET_PREFIX}{value}"))
+    }
+
+    /// Create a webhook secret from a stored value
+    pub fn new(value: &str) -> Self {
+        Self(value.to_string())
+    }
+
+    /// Get plain value
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+
+    /// Sign a payload with HMAC-SHA256
+    ///
+    /// The signed content is `<timestamp>.<payload>` to prevent replay attacks.
+    /// The signature is returned as a lowercase hexadecimal string.
+    ///
+    /// # Example
+    /// ```rust
+    /// use auth2_api::domain::value_objects::webhook_secret::WebhookSecret;
+    ///
+    /// let secret = WebhookSecret::new("whsec_test");
+    /// assert_eq!(secret.sign(1_700_000_000, "{}"), secret.sign(1_700_000_000, "{}"));
+    /// assert_ne!(secret.sign(1_700_000_000, "{}"), secret.sign(1_700_000_001, "{}"));
+    /// ```
+    pub fn sign(&self, timestamp: i64, payload: &str) -> String {
+        // HMAC accepts keys of any length
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.0.as_bytes()).expect("HMAC can take key of any size");
+        mac.update(format!("{timestamp}.{payload}").as_bytes());
+
+        hex::encode(mac.finalize().into_bytes())
+    }
+}
+
+/// The plain secret must never be displayed in logs
+impl Debug for WebhookSecret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "WebhookSecret(***)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_webhook_secret_sign() {
+        // Reference value computed with `openssl dgst -sha256 -hmac`
+        let secret = WebhookSecret::new("secret");
+        assert_eq!(
+            secret.sign(1, "payload"),
+            "0cb97d5610dee9645ed2ef52bff5370e436d4414b426199dc8c9aacc001d6c14"
+        );
+        assert_eq!(format!("{secret:?}"), "WebhookSecret(***)");
+    }
+}
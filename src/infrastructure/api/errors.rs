@@ -5,7 +5,11 @@ use crate::config::ConfigError;
 use crate::domain::services::security::jwt::JwtError;
 use crate::domain::value_objects::email::EmailError;
 use crate::domain::value_objects::id::IdError;
+use crate::domain::value_objects::locale::LocaleError;
+use crate::domain::value_objects::oidc_provider::OidcProviderError;
+use crate::domain::value_objects::pagination::PaginationError;
 use crate::domain::value_objects::password::PasswordError;
+use crate::domain::value_objects::query_filter::QueryFilterError;
 use crate::domain::value_objects::scope_id::ScopeIdError;
 use crate::infrastructure::api::response::ApiError;
 use axum::BoxError;
@@ -56,8 +60,32 @@ impl From<PasswordError> for ApiError {
     }
 }
 
+impl From<LocaleError> for ApiError {
+    fn from(value: LocaleError) -> Self {
+        Self::BadRequest(value.to_string())
+    }
+}
+
+impl From<PaginationError> for ApiError {
+    fn from(value: PaginationError) -> Self {
+        Self::BadRequest(value.to_string())
+    }
+}
+
+impl From<QueryFilterError> for ApiError {
+    fn from(value: QueryFilterError) -> Self {
+        Self::BadRequest(value.to_string())
+    }
+}
+
 impl From<ScopeIdError> for ApiError {
     fn from(value: ScopeIdError) -> Self {
         Self::BadRequest(value.to_string())
     }
 }
+
+impl From<OidcProviderError> for ApiError {
+    fn from(value: OidcProviderError) -> Self {
+        Self::BadRequest(value.to_string())
+    }
+}
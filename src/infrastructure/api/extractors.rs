@@ -1,15 +1,20 @@
 //! Custom Axum extractors
 
 use crate::domain::entities::access_token::AccessToken;
+use crate::domain::entities::audit_event::AuditContext;
+use crate::domain::entities::user::UserId;
 use crate::domain::services::security::jwt::Jwt;
 use crate::domain::services::security::payload::{Payload, PayloadError, PayloadExtractor};
 use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::infrastructure::api::layers::state::SharedState;
 use crate::infrastructure::api::response::ApiError;
-use axum::extract::FromRequestParts;
 use axum::extract::path::ErrorKind;
 use axum::extract::rejection::PathRejection;
+use axum::extract::{ConnectInfo, FromRequestParts};
 use axum::http::{HeaderMap, StatusCode, header, header::HeaderValue, request::Parts};
 use serde::de::DeserializeOwned;
+use std::net::{IpAddr, SocketAddr};
+use std::str::FromStr;
 
 /// Request ID extractor from HTTP headers
 pub struct ExtractRequestId(pub HeaderValue);
@@ -28,6 +33,68 @@ where
     }
 }
 
+/// Audit context extractor (actor, client IP and request ID)
+///
+/// The actor is only known on authenticated routes. The client IP is the connection peer address,
+/// unless the peer is a trusted proxy (see [`client_ip`]).
+pub struct ExtractAuditContext(pub AuditContext);
+
+impl FromRequestParts<SharedState> for ExtractAuditContext {
+    type Rejection = ();
+
+    async fn from_request_parts(parts: &mut Parts, state: &SharedState) -> Result<Self, Self::Rejection> {
+        let actor_id = Payload::try_from_headers(&parts.headers, &state.jwt)
+            .ok()
+            .and_then(|payload| UserId::from_str(&payload.sub).ok());
+
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+        let forwarded_for = parts.headers.get("x-forwarded-for").and_then(|h| h.to_str().ok());
+        let ip = client_ip(peer, forwarded_for, &state.config.trusted_proxies).map(|ip| ip.to_string());
+
+        let ExtractRequestId(request_id) = ExtractRequestId::from_request_parts(parts, state).await?;
+        let request_id = request_id
+            .to_str()
+            .ok()
+            .filter(|id| !id.is_empty())
+            .map(|id| id.to_string());
+
+        Ok(Self(AuditContext {
+            actor_id,
+            ip,
+            request_id,
+        }))
+    }
+}
+
+/// Client IP address of a request
+///
+/// `X-Forwarded-For` is only read when the peer is a trusted proxy: the client is the right-most
+/// address which is not a trusted proxy, because the left-most entries can be set by anyone.
+/// The walk stops at an invalid entry and keeps the last valid hop.
+fn client_ip(peer: Option<IpAddr>, forwarded_for: Option<&str>, trusted_proxies: &[IpAddr]) -> Option<IpAddr> {
+    let mut ip = peer?;
+    if !trusted_proxies.contains(&ip) {
+        return Some(ip);
+    }
+
+    for hop in forwarded_for.unwrap_or_default().rsplit(',') {
+        match IpAddr::from_str(hop.trim()) {
+            Ok(hop) => {
+                ip = hop;
+                if !trusted_proxies.contains(&ip) {
+                    break;
+                }
+            }
+            Err(_) => break,
+        }
+    }
+
+    Some(ip)
+}
+
 impl PayloadExtractor<HeaderMap> for Payload {
     fn try_from_headers(headers: &HeaderMap, jwt: &Jwt) -> Result<Payload, PayloadError> {
         let result = headers
@@ -153,3 +220,37 @@ where
         Ok(Query(value))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_ip() {
+        let proxy = IpAddr::from([10, 0, 0, 1]);
+        let other_proxy = IpAddr::from([10, 0, 0, 2]);
+        let client = IpAddr::from([192, 168, 1, 10]);
+        let trusted = [proxy, other_proxy];
+
+        // No trusted proxy
+        assert_eq!(client_ip(Some(client), Some("1.2.3.4"), &[]), Some(client));
+        assert_eq!(client_ip(None, Some("1.2.3.4"), &trusted), None);
+
+        // The peer is not a trusted proxy
+        assert_eq!(client_ip(Some(client), Some("1.2.3.4"), &trusted), Some(client));
+
+        // Right-most untrusted hop (the left-most entry is set by the client)
+        assert_eq!(
+            client_ip(Some(proxy), Some("1.2.3.4, 192.168.1.10, 10.0.0.2"), &trusted),
+            Some(client)
+        );
+
+        // Missing header, invalid entry or only trusted hops
+        assert_eq!(client_ip(Some(proxy), None, &trusted), Some(proxy));
+        assert_eq!(
+            client_ip(Some(proxy), Some("192.168.1.10, invalid, 10.0.0.2"), &trusted),
+            Some(other_proxy)
+        );
+        assert_eq!(client_ip(Some(proxy), Some("10.0.0.2"), &trusted), Some(other_proxy));
+    }
+}
@@ -1,5 +1,6 @@
 //! Applications handlers DTO
 
+use crate::domain::entities::audit_event::AuditContext;
 use crate::domain::use_cases::application::ApplicationUseCaseResponse;
 use crate::domain::use_cases::application::create_application::CreateApplicationUseCaseRequest;
 use crate::domain::use_cases::application::delete_application::DeleteApplicationUseCaseResponse;
@@ -7,6 +8,7 @@ use crate::domain::use_cases::application::get_applications::GetApplicationsUseC
 use crate::domain::use_cases::application::restore_application::RestoreApplicationUseCaseResponse;
 use crate::domain::use_cases::application::update_application::UpdateApplicationUseCaseResponse;
 use crate::infrastructure::api::handlers::filter::FilterRequest;
+use crate::infrastructure::api::response::{ApiPage, ApiPaginated};
 use serde::{Deserialize, Serialize};
 
 /// Application response
@@ -14,6 +16,12 @@ use serde::{Deserialize, Serialize};
 pub struct ApplicationResponse {
     pub id: String,
     pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_token_lifetime: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token_lifetime: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audience: Option<String>,
     pub created_at: String,
     pub updated_at: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -25,6 +33,9 @@ impl From<ApplicationUseCaseResponse> for ApplicationResponse {
         Self {
             id: value.id.to_string(),
             name: value.name,
+            access_token_lifetime: value.access_token_lifetime,
+            refresh_token_lifetime: value.refresh_token_lifetime,
+            audience: value.audience,
             created_at: value.created_at.to_string(),
             updated_at: value.updated_at.to_string(),
             deleted_at: value.deleted_at.map(|dt| dt.to_string()),
@@ -37,11 +48,21 @@ impl From<ApplicationUseCaseResponse> for ApplicationResponse {
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreateApplicationRequest {
     pub name: String,
+    pub access_token_lifetime: Option<u32>,
+    pub refresh_token_lifetime: Option<u32>,
+    pub audience: Option<String>,
 }
 
-impl From<CreateApplicationRequest> for CreateApplicationUseCaseRequest {
-    fn from(value: CreateApplicationRequest) -> Self {
-        Self { name: value.name }
+impl CreateApplicationRequest {
+    /// Convert into use case request
+    pub fn into_use_case_request(self, audit: AuditContext) -> CreateApplicationUseCaseRequest {
+        CreateApplicationUseCaseRequest {
+            name: self.name,
+            access_token_lifetime: self.access_token_lifetime,
+            refresh_token_lifetime: self.refresh_token_lifetime,
+            audience: self.audience,
+            audit,
+        }
     }
 }
 
@@ -49,17 +70,14 @@ impl From<CreateApplicationRequest> for CreateApplicationUseCaseRequest {
 
 pub type GetApplicationsRequest = FilterRequest;
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
-pub struct GetApplicationsResponse {
-    pub total: i64,
-    pub data: Vec<ApplicationResponse>,
-}
+pub type GetApplicationsResponse = ApiPaginated<ApplicationResponse>;
 
-impl From<GetApplicationsUseCaseResponse> for GetApplicationsResponse {
+impl From<GetApplicationsUseCaseResponse> for ApiPage<ApplicationResponse> {
     fn from(value: GetApplicationsUseCaseResponse) -> Self {
         Self {
-            total: value.total,
             data: value.applications.into_iter().map(|app| app.into()).collect(),
+            total: value.total,
+            next_cursor: value.next_cursor.map(|cursor| cursor.to_string()),
         }
     }
 }
@@ -80,6 +98,9 @@ impl From<DeleteApplicationUseCaseResponse> for DeleteApplicationResponse {
 #[derive(Debug, Clone, Deserialize)]
 pub struct UpdateApplicationRequest {
     pub name: String,
+    pub access_token_lifetime: Option<u32>,
+    pub refresh_token_lifetime: Option<u32>,
+    pub audience: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
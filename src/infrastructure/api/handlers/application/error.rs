@@ -8,8 +8,16 @@ impl From<ApplicationUseCaseError> for ApiError {
         match value {
             ApplicationUseCaseError::DatabaseError(msg) => ApiError::InternalServerError(msg),
             ApplicationUseCaseError::InvalidName(msg) => ApiError::BadRequest(msg),
+            ApplicationUseCaseError::InvalidTokenSettings(msg) => ApiError::BadRequest(msg),
             ApplicationUseCaseError::InvalidId() => ApiError::InternalServerError(value.to_string()),
             ApplicationUseCaseError::ApplicationNotFound() => ApiError::NotFound(value.to_string()),
+            ApplicationUseCaseError::ClientNotFound() => ApiError::NotFound(value.to_string()),
+            ApplicationUseCaseError::ClientSecretNotFound() => ApiError::NotFound(value.to_string()),
+            ApplicationUseCaseError::LastClientSecret() => ApiError::BadRequest(value.to_string()),
+            ApplicationUseCaseError::InvalidClient(msg) => ApiError::BadRequest(msg),
+            ApplicationUseCaseError::ClientSecretGenerationError(_) => {
+                ApiError::InternalServerError("Internal server error".to_string())
+            }
             ApplicationUseCaseError::FromModelError() => {
                 ApiError::InternalServerError("Internal server error".to_string())
             }
@@ -3,32 +3,34 @@
 mod dto;
 mod error;
 
-use crate::domain::use_cases::application::create_application::CreateApplicationUseCaseRequest;
+use crate::adapters::database::DatabaseRepositories;
 use crate::domain::use_cases::application::delete_application::DeleteApplicationUseCaseRequest;
 use crate::domain::use_cases::application::get_application::GetApplicationByIdUseCaseRequest;
 use crate::domain::use_cases::application::get_applications::GetApplicationsUseCaseRequest;
 use crate::domain::use_cases::application::restore_application::RestoreApplicationUseCaseRequest;
 use crate::domain::use_cases::application::update_application::UpdateApplicationUseCaseRequest;
 use crate::domain::value_objects::id::Id;
-use crate::infrastructure::api::extractors::{ExtractRequestId, Path, Query};
+use crate::infrastructure::api::extractors::{ExtractAuditContext, ExtractRequestId, Path, Query};
 use crate::infrastructure::api::handlers::application::dto::*;
-use crate::infrastructure::api::response::{ApiError, ApiSuccess};
+use crate::infrastructure::api::handlers::filter::QueryFiltersRequest;
+use crate::infrastructure::api::response::{ApiError, ApiPaginated, ApiSuccess};
 use crate::infrastructure::api::use_cases::AppUseCases;
+use axum::extract::OriginalUri;
 use axum::http::StatusCode;
 use axum::{Extension, Json};
 use std::str::FromStr;
 
 /// Application creation route: POST /api/v1/applications
 #[instrument(skip(uc), name = "create_application_handler")]
-pub async fn create(
-    Extension(uc): Extension<AppUseCases>,
-    ExtractRequestId(request_id): ExtractRequestId,
+pub async fn create<D: DatabaseRepositories>(
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
     Json(request): Json<CreateApplicationRequest>,
 ) -> Result<ApiSuccess<ApplicationResponse>, ApiError> {
     let response = uc
         .application
         .create_application
-        .call(CreateApplicationUseCaseRequest::from(request))
+        .call(request.into_use_case_request(audit))
         .await?;
 
     Ok(ApiSuccess::new(StatusCode::CREATED, response.into()))
@@ -36,9 +38,9 @@ pub async fn create(
 
 /// Get application by ID route: GET /api/v1/applications/:application_id
 #[instrument(skip(uc), name = "get_application_by_id_handler")]
-pub async fn get_by_id(
+pub async fn get_by_id<D: DatabaseRepositories>(
     Path(application_id): Path<String>,
-    Extension(uc): Extension<AppUseCases>,
+    Extension(uc): Extension<AppUseCases<D>>,
     ExtractRequestId(request_id): ExtractRequestId,
 ) -> Result<ApiSuccess<ApplicationResponse>, ApiError> {
     let response = uc
@@ -54,56 +56,65 @@ pub async fn get_by_id(
 
 /// Get applications route: GET /api/v1/applications
 #[instrument(skip(uc), name = "get_applications_handler")]
-pub async fn get_all(
+pub async fn get_all<D: DatabaseRepositories>(
     Query(request): Query<GetApplicationsRequest>,
-    Extension(uc): Extension<AppUseCases>,
+    Query(filters): Query<QueryFiltersRequest>,
+    OriginalUri(uri): OriginalUri,
+    Extension(uc): Extension<AppUseCases<D>>,
     ExtractRequestId(request_id): ExtractRequestId,
-) -> Result<ApiSuccess<GetApplicationsResponse>, ApiError> {
+) -> Result<GetApplicationsResponse, ApiError> {
+    let pagination = request.pagination()?;
     let response = uc
         .application
         .get_applications
         .call(GetApplicationsUseCaseRequest {
-            pagination: request.pagination(),
+            pagination: pagination.clone(),
             sorts: request.sorts(),
+            filters: filters.filters()?,
             deleted: false,
         })
         .await?;
 
-    Ok(ApiSuccess::new(StatusCode::OK, response.into()))
+    Ok(ApiPaginated::new(response.into(), &pagination, &uri))
 }
 
 /// Get deleted applications route: GET /api/v1/applications/deleted
 #[instrument(skip(uc), name = "get_deleted_applications_handler")]
-pub async fn get_all_deleted(
+pub async fn get_all_deleted<D: DatabaseRepositories>(
     Query(request): Query<GetApplicationsRequest>,
-    Extension(uc): Extension<AppUseCases>,
+    Query(filters): Query<QueryFiltersRequest>,
+    OriginalUri(uri): OriginalUri,
+    Extension(uc): Extension<AppUseCases<D>>,
     ExtractRequestId(request_id): ExtractRequestId,
-) -> Result<ApiSuccess<GetApplicationsResponse>, ApiError> {
+) -> Result<GetApplicationsResponse, ApiError> {
+    let pagination = request.pagination()?;
     let response = uc
         .application
         .get_applications
         .call(GetApplicationsUseCaseRequest {
-            pagination: request.pagination(),
+            pagination: pagination.clone(),
             sorts: request.sorts(),
+            filters: filters.filters()?,
             deleted: true,
         })
         .await?;
 
-    Ok(ApiSuccess::new(StatusCode::OK, response.into()))
+    Ok(ApiPaginated::new(response.into(), &pagination, &uri))
 }
 
 /// Delete an application route: DELETE /api/v1/applications/:application_id
 #[instrument(skip(uc), name = "delete_application_handler")]
-pub async fn delete(
+pub async fn delete<D: DatabaseRepositories>(
     Path(application_id): Path<String>,
-    Extension(uc): Extension<AppUseCases>,
-    ExtractRequestId(request_id): ExtractRequestId,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
 ) -> Result<ApiSuccess<DeleteApplicationResponse>, ApiError> {
     let response = uc
         .application
         .delete_application
         .call(DeleteApplicationUseCaseRequest {
             id: Id::from_str(&application_id)?,
+            audit,
         })
         .await?;
 
@@ -112,10 +123,10 @@ pub async fn delete(
 
 /// Update an application route: PATCH /api/v1/applications/:application_id
 #[instrument(skip(uc), name = "update_application_handler")]
-pub async fn update(
+pub async fn update<D: DatabaseRepositories>(
     Path(application_id): Path<String>,
-    Extension(uc): Extension<AppUseCases>,
-    ExtractRequestId(request_id): ExtractRequestId,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
     Json(request): Json<UpdateApplicationRequest>,
 ) -> Result<ApiSuccess<UpdateApplicationResponse>, ApiError> {
     let response = uc
@@ -124,6 +135,10 @@ pub async fn update(
         .call(UpdateApplicationUseCaseRequest {
             id: Id::from_str(&application_id)?,
             name: request.name,
+            access_token_lifetime: request.access_token_lifetime,
+            refresh_token_lifetime: request.refresh_token_lifetime,
+            audience: request.audience,
+            audit,
         })
         .await?;
 
@@ -132,16 +147,17 @@ pub async fn update(
 
 /// Restore a deleted application route: PATCH /api/v1/applications/:application_id/restore
 #[instrument(skip(uc), name = "restore_application_handler")]
-pub async fn restore(
+pub async fn restore<D: DatabaseRepositories>(
     Path(application_id): Path<String>,
-    Extension(uc): Extension<AppUseCases>,
-    ExtractRequestId(request_id): ExtractRequestId,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
 ) -> Result<ApiSuccess<RestoreApplicationResponse>, ApiError> {
     let response = uc
         .application
         .restore_application
         .call(RestoreApplicationUseCaseRequest {
             id: Id::from_str(&application_id)?,
+            audit,
         })
         .await?;
 
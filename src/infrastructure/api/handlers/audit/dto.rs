@@ -0,0 +1,111 @@
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::entities::user::UserId;
+use crate::domain::use_cases::audit::get_audit_events::{
+    AuditEventFilters, GetAuditEventsUseCaseRequest, GetAuditEventsUseCaseResponse,
+};
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::infrastructure::api::handlers::filter::FilterRequest;
+use crate::infrastructure::api::response::{ApiError, ApiPage, ApiPaginated};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Audit event response
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AuditEventResponse {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<String>,
+    pub action: String,
+    pub target_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    pub created_at: String,
+}
+
+impl From<AuditEvent> for AuditEventResponse {
+    fn from(value: AuditEvent) -> Self {
+        Self {
+            id: value.id.to_string(),
+            actor_id: value.actor_id.map(|actor_id| actor_id.to_string()),
+            action: value.action.to_string(),
+            target_id: value.target_id,
+            ip: value.ip,
+            request_id: value.request_id,
+            created_at: value.created_at.to_string(),
+        }
+    }
+}
+
+// ================ Get audit events ================
+
+/// Pagination and sorts are declared here because `serde(flatten)` does not work
+/// with numbers in query strings.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetAuditEventsRequest {
+    #[serde(rename(deserialize = "p"))]
+    pub page: Option<u32>,
+
+    #[serde(rename(deserialize = "l"))]
+    pub limit: Option<u32>,
+
+    #[serde(rename(deserialize = "s"))]
+    pub sort: Option<String>,
+
+    #[serde(rename(deserialize = "c"))]
+    pub cursor: Option<String>,
+
+    pub actor_id: Option<String>,
+    pub action: Option<String>,
+    pub target_id: Option<String>,
+
+    /// RFC3339 date (included)
+    pub from: Option<String>,
+
+    /// RFC3339 date (excluded)
+    pub to: Option<String>,
+}
+
+impl TryFrom<GetAuditEventsRequest> for GetAuditEventsUseCaseRequest {
+    type Error = ApiError;
+
+    fn try_from(value: GetAuditEventsRequest) -> Result<Self, Self::Error> {
+        let filter = FilterRequest {
+            page: value.page,
+            limit: value.limit,
+            sort: value.sort,
+            cursor: value.cursor,
+        };
+        let parse_date =
+            |date: String| UtcDateTime::from_rfc3339(&date).map_err(|err| ApiError::BadRequest(err.to_string()));
+
+        Ok(Self {
+            pagination: filter.pagination()?,
+            sorts: filter.sorts(),
+            filters: AuditEventFilters {
+                actor_id: value.actor_id.map(|id| UserId::from_str(&id)).transpose()?,
+                action: value
+                    .action
+                    .map(|action| AuditAction::from_str(&action))
+                    .transpose()
+                    .map_err(ApiError::BadRequest)?,
+                target_id: value.target_id,
+                from: value.from.map(parse_date).transpose()?,
+                to: value.to.map(parse_date).transpose()?,
+            },
+        })
+    }
+}
+
+pub type GetAuditEventsResponse = ApiPaginated<AuditEventResponse>;
+
+impl From<GetAuditEventsUseCaseResponse> for ApiPage<AuditEventResponse> {
+    fn from(value: GetAuditEventsUseCaseResponse) -> Self {
+        Self {
+            data: value.events.into_iter().map(|event| event.into()).collect(),
+            total: value.total,
+            next_cursor: value.next_cursor.map(|cursor| cursor.to_string()),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+//! Error conversion for audit handlers
+
+use crate::domain::use_cases::audit::AuditUseCaseError;
+use crate::infrastructure::api::response::ApiError;
+
+impl From<AuditUseCaseError> for ApiError {
+    fn from(value: AuditUseCaseError) -> Self {
+        match value {
+            AuditUseCaseError::DatabaseError(msg) => ApiError::InternalServerError(msg),
+            AuditUseCaseError::InvalidId() => ApiError::InternalServerError(value.to_string()),
+            AuditUseCaseError::FromModelError() => ApiError::InternalServerError("Internal server error".to_string()),
+        }
+    }
+}
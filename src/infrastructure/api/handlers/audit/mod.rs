@@ -0,0 +1,28 @@
+//! Audit handlers
+
+mod dto;
+mod error;
+
+use crate::adapters::database::DatabaseRepositories;
+use crate::domain::use_cases::audit::get_audit_events::GetAuditEventsUseCaseRequest;
+use crate::infrastructure::api::extractors::{ExtractRequestId, Query};
+use crate::infrastructure::api::response::{ApiError, ApiPaginated};
+use crate::infrastructure::api::use_cases::AppUseCases;
+use axum::Extension;
+use axum::extract::OriginalUri;
+use dto::{GetAuditEventsRequest, GetAuditEventsResponse};
+
+/// Get audit events route: GET /api/v1/audit-events
+#[instrument(skip(uc), name = "get_audit_events_handler")]
+pub async fn get_all<D: DatabaseRepositories>(
+    Query(request): Query<GetAuditEventsRequest>,
+    OriginalUri(uri): OriginalUri,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractRequestId(request_id): ExtractRequestId,
+) -> Result<GetAuditEventsResponse, ApiError> {
+    let request: GetAuditEventsUseCaseRequest = request.try_into()?;
+    let pagination = request.pagination.clone();
+    let response = uc.audit.get_audit_events.call(request).await?;
+
+    Ok(ApiPaginated::new(response.into(), &pagination, &uri))
+}
@@ -0,0 +1,185 @@
+//! Application clients handlers DTO
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::audit_event::AuditContext;
+use crate::domain::use_cases::application::create_client::{CreateClientUseCaseRequest, CreateClientUseCaseResponse};
+use crate::domain::use_cases::application::create_client_secret::CreateClientSecretUseCaseResponse;
+use crate::domain::use_cases::application::delete_client::DeleteClientUseCaseResponse;
+use crate::domain::use_cases::application::delete_client_secret::DeleteClientSecretUseCaseResponse;
+use crate::domain::use_cases::application::get_clients::GetClientsUseCaseResponse;
+use crate::domain::use_cases::application::update_client::UpdateClientUseCaseResponse;
+use crate::domain::use_cases::application::{ClientSecretUseCaseResponse, ClientUseCaseResponse};
+use crate::domain::value_objects::password_policy::PasswordPolicy;
+use crate::infrastructure::api::handlers::filter::FilterRequest;
+use crate::infrastructure::api::response::{ApiPage, ApiPaginated};
+use serde::{Deserialize, Serialize};
+
+/// Client response
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ClientResponse {
+    pub id: String,
+    pub application_id: String,
+    pub name: String,
+    pub created_at: String,
+    pub updated_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<String>,
+}
+
+impl From<ClientUseCaseResponse> for ClientResponse {
+    fn from(value: ClientUseCaseResponse) -> Self {
+        Self {
+            id: value.id.to_string(),
+            application_id: value.application_id.to_string(),
+            name: value.name,
+            created_at: value.created_at.to_string(),
+            updated_at: value.updated_at.to_string(),
+            deleted_at: value.deleted_at.map(|dt| dt.to_string()),
+        }
+    }
+}
+
+/// Client secret response (the secret value is never returned)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ClientSecretResponse {
+    pub id: String,
+    pub client_id: String,
+    pub created_at: String,
+}
+
+impl From<ClientSecretUseCaseResponse> for ClientSecretResponse {
+    fn from(value: ClientSecretUseCaseResponse) -> Self {
+        Self {
+            id: value.id.to_string(),
+            client_id: value.client_id.to_string(),
+            created_at: value.created_at.to_string(),
+        }
+    }
+}
+
+// ================ Client creation ================
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateClientRequest {
+    pub name: String,
+}
+
+impl CreateClientRequest {
+    /// Convert into use case request
+    pub fn into_use_case_request(
+        self,
+        application_id: ApplicationId,
+        policy: &PasswordPolicy,
+        audit: AuditContext,
+    ) -> CreateClientUseCaseRequest {
+        CreateClientUseCaseRequest {
+            application_id,
+            name: self.name,
+            password_policy: policy.clone(),
+            audit,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CreateClientResponse {
+    #[serde(flatten)]
+    pub client: ClientResponse,
+
+    /// Plain secret, only returned once
+    pub secret: String,
+}
+
+impl From<CreateClientUseCaseResponse> for CreateClientResponse {
+    fn from(value: CreateClientUseCaseResponse) -> Self {
+        Self {
+            client: value.client.into(),
+            secret: value.secret.value().to_string(),
+        }
+    }
+}
+
+// ================ Get clients ================
+
+pub type GetClientsRequest = FilterRequest;
+
+pub type GetClientsResponse = ApiPaginated<ClientResponse>;
+
+impl From<GetClientsUseCaseResponse> for ApiPage<ClientResponse> {
+    fn from(value: GetClientsUseCaseResponse) -> Self {
+        Self {
+            data: value.clients.into_iter().map(|client| client.into()).collect(),
+            total: value.total,
+            next_cursor: value.next_cursor.map(|cursor| cursor.to_string()),
+        }
+    }
+}
+
+// ================ Update client ================
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateClientRequest {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateClientResponse();
+
+impl From<UpdateClientUseCaseResponse> for UpdateClientResponse {
+    fn from(_: UpdateClientUseCaseResponse) -> Self {
+        Self {}
+    }
+}
+
+// ================ Delete client ================
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteClientResponse();
+
+impl From<DeleteClientUseCaseResponse> for DeleteClientResponse {
+    fn from(_: DeleteClientUseCaseResponse) -> Self {
+        Self {}
+    }
+}
+
+// ================ Client secrets ================
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CreateClientSecretResponse {
+    #[serde(flatten)]
+    pub client_secret: ClientSecretResponse,
+
+    /// Plain secret, only returned once
+    pub secret: String,
+}
+
+impl From<CreateClientSecretUseCaseResponse> for CreateClientSecretResponse {
+    fn from(value: CreateClientSecretUseCaseResponse) -> Self {
+        Self {
+            client_secret: value.client_secret.into(),
+            secret: value.secret.value().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GetClientSecretsResponse {
+    pub data: Vec<ClientSecretResponse>,
+}
+
+impl From<Vec<ClientSecretUseCaseResponse>> for GetClientSecretsResponse {
+    fn from(value: Vec<ClientSecretUseCaseResponse>) -> Self {
+        Self {
+            data: value.into_iter().map(|secret| secret.into()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteClientSecretResponse();
+
+impl From<DeleteClientSecretUseCaseResponse> for DeleteClientSecretResponse {
+    fn from(_: DeleteClientSecretUseCaseResponse) -> Self {
+        Self {}
+    }
+}
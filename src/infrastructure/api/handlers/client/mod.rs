@@ -0,0 +1,185 @@
+//! Application clients handlers
+
+mod dto;
+
+use crate::adapters::database::DatabaseRepositories;
+use crate::domain::use_cases::application::create_client_secret::CreateClientSecretUseCaseRequest;
+use crate::domain::use_cases::application::delete_client::DeleteClientUseCaseRequest;
+use crate::domain::use_cases::application::delete_client_secret::DeleteClientSecretUseCaseRequest;
+use crate::domain::use_cases::application::get_client::GetClientByIdUseCaseRequest;
+use crate::domain::use_cases::application::get_client_secrets::GetClientSecretsUseCaseRequest;
+use crate::domain::use_cases::application::get_clients::GetClientsUseCaseRequest;
+use crate::domain::use_cases::application::update_client::UpdateClientUseCaseRequest;
+use crate::domain::value_objects::id::Id;
+use crate::infrastructure::api::extractors::{ExtractAuditContext, ExtractRequestId, Path, Query};
+use crate::infrastructure::api::handlers::client::dto::*;
+use crate::infrastructure::api::layers::state::SharedState;
+use crate::infrastructure::api::response::{ApiError, ApiPaginated, ApiSuccess};
+use crate::infrastructure::api::use_cases::AppUseCases;
+use axum::extract::{OriginalUri, State};
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use std::str::FromStr;
+
+/// Client creation route: POST /api/v1/applications/:application_id/clients
+#[instrument(skip(uc, state, request), name = "create_client_handler")]
+pub async fn create<D: DatabaseRepositories>(
+    Path(application_id): Path<String>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    State(state): State<SharedState>,
+    ExtractAuditContext(audit): ExtractAuditContext,
+    Json(request): Json<CreateClientRequest>,
+) -> Result<ApiSuccess<CreateClientResponse>, ApiError> {
+    let response = uc
+        .application
+        .create_client
+        .call(request.into_use_case_request(Id::from_str(&application_id)?, &state.config.password_policy, audit))
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::CREATED, response.into()))
+}
+
+/// Get clients route: GET /api/v1/applications/:application_id/clients
+#[instrument(skip(uc), name = "get_clients_handler")]
+pub async fn get_all<D: DatabaseRepositories>(
+    Path(application_id): Path<String>,
+    Query(request): Query<GetClientsRequest>,
+    OriginalUri(uri): OriginalUri,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractRequestId(request_id): ExtractRequestId,
+) -> Result<GetClientsResponse, ApiError> {
+    let pagination = request.pagination()?;
+    let response = uc
+        .application
+        .get_clients
+        .call(GetClientsUseCaseRequest {
+            application_id: Id::from_str(&application_id)?,
+            pagination: pagination.clone(),
+            sorts: request.sorts(),
+        })
+        .await?;
+
+    Ok(ApiPaginated::new(response.into(), &pagination, &uri))
+}
+
+/// Get client by ID route: GET /api/v1/applications/:application_id/clients/:client_id
+#[instrument(skip(uc), name = "get_client_by_id_handler")]
+pub async fn get_by_id<D: DatabaseRepositories>(
+    Path((application_id, client_id)): Path<(String, String)>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractRequestId(request_id): ExtractRequestId,
+) -> Result<ApiSuccess<ClientResponse>, ApiError> {
+    let response = uc
+        .application
+        .get_client
+        .call(GetClientByIdUseCaseRequest {
+            application_id: Id::from_str(&application_id)?,
+            id: Id::from_str(&client_id)?,
+        })
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::OK, response.into()))
+}
+
+/// Update a client route: PATCH /api/v1/applications/:application_id/clients/:client_id
+#[instrument(skip(uc, request), name = "update_client_handler")]
+pub async fn update<D: DatabaseRepositories>(
+    Path((application_id, client_id)): Path<(String, String)>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractRequestId(request_id): ExtractRequestId,
+    Json(request): Json<UpdateClientRequest>,
+) -> Result<ApiSuccess<UpdateClientResponse>, ApiError> {
+    let response = uc
+        .application
+        .update_client
+        .call(UpdateClientUseCaseRequest {
+            application_id: Id::from_str(&application_id)?,
+            id: Id::from_str(&client_id)?,
+            name: request.name,
+        })
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::NO_CONTENT, response.into()))
+}
+
+/// Delete a client route: DELETE /api/v1/applications/:application_id/clients/:client_id
+#[instrument(skip(uc), name = "delete_client_handler")]
+pub async fn delete<D: DatabaseRepositories>(
+    Path((application_id, client_id)): Path<(String, String)>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
+) -> Result<ApiSuccess<DeleteClientResponse>, ApiError> {
+    let response = uc
+        .application
+        .delete_client
+        .call(DeleteClientUseCaseRequest {
+            application_id: Id::from_str(&application_id)?,
+            id: Id::from_str(&client_id)?,
+            audit,
+        })
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::NO_CONTENT, response.into()))
+}
+
+/// Get client secrets route: GET /api/v1/applications/:application_id/clients/:client_id/secrets
+#[instrument(skip(uc), name = "get_client_secrets_handler")]
+pub async fn get_secrets<D: DatabaseRepositories>(
+    Path((application_id, client_id)): Path<(String, String)>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractRequestId(request_id): ExtractRequestId,
+) -> Result<ApiSuccess<GetClientSecretsResponse>, ApiError> {
+    let response = uc
+        .application
+        .get_client_secrets
+        .call(GetClientSecretsUseCaseRequest {
+            application_id: Id::from_str(&application_id)?,
+            client_id: Id::from_str(&client_id)?,
+        })
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::OK, response.into()))
+}
+
+/// Client secret creation route: POST /api/v1/applications/:application_id/clients/:client_id/secrets
+#[instrument(skip(uc, state), name = "create_client_secret_handler")]
+pub async fn create_secret<D: DatabaseRepositories>(
+    Path((application_id, client_id)): Path<(String, String)>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    State(state): State<SharedState>,
+    ExtractAuditContext(audit): ExtractAuditContext,
+) -> Result<ApiSuccess<CreateClientSecretResponse>, ApiError> {
+    let response = uc
+        .application
+        .create_client_secret
+        .call(CreateClientSecretUseCaseRequest {
+            application_id: Id::from_str(&application_id)?,
+            client_id: Id::from_str(&client_id)?,
+            password_policy: state.config.password_policy.clone(),
+            audit,
+        })
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::CREATED, response.into()))
+}
+
+/// Delete a client secret route: DELETE /api/v1/applications/:application_id/clients/:client_id/secrets/:secret_id
+#[instrument(skip(uc), name = "delete_client_secret_handler")]
+pub async fn delete_secret<D: DatabaseRepositories>(
+    Path((application_id, client_id, secret_id)): Path<(String, String, String)>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
+) -> Result<ApiSuccess<DeleteClientSecretResponse>, ApiError> {
+    let response = uc
+        .application
+        .delete_client_secret
+        .call(DeleteClientSecretUseCaseRequest {
+            application_id: Id::from_str(&application_id)?,
+            client_id: Id::from_str(&client_id)?,
+            id: Id::from_str(&secret_id)?,
+            audit,
+        })
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::NO_CONTENT, response.into()))
+}
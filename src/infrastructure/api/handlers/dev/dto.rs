@@ -0,0 +1,46 @@
+use crate::domain::entities::email::EmailMessage;
+use serde::Serialize;
+
+/// Captured email response
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EmailResponse {
+    pub from: String,
+    pub to: Vec<String>,
+    pub subject: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_body: Option<String>,
+}
+
+impl From<EmailMessage> for EmailResponse {
+    fn from(value: EmailMessage) -> Self {
+        Self {
+            from: value.from_address,
+            to: value.to_addresses,
+            subject: value.subject,
+            text_body: value.text_body,
+            html_body: value.html_body,
+        }
+    }
+}
+
+// ================ Get captured emails ================
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GetEmailsResponse {
+    pub data: Vec<EmailResponse>,
+}
+
+impl From<Vec<EmailMessage>> for GetEmailsResponse {
+    fn from(value: Vec<EmailMessage>) -> Self {
+        Self {
+            data: value.into_iter().map(|email| email.into()).collect(),
+        }
+    }
+}
+
+// ================ Clear captured emails ================
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ClearEmailsResponse();
@@ -0,0 +1,40 @@
+//! Development handlers
+//!
+//! The routes are not registered in production.
+
+mod dto;
+
+use crate::adapters::database::DatabaseRepositories;
+use crate::adapters::email::transport::MemoryTransport;
+use crate::infrastructure::api::response::{ApiError, ApiSuccess};
+use crate::infrastructure::api::use_cases::AppUseCases;
+use axum::Extension;
+use axum::http::StatusCode;
+use dto::{ClearEmailsResponse, GetEmailsResponse};
+
+/// Memory transport of the emails
+fn memory_transport<D: DatabaseRepositories>(uc: &AppUseCases<D>) -> Result<&MemoryTransport, ApiError> {
+    uc.email_service
+        .memory_transport()
+        .ok_or_else(|| ApiError::NotFound("Emails are only captured by the memory transport".to_string()))
+}
+
+/// Get captured emails route: GET /api/v1/dev/emails
+#[instrument(skip(uc), name = "get_captured_emails_handler")]
+pub async fn get_emails<D: DatabaseRepositories>(
+    Extension(uc): Extension<AppUseCases<D>>,
+) -> Result<ApiSuccess<GetEmailsResponse>, ApiError> {
+    let emails = memory_transport(&uc)?.messages();
+
+    Ok(ApiSuccess::new(StatusCode::OK, emails.into()))
+}
+
+/// Clear captured emails route: DELETE /api/v1/dev/emails
+#[instrument(skip(uc), name = "clear_captured_emails_handler")]
+pub async fn clear_emails<D: DatabaseRepositories>(
+    Extension(uc): Extension<AppUseCases<D>>,
+) -> Result<ApiSuccess<ClearEmailsResponse>, ApiError> {
+    memory_transport(&uc)?.clear();
+
+    Ok(ApiSuccess::new(StatusCode::NO_CONTENT, ClearEmailsResponse()))
+}
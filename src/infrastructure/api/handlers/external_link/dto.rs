@@ -1,13 +1,20 @@
 //! External links handlers DTO
 
 use crate::{
+    domain::entities::audit_event::AuditContext,
     domain::use_cases::external_link::{
-        ExternalLinkUseCaseResponse, create_external_link::CreateExternalLinkUseCaseRequest,
+        ExternalLinkApplicationUseCaseResponse, ExternalLinkUseCaseResponse, ExternalLinkUserUseCaseResponse,
+        create_external_link::CreateExternalLinkUseCaseRequest,
         delete_external_link::DeleteExternalLinkUseCaseResponse, get_external_links::GetExternalLinksUseCaseResponse,
         restore_external_link::RestoreExternalLinkUseCaseResponse,
+        unlink_application::UnlinkApplicationUseCaseResponse, unlink_user::UnlinkUserUseCaseResponse,
         update_external_link::UpdateExternalLinkUseCaseResponse,
     },
-    infrastructure::api::handlers::filter::FilterRequest,
+    domain::value_objects::oidc_provider::OidcProvider,
+    infrastructure::api::{
+        handlers::filter::FilterRequest,
+        response::{ApiError, ApiPage, ApiPaginated},
+    },
 };
 use serde::{Deserialize, Serialize};
 
@@ -16,6 +23,8 @@ use serde::{Deserialize, Serialize};
 pub struct ExternalLinkResponse {
     pub id: String,
     pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<OidcProviderResponse>,
     pub created_at: String,
     pub updated_at: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -27,6 +36,7 @@ impl From<ExternalLinkUseCaseResponse> for ExternalLinkResponse {
         Self {
             id: value.id.to_string(),
             name: value.name,
+            provider: value.provider.map(OidcProviderResponse::from),
             created_at: value.created_at.to_string(),
             updated_at: value.updated_at.to_string(),
             deleted_at: value.deleted_at.map(|dt| dt.to_string()),
@@ -34,16 +44,74 @@ impl From<ExternalLinkUseCaseResponse> for ExternalLinkResponse {
     }
 }
 
+/// OpenID Connect provider response (the client secret is never returned)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OidcProviderResponse {
+    pub issuer: String,
+    pub client_id: String,
+    pub scopes: Vec<String>,
+}
+
+impl From<OidcProvider> for OidcProviderResponse {
+    fn from(value: OidcProvider) -> Self {
+        Self {
+            issuer: value.issuer().to_string(),
+            client_id: value.client_id().to_string(),
+            scopes: value.scopes().to_vec(),
+        }
+    }
+}
+
+/// OpenID Connect provider request
+#[derive(Clone, Deserialize)]
+pub struct OidcProviderRequest {
+    pub issuer: String,
+    pub client_id: String,
+    pub client_secret: String,
+    #[serde(default)]
+    pub scopes: Vec<String>,
+}
+
+/// The client secret must never be displayed in logs
+impl std::fmt::Debug for OidcProviderRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OidcProviderRequest")
+            .field("issuer", &self.issuer)
+            .field("client_id", &self.client_id)
+            .field("scopes", &self.scopes)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TryFrom<OidcProviderRequest> for OidcProvider {
+    type Error = ApiError;
+
+    fn try_from(value: OidcProviderRequest) -> Result<Self, Self::Error> {
+        Ok(OidcProvider::new(
+            &value.issuer,
+            &value.client_id,
+            &value.client_secret,
+            value.scopes,
+        )?)
+    }
+}
+
 // ================ External link creation ================
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreateExternalLinkRequest {
     pub name: String,
+    pub provider: Option<OidcProviderRequest>,
 }
 
-impl From<CreateExternalLinkRequest> for CreateExternalLinkUseCaseRequest {
-    fn from(value: CreateExternalLinkRequest) -> Self {
-        Self { name: value.name }
+impl CreateExternalLinkRequest {
+    /// Convert into use case request
+    pub fn into_use_case_request(self, audit: AuditContext) -> Result<CreateExternalLinkUseCaseRequest, ApiError> {
+        Ok(CreateExternalLinkUseCaseRequest {
+            name: self.name,
+            provider: self.provider.map(OidcProvider::try_from).transpose()?,
+            audit,
+        })
     }
 }
 
@@ -51,17 +119,14 @@ impl From<CreateExternalLinkRequest> for CreateExternalLinkUseCaseRequest {
 
 pub type GetExternalLinksRequest = FilterRequest;
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
-pub struct GetExternalLinksResponse {
-    pub total: i64,
-    pub data: Vec<ExternalLinkResponse>,
-}
+pub type GetExternalLinksResponse = ApiPaginated<ExternalLinkResponse>;
 
-impl From<GetExternalLinksUseCaseResponse> for GetExternalLinksResponse {
+impl From<GetExternalLinksUseCaseResponse> for ApiPage<ExternalLinkResponse> {
     fn from(value: GetExternalLinksUseCaseResponse) -> Self {
         Self {
-            total: value.total,
             data: value.external_links.into_iter().map(|app| app.into()).collect(),
+            total: value.total,
+            next_cursor: value.next_cursor.map(|cursor| cursor.to_string()),
         }
     }
 }
@@ -82,6 +147,7 @@ impl From<DeleteExternalLinkUseCaseResponse> for DeleteExternalLinkResponse {
 #[derive(Debug, Clone, Deserialize)]
 pub struct UpdateExternalLinkRequest {
     pub name: String,
+    pub provider: Option<OidcProviderRequest>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
@@ -103,3 +169,68 @@ impl From<RestoreExternalLinkUseCaseResponse> for RestoreExternalLinkResponse {
         Self {}
     }
 }
+
+// ================ Linked users ================
+
+/// User linked to an external link response
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExternalLinkUserResponse {
+    pub external_link_id: String,
+    pub user_id: String,
+    pub external_user_id: String,
+    pub created_at: String,
+}
+
+impl From<ExternalLinkUserUseCaseResponse> for ExternalLinkUserResponse {
+    fn from(value: ExternalLinkUserUseCaseResponse) -> Self {
+        Self {
+            external_link_id: value.external_link_id.to_string(),
+            user_id: value.user_id.to_string(),
+            external_user_id: value.external_user_id,
+            created_at: value.created_at.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LinkUserRequest {
+    pub external_user_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UnlinkUserResponse();
+
+impl From<UnlinkUserUseCaseResponse> for UnlinkUserResponse {
+    fn from(_: UnlinkUserUseCaseResponse) -> Self {
+        Self {}
+    }
+}
+
+// ================ Linked applications ================
+
+/// Application linked to an external link response
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExternalLinkApplicationResponse {
+    pub external_link_id: String,
+    pub application_id: String,
+    pub created_at: String,
+}
+
+impl From<ExternalLinkApplicationUseCaseResponse> for ExternalLinkApplicationResponse {
+    fn from(value: ExternalLinkApplicationUseCaseResponse) -> Self {
+        Self {
+            external_link_id: value.external_link_id.to_string(),
+            application_id: value.application_id.to_string(),
+            created_at: value.created_at.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UnlinkApplicationResponse();
+
+impl From<UnlinkApplicationUseCaseResponse> for UnlinkApplicationResponse {
+    fn from(_: UnlinkApplicationUseCaseResponse) -> Self {
+        Self {}
+    }
+}
@@ -8,6 +8,12 @@ impl From<ExternalLinkUseCaseError> for ApiError {
             ExternalLinkUseCaseError::DatabaseError(msg) => ApiError::InternalServerError(msg),
             ExternalLinkUseCaseError::InvalidId() => ApiError::InternalServerError(value.to_string()),
             ExternalLinkUseCaseError::ExternalLinkNotFound() => ApiError::NotFound(value.to_string()),
+            ExternalLinkUseCaseError::UserNotFound() => ApiError::NotFound(value.to_string()),
+            ExternalLinkUseCaseError::ApplicationNotFound() => ApiError::NotFound(value.to_string()),
+            ExternalLinkUseCaseError::ExternalLinkUserNotFound() => ApiError::NotFound(value.to_string()),
+            ExternalLinkUseCaseError::ExternalLinkApplicationNotFound() => ApiError::NotFound(value.to_string()),
+            ExternalLinkUseCaseError::InvalidExternalUserId() => ApiError::BadRequest(value.to_string()),
+            ExternalLinkUseCaseError::ExternalUserIdAlreadyUsed() => ApiError::BadRequest(value.to_string()),
             ExternalLinkUseCaseError::FromModelError() => {
                 ApiError::InternalServerError("Internal server error".to_string())
             }
@@ -3,35 +3,45 @@
 mod dto;
 mod error;
 
-use crate::domain::use_cases::external_link::create_external_link::CreateExternalLinkUseCaseRequest;
+use crate::adapters::database::DatabaseRepositories;
 use crate::domain::use_cases::external_link::delete_external_link::DeleteExternalLinkUseCaseRequest;
 use crate::domain::use_cases::external_link::get_external_link::GetExternalLinkByIdUseCaseRequest;
 use crate::domain::use_cases::external_link::get_external_links::GetExternalLinksUseCaseRequest;
+use crate::domain::use_cases::external_link::get_linked_user::GetLinkedUserUseCaseRequest;
+use crate::domain::use_cases::external_link::link_application::LinkApplicationUseCaseRequest;
+use crate::domain::use_cases::external_link::link_user::LinkUserUseCaseRequest;
 use crate::domain::use_cases::external_link::restore_external_link::RestoreExternalLinkUseCaseRequest;
+use crate::domain::use_cases::external_link::unlink_application::UnlinkApplicationUseCaseRequest;
+use crate::domain::use_cases::external_link::unlink_user::UnlinkUserUseCaseRequest;
 use crate::domain::use_cases::external_link::update_external_link::UpdateExternalLinkUseCaseRequest;
 use crate::domain::value_objects::id::Id;
-use crate::infrastructure::api::extractors::{ExtractRequestId, Path, Query};
-use crate::infrastructure::api::response::{ApiError, ApiSuccess};
+use crate::domain::value_objects::oidc_provider::OidcProvider;
+use crate::infrastructure::api::extractors::{ExtractAuditContext, ExtractRequestId, Path, Query};
+use crate::infrastructure::api::handlers::filter::QueryFiltersRequest;
+use crate::infrastructure::api::response::{ApiError, ApiPaginated, ApiSuccess};
 use crate::infrastructure::api::use_cases::AppUseCases;
+use axum::extract::OriginalUri;
 use axum::http::StatusCode;
 use axum::{Extension, Json};
 use dto::{
-    CreateExternalLinkRequest, DeleteExternalLinkResponse, ExternalLinkResponse, GetExternalLinksRequest,
-    GetExternalLinksResponse, RestoreExternalLinkResponse, UpdateExternalLinkRequest, UpdateExternalLinkResponse,
+    CreateExternalLinkRequest, DeleteExternalLinkResponse, ExternalLinkApplicationResponse, ExternalLinkResponse,
+    ExternalLinkUserResponse, GetExternalLinksRequest, GetExternalLinksResponse, LinkUserRequest,
+    RestoreExternalLinkResponse, UnlinkApplicationResponse, UnlinkUserResponse, UpdateExternalLinkRequest,
+    UpdateExternalLinkResponse,
 };
 use std::str::FromStr;
 
 /// External link creation route: POST /api/v1/external-links
 #[instrument(skip(uc), name = "create_external_link_handler")]
-pub async fn create(
-    Extension(uc): Extension<AppUseCases>,
-    ExtractRequestId(request_id): ExtractRequestId,
+pub async fn create<D: DatabaseRepositories>(
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
     Json(request): Json<CreateExternalLinkRequest>,
 ) -> Result<ApiSuccess<ExternalLinkResponse>, ApiError> {
     let response = uc
         .external_link
         .create_external_link
-        .call(CreateExternalLinkUseCaseRequest::from(request))
+        .call(request.into_use_case_request(audit)?)
         .await?;
 
     Ok(ApiSuccess::new(StatusCode::CREATED, response.into()))
@@ -39,9 +49,9 @@ pub async fn create(
 
 /// Get external link by ID route: GET /api/v1/external-links/:external_link_id
 #[instrument(skip(uc), name = "get_external_link_by_id_handler")]
-pub async fn get_by_id(
+pub async fn get_by_id<D: DatabaseRepositories>(
     Path(external_link_id): Path<String>,
-    Extension(uc): Extension<AppUseCases>,
+    Extension(uc): Extension<AppUseCases<D>>,
     ExtractRequestId(request_id): ExtractRequestId,
 ) -> Result<ApiSuccess<ExternalLinkResponse>, ApiError> {
     let response = uc
@@ -57,56 +67,65 @@ pub async fn get_by_id(
 
 /// Get external links route: GET /api/v1/external-links
 #[instrument(skip(uc), name = "get_external_links_handler")]
-pub async fn get_all(
+pub async fn get_all<D: DatabaseRepositories>(
     Query(request): Query<GetExternalLinksRequest>,
-    Extension(uc): Extension<AppUseCases>,
+    Query(filters): Query<QueryFiltersRequest>,
+    OriginalUri(uri): OriginalUri,
+    Extension(uc): Extension<AppUseCases<D>>,
     ExtractRequestId(request_id): ExtractRequestId,
-) -> Result<ApiSuccess<GetExternalLinksResponse>, ApiError> {
+) -> Result<GetExternalLinksResponse, ApiError> {
+    let pagination = request.pagination()?;
     let response = uc
         .external_link
         .get_external_links
         .call(GetExternalLinksUseCaseRequest {
-            pagination: request.pagination(),
+            pagination: pagination.clone(),
             sorts: request.sorts(),
+            filters: filters.filters()?,
             deleted: false,
         })
         .await?;
 
-    Ok(ApiSuccess::new(StatusCode::OK, response.into()))
+    Ok(ApiPaginated::new(response.into(), &pagination, &uri))
 }
 
 /// Get deleted external links route: GET /api/v1/external-links/deleted
 #[instrument(skip(uc), name = "get_deleted_external_links_handler")]
-pub async fn get_all_deleted(
+pub async fn get_all_deleted<D: DatabaseRepositories>(
     Query(request): Query<GetExternalLinksRequest>,
-    Extension(uc): Extension<AppUseCases>,
+    Query(filters): Query<QueryFiltersRequest>,
+    OriginalUri(uri): OriginalUri,
+    Extension(uc): Extension<AppUseCases<D>>,
     ExtractRequestId(request_id): ExtractRequestId,
-) -> Result<ApiSuccess<GetExternalLinksResponse>, ApiError> {
+) -> Result<GetExternalLinksResponse, ApiError> {
+    let pagination = request.pagination()?;
     let response = uc
         .external_link
         .get_external_links
         .call(GetExternalLinksUseCaseRequest {
-            pagination: request.pagination(),
+            pagination: pagination.clone(),
             sorts: request.sorts(),
+            filters: filters.filters()?,
             deleted: true,
         })
         .await?;
 
-    Ok(ApiSuccess::new(StatusCode::OK, response.into()))
+    Ok(ApiPaginated::new(response.into(), &pagination, &uri))
 }
 
 /// Delete an external link route: DELETE /api/v1/external-links/:external_link_id
 #[instrument(skip(uc), name = "delete_external_link_handler")]
-pub async fn delete(
+pub async fn delete<D: DatabaseRepositories>(
     Path(external_link_id): Path<String>,
-    Extension(uc): Extension<AppUseCases>,
-    ExtractRequestId(request_id): ExtractRequestId,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
 ) -> Result<ApiSuccess<DeleteExternalLinkResponse>, ApiError> {
     let response = uc
         .external_link
         .delete_external_link
         .call(DeleteExternalLinkUseCaseRequest {
             id: Id::from_str(&external_link_id)?,
+            audit,
         })
         .await?;
 
@@ -115,10 +134,10 @@ pub async fn delete(
 
 /// Update an external link route: PATCH /api/v1/external-links/:external_link_id
 #[instrument(skip(uc), name = "update_external_link_handler")]
-pub async fn update(
+pub async fn update<D: DatabaseRepositories>(
     Path(external_link_id): Path<String>,
-    Extension(uc): Extension<AppUseCases>,
-    ExtractRequestId(request_id): ExtractRequestId,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
     Json(request): Json<UpdateExternalLinkRequest>,
 ) -> Result<ApiSuccess<UpdateExternalLinkResponse>, ApiError> {
     let response = uc
@@ -127,6 +146,8 @@ pub async fn update(
         .call(UpdateExternalLinkUseCaseRequest {
             id: Id::from_str(&external_link_id)?,
             name: request.name,
+            provider: request.provider.map(OidcProvider::try_from).transpose()?,
+            audit,
         })
         .await?;
 
@@ -135,16 +156,120 @@ pub async fn update(
 
 /// Restore a deleted external link route: PATCH /api/v1/external-links/:external_link_id/restore
 #[instrument(skip(uc), name = "restore_external_link_handler")]
-pub async fn restore(
+pub async fn restore<D: DatabaseRepositories>(
     Path(external_link_id): Path<String>,
-    Extension(uc): Extension<AppUseCases>,
-    ExtractRequestId(request_id): ExtractRequestId,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
 ) -> Result<ApiSuccess<RestoreExternalLinkResponse>, ApiError> {
     let response = uc
         .external_link
         .restore_external_link
         .call(RestoreExternalLinkUseCaseRequest {
             id: Id::from_str(&external_link_id)?,
+            audit,
+        })
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::NO_CONTENT, response.into()))
+}
+
+/// Link a user route: PUT /api/v1/external-links/:external_link_id/users/:user_id
+///
+/// If the user is already linked, its external user ID is replaced.
+#[instrument(skip(uc), name = "link_user_handler")]
+pub async fn link_user<D: DatabaseRepositories>(
+    Path((external_link_id, user_id)): Path<(String, String)>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
+    Json(request): Json<LinkUserRequest>,
+) -> Result<ApiSuccess<ExternalLinkUserResponse>, ApiError> {
+    let response = uc
+        .external_link
+        .link_user
+        .call(LinkUserUseCaseRequest {
+            external_link_id: Id::from_str(&external_link_id)?,
+            user_id: Id::from_str(&user_id)?,
+            external_user_id: request.external_user_id,
+            audit,
+        })
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::OK, response.into()))
+}
+
+/// Unlink a user route: DELETE /api/v1/external-links/:external_link_id/users/:user_id
+#[instrument(skip(uc), name = "unlink_user_handler")]
+pub async fn unlink_user<D: DatabaseRepositories>(
+    Path((external_link_id, user_id)): Path<(String, String)>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
+) -> Result<ApiSuccess<UnlinkUserResponse>, ApiError> {
+    let response = uc
+        .external_link
+        .unlink_user
+        .call(UnlinkUserUseCaseRequest {
+            external_link_id: Id::from_str(&external_link_id)?,
+            user_id: Id::from_str(&user_id)?,
+            audit,
+        })
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::NO_CONTENT, response.into()))
+}
+
+/// Get a user from its partner ID route: GET /api/v1/external-links/:external_link_id/external-users/:external_user_id
+#[instrument(skip(uc), name = "get_linked_user_handler")]
+pub async fn get_linked_user<D: DatabaseRepositories>(
+    Path((external_link_id, external_user_id)): Path<(String, String)>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractRequestId(request_id): ExtractRequestId,
+) -> Result<ApiSuccess<ExternalLinkUserResponse>, ApiError> {
+    let response = uc
+        .external_link
+        .get_linked_user
+        .call(GetLinkedUserUseCaseRequest {
+            external_link_id: Id::from_str(&external_link_id)?,
+            external_user_id,
+        })
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::OK, response.into()))
+}
+
+/// Link an application route: PUT /api/v1/external-links/:external_link_id/applications/:application_id
+#[instrument(skip(uc), name = "link_application_handler")]
+pub async fn link_application<D: DatabaseRepositories>(
+    Path((external_link_id, application_id)): Path<(String, String)>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
+) -> Result<ApiSuccess<ExternalLinkApplicationResponse>, ApiError> {
+    let response = uc
+        .external_link
+        .link_application
+        .call(LinkApplicationUseCaseRequest {
+            external_link_id: Id::from_str(&external_link_id)?,
+            application_id: Id::from_str(&application_id)?,
+            audit,
+        })
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::OK, response.into()))
+}
+
+/// Unlink an application route: DELETE /api/v1/external-links/:external_link_id/applications/:application_id
+#[instrument(skip(uc), name = "unlink_application_handler")]
+pub async fn unlink_application<D: DatabaseRepositories>(
+    Path((external_link_id, application_id)): Path<(String, String)>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
+) -> Result<ApiSuccess<UnlinkApplicationResponse>, ApiError> {
+    let response = uc
+        .external_link
+        .unlink_application
+        .call(UnlinkApplicationUseCaseRequest {
+            external_link_id: Id::from_str(&external_link_id)?,
+            application_id: Id::from_str(&application_id)?,
+            audit,
         })
         .await?;
 
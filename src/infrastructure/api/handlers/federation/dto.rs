@@ -0,0 +1,75 @@
+//! DTO for federation handlers
+
+use crate::domain::use_cases::federation::complete_login::CompleteLoginUseCaseResponse;
+use crate::infrastructure::api::response::ApiError;
+use serde::{Deserialize, Serialize};
+
+// ================ Login ================
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LoginRequest {
+    /// Application for which the tokens will be issued
+    pub application_id: String,
+}
+
+// ================ Callback ================
+
+/// Authorization response of the provider
+#[derive(Clone, Deserialize)]
+pub struct CallbackRequest {
+    pub code: Option<String>,
+    pub state: Option<String>,
+    pub error: Option<String>,
+    pub error_description: Option<String>,
+}
+
+impl CallbackRequest {
+    /// Get the authorization code and the state, or the error returned by the provider
+    pub fn code_and_state(self) -> Result<(String, String), ApiError> {
+        if let Some(error) = self.error {
+            let description = self
+                .error_description
+                .map(|desc| format!(" ({desc})"))
+                .unwrap_or_default();
+            return Err(ApiError::Unauthorized(format!(
+                "Identity provider error: {error}{description}"
+            )));
+        }
+
+        match (self.code, self.state) {
+            (Some(code), Some(state)) => Ok((code, state)),
+            _ => Err(ApiError::BadRequest("Missing code or state".to_string())),
+        }
+    }
+}
+
+/// The authorization code must never be displayed in logs
+impl std::fmt::Debug for CallbackRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CallbackRequest")
+            .field("code", &self.code.as_ref().map(|_| "***"))
+            .field("state", &self.state)
+            .field("error", &self.error)
+            .field("error_description", &self.error_description)
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CallbackResponse {
+    pub access_token: String,
+    pub access_token_expired_at: String,
+    pub refresh_token: String,
+    pub refresh_token_expired_at: String,
+}
+
+impl From<CompleteLoginUseCaseResponse> for CallbackResponse {
+    fn from(value: CompleteLoginUseCaseResponse) -> Self {
+        Self {
+            access_token: value.access_token.token,
+            access_token_expired_at: value.access_token.expired_at.to_string(),
+            refresh_token: value.refresh_token.refresh_token.to_string(),
+            refresh_token_expired_at: value.refresh_token.expired_at.to_string(),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//! Error conversion for federation handlers
+
+use crate::domain::use_cases::federation::FederationUseCaseError;
+use crate::infrastructure::api::response::ApiError;
+
+impl From<FederationUseCaseError> for ApiError {
+    fn from(value: FederationUseCaseError) -> Self {
+        match value {
+            FederationUseCaseError::DatabaseError(msg) => ApiError::InternalServerError(msg),
+            FederationUseCaseError::ProviderNotFound() => ApiError::NotFound(value.to_string()),
+            FederationUseCaseError::ApplicationNotFound() => ApiError::NotFound(value.to_string()),
+            FederationUseCaseError::InvalidState() => ApiError::BadRequest(value.to_string()),
+            FederationUseCaseError::IdentityProviderError(_) => ApiError::Unauthorized(value.to_string()),
+            FederationUseCaseError::EmailRequired() => ApiError::Forbidden(value.to_string()),
+            FederationUseCaseError::UserDisabled() => ApiError::Forbidden(value.to_string()),
+            FederationUseCaseError::UserAlreadyLinked() => ApiError::Forbidden(value.to_string()),
+            FederationUseCaseError::InvalidPassword(_) => ApiError::InternalServerError(value.to_string()),
+            FederationUseCaseError::AccessTokenGenerationError() => ApiError::InternalServerError(value.to_string()),
+            FederationUseCaseError::RefreshTokenCreationError(_) => ApiError::InternalServerError(value.to_string()),
+            FederationUseCaseError::InvalidId() => ApiError::InternalServerError(value.to_string()),
+            FederationUseCaseError::FromModelError() => {
+                ApiError::InternalServerError("Internal server error".to_string())
+            }
+        }
+    }
+}
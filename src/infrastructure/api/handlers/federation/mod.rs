@@ -0,0 +1,82 @@
+//! Federation handlers (login with the OpenID Connect provider of an external link)
+
+mod dto;
+mod error;
+
+use crate::adapters::database::DatabaseRepositories;
+use crate::domain::use_cases::federation::complete_login::CompleteLoginUseCaseRequest;
+use crate::domain::use_cases::federation::start_login::StartLoginUseCaseRequest;
+use crate::domain::value_objects::id::Id;
+use crate::infrastructure::api::extractors::{ExtractAuditContext, ExtractRequestId, Path, Query};
+use crate::infrastructure::api::layers::state::SharedState;
+use crate::infrastructure::api::response::{ApiError, ApiSuccess};
+use crate::infrastructure::api::use_cases::AppUseCases;
+use axum::Extension;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::Redirect;
+use dto::{CallbackRequest, CallbackResponse, LoginRequest};
+use std::str::FromStr;
+
+/// URL called by the provider after the login
+fn redirect_uri(state: &SharedState, external_link_id: &str) -> String {
+    format!(
+        "{}/api/v1/external-links/{external_link_id}/callback",
+        state.config.oidc_redirect_base_url
+    )
+}
+
+/// Login route: GET /api/v1/external-links/{external_link_id}/login?application_id={application_id}
+///
+/// The user is redirected to the login page of the external link provider.
+#[instrument(skip(uc, state), name = "federation_login_handler")]
+pub async fn login<D: DatabaseRepositories>(
+    Path(external_link_id): Path<String>,
+    Query(request): Query<LoginRequest>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    State(state): State<SharedState>,
+    ExtractRequestId(request_id): ExtractRequestId,
+) -> Result<Redirect, ApiError> {
+    let response = uc
+        .federation
+        .start_login
+        .call(StartLoginUseCaseRequest {
+            external_link_id: Id::from_str(&external_link_id)?,
+            application_id: Id::from_str(&request.application_id)?,
+            redirect_uri: redirect_uri(&state, &external_link_id),
+            expiration_duration: state.config.oidc_login_expiration_duration,
+        })
+        .await?;
+
+    Ok(Redirect::to(&response.authorization_url))
+}
+
+/// Login callback route: GET /api/v1/external-links/{external_link_id}/callback?code={code}&state={state}
+///
+/// The ID token is validated and the user linked to the external user gets an access token and a refresh token.
+#[instrument(skip(uc, state), name = "federation_callback_handler")]
+pub async fn callback<D: DatabaseRepositories>(
+    Path(external_link_id): Path<String>,
+    Query(request): Query<CallbackRequest>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    State(state): State<SharedState>,
+    ExtractAuditContext(audit): ExtractAuditContext,
+) -> Result<ApiSuccess<CallbackResponse>, ApiError> {
+    let (code, login_state) = request.code_and_state()?;
+
+    let response = uc
+        .federation
+        .complete_login
+        .call(CompleteLoginUseCaseRequest {
+            external_link_id: Id::from_str(&external_link_id)?,
+            state: login_state,
+            code,
+            redirect_uri: redirect_uri(&state, &external_link_id),
+            jwt: state.jwt.clone(),
+            password_policy: state.config.password_policy.clone(),
+            audit,
+        })
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::OK, response.into()))
+}
@@ -1,11 +1,14 @@
-//! Filter for pagination and sorting
+//! Filter for pagination, sorting and filtering
 
-// TODO: Add filtering (https://www.moesif.com/blog/technical/api-design/REST-API-Design-Filtering-Sorting-and-Pagination/)
-// Try something like this: /applications/test?price=gte:10,lte:200
-
-use crate::domain::value_objects::pagination::Pagination;
+use crate::domain::value_objects::pagination::{PAGINATION_MAX_LIMIT, Pagination, PaginationCursor, PaginationError};
+use crate::domain::value_objects::query_filter::{QueryFilterError, QueryFilters};
 use crate::domain::value_objects::query_sort::QuerySorts;
 use serde::Deserialize;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Query parameters of `FilterRequest` and of the search (`q`), which are not field filters
+const FILTER_REQUEST_PARAMETERS: [&str; 5] = ["p", "l", "s", "c", "q"];
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct FilterRequest {
@@ -17,13 +20,21 @@ pub struct FilterRequest {
 
     #[serde(rename(deserialize = "s"))]
     pub sort: Option<String>,
+
+    /// Cursor mode: empty for the first page, then the `next_cursor` of the previous page
+    #[serde(rename(deserialize = "c"))]
+    pub cursor: Option<String>,
 }
 
 impl FilterRequest {
-    pub fn pagination(&self) -> Pagination {
-        match (self.page, self.limit) {
-            (Some(page), Some(limit)) => Pagination::new(page, limit),
-            _ => Pagination::default(),
+    pub fn pagination(&self) -> Result<Pagination, PaginationError> {
+        let limit = self.limit.unwrap_or(PAGINATION_MAX_LIMIT);
+
+        match (&self.cursor, self.page, self.limit) {
+            (Some(cursor), _, _) if cursor.is_empty() => Ok(Pagination::new_cursor(None, limit)),
+            (Some(cursor), _, _) => Ok(Pagination::new_cursor(Some(PaginationCursor::from_str(cursor)?), limit)),
+            (None, Some(page), Some(limit)) => Ok(Pagination::new(page, limit)),
+            _ => Ok(Pagination::default()),
         }
     }
 
@@ -31,3 +42,29 @@ impl FilterRequest {
         self.sort.as_ref().map(|sort| QuerySorts::from(sort.as_str()))
     }
 }
+
+/// Field filters: all the query parameters except those of `FilterRequest`
+///
+/// Example: `?name=in:foo,bar&created_at=gte:2024-01-01`
+///
+/// They are not flattened in `FilterRequest` because `serde(flatten)` does not work
+/// with numbers in query strings.
+/// Only the fields allowed by the repository are used, the others are ignored.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(transparent)]
+pub struct QueryFiltersRequest(HashMap<String, String>);
+
+impl QueryFiltersRequest {
+    pub fn filters(self) -> Result<Option<QueryFilters>, QueryFilterError> {
+        let params = self
+            .0
+            .into_iter()
+            .filter(|(key, _)| !FILTER_REQUEST_PARAMETERS.contains(&key.as_str()))
+            .collect::<HashMap<_, _>>();
+
+        match params.is_empty() {
+            true => Ok(None),
+            false => QueryFilters::try_from(params).map(Some),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Token introspection handlers DTO
+
+use crate::domain::entities::client::ClientId;
+use crate::domain::use_cases::introspection::introspect_token::IntrospectTokenUseCaseResponse;
+use crate::domain::value_objects::client_secret::ClientSecret;
+use crate::domain::value_objects::id::Id;
+use crate::infrastructure::api::response::ApiError;
+use axum::http::{HeaderMap, header};
+use http_auth_basic::Credentials;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+// ================ Introspect ================
+
+#[derive(Deserialize)]
+pub struct IntrospectRequest {
+    pub token: String,
+    pub token_type_hint: Option<String>,
+    pub client_id: Option<String>,
+    pub client_secret: Option<String>,
+}
+
+/// Tokens and secrets must never be displayed in logs
+impl std::fmt::Debug for IntrospectRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IntrospectRequest")
+            .field("token_type_hint", &self.token_type_hint)
+            .field("client_id", &self.client_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl IntrospectRequest {
+    /// Client credentials from the `Authorization` header or from the form parameters
+    pub fn client_credentials(&self, headers: &HeaderMap) -> Result<(ClientId, ClientSecret), ApiError> {
+        let invalid_client = || ApiError::Unauthorized("Invalid client credentials".to_string());
+
+        let (client_id, client_secret) = match headers.get(header::AUTHORIZATION) {
+            Some(auth) => {
+                let auth = auth.to_str().map_err(|_| invalid_client())?;
+                let credentials = Credentials::from_header(auth.to_string()).map_err(|_| invalid_client())?;
+                (credentials.user_id, credentials.password)
+            }
+            None => (
+                self.client_id.clone().ok_or_else(invalid_client)?,
+                self.client_secret.clone().ok_or_else(invalid_client)?,
+            ),
+        };
+
+        Ok((
+            Id::from_str(&client_id).map_err(|_| invalid_client())?,
+            ClientSecret::new(&client_secret),
+        ))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IntrospectResponse {
+    pub active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iat: Option<i64>,
+}
+
+impl From<IntrospectTokenUseCaseResponse> for IntrospectResponse {
+    fn from(value: IntrospectTokenUseCaseResponse) -> Self {
+        match value {
+            IntrospectTokenUseCaseResponse::Inactive => Self {
+                active: false,
+                sub: None,
+                scope: None,
+                client_id: None,
+                exp: None,
+                iat: None,
+            },
+            IntrospectTokenUseCaseResponse::Active {
+                sub,
+                scopes,
+                client_id,
+                exp,
+                iat,
+            } => Self {
+                active: true,
+                sub: Some(sub.to_string()),
+                scope: Some(
+                    scopes
+                        .iter()
+                        .map(|scope| scope.to_string())
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                ),
+                client_id: Some(client_id),
+                exp: Some(exp),
+                iat: Some(iat),
+            },
+        }
+    }
+}
@@ -0,0 +1,17 @@
+//! Error conversion for introspection handlers
+
+use crate::domain::use_cases::introspection::IntrospectionUseCaseError;
+use crate::infrastructure::api::response::ApiError;
+
+impl From<IntrospectionUseCaseError> for ApiError {
+    fn from(value: IntrospectionUseCaseError) -> Self {
+        match value {
+            IntrospectionUseCaseError::DatabaseError(msg) => ApiError::InternalServerError(msg),
+            IntrospectionUseCaseError::InvalidClient() => ApiError::Unauthorized(value.to_string()),
+            IntrospectionUseCaseError::InvalidId() => ApiError::InternalServerError(value.to_string()),
+            IntrospectionUseCaseError::FromModelError() => {
+                ApiError::InternalServerError("Internal server error".to_string())
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//! Token introspection handlers (RFC 7662)
+
+mod dto;
+mod error;
+
+use crate::adapters::database::DatabaseRepositories;
+use crate::domain::entities::access_token::AccessToken;
+use crate::domain::use_cases::introspection::introspect_token::IntrospectTokenUseCaseRequest;
+use crate::domain::value_objects::datetime::UtcDateTime;
+use crate::infrastructure::api::extractors::ExtractRequestId;
+use crate::infrastructure::api::layers::state::SharedState;
+use crate::infrastructure::api::response::{ApiError, ApiSuccess};
+use crate::infrastructure::api::use_cases::AppUseCases;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::{Extension, Form};
+use dto::{IntrospectRequest, IntrospectResponse};
+
+/// Token introspection route: POST /api/v1/introspect
+///
+/// The client authenticates with HTTP Basic credentials (`client_id:client_secret`)
+/// or with `client_id` and `client_secret` form parameters.
+#[instrument(skip(uc, state, headers, request), name = "introspect_handler")]
+pub async fn introspect<D: DatabaseRepositories>(
+    Extension(uc): Extension<AppUseCases<D>>,
+    State(state): State<SharedState>,
+    ExtractRequestId(request_id): ExtractRequestId,
+    headers: HeaderMap,
+    Form(request): Form<IntrospectRequest>,
+) -> Result<ApiSuccess<IntrospectResponse>, ApiError> {
+    let (client_id, client_secret) = request.client_credentials(&headers)?;
+
+    let response = uc
+        .introspection
+        .introspect_token
+        .call(IntrospectTokenUseCaseRequest {
+            client_id,
+            client_secret,
+            token: AccessToken::new(request.token, UtcDateTime::now()),
+            jwt: state.jwt.clone(),
+        })
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::OK, response.into()))
+}
@@ -0,0 +1,134 @@
+//! Invitations handlers DTO
+
+use crate::domain::entities::audit_event::AuditContext;
+use crate::domain::use_cases::invitation::InvitationUseCaseResponse;
+use crate::domain::use_cases::invitation::get_invitations::GetInvitationsUseCaseResponse;
+use crate::domain::use_cases::invitation::invite_user::InviteUserUseCaseRequest;
+use crate::domain::use_cases::invitation::resend_invitation::ResendInvitationUseCaseResponse;
+use crate::domain::use_cases::invitation::revoke_invitation::RevokeInvitationUseCaseResponse;
+use crate::domain::value_objects::email::Email;
+use crate::domain::value_objects::locale::Locale;
+use crate::domain::value_objects::scope_id::ScopeId;
+use crate::infrastructure::api::handlers::filter::FilterRequest;
+use crate::infrastructure::api::response::{ApiError, ApiPage, ApiPaginated};
+use serde::{Deserialize, Serialize};
+
+/// Invitation response
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InvitationResponse {
+    pub id: String,
+    pub user_id: String,
+    pub email: String,
+    pub lastname: String,
+    pub firstname: String,
+    pub locale: Option<String>,
+    pub expired_at: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<InvitationUseCaseResponse> for InvitationResponse {
+    fn from(value: InvitationUseCaseResponse) -> Self {
+        Self {
+            id: value.id.to_string(),
+            user_id: value.user_id.to_string(),
+            email: value.email.value(),
+            lastname: value.lastname,
+            firstname: value.firstname,
+            locale: value.locale.map(|locale| locale.to_string()),
+            expired_at: value.expired_at.to_string(),
+            created_at: value.created_at.to_string(),
+            updated_at: value.updated_at.to_string(),
+        }
+    }
+}
+
+// ================ Invite user ================
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct InviteUserRequest {
+    pub email: String,
+    pub lastname: String,
+    pub firstname: String,
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    pub locale: Option<String>,
+}
+
+impl InviteUserRequest {
+    /// Convert into use case request
+    pub fn into_use_case_request(
+        self,
+        expiration_duration: i64,
+        audit: AuditContext,
+    ) -> Result<InviteUserUseCaseRequest, ApiError> {
+        let scopes = self
+            .scopes
+            .iter()
+            .map(|scope| ScopeId::new(scope))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(InviteUserUseCaseRequest {
+            email: Email::new(&self.email)?,
+            lastname: self.lastname,
+            firstname: self.firstname,
+            scopes,
+            locale: self.locale.map(|locale| Locale::new(&locale)).transpose()?,
+            expiration_duration,
+            audit,
+        })
+    }
+}
+
+// ================ Get invitations ================
+
+pub type GetInvitationsRequest = FilterRequest;
+
+pub type GetInvitationsResponse = ApiPaginated<InvitationResponse>;
+
+impl From<GetInvitationsUseCaseResponse> for ApiPage<InvitationResponse> {
+    fn from(value: GetInvitationsUseCaseResponse) -> Self {
+        Self {
+            data: value
+                .invitations
+                .into_iter()
+                .map(|invitation| invitation.into())
+                .collect(),
+            total: value.total,
+            next_cursor: value.next_cursor.map(|cursor| cursor.to_string()),
+        }
+    }
+}
+
+// ================ Resend invitation ================
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ResendInvitationResponse();
+
+impl From<ResendInvitationUseCaseResponse> for ResendInvitationResponse {
+    fn from(_: ResendInvitationUseCaseResponse) -> Self {
+        Self {}
+    }
+}
+
+// ================ Revoke invitation ================
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RevokeInvitationResponse();
+
+impl From<RevokeInvitationUseCaseResponse> for RevokeInvitationResponse {
+    fn from(_: RevokeInvitationUseCaseResponse) -> Self {
+        Self {}
+    }
+}
+
+// ================ Accept invitation ================
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AcceptInvitationRequest {
+    pub token: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AcceptInvitationResponse();
@@ -0,0 +1,19 @@
+//! Error conversion for invitation handlers
+
+use crate::domain::use_cases::invitation::InvitationUseCaseError;
+use crate::infrastructure::api::response::ApiError;
+
+impl From<InvitationUseCaseError> for ApiError {
+    fn from(value: InvitationUseCaseError) -> Self {
+        match value {
+            InvitationUseCaseError::DatabaseError(msg) => ApiError::InternalServerError(msg),
+            InvitationUseCaseError::InvalidId() => ApiError::InternalServerError(value.to_string()),
+            InvitationUseCaseError::InvitationNotFound() => ApiError::NotFound(value.to_string()),
+            InvitationUseCaseError::InvalidEmail(_) => ApiError::BadRequest(value.to_string()),
+            InvitationUseCaseError::SendEmailError(_) => ApiError::InternalServerError(value.to_string()),
+            InvitationUseCaseError::FromModelError() => {
+                ApiError::InternalServerError("Internal server error".to_string())
+            }
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! Invitations handlers
+
+mod dto;
+mod error;
+
+use crate::adapters::database::DatabaseRepositories;
+use crate::domain::use_cases::invitation::accept_invitation::AcceptInvitationUseCaseRequest;
+use crate::domain::use_cases::invitation::get_invitations::GetInvitationsUseCaseRequest;
+use crate::domain::use_cases::invitation::resend_invitation::ResendInvitationUseCaseRequest;
+use crate::domain::use_cases::invitation::revoke_invitation::RevokeInvitationUseCaseRequest;
+use crate::domain::value_objects::id::Id;
+use crate::domain::value_objects::password::Password;
+use crate::infrastructure::api::extractors::{ExtractAuditContext, ExtractRequestId, Path, Query};
+use crate::infrastructure::api::layers::state::SharedState;
+use crate::infrastructure::api::response::{ApiError, ApiPaginated, ApiSuccess};
+use crate::infrastructure::api::use_cases::AppUseCases;
+use axum::extract::{OriginalUri, State};
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use dto::{
+    AcceptInvitationRequest, AcceptInvitationResponse, GetInvitationsRequest, GetInvitationsResponse,
+    InvitationResponse, InviteUserRequest, ResendInvitationResponse, RevokeInvitationResponse,
+};
+use std::str::FromStr;
+
+/// Invite a user route: POST /api/v1/invitations
+#[instrument(skip(uc, state), name = "invite_user_handler")]
+pub async fn create<D: DatabaseRepositories>(
+    State(state): State<SharedState>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
+    Json(request): Json<InviteUserRequest>,
+) -> Result<ApiSuccess<InvitationResponse>, ApiError> {
+    let response = uc
+        .invitation
+        .invite_user
+        .call(request.into_use_case_request(state.config.invitation_expiration_duration, audit)?)
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::CREATED, response.into()))
+}
+
+/// Get pending invitations route: GET /api/v1/invitations
+#[instrument(skip(uc), name = "get_invitations_handler")]
+pub async fn get_all<D: DatabaseRepositories>(
+    Query(request): Query<GetInvitationsRequest>,
+    OriginalUri(uri): OriginalUri,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractRequestId(request_id): ExtractRequestId,
+) -> Result<GetInvitationsResponse, ApiError> {
+    let pagination = request.pagination()?;
+    let response = uc
+        .invitation
+        .get_invitations
+        .call(GetInvitationsUseCaseRequest {
+            pagination: pagination.clone(),
+            sorts: request.sorts(),
+        })
+        .await?;
+
+    Ok(ApiPaginated::new(response.into(), &pagination, &uri))
+}
+
+/// Resend an invitation with a new token route: POST /api/v1/invitations/:invitation_id/resend
+#[instrument(skip(uc, state), name = "resend_invitation_handler")]
+pub async fn resend<D: DatabaseRepositories>(
+    Path(invitation_id): Path<String>,
+    State(state): State<SharedState>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractRequestId(request_id): ExtractRequestId,
+) -> Result<ApiSuccess<ResendInvitationResponse>, ApiError> {
+    let response = uc
+        .invitation
+        .resend_invitation
+        .call(ResendInvitationUseCaseRequest {
+            id: Id::from_str(&invitation_id)?,
+            expiration_duration: state.config.invitation_expiration_duration,
+        })
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::NO_CONTENT, response.into()))
+}
+
+/// Revoke an invitation route: DELETE /api/v1/invitations/:invitation_id
+#[instrument(skip(uc), name = "revoke_invitation_handler")]
+pub async fn revoke<D: DatabaseRepositories>(
+    Path(invitation_id): Path<String>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractRequestId(request_id): ExtractRequestId,
+) -> Result<ApiSuccess<RevokeInvitationResponse>, ApiError> {
+    let response = uc
+        .invitation
+        .revoke_invitation
+        .call(RevokeInvitationUseCaseRequest {
+            id: Id::from_str(&invitation_id)?,
+        })
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::NO_CONTENT, response.into()))
+}
+
+/// Accept an invitation by choosing a password route: PATCH /api/v1/accept-invitation
+#[instrument(skip(uc, state, body), name = "accept_invitation_handler")]
+pub async fn accept<D: DatabaseRepositories>(
+    State(state): State<SharedState>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
+    Json(body): Json<AcceptInvitationRequest>,
+) -> Result<ApiSuccess<AcceptInvitationResponse>, ApiError> {
+    uc.invitation
+        .accept_invitation
+        .call(AcceptInvitationUseCaseRequest {
+            token: body.token,
+            password: Password::new_with_policy(&body.password, false, &state.config.password_policy)?,
+            audit,
+        })
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::NO_CONTENT, AcceptInvitationResponse()))
+}
@@ -1,8 +1,15 @@
 //! Handlers
 
 pub mod application;
+pub mod audit;
+pub mod client;
+pub mod dev;
 pub mod external_link;
+pub mod federation;
 pub mod filter;
+pub mod introspection;
+pub mod invitation;
 pub mod scope;
 pub mod user;
 pub mod web;
+pub mod webhook;
@@ -5,6 +5,7 @@ use crate::domain::use_cases::scope::delete_scope::DeleteScopeUseCaseResponse;
 use crate::domain::use_cases::scope::get_scopes::GetScopesUseCaseResponse;
 use crate::domain::use_cases::scope::restore_scope::RestoreScopeUseCaseResponse;
 use crate::infrastructure::api::handlers::filter::FilterRequest;
+use crate::infrastructure::api::response::{ApiPage, ApiPaginated};
 use serde::{Deserialize, Serialize};
 
 /// Scope response
@@ -46,17 +47,14 @@ pub struct GetScopesCustomFilterRequest {
     pub application_id: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
-pub struct GetScopesResponse {
-    pub total: i64,
-    pub data: Vec<ScopeResponse>,
-}
+pub type GetScopesResponse = ApiPaginated<ScopeResponse>;
 
-impl From<GetScopesUseCaseResponse> for GetScopesResponse {
+impl From<GetScopesUseCaseResponse> for ApiPage<ScopeResponse> {
     fn from(value: GetScopesUseCaseResponse) -> Self {
         Self {
-            total: value.total,
             data: value.scopes.into_iter().map(|app| app.into()).collect(),
+            total: value.total,
+            next_cursor: value.next_cursor.map(|cursor| cursor.to_string()),
         }
     }
 }
@@ -1,18 +1,21 @@
 //! Scopes handlers
 
+use crate::adapters::database::DatabaseRepositories;
 use crate::domain::use_cases::scope::create_scope::CreateScopeUseCaseRequest;
 use crate::domain::use_cases::scope::delete_scope::DeleteScopeUseCaseRequest;
 use crate::domain::use_cases::scope::get_scopes::GetScopesUseCaseRequest;
 use crate::domain::use_cases::scope::restore_scope::RestoreScopeUseCaseRequest;
 use crate::domain::value_objects::id::Id;
 use crate::domain::value_objects::scope_id::ScopeId;
-use crate::infrastructure::api::extractors::{ExtractRequestId, Path, Query};
+use crate::infrastructure::api::extractors::{ExtractAuditContext, ExtractRequestId, Path, Query};
+use crate::infrastructure::api::handlers::filter::QueryFiltersRequest;
 use crate::infrastructure::api::handlers::scope::dto::{
     CreateScopeRequest, DeleteScopeResponse, GetScopesFilterRequest, GetScopesResponse, RestoreScopeResponse,
     ScopeResponse,
 };
-use crate::infrastructure::api::response::{ApiError, ApiSuccess};
+use crate::infrastructure::api::response::{ApiError, ApiPaginated, ApiSuccess};
 use crate::infrastructure::api::use_cases::AppUseCases;
+use axum::extract::OriginalUri;
 use axum::http::StatusCode;
 use axum::{Extension, Json};
 use dto::GetScopesCustomFilterRequest;
@@ -23,10 +26,10 @@ mod error;
 
 /// Scope creation route: POST /api/v1/applications/:application_id/scopes
 #[instrument(skip(uc), name = "create_scopes_handler")]
-pub async fn create(
+pub async fn create<D: DatabaseRepositories>(
     Path(application_id): Path<String>,
-    Extension(uc): Extension<AppUseCases>,
-    ExtractRequestId(request_id): ExtractRequestId,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
     Json(request): Json<CreateScopeRequest>,
 ) -> Result<ApiSuccess<ScopeResponse>, ApiError> {
     let response = uc
@@ -35,6 +38,7 @@ pub async fn create(
         .call(CreateScopeUseCaseRequest {
             application_id: Id::from_str(&application_id)?,
             id: ScopeId::new(&request.id)?,
+            audit,
         })
         .await?;
 
@@ -43,70 +47,79 @@ pub async fn create(
 
 /// Get scopes route: GET /api/v1/scopes
 #[instrument(skip(uc), name = "get_scopes_handler")]
-pub async fn get_all(
+pub async fn get_all<D: DatabaseRepositories>(
     Query(request): Query<GetScopesFilterRequest>,
+    Query(filters): Query<QueryFiltersRequest>,
     Query(filter): Query<GetScopesCustomFilterRequest>,
-    Extension(uc): Extension<AppUseCases>,
+    OriginalUri(uri): OriginalUri,
+    Extension(uc): Extension<AppUseCases<D>>,
     ExtractRequestId(request_id): ExtractRequestId,
-) -> Result<ApiSuccess<GetScopesResponse>, ApiError> {
+) -> Result<GetScopesResponse, ApiError> {
     let application_id = match filter.application_id {
         Some(id) => Some(Id::from_str(&id)?),
         None => None,
     };
 
+    let pagination = request.pagination()?;
     let response = uc
         .scope
         .get_scopes
         .call(GetScopesUseCaseRequest {
-            pagination: request.pagination(),
+            pagination: pagination.clone(),
             sorts: request.sorts(),
+            filters: filters.filters()?,
             deleted: false,
             application_id,
         })
         .await?;
 
-    Ok(ApiSuccess::new(StatusCode::OK, response.into()))
+    Ok(ApiPaginated::new(response.into(), &pagination, &uri))
 }
 
 /// Get scopes route: GET /api/v1/scopes/deleted
 #[instrument(skip(uc), name = "get_deleted_scopes_handler")]
-pub async fn get_all_deleted(
+pub async fn get_all_deleted<D: DatabaseRepositories>(
     Query(request): Query<GetScopesFilterRequest>,
+    Query(filters): Query<QueryFiltersRequest>,
     Query(filter): Query<GetScopesCustomFilterRequest>,
-    Extension(uc): Extension<AppUseCases>,
+    OriginalUri(uri): OriginalUri,
+    Extension(uc): Extension<AppUseCases<D>>,
     ExtractRequestId(request_id): ExtractRequestId,
-) -> Result<ApiSuccess<GetScopesResponse>, ApiError> {
+) -> Result<GetScopesResponse, ApiError> {
     let application_id = match filter.application_id {
         Some(id) => Some(Id::from_str(&id)?),
         None => None,
     };
 
+    let pagination = request.pagination()?;
     let response = uc
         .scope
         .get_scopes
         .call(GetScopesUseCaseRequest {
-            pagination: request.pagination(),
+            pagination: pagination.clone(),
             sorts: request.sorts(),
+            filters: filters.filters()?,
             deleted: true,
             application_id,
         })
         .await?;
 
-    Ok(ApiSuccess::new(StatusCode::OK, response.into()))
+    Ok(ApiPaginated::new(response.into(), &pagination, &uri))
 }
 
 /// Delete a scope route: DELETE /api/v1/scopes/:scope_id
 #[instrument(skip(uc), name = "delete_scope_handler")]
-pub async fn delete(
+pub async fn delete<D: DatabaseRepositories>(
     Path(scope_id): Path<String>,
-    Extension(uc): Extension<AppUseCases>,
-    ExtractRequestId(request_id): ExtractRequestId,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
 ) -> Result<ApiSuccess<DeleteScopeResponse>, ApiError> {
     let response = uc
         .scope
         .delete_scope
         .call(DeleteScopeUseCaseRequest {
             id: ScopeId::new(&scope_id)?,
+            audit,
         })
         .await?;
 
@@ -115,16 +128,17 @@ pub async fn delete(
 
 /// Restore a deleted scope route: PATCH /api/v1/scopes/:scope_id/restore
 #[instrument(skip(uc), name = "restore_scope_handler")]
-pub async fn restore(
+pub async fn restore<D: DatabaseRepositories>(
     Path(scope_id): Path<String>,
-    Extension(uc): Extension<AppUseCases>,
-    ExtractRequestId(request_id): ExtractRequestId,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
 ) -> Result<ApiSuccess<RestoreScopeResponse>, ApiError> {
     let response = uc
         .scope
         .restore_scope
         .call(RestoreScopeUseCaseRequest {
             id: ScopeId::new(&scope_id)?,
+            audit,
         })
         .await?;
 
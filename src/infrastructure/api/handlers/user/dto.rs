@@ -1,5 +1,7 @@
 //! Users handlers DTO
 
+use crate::domain::entities::audit_event::AuditContext;
+use crate::domain::use_cases::user::consume_magic_link::ConsumeMagicLinkUseCaseResponse;
 use crate::domain::use_cases::user::create_user::CreateUserUseCaseRequest;
 use crate::domain::use_cases::user::delete_user::DeleteUserUseCaseResponse;
 use crate::domain::use_cases::user::forgotten_password::ForgottenPasswordUseCaseResponse;
@@ -8,10 +10,14 @@ use crate::domain::use_cases::user::get_user::GetUserUseCaseResponse;
 use crate::domain::use_cases::user::get_users::GetUsersUseCaseResponse;
 use crate::domain::use_cases::user::refresh_token::RefreshTokenUseCaseResponse;
 use crate::domain::use_cases::user::restore_user::RestoreUserUseCaseResponse;
+use crate::domain::use_cases::user::revoke_sessions::RevokeSessionsUseCaseResponse;
 use crate::domain::use_cases::user::{UserUseCaseError, UserUseCaseResponse};
 use crate::domain::value_objects::email::Email;
+use crate::domain::value_objects::locale::Locale;
 use crate::domain::value_objects::password::Password;
+use crate::domain::value_objects::password_policy::PasswordPolicy;
 use crate::infrastructure::api::handlers::filter::FilterRequest;
+use crate::infrastructure::api::response::{ApiPage, ApiPaginated};
 use serde::{Deserialize, Serialize};
 
 /// User response
@@ -21,6 +27,7 @@ pub struct UserResponse {
     pub lastname: String,
     pub firstname: String,
     pub email: String,
+    pub locale: Option<String>,
     pub created_at: String,
     pub updated_at: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -34,6 +41,7 @@ impl From<UserUseCaseResponse> for UserResponse {
             lastname: value.lastname,
             firstname: value.firstname,
             email: value.email.value(),
+            locale: value.locale.map(|locale| locale.to_string()),
             created_at: value.created_at.to_string(),
             updated_at: value.updated_at.to_string(),
             deleted_at: value.deleted_at.map(|d| d.to_string()),
@@ -49,21 +57,32 @@ pub struct CreateUserRequest {
     pub password: String,
     pub lastname: String,
     pub firstname: String,
+    pub locale: Option<String>,
 }
 
-impl TryFrom<CreateUserRequest> for CreateUserUseCaseRequest {
-    type Error = UserUseCaseError;
-
-    fn try_from(value: CreateUserRequest) -> Result<Self, Self::Error> {
-        let email = Email::new(&value.email).map_err(|err| UserUseCaseError::InvalidEmail(err.to_string()))?;
-        let password =
-            Password::new(&value.password, false).map_err(|err| UserUseCaseError::InvalidPassword(err.to_string()))?;
-
-        Ok(Self {
+impl CreateUserRequest {
+    /// Convert into use case request, the password must respect the password policy
+    pub fn into_use_case_request(
+        self,
+        policy: &PasswordPolicy,
+        audit: AuditContext,
+    ) -> Result<CreateUserUseCaseRequest, UserUseCaseError> {
+        let email = Email::new(&self.email).map_err(|err| UserUseCaseError::InvalidEmail(err.to_string()))?;
+        let password = Password::new_with_policy(&self.password, false, policy)
+            .map_err(|err| UserUseCaseError::InvalidPassword(err.to_string()))?;
+        let locale = self
+            .locale
+            .map(|locale| Locale::new(&locale))
+            .transpose()
+            .map_err(|err| UserUseCaseError::InvalidLocale(err.to_string()))?;
+
+        Ok(CreateUserUseCaseRequest {
             email,
             password,
-            lastname: value.lastname,
-            firstname: value.firstname,
+            lastname: self.lastname,
+            firstname: self.firstname,
+            locale,
+            audit,
         })
     }
 }
@@ -74,23 +93,42 @@ impl TryFrom<CreateUserRequest> for CreateUserUseCaseRequest {
 pub struct GetAccessTokenRequest {
     pub email: String,
     pub password: String,
+    pub application_id: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
-pub struct GetAccessTokenResponse {
-    pub access_token: String,
-    pub access_token_expired_at: String,
-    pub refresh_token: String,
-    pub refresh_token_expired_at: String,
+#[serde(untagged)]
+pub enum GetAccessTokenResponse {
+    Tokens {
+        access_token: String,
+        access_token_expired_at: String,
+        refresh_token: String,
+        refresh_token_expired_at: String,
+    },
+    PasswordExpired {
+        message: String,
+        token: String,
+        expired_at: String,
+    },
 }
 
 impl From<GetAccessTokenUseCaseResponse> for GetAccessTokenResponse {
     fn from(value: GetAccessTokenUseCaseResponse) -> Self {
-        Self {
-            access_token: value.access_token.token,
-            access_token_expired_at: value.access_token.expired_at.to_string(),
-            refresh_token: value.refresh_token.refresh_token.to_string(),
-            refresh_token_expired_at: value.refresh_token.expired_at.to_string(),
+        match value {
+            GetAccessTokenUseCaseResponse::Tokens {
+                access_token,
+                refresh_token,
+            } => Self::Tokens {
+                access_token: access_token.token,
+                access_token_expired_at: access_token.expired_at.to_string(),
+                refresh_token: refresh_token.refresh_token.to_string(),
+                refresh_token_expired_at: refresh_token.expired_at.to_string(),
+            },
+            GetAccessTokenUseCaseResponse::PasswordExpired(password_reset) => Self::PasswordExpired {
+                message: "Password has expired".to_string(),
+                token: password_reset.token.to_string(),
+                expired_at: password_reset.expired_at.to_string(),
+            },
         }
     }
 }
@@ -99,17 +137,20 @@ impl From<GetAccessTokenUseCaseResponse> for GetAccessTokenResponse {
 
 pub type GetUsersRequest = FilterRequest;
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
-pub struct GetUsersResponse {
-    pub total: i64,
-    pub data: Vec<UserResponse>,
+#[derive(Debug, Clone, Deserialize)]
+pub struct SearchUsersRequest {
+    /// Full-text search on email, firstname and lastname
+    pub q: Option<String>,
 }
 
-impl From<GetUsersUseCaseResponse> for GetUsersResponse {
+pub type GetUsersResponse = ApiPaginated<UserResponse>;
+
+impl From<GetUsersUseCaseResponse> for ApiPage<UserResponse> {
     fn from(value: GetUsersUseCaseResponse) -> Self {
         Self {
-            total: value.total,
             data: value.users.into_iter().map(|u| u.into()).collect(),
+            total: value.total,
+            next_cursor: value.next_cursor.map(|cursor| cursor.to_string()),
         }
     }
 }
@@ -138,22 +179,29 @@ impl From<DeleteUserUseCaseResponse> for DeleteUserResponse {
 
 // ================ Refresh token ================
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
-pub struct RefreshTokenResponse {
-    pub access_token: String,
-    pub access_token_expired_at: String,
-    pub refresh_token: String,
-    pub refresh_token_expired_at: String,
+#[derive(Debug, Clone, Deserialize)]
+pub struct RefreshTokenRequest {
+    pub application_id: String,
 }
 
+/// Same response as the access token route, including the expired password case
+pub type RefreshTokenResponse = GetAccessTokenResponse;
+
 impl From<RefreshTokenUseCaseResponse> for RefreshTokenResponse {
     fn from(value: RefreshTokenUseCaseResponse) -> Self {
-        Self {
-            access_token: value.access_token.token,
-            access_token_expired_at: value.access_token.expired_at.to_string(),
-            refresh_token: value.refresh_token.refresh_token.to_string(),
-            refresh_token_expired_at: value.refresh_token.expired_at.to_string(),
+        match value {
+            RefreshTokenUseCaseResponse::Tokens {
+                access_token,
+                refresh_token,
+            } => GetAccessTokenUseCaseResponse::Tokens {
+                access_token,
+                refresh_token,
+            },
+            RefreshTokenUseCaseResponse::PasswordExpired(password_reset) => {
+                GetAccessTokenUseCaseResponse::PasswordExpired(password_reset)
+            }
         }
+        .into()
     }
 }
 
@@ -195,3 +243,46 @@ impl From<RestoreUserUseCaseResponse> for RestoreUserResponse {
         Self()
     }
 }
+
+// ================ Revoke sessions ================
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RevokeSessionsResponse();
+
+impl From<RevokeSessionsUseCaseResponse> for RevokeSessionsResponse {
+    fn from(_value: RevokeSessionsUseCaseResponse) -> Self {
+        Self()
+    }
+}
+
+// ================ Magic link ================
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SendMagicLinkResponse();
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ConsumeMagicLinkRequest {
+    pub token: String,
+    pub application_id: String,
+}
+
+/// Same response as the access token route, including the expired password case
+pub type ConsumeMagicLinkResponse = GetAccessTokenResponse;
+
+impl From<ConsumeMagicLinkUseCaseResponse> for ConsumeMagicLinkResponse {
+    fn from(value: ConsumeMagicLinkUseCaseResponse) -> Self {
+        match value {
+            ConsumeMagicLinkUseCaseResponse::Tokens {
+                access_token,
+                refresh_token,
+            } => GetAccessTokenUseCaseResponse::Tokens {
+                access_token,
+                refresh_token,
+            },
+            ConsumeMagicLinkUseCaseResponse::PasswordExpired(password_reset) => {
+                GetAccessTokenUseCaseResponse::PasswordExpired(password_reset)
+            }
+        }
+        .into()
+    }
+}
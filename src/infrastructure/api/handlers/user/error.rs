@@ -8,6 +8,7 @@ impl From<UserUseCaseError> for ApiError {
         match value {
             UserUseCaseError::InvalidEmail(msg) => ApiError::BadRequest(msg),
             UserUseCaseError::InvalidPassword(msg) => ApiError::BadRequest(msg),
+            UserUseCaseError::InvalidLocale(msg) => ApiError::BadRequest(msg),
             UserUseCaseError::InvalidUtcDateTime(msg) => ApiError::InternalServerError(msg),
             UserUseCaseError::InvalidArguments(msg) => ApiError::BadRequest(msg),
             UserUseCaseError::SendEmailError(msg) => ApiError::InternalServerError(msg),
@@ -17,10 +18,12 @@ impl From<UserUseCaseError> for ApiError {
             UserUseCaseError::UserNotFound() => ApiError::NotFound(value.to_string()),
             UserUseCaseError::Unauthorized() => ApiError::Unauthorized(value.to_string()),
             UserUseCaseError::ForgottenPasswordNotFound() => ApiError::NotFound(value.to_string()),
+            UserUseCaseError::ApplicationNotFound() => ApiError::NotFound(value.to_string()),
+            UserUseCaseError::MagicLinkNotFound() => ApiError::Unauthorized(value.to_string()),
+            UserUseCaseError::PasswordAlreadyUsed() => ApiError::BadRequest(value.to_string()),
             UserUseCaseError::AccessTokenGenerationError() => ApiError::InternalServerError(value.to_string()),
             UserUseCaseError::InvalidRefreshToken() => ApiError::Unauthorized("Invalid refresh token".to_string()),
             UserUseCaseError::FromModelError() => ApiError::InternalServerError("Internal server error".to_string()),
-            UserUseCaseError::IncorrectPassword() => ApiError::Unauthorized("User not found".to_string()),
         }
     }
 }
@@ -3,114 +3,146 @@
 mod dto;
 mod error;
 
+use crate::adapters::database::DatabaseRepositories;
 use crate::domain::entities::user::UserId;
-use crate::domain::use_cases::user::create_user::CreateUserUseCaseRequest;
+use crate::domain::use_cases::user::consume_magic_link::{
+    ConsumeMagicLinkUseCaseRequest, ConsumeMagicLinkUseCaseResponse,
+};
 use crate::domain::use_cases::user::delete_user::DeleteUserUseCaseRequest;
 use crate::domain::use_cases::user::forgotten_password::ForgottenPasswordUseCaseRequest;
-use crate::domain::use_cases::user::get_access_token::GetAccessTokenUseCaseRequest;
+use crate::domain::use_cases::user::get_access_token::{GetAccessTokenUseCaseRequest, GetAccessTokenUseCaseResponse};
 use crate::domain::use_cases::user::get_user::GetUserUseCaseRequest;
 use crate::domain::use_cases::user::get_users::GetUsersUseCaseRequest;
-use crate::domain::use_cases::user::refresh_token::RefreshTokenUseCaseRequest;
+use crate::domain::use_cases::user::refresh_token::{RefreshTokenUseCaseRequest, RefreshTokenUseCaseResponse};
 use crate::domain::use_cases::user::restore_user::RestoreUserUseCaseRequest;
+use crate::domain::use_cases::user::revoke_sessions::RevokeSessionsUseCaseRequest;
+use crate::domain::use_cases::user::send_magic_link::SendMagicLinkUseCaseRequest;
 use crate::domain::use_cases::user::update_password_from_token::UpdatePasswordFromTokenUseCaseRequest;
 use crate::domain::value_objects::email::Email;
 use crate::domain::value_objects::id::Id;
-use crate::domain::value_objects::password::Password;
-use crate::infrastructure::api::extractors::{ExtractRequestId, Path, Query};
+use crate::domain::value_objects::password::{Password, PlainPassword};
+use crate::infrastructure::api::extractors::{ExtractAuditContext, ExtractRequestId, Path, Query};
+use crate::infrastructure::api::handlers::filter::QueryFiltersRequest;
 use crate::infrastructure::api::handlers::user::dto::*;
 use crate::infrastructure::api::layers::state::SharedState;
-use crate::infrastructure::api::response::{ApiError, ApiSuccess};
+use crate::infrastructure::api::response::{ApiError, ApiPaginated, ApiSuccess};
 use crate::infrastructure::api::use_cases::AppUseCases;
-use axum::extract::State;
+use axum::extract::{OriginalUri, State};
 use axum::http::StatusCode;
 use axum::{Extension, Json};
 use std::str::FromStr;
 
 /// User creation route: POST /api/v1/users
-#[instrument(skip(uc), name = "create_user_handler")]
-pub async fn create(
-    Extension(uc): Extension<AppUseCases>,
-    ExtractRequestId(request_id): ExtractRequestId,
+#[instrument(skip(uc, state), name = "create_user_handler")]
+pub async fn create<D: DatabaseRepositories>(
+    Extension(uc): Extension<AppUseCases<D>>,
+    State(state): State<SharedState>,
+    ExtractAuditContext(audit): ExtractAuditContext,
     Json(request): Json<CreateUserRequest>,
 ) -> Result<ApiSuccess<UserResponse>, ApiError> {
     let response = uc
         .user
         .create_user
-        .call(CreateUserUseCaseRequest::try_from(request)?)
+        .call(request.into_use_case_request(&state.config.password_policy, audit)?)
         .await?;
 
     Ok(ApiSuccess::new(StatusCode::CREATED, response.into()))
 }
 
 /// User access token route: POST /api/v1/token
+///
+/// If the user password has expired, a `403 Forbidden` is returned with a password reset token
+/// which must be used to update the password (`PATCH /api/v1/update-password`).
 #[instrument(skip(uc, state), name = "get_access_token_handler")]
-pub async fn get_access_token(
-    Extension(uc): Extension<AppUseCases>,
+pub async fn get_access_token<D: DatabaseRepositories>(
+    Extension(uc): Extension<AppUseCases<D>>,
     State(state): State<SharedState>,
-    ExtractRequestId(request_id): ExtractRequestId,
+    ExtractAuditContext(audit): ExtractAuditContext,
     Json(request): Json<GetAccessTokenRequest>,
 ) -> Result<ApiSuccess<GetAccessTokenResponse>, ApiError> {
     let email = Email::new(&request.email).map_err(|err| ApiError::BadRequest(err.to_string()))?;
-    let password = Password::new(&request.password, false).map_err(|err| ApiError::BadRequest(err.to_string()))?;
+    let password = PlainPassword::new(&request.password).map_err(|err| ApiError::BadRequest(err.to_string()))?;
 
     let response = uc
         .user
         .get_access_token
         .call(GetAccessTokenUseCaseRequest {
             email,
+            application_id: Id::from_str(&request.application_id)?,
             password,
             jwt: state.jwt.clone(),
+            password_lifetime: state.config.password_lifetime,
+            password_reset_expiration_duration: state.config.forgotten_password_expiration_duration,
+            password_policy: state.config.password_policy.clone(),
+            audit,
         })
         .await?;
 
-    Ok(ApiSuccess::new(StatusCode::OK, response.into()))
+    let status = match response {
+        GetAccessTokenUseCaseResponse::Tokens { .. } => StatusCode::OK,
+        GetAccessTokenUseCaseResponse::PasswordExpired(_) => StatusCode::FORBIDDEN,
+    };
+
+    Ok(ApiSuccess::new(status, response.into()))
 }
 
 /// Users list route: GET /api/v1/users
 #[instrument(skip(uc), name = "get_users_handler")]
-pub async fn get_all(
+pub async fn get_all<D: DatabaseRepositories>(
     Query(request): Query<GetUsersRequest>,
-    Extension(uc): Extension<AppUseCases>,
+    Query(filters): Query<QueryFiltersRequest>,
+    Query(search): Query<SearchUsersRequest>,
+    OriginalUri(uri): OriginalUri,
+    Extension(uc): Extension<AppUseCases<D>>,
     ExtractRequestId(request_id): ExtractRequestId,
-) -> Result<ApiSuccess<GetUsersResponse>, ApiError> {
+) -> Result<GetUsersResponse, ApiError> {
+    let pagination = request.pagination()?;
     let response = uc
         .user
         .get_users
         .call(GetUsersUseCaseRequest {
-            pagination: request.pagination(),
+            pagination: pagination.clone(),
             sorts: request.sorts(),
+            filters: filters.filters()?,
+            search: search.q,
             deleted: false,
         })
         .await?;
 
-    Ok(ApiSuccess::new(StatusCode::OK, response.into()))
+    Ok(ApiPaginated::new(response.into(), &pagination, &uri))
 }
 
 /// Deleted users list route: GET /api/v1/users/deleted
 #[instrument(skip(uc), name = "get_deleted_users_handler")]
-pub async fn get_all_deleted(
+pub async fn get_all_deleted<D: DatabaseRepositories>(
     Query(request): Query<GetUsersRequest>,
-    Extension(uc): Extension<AppUseCases>,
+    Query(filters): Query<QueryFiltersRequest>,
+    Query(search): Query<SearchUsersRequest>,
+    OriginalUri(uri): OriginalUri,
+    Extension(uc): Extension<AppUseCases<D>>,
     ExtractRequestId(request_id): ExtractRequestId,
-) -> Result<ApiSuccess<GetUsersResponse>, ApiError> {
+) -> Result<GetUsersResponse, ApiError> {
+    let pagination = request.pagination()?;
     let response = uc
         .user
         .get_users
         .call(GetUsersUseCaseRequest {
-            pagination: request.pagination(),
+            pagination: pagination.clone(),
             sorts: request.sorts(),
+            filters: filters.filters()?,
+            search: search.q,
             deleted: true,
         })
         .await?;
 
-    Ok(ApiSuccess::new(StatusCode::OK, response.into()))
+    Ok(ApiPaginated::new(response.into(), &pagination, &uri))
 }
 
 /// Get user route: GET /api/v1/users/:user_id
 #[instrument(skip(uc), name = "get_user_handler")]
-pub async fn get_by_id(
+pub async fn get_by_id<D: DatabaseRepositories>(
     Path(user_id): Path<String>,
-    Extension(uc): Extension<AppUseCases>,
+    Extension(uc): Extension<AppUseCases<D>>,
     ExtractRequestId(request_id): ExtractRequestId,
 ) -> Result<ApiSuccess<GetUserResponse>, ApiError> {
     let response = uc
@@ -126,16 +158,17 @@ pub async fn get_by_id(
 
 /// Delete user route: DELETE /api/v1/users/:user_id
 #[instrument(skip(uc), name = "delete_user_handler")]
-pub async fn delete(
+pub async fn delete<D: DatabaseRepositories>(
     Path(user_id): Path<String>,
-    Extension(uc): Extension<AppUseCases>,
-    ExtractRequestId(request_id): ExtractRequestId,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
 ) -> Result<ApiSuccess<DeleteUserResponse>, ApiError> {
     let response = uc
         .user
         .delete_user
         .call(DeleteUserUseCaseRequest {
             user_id: UserId::from_str(&user_id)?,
+            audit,
         })
         .await?;
 
@@ -144,16 +177,36 @@ pub async fn delete(
 
 /// Restore user route: PATCH /api/v1/users/:user_id/restore
 #[instrument(skip(uc), name = "restore_user_handler")]
-pub async fn restore(
+pub async fn restore<D: DatabaseRepositories>(
     Path(user_id): Path<String>,
-    Extension(uc): Extension<AppUseCases>,
-    ExtractRequestId(request_id): ExtractRequestId,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
 ) -> Result<ApiSuccess<RestoreUserResponse>, ApiError> {
     let response = uc
         .user
         .restore_user
         .call(RestoreUserUseCaseRequest {
             user_id: UserId::from_str(&user_id)?,
+            audit,
+        })
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::NO_CONTENT, response.into()))
+}
+
+/// Revoke user sessions route: DELETE /api/v1/users/:user_id/sessions
+#[instrument(skip(uc), name = "revoke_sessions_handler")]
+pub async fn revoke_sessions<D: DatabaseRepositories>(
+    Path(user_id): Path<String>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
+) -> Result<ApiSuccess<RevokeSessionsResponse>, ApiError> {
+    let response = uc
+        .user
+        .revoke_sessions
+        .call(RevokeSessionsUseCaseRequest {
+            user_id: UserId::from_str(&user_id)?,
+            audit,
         })
         .await?;
 
@@ -161,31 +214,43 @@ pub async fn restore(
 }
 
 /// Refresh token route: POST /api/v1/refresh-token/:refresh_token
+///
+/// As for the access token route, a `403 Forbidden` is returned with a password reset token
+/// if the user password has expired.
 #[instrument(skip(uc, state), name = "refresh_token_user_handler")]
-pub async fn refresh_token(
+pub async fn refresh_token<D: DatabaseRepositories>(
     Path(refresh_token): Path<String>,
     State(state): State<SharedState>,
-    Extension(uc): Extension<AppUseCases>,
+    Extension(uc): Extension<AppUseCases<D>>,
     ExtractRequestId(request_id): ExtractRequestId,
+    Json(request): Json<RefreshTokenRequest>,
 ) -> Result<ApiSuccess<RefreshTokenResponse>, ApiError> {
     let response = uc
         .user
         .refresh_token
         .call(RefreshTokenUseCaseRequest {
             refresh_token_id: Id::from_str(&refresh_token)?,
+            application_id: Id::from_str(&request.application_id)?,
             jwt: state.jwt.clone(),
+            password_lifetime: state.config.password_lifetime,
+            password_reset_expiration_duration: state.config.forgotten_password_expiration_duration,
         })
         .await?;
 
-    Ok(ApiSuccess::new(StatusCode::OK, response.into()))
+    let status = match response {
+        RefreshTokenUseCaseResponse::Tokens { .. } => StatusCode::OK,
+        RefreshTokenUseCaseResponse::PasswordExpired(_) => StatusCode::FORBIDDEN,
+    };
+
+    Ok(ApiSuccess::new(status, response.into()))
 }
 
 /// Send forgotten password request: POST /api/v1/forgotten-password/:email
 #[instrument(skip(uc, state), name = "forgotten_password_handler")]
-pub async fn forgotten_password(
+pub async fn forgotten_password<D: DatabaseRepositories>(
     Path(email): Path<String>,
     State(state): State<SharedState>,
-    Extension(uc): Extension<AppUseCases>,
+    Extension(uc): Extension<AppUseCases<D>>,
     ExtractRequestId(request_id): ExtractRequestId,
 ) -> Result<ApiSuccess<ForgottenPasswordResponse>, ApiError> {
     let response = uc
@@ -201,9 +266,10 @@ pub async fn forgotten_password(
 }
 
 /// Update user password from forgotten password request: PATCH /api/v1/update-password
-#[instrument(skip(uc), name = "update_password_from_token_handler")]
-pub async fn update_password_from_token(
-    Extension(uc): Extension<AppUseCases>,
+#[instrument(skip(uc, state), name = "update_password_from_token_handler")]
+pub async fn update_password_from_token<D: DatabaseRepositories>(
+    Extension(uc): Extension<AppUseCases<D>>,
+    State(state): State<SharedState>,
     ExtractRequestId(request_id): ExtractRequestId,
     Json(body): Json<UpdatePasswordFromTokenRequest>,
 ) -> Result<ApiSuccess<UpdatePasswordFromTokenResponse>, ApiError> {
@@ -211,7 +277,8 @@ pub async fn update_password_from_token(
         .update_password_from_token
         .call(UpdatePasswordFromTokenUseCaseRequest {
             token: body.token,
-            password: Password::new(&body.password, false)?,
+            password: Password::new_with_policy(&body.password, false, &state.config.password_policy)?,
+            password_history_size: state.config.password_history_size,
         })
         .await?;
 
@@ -220,3 +287,54 @@ pub async fn update_password_from_token(
         UpdatePasswordFromTokenResponse(),
     ))
 }
+
+/// Send a magic link by email to log in without password: POST /api/v1/magic-link/:email
+#[instrument(skip(uc, state), name = "send_magic_link_handler")]
+pub async fn send_magic_link<D: DatabaseRepositories>(
+    Path(email): Path<String>,
+    State(state): State<SharedState>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractRequestId(request_id): ExtractRequestId,
+) -> Result<ApiSuccess<SendMagicLinkResponse>, ApiError> {
+    uc.user
+        .send_magic_link
+        .call(SendMagicLinkUseCaseRequest {
+            user_email: Email::new(&email)?,
+            expiration_duration: state.config.magic_link_expiration_duration,
+        })
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::NO_CONTENT, SendMagicLinkResponse()))
+}
+
+/// Exchange a magic link token for an access token: POST /api/v1/magic-link
+///
+/// As for the access token route, a `403 Forbidden` is returned with a password reset token
+/// if the user password has expired.
+#[instrument(skip(uc, state, body), name = "consume_magic_link_handler")]
+pub async fn consume_magic_link<D: DatabaseRepositories>(
+    State(state): State<SharedState>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractAuditContext(audit): ExtractAuditContext,
+    Json(body): Json<ConsumeMagicLinkRequest>,
+) -> Result<ApiSuccess<ConsumeMagicLinkResponse>, ApiError> {
+    let response = uc
+        .user
+        .consume_magic_link
+        .call(ConsumeMagicLinkUseCaseRequest {
+            token: body.token,
+            application_id: Id::from_str(&body.application_id)?,
+            jwt: state.jwt.clone(),
+            password_lifetime: state.config.password_lifetime,
+            password_reset_expiration_duration: state.config.forgotten_password_expiration_duration,
+            audit,
+        })
+        .await?;
+
+    let status = match response {
+        ConsumeMagicLinkUseCaseResponse::Tokens { .. } => StatusCode::OK,
+        ConsumeMagicLinkUseCaseResponse::PasswordExpired(_) => StatusCode::FORBIDDEN,
+    };
+
+    Ok(ApiSuccess::new(status, response.into()))
+}
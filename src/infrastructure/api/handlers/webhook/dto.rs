@@ -0,0 +1,108 @@
+//! Application webhooks handlers DTO
+
+use crate::domain::entities::application::ApplicationId;
+use crate::domain::entities::webhook::{Webhook, WebhookEvent};
+use crate::domain::use_cases::webhook::create_webhook::{CreateWebhookUseCaseRequest, CreateWebhookUseCaseResponse};
+use crate::domain::use_cases::webhook::delete_webhook::DeleteWebhookUseCaseResponse;
+use crate::domain::use_cases::webhook::get_webhooks::GetWebhooksUseCaseResponse;
+use crate::infrastructure::api::handlers::filter::FilterRequest;
+use crate::infrastructure::api::response::{ApiError, ApiPage, ApiPaginated};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Webhook response (the secret is only returned at creation)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WebhookResponse {
+    pub id: String,
+    pub application_id: String,
+    pub url: String,
+    pub events: Vec<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<Webhook> for WebhookResponse {
+    fn from(value: Webhook) -> Self {
+        Self {
+            id: value.id.to_string(),
+            application_id: value.application_id.to_string(),
+            url: value.url,
+            events: value.events.iter().map(|event| event.to_string()).collect(),
+            created_at: value.created_at.to_string(),
+            updated_at: value.updated_at.to_string(),
+        }
+    }
+}
+
+// ================ Webhook creation ================
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateWebhookRequest {
+    pub url: String,
+    pub events: Vec<String>,
+}
+
+impl CreateWebhookRequest {
+    /// Convert into use case request
+    pub fn into_use_case_request(self, application_id: ApplicationId) -> Result<CreateWebhookUseCaseRequest, ApiError> {
+        let events = self
+            .events
+            .iter()
+            .map(|event| WebhookEvent::from_str(event))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ApiError::BadRequest)?;
+
+        Ok(CreateWebhookUseCaseRequest {
+            application_id,
+            url: self.url,
+            events,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CreateWebhookResponse {
+    #[serde(flatten)]
+    pub webhook: WebhookResponse,
+
+    /// Secret used to sign the payloads, it is only returned once
+    pub secret: String,
+}
+
+impl From<CreateWebhookUseCaseResponse> for CreateWebhookResponse {
+    fn from(value: CreateWebhookUseCaseResponse) -> Self {
+        let secret = value.webhook.secret.value().to_string();
+
+        Self {
+            webhook: value.webhook.into(),
+            secret,
+        }
+    }
+}
+
+// ================ Get webhooks ================
+
+pub type GetWebhooksRequest = FilterRequest;
+
+pub type GetWebhooksResponse = ApiPaginated<WebhookResponse>;
+
+impl From<GetWebhooksUseCaseResponse> for ApiPage<WebhookResponse> {
+    fn from(value: GetWebhooksUseCaseResponse) -> Self {
+        Self {
+            data: value.webhooks.into_iter().map(|webhook| webhook.into()).collect(),
+            total: value.total,
+            next_cursor: value.next_cursor.map(|cursor| cursor.to_string()),
+        }
+    }
+}
+
+// ================ Webhook deletion ================
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteWebhookResponse();
+
+impl From<DeleteWebhookUseCaseResponse> for DeleteWebhookResponse {
+    fn from(_value: DeleteWebhookUseCaseResponse) -> Self {
+        Self()
+    }
+}
@@ -0,0 +1,17 @@
+//! Error conversion for webhook handlers
+
+use crate::domain::use_cases::webhook::WebhookUseCaseError;
+use crate::infrastructure::api::response::ApiError;
+
+impl From<WebhookUseCaseError> for ApiError {
+    fn from(value: WebhookUseCaseError) -> Self {
+        match value {
+            WebhookUseCaseError::DatabaseError(msg) => ApiError::InternalServerError(msg),
+            WebhookUseCaseError::InvalidWebhook(msg) => ApiError::BadRequest(msg),
+            WebhookUseCaseError::InvalidId() => ApiError::InternalServerError(value.to_string()),
+            WebhookUseCaseError::ApplicationNotFound() => ApiError::NotFound(value.to_string()),
+            WebhookUseCaseError::WebhookNotFound() => ApiError::NotFound(value.to_string()),
+            WebhookUseCaseError::FromModelError() => ApiError::InternalServerError("Internal server error".to_string()),
+        }
+    }
+}
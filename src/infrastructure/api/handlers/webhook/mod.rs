@@ -0,0 +1,76 @@
+//! Application webhooks handlers
+
+mod dto;
+mod error;
+
+use crate::adapters::database::DatabaseRepositories;
+use crate::domain::use_cases::webhook::delete_webhook::DeleteWebhookUseCaseRequest;
+use crate::domain::use_cases::webhook::get_webhooks::GetWebhooksUseCaseRequest;
+use crate::domain::value_objects::id::Id;
+use crate::infrastructure::api::extractors::{ExtractRequestId, Path, Query};
+use crate::infrastructure::api::handlers::webhook::dto::*;
+use crate::infrastructure::api::response::{ApiError, ApiPaginated, ApiSuccess};
+use crate::infrastructure::api::use_cases::AppUseCases;
+use axum::extract::OriginalUri;
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use std::str::FromStr;
+
+/// Webhook creation route: POST /api/v1/applications/:application_id/webhooks
+#[instrument(skip(uc, request), name = "create_webhook_handler")]
+pub async fn create<D: DatabaseRepositories>(
+    Path(application_id): Path<String>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractRequestId(request_id): ExtractRequestId,
+    Json(request): Json<CreateWebhookRequest>,
+) -> Result<ApiSuccess<CreateWebhookResponse>, ApiError> {
+    let response = uc
+        .webhook
+        .create_webhook
+        .call(request.into_use_case_request(Id::from_str(&application_id)?)?)
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::CREATED, response.into()))
+}
+
+/// Get webhooks route: GET /api/v1/applications/:application_id/webhooks
+#[instrument(skip(uc), name = "get_webhooks_handler")]
+pub async fn get_all<D: DatabaseRepositories>(
+    Path(application_id): Path<String>,
+    Query(request): Query<GetWebhooksRequest>,
+    OriginalUri(uri): OriginalUri,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractRequestId(request_id): ExtractRequestId,
+) -> Result<GetWebhooksResponse, ApiError> {
+    let pagination = request.pagination()?;
+    let response = uc
+        .webhook
+        .get_webhooks
+        .call(GetWebhooksUseCaseRequest {
+            application_id: Id::from_str(&application_id)?,
+            pagination: pagination.clone(),
+            sorts: request.sorts(),
+        })
+        .await?;
+
+    Ok(ApiPaginated::new(response.into(), &pagination, &uri))
+}
+
+/// Delete a webhook route: DELETE /api/v1/applications/:application_id/webhooks/:webhook_id
+#[instrument(skip(uc), name = "delete_webhook_handler")]
+pub async fn delete<D: DatabaseRepositories>(
+    Path((application_id, webhook_id)): Path<(String, String)>,
+    Extension(uc): Extension<AppUseCases<D>>,
+    ExtractRequestId(request_id): ExtractRequestId,
+) -> Result<ApiSuccess<DeleteWebhookResponse>, ApiError> {
+    let response = uc
+        .webhook
+        .delete_webhook
+        .call(DeleteWebhookUseCaseRequest {
+            application_id: Id::from_str(&application_id)?,
+            id: Id::from_str(&webhook_id)?,
+        })
+        .await?;
+
+    Ok(ApiSuccess::new(StatusCode::NO_CONTENT, response.into()))
+}
@@ -77,7 +77,7 @@ where
         let now = Instant::now();
         let request_headers = request.headers();
 
-        let mut message = LoggerMessage {
+        let message = LoggerMessage {
             method: request.method().to_string(),
             uri: request.uri().to_string(),
             host: header_value_to_str(request_headers.get("host")).to_string(),
@@ -90,6 +90,7 @@ where
         Box::pin(async move {
             let response: Response = future.await?;
 
+            let mut message = message;
             message.status_code = response.status().as_u16();
             message.version = format!("{:?}", response.version());
             message.latency = now.elapsed();
@@ -2,7 +2,10 @@
 
 use crate::config::Config;
 use crate::domain::services::security::jwt::Jwt;
+use crate::domain::value_objects::password_policy::PasswordPolicy;
 use crate::infrastructure::api::response::ApiError;
+use std::net::IpAddr;
+use std::str::FromStr;
 use std::{fs::read_to_string, sync::Arc};
 
 /// SharedState
@@ -17,7 +20,7 @@ pub struct State {
 impl State {
     /// Initialize `State` with configuration data (`.env`)
     pub fn init(config: &Config) -> Result<Self, ApiError> {
-        let config_state: ConfigState = config.clone().into();
+        let config_state = ConfigState::try_from(config.clone())?;
         let private_key = match config.jwt_private_key.as_deref() {
             Some(name) => read_to_string(format!("./keys/{}", name)).ok(),
             None => None,
@@ -49,13 +52,58 @@ pub struct ConfigState {
 
     /// Forgotten password expiration duration in hour
     pub forgotten_password_expiration_duration: i64,
+
+    /// Magic link expiration duration in minute
+    pub magic_link_expiration_duration: i64,
+
+    /// Invitation expiration duration in hour
+    pub invitation_expiration_duration: i64,
+
+    /// OpenID Connect base URL for the login callback
+    pub oidc_redirect_base_url: String,
+
+    /// OpenID Connect login expiration duration in minute
+    pub oidc_login_expiration_duration: i64,
+
+    /// Password lifetime in day (0: never expires)
+    pub password_lifetime: i64,
+
+    /// Number of previous passwords which cannot be reused
+    pub password_history_size: u32,
+
+    /// Password policy (strength and hash parameters)
+    pub password_policy: PasswordPolicy,
+
+    /// Reverse proxies allowed to set the client IP with `X-Forwarded-For`
+    pub trusted_proxies: Vec<IpAddr>,
 }
 
-impl From<Config> for ConfigState {
-    fn from(config: Config) -> Self {
-        Self {
+impl TryFrom<Config> for ConfigState {
+    type Error = ApiError;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        let trusted_proxies = config
+            .trusted_proxies
+            .split(',')
+            .map(str::trim)
+            .filter(|proxy| !proxy.is_empty())
+            .map(|proxy| {
+                IpAddr::from_str(proxy)
+                    .map_err(|_| ApiError::InternalServerError(format!("invalid trusted proxy IP address: {proxy}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
             response_body_max_size: config.response_body_max_size.saturating_mul(1_024),
             forgotten_password_expiration_duration: config.forgotten_password_expiration_duration,
-        }
+            magic_link_expiration_duration: config.magic_link_expiration_duration,
+            invitation_expiration_duration: config.invitation_expiration_duration,
+            oidc_redirect_base_url: config.oidc_redirect_base_url.trim_end_matches('/').to_string(),
+            oidc_login_expiration_duration: config.oidc_login_expiration_duration,
+            password_lifetime: config.password_lifetime,
+            password_history_size: config.password_history_size,
+            password_policy: PasswordPolicy::from(config),
+            trusted_proxies,
+        })
     }
 }
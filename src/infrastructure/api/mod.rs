@@ -8,4 +8,6 @@ mod logger;
 pub mod response;
 mod routes;
 pub mod server;
+#[cfg(test)]
+mod tests;
 mod use_cases;
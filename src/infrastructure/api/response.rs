@@ -1,7 +1,8 @@
 //! API response module
 
+use crate::domain::value_objects::pagination::{PAGINATION_MAX_LIMIT, Pagination};
 use axum::Json;
-use axum::http::StatusCode;
+use axum::http::{HeaderValue, StatusCode, Uri, header};
 use axum::response::{IntoResponse, Response};
 use serde::Serialize;
 use thiserror::Error;
@@ -31,6 +32,128 @@ impl<T: Serialize + PartialEq> IntoResponse for ApiSuccess<T> {
     }
 }
 
+/// Page of a list: the data, the total number of items and the cursor of the next page
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiPage<T> {
+    pub data: Vec<T>,
+    pub total: i64,
+    pub next_cursor: Option<String>,
+}
+
+/// API paginated list response
+///
+/// The body contains the data with the pagination metadata and the navigation URLs,
+/// which are also sent in the `Link` header (RFC 8288).
+/// URLs are relative to the server and keep the other query parameters (sorts, filters, etc.).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ApiPaginated<T: Serialize + PartialEq> {
+    /// Current page (`null` in cursor mode)
+    pub page: Option<u32>,
+    pub limit: u32,
+    pub total: i64,
+    pub total_pages: i64,
+    pub next: Option<String>,
+    pub prev: Option<String>,
+
+    /// Cursor of the next page (cursor mode only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+
+    pub data: Vec<T>,
+
+    /// First and last pages links
+    #[serde(skip)]
+    first: Option<String>,
+    #[serde(skip)]
+    last: Option<String>,
+}
+
+impl<T: Serialize + PartialEq> ApiPaginated<T> {
+    /// Create a paginated response from a page, the requested pagination and the request URI
+    pub fn new(page: ApiPage<T>, pagination: &Pagination, uri: &Uri) -> Self {
+        let limit = match pagination.limit() {
+            0 => PAGINATION_MAX_LIMIT,
+            limit => limit,
+        };
+        let total_pages = (page.total + i64::from(limit) - 1) / i64::from(limit);
+
+        match pagination.is_cursor() {
+            true => Self {
+                page: None,
+                limit,
+                total: page.total,
+                total_pages,
+                next: page
+                    .next_cursor
+                    .as_ref()
+                    .map(|cursor| Self::url(uri, "c", cursor, limit)),
+                prev: None,
+                next_cursor: page.next_cursor,
+                data: page.data,
+                first: Some(Self::url(uri, "c", "", limit)),
+                last: None,
+            },
+            false => {
+                let current = pagination.page();
+                let last = total_pages.max(1) as u32;
+
+                Self {
+                    page: Some(current),
+                    limit,
+                    total: page.total,
+                    total_pages,
+                    next: (current < last).then(|| Self::url(uri, "p", &(current + 1).to_string(), limit)),
+                    prev: (current > 1).then(|| Self::url(uri, "p", &(current - 1).min(last).to_string(), limit)),
+                    next_cursor: None,
+                    data: page.data,
+                    first: Some(Self::url(uri, "p", "1", limit)),
+                    last: Some(Self::url(uri, "p", &last.to_string(), limit)),
+                }
+            }
+        }
+    }
+
+    /// URL of the request with another page (or cursor) and the limit
+    fn url(uri: &Uri, param: &str, value: &str, limit: u32) -> String {
+        let mut params = uri
+            .query()
+            .and_then(|query| serde_urlencoded::from_str::<Vec<(String, String)>>(query).ok())
+            .unwrap_or_default();
+        params.retain(|(key, _)| !["p", "l", "c"].contains(&key.as_str()));
+        params.push((param.to_string(), value.to_string()));
+        params.push(("l".to_string(), limit.to_string()));
+
+        format!(
+            "{}?{}",
+            uri.path(),
+            serde_urlencoded::to_string(params).unwrap_or_default()
+        )
+    }
+
+    /// `Link` header value
+    fn link(&self) -> String {
+        [
+            ("next", &self.next),
+            ("prev", &self.prev),
+            ("first", &self.first),
+            ("last", &self.last),
+        ]
+        .into_iter()
+        .filter_map(|(rel, url)| url.as_ref().map(|url| format!(r#"<{url}>; rel="{rel}""#)))
+        .collect::<Vec<_>>()
+        .join(", ")
+    }
+}
+
+impl<T: Serialize + PartialEq> IntoResponse for ApiPaginated<T> {
+    fn into_response(self) -> Response {
+        match HeaderValue::from_str(&self.link()) {
+            Ok(link) if !link.is_empty() => (StatusCode::OK, [(header::LINK, link)], Json(self)).into_response(),
+            _ => (StatusCode::OK, Json(self)).into_response(),
+        }
+    }
+}
+
 /// Generic response structure shared by all API responses.
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ApiErrorResponse<T: Serialize + PartialEq> {
@@ -116,6 +239,10 @@ impl ApiError {
                 StatusCode::NOT_FOUND,
                 Json(ApiErrorResponse::new(StatusCode::NOT_FOUND, message)),
             ),
+            StatusCode::UNPROCESSABLE_ENTITY => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(ApiErrorResponse::new(StatusCode::UNPROCESSABLE_ENTITY, message)),
+            ),
             _ => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(ApiErrorResponse::new(StatusCode::INTERNAL_SERVER_ERROR, message)),
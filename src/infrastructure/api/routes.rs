@@ -1,5 +1,6 @@
 //! Routes list
 
+use crate::adapters::database::DatabaseRepositories;
 use crate::auth;
 use crate::config::Config;
 use crate::infrastructure::api::handlers;
@@ -7,7 +8,7 @@ use crate::infrastructure::api::layers::auth::JwtLayer;
 use crate::infrastructure::api::layers::basic_auth::BasicAuthLayer;
 use crate::infrastructure::api::layers::state::SharedState;
 use axum::Router;
-use axum::routing::{delete, get, patch, post};
+use axum::routing::{delete, get, patch, post, put};
 
 /// Return web routes list
 pub fn web(settings: &Config) -> Router<SharedState> {
@@ -26,67 +27,165 @@ pub fn web(settings: &Config) -> Router<SharedState> {
 }
 
 /// Return API routes list
-pub fn api(state: SharedState) -> Router<SharedState> {
+pub fn api<D: DatabaseRepositories>(state: SharedState) -> Router<SharedState> {
     Router::new()
         // Public routes
-        .route("/token", post(handlers::user::get_access_token))
-        .route("/refresh-token/{token}", post(handlers::user::refresh_token))
-        .route("/forgotten-password/{email}", post(handlers::user::forgotten_password))
-        .route("/update-password", patch(handlers::user::update_password_from_token))
+        .route("/token", post(handlers::user::get_access_token::<D>))
+        .route("/refresh-token/{token}", post(handlers::user::refresh_token::<D>))
+        .route(
+            "/forgotten-password/{email}",
+            post(handlers::user::forgotten_password::<D>),
+        )
+        .route(
+            "/update-password",
+            patch(handlers::user::update_password_from_token::<D>),
+        )
+        .route("/magic-link", post(handlers::user::consume_magic_link::<D>))
+        .route("/magic-link/{email}", post(handlers::user::send_magic_link::<D>))
+        .route("/accept-invitation", patch(handlers::invitation::accept::<D>))
+        .route("/introspect", post(handlers::introspection::introspect::<D>))
+        .route(
+            "/external-links/{external_link_id}/login",
+            get(handlers::federation::login::<D>),
+        )
+        .route(
+            "/external-links/{external_link_id}/callback",
+            get(handlers::federation::callback::<D>),
+        )
         // Private routes
-        .merge(api_protected(state))
+        .merge(api_protected::<D>(state))
 }
 
 /// Protected API routes
-fn api_protected(state: SharedState) -> Router<SharedState> {
+fn api_protected<D: DatabaseRepositories>(state: SharedState) -> Router<SharedState> {
     Router::new()
-        .nest("/users", api_users().layer(auth!(state.clone())))
-        .nest("/applications", api_applications().layer(auth!(state.clone())))
-        .nest("/scopes", api_scopes().layer(auth!(state.clone())))
-        .nest("/external-links", api_external_links().layer(auth!(state.clone())))
+        .nest("/users", api_users::<D>().layer(auth!(state.clone())))
+        .nest("/applications", api_applications::<D>().layer(auth!(state.clone())))
+        .nest("/scopes", api_scopes::<D>().layer(auth!(state.clone())))
+        .nest("/external-links", api_external_links::<D>().layer(auth!(state.clone())))
+        .nest("/invitations", api_invitations::<D>().layer(auth!(state.clone())))
+        .nest("/audit-events", api_audit_events::<D>().layer(auth!(state.clone())))
 }
 
 /// Users API routes
-fn api_users() -> Router<SharedState> {
+fn api_users<D: DatabaseRepositories>() -> Router<SharedState> {
     Router::new()
-        .route("/", post(handlers::user::create))
-        .route("/", get(handlers::user::get_all))
-        .route("/deleted", get(handlers::user::get_all_deleted))
-        .route("/{user_id}", get(handlers::user::get_by_id))
-        .route("/{user_id}", delete(handlers::user::delete))
-        .route("/{user_id}/restore", patch(handlers::user::restore))
+        .route("/", post(handlers::user::create::<D>))
+        .route("/", get(handlers::user::get_all::<D>))
+        .route("/deleted", get(handlers::user::get_all_deleted::<D>))
+        .route("/{user_id}", get(handlers::user::get_by_id::<D>))
+        .route("/{user_id}", delete(handlers::user::delete::<D>))
+        .route("/{user_id}/restore", patch(handlers::user::restore::<D>))
+        .route("/{user_id}/sessions", delete(handlers::user::revoke_sessions::<D>))
 }
 
 /// Applications API routes
-fn api_applications() -> Router<SharedState> {
+fn api_applications<D: DatabaseRepositories>() -> Router<SharedState> {
     Router::new()
-        .route("/", post(handlers::application::create))
-        .route("/", get(handlers::application::get_all))
-        .route("/deleted", get(handlers::application::get_all_deleted))
-        .route("/{application_id}", get(handlers::application::get_by_id))
-        .route("/{application_id}", patch(handlers::application::update))
-        .route("/{application_id}", delete(handlers::application::delete))
-        .route("/{application_id}/restore", patch(handlers::application::restore))
-        .route("/{application_id}/scopes", post(handlers::scope::create))
+        .route("/", post(handlers::application::create::<D>))
+        .route("/", get(handlers::application::get_all::<D>))
+        .route("/deleted", get(handlers::application::get_all_deleted::<D>))
+        .route("/{application_id}", get(handlers::application::get_by_id::<D>))
+        .route("/{application_id}", patch(handlers::application::update::<D>))
+        .route("/{application_id}", delete(handlers::application::delete::<D>))
+        .route("/{application_id}/restore", patch(handlers::application::restore::<D>))
+        .route("/{application_id}/scopes", post(handlers::scope::create::<D>))
+        .route("/{application_id}/webhooks", post(handlers::webhook::create::<D>))
+        .route("/{application_id}/webhooks", get(handlers::webhook::get_all::<D>))
+        .route(
+            "/{application_id}/webhooks/{webhook_id}",
+            delete(handlers::webhook::delete::<D>),
+        )
+        .route("/{application_id}/clients", post(handlers::client::create::<D>))
+        .route("/{application_id}/clients", get(handlers::client::get_all::<D>))
+        .route(
+            "/{application_id}/clients/{client_id}",
+            get(handlers::client::get_by_id::<D>),
+        )
+        .route(
+            "/{application_id}/clients/{client_id}",
+            patch(handlers::client::update::<D>),
+        )
+        .route(
+            "/{application_id}/clients/{client_id}",
+            delete(handlers::client::delete::<D>),
+        )
+        .route(
+            "/{application_id}/clients/{client_id}/secrets",
+            get(handlers::client::get_secrets::<D>),
+        )
+        .route(
+            "/{application_id}/clients/{client_id}/secrets",
+            post(handlers::client::create_secret::<D>),
+        )
+        .route(
+            "/{application_id}/clients/{client_id}/secrets/{secret_id}",
+            delete(handlers::client::delete_secret::<D>),
+        )
 }
 
 /// Scopes API routes
-fn api_scopes() -> Router<SharedState> {
+fn api_scopes<D: DatabaseRepositories>() -> Router<SharedState> {
     Router::new()
-        .route("/", get(handlers::scope::get_all))
-        .route("/deleted", get(handlers::scope::get_all_deleted))
-        .route("/{scope_id}", delete(handlers::scope::delete))
-        .route("/{scope_id}/restore", patch(handlers::scope::restore))
+        .route("/", get(handlers::scope::get_all::<D>))
+        .route("/deleted", get(handlers::scope::get_all_deleted::<D>))
+        .route("/{scope_id}", delete(handlers::scope::delete::<D>))
+        .route("/{scope_id}/restore", patch(handlers::scope::restore::<D>))
 }
 
 /// External links API routes
-fn api_external_links() -> Router<SharedState> {
+fn api_external_links<D: DatabaseRepositories>() -> Router<SharedState> {
     Router::new()
-        .route("/", post(handlers::external_link::create))
-        .route("/", get(handlers::external_link::get_all))
-        .route("/deleted", get(handlers::external_link::get_all_deleted))
-        .route("/{external_link_id}", get(handlers::external_link::get_by_id))
-        .route("/{external_link_id}", patch(handlers::external_link::update))
-        .route("/{external_link_id}", delete(handlers::external_link::delete))
-        .route("/{external_link_id}/restore", patch(handlers::external_link::restore))
+        .route("/", post(handlers::external_link::create::<D>))
+        .route("/", get(handlers::external_link::get_all::<D>))
+        .route("/deleted", get(handlers::external_link::get_all_deleted::<D>))
+        .route("/{external_link_id}", get(handlers::external_link::get_by_id::<D>))
+        .route("/{external_link_id}", patch(handlers::external_link::update::<D>))
+        .route("/{external_link_id}", delete(handlers::external_link::delete::<D>))
+        .route(
+            "/{external_link_id}/restore",
+            patch(handlers::external_link::restore::<D>),
+        )
+        .route(
+            "/{external_link_id}/users/{user_id}",
+            put(handlers::external_link::link_user::<D>),
+        )
+        .route(
+            "/{external_link_id}/users/{user_id}",
+            delete(handlers::external_link::unlink_user::<D>),
+        )
+        .route(
+            "/{external_link_id}/external-users/{external_user_id}",
+            get(handlers::external_link::get_linked_user::<D>),
+        )
+        .route(
+            "/{external_link_id}/applications/{application_id}",
+            put(handlers::external_link::link_application::<D>),
+        )
+        .route(
+            "/{external_link_id}/applications/{application_id}",
+            delete(handlers::external_link::unlink_application::<D>),
+        )
+}
+
+/// Invitations API routes
+fn api_invitations<D: DatabaseRepositories>() -> Router<SharedState> {
+    Router::new()
+        .route("/", post(handlers::invitation::create::<D>))
+        .route("/", get(handlers::invitation::get_all::<D>))
+        .route("/{invitation_id}", delete(handlers::invitation::revoke::<D>))
+        .route("/{invitation_id}/resend", post(handlers::invitation::resend::<D>))
+}
+
+/// Development API routes (not registered in production)
+pub fn api_dev<D: DatabaseRepositories>(state: SharedState) -> Router<SharedState> {
+    Router::new()
+        .route("/emails", get(handlers::dev::get_emails::<D>))
+        .route("/emails", delete(handlers::dev::clear_emails::<D>))
+        .layer(auth!(state))
+}
+
+/// Audit events API routes
+fn api_audit_events<D: DatabaseRepositories>() -> Router<SharedState> {
+    Router::new().route("/", get(handlers::audit::get_all::<D>))
 }
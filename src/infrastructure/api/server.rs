@@ -6,15 +6,20 @@ use super::layers::{
     state::{SharedState, State},
 };
 use super::{layers, logger, routes};
-use crate::adapters::database::GenericDb;
-use crate::adapters::database::mysql::Db;
+use crate::adapters::database::{DatabaseDriver, DatabaseRepositories, GenericDb, mysql, postgres, sqlite};
 use crate::adapters::email::EmailAdapter;
+use crate::adapters::identity_provider::OidcAdapter;
+use crate::adapters::webhook::WebhookAdapter;
 use crate::config::Config;
 use crate::domain::entities::email::EmailConfig;
+use crate::domain::services::email::EmailTransport;
 use crate::infrastructure::api::errors::timeout_error;
 use crate::infrastructure::api::response::ApiError;
 use crate::infrastructure::api::use_cases::AppUseCases;
+use crate::infrastructure::worker;
 use axum::{Extension, Router, error_handling::HandleErrorLayer, middleware};
+use std::net::SocketAddr;
+use std::str::FromStr;
 use std::time::Duration;
 use tera::Tera;
 use tokio::net::TcpListener;
@@ -39,7 +44,7 @@ pub async fn start_server() -> Result<(), ApiError> {
     let listener = TcpListener::bind(&addr).await.unwrap();
     info!("Starting server on {}...", &addr);
 
-    let server = axum::serve(listener, app);
+    let server = axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>());
 
     // Graceful shutdown only in production environment
     if settings.environment != "production" {
@@ -59,6 +64,52 @@ async fn get_app(settings: &Config) -> Result<Router, ApiError> {
     // Tracing
     logger::init(&settings.environment, &settings.logs_path, &settings.logs_file)?;
 
+    // Database (the driver is selected from the URL scheme)
+    match DatabaseDriver::from_str(&settings.database_url)? {
+        DatabaseDriver::Mysql => get_app_with_db(settings, mysql::Db::new(settings).await?).await,
+        DatabaseDriver::Postgres => get_app_with_db(settings, postgres::Db::new(settings).await?).await,
+        DatabaseDriver::Sqlite => get_app_with_db(settings, sqlite::Db::new(settings).await?).await,
+    }
+}
+
+/// Initialize router with the repositories of a database adapter
+async fn get_app_with_db<D: DatabaseRepositories>(settings: &Config, db: D) -> Result<Router, ApiError> {
+    // Email service
+    let email_service = EmailAdapter::new(EmailConfig::from(settings.clone()))
+        .map_err(|err| ApiError::InternalServerError(err.to_string()))?;
+    if settings.smtp_check_on_startup {
+        email_service
+            .check()
+            .await
+            .map_err(|err| ApiError::InternalServerError(err.to_string()))?;
+        info!("Email transport checked");
+    }
+
+    // Identity provider service
+    let identity_provider_service = OidcAdapter::new(settings.oidc_request_timeout, settings.oidc_cache_ttl)
+        .map_err(|err| ApiError::InternalServerError(err.to_string()))?;
+
+    // Webhook service (local receivers are only allowed in development)
+    let webhook_service = WebhookAdapter::new(settings.webhook_request_timeout, settings.environment == "development")
+        .map_err(|err| ApiError::InternalServerError(err.to_string()))?;
+
+    // Use cases
+    let use_cases = AppUseCases::new(db, email_service, identity_provider_service, webhook_service).await?;
+
+    // Workers
+    worker::webhook::start(use_cases.webhook.deliver_webhooks.clone(), settings);
+    worker::email::start(use_cases.email.deliver_emails.clone(), settings);
+
+    get_router(settings, use_cases)
+}
+
+/// Initialize router with the use cases
+///
+/// No worker is started: the emails and the webhook deliveries stay in the outbox.
+pub(super) fn get_router<D: DatabaseRepositories>(
+    settings: &Config,
+    use_cases: AppUseCases<D>,
+) -> Result<Router, ApiError> {
     // CORS
     let cors = layers::cors(settings);
 
@@ -66,9 +117,11 @@ async fn get_app(settings: &Config) -> Result<Router, ApiError> {
     let global_state = SharedState::new(State::init(settings)?);
 
     // Routing - API
-    let mut app = Router::new()
-        .nest("/api/v1", routes::api(global_state.clone()))
-        .layer(cors);
+    let mut api = routes::api::<D>(global_state.clone());
+    if settings.environment != "production" && use_cases.email_service.memory_transport().is_some() {
+        api = api.nest("/dev", routes::api_dev::<D>(global_state.clone()));
+    }
+    let mut app = Router::new().nest("/api/v1", api).layer(cors);
 
     // Routing - Web
     app = app.merge(routes::web(settings));
@@ -79,12 +132,6 @@ async fn get_app(settings: &Config) -> Result<Router, ApiError> {
     tera.autoescape_on(vec![".html", ".txt"]);
     app = app.layer(Extension(tera));
 
-    // Database
-    let db = Db::new(settings).await?;
-
-    // Email service
-    let email_service = EmailAdapter::new(EmailConfig::from(settings.clone()));
-
     // Static files
     app = app.fallback_service(ServeDir::new("assets").append_index_html_on_directories(true));
 
@@ -117,7 +164,7 @@ async fn get_app(settings: &Config) -> Result<Router, ApiError> {
     }
 
     // Use cases
-    app = app.layer(Extension(AppUseCases::new(db, email_service).await?));
+    app = app.layer(Extension(use_cases));
 
     // State
     let app = app.with_state(global_state);
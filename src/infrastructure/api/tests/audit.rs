@@ -0,0 +1,339 @@
+//! Audit events recorded by the routes
+
+use super::{TestApp, TestDatabase};
+use axum::extract::Form;
+use axum::http::{Method, Request, StatusCode, header};
+use axum::routing::{get, post};
+use axum::{Json, Router, body::Body};
+use jsonwebtoken::{EncodingKey, Header, encode};
+use serde_json::json;
+use std::collections::HashMap;
+
+/// Client of the stub identity provider
+const PROVIDER_CLIENT_ID: &str = "partner-client";
+const PROVIDER_CLIENT_SECRET: &str = "partner-secret";
+
+/// Targets of the audit events of an action
+async fn audit_targets<D: TestDatabase>(app: &TestApp<D>, token: &str, action: &str) -> Vec<String> {
+    let (status, body) = app
+        .json(
+            Method::GET,
+            &format!("/api/v1/audit-events?action={action}"),
+            Some(token),
+            None,
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK);
+
+    body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|event| event["target_id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+/// ID of the user created with the application
+async fn admin_id<D: TestDatabase>(app: &TestApp<D>, token: &str) -> String {
+    let (status, body) = app.json(Method::GET, "/api/v1/users", Some(token), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    body["data"][0]["id"].as_str().unwrap().to_string()
+}
+
+/// Start a local identity provider and return its issuer URL
+///
+/// The token endpoint returns an ID token whose nonce is the authorization code,
+/// so that the tests can use the nonce of the login as code.
+async fn stub_identity_provider() -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let issuer = format!("http://{}", listener.local_addr().unwrap());
+    let discovery = json!({
+        "issuer": issuer,
+        "authorization_endpoint": format!("{issuer}/authorize"),
+        "token_endpoint": format!("{issuer}/token"),
+        "id_token_signing_alg_values_supported": ["HS256"],
+    });
+    let token_issuer = issuer.clone();
+
+    let app = Router::new()
+        .route(
+            "/.well-known/openid-configuration",
+            get(move || async move { Json(discovery) }),
+        )
+        .route(
+            "/token",
+            post(move |Form(form): Form<HashMap<String, String>>| async move {
+                let now = chrono::Utc::now().timestamp();
+                let claims = json!({
+                    "iss": token_issuer,
+                    "aud": PROVIDER_CLIENT_ID,
+                    "sub": "partner-user-42",
+                    "iat": now,
+                    "exp": now + 300,
+                    "nonce": form.get("code"),
+                    "email": "partner.user@test.com",
+                    "email_verified": true,
+                });
+                let id_token = encode(
+                    &Header::default(),
+                    &claims,
+                    &EncodingKey::from_secret(PROVIDER_CLIENT_SECRET.as_bytes()),
+                )
+                .unwrap();
+
+                Json(json!({"id_token": id_token, "token_type": "Bearer"}))
+            }),
+        );
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    issuer
+}
+
+async fn test_client_audit_events<D: TestDatabase>() {
+    let app = TestApp::<D>::new().await;
+    let token = app.admin_token().await;
+    let clients = format!("/api/v1/applications/{}/clients", app.application_id);
+
+    let (status, client) = app
+        .json(Method::POST, &clients, Some(&token), Some(json!({ "name": "Backend" })))
+        .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let client_id = client["id"].as_str().unwrap();
+
+    let (status, secret) = app
+        .json(
+            Method::POST,
+            &format!("{clients}/{client_id}/secrets"),
+            Some(&token),
+            None,
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let secret_id = secret["id"].as_str().unwrap();
+
+    let (status, _) = app
+        .json(
+            Method::DELETE,
+            &format!("{clients}/{client_id}/secrets/{secret_id}"),
+            Some(&token),
+            None,
+        )
+        .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = app
+        .json(Method::DELETE, &format!("{clients}/{client_id}"), Some(&token), None)
+        .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    assert_eq!(audit_targets(&app, &token, "client.created").await, vec![client_id]);
+    assert_eq!(
+        audit_targets(&app, &token, "client_secret.created").await,
+        vec![secret_id]
+    );
+    assert_eq!(
+        audit_targets(&app, &token, "client_secret.deleted").await,
+        vec![secret_id]
+    );
+    assert_eq!(audit_targets(&app, &token, "client.deleted").await, vec![client_id]);
+}
+
+async fn test_invitation_audit_events<D: TestDatabase>() {
+    let app = TestApp::<D>::new().await;
+    let token = app.admin_token().await;
+
+    let (status, invitation) = app
+        .json(
+            Method::POST,
+            "/api/v1/invitations",
+            Some(&token),
+            Some(json!({ "email": "invited@test.com", "lastname": "Doe", "firstname": "Jane" })),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let user_id = invitation["user_id"].as_str().unwrap();
+    assert_eq!(audit_targets(&app, &token, "invitation.created").await, vec![user_id]);
+
+    // The invitation token is the end of the link sent by email
+    let emails = app.queued_emails().await;
+    let invitation_token = emails[0]
+        .text_body
+        .as_deref()
+        .unwrap_or_default()
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("http://localhost/"))
+        .unwrap();
+
+    let (status, _) = app
+        .json(
+            Method::PATCH,
+            "/api/v1/accept-invitation",
+            None,
+            Some(json!({ "token": invitation_token, "password": "Invited-Password-1" })),
+        )
+        .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    assert_eq!(audit_targets(&app, &token, "invitation.accepted").await, vec![user_id]);
+}
+
+async fn test_external_link_audit_events<D: TestDatabase>() {
+    let app = TestApp::<D>::new().await;
+    let token = app.admin_token().await;
+    let user_id = admin_id(&app, &token).await;
+
+    let (status, external_link) = app
+        .json(
+            Method::POST,
+            "/api/v1/external-links",
+            Some(&token),
+            Some(json!({ "name": "Partner" })),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let users = format!(
+        "/api/v1/external-links/{}/users/{user_id}",
+        external_link["id"].as_str().unwrap()
+    );
+    let applications = format!(
+        "/api/v1/external-links/{}/applications/{}",
+        external_link["id"].as_str().unwrap(),
+        app.application_id
+    );
+
+    let (status, _) = app
+        .json(
+            Method::PUT,
+            &users,
+            Some(&token),
+            Some(json!({ "external_user_id": "partner-user-42" })),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = app.json(Method::DELETE, &users, Some(&token), None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = app.json(Method::PUT, &applications, Some(&token), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = app.json(Method::DELETE, &applications, Some(&token), None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    assert_eq!(
+        audit_targets(&app, &token, "external_link.user_linked").await,
+        vec![user_id.clone()]
+    );
+    assert_eq!(
+        audit_targets(&app, &token, "external_link.user_unlinked").await,
+        vec![user_id]
+    );
+    assert_eq!(
+        audit_targets(&app, &token, "external_link.application_linked").await,
+        vec![app.application_id.clone()]
+    );
+    assert_eq!(
+        audit_targets(&app, &token, "external_link.application_unlinked").await,
+        vec![app.application_id.clone()]
+    );
+}
+
+async fn test_federated_login_audit_event<D: TestDatabase>() {
+    let app = TestApp::<D>::new().await;
+    let token = app.admin_token().await;
+    let issuer = stub_identity_provider().await;
+
+    let (status, external_link) = app
+        .json(
+            Method::POST,
+            "/api/v1/external-links",
+            Some(&token),
+            Some(json!({
+                "name": "Partner",
+                "provider": {
+                    "issuer": issuer,
+                    "client_id": PROVIDER_CLIENT_ID,
+                    "client_secret": PROVIDER_CLIENT_SECRET,
+                },
+            })),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let external_link_id = external_link["id"].as_str().unwrap();
+    let (status, _) = app
+        .json(
+            Method::PUT,
+            &format!(
+                "/api/v1/external-links/{external_link_id}/applications/{}",
+                app.application_id
+            ),
+            Some(&token),
+            None,
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Login redirection to the provider, with the state and the nonce
+    let request = Request::builder()
+        .uri(format!(
+            "/api/v1/external-links/{external_link_id}/login?application_id={}",
+            app.application_id
+        ))
+        .body(Body::empty())
+        .unwrap();
+    let (status, headers, _) = app.request(request).await;
+    assert!(status.is_redirection());
+    let location = reqwest::Url::parse(headers[header::LOCATION].to_str().unwrap()).unwrap();
+    let query = location.query_pairs().into_owned().collect::<HashMap<_, _>>();
+
+    let (status, body) = app
+        .json(
+            Method::GET,
+            &format!(
+                "/api/v1/external-links/{external_link_id}/callback?code={}&state={}",
+                query["nonce"], query["state"]
+            ),
+            None,
+            None,
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["access_token"].is_string());
+
+    // The login is recorded for the user created for the partner user
+    let (status, users) = app.json(Method::GET, "/api/v1/users", Some(&token), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let user_id = users["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|user| user["email"] == "partner.user@test.com")
+        .map(|user| user["id"].as_str().unwrap().to_string())
+        .unwrap();
+    assert!(audit_targets(&app, &token, "login.succeeded").await.contains(&user_id));
+}
+
+async fn test_audit_events_are_not_recorded_on_error<D: TestDatabase>() {
+    let app = TestApp::<D>::new().await;
+    let token = app.admin_token().await;
+
+    let (status, _) = app
+        .json(
+            Method::DELETE,
+            &format!(
+                "/api/v1/applications/{}/clients/{}",
+                app.application_id,
+                uuid::Uuid::new_v4()
+            ),
+            Some(&token),
+            None,
+        )
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(audit_targets(&app, &token, "client.deleted").await.is_empty());
+}
+
+http_tests!(
+    test_client_audit_events,
+    test_invitation_audit_events,
+    test_external_link_audit_events,
+    test_federated_login_audit_event,
+    test_audit_events_are_not_recorded_on_error,
+);
@@ -0,0 +1,43 @@
+//! Development routes
+
+use super::{TestApp, TestDatabase};
+use axum::http::{Method, StatusCode};
+
+async fn test_captured_emails<D: TestDatabase>() {
+    let app = TestApp::<D>::new().await;
+    let token = app.admin_token().await;
+
+    // Authentication is required
+    let (status, _) = app.json(Method::GET, "/api/v1/dev/emails", None, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // Emails are captured once sent from the outbox
+    let (status, body) = app
+        .json(Method::POST, "/api/v1/forgotten-password/admin@test.com", None, None)
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    let reset_token = body["token"].as_str().unwrap().to_string();
+
+    let (status, body) = app.json(Method::GET, "/api/v1/dev/emails", Some(&token), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"].as_array().unwrap().len(), 0);
+
+    assert_eq!(app.deliver_emails().await, 1);
+
+    let (status, body) = app.json(Method::GET, "/api/v1/dev/emails", Some(&token), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let emails = body["data"].as_array().unwrap();
+    assert_eq!(emails.len(), 1);
+    assert_eq!(emails[0]["to"][0], "admin@test.com");
+    assert_eq!(emails[0]["from"], "no-reply@test.com");
+    assert!(emails[0]["text_body"].as_str().unwrap().contains(&reset_token));
+
+    // Clear
+    let (status, _) = app.json(Method::DELETE, "/api/v1/dev/emails", Some(&token), None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (_, body) = app.json(Method::GET, "/api/v1/dev/emails", Some(&token), None).await;
+    assert_eq!(body["data"].as_array().unwrap().len(), 0);
+}
+
+http_tests!(test_captured_emails,);
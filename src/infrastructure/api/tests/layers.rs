@@ -0,0 +1,147 @@
+//! Layers and extractors
+
+use super::{BASIC_AUTH, TestApp, TestDatabase};
+use axum::body::Body;
+use axum::extract::ConnectInfo;
+use axum::http::{Method, Request, StatusCode, header};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use std::net::SocketAddr;
+
+async fn test_jwt_layer<D: TestDatabase>() {
+    let app = TestApp::<D>::new().await;
+
+    let (status, body) = app.json(Method::GET, "/api/v1/users", None, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body["code"], 401);
+
+    let (status, _) = app
+        .json(Method::GET, "/api/v1/users", Some("invalid-token"), None)
+        .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let token = app.admin_token().await;
+    let (status, body) = app.json(Method::GET, "/api/v1/users", Some(&token), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["total"], 1);
+}
+
+async fn test_basic_auth_layer<D: TestDatabase>() {
+    let app = TestApp::<D>::new().await;
+    let request = |credentials: Option<(&str, &str)>| {
+        let mut builder = Request::builder().uri("/doc/api-v1");
+        if let Some((username, password)) = credentials {
+            let value = STANDARD.encode(format!("{username}:{password}"));
+            builder = builder.header(header::AUTHORIZATION, format!("Basic {value}"));
+        }
+
+        builder.body(Body::empty()).unwrap()
+    };
+
+    let (status, _, _) = app.request(request(None)).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, _, _) = app.request(request(Some((BASIC_AUTH.0, "invalid")))).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, _, _) = app.request(request(Some(BASIC_AUTH))).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+async fn test_override_http_errors<D: TestDatabase>() {
+    let app = TestApp::<D>::new().await;
+
+    let (status, body) = app.json(Method::DELETE, "/api/v1/token", None, None).await;
+    assert_eq!(status, StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(body["code"], 405);
+
+    let (status, body) = app
+        .json(Method::POST, "/api/v1/token", None, Some(serde_json::json!({})))
+        .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["code"], 422);
+}
+
+async fn test_extractors<D: TestDatabase>() {
+    let app = TestApp::<D>::new().await;
+    let token = app.admin_token().await;
+
+    // Path
+    let (status, body) = app
+        .json(Method::GET, "/api/v1/users/invalid-id", Some(&token), None)
+        .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], 400);
+
+    // Query
+    let (status, body) = app
+        .json(Method::GET, "/api/v1/users?p=invalid", Some(&token), None)
+        .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], 400);
+
+    // Request ID (generated if missing)
+    let request = Request::builder()
+        .uri("/health")
+        .header("x-request-id", "test-request-id")
+        .body(Body::empty())
+        .unwrap();
+    let (status, headers, _) = app.request(request).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers["x-request-id"], "test-request-id");
+
+    let (_, headers, _) = app
+        .request(Request::builder().uri("/health").body(Body::empty()).unwrap())
+        .await;
+    assert!(headers.contains_key("x-request-id"));
+}
+
+async fn test_audit_context_extractor<D: TestDatabase>() {
+    let app = TestApp::<D>::new().await;
+    let token = app.admin_token().await;
+
+    // The client is not a trusted proxy: its X-Forwarded-For header is ignored
+    let mut request = Request::builder()
+        .method(Method::POST)
+        .uri("/api/v1/applications")
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .header(header::CONTENT_TYPE, "application/json")
+        .header("x-forwarded-for", "10.0.0.1")
+        .header("x-request-id", "audit-request-id")
+        .body(Body::from(
+            serde_json::json!({ "name": "Audited application" }).to_string(),
+        ))
+        .unwrap();
+    request
+        .extensions_mut()
+        .insert(ConnectInfo(SocketAddr::from(([192, 168, 1, 10], 4242))));
+    let (status, _, application) = app.request(request).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, body) = app
+        .json(
+            Method::GET,
+            "/api/v1/audit-events?action=application.created",
+            Some(&token),
+            None,
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    let event = body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|event| event["target_id"] == application["id"])
+        .unwrap();
+    assert_eq!(event["ip"], "192.168.1.10");
+    assert_eq!(event["request_id"], "audit-request-id");
+    assert!(event["actor_id"].is_string());
+}
+
+http_tests!(
+    test_jwt_layer,
+    test_basic_auth_layer,
+    test_override_http_errors,
+    test_extractors,
+    test_audit_context_extractor,
+);
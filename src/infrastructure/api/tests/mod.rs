@@ -0,0 +1,253 @@
+//! HTTP tests of the API
+//!
+//! The router is built with a new database and called with [`ServiceExt::oneshot`],
+//! so that the routes, the layers and the extractors are tested together.
+//! Each test runs with the memory adapter and with an in-memory SQLite database ([`http_tests`]).
+//! No worker is started: the emails stay in the outbox, where the tests can read them,
+//! until [`TestApp::deliver_emails`] sends them with the memory transport.
+
+/// Generate the tests of the given generic test functions for each database
+///
+/// The tests are in the `memory` and `sqlite` modules of the calling module.
+macro_rules! http_tests {
+    ($($test:ident),+ $(,)?) => {
+        mod memory {
+            $(
+                #[tokio::test]
+                async fn $test() {
+                    super::$test::<$crate::adapters::database::memory::Db>().await;
+                }
+            )+
+        }
+
+        mod sqlite {
+            $(
+                #[tokio::test]
+                async fn $test() {
+                    super::$test::<$crate::adapters::database::sqlite::Db>().await;
+                }
+            )+
+        }
+    };
+}
+
+mod audit;
+mod dev;
+mod layers;
+mod user;
+
+use super::server::get_router;
+use super::use_cases::AppUseCases;
+use crate::adapters::database::{DatabaseRepositories, GenericDb};
+use crate::adapters::email::EmailAdapter;
+use crate::adapters::identity_provider::OidcAdapter;
+use crate::adapters::webhook::WebhookAdapter;
+use crate::config::Config;
+use crate::domain::entities::audit_event::AuditContext;
+use crate::domain::entities::email::{EmailConfig, EmailMessage};
+use crate::domain::repositories::email::EmailRepository;
+use crate::domain::repositories::email::dto::GetDueEmailsDtoRequest;
+use crate::domain::use_cases::application::create_application::CreateApplicationUseCaseRequest;
+use crate::domain::use_cases::email::deliver_emails::DeliverEmailsUseCaseRequest;
+use crate::domain::use_cases::user::create_user::CreateUserUseCaseRequest;
+use crate::domain::value_objects::email::Email;
+use crate::domain::value_objects::password::Password;
+use crate::domain::value_objects::password_policy::PasswordPolicy;
+use axum::Router;
+use axum::body::Body;
+use axum::http::{HeaderMap, Method, Request, StatusCode, header};
+use serde_json::Value;
+use tower::ServiceExt;
+
+/// Email of the user created with the application
+const ADMIN_EMAIL: &str = "admin@test.com";
+
+/// Password of the user created with the application
+const ADMIN_PASSWORD: &str = "Admin-Password-1";
+
+/// Basic Auth credentials of the API documentation
+const BASIC_AUTH: (&str, &str) = ("doc", "doc-password");
+
+/// Configuration of the tests (fast password hashing, emails captured in memory)
+fn config() -> Config {
+    Config {
+        environment: "test".to_string(),
+        database_url: "sqlite::memory:".to_string(),
+        database_auto_migration: true,
+        database_max_connections: 5,
+        database_min_connections: 1,
+        database_max_lifetime: 60,
+        database_connect_timeout: 10,
+        database_idle_timeout: 60,
+        request_timeout: 10,
+        request_body_max_size: 1_024,
+        response_body_max_size: 1_024,
+        jwt_algorithm: "HS512".to_string(),
+        jwt_secret_key: Some("test-secret-key".to_string()),
+        jwt_access_lifetime: 15,
+        jwt_refresh_lifetime: 7,
+        cors_allow_origin: "*".to_string(),
+        basic_auth_username: BASIC_AUTH.0.to_string(),
+        basic_auth_password: BASIC_AUTH.1.to_string(),
+        email_transport: "memory".to_string(),
+        email_default_locale: "en".to_string(),
+        forgotten_password_expiration_duration: 1,
+        forgotten_password_base_url: "http://localhost".to_string(),
+        forgotten_password_email_from: "no-reply@test.com".to_string(),
+        magic_link_expiration_duration: 15,
+        magic_link_base_url: "http://localhost".to_string(),
+        magic_link_email_from: "no-reply@test.com".to_string(),
+        invitation_expiration_duration: 24,
+        invitation_base_url: "http://localhost".to_string(),
+        invitation_email_from: "no-reply@test.com".to_string(),
+        oidc_login_expiration_duration: 10,
+        password_history_size: 3,
+        password_length_min: 8,
+        argon2_memory_cost: 1_024,
+        argon2_iterations: 1,
+        argon2_parallelism: 1,
+        ..Config::default()
+    }
+}
+
+/// Database of the tests: a new and migrated database is created for each test
+trait TestDatabase: DatabaseRepositories + GenericDb<Db = Self> {}
+
+impl<D: DatabaseRepositories + GenericDb<Db = D>> TestDatabase for D {}
+
+/// API built with a new database
+struct TestApp<D: TestDatabase> {
+    router: Router,
+    db: D,
+    use_cases: AppUseCases<D>,
+    application_id: String,
+}
+
+impl<D: TestDatabase> TestApp<D> {
+    /// Build the router with an application and a user (the first user cannot be created with the API)
+    async fn new() -> Self {
+        let settings = config();
+        let db = D::new(&settings).await.unwrap();
+
+        let email_service = EmailAdapter::new(EmailConfig::from(settings.clone())).unwrap();
+        let identity_provider_service =
+            OidcAdapter::new(settings.oidc_request_timeout, settings.oidc_cache_ttl).unwrap();
+        let webhook_service = WebhookAdapter::new(settings.webhook_request_timeout, false).unwrap();
+        let use_cases = AppUseCases::new(db.clone(), email_service, identity_provider_service, webhook_service)
+            .await
+            .unwrap();
+
+        let application = use_cases
+            .application
+            .create_application
+            .call(CreateApplicationUseCaseRequest {
+                name: "Test application".to_string(),
+                access_token_lifetime: None,
+                refresh_token_lifetime: None,
+                audience: None,
+                audit: AuditContext::default(),
+            })
+            .await
+            .unwrap();
+        use_cases
+            .user
+            .create_user
+            .call(CreateUserUseCaseRequest {
+                email: Email::new(ADMIN_EMAIL).unwrap(),
+                password: Password::new_with_policy(ADMIN_PASSWORD, false, &PasswordPolicy::from(settings.clone()))
+                    .unwrap(),
+                lastname: "Admin".to_string(),
+                firstname: "Test".to_string(),
+                locale: None,
+                audit: AuditContext::default(),
+            })
+            .await
+            .unwrap();
+
+        Self {
+            router: get_router(&settings, use_cases.clone()).unwrap(),
+            db,
+            use_cases,
+            application_id: application.id.to_string(),
+        }
+    }
+
+    /// Send a request and return the status, the headers and the JSON body (`null` if empty)
+    async fn request(&self, request: Request<Body>) -> (StatusCode, HeaderMap, Value) {
+        let response = self.router.clone().oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let body = axum::body::to_bytes(body, usize::MAX).await.unwrap();
+        let body = match body.is_empty() {
+            true => Value::Null,
+            false => serde_json::from_slice(&body)
+                .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&body).to_string())),
+        };
+
+        (parts.status, parts.headers, body)
+    }
+
+    /// Send a JSON request, authenticated if a token is given
+    async fn json(&self, method: Method, uri: &str, token: Option<&str>, body: Option<Value>) -> (StatusCode, Value) {
+        let mut builder = Request::builder().method(method).uri(uri);
+        if let Some(token) = token {
+            builder = builder.header(header::AUTHORIZATION, format!("Bearer {token}"));
+        }
+        let request = match body {
+            Some(body) => builder
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string())),
+            None => builder.body(Body::empty()),
+        }
+        .unwrap();
+
+        let (status, _, body) = self.request(request).await;
+        (status, body)
+    }
+
+    /// Get an access token and a refresh token
+    async fn login(&self, email: &str, password: &str) -> (StatusCode, Value) {
+        let body = serde_json::json!({
+            "email": email,
+            "password": password,
+            "application_id": self.application_id,
+        });
+
+        self.json(Method::POST, "/api/v1/token", None, Some(body)).await
+    }
+
+    /// Access token of the user created with the application
+    async fn admin_token(&self) -> String {
+        let (status, body) = self.login(ADMIN_EMAIL, ADMIN_PASSWORD).await;
+        assert_eq!(status, StatusCode::OK);
+
+        body["access_token"].as_str().unwrap().to_string()
+    }
+
+    /// Emails waiting in the outbox
+    async fn queued_emails(&self) -> Vec<EmailMessage> {
+        self.db
+            .email_repository()
+            .get_due_emails(GetDueEmailsDtoRequest { limit: 100 })
+            .await
+            .unwrap()
+            .0
+            .into_iter()
+            .map(|email| email.message)
+            .collect()
+    }
+
+    /// Send the emails of the outbox, as the worker does
+    async fn deliver_emails(&self) -> usize {
+        self.use_cases
+            .email
+            .deliver_emails
+            .call(DeliverEmailsUseCaseRequest {
+                batch_size: 100,
+                max_attempts: 1,
+                backoff_base: 1,
+            })
+            .await
+            .unwrap()
+            .sent
+    }
+}
@@ -0,0 +1,178 @@
+//! Users routes
+
+use super::{TestApp, TestDatabase};
+use axum::http::{Method, StatusCode};
+use serde_json::json;
+
+async fn test_user_password_flow<D: TestDatabase>() {
+    let app = TestApp::<D>::new().await;
+    let token = app.admin_token().await;
+
+    // Create user
+    let (status, user) = app
+        .json(
+            Method::POST,
+            "/api/v1/users",
+            Some(&token),
+            Some(json!({
+                "email": "john.doe@test.com",
+                "password": "John-Password-1",
+                "lastname": "Doe",
+                "firstname": "John",
+            })),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(user["email"], "john.doe@test.com");
+
+    let (status, body) = app
+        .json(
+            Method::GET,
+            &format!("/api/v1/users/{}", user["id"].as_str().unwrap()),
+            Some(&token),
+            None,
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["lastname"], "Doe");
+
+    // Get token
+    let (status, tokens) = app.login("john.doe@test.com", "John-Password-1").await;
+    assert_eq!(status, StatusCode::OK);
+    let refresh_token = tokens["refresh_token"].as_str().unwrap();
+
+    // Refresh token
+    let refresh_uri = format!("/api/v1/refresh-token/{refresh_token}");
+    let refresh = || {
+        app.json(
+            Method::POST,
+            &refresh_uri,
+            None,
+            Some(json!({ "application_id": app.application_id })),
+        )
+    };
+    let (status, body) = refresh().await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["access_token"].is_string());
+    assert_ne!(body["refresh_token"], refresh_token);
+
+    let (status, _) = refresh().await;
+    assert_eq!(
+        status,
+        StatusCode::UNAUTHORIZED,
+        "a refresh token can only be used once"
+    );
+
+    // A refresh token cannot be used by another application
+    let (status, application) = app
+        .json(
+            Method::POST,
+            "/api/v1/applications",
+            Some(&token),
+            Some(json!({ "name": "Other application" })),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let refresh_token = body["refresh_token"].as_str().unwrap();
+    let (status, _) = app
+        .json(
+            Method::POST,
+            &format!("/api/v1/refresh-token/{refresh_token}"),
+            None,
+            Some(json!({ "application_id": application["id"] })),
+        )
+        .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // Forgotten password
+    let (status, body) = app
+        .json(Method::POST, "/api/v1/forgotten-password/john.doe@test.com", None, None)
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    let reset_token = body["token"].as_str().unwrap();
+
+    let emails = app.queued_emails().await;
+    assert_eq!(emails.len(), 1);
+    assert_eq!(emails[0].to_addresses, vec!["john.doe@test.com".to_string()]);
+    assert!(emails[0].text_body.as_deref().unwrap_or_default().contains(reset_token));
+
+    // Update password
+    let update_password = |password: &'static str| {
+        app.json(
+            Method::PATCH,
+            "/api/v1/update-password",
+            None,
+            Some(json!({ "token": reset_token, "password": password })),
+        )
+    };
+    let (status, _) = update_password("John-Password-1").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "a previous password cannot be reused");
+
+    let (status, _) = update_password("John-Password-2").await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, wrong_password) = app.login("john.doe@test.com", "John-Password-1").await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // The response does not reveal if the account exists
+    let (status, unknown_email) = app.login("unknown@test.com", "John-Password-1").await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(unknown_email, wrong_password);
+
+    let (status, _) = app.login("john.doe@test.com", "John-Password-2").await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+async fn test_create_user_with_invalid_body<D: TestDatabase>() {
+    let app = TestApp::<D>::new().await;
+    let token = app.admin_token().await;
+
+    let (status, body) = app
+        .json(
+            Method::POST,
+            "/api/v1/users",
+            Some(&token),
+            Some(json!({ "email": "john.doe@test.com" })),
+        )
+        .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{body}");
+    assert_eq!(body["code"], 422);
+}
+
+async fn test_search_users_with_cursor<D: TestDatabase>() {
+    let app = TestApp::<D>::new().await;
+    let token = app.admin_token().await;
+
+    let (status, body) = app
+        .json(Method::GET, "/api/v1/users?q=admin&p=1&l=10", Some(&token), None)
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["total"], 1);
+
+    // Search results are ranked by relevance and cannot be paginated with a cursor
+    for uri in ["/api/v1/users?q=admin&c=", "/api/v1/users/deleted?q=admin&c="] {
+        let (status, body) = app.json(Method::GET, uri, Some(&token), None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{uri}");
+        assert_eq!(body["code"], 400);
+    }
+}
+
+async fn test_send_magic_link_to_unknown_email<D: TestDatabase>() {
+    let app = TestApp::<D>::new().await;
+
+    // The response does not reveal if the account exists, only the user gets an email
+    for email in ["admin@test.com", "unknown@test.com"] {
+        let (status, _) = app
+            .json(Method::POST, &format!("/api/v1/magic-link/{email}"), None, None)
+            .await;
+        assert_eq!(status, StatusCode::NO_CONTENT, "{email}");
+    }
+    assert_eq!(app.queued_emails().await.len(), 1);
+}
+
+http_tests!(
+    test_user_password_flow,
+    test_create_user_with_invalid_body,
+    test_search_users_with_cursor,
+    test_send_magic_link_to_unknown_email,
+);
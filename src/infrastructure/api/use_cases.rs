@@ -1,58 +1,136 @@
 //! Use cases
 
-use crate::adapters::database::mysql::Db;
-use crate::adapters::database::mysql::repositories::application::ApplicationMysqlRepository;
-use crate::adapters::database::mysql::repositories::external_link::ExternalLinkMysqlRepository;
-use crate::adapters::database::mysql::repositories::password_reset::PasswordResetMysqlRepository;
-use crate::adapters::database::mysql::repositories::refresh_token::RefreshTokenMysqlRepository;
-use crate::adapters::database::mysql::repositories::scope::ScopeMysqlRepository;
-use crate::adapters::database::mysql::repositories::user::UserMysqlRepository;
+use crate::adapters::database::DatabaseRepositories;
 use crate::adapters::email::EmailAdapter;
+use crate::adapters::identity_provider::OidcAdapter;
+use crate::adapters::webhook::WebhookAdapter;
 use crate::domain::use_cases::application::ApplicationUseCases;
+use crate::domain::use_cases::audit::AuditUseCases;
+use crate::domain::use_cases::email::EmailUseCases;
 use crate::domain::use_cases::external_link::ExternalLinkUseCases;
+use crate::domain::use_cases::federation::FederationUseCases;
+use crate::domain::use_cases::introspection::IntrospectionUseCases;
+use crate::domain::use_cases::invitation::InvitationUseCases;
 use crate::domain::use_cases::scope::ScopeUseCases;
 use crate::domain::use_cases::user::UserUseCases;
+use crate::domain::use_cases::webhook::WebhookUseCases;
 use crate::infrastructure::api::response::ApiError;
 
+/// User use cases with the repositories of a database adapter
+type AppUserUseCases<D> = UserUseCases<
+    <D as DatabaseRepositories>::User,
+    <D as DatabaseRepositories>::RefreshToken,
+    <D as DatabaseRepositories>::PasswordReset,
+    <D as DatabaseRepositories>::MagicLink,
+    <D as DatabaseRepositories>::Application,
+    EmailAdapter,
+    <D as DatabaseRepositories>::Audit,
+>;
+
+/// Use cases of the API, generic over the repositories of the database adapter
 #[derive(Clone)]
-pub struct AppUseCases {
-    pub user:
-        UserUseCases<UserMysqlRepository, RefreshTokenMysqlRepository, PasswordResetMysqlRepository, EmailAdapter>,
-    pub application: ApplicationUseCases<ApplicationMysqlRepository>,
-    pub scope: ScopeUseCases<ScopeMysqlRepository>,
-    pub external_link: ExternalLinkUseCases<ExternalLinkMysqlRepository>,
+pub struct AppUseCases<D: DatabaseRepositories> {
+    pub user: AppUserUseCases<D>,
+    pub application: ApplicationUseCases<D::Application, D::Client, D::Audit>,
+    pub scope: ScopeUseCases<D::Scope, D::Audit>,
+    pub external_link: ExternalLinkUseCases<D::ExternalLink, D::Audit>,
+    pub invitation: InvitationUseCases<D::Invitation, EmailAdapter, D::Audit>,
+    pub introspection: IntrospectionUseCases<D::Introspection>,
+    pub federation: FederationUseCases<D::Federation, D::RefreshToken, D::Application, OidcAdapter, D::Audit>,
+    pub audit: AuditUseCases<D::Audit>,
+    pub webhook: WebhookUseCases<D::Webhook, WebhookAdapter>,
+    pub email: EmailUseCases<D::Email, EmailAdapter>,
+
+    /// Email adapter, to read the emails captured by the memory transport
+    pub email_service: EmailAdapter,
 }
 
-impl AppUseCases {
-    pub async fn new(db: Db, email_service: EmailAdapter) -> Result<Self, ApiError> {
+impl<D: DatabaseRepositories> AppUseCases<D> {
+    pub async fn new(
+        db: D,
+        email_service: EmailAdapter,
+        identity_provider_service: OidcAdapter,
+        webhook_service: WebhookAdapter,
+    ) -> Result<Self, ApiError> {
+        // Audit (events are saved by the other use cases)
+        let audit_repository = db.audit_repository();
+
+        // Webhook (events are published by the other use cases)
+        let webhook_repository = db.webhook_repository();
+
+        // Application
+        let application_repository = db.application_repository();
+        let client_repository = db.client_repository();
+        let application_use_case = ApplicationUseCases::new(
+            application_repository.clone(),
+            client_repository,
+            audit_repository.clone(),
+        );
+
         // User
-        let user_repository = UserMysqlRepository::new(db.clone());
-        let refresh_token_repository = RefreshTokenMysqlRepository::new(db.clone());
-        let password_reset_repository = PasswordResetMysqlRepository::new(db.clone());
+        let user_repository = db.user_repository();
+        let refresh_token_repository = db.refresh_token_repository();
+        let password_reset_repository = db.password_reset_repository();
+        let magic_link_repository = db.magic_link_repository();
         let user_use_case = UserUseCases::new(
             user_repository,
-            refresh_token_repository,
+            refresh_token_repository.clone(),
             password_reset_repository,
-            email_service,
+            magic_link_repository,
+            application_repository.clone(),
+            email_service.clone(),
+            audit_repository.clone(),
         );
 
-        // Application
-        let application_repository = ApplicationMysqlRepository::new(db.clone());
-        let application_use_case = ApplicationUseCases::new(application_repository);
-
         // Scope
-        let scope_repository = ScopeMysqlRepository::new(db.clone());
-        let scope_use_case = ScopeUseCases::new(scope_repository);
+        let scope_repository = db.scope_repository();
+        let scope_use_case = ScopeUseCases::new(scope_repository, audit_repository.clone());
 
         // External link
-        let external_link_repository = ExternalLinkMysqlRepository::new(db.clone());
-        let external_link_use_case = ExternalLinkUseCases::new(external_link_repository);
+        let external_link_repository = db.external_link_repository();
+        let external_link_use_case = ExternalLinkUseCases::new(external_link_repository, audit_repository.clone());
+
+        // Invitation
+        let invitation_repository = db.invitation_repository();
+        let invitation_use_case =
+            InvitationUseCases::new(invitation_repository, email_service.clone(), audit_repository.clone());
+
+        // Introspection
+        let introspection_repository = db.introspection_repository();
+        let introspection_use_case = IntrospectionUseCases::new(introspection_repository);
+
+        // Federation
+        let federation_repository = db.federation_repository();
+        let federation_use_case = FederationUseCases::new(
+            federation_repository,
+            refresh_token_repository,
+            application_repository,
+            identity_provider_service,
+            audit_repository.clone(),
+        );
+
+        // Audit
+        let audit_use_case = AuditUseCases::new(audit_repository);
+
+        // Webhook
+        let webhook_use_case = WebhookUseCases::new(webhook_repository, webhook_service);
+
+        // Email (emails are queued by the other use cases)
+        let email_repository = db.email_repository();
+        let email_use_case = EmailUseCases::new(email_repository, email_service.clone());
 
         Ok(Self {
             user: user_use_case,
             application: application_use_case,
             scope: scope_use_case,
             external_link: external_link_use_case,
+            invitation: invitation_use_case,
+            introspection: introspection_use_case,
+            federation: federation_use_case,
+            audit: audit_use_case,
+            webhook: webhook_use_case,
+            email: email_use_case,
+            email_service,
         })
     }
 }
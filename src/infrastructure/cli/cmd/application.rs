@@ -0,0 +1,52 @@
+//! Application CLI commands
+
+use crate::adapters::database::{DatabaseDriver, DatabaseRepositories, GenericDb, mysql, postgres, sqlite};
+use crate::config::Config;
+use crate::domain::entities::audit_event::AuditContext;
+use crate::domain::use_cases::application::ApplicationUseCases;
+use crate::domain::use_cases::application::create_application::CreateApplicationUseCaseRequest;
+use crate::infrastructure::cli::error::CliError;
+use std::str::FromStr;
+
+/// Create a new application (needed to get the first access token)
+pub async fn create(name: &str) -> Result<(), CliError> {
+    println!("\nCreating new application...");
+
+    // Load configuration
+    let config = Config::from_env().map_err(|err| CliError::ConfigError(err.to_string()))?;
+    println!("\n► Configuration.....OK");
+
+    // Database
+    match DatabaseDriver::from_str(&config.database_url)? {
+        DatabaseDriver::Mysql => create_with_db(mysql::Db::new(&config).await?, name).await,
+        DatabaseDriver::Postgres => create_with_db(postgres::Db::new(&config).await?, name).await,
+        DatabaseDriver::Sqlite => create_with_db(sqlite::Db::new(&config).await?, name).await,
+    }
+}
+
+/// Create a new application with the repositories of a database adapter
+async fn create_with_db<D: DatabaseRepositories>(db: D, name: &str) -> Result<(), CliError> {
+    println!("► Database..........OK");
+
+    // Application use case
+    let application_use_case = ApplicationUseCases::new(
+        db.application_repository(),
+        db.client_repository(),
+        db.audit_repository(),
+    );
+
+    let response = application_use_case
+        .create_application
+        .call(CreateApplicationUseCaseRequest {
+            name: name.trim().to_string(),
+            access_token_lifetime: None,
+            refresh_token_lifetime: None,
+            audience: None,
+            audit: AuditContext::default(),
+        })
+        .await
+        .map_err(|err| CliError::InvalidArguments(err.to_string()))?;
+    println!("\n→ Application creation success with ID: {}", response.id);
+
+    Ok(())
+}
@@ -1,13 +1,12 @@
 //! Database commands
 
-use crate::adapters::database::GenericDb;
-use crate::adapters::database::mysql::Db;
-use crate::adapters::database::mysql::repositories::refresh_token::RefreshTokenMysqlRepository;
+use crate::adapters::database::{DatabaseDriver, DatabaseRepositories, GenericDb, mysql, postgres, sqlite};
 use crate::config::Config;
 use crate::domain::use_cases::database::clean_expired_refresh_tokens::{
     CleanExpiredRefreshTokens, CleanExpiredRefreshTokensUseCaseRequest,
 };
 use crate::infrastructure::cli::error::CliError;
+use std::str::FromStr;
 
 /// Clean expired data
 pub async fn clean_data() -> Result<(), CliError> {
@@ -18,14 +17,19 @@ pub async fn clean_data() -> Result<(), CliError> {
     println!("\n► Configuration.....OK");
 
     // Database
-    let db = Db::new(&config)
-        .await
-        .map_err(|err| CliError::DatabaseError(err.to_string()))?;
+    match DatabaseDriver::from_str(&config.database_url)? {
+        DatabaseDriver::Mysql => clean_data_with_db(mysql::Db::new(&config).await?).await,
+        DatabaseDriver::Postgres => clean_data_with_db(postgres::Db::new(&config).await?).await,
+        DatabaseDriver::Sqlite => clean_data_with_db(sqlite::Db::new(&config).await?).await,
+    }
+}
+
+/// Clean expired data with the repositories of a database adapter
+async fn clean_data_with_db<D: DatabaseRepositories>(db: D) -> Result<(), CliError> {
     println!("► Database..........OK");
 
     // Refresh token use case
-    let refresh_token_repository = RefreshTokenMysqlRepository::new(db.clone());
-    let refresh_token_use_case = CleanExpiredRefreshTokens::new(refresh_token_repository);
+    let refresh_token_use_case = CleanExpiredRefreshTokens::new(db.refresh_token_repository());
     let affected_rows = refresh_token_use_case
         .call(CleanExpiredRefreshTokensUseCaseRequest())
         .await
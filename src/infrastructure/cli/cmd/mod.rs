@@ -1,4 +1,5 @@
 //! CLI commands list
 
+pub mod application;
 pub mod database;
 pub mod user;
@@ -1,18 +1,17 @@
 //! User CLI commands
 
-use crate::adapters::database::GenericDb;
-use crate::adapters::database::mysql::Db;
-use crate::adapters::database::mysql::repositories::password_reset::PasswordResetMysqlRepository;
-use crate::adapters::database::mysql::repositories::refresh_token::RefreshTokenMysqlRepository;
-use crate::adapters::database::mysql::repositories::user::UserMysqlRepository;
+use crate::adapters::database::{DatabaseDriver, DatabaseRepositories, GenericDb, mysql, postgres, sqlite};
 use crate::adapters::email::EmailAdapter;
 use crate::config::Config;
+use crate::domain::entities::audit_event::AuditContext;
 use crate::domain::entities::email::EmailConfig;
 use crate::domain::use_cases::user::UserUseCases;
 use crate::domain::use_cases::user::create_user::CreateUserUseCaseRequest;
 use crate::domain::value_objects::email::Email;
 use crate::domain::value_objects::password::Password;
+use crate::domain::value_objects::password_policy::PasswordPolicy;
 use crate::infrastructure::cli::error::CliError;
+use std::str::FromStr;
 
 /// Register a new user
 pub async fn register(lastname: &str, firstname: &str, email: &str, password: &str) -> Result<(), CliError> {
@@ -23,35 +22,82 @@ pub async fn register(lastname: &str, firstname: &str, email: &str, password: &s
     println!("\n► Configuration.....OK");
 
     // Database
-    let db = Db::new(&config)
-        .await
-        .map_err(|err| CliError::DatabaseError(err.to_string()))?;
+    match DatabaseDriver::from_str(&config.database_url)? {
+        DatabaseDriver::Mysql => {
+            register_with_db(
+                &config,
+                mysql::Db::new(&config).await?,
+                lastname,
+                firstname,
+                email,
+                password,
+            )
+            .await
+        }
+        DatabaseDriver::Postgres => {
+            register_with_db(
+                &config,
+                postgres::Db::new(&config).await?,
+                lastname,
+                firstname,
+                email,
+                password,
+            )
+            .await
+        }
+        DatabaseDriver::Sqlite => {
+            register_with_db(
+                &config,
+                sqlite::Db::new(&config).await?,
+                lastname,
+                firstname,
+                email,
+                password,
+            )
+            .await
+        }
+    }
+}
+
+/// Register a new user with the repositories of a database adapter
+async fn register_with_db<D: DatabaseRepositories>(
+    config: &Config,
+    db: D,
+    lastname: &str,
+    firstname: &str,
+    email: &str,
+    password: &str,
+) -> Result<(), CliError> {
     println!("► Database..........OK");
 
     // Email service
-    let email_service = EmailAdapter::new(EmailConfig::from(config.clone()));
+    let email_service =
+        EmailAdapter::new(EmailConfig::from(config.clone())).map_err(|err| CliError::ConfigError(err.to_string()))?;
 
     // User use case
-    let user_repository = UserMysqlRepository::new(db.clone());
-    let refresh_token_repository = RefreshTokenMysqlRepository::new(db.clone());
-    let password_reset_repository = PasswordResetMysqlRepository::new(db.clone());
     let user_use_case = UserUseCases::new(
-        user_repository,
-        refresh_token_repository,
-        password_reset_repository,
+        db.user_repository(),
+        db.refresh_token_repository(),
+        db.password_reset_repository(),
+        db.magic_link_repository(),
+        db.application_repository(),
         email_service,
+        db.audit_repository(),
     );
 
     let email = Email::new(email).map_err(|err| CliError::InvalidArguments(err.to_string()))?;
-    let password = Password::new(password, false).map_err(|err| CliError::InvalidArguments(err.to_string()))?;
+    let password = Password::new_with_policy(password, false, &PasswordPolicy::from(config.clone()))
+        .map_err(|err| CliError::InvalidArguments(err.to_string()))?;
 
     let response = user_use_case
         .create_user
         .call(CreateUserUseCaseRequest {
             lastname: lastname.trim().to_string(),
             firstname: firstname.trim().to_string(),
+            locale: None,
             email,
             password,
+            audit: AuditContext::default(),
         })
         .await
         .map_err(|err| CliError::DatabaseError(err.to_string()))?;
@@ -2,6 +2,7 @@
 
 #![allow(dead_code)]
 
+use crate::adapters::database::DatabaseError;
 use crate::config::ConfigError;
 use crate::infrastructure::api::response::ApiError;
 use thiserror::Error;
@@ -42,3 +43,10 @@ impl From<ConfigError> for CliError {
         Self::ConfigError(value.to_string())
     }
 }
+
+/// Database error
+impl From<DatabaseError> for CliError {
+    fn from(value: DatabaseError) -> Self {
+        Self::DatabaseError(value.to_string())
+    }
+}
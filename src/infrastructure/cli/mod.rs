@@ -82,6 +82,21 @@ enum Commands {
         // scopes: Option<Vec<String>>,
     },
 
+    /// Create application
+    #[clap(about = "Create a new application", long_about = None)]
+    CreateApplication {
+        /// Application name
+        #[clap(
+            required = true,
+            short = 'n',
+            long,
+            value_name = "Name",
+            num_args = 1,
+            help = "Name (at least 3 characters)"
+        )]
+        name: String,
+    },
+
     /// Clean expired database data
     #[clap(about = "Clean expired database data", long_about = None)]
     CleanDatabase,
@@ -98,6 +113,7 @@ pub async fn start() -> Result<(), CliError> {
             email,
             password,
         } => cmd::user::register(lastname, firstname, email, password).await,
+        Commands::CreateApplication { name } => cmd::application::create(name).await,
         Commands::CleanDatabase => cmd::database::clean_data().await,
     }
 }
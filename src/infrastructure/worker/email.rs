@@ -0,0 +1,51 @@
+//! Email outbox worker
+
+use crate::config::Config;
+use crate::domain::repositories::email::EmailRepository;
+use crate::domain::services::email::EmailTransport;
+use crate::domain::use_cases::email::deliver_emails::{DeliverEmailsUseCase, DeliverEmailsUseCaseRequest};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+/// Spawn a task which sends the due emails of the outbox at regular intervals
+///
+/// Nothing is spawned if the interval is 0.
+pub fn start<R, T>(use_case: DeliverEmailsUseCase<R, T>, settings: &Config) -> Option<JoinHandle<()>>
+where
+    R: EmailRepository + Send + Sync + 'static,
+    T: EmailTransport + Send + Sync + 'static,
+{
+    if settings.email_worker_interval == 0 {
+        info!("Email worker disabled");
+        return None;
+    }
+
+    let request = DeliverEmailsUseCaseRequest {
+        batch_size: settings.email_batch_size,
+        max_attempts: settings.email_max_attempts,
+        backoff_base: settings.email_backoff_base,
+    };
+    let mut interval = tokio::time::interval(Duration::from_secs(settings.email_worker_interval));
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    info!("Starting email worker...");
+    Some(tokio::spawn(async move {
+        loop {
+            interval.tick().await;
+
+            match use_case.call(request.clone()).await {
+                Ok(response) if response.sent + response.retried + response.dead > 0 => {
+                    info!(
+                        sent = response.sent,
+                        retried = response.retried,
+                        dead = response.dead,
+                        "Emails sent"
+                    );
+                }
+                Ok(_) => {}
+                Err(err) => error!(error = %err, "Email worker error"),
+            }
+        }
+    }))
+}
@@ -0,0 +1,4 @@
+//! Background workers started with the server
+
+pub mod email;
+pub mod webhook;
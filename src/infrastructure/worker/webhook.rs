@@ -0,0 +1,51 @@
+//! Webhook delivery worker
+
+use crate::config::Config;
+use crate::domain::repositories::webhook::WebhookRepository;
+use crate::domain::services::webhook::WebhookService;
+use crate::domain::use_cases::webhook::deliver_webhooks::{DeliverWebhooksUseCase, DeliverWebhooksUseCaseRequest};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+/// Spawn a task which sends the due webhook deliveries at regular intervals
+///
+/// Nothing is spawned if the interval is 0.
+pub fn start<W, S>(use_case: DeliverWebhooksUseCase<W, S>, settings: &Config) -> Option<JoinHandle<()>>
+where
+    W: WebhookRepository + Send + Sync + 'static,
+    S: WebhookService + Send + Sync + 'static,
+{
+    if settings.webhook_worker_interval == 0 {
+        info!("Webhook worker disabled");
+        return None;
+    }
+
+    let request = DeliverWebhooksUseCaseRequest {
+        batch_size: settings.webhook_batch_size,
+        max_attempts: settings.webhook_max_attempts,
+        backoff_base: settings.webhook_backoff_base,
+    };
+    let mut interval = tokio::time::interval(Duration::from_secs(settings.webhook_worker_interval));
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    info!("Starting webhook worker...");
+    Some(tokio::spawn(async move {
+        loop {
+            interval.tick().await;
+
+            match use_case.call(request.clone()).await {
+                Ok(response) if response.delivered + response.retried + response.failed > 0 => {
+                    info!(
+                        delivered = response.delivered,
+                        retried = response.retried,
+                        failed = response.failed,
+                        "Webhook deliveries sent"
+                    );
+                }
+                Ok(_) => {}
+                Err(err) => error!(error = %err, "Webhook worker error"),
+            }
+        }
+    }))
+}